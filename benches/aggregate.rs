@@ -2,6 +2,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 
 use arrow2::array::*;
 use arrow2::compute::aggregate::*;
+use arrow2::compute::boolean_kleene::{all, any};
 use arrow2::util::bench_util::*;
 
 fn bench_sum(arr_a: &PrimitiveArray<f32>) {
@@ -12,6 +13,15 @@ fn bench_min(arr_a: &PrimitiveArray<f32>) {
     min_primitive(criterion::black_box(arr_a)).unwrap();
 }
 
+fn bench_min_max(arr_a: &PrimitiveArray<f32>) {
+    min_max_primitive(criterion::black_box(arr_a)).unwrap();
+}
+
+fn bench_min_then_max(arr_a: &PrimitiveArray<f32>) {
+    min_primitive(criterion::black_box(arr_a)).unwrap();
+    max_primitive(criterion::black_box(arr_a)).unwrap();
+}
+
 fn add_benchmark(c: &mut Criterion) {
     (10..=20).step_by(2).for_each(|log2_size| {
         let size = 2usize.pow(log2_size);
@@ -33,6 +43,26 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("min null 2^{} f32", log2_size), |b| {
             b.iter(|| bench_min(&arr_a))
         });
+
+        c.bench_function(&format!("min_max null 2^{} f32", log2_size), |b| {
+            b.iter(|| bench_min_max(&arr_a))
+        });
+
+        c.bench_function(&format!("min then max null 2^{} f32", log2_size), |b| {
+            b.iter(|| bench_min_then_max(&arr_a))
+        });
+
+        // `any` is decided by the first word, while `all` of `true`s reads every word
+        let arr_a = create_boolean_array(size, 0.1, 0.5);
+
+        c.bench_function(&format!("any early exit 2^{} bool", log2_size), |b| {
+            b.iter(|| any(criterion::black_box(&arr_a)))
+        });
+
+        let arr_a = create_boolean_array(size, 0.1, 1.0);
+        c.bench_function(&format!("all 2^{} bool", log2_size), |b| {
+            b.iter(|| all(criterion::black_box(&arr_a)))
+        });
     });
 }
 
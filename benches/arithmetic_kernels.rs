@@ -4,14 +4,14 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use arrow2::array::*;
 use arrow2::util::bench_util::*;
 use arrow2::{compute::arithmetics::basic::add, compute::arithmetics::basic::div_scalar};
-use num_traits::NumCast;
+use num_traits::{NumCast, Zero};
 use std::ops::{Add, Div};
 
 fn bench_div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T)
 where
-    T: NativeArithmetics + Div<Output = T> + NumCast,
+    T: NativeArithmetics + Div<Output = T> + NumCast + Zero,
 {
-    criterion::black_box(div_scalar(lhs, rhs));
+    criterion::black_box(div_scalar(lhs, rhs).unwrap());
 }
 
 fn bench_add<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>)
@@ -42,5 +42,17 @@ fn add_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, add_benchmark);
+fn add_nulls_benchmark(c: &mut Criterion) {
+    let size = 1_000_000;
+    [0.0, 0.1, 0.5].iter().for_each(|null_density| {
+        let arr_a = create_primitive_array_with_seed::<f64>(size, *null_density, 43);
+        let arr_b = create_primitive_array_with_seed::<f64>(size, *null_density, 42);
+
+        c.bench_function(&format!("add f64 1M null_density={}", null_density), |b| {
+            b.iter(|| bench_add(&arr_a, &arr_b))
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark, add_nulls_benchmark);
 criterion_main!(benches);
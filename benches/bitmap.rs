@@ -13,7 +13,7 @@ fn add_benchmark(c: &mut Criterion) {
         c.bench_function(&format!("bitmap extend aligned 2^{}", log2_size), |b| {
             let mut bitmap1 = MutableBitmap::new();
             b.iter(|| {
-                bitmap1.extend_from_bitmap(&bitmap2);
+                bitmap1.extend_from_bitmap(&bitmap2, 0, bitmap2.len());
                 bitmap1.clear();
             })
         });
@@ -22,7 +22,7 @@ fn add_benchmark(c: &mut Criterion) {
             let mut bitmap1 = MutableBitmap::with_capacity(1);
             b.iter(|| {
                 bitmap1.push(true);
-                bitmap1.extend_from_bitmap(&bitmap2);
+                bitmap1.extend_from_bitmap(&bitmap2, 0, bitmap2.len());
                 bitmap1.clear();
             })
         });
@@ -72,5 +72,27 @@ fn add_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, add_benchmark);
+fn add_offset_benchmark(c: &mut Criterion) {
+    let size = 1_000_000;
+    let bitmap = Bitmap::from_iter((0..size + 5).into_iter().map(|x| x % 3 == 0));
+
+    c.bench_function("bitmap extend offset 5 1M", |b| {
+        let mut bitmap1 = MutableBitmap::new();
+        b.iter(|| {
+            bitmap1.extend_from_bitmap(&bitmap, 5, size);
+            bitmap1.clear();
+        })
+    });
+
+    c.bench_function("bitmap extend offset 5 unaligned 1M", |b| {
+        let mut bitmap1 = MutableBitmap::new();
+        b.iter(|| {
+            bitmap1.push(true);
+            bitmap1.extend_from_bitmap(&bitmap, 5, size);
+            bitmap1.clear();
+        })
+    });
+}
+
+criterion_group!(benches, add_benchmark, add_offset_benchmark);
 criterion_main!(benches);
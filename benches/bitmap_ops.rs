@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use arrow2::bitmap::Bitmap;
+use arrow2::bitmap::{and, xor, Bitmap};
 
 fn bench_arrow2(lhs: &Bitmap, rhs: &Bitmap) {
     let r = lhs | rhs;
@@ -60,5 +60,29 @@ fn add_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, add_benchmark);
+fn add_misaligned_benchmark(c: &mut Criterion) {
+    let size = 10_000_000;
+    let lhs: Bitmap = (0..size + 3).map(|x| x % 3 == 0).collect();
+    let rhs: Bitmap = (0..size + 61).map(|x| x % 4 == 0).collect();
+    let lhs = lhs.slice(3, size);
+    let rhs = rhs.slice(61, size);
+
+    c.bench_function("bitmap misaligned and 10M", |b| {
+        b.iter(|| {
+            let r = and(&lhs, &rhs);
+            assert!(r.null_count() > 0);
+        })
+    });
+    c.bench_function("bitmap misaligned or 10M", |b| {
+        b.iter(|| bench_arrow2(&lhs, &rhs))
+    });
+    c.bench_function("bitmap misaligned xor 10M", |b| {
+        b.iter(|| {
+            let r = xor(&lhs, &rhs);
+            assert!(r.null_count() > 0);
+        })
+    });
+}
+
+criterion_group!(benches, add_benchmark, add_misaligned_benchmark);
 criterion_main!(benches);
@@ -1,9 +1,54 @@
 use criterion::{criterion_group, criterion_main, Criterion};
+use rand::distributions::{Distribution, Standard};
 
-use arrow2::compute::comparison::{eq, eq_scalar};
+use arrow2::compute::comparison::primitive;
+use arrow2::compute::comparison::{eq, eq_scalar, Simd8, Simd8PartialEq, Simd8PartialOrd};
 use arrow2::scalar::*;
+use arrow2::types::NativeType;
 use arrow2::util::bench_util::*;
 
+// the primitive kernels, which are dispatched to AVX2 or SSE4.1 at runtime when available
+fn bench_primitive<T>(c: &mut Criterion, name: &str)
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialEq + Simd8PartialOrd,
+    Standard: Distribution<T>,
+{
+    [10, 20].iter().for_each(|log2_size| {
+        let size = 2usize.pow(*log2_size);
+
+        let arr_a = create_primitive_array_with_seed::<T>(size, 0.0, 42);
+        let arr_b = create_primitive_array_with_seed::<T>(size, 0.0, 43);
+        let scalar = arr_b.value(0);
+
+        c.bench_function(&format!("eq {} 2^{}", name, log2_size), |b| {
+            b.iter(|| primitive::eq(&arr_a, &arr_b))
+        });
+        c.bench_function(&format!("lt {} 2^{}", name, log2_size), |b| {
+            b.iter(|| primitive::lt(&arr_a, &arr_b))
+        });
+        c.bench_function(&format!("eq scalar {} 2^{}", name, log2_size), |b| {
+            b.iter(|| primitive::eq_scalar(&arr_a, scalar))
+        });
+        c.bench_function(&format!("lt scalar {} 2^{}", name, log2_size), |b| {
+            b.iter(|| primitive::lt_scalar(&arr_a, scalar))
+        });
+    })
+}
+
+fn add_primitive_benchmark(c: &mut Criterion) {
+    bench_primitive::<u8>(c, "u8");
+    bench_primitive::<u16>(c, "u16");
+    bench_primitive::<u32>(c, "u32");
+    bench_primitive::<u64>(c, "u64");
+    bench_primitive::<i8>(c, "i8");
+    bench_primitive::<i16>(c, "i16");
+    bench_primitive::<i32>(c, "i32");
+    bench_primitive::<i64>(c, "i64");
+    bench_primitive::<f32>(c, "f32");
+    bench_primitive::<f64>(c, "f64");
+}
+
 fn add_benchmark(c: &mut Criterion) {
     (10..=20).step_by(2).for_each(|log2_size| {
         let size = 2usize.pow(log2_size);
@@ -34,11 +79,11 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| eq(&arr_a, &arr_b))
         });
 
-        c.bench_function(&format!("utf8 2^{}", log2_size), |b| {
+        c.bench_function(&format!("utf8 scalar 2^{}", log2_size), |b| {
             b.iter(|| eq_scalar(&arr_a, &Utf8Scalar::<i32>::from(Some("abc"))))
         });
     })
 }
 
-criterion_group!(benches, add_benchmark);
+criterion_group!(benches, add_benchmark, add_primitive_benchmark);
 criterion_main!(benches);
@@ -1,6 +1,7 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use arrow2::bitmap::utils::count_zeros;
+use arrow2::array::Int8Array;
+use arrow2::bitmap::{utils::count_zeros, Bitmap};
 
 fn add_benchmark(c: &mut Criterion) {
     (10..=20).step_by(2).for_each(|log2_size| {
@@ -20,5 +21,46 @@ fn add_benchmark(c: &mut Criterion) {
     })
 }
 
-criterion_group!(benches, add_benchmark);
+fn add_slice_benchmark(c: &mut Criterion) {
+    let size = 100_000_000;
+    let bitmap: Bitmap = (0..size).map(|x| x % 3 == 0).collect();
+
+    c.bench_function("bitmap slice 100M", |b| {
+        b.iter(|| {
+            (0..10).for_each(|i| {
+                let offset = i * 1_000_003;
+                let len = size / 3 + i;
+                let r = bitmap.clone().slice(offset, len);
+                assert!(r.null_count() > 0);
+            })
+        })
+    });
+
+    c.bench_function("bitmap count_ones 100M", |b| {
+        b.iter(|| bitmap.count_ones(3, size - 64))
+    });
+}
+
+fn add_array_slice_benchmark(c: &mut Criterion) {
+    let size = 100_000_000;
+    let array = (0..size)
+        .map(|x| if x % 3 == 0 { None } else { Some(x as i8) })
+        .collect::<Int8Array>();
+
+    c.bench_function("array slice 100M 10k times", |b| {
+        b.iter(|| {
+            (0..10_000).for_each(|i| {
+                let r = array.slice(i, size - 2 * i);
+                assert_eq!(r.len(), size - 2 * i);
+            })
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    add_benchmark,
+    add_slice_benchmark,
+    add_array_slice_benchmark
+);
 criterion_main!(benches);
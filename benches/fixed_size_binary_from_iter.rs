@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::MutableFixedSizeBinaryArray;
+
+fn uuids(size: usize) -> Vec<Option<[u8; 16]>> {
+    (0..size as u128)
+        .map(|i| {
+            if i % 10 == 0 {
+                None
+            } else {
+                Some(
+                    i.wrapping_mul(0x9e3779b97f4a7c15f39cc0605cedc834)
+                        .to_le_bytes(),
+                )
+            }
+        })
+        .collect()
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 1_000_000;
+    let values = uuids(size);
+
+    c.bench_function("uuids try_push 1M", |b| {
+        b.iter(|| {
+            let mut array = MutableFixedSizeBinaryArray::with_capacity(16, size);
+            values
+                .iter()
+                .try_for_each(|x| array.try_push(x.as_ref()))
+                .unwrap();
+            array
+        })
+    });
+
+    c.bench_function("uuids from_trusted_len_iter 1M", |b| {
+        b.iter(|| {
+            MutableFixedSizeBinaryArray::from_trusted_len_iter(
+                values.iter().map(|x| x.as_ref()),
+                16,
+            )
+            .unwrap()
+        })
+    });
+
+    c.bench_function("uuids from_trusted_len_iter_unchecked 1M", |b| {
+        b.iter(|| unsafe {
+            MutableFixedSizeBinaryArray::from_trusted_len_iter_unchecked(
+                values.iter().map(|x| x.as_ref()),
+                16,
+            )
+        })
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
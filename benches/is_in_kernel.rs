@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::boolean::or;
+use arrow2::compute::comparison::{primitive, utf8};
+use arrow2::compute::contains::is_in;
+use arrow2::util::bench_util::*;
+
+fn bench_is_in(values: &dyn Array, set: &dyn Array) {
+    is_in(values, set).unwrap();
+}
+
+fn bench_eq_or_primitive(values: &Int32Array, set: &[i32]) {
+    set.iter()
+        .map(|x| primitive::eq_scalar(values, *x))
+        .reduce(|lhs, rhs| or(&lhs, &rhs).unwrap())
+        .unwrap();
+}
+
+fn bench_eq_or_utf8(values: &Utf8Array<i32>, set: &[&str]) {
+    set.iter()
+        .map(|x| utf8::eq_scalar(values, x))
+        .reduce(|lhs, rhs| or(&lhs, &rhs).unwrap())
+        .unwrap();
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+
+        let values = create_primitive_array::<i32>(size, 0.1);
+        let set = create_primitive_array_with_seed::<i32>(10, 0.0, 43);
+        let set_values = set.values().to_vec();
+
+        c.bench_function(&format!("is_in 2^{} i32", log2_size), |b| {
+            b.iter(|| bench_is_in(&values, &set))
+        });
+        c.bench_function(&format!("eq+or 2^{} i32", log2_size), |b| {
+            b.iter(|| bench_eq_or_primitive(&values, &set_values))
+        });
+
+        let values = create_string_array::<i32>(size, 4, 0.1, 42);
+        let set = create_string_array::<i32>(10, 4, 0.0, 43);
+        let set_values = set.values_iter().collect::<Vec<_>>();
+
+        c.bench_function(&format!("is_in 2^{} utf8", log2_size), |b| {
+            b.iter(|| bench_is_in(&values, &set))
+        });
+        c.bench_function(&format!("eq+or 2^{} utf8", log2_size), |b| {
+            b.iter(|| bench_eq_or_utf8(&values, &set_values))
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,50 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::*;
+use arrow2::compute::concatenate::concatenate;
+use arrow2::compute::merge_sort::{merge_sort, SortOptions};
+use arrow2::compute::sort::sort;
+use arrow2::util::bench_util::*;
+
+fn bench_merge_sort(lhs: &dyn Array, rhs: &dyn Array) {
+    merge_sort(lhs, rhs, &SortOptions::default(), None).unwrap();
+}
+
+fn bench_concatenate_sort(lhs: &dyn Array, rhs: &dyn Array) {
+    let array = concatenate(&[lhs, rhs]).unwrap();
+    sort(array.as_ref(), &SortOptions::default(), None).unwrap();
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+        let options = SortOptions::default();
+
+        let lhs = create_primitive_array::<i32>(size, 0.0);
+        let rhs = create_primitive_array_with_seed::<i32>(size, 0.0, 43);
+        let lhs = sort(&lhs, &options, None).unwrap();
+        let rhs = sort(&rhs, &options, None).unwrap();
+
+        c.bench_function(&format!("merge_sort 2^{} i32", log2_size), |b| {
+            b.iter(|| bench_merge_sort(lhs.as_ref(), rhs.as_ref()))
+        });
+        c.bench_function(&format!("concatenate+sort 2^{} i32", log2_size), |b| {
+            b.iter(|| bench_concatenate_sort(lhs.as_ref(), rhs.as_ref()))
+        });
+
+        let lhs = create_string_array::<i32>(size, 4, 0.0, 42);
+        let rhs = create_string_array::<i32>(size, 4, 0.0, 43);
+        let lhs = sort(&lhs, &options, None).unwrap();
+        let rhs = sort(&rhs, &options, None).unwrap();
+
+        c.bench_function(&format!("merge_sort 2^{} utf8", log2_size), |b| {
+            b.iter(|| bench_merge_sort(lhs.as_ref(), rhs.as_ref()))
+        });
+        c.bench_function(&format!("concatenate+sort 2^{} utf8", log2_size), |b| {
+            b.iter(|| bench_concatenate_sort(lhs.as_ref(), rhs.as_ref()))
+        });
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::MutableUtf8Array;
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 1_000_000;
+    let values = (0..size)
+        .map(|i| {
+            if i % 10 == 0 {
+                None
+            } else {
+                Some(format!("s{}", i % 1000))
+            }
+        })
+        .collect::<Vec<_>>();
+    let bytes = values.iter().flatten().map(|x| x.len()).sum::<usize>();
+
+    c.bench_function("utf8 push 1M", |b| {
+        b.iter(|| {
+            let mut array = MutableUtf8Array::<i32>::with_capacities(size, bytes);
+            values.iter().for_each(|x| array.push(x.as_ref()));
+            array
+        })
+    });
+
+    c.bench_function("utf8 push_unchecked 1M", |b| {
+        b.iter(|| {
+            let mut array = MutableUtf8Array::<i32>::with_capacities(size, bytes);
+            values
+                .iter()
+                .for_each(|x| unsafe { array.push_unchecked(x.as_ref()) });
+            array
+        })
+    });
+
+    c.bench_function("utf8 extend_trusted_len 1M", |b| {
+        b.iter(|| {
+            let mut array = MutableUtf8Array::<i32>::with_capacities(size, bytes);
+            array.extend_trusted_len(values.iter().map(|x| x.as_ref()));
+            array
+        })
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
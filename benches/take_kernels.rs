@@ -44,6 +44,11 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| bench_take(&values_nulls, &indices))
         });
 
+        c.bench_function(
+            &format!("take i32 values nulls indices nulls 2^{}", log2_size),
+            |b| b.iter(|| bench_take(&values_nulls, &indices_nulls)),
+        );
+
         let values = create_boolean_array(size, 0.0, 0.5);
         c.bench_function(&format!("take bool 2^{}", log2_size), |b| {
             b.iter(|| bench_take(&values, &indices))
@@ -37,7 +37,10 @@ fn main() -> Result<()> {
 fn write_ipc<W: Write + Seek>(writer: W, array: impl Array + 'static) -> Result<W> {
     let schema = vec![Field::new("a", array.data_type().clone(), false)].into();
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
     let mut writer = write::FileWriter::try_new(writer, &schema, None, options)?;
 
     let batch = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
@@ -10,7 +10,10 @@ use arrow2::io::ipc::write;
 fn write_batches(path: &str, schema: &Schema, columns: &[Chunk<Arc<dyn Array>>]) -> Result<()> {
     let file = File::create(path)?;
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
     let mut writer = write::FileWriter::try_new(file, schema, None, options)?;
 
     for columns in columns {
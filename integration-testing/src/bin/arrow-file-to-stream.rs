@@ -34,7 +34,10 @@ fn main() -> Result<()> {
     let metadata = read::read_file_metadata(&mut f)?;
     let mut reader = read::FileReader::new(f, metadata.clone(), None);
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
     let mut writer = write::StreamWriter::new(std::io::stdout(), options);
 
     let fields = metadata.ipc_schema.fields.clone();
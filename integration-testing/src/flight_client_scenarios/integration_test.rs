@@ -94,7 +94,10 @@ async fn upload_data(
 ) -> Result {
     let (mut upload_tx, upload_rx) = mpsc::channel(10);
 
-    let options = write::WriteOptions { compression: None };
+    let options = write::WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
 
     let mut schema = flight::serialize_schema(schema, Some(fields));
     schema.flight_descriptor = Some(descriptor.clone());
@@ -109,7 +109,10 @@ impl FlightService for FlightServiceImpl {
             .get(&key)
             .ok_or_else(|| Status::not_found(format!("Could not find flight. {}", key)))?;
 
-        let options = ipc::write::WriteOptions { compression: None };
+        let options = ipc::write::WriteOptions {
+            compression: None,
+            delta_dictionaries: false,
+        };
 
         let schema = std::iter::once(Ok(serialize_schema(
             &flight.schema,
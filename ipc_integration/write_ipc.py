@@ -0,0 +1,50 @@
+import pyarrow as pa
+import pyarrow.ipc
+import numpy as np
+import os
+from decimal import Decimal
+
+PYARROW_PATH = "fixtures/pyarrow3/ipc"
+
+
+def case_float16():
+    values = np.array([1.0, 0.0, -0.5, 65504.0, np.inf], dtype=np.float16)
+    mask = np.array([False, True, False, False, False])
+
+    fields = [pa.field("float16", pa.float16())]
+    schema = pa.schema(fields)
+
+    return {"float16": pa.array(values, mask=mask)}, schema, "float16"
+
+
+def case_decimal256():
+    decimal_76 = [Decimal(2 ** 200), None, Decimal(-(2 ** 130 + 7)), Decimal(0)]
+    decimal_40 = [Decimal("1.25"), Decimal("-0.01"), None, Decimal(10 ** 35)]
+
+    fields = [
+        pa.field("decimal_76", pa.decimal256(76, 0)),
+        pa.field("decimal_40", pa.decimal256(40, 2)),
+    ]
+    schema = pa.schema(fields)
+
+    return (
+        {"decimal_76": decimal_76, "decimal_40": decimal_40},
+        schema,
+        "decimal256",
+    )
+
+
+def write_pyarrow(case):
+    data, schema, name = case()
+
+    os.makedirs(PYARROW_PATH, exist_ok=True)
+
+    t = pa.table(data, schema=schema)
+    with pa.ipc.new_file(f"{PYARROW_PATH}/{name}.arrow_file", schema) as writer:
+        writer.write_table(t)
+    with pa.ipc.new_stream(f"{PYARROW_PATH}/{name}.stream", schema) as writer:
+        writer.write_table(t)
+
+
+for case in [case_float16, case_decimal256]:
+    write_pyarrow(case)
@@ -1,5 +1,5 @@
 use crate::{
-    bitmap::Bitmap,
+    bitmap::{Bitmap, MutableBitmap},
     buffer::Buffer,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -248,17 +248,53 @@ impl<O: Offset> BinaryArray<O> {
         }
     }
 
-    /// Clones this [`BinaryArray`] with a different validity.
+    /// Returns a new [`BinaryArray`] with a different logical type, e.g. an extension type over it.
+    /// This is `O(1)`.
+    /// # Panics
+    /// Panics iff the logical type of `data_type` differs from the logical type of `self`.
+    #[inline]
+    pub fn to(self, data_type: DataType) -> Self {
+        assert_eq!(
+            data_type.to_logical_type(),
+            self.data_type.to_logical_type(),
+            "BinaryArray cannot change its logical type"
+        );
+        Self { data_type, ..self }
+    }
+
+    /// Returns this [`BinaryArray`] with a new validity bitmap.
     /// # Panic
-    /// Panics iff `validity.len() != self.len()`.
+    /// This function panics iff `validity.len() != self.len()`.
     #[must_use]
-    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity bitmap of this [`BinaryArray`].
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity's length must be equal to the array's length")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
+    }
+
+    /// Replaces the validity bitmap of this [`BinaryArray`] by the result of `f` over it,
+    /// e.g. to combine it with another mask. When it has no validity, `f` is applied over a bitmap whose slots are all valid.
+    /// # Panic
+    /// This function panics iff `f` returns a bitmap whose length is not `self.len()`.
+    pub fn apply_validity<F: Fn(&Bitmap) -> Bitmap>(&mut self, f: F) {
+        let validity = match self.validity.as_ref() {
+            Some(validity) => f(validity),
+            None => {
+                let mut validity = MutableBitmap::with_capacity(self.len());
+                validity.extend_constant(self.len(), true);
+                f(&validity.into())
+            }
+        };
+        self.set_validity(Some(validity));
     }
 }
 
@@ -339,7 +375,7 @@ impl<O: Offset> Array for BinaryArray<O> {
         Box::new(self.slice_unchecked(offset, length))
     }
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
-        Box::new(self.with_validity(validity))
+        Box::new(self.clone().with_validity(validity))
     }
 }
 
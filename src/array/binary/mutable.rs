@@ -1,7 +1,10 @@
 use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
-    array::{specification::check_offsets, Array, MutableArray, Offset, TryExtend, TryPush},
+    array::{
+        check_extend_from_array, extend_validity, specification::check_offsets, try_extend_offsets,
+        Array, MutableArray, Offset, TryExtend, TryPush,
+    },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -181,7 +184,7 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
     fn as_box(&mut self) -> Box<dyn Array> {
         Box::new(BinaryArray::from_data(
             self.data_type.clone(),
-            std::mem::take(&mut self.offsets).into(),
+            std::mem::replace(&mut self.offsets, vec![O::default()]).into(),
             std::mem::take(&mut self.values).into(),
             std::mem::take(&mut self.validity).map(|x| x.into()),
         ))
@@ -190,7 +193,7 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
     fn as_arc(&mut self) -> Arc<dyn Array> {
         Arc::new(BinaryArray::from_data(
             self.data_type.clone(),
-            std::mem::take(&mut self.offsets).into(),
+            std::mem::replace(&mut self.offsets, vec![O::default()]).into(),
             std::mem::take(&mut self.values).into(),
             std::mem::take(&mut self.validity).map(|x| x.into()),
         ))
@@ -216,6 +219,23 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+
+        let offsets = &array.offsets()[start..start + len + 1];
+        let values = &array.values()[offsets[0].to_usize()..offsets[len].to_usize()];
+        let length = self.offsets.len() - 1;
+        try_extend_offsets(&mut self.offsets, offsets)?;
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+        self.values.extend_from_slice(values);
+        Ok(())
+    }
 }
 
 impl<O: Offset, P: AsRef<[u8]>> FromIterator<Option<P>> for MutableBinaryArray<O> {
@@ -314,23 +334,61 @@ impl<O: Offset> MutableBinaryArray<O> {
 
     /// Extends the [`MutableBinaryArray`] from an iterator of trusted length.
     /// This differs from `extend_trusted_len` which accepts iterator of optional values.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
     #[inline]
     pub fn extend_trusted_len_values<I, P>(&mut self, iterator: I)
+    where
+        P: AsRef<[u8]>,
+        I: TrustedLen<Item = P>,
+    {
+        self.try_extend_trusted_len_values(iterator).unwrap()
+    }
+
+    /// Extends the [`MutableBinaryArray`] from an iterator of trusted length.
+    /// This differs from `try_extend_trusted_len` which accepts iterator of optional values.
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged. Use `DataType::LargeBinary`
+    /// (i.e. `O = i64`) for larger arrays.
+    #[inline]
+    pub fn try_extend_trusted_len_values<I, P>(&mut self, iterator: I) -> Result<()>
     where
         P: AsRef<[u8]>,
         I: TrustedLen<Item = P>,
     {
         // Safety: The iterator is `TrustedLen`
-        unsafe { self.extend_trusted_len_values_unchecked(iterator) }
+        unsafe { self.try_extend_trusted_len_values_unchecked(iterator) }
     }
 
     /// Extends the [`MutableBinaryArray`] from an `iterator` of values of trusted length.
     /// This differs from `extend_trusted_len_unchecked` which accepts iterator of optional
     /// values.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
     /// # Safety
     /// The `iterator` must be [`TrustedLen`]
     #[inline]
     pub unsafe fn extend_trusted_len_values_unchecked<I, P>(&mut self, iterator: I)
+    where
+        P: AsRef<[u8]>,
+        I: Iterator<Item = P>,
+    {
+        self.try_extend_trusted_len_values_unchecked(iterator)
+            .unwrap()
+    }
+
+    /// Extends the [`MutableBinaryArray`] from an `iterator` of values of trusted length.
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged.
+    /// # Safety
+    /// The `iterator` must be [`TrustedLen`]
+    #[inline]
+    pub unsafe fn try_extend_trusted_len_values_unchecked<I, P>(
+        &mut self,
+        iterator: I,
+    ) -> Result<()>
     where
         P: AsRef<[u8]>,
         I: Iterator<Item = P>,
@@ -338,29 +396,63 @@ impl<O: Offset> MutableBinaryArray<O> {
         let (_, upper) = iterator.size_hint();
         let additional = upper.expect("extend_trusted_len_values requires an upper limit");
 
-        extend_from_trusted_len_values_iter(&mut self.offsets, &mut self.values, iterator);
+        try_extend_from_trusted_len_values_iter(&mut self.offsets, &mut self.values, iterator)?;
 
         if let Some(validity) = self.validity.as_mut() {
             validity.extend_constant(additional, true);
         }
+        Ok(())
     }
 
     /// Extends the [`MutableBinaryArray`] from an iterator of [`TrustedLen`]
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
     #[inline]
     pub fn extend_trusted_len<I, P>(&mut self, iterator: I)
+    where
+        P: AsRef<[u8]>,
+        I: TrustedLen<Item = Option<P>>,
+    {
+        self.try_extend_trusted_len(iterator).unwrap()
+    }
+
+    /// Extends the [`MutableBinaryArray`] from an iterator of [`TrustedLen`]
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged. Use `DataType::LargeBinary`
+    /// (i.e. `O = i64`) for larger arrays.
+    #[inline]
+    pub fn try_extend_trusted_len<I, P>(&mut self, iterator: I) -> Result<()>
     where
         P: AsRef<[u8]>,
         I: TrustedLen<Item = Option<P>>,
     {
         // Safety: The iterator is `TrustedLen`
-        unsafe { self.extend_trusted_len_unchecked(iterator) }
+        unsafe { self.try_extend_trusted_len_unchecked(iterator) }
     }
 
     /// Extends the [`MutableBinaryArray`] from an iterator of [`TrustedLen`]
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
     /// # Safety
     /// The `iterator` must be [`TrustedLen`]
     #[inline]
     pub unsafe fn extend_trusted_len_unchecked<I, P>(&mut self, iterator: I)
+    where
+        P: AsRef<[u8]>,
+        I: Iterator<Item = Option<P>>,
+    {
+        self.try_extend_trusted_len_unchecked(iterator).unwrap()
+    }
+
+    /// Extends the [`MutableBinaryArray`] from an iterator of [`TrustedLen`]
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged.
+    /// # Safety
+    /// The `iterator` must be [`TrustedLen`]
+    #[inline]
+    pub unsafe fn try_extend_trusted_len_unchecked<I, P>(&mut self, iterator: I) -> Result<()>
     where
         P: AsRef<[u8]>,
         I: Iterator<Item = Option<P>>,
@@ -371,7 +463,7 @@ impl<O: Offset> MutableBinaryArray<O> {
             self.validity = Some(validity);
         }
 
-        extend_from_trusted_len_iter(
+        let result = try_extend_from_trusted_len_iter(
             &mut self.offsets,
             &mut self.values,
             self.validity.as_mut().unwrap(),
@@ -381,6 +473,7 @@ impl<O: Offset> MutableBinaryArray<O> {
         if self.validity.as_mut().unwrap().null_count() == 0 {
             self.validity = None;
         }
+        result
     }
 
     /// Creates a new [`MutableBinaryArray`] from a [`Iterator`] of `&[u8]`.
@@ -2,7 +2,7 @@ use std::iter::FromIterator;
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{check_extend_from_array, extend_validity, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::{DataType, PhysicalType},
     error::Result,
@@ -189,6 +189,23 @@ impl MutableBooleanArray {
     pub fn values(&self) -> &MutableBitmap {
         &self.values
     }
+
+    /// Returns the value at position `index`, or `None` if it is null.
+    /// # Panic
+    /// Panics iff `index >= self.len()`.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        assert!(
+            index < self.len(),
+            "index {} is out of bounds of a MutableBooleanArray of len {}",
+            index,
+            self.len()
+        );
+        if self.validity.as_ref().map(|x| x.get(index)).unwrap_or(true) {
+            Some(self.values.get(index))
+        } else {
+            None
+        }
+    }
 }
 
 /// Setters
@@ -197,8 +214,14 @@ impl MutableBooleanArray {
     /// Note that if it is the first time a null appears in this array,
     /// this initializes the validity bitmap (`O(N)`).
     /// # Panic
-    /// Panics iff index is larger than `self.len()`.
+    /// Panics iff `index >= self.len()`.
     pub fn set(&mut self, index: usize, value: Option<bool>) {
+        assert!(
+            index < self.len(),
+            "index {} is out of bounds of a MutableBooleanArray of len {}",
+            index,
+            self.len()
+        );
         self.values.set(index, value.unwrap_or_default());
 
         if value.is_none() && self.validity.is_none() {
@@ -488,6 +511,26 @@ impl MutableArray for MutableBooleanArray {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+        extend_validity(
+            self.values.len(),
+            &mut self.validity,
+            array.validity(),
+            start,
+            len,
+        );
+        let (slice, offset, _) = array.values().as_slice();
+        self.values.extend_from_slice(slice, offset + start, len);
+        Ok(())
+    }
 }
 
 impl Extend<Option<bool>> for MutableBooleanArray {
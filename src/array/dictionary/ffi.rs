@@ -35,6 +35,6 @@ impl<K: DictionaryKey, A: ffi::ArrowArrayRef> FromFfi<A> for DictionaryArray<K>
         let values = array.dictionary()?.unwrap();
         let values = ffi::try_from(values)?.into();
 
-        Ok(DictionaryArray::<K>::from_data(keys, values))
+        DictionaryArray::<K>::try_new(keys, values)
     }
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::{
     bitmap::Bitmap,
     datatypes::{DataType, IntegerType},
+    error::{ArrowError, Result},
     scalar::{new_scalar, Scalar},
     types::NativeType,
 };
@@ -62,23 +63,73 @@ impl<K: DictionaryKey> DictionaryArray<K> {
     pub fn new_empty(data_type: DataType) -> Self {
         let values = Self::get_child(&data_type);
         let values = new_empty_array(values.clone()).into();
-        let data_type = K::PRIMITIVE.into();
-        Self::from_data(PrimitiveArray::<K>::new_empty(data_type), values)
+        let keys = PrimitiveArray::<K>::new_empty(K::PRIMITIVE.into());
+        Self::from_data(keys, values).to(data_type)
     }
 
     /// Returns an [`DictionaryArray`] whose all elements are null
     #[inline]
     pub fn new_null(data_type: DataType, length: usize) -> Self {
         let values = Self::get_child(&data_type);
-        let data_type = K::PRIMITIVE.into();
-        Self::from_data(
-            PrimitiveArray::<K>::new_null(data_type, length),
-            new_empty_array(values.clone()).into(),
-        )
+        let values = new_empty_array(values.clone()).into();
+        let keys = PrimitiveArray::<K>::new_null(K::PRIMITIVE.into(), length);
+        Self::from_data(keys, values).to(data_type)
+    }
+
+    /// Returns a new [`DictionaryArray`].
+    /// # Errors
+    /// This function errors iff any of the non-null `keys` is negative or not smaller than
+    /// `values.len()`.
+    /// # Implementation
+    /// This function is `O(N)` where `N` is the number of keys.
+    pub fn try_new(keys: PrimitiveArray<K>, values: Arc<dyn Array>) -> Result<Self> {
+        let len = values.len();
+        let check = |(index, key): (usize, &K)| {
+            if key.to_usize().map_or(true, |key| key >= len) {
+                Err(ArrowError::oos(format!(
+                    "The key at position {index} of a DictionaryArray must be non-negative and smaller than the length of its values ({len})"
+                )))
+            } else {
+                Ok(())
+            }
+        };
+        match keys.validity() {
+            None => keys.values().iter().enumerate().try_for_each(check)?,
+            Some(validity) => keys
+                .values()
+                .iter()
+                .enumerate()
+                .zip(validity.iter())
+                .filter(|(_, is_valid)| *is_valid)
+                .try_for_each(|(key, _)| check(key))?,
+        };
+
+        // Safety: the keys were just checked
+        Ok(unsafe { Self::new_unchecked(keys, values) })
+    }
+
+    /// Returns a new [`DictionaryArray`].
+    /// # Panics
+    /// This function panics iff any of the non-null `keys` is negative or not smaller than
+    /// `values.len()`.
+    /// # Implementation
+    /// This function is `O(N)` where `N` is the number of keys.
+    pub fn new(keys: PrimitiveArray<K>, values: Arc<dyn Array>) -> Self {
+        Self::try_new(keys, values).unwrap()
     }
 
-    /// The canonical method to create a new [`DictionaryArray`].
+    /// Alias for `new`
     pub fn from_data(keys: PrimitiveArray<K>, values: Arc<dyn Array>) -> Self {
+        Self::new(keys, values)
+    }
+
+    /// Returns a new [`DictionaryArray`] without checking its keys.
+    /// # Safety
+    /// The caller must ensure that every non-null key is non-negative and smaller than
+    /// `values.len()`.
+    /// # Implementation
+    /// This function is `O(1)`
+    pub unsafe fn new_unchecked(keys: PrimitiveArray<K>, values: Arc<dyn Array>) -> Self {
         let data_type =
             DataType::Dictionary(K::KEY_TYPE, Box::new(values.data_type().clone()), false);
 
@@ -89,6 +140,14 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
+    /// Alias for `new_unchecked`
+    /// # Safety
+    /// The caller must ensure that every non-null key is non-negative and smaller than
+    /// `values.len()`.
+    pub unsafe fn from_data_unchecked(keys: PrimitiveArray<K>, values: Arc<dyn Array>) -> Self {
+        Self::new_unchecked(keys, values)
+    }
+
     /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
     /// # Panics
     /// iff `offset + length > self.len()`.
@@ -111,16 +170,45 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
-    /// Sets the validity bitmap on this [`Array`].
+    /// Returns a new [`DictionaryArray`] with a different logical type, e.g. an extension type
+    /// over it or a different `is_ordered`.
+    /// This is `O(1)`.
+    /// # Panics
+    /// Panics iff `data_type` is not a dictionary of the same key and value types as `self`.
+    #[inline]
+    pub fn to(self, data_type: DataType) -> Self {
+        assert!(
+            data_type.to_physical_type() == self.data_type.to_physical_type()
+                && Self::get_child(&data_type) == self.values.data_type(),
+            "DictionaryArray cannot change its key or value types"
+        );
+        Self { data_type, ..self }
+    }
+
+    /// Returns this [`DictionaryArray`] with a new validity bitmap.
+    /// This replaces the validity of its keys.
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
-    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
-        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
-            panic!("validity should be as least as large as the array")
-        }
-        let mut arr = self.clone();
-        arr.values = Arc::from(arr.values.with_validity(validity));
-        arr
+    #[must_use]
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity bitmap of this [`DictionaryArray`].
+    /// This replaces the validity of its keys.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
+        self.keys.set_validity(validity);
+    }
+
+    /// Replaces the validity bitmap of this [`DictionaryArray`] by the result of `f` over it,
+    /// e.g. to combine it with another mask. When it has no validity, `f` is applied over a bitmap whose slots are all valid.
+    /// # Panic
+    /// This function panics iff `f` returns a bitmap whose length is not `self.len()`.
+    pub fn apply_validity<F: Fn(&Bitmap) -> Bitmap>(&mut self, f: F) {
+        self.keys.apply_validity(f);
     }
 }
 
@@ -200,6 +288,6 @@ impl<K: DictionaryKey> Array for DictionaryArray<K> {
         Box::new(self.slice_unchecked(offset, length))
     }
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
-        Box::new(self.with_validity(validity))
+        Box::new(self.clone().with_validity(validity))
     }
 }
@@ -3,7 +3,7 @@ use std::{collections::hash_map::DefaultHasher, sync::Arc};
 
 use hash_hasher::HashedMap;
 
-use crate::array::TryExtend;
+use crate::array::{TryExtend, TryPush};
 use crate::{
     array::{primitive::MutablePrimitiveArray, Array, MutableArray},
     bitmap::MutableBitmap,
@@ -67,7 +67,12 @@ impl<K: DictionaryKey, M: MutableArray + Default> Default for MutableDictionaryA
 }
 
 impl<K: DictionaryKey, M: MutableArray> MutableDictionaryArray<K, M> {
-    /// Returns whether the value should be pushed to the values or not
+    /// Pushes the key of `value`, and returns whether `value` is new and should
+    /// therefore be pushed to the values.
+    /// Values are identified by their [`Hash`], so that e.g. a `&str` and a `String`
+    /// with the same bytes are assigned the same key.
+    /// # Errors
+    /// Errors iff the number of distinct values exceeds the maximum value of `K`.
     pub fn try_push_valid<T: Hash>(&mut self, value: &T) -> Result<bool> {
         let mut hasher = DefaultHasher::new();
         value.hash(&mut hasher);
@@ -130,6 +135,7 @@ impl<K: DictionaryKey, M: 'static + MutableArray> MutableArray for MutableDictio
     }
 
     fn as_box(&mut self) -> Box<dyn Array> {
+        self.map.clear();
         Box::new(DictionaryArray::<K>::from_data(
             std::mem::take(&mut self.keys).into(),
             self.values.as_arc(),
@@ -137,6 +143,7 @@ impl<K: DictionaryKey, M: 'static + MutableArray> MutableArray for MutableDictio
     }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.map.clear();
         Arc::new(DictionaryArray::<K>::from_data(
             std::mem::take(&mut self.keys).into(),
             self.values.as_arc(),
@@ -161,6 +168,10 @@ impl<K: DictionaryKey, M: 'static + MutableArray> MutableArray for MutableDictio
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.keys.reserve(additional)
+    }
 }
 
 impl<K, M, T: Hash> TryExtend<Option<T>> for MutableDictionaryArray<K, M>
@@ -181,3 +192,24 @@ where
         Ok(())
     }
 }
+
+impl<K, M, T: Hash> TryPush<Option<T>> for MutableDictionaryArray<K, M>
+where
+    K: DictionaryKey,
+    M: MutableArray + TryPush<Option<T>>,
+{
+    /// Pushes a new element, only adding it to the values if it was not seen before.
+    /// # Errors
+    /// Errors iff the number of distinct values exceeds the maximum value of `K`
+    /// or if the values error on push.
+    fn try_push(&mut self, item: Option<T>) -> Result<()> {
+        if let Some(value) = item {
+            if self.try_push_valid(&value)? {
+                self.values.try_push(Some(value))?;
+            }
+        } else {
+            self.push_null();
+        }
+        Ok(())
+    }
+}
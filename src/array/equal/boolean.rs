@@ -1,5 +1,7 @@
-use crate::array::BooleanArray;
+use crate::array::{Array, BooleanArray};
 
 pub(super) fn equal(lhs: &BooleanArray, rhs: &BooleanArray) -> bool {
-    lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+    super::data_type_eq(lhs.data_type(), rhs.data_type())
+        && lhs.len() == rhs.len()
+        && lhs.iter().eq(rhs.iter())
 }
@@ -1,5 +1,7 @@
 use crate::array::{Array, ListArray, Offset};
 
 pub(super) fn equal<O: Offset>(lhs: &ListArray<O>, rhs: &ListArray<O>) -> bool {
-    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+    super::data_type_eq(lhs.data_type(), rhs.data_type())
+        && lhs.len() == rhs.len()
+        && lhs.iter().eq(rhs.iter())
 }
@@ -1,5 +1,7 @@
 use crate::array::{Array, MapArray};
 
 pub(super) fn equal(lhs: &MapArray, rhs: &MapArray) -> bool {
-    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+    super::data_type_eq(lhs.data_type(), rhs.data_type())
+        && lhs.len() == rhs.len()
+        && lhs.iter().eq(rhs.iter())
 }
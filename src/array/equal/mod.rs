@@ -165,12 +165,31 @@ impl PartialEq<&dyn Array> for UnionArray {
     }
 }
 
+impl PartialEq<MapArray> for MapArray {
+    fn eq(&self, other: &Self) -> bool {
+        map::equal(self, other)
+    }
+}
+
+impl PartialEq<&dyn Array> for MapArray {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 /// Logically compares two [`Array`]s.
 /// Two arrays are logically equal if and only if:
-/// * their data types are equal
-/// * each of their items are equal
+/// * their data types are equal, where extension types are equal iff they have the same name
+///   and storage type (their metadata is not compared)
+/// * their lengths are equal
+/// * each of their slots is null on both or valid on both with equal values
+///
+/// The comparison is independent of how the arrays are laid out in memory: offsets from
+/// slicing and the values behind null slots are not compared.
+/// Two [`DictionaryArray`]s with different dictionaries are equal iff their keys resolve
+/// to equal values; a null key equals a key pointing to a null value.
 pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
-    if lhs.data_type() != rhs.data_type() {
+    if !data_type_eq(lhs.data_type(), rhs.data_type()) {
         return false;
     }
 
@@ -255,3 +274,13 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
         }
     }
 }
+
+/// Compares two [`DataType`]s of arrays, ignoring the metadata of extension types.
+fn data_type_eq(lhs: &DataType, rhs: &DataType) -> bool {
+    match (lhs, rhs) {
+        (DataType::Extension(lhs_name, lhs, _), DataType::Extension(rhs_name, rhs, _)) => {
+            lhs_name == rhs_name && data_type_eq(lhs, rhs)
+        }
+        _ => lhs == rhs,
+    }
+}
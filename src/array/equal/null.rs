@@ -2,5 +2,5 @@ use crate::array::{Array, NullArray};
 
 #[inline]
 pub(super) fn equal(lhs: &NullArray, rhs: &NullArray) -> bool {
-    lhs.len() == rhs.len()
+    super::data_type_eq(lhs.data_type(), rhs.data_type()) && lhs.len() == rhs.len()
 }
@@ -1,54 +1,25 @@
 use crate::array::{Array, StructArray};
 
 pub(super) fn equal(lhs: &StructArray, rhs: &StructArray) -> bool {
-    lhs.data_type() == rhs.data_type()
-        && lhs.len() == rhs.len()
-        && match (lhs.validity(), rhs.validity()) {
-            (None, None) => lhs.values().iter().eq(rhs.values().iter()),
-            (Some(l_validity), Some(r_validity)) => lhs
-                .values()
-                .iter()
-                .zip(rhs.values().iter())
-                .all(|(lhs, rhs)| {
-                    l_validity.iter().zip(r_validity.iter()).enumerate().all(
-                        |(i, (lhs_is_valid, rhs_is_valid))| {
-                            if lhs_is_valid && rhs_is_valid {
-                                lhs.slice(i, 1) == rhs.slice(i, 1)
-                            } else {
-                                lhs_is_valid == rhs_is_valid
-                            }
-                        },
-                    )
-                }),
-            (Some(l_validity), None) => {
-                lhs.values()
-                    .iter()
-                    .zip(rhs.values().iter())
-                    .all(|(lhs, rhs)| {
-                        l_validity.iter().enumerate().all(|(i, lhs_is_valid)| {
-                            if lhs_is_valid {
-                                lhs.slice(i, 1) == rhs.slice(i, 1)
-                            } else {
-                                // rhs is always valid => different
-                                false
-                            }
-                        })
-                    })
-            }
-            (None, Some(r_validity)) => {
-                lhs.values()
-                    .iter()
-                    .zip(rhs.values().iter())
-                    .all(|(lhs, rhs)| {
-                        r_validity.iter().enumerate().all(|(i, rhs_is_valid)| {
-                            if rhs_is_valid {
-                                lhs.slice(i, 1) == rhs.slice(i, 1)
-                            } else {
-                                // lhs is always valid => different
-                                false
-                            }
-                        })
-                    })
-            }
-        }
+    if !(super::data_type_eq(lhs.data_type(), rhs.data_type()) && lhs.len() == rhs.len()) {
+        return false;
+    }
+
+    if lhs.null_count() == 0 && rhs.null_count() == 0 {
+        return lhs
+            .values()
+            .iter()
+            .zip(rhs.values().iter())
+            .all(|(lhs, rhs)| lhs.as_ref() == rhs.as_ref());
+    }
+
+    // slots are compared one by one so that the values behind null slots are ignored
+    (0..lhs.len()).all(|i| match (lhs.is_valid(i), rhs.is_valid(i)) {
+        (true, true) => lhs
+            .values()
+            .iter()
+            .zip(rhs.values().iter())
+            .all(|(lhs, rhs)| lhs.slice(i, 1).as_ref() == rhs.slice(i, 1).as_ref()),
+        (lhs_is_valid, rhs_is_valid) => lhs_is_valid == rhs_is_valid,
+    })
 }
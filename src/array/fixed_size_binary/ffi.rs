@@ -54,6 +54,6 @@ impl<A: ffi::ArrowArrayRef> FromFfi<A> for FixedSizeBinaryArray {
         let validity = unsafe { array.validity() }?;
         let values = unsafe { array.buffer::<u8>(1) }?;
 
-        Ok(Self::from_data(data_type, values, validity))
+        Self::try_new(data_type, values, validity)
     }
 }
@@ -1,14 +1,14 @@
 use crate::bitmap::utils::{zip_validity, ZipValidity};
+use crate::trusted_len::TrustedLen;
 
 use super::super::MutableArray;
 use super::{FixedSizeBinaryArray, FixedSizeBinaryValues, MutableFixedSizeBinaryArray};
 
-/// # Safety
-/// This iterator is `TrustedLen`
+/// Iterator of values of a [`FixedSizeBinaryArray`] or [`MutableFixedSizeBinaryArray`].
 pub struct FixedSizeBinaryValuesIter<'a, T: FixedSizeBinaryValues> {
     array: &'a T,
-    len: usize,
     index: usize,
+    end: usize,
 }
 
 impl<'a, T: FixedSizeBinaryValues> FixedSizeBinaryValuesIter<'a, T> {
@@ -16,10 +16,20 @@ impl<'a, T: FixedSizeBinaryValues> FixedSizeBinaryValuesIter<'a, T> {
     pub fn new(array: &'a T) -> Self {
         Self {
             array,
-            len: array.values().len() / array.size(),
             index: 0,
+            end: array.values().len() / array.size(),
         }
     }
+
+    /// # Safety
+    /// The caller must ensure that `index < self.end`
+    #[inline]
+    unsafe fn value_unchecked(&self, index: usize) -> &'a [u8] {
+        let size = self.array.size();
+        self.array
+            .values()
+            .get_unchecked(index * size..(index + 1) * size)
+    }
 }
 
 impl<'a, T: FixedSizeBinaryValues> Iterator for FixedSizeBinaryValuesIter<'a, T> {
@@ -27,22 +37,35 @@ impl<'a, T: FixedSizeBinaryValues> Iterator for FixedSizeBinaryValuesIter<'a, T>
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.len {
+        if self.index == self.end {
             return None;
         }
-        let index = self.index;
-        let r = Some(unsafe {
-            std::slice::from_raw_parts(
-                self.array.values().as_ptr().add(index * self.array.size()),
-                self.array.size(),
-            )
-        });
+        let old = self.index;
         self.index += 1;
-        r
+        // Safety:
+        // self.end is maximized by the length of the array
+        Some(unsafe { self.value_unchecked(old) })
     }
 
+    #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.len - self.index, Some(self.len - self.index))
+        (self.end - self.index, Some(self.end - self.index))
+    }
+}
+
+unsafe impl<'a, T: FixedSizeBinaryValues> TrustedLen for FixedSizeBinaryValuesIter<'a, T> {}
+
+impl<'a, T: FixedSizeBinaryValues> DoubleEndedIterator for FixedSizeBinaryValuesIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            // Safety:
+            // self.end is maximized by the length of the array
+            Some(unsafe { self.value_unchecked(self.end) })
+        }
     }
 }
 
@@ -67,9 +90,15 @@ impl<'a> FixedSizeBinaryArray {
     }
 
     /// Returns iterator over the values of [`FixedSizeBinaryArray`]
-    pub fn iter_values(&'a self) -> FixedSizeBinaryValuesIter<'a, FixedSizeBinaryArray> {
+    pub fn values_iter(&'a self) -> FixedSizeBinaryValuesIter<'a, FixedSizeBinaryArray> {
         FixedSizeBinaryValuesIter::new(self)
     }
+
+    /// Returns iterator over the values of [`FixedSizeBinaryArray`]
+    #[deprecated(since = "0.10.0", note = "Use `values_iter` instead")]
+    pub fn iter_values(&'a self) -> FixedSizeBinaryValuesIter<'a, FixedSizeBinaryArray> {
+        self.values_iter()
+    }
 }
 
 impl<'a> IntoIterator for &'a MutableFixedSizeBinaryArray {
@@ -94,7 +123,13 @@ impl<'a> MutableFixedSizeBinaryArray {
     }
 
     /// Returns iterator over the values of [`MutableFixedSizeBinaryArray`]
-    pub fn iter_values(&'a self) -> FixedSizeBinaryValuesIter<'a, MutableFixedSizeBinaryArray> {
+    pub fn values_iter(&'a self) -> FixedSizeBinaryValuesIter<'a, MutableFixedSizeBinaryArray> {
         FixedSizeBinaryValuesIter::new(self)
     }
+
+    /// Returns iterator over the values of [`MutableFixedSizeBinaryArray`]
+    #[deprecated(since = "0.10.0", note = "Use `values_iter` instead")]
+    pub fn iter_values(&'a self) -> FixedSizeBinaryValuesIter<'a, MutableFixedSizeBinaryArray> {
+        self.values_iter()
+    }
 }
@@ -1,4 +1,9 @@
-use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType, error::ArrowError};
+use crate::{
+    bitmap::{Bitmap, MutableBitmap},
+    buffer::Buffer,
+    datatypes::DataType,
+    error::ArrowError,
+};
 
 use super::Array;
 
@@ -81,9 +86,10 @@ impl FixedSizeBinaryArray {
 
     /// Returns a new null [`FixedSizeBinaryArray`].
     pub fn new_null(data_type: DataType, length: usize) -> Self {
+        let size = Self::get_size(&data_type);
         Self::from_data(
             data_type,
-            Buffer::new_zeroed(length),
+            Buffer::new_zeroed(length * size),
             Some(Bitmap::new_zeroed(length)),
         )
     }
@@ -128,17 +134,39 @@ impl FixedSizeBinaryArray {
         }
     }
 
-    /// Sets the validity bitmap on this [`FixedSizeBinaryArray`].
+    /// Returns this [`FixedSizeBinaryArray`] with a new validity bitmap.
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
     #[must_use]
-    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity bitmap of this [`FixedSizeBinaryArray`].
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
+    }
+
+    /// Replaces the validity bitmap of this [`FixedSizeBinaryArray`] by the result of `f` over it,
+    /// e.g. to combine it with another mask. When it has no validity, `f` is applied over a bitmap whose slots are all valid.
+    /// # Panic
+    /// This function panics iff `f` returns a bitmap whose length is not `self.len()`.
+    pub fn apply_validity<F: Fn(&Bitmap) -> Bitmap>(&mut self, f: F) {
+        let validity = match self.validity.as_ref() {
+            Some(validity) => f(validity),
+            None => {
+                let mut validity = MutableBitmap::with_capacity(self.len());
+                validity.extend_constant(self.len(), true);
+                f(&validity.into())
+            }
+        };
+        self.set_validity(Some(validity));
     }
 }
 
@@ -251,7 +279,7 @@ impl Array for FixedSizeBinaryArray {
         Box::new(self.slice_unchecked(offset, length))
     }
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
-        Box::new(self.with_validity(validity))
+        Box::new(self.clone().with_validity(validity))
     }
 }
 
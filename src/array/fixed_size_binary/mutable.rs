@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray},
+    array::{check_extend_from_array, extend_validity, Array, MutableArray, TryExtend},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
+    trusted_len::TrustedLen,
 };
 
 use super::{FixedSizeBinaryArray, FixedSizeBinaryValues};
@@ -31,6 +32,14 @@ impl From<MutableFixedSizeBinaryArray> for FixedSizeBinaryArray {
     }
 }
 
+impl Default for MutableFixedSizeBinaryArray {
+    /// Creates an empty [`MutableFixedSizeBinaryArray`] of size 1, the smallest valid size.
+    /// Use [`MutableFixedSizeBinaryArray::new`] for other sizes.
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
 impl MutableFixedSizeBinaryArray {
     /// Canonical method to create a new [`MutableFixedSizeBinaryArray`].
     pub fn from_data(
@@ -94,11 +103,11 @@ impl MutableFixedSizeBinaryArray {
                 }
             }
             None => {
-                self.values.resize(self.values.len() + self.size, 0);
                 match &mut self.validity {
                     Some(validity) => validity.push(false),
                     None => self.init_validity(),
                 }
+                self.values.resize(self.values.len() + self.size, 0);
             }
         }
         Ok(())
@@ -128,16 +137,184 @@ impl MutableFixedSizeBinaryArray {
         Ok(primitive)
     }
 
+    /// Extends the [`MutableFixedSizeBinaryArray`] from an iterator of optional values,
+    /// checking the size of every value iff `check`.
+    /// # Safety
+    /// The iterator must be [`TrustedLen`], and, when not `check`, every value must have
+    /// the size of the array.
+    #[inline]
+    unsafe fn extend_trusted_len_impl<P, I>(&mut self, iterator: I, check: bool) -> Result<()>
+    where
+        P: AsRef<[u8]>,
+        I: Iterator<Item = Option<P>>,
+    {
+        let (_, upper) = iterator.size_hint();
+        let additional = upper.expect("extend_trusted_len requires an upper limit");
+        self.values.reserve(additional * self.size);
+
+        let len = self.len();
+        let has_validity = self.validity.is_some();
+        let mut validity = self.validity.take().unwrap_or_else(|| {
+            let mut validity = MutableBitmap::with_capacity(len + additional);
+            validity.extend_constant(len, true);
+            validity
+        });
+        validity.reserve(additional);
+
+        let size = self.size;
+        let mut result = Ok(());
+        for item in iterator {
+            match item {
+                Some(bytes) => {
+                    let bytes = bytes.as_ref();
+                    if check && bytes.len() != size {
+                        result = Err(ArrowError::InvalidArgumentError(
+                            "FixedSizeBinaryArray requires every item to be of its length"
+                                .to_string(),
+                        ));
+                        break;
+                    }
+                    self.values.extend_from_slice(bytes);
+                    validity.push_unchecked(true);
+                }
+                None => {
+                    self.values.resize(self.values.len() + size, 0);
+                    validity.push_unchecked(false);
+                }
+            }
+        }
+        if has_validity || validity.null_count() > 0 {
+            self.validity = Some(validity);
+        }
+        result
+    }
+
+    /// Extends the [`MutableFixedSizeBinaryArray`] from an iterator of trusted len.
+    /// # Errors
+    /// Errors iff the size of any of the values is not equal to its own size, in which case
+    /// the values before it are pushed.
+    #[inline]
+    pub fn extend_trusted_len<P, I>(&mut self, iterator: I) -> Result<()>
+    where
+        P: AsRef<[u8]>,
+        I: TrustedLen<Item = Option<P>>,
+    {
+        unsafe { self.extend_trusted_len_impl(iterator, true) }
+    }
+
+    /// Extends the [`MutableFixedSizeBinaryArray`] from an iterator of trusted len.
+    /// # Safety
+    /// The iterator must be [`TrustedLen`] and every value must have the size of the array.
+    /// Otherwise, the behavior is undefined.
+    #[inline]
+    pub unsafe fn extend_trusted_len_unchecked<P, I>(&mut self, iterator: I)
+    where
+        P: AsRef<[u8]>,
+        I: Iterator<Item = Option<P>>,
+    {
+        // infallible when not checking
+        let _ = self.extend_trusted_len_impl(iterator, false);
+    }
+
+    /// Creates a [`MutableFixedSizeBinaryArray`] of values of `size` bytes from
+    /// an iterator of trusted len.
+    /// # Errors
+    /// Errors iff the size of any of the values is not equal to `size`.
+    #[inline]
+    pub fn from_trusted_len_iter<P, I>(iterator: I, size: usize) -> Result<Self>
+    where
+        P: AsRef<[u8]>,
+        I: TrustedLen<Item = Option<P>>,
+    {
+        let mut array = Self::new(size);
+        array.extend_trusted_len(iterator)?;
+        Ok(array)
+    }
+
+    /// Creates a [`MutableFixedSizeBinaryArray`] of values of `size` bytes from
+    /// an iterator of trusted len.
+    /// # Safety
+    /// The iterator must be [`TrustedLen`] and every value must have `size` bytes.
+    /// Otherwise, the behavior is undefined.
+    #[inline]
+    pub unsafe fn from_trusted_len_iter_unchecked<P, I>(iterator: I, size: usize) -> Self
+    where
+        P: AsRef<[u8]>,
+        I: Iterator<Item = Option<P>>,
+    {
+        let mut array = Self::new(size);
+        array.extend_trusted_len_unchecked(iterator);
+        array
+    }
+
+    /// Creates a [`MutableFixedSizeBinaryArray`] of values of `size` bytes from
+    /// an iterator of (non-null) values of trusted len.
+    /// # Errors
+    /// Errors iff the size of any of the values is not equal to `size`.
+    #[inline]
+    pub fn from_trusted_len_values_iter<P, I>(iterator: I, size: usize) -> Result<Self>
+    where
+        P: AsRef<[u8]>,
+        I: TrustedLen<Item = P>,
+    {
+        let (_, upper) = iterator.size_hint();
+        let additional = upper.expect("from_trusted_len_values_iter requires an upper limit");
+        let mut values = Vec::with_capacity(additional * size);
+        for bytes in iterator {
+            let bytes = bytes.as_ref();
+            if bytes.len() != size {
+                return Err(ArrowError::InvalidArgumentError(
+                    "FixedSizeBinaryArray requires every item to be of its length".to_string(),
+                ));
+            }
+            values.extend_from_slice(bytes);
+        }
+        Ok(Self::from_data(
+            DataType::FixedSizeBinary(size),
+            values,
+            None,
+        ))
+    }
+
+    /// Pops the last entry from [`MutableFixedSizeBinaryArray`].
+    /// Returns `None` if it is empty.
+    pub fn pop(&mut self) -> Option<Option<Vec<u8>>> {
+        if self.values.len() < self.size {
+            return None;
+        }
+        let value = self.values.split_off(self.values.len() - self.size);
+        let is_valid = self
+            .validity
+            .as_mut()
+            .map(|validity| validity.pop().unwrap())
+            .unwrap_or(true);
+        Some(if is_valid { Some(value) } else { None })
+    }
+
+    /// Reserves `additional` entries.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional * self.size);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    /// Returns the number of entries that can be pushed without re-allocating.
+    pub fn capacity(&self) -> usize {
+        self.values.capacity() / self.size
+    }
+
     /// returns the (fixed) size of the [`MutableFixedSizeBinaryArray`].
     #[inline]
     pub fn size(&self) -> usize {
         self.size
     }
 
+    /// Initializes the validity with every existing entry valid, followed by a null.
     fn init_validity(&mut self) {
         let mut validity = MutableBitmap::new();
         validity.extend_constant(self.len(), true);
-        validity.set(self.len() - 1, false);
+        validity.push(false);
         self.validity = Some(validity)
     }
 
@@ -215,12 +392,53 @@ impl MutableArray for MutableFixedSizeBinaryArray {
     }
 
     fn push_null(&mut self) {
-        self.values.resize(self.values.len() + self.size, 0);
+        self.push::<&[u8]>(None)
     }
 
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+
+        extend_validity(
+            self.values.len() / self.size,
+            &mut self.validity,
+            array.validity(),
+            start,
+            len,
+        );
+        self.values
+            .extend_from_slice(&array.values()[start * self.size..(start + len) * self.size]);
+        Ok(())
+    }
+}
+
+impl<P: AsRef<[u8]>> Extend<Option<P>> for MutableFixedSizeBinaryArray {
+    /// # Panics
+    /// Panics iff the size of any of the values is not equal to its own size.
+    fn extend<I: IntoIterator<Item = Option<P>>>(&mut self, iter: I) {
+        self.try_extend(iter).unwrap();
+    }
+}
+
+impl<P: AsRef<[u8]>> TryExtend<Option<P>> for MutableFixedSizeBinaryArray {
+    /// # Errors
+    /// Errors iff the size of any of the values is not equal to its own size.
+    fn try_extend<I: IntoIterator<Item = Option<P>>>(&mut self, iter: I) -> Result<()> {
+        let mut iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.try_for_each(|x| self.try_push(x))
+    }
 }
 
 impl FixedSizeBinaryValues for MutableFixedSizeBinaryArray {
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    bitmap::Bitmap,
+    bitmap::{Bitmap, MutableBitmap},
     datatypes::{DataType, Field},
     error::ArrowError,
 };
@@ -153,17 +153,55 @@ impl FixedSizeListArray {
         }
     }
 
-    /// Sets the validity bitmap on this [`FixedSizeListArray`].
+    /// Returns a new [`FixedSizeListArray`] with a different logical type, e.g. an extension type over it.
+    /// This is `O(1)`.
+    /// # Panics
+    /// Panics iff the logical type of `data_type` differs from the logical type of `self`.
+    #[inline]
+    pub fn to(self, data_type: DataType) -> Self {
+        assert_eq!(
+            data_type.to_logical_type(),
+            self.data_type.to_logical_type(),
+            "FixedSizeListArray cannot change its logical type"
+        );
+        Self { data_type, ..self }
+    }
+
+    /// Returns this [`FixedSizeListArray`] with a new validity bitmap.
+    /// Only the top-level validity is replaced; the children are untouched.
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
     #[must_use]
-    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity bitmap of this [`FixedSizeListArray`].
+    /// Only the top-level validity is replaced; the children are untouched.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
+    }
+
+    /// Replaces the validity bitmap of this [`FixedSizeListArray`] by the result of `f` over it,
+    /// e.g. to combine it with another mask. When it has no validity, `f` is applied over a bitmap whose slots are all valid.
+    /// # Panic
+    /// This function panics iff `f` returns a bitmap whose length is not `self.len()`.
+    pub fn apply_validity<F: Fn(&Bitmap) -> Bitmap>(&mut self, f: F) {
+        let validity = match self.validity.as_ref() {
+            Some(validity) => f(validity),
+            None => {
+                let mut validity = MutableBitmap::with_capacity(self.len());
+                validity.extend_constant(self.len(), true);
+                f(&validity.into())
+            }
+        };
+        self.set_validity(Some(validity));
     }
 }
 
@@ -253,6 +291,6 @@ impl Array for FixedSizeListArray {
         Box::new(self.slice_unchecked(offset, length))
     }
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
-        Box::new(self.with_validity(validity))
+        Box::new(self.clone().with_validity(validity))
     }
 }
@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{
+        check_extend_from_array, extend_validity, try_truncate, Array, MutableArray, TryExtend,
+        TryPush,
+    },
     bitmap::MutableBitmap,
-    datatypes::DataType,
+    datatypes::{DataType, Field},
     error::{ArrowError, Result},
 };
 
@@ -16,6 +19,7 @@ pub struct MutableFixedSizeListArray<M: MutableArray> {
     size: usize,
     values: M,
     validity: Option<MutableBitmap>,
+    length: usize,
 }
 
 impl<M: MutableArray> From<MutableFixedSizeListArray<M>> for FixedSizeListArray {
@@ -32,12 +36,29 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
     /// Creates a new [`MutableFixedSizeListArray`] from a [`MutableArray`] and size.
     pub fn new(values: M, size: usize) -> Self {
         let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), size);
+        Self::new_from(values, data_type)
+    }
+
+    /// Creates a new [`MutableFixedSizeListArray`] from a [`MutableArray`], the name
+    /// and nullability of its inner field, and size.
+    pub fn new_with_field(values: M, name: &str, nullable: bool, size: usize) -> Self {
+        let field = Box::new(Field::new(name, values.data_type().clone(), nullable));
+        Self::new_from(values, DataType::FixedSizeList(field, size))
+    }
+
+    /// Creates a new [`MutableFixedSizeListArray`] from a [`MutableArray`] and a [`DataType`].
+    /// # Panics
+    /// This function panics iff `data_type`'s logical type is not
+    /// [`DataType::FixedSizeList`] or `values` is not empty.
+    pub fn new_from(values: M, data_type: DataType) -> Self {
+        let (_, size) = FixedSizeListArray::get_child_and_size(&data_type);
         assert_eq!(values.len(), 0);
         Self {
             size,
             data_type,
             values,
             validity: None,
+            length: 0,
         }
     }
 
@@ -46,33 +67,67 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
         &self.values
     }
 
-    fn init_validity(&mut self) {
-        let len = self.values.len() / self.size;
+    /// The inner values
+    pub fn mut_values(&mut self) -> &mut M {
+        &mut self.values
+    }
+
+    /// The number of values of every list
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    // removes the values pushed since the last list
+    fn truncate_values(&mut self) {
+        let len = self.length * self.size;
+        if self.values.len() > len {
+            // the error that required this truncation takes precedence
+            let _ = try_truncate(&mut self.values, len);
+        }
+    }
 
-        let mut validity = MutableBitmap::new();
-        validity.extend_constant(len, true);
-        validity.set(len - 1, false);
+    fn init_validity(&mut self) {
+        let mut validity = MutableBitmap::with_capacity(self.length + 1);
+        validity.extend_constant(self.length, true);
+        validity.push(false);
         self.validity = Some(validity)
     }
 
+    /// Needs to be called when `size` valid values were pushed to the values of this array.
+    /// This is a relatively low level function, prefer `try_push` when you can.
+    /// # Errors
+    /// Errors iff exactly `size` values were not pushed since the last list was pushed.
+    /// On error, the values pushed since the last list are removed (provided that they
+    /// support [`MutableArray::try_extend_from_array`]).
     #[inline]
-    fn try_push_valid(&mut self) -> Result<()> {
-        if self.values.len() % self.size != 0 {
-            return Err(ArrowError::Overflow);
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        let expected = (self.length + 1) * self.size;
+        if self.values.len() != expected {
+            let error = ArrowError::InvalidArgumentError(format!(
+                "A MutableFixedSizeListArray of size {} expects its values to have length {} but they have length {}",
+                self.size,
+                expected,
+                self.values.len()
+            ));
+            self.truncate_values();
+            return Err(error);
         };
         if let Some(validity) = &mut self.validity {
             validity.push(true)
         }
+        self.length += 1;
         Ok(())
     }
 
+    /// Pushes a null list, pushing `size` nulls to its values.
     #[inline]
-    fn push_null(&mut self) {
+    pub fn push_null(&mut self) {
         (0..self.size).for_each(|_| self.values.push_null());
         match &mut self.validity {
             Some(validity) => validity.push(false),
             None => self.init_validity(),
         }
+        self.length += 1;
     }
     /// Shrinks the capacity of the [`MutableFixedSizeListArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
@@ -85,7 +140,7 @@ impl<M: MutableArray> MutableFixedSizeListArray<M> {
 
 impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
     fn len(&self) -> usize {
-        self.values.len() / self.size
+        self.length
     }
 
     fn validity(&self) -> Option<&MutableBitmap> {
@@ -93,6 +148,7 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
     }
 
     fn as_box(&mut self) -> Box<dyn Array> {
+        self.length = 0;
         Box::new(FixedSizeListArray::from_data(
             self.data_type.clone(),
             self.values.as_arc(),
@@ -101,6 +157,7 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
     }
 
     fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.length = 0;
         Arc::new(FixedSizeListArray::from_data(
             self.data_type.clone(),
             self.values.as_arc(),
@@ -122,19 +179,39 @@ impl<M: MutableArray + 'static> MutableArray for MutableFixedSizeListArray<M> {
 
     #[inline]
     fn push_null(&mut self) {
-        (0..self.size).for_each(|_| {
-            self.values.push_null();
-        });
-        if let Some(validity) = &mut self.validity {
-            validity.push(false)
-        } else {
-            self.init_validity()
-        }
+        self.push_null()
     }
 
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional * self.size);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+
+        self.values.try_extend_from_array(
+            array.values().as_ref(),
+            start * self.size,
+            len * self.size,
+        )?;
+        extend_validity(
+            self.length,
+            &mut self.validity,
+            array.validity(),
+            start,
+            len,
+        );
+        self.length += len;
+        Ok(())
+    }
 }
 
 impl<M, I, T> TryExtend<Option<I>> for MutableFixedSizeListArray<M>
@@ -156,10 +233,18 @@ where
     M: MutableArray + TryExtend<Option<T>>,
     I: IntoIterator<Item = Option<T>>,
 {
+    /// Pushes a new list.
+    /// # Errors
+    /// Errors iff `item` does not have exactly `size` values, or the inner values error.
+    /// On error, the list is not pushed and its values are removed from the inner values
+    /// (provided that they support [`MutableArray::try_extend_from_array`]).
     #[inline]
     fn try_push(&mut self, item: Option<I>) -> Result<()> {
         if let Some(items) = item {
-            self.values.try_extend(items)?;
+            if let Err(error) = self.values.try_extend(items) {
+                self.truncate_values();
+                return Err(error);
+            }
             self.try_push_valid()?;
         } else {
             self.push_null();
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::{
     array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
     bitmap::MutableBitmap,
+    datatypes::DataType,
 };
 
 use super::{
@@ -14,8 +15,10 @@ use super::{
 /// Concrete [`Growable`] for the [`DictionaryArray`].
 /// # Implementation
 /// This growable does not perform collision checks and instead concatenates
-/// the values of each [`DictionaryArray`] one after the other.
+/// the values of each [`DictionaryArray`] one after the other, remapping the keys of
+/// each array to its values. When all arrays share the same values, these are re-used.
 pub struct GrowableDictionary<'a, K: DictionaryKey> {
+    data_type: DataType,
     keys_values: Vec<&'a [K]>,
     key_values: Vec<K>,
     key_validity: MutableBitmap,
@@ -66,9 +69,18 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
             .map(|array| array.values().as_ref())
             .collect::<Vec<_>>();
 
-        let (values, offsets) = concatenate_values(&arrays_keys, &arrays_values, capacity);
+        let first = arrays[0].values();
+        let (values, offsets) = if arrays
+            .iter()
+            .all(|array| Arc::ptr_eq(array.values(), first))
+        {
+            (first.clone(), vec![0; arrays.len()])
+        } else {
+            concatenate_values(&arrays_keys, &arrays_values, capacity)
+        };
 
         Self {
+            data_type: arrays[0].data_type().clone(),
             offsets,
             values,
             keys_values,
@@ -86,7 +98,9 @@ impl<'a, T: DictionaryKey> GrowableDictionary<'a, T> {
         let data_type = T::PRIMITIVE.into();
         let keys = PrimitiveArray::<T>::from_data(data_type, values.into(), validity.into());
 
-        DictionaryArray::<T>::from_data(keys, self.values.clone())
+        // Safety: the keys of each array were offsetted by the position of its values
+        unsafe { DictionaryArray::<T>::new_unchecked(keys, self.values.clone()) }
+            .to(self.data_type.clone())
     }
 }
 
@@ -133,6 +147,7 @@ impl<'a, T: DictionaryKey> From<GrowableDictionary<'a, T>> for DictionaryArray<T
             val.key_validity.into(),
         );
 
-        DictionaryArray::<T>::from_data(keys, val.values)
+        // Safety: the keys of each array were offsetted by the position of its values
+        unsafe { DictionaryArray::<T>::new_unchecked(keys, val.values) }.to(val.data_type)
     }
 }
@@ -24,6 +24,10 @@ mod utf8;
 pub use utf8::GrowableUtf8;
 mod dictionary;
 pub use dictionary::GrowableDictionary;
+mod map;
+pub use map::GrowableMap;
+mod union;
+pub use union::GrowableUnion;
 
 mod utils;
 
@@ -110,7 +114,8 @@ pub fn make_growable<'a>(
             use_validity,
             capacity
         ),
-        Union | Map => todo!(),
+        Union => dyn_growable!(union::GrowableUnion, arrays, use_validity, capacity),
+        Map => dyn_growable!(map::GrowableMap, arrays, use_validity, capacity),
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let arrays = arrays
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, UnionArray},
+    datatypes::DataType,
+};
+
+use super::{make_growable, Growable};
+
+/// Concrete [`Growable`] for the [`UnionArray`].
+/// # Implementation
+/// A [`UnionArray`] has no validity: null slots are represented as nulls of its first field.
+pub struct GrowableUnion<'a> {
+    arrays: Vec<&'a UnionArray>,
+    types: Vec<i8>,
+    // `None` when sparse
+    offsets: Option<Vec<i32>>,
+    fields: Vec<Box<dyn Growable<'a> + 'a>>,
+    // the length of each of the fields (only used when dense)
+    fields_lengths: Vec<usize>,
+    // the type id of the first field
+    null_type: i8,
+}
+
+impl<'a> GrowableUnion<'a> {
+    /// Creates a new [`GrowableUnion`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a UnionArray>, use_validity: bool, capacity: usize) -> Self {
+        let data_type = arrays[0].data_type();
+
+        let (fields, ids) = match data_type.to_logical_type() {
+            DataType::Union(fields, ids, _) => (fields, ids),
+            _ => unreachable!(),
+        };
+        let null_type = ids.as_ref().map(|ids| ids[0] as i8).unwrap_or(0);
+        let is_sparse = UnionArray::is_sparse(data_type);
+
+        let fields = (0..fields.len())
+            .map(|i| {
+                let inner = arrays
+                    .iter()
+                    .map(|x| x.fields()[i].as_ref())
+                    .collect::<Vec<_>>();
+                make_growable(&inner, use_validity, if is_sparse { capacity } else { 0 })
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            types: Vec::with_capacity(capacity),
+            offsets: if is_sparse {
+                None
+            } else {
+                Some(Vec::with_capacity(capacity))
+            },
+            fields_lengths: vec![0; fields.len()],
+            fields,
+            arrays,
+            null_type,
+        }
+    }
+
+    fn to(&mut self) -> UnionArray {
+        let types = std::mem::take(&mut self.types);
+        let offsets = self.offsets.as_mut().map(std::mem::take);
+        let fields = self.fields.iter_mut().map(|x| x.as_arc()).collect();
+        self.fields_lengths.iter_mut().for_each(|x| *x = 0);
+
+        UnionArray::from_data(
+            self.arrays[0].data_type().clone(),
+            types.into(),
+            fields,
+            offsets.map(|x| x.into()),
+        )
+    }
+}
+
+impl<'a> Growable<'a> for GrowableUnion<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        let array = self.arrays[index];
+        self.types
+            .extend_from_slice(&array.types()[start..start + len]);
+
+        if let Some(offsets) = self.offsets.as_mut() {
+            (start..start + len).for_each(|i| {
+                let (field, slot) = array.index(i);
+                offsets.push(self.fields_lengths[field] as i32);
+                self.fields_lengths[field] += 1;
+                self.fields[field].extend(index, slot, 1);
+            })
+        } else {
+            let start = array.slice_offset() + start;
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend(index, start, len));
+        }
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.types
+            .resize(self.types.len() + additional, self.null_type);
+
+        if let Some(offsets) = self.offsets.as_mut() {
+            let length = self.fields_lengths[0];
+            offsets.extend((length..length + additional).map(|x| x as i32));
+            self.fields_lengths[0] += additional;
+            self.fields[0].extend_validity(additional);
+        } else {
+            self.fields
+                .iter_mut()
+                .for_each(|field| field.extend_validity(additional));
+        }
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a> From<GrowableUnion<'a>> for UnionArray {
+    fn from(mut val: GrowableUnion<'a>) -> Self {
+        val.to()
+    }
+}
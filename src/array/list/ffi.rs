@@ -58,6 +58,6 @@ impl<O: Offset, A: ffi::ArrowArrayRef> FromFfi<A> for ListArray<O> {
         let child = unsafe { array.child(0)? };
         let values = ffi::try_from(child)?.into();
 
-        Ok(Self::from_data(data_type, offsets, values, validity))
+        Self::try_new(data_type, offsets, values, validity)
     }
 }
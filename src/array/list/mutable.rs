@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray, Offset, TryExtend, TryPush},
+    array::{
+        check_extend_from_array, extend_validity, try_extend_offsets, Array, MutableArray, Offset,
+        TryExtend, TryPush,
+    },
     bitmap::MutableBitmap,
     datatypes::{DataType, Field},
     error::{ArrowError, Result},
@@ -211,7 +214,7 @@ impl<O: Offset, M: MutableArray + Default + 'static> MutableArray for MutableLis
     fn as_box(&mut self) -> Box<dyn Array> {
         Box::new(ListArray::from_data(
             self.data_type.clone(),
-            std::mem::take(&mut self.offsets).into(),
+            std::mem::replace(&mut self.offsets, vec![O::default()]).into(),
             self.values.as_arc(),
             std::mem::take(&mut self.validity).map(|x| x.into()),
         ))
@@ -220,7 +223,7 @@ impl<O: Offset, M: MutableArray + Default + 'static> MutableArray for MutableLis
     fn as_arc(&mut self) -> Arc<dyn Array> {
         Arc::new(ListArray::from_data(
             self.data_type.clone(),
-            std::mem::take(&mut self.offsets).into(),
+            std::mem::replace(&mut self.offsets, vec![O::default()]).into(),
             self.values.as_arc(),
             std::mem::take(&mut self.validity).map(|x| x.into()),
         ))
@@ -245,4 +248,28 @@ impl<O: Offset, M: MutableArray + Default + 'static> MutableArray for MutableLis
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit();
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+
+        let offsets = &array.offsets()[start..start + len + 1];
+        let first = offsets[0].to_usize();
+        self.values.try_extend_from_array(
+            array.values().as_ref(),
+            first,
+            offsets[len].to_usize() - first,
+        )?;
+        let length = self.offsets.len() - 1;
+        try_extend_offsets(&mut self.offsets, offsets)?;
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+        Ok(())
+    }
 }
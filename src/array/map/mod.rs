@@ -1,20 +1,23 @@
 use std::sync::Arc;
 
 use crate::{
-    bitmap::Bitmap,
+    bitmap::{Bitmap, MutableBitmap},
     buffer::Buffer,
     datatypes::{DataType, Field},
     error::ArrowError,
 };
 
-use super::{new_empty_array, specification::try_check_offsets, Array};
+use super::{new_empty_array, specification::try_check_offsets, Array, StructArray};
 
 mod ffi;
+pub(super) mod fmt;
 mod iterator;
 pub use iterator::*;
+mod mutable;
+pub use mutable::*;
 
 /// An array representing a (key, value), both of arbitrary logical types.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct MapArray {
     data_type: DataType,
     // invariant: field.len() == offsets.len() - 1
@@ -31,6 +34,7 @@ impl MapArray {
     /// * the offsets are not monotonically increasing
     /// * The last offset is not equal to the field' length
     /// * The `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Map`]
+    /// * The inner field of `data_type` is not a `Struct` of a non-nullable key and a value
     /// * The fields' `data_type` is not equal to the inner field of `data_type`
     /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
     pub fn try_new(
@@ -48,6 +52,11 @@ impl MapArray {
                     "MapArray's inner `Struct` must have 2 fields (keys and maps)".to_string(),
                 ));
             }
+            if inner[0].is_nullable {
+                return Err(ArrowError::InvalidArgumentError(
+                    "MapArray's keys field must not be nullable".to_string(),
+                ));
+            }
         } else {
             return Err(ArrowError::InvalidArgumentError(
                 "MapArray expects `DataType::Struct` as its inner logical type".to_string(),
@@ -81,6 +90,7 @@ impl MapArray {
     /// * the offsets are not monotonically increasing
     /// * The last offset is not equal to the field' length.
     /// * The `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Map`],
+    /// * The inner field of `data_type` is not a `Struct` of a non-nullable key and a value
     /// * The validity is not `None` and its length is different from `offsets.len() - 1`.
     pub fn new(
         data_type: DataType,
@@ -148,6 +158,57 @@ impl MapArray {
         }
     }
 
+    /// Returns a new [`MapArray`] with a different logical type, e.g. an extension type over it.
+    /// This is `O(1)`.
+    /// # Panics
+    /// Panics iff the logical type of `data_type` differs from the logical type of `self`.
+    #[inline]
+    pub fn to(self, data_type: DataType) -> Self {
+        assert_eq!(
+            data_type.to_logical_type(),
+            self.data_type.to_logical_type(),
+            "MapArray cannot change its logical type"
+        );
+        Self { data_type, ..self }
+    }
+
+    /// Returns this [`MapArray`] with a new validity bitmap.
+    /// Only the top-level validity is replaced; the entries are untouched.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    #[must_use]
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity bitmap of this [`MapArray`].
+    /// Only the top-level validity is replaced; the entries are untouched.
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
+        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
+            panic!("validity should be as least as large as the array")
+        }
+        self.validity = validity;
+    }
+
+    /// Replaces the validity bitmap of this [`MapArray`] by the result of `f` over it,
+    /// e.g. to combine it with another mask. When it has no validity, `f` is applied over a bitmap whose slots are all valid.
+    /// # Panic
+    /// This function panics iff `f` returns a bitmap whose length is not `self.len()`.
+    pub fn apply_validity<F: Fn(&Bitmap) -> Bitmap>(&mut self, f: F) {
+        let validity = match self.validity.as_ref() {
+            Some(validity) => f(validity),
+            None => {
+                let mut validity = MutableBitmap::with_capacity(self.len());
+                validity.extend_constant(self.len(), true);
+                f(&validity.into())
+            }
+        };
+        self.set_validity(Some(validity));
+    }
+
     pub(crate) fn try_get_field(data_type: &DataType) -> Result<&Field, ArrowError> {
         if let DataType::Map(field, _) = data_type.to_logical_type() {
             Ok(field.as_ref())
@@ -183,7 +244,26 @@ impl MapArray {
         &self.field
     }
 
-    /// Returns the element at index `i`.
+    #[inline]
+    fn entries(&self) -> &StructArray {
+        // `try_new` guarantees that the field is a `StructArray`
+        self.field.as_any().downcast_ref().unwrap()
+    }
+
+    /// Returns the keys of all entries of this [`MapArray`], i.e. the first child of [`MapArray::field`].
+    #[inline]
+    pub fn keys(&self) -> &Arc<dyn Array> {
+        &self.entries().values()[0]
+    }
+
+    /// Returns the values of all entries of this [`MapArray`], i.e. the second child of [`MapArray::field`].
+    #[inline]
+    pub fn values(&self) -> &Arc<dyn Array> {
+        &self.entries().values()[1]
+    }
+
+    /// Returns the entries at index `i`, a slice of [`MapArray::field`] and
+    /// therefore a [`StructArray`] of (key, value).
     #[inline]
     pub fn value(&self, i: usize) -> Box<dyn Array> {
         let offset = self.offsets[i];
@@ -196,7 +276,7 @@ impl MapArray {
         unsafe { self.field.slice_unchecked(offset as usize, length) }
     }
 
-    /// Returns the element at index `i`.
+    /// Returns the entries at index `i`.
     /// # Safety
     /// Assumes that the `i < self.len`.
     #[inline]
@@ -238,7 +318,7 @@ impl Array for MapArray {
         Box::new(self.slice_unchecked(offset, length))
     }
 
-    fn with_validity(&self, _validity: Option<Bitmap>) -> Box<dyn Array> {
-        Box::new(self.clone())
+    fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
+        Box::new(self.clone().with_validity(validity))
     }
 }
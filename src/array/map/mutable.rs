@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        check_extend_from_array, extend_validity, try_extend_offsets, try_truncate, Array,
+        MutableArray, MutableStructArray,
+    },
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::MapArray;
+
+/// The mutable version of [`MapArray`].
+///
+/// Entries are built by pushing a key and a value (see [`MutableMapArray::keys`] and
+/// [`MutableMapArray::values`]) and committing them with [`MutableMapArray::try_push_entry`].
+/// Maps are committed with [`MutableMapArray::try_push_valid`].
+/// # Example
+/// ```
+/// use arrow2::array::*;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let data_type = DataType::Map(
+///     Box::new(Field::new(
+///         "entries",
+///         DataType::Struct(vec![
+///             Field::new("key", DataType::Utf8, false),
+///             Field::new("value", DataType::Int32, true),
+///         ]),
+///         false,
+///     )),
+///     false,
+/// );
+/// let mut array = MutableMapArray::new(
+///     data_type,
+///     Box::new(MutableUtf8Array::<i32>::new()),
+///     Box::new(MutablePrimitiveArray::<i32>::new()),
+/// );
+///
+/// // {"a": 1, "b": null}
+/// array.keys::<MutableUtf8Array<i32>>().push(Some("a"));
+/// array.values::<MutablePrimitiveArray<i32>>().push(Some(1));
+/// array.try_push_entry().unwrap();
+/// array.keys::<MutableUtf8Array<i32>>().push(Some("b"));
+/// array.values::<MutablePrimitiveArray<i32>>().push(None);
+/// array.try_push_entry().unwrap();
+/// array.try_push_valid().unwrap();
+/// // null
+/// array.push_null();
+///
+/// let array: MapArray = array.into();
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(array.offsets().as_slice(), &[0, 2, 2]);
+/// ```
+#[derive(Debug)]
+pub struct MutableMapArray {
+    data_type: DataType,
+    offsets: Vec<i32>,
+    field: MutableStructArray,
+    validity: Option<MutableBitmap>,
+}
+
+impl From<MutableMapArray> for MapArray {
+    fn from(mut other: MutableMapArray) -> Self {
+        MapArray::from_data(
+            other.data_type,
+            other.offsets.into(),
+            other.field.as_arc(),
+            other.validity.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableMapArray {
+    /// Creates a new [`MutableMapArray`] from a [`DataType`] and its (empty) keys and values.
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s logical type is not [`DataType::Map`]
+    /// * the inner field of `data_type` is not a [`DataType::Struct`] with 2 fields
+    /// * the data types of `keys` and `values` are different from the ones of the inner fields
+    /// * `keys` or `values` are not empty
+    pub fn try_new(
+        data_type: DataType,
+        keys: Box<dyn MutableArray>,
+        values: Box<dyn MutableArray>,
+    ) -> Result<Self> {
+        let inner = MapArray::try_get_field(&data_type)?.data_type().clone();
+        if !matches!(&inner, DataType::Struct(fields) if fields.len() == 2) {
+            return Err(ArrowError::InvalidArgumentError(
+                "MutableMapArray's inner `Struct` must have 2 fields (keys and maps)".to_string(),
+            ));
+        }
+        let field = MutableStructArray::try_new(inner, vec![keys, values])?;
+        Ok(Self {
+            data_type,
+            offsets: vec![0],
+            field,
+            validity: None,
+        })
+    }
+
+    /// Creates a new [`MutableMapArray`] from a [`DataType`] and its (empty) keys and values.
+    /// # Panics
+    /// This function panics iff [`MutableMapArray::try_new`] errors.
+    pub fn new(
+        data_type: DataType,
+        keys: Box<dyn MutableArray>,
+        values: Box<dyn MutableArray>,
+    ) -> Self {
+        Self::try_new(data_type, keys, values).unwrap()
+    }
+
+    /// The offsets
+    pub fn offsets(&self) -> &[i32] {
+        &self.offsets
+    }
+
+    /// The entries, as a [`MutableStructArray`] of keys and values.
+    pub fn field(&self) -> &MutableStructArray {
+        &self.field
+    }
+
+    /// Returns a mutable reference to the keys, downcasted to `A`.
+    /// # Panics
+    /// This function panics iff the keys are not an `A`.
+    pub fn keys<A: MutableArray + 'static>(&mut self) -> &mut A {
+        self.field.value::<A>(0)
+    }
+
+    /// Returns a mutable reference to the values, downcasted to `A`.
+    /// # Panics
+    /// This function panics iff the values are not an `A`.
+    pub fn values<A: MutableArray + 'static>(&mut self) -> &mut A {
+        self.field.value::<A>(1)
+    }
+
+    /// Commits a new entry of the current map, whose key and value were pushed
+    /// to the keys and values.
+    /// # Errors
+    /// This function errors iff the key is null or exactly one key and one value
+    /// were not pushed since the last entry. On error, the entry is not committed and
+    /// the keys and values pushed since the last entry are removed (provided that they
+    /// support [`MutableArray::try_extend_from_array`]).
+    pub fn try_push_entry(&mut self) -> Result<()> {
+        let index = self.field.len();
+        let keys = &self.field.values()[0];
+        let result = if keys.len() == index + 1 && !keys.is_valid(index) {
+            Err(ArrowError::InvalidArgumentError(
+                "The keys of a MutableMapArray cannot be null".to_string(),
+            ))
+        } else {
+            self.field.try_push(true)
+        };
+        if result.is_err() {
+            for child in self.field.mut_values() {
+                if child.len() > index {
+                    // the error of the push takes precedence
+                    let _ = try_truncate(child.as_mut(), index);
+                }
+            }
+        }
+        result
+    }
+
+    /// Commits a new map, made of all entries committed since the last map.
+    /// # Errors
+    /// This function errors iff the total number of entries exceeds `i32::MAX`.
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        let offset = i32::try_from(self.field.len()).map_err(|_| ArrowError::Overflow)?;
+        self.offsets.push(offset);
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    /// Pushes a null map. Entries committed since the last map are part of the next map.
+    pub fn push_null(&mut self) {
+        self.offsets.push(*self.offsets.last().unwrap());
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len() - 1;
+
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// Shrinks the capacity of the [`MutableMapArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.field.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+}
+
+impl MutableArray for MutableMapArray {
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(MapArray::from_data(
+            self.data_type.clone(),
+            std::mem::replace(&mut self.offsets, vec![0]).into(),
+            self.field.as_arc(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(MapArray::from_data(
+            self.data_type.clone(),
+            std::mem::replace(&mut self.offsets, vec![0]).into(),
+            self.field.as_arc(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+
+        let offsets = &array.offsets()[start..start + len + 1];
+        let first = offsets[0] as usize;
+        self.field.try_extend_from_array(
+            array.field().as_ref(),
+            first,
+            offsets[len] as usize - first,
+        )?;
+        let length = self.offsets.len() - 1;
+        try_extend_offsets(&mut self.offsets, offsets)?;
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+        Ok(())
+    }
+}
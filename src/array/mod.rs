@@ -18,7 +18,7 @@
 //! can be operated in-place.
 use std::any::Any;
 
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{
     bitmap::{Bitmap, MutableBitmap},
     datatypes::DataType,
@@ -157,6 +157,108 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
 
     /// Shrink the array to fit its length.
     fn shrink_to_fit(&mut self);
+
+    /// Reserves `additional` slots.
+    fn reserve(&mut self, additional: usize);
+
+    /// Extends the array with the slots `start..start + len` of `array`.
+    ///
+    /// This allows accumulating arrays of the same [`DataType`] into a single mutable array
+    /// without concatenating them. Arrays that support this operation override this method,
+    /// which otherwise errors.
+    /// # Error
+    /// Errors iff
+    /// * the [`DataType`] of `array` differs from the one of this array
+    /// * `start + len > array.len()`
+    /// * this array does not support this operation
+    ///
+    /// Nested arrays extend their children in order and may thus be left partially extended
+    /// when a child errors.
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let _ = (array, start, len);
+        Err(ArrowError::NotYetImplemented(format!(
+            "Extending a mutable array of type {:?} from an array",
+            self.data_type()
+        )))
+    }
+}
+
+/// Checks the arguments of [`MutableArray::try_extend_from_array`] of a mutable array of
+/// `data_type`.
+pub(crate) fn check_extend_from_array(
+    data_type: &DataType,
+    array: &dyn Array,
+    start: usize,
+    len: usize,
+) -> Result<()> {
+    if array.data_type() != data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "A mutable array of type {:?} cannot be extended from an array of type {:?}",
+            data_type,
+            array.data_type()
+        )));
+    }
+    if start + len > array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The range {}..{} is out of bounds of an array of length {}",
+            start,
+            start + len,
+            array.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Extends the validity of a mutable array of length `length` with the slots
+/// `start..start + len` of `other`, initializing it when `other` contains nulls.
+pub(crate) fn extend_validity(
+    length: usize,
+    validity: &mut Option<MutableBitmap>,
+    other: Option<&Bitmap>,
+    start: usize,
+    len: usize,
+) {
+    match (validity.as_mut(), other) {
+        (Some(validity), Some(other)) => validity.extend_from_bitmap(other, start, len),
+        (Some(validity), None) => validity.extend_constant(len, true),
+        (None, Some(other)) if other.null_count_range(start, len) > 0 => {
+            let mut new = MutableBitmap::with_capacity(length + len);
+            new.extend_constant(length, true);
+            new.extend_from_bitmap(other, start, len);
+            *validity = Some(new);
+        }
+        (None, _) => {}
+    }
+}
+
+/// Truncates `array` to its first `len` slots, by freezing it and extending it back from the
+/// frozen array.
+/// # Error
+/// Errors iff `array` does not support [`MutableArray::try_extend_from_array`], in which case
+/// it is left unchanged.
+pub(crate) fn try_truncate(array: &mut dyn MutableArray, len: usize) -> Result<()> {
+    // errors before any data is moved out when the operation is not supported
+    array.try_extend_from_array(new_empty_array(array.data_type().clone()).as_ref(), 0, 0)?;
+    let frozen = array.as_box();
+    array.try_extend_from_array(frozen.as_ref(), 0, len)
+}
+
+/// Extends `offsets` with `other`, a window of the offsets of another array, rebased to the
+/// last offset of `offsets`.
+/// # Error
+/// Errors iff the resulting offsets overflow `O`.
+pub(crate) fn try_extend_offsets<O: Offset>(offsets: &mut Vec<O>, other: &[O]) -> Result<()> {
+    let last = offsets.last().unwrap().to_usize();
+    let first = other[0].to_usize();
+    O::from_usize(last + other[other.len() - 1].to_usize() - first).ok_or(ArrowError::Overflow)?;
+
+    // all offsets are smaller than the last one and thus do not overflow either
+    offsets.extend(
+        other[1..]
+            .iter()
+            .map(|x| O::from_usize(last + x.to_usize() - first).unwrap()),
+    );
+    Ok(())
 }
 
 macro_rules! general_dyn {
@@ -195,19 +297,21 @@ macro_rules! with_match_primitive_type {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns};
+    use crate::types::{days_ms, f16, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -237,7 +341,7 @@ impl std::fmt::Debug for dyn Array + '_ {
                     fmt_dyn!(self, DictionaryArray::<$T>, f)
                 })
             }
-            Map => todo!(),
+            Map => fmt_dyn!(self, MapArray, f),
         }
     }
 }
@@ -338,6 +442,47 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
     }
 }
 
+macro_rules! to_dyn {
+    ($array:expr, $ty:ty, $data_type:expr) => {{
+        let f = |x: &$ty| Box::new(x.clone().to($data_type));
+        general_dyn!($array, $ty, f)
+    }};
+}
+
+/// Returns a new [`Array`] with the values of `array` and `data_type` as its data type,
+/// e.g. to wrap or unwrap an extension type.
+/// # Implementation
+/// This operation is `O(1)` over `len`.
+/// # Panics
+/// Panics iff `data_type` is not supported by `array`'s physical representation (see the
+/// `to` method of each array).
+pub fn to_data_type(array: &dyn Array, data_type: DataType) -> Box<dyn Array> {
+    use crate::datatypes::PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Null => to_dyn!(array, NullArray, data_type),
+        Boolean => to_dyn!(array, BooleanArray, data_type),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            to_dyn!(array, PrimitiveArray<$T>, data_type)
+        }),
+        Binary => to_dyn!(array, BinaryArray<i32>, data_type),
+        LargeBinary => to_dyn!(array, BinaryArray<i64>, data_type),
+        FixedSizeBinary => to_dyn!(array, FixedSizeBinaryArray, data_type),
+        Utf8 => to_dyn!(array, Utf8Array::<i32>, data_type),
+        LargeUtf8 => to_dyn!(array, Utf8Array::<i64>, data_type),
+        List => to_dyn!(array, ListArray::<i32>, data_type),
+        LargeList => to_dyn!(array, ListArray::<i64>, data_type),
+        FixedSizeList => to_dyn!(array, FixedSizeListArray, data_type),
+        Struct => to_dyn!(array, StructArray, data_type),
+        Union => to_dyn!(array, UnionArray, data_type),
+        Map => to_dyn!(array, MapArray, data_type),
+        Dictionary(key_type) => {
+            match_integer_type!(key_type, |$T| {
+                to_dyn!(array, DictionaryArray::<$T>, data_type)
+            })
+        }
+    }
+}
+
 // see https://users.rust-lang.org/t/generic-for-dyn-a-or-box-dyn-a-or-arc-dyn-a/69430/3
 // for details
 impl<'a> AsRef<(dyn Array + 'a)> for dyn Array {
@@ -376,10 +521,10 @@ pub use dictionary::{DictionaryArray, DictionaryKey, MutableDictionaryArray};
 pub use fixed_size_binary::{FixedSizeBinaryArray, MutableFixedSizeBinaryArray};
 pub use fixed_size_list::{FixedSizeListArray, MutableFixedSizeListArray};
 pub use list::{ListArray, MutableListArray};
-pub use map::MapArray;
+pub use map::{MapArray, MutableMapArray};
 pub use null::NullArray;
 pub use primitive::*;
-pub use struct_::StructArray;
+pub use struct_::{MutableStructArray, StructArray};
 pub use union::UnionArray;
 pub use utf8::{MutableUtf8Array, Utf8Array, Utf8ValuesIter};
 
@@ -54,6 +54,20 @@ impl NullArray {
 }
 
 impl NullArray {
+    /// Returns a new [`NullArray`] with a different logical type, e.g. an extension type over it.
+    /// This is `O(1)`.
+    /// # Panics
+    /// Panics iff the logical type of `data_type` differs from the logical type of `self`.
+    #[inline]
+    pub fn to(self, data_type: DataType) -> Self {
+        assert_eq!(
+            data_type.to_logical_type(),
+            self.data_type.to_logical_type(),
+            "NullArray cannot change its logical type"
+        );
+        Self { data_type, ..self }
+    }
+
     /// Returns a slice of the [`NullArray`].
     pub fn slice(&self, _offset: usize, length: usize) -> Self {
         Self {
@@ -124,6 +138,6 @@ unsafe impl ToFfi for NullArray {
 impl<A: ffi::ArrowArrayRef> FromFfi<A> for NullArray {
     unsafe fn try_from_ffi(array: A) -> Result<Self, ArrowError> {
         let data_type = array.data_type().clone();
-        Ok(Self::from_data(data_type, array.array().len()))
+        Self::try_new(data_type, array.array().len())
     }
 }
@@ -4,7 +4,10 @@ use std::cmp::Ordering;
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
-use crate::{array::*, types::NativeType};
+use crate::{
+    array::*,
+    types::{days_ms, i256, months_days_ns, NativeType},
+};
 
 /// Compare the values at two arbitrary indices in two arrays.
 pub type DynComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
@@ -131,6 +134,185 @@ fn compare_binary<O: Offset>(left: &dyn Array, right: &dyn Array) -> DynComparat
     Box::new(move |i, j| left.value(i).cmp(right.value(j)))
 }
 
+fn compare_days_ms(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<days_ms>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<days_ms>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| {
+        let lhs = left.value(i);
+        let rhs = right.value(j);
+        lhs.days()
+            .cmp(&rhs.days())
+            .then(lhs.milliseconds().cmp(&rhs.milliseconds()))
+    })
+}
+
+fn compare_months_days_ns(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| {
+        let lhs = left.value(i);
+        let rhs = right.value(j);
+        lhs.months()
+            .cmp(&rhs.months())
+            .then(lhs.days().cmp(&rhs.days()))
+            .then(lhs.ns().cmp(&rhs.ns()))
+    })
+}
+
+/// Returns `value * factor`, or `None` if it does not fit in an `i128`.
+/// A `factor` of `None` is itself too large to fit in an `i128`.
+#[inline]
+fn rescale(value: i128, factor: Option<i128>) -> Option<i128> {
+    if value == 0 {
+        return Some(0);
+    }
+    factor.and_then(|factor| value.checked_mul(factor))
+}
+
+fn compare_decimal(
+    left: &dyn Array,
+    right: &dyn Array,
+    left_scale: usize,
+    right_scale: usize,
+) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i128>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i128>>()
+        .unwrap()
+        .clone();
+    // both sides are rescaled to the largest of the two scales
+    let scale = left_scale.max(right_scale);
+    let left_factor = 10i128.checked_pow((scale - left_scale) as u32);
+    let right_factor = 10i128.checked_pow((scale - right_scale) as u32);
+    Box::new(move |i, j| {
+        let lhs = left.value(i);
+        let rhs = right.value(j);
+        match (rescale(lhs, left_factor), rescale(rhs, right_factor)) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+            // at most one side is rescaled, and a value that overflows when rescaled
+            // is larger in magnitude than any value of the other side.
+            (None, _) => lhs.cmp(&0),
+            (_, None) => 0.cmp(&rhs),
+        }
+    })
+}
+
+fn compare_decimal256(
+    left: &dyn Array,
+    right: &dyn Array,
+    left_scale: usize,
+    right_scale: usize,
+) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i256>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i256>>()
+        .unwrap()
+        .clone();
+    let scale = left_scale.max(right_scale);
+    let ten = i256::from(10);
+    let left_factor = ten.checked_pow((scale - left_scale) as u32);
+    let right_factor = ten.checked_pow((scale - right_scale) as u32);
+    let zero = i256::default();
+    Box::new(move |i, j| {
+        let lhs = left.value(i);
+        let rhs = right.value(j);
+        let rescale = |value: i256, factor: Option<i256>| {
+            if value == zero {
+                Some(zero)
+            } else {
+                factor.and_then(|factor| value.checked_mul(factor))
+            }
+        };
+        match (rescale(lhs, left_factor), rescale(rhs, right_factor)) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+            (None, _) => lhs.cmp(&zero),
+            (_, None) => zero.cmp(&rhs),
+        }
+    })
+}
+
+/// Returns a comparator of two child arrays that orders null slots before valid ones.
+fn build_compare_nullable(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let comparator = build_compare(left, right)?;
+    let left = left.validity().cloned();
+    let right = right.validity().cloned();
+    Ok(Box::new(move |i, j| {
+        let is_valid_left = left.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+        let is_valid_right = right.as_ref().map(|x| x.get_bit(j)).unwrap_or(true);
+        match (is_valid_left, is_valid_right) {
+            (true, true) => (comparator)(i, j),
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, false) => Ordering::Equal,
+        }
+    }))
+}
+
+fn compare_list<O: Offset>(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+
+    let comparator = build_compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+
+    let left = left.offsets().clone();
+    let right = right.offsets().clone();
+    Ok(Box::new(move |i, j| {
+        let left = left[i].to_usize()..left[i + 1].to_usize();
+        let right = right[j].to_usize()..right[j + 1].to_usize();
+        let (left_len, right_len) = (left.len(), right.len());
+        left.zip(right)
+            .map(|(i, j)| (comparator)(i, j))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or_else(|| left_len.cmp(&right_len))
+    }))
+}
+
+fn compare_struct(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+
+    let comparators = left
+        .values()
+        .iter()
+        .zip(right.values())
+        .map(|(left, right)| build_compare_nullable(left.as_ref(), right.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i, j| {
+        comparators
+            .iter()
+            .map(|comparator| (comparator)(i, j))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
 fn compare_dict<K>(left: &DictionaryArray<K>, right: &DictionaryArray<K>) -> Result<DynComparator>
 where
     K: DictionaryKey,
@@ -138,7 +320,7 @@ where
     let left_keys = left.keys().values().clone();
     let right_keys = right.keys().values().clone();
 
-    let comparator = build_compare(left.values().as_ref(), right.values().as_ref())?;
+    let comparator = build_compare_nullable(left.values().as_ref(), right.values().as_ref())?;
 
     Ok(Box::new(move |i: usize, j: usize| {
         let key_left = left_keys[i].to_usize().unwrap();
@@ -172,14 +354,25 @@ macro_rules! dyn_dict {
 /// # Ok(())
 /// # }
 /// ```
+/// The comparator does not consider the validity of `left` and `right`: callers must handle
+/// null slots themselves. Null slots of values nested in lists, structs and dictionaries
+/// are ordered before valid ones.
+/// * dictionaries are compared by their values, which may differ between `left` and `right`
+/// * decimals (of the same width) are compared after rescaling both sides to the largest of their scales
+/// * intervals are compared field by field, from the largest unit to the smallest
+/// * lists are compared lexicographically by their elements
+/// * structs are compared lexicographically by their fields, in declaration order
 /// # Error
-/// The arrays' [`DataType`] must be equal and the types must have a natural order.
+/// The arrays' [`DataType`] must be equal (up to the precision and scale of decimals)
+/// and the types must have a natural order.
 // This is a factory of comparisons.
 pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
     use DataType::*;
     use IntervalUnit::*;
     use TimeUnit::*;
     Ok(match (left.data_type(), right.data_type()) {
+        (Decimal(_, lhs), Decimal(_, rhs)) => compare_decimal(left, right, *lhs, *rhs),
+        (Decimal256(_, lhs), Decimal256(_, rhs)) => compare_decimal256(left, right, *lhs, *rhs),
         (a, b) if a != b => {
             return Err(ArrowError::InvalidArgumentError(
                 "Can't compare arrays of different types".to_string(),
@@ -215,6 +408,11 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
         (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (Interval(DayTime), Interval(DayTime)) => compare_days_ms(left, right),
+        (Interval(MonthDayNano), Interval(MonthDayNano)) => compare_months_days_ns(left, right),
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
         (Dictionary(key_type_lhs, ..), Dictionary(key_type_rhs, ..)) => {
             match (key_type_lhs, key_type_rhs) {
                 (IntegerType::UInt8, IntegerType::UInt8) => dyn_dict!(u8, left, right),
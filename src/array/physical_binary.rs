@@ -1,5 +1,6 @@
 use crate::array::Offset;
 use crate::bitmap::MutableBitmap;
+use crate::error::{ArrowError, Result};
 
 /// # Safety
 /// The caller must ensure that `iterator` is `TrustedLen`.
@@ -107,6 +108,8 @@ where
 
 // Populates `offsets` and `values` [`Vec`]s with information extracted
 // from the incoming `iterator`.
+// # Panics
+// This function panics iff the length of all values (in bytes) exceeds `O` maximum value.
 // # Safety
 // The caller must ensure the `iterator` is [`TrustedLen`]
 #[inline]
@@ -118,6 +121,29 @@ pub(crate) unsafe fn extend_from_trusted_len_values_iter<I, P, O>(
     O: Offset,
     P: AsRef<[u8]>,
     I: Iterator<Item = P>,
+{
+    try_extend_from_trusted_len_values_iter(offsets, values, iterator)
+        .expect("The length of all values exceeds the maximum offset")
+}
+
+// Populates `offsets` and `values` [`Vec`]s with information extracted
+// from the incoming `iterator`.
+// # Errors
+// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds `O`
+// maximum value, in which case both `offsets` and `values` are left unchanged.
+// The solution is to use a large offset (`LargeUtf8` or `LargeBinary`).
+// # Safety
+// The caller must ensure the `iterator` is [`TrustedLen`]
+#[inline]
+pub(crate) unsafe fn try_extend_from_trusted_len_values_iter<I, P, O>(
+    offsets: &mut Vec<O>,
+    values: &mut Vec<u8>,
+    iterator: I,
+) -> Result<()>
+where
+    O: Offset,
+    P: AsRef<[u8]>,
+    I: Iterator<Item = P>,
 {
     let (_, upper) = iterator.size_hint();
     let additional = upper.expect("extend_from_trusted_len_values_iter requires an upper limit");
@@ -127,6 +153,7 @@ pub(crate) unsafe fn extend_from_trusted_len_values_iter<I, P, O>(
     // Read in the last offset, will be used to increment and store
     // new values later on
     let mut length = *offsets.last().unwrap();
+    let values_len = values.len();
 
     // Get a mutable pointer to the `offsets`, and move the pointer
     // to the position, where a new value will be written
@@ -137,7 +164,14 @@ pub(crate) unsafe fn extend_from_trusted_len_values_iter<I, P, O>(
         let s = item.as_ref();
 
         // Calculate the new offset value
-        length += O::from_usize(s.len()).unwrap();
+        length = match O::from_usize(s.len()).and_then(|len| length.checked_add(&len)) {
+            Some(length) => length,
+            None => {
+                // the new offsets were never made visible (`set_len` was not called)
+                values.truncate(values_len);
+                return Err(ArrowError::Overflow);
+            }
+        };
 
         // Push new entries for both `values` and `offsets` buffer
         values.extend_from_slice(s);
@@ -155,6 +189,7 @@ pub(crate) unsafe fn extend_from_trusted_len_values_iter<I, P, O>(
 
     // We make sure to set the new length for the `offsets` buffer
     offsets.set_len(offsets.len() + additional);
+    Ok(())
 }
 
 // Populates `offsets` and `values` [`Vec`]s with information extracted
@@ -195,6 +230,8 @@ where
 // Populates `offsets`, `values`, and `validity` [`Vec`]s with
 // information extracted from the incoming `iterator`.
 //
+// # Panics
+// This function panics iff the length of all values (in bytes) exceeds `O` maximum value.
 // # Safety
 // The caller must ensure that `iterator` is [`TrustedLen`]
 #[inline]
@@ -207,6 +244,31 @@ pub(crate) unsafe fn extend_from_trusted_len_iter<O, I, P>(
     O: Offset,
     P: AsRef<[u8]>,
     I: Iterator<Item = Option<P>>,
+{
+    try_extend_from_trusted_len_iter(offsets, values, validity, iterator)
+        .expect("The length of all values exceeds the maximum offset")
+}
+
+// Populates `offsets`, `values`, and `validity` [`Vec`]s with
+// information extracted from the incoming `iterator`.
+//
+// # Errors
+// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds `O`
+// maximum value, in which case `offsets`, `values` and `validity` are left unchanged.
+// The solution is to use a large offset (`LargeUtf8` or `LargeBinary`).
+// # Safety
+// The caller must ensure that `iterator` is [`TrustedLen`]
+#[inline]
+pub(crate) unsafe fn try_extend_from_trusted_len_iter<O, I, P>(
+    offsets: &mut Vec<O>,
+    values: &mut Vec<u8>,
+    validity: &mut MutableBitmap,
+    iterator: I,
+) -> Result<()>
+where
+    O: Offset,
+    P: AsRef<[u8]>,
+    I: Iterator<Item = Option<P>>,
 {
     let (_, upper) = iterator.size_hint();
     let additional = upper.expect("extend_from_trusted_len_iter requires an upper limit");
@@ -217,6 +279,8 @@ pub(crate) unsafe fn extend_from_trusted_len_iter<O, I, P>(
     // Read in the last offset, will be used to increment and store
     // new values later on
     let mut length = *offsets.last().unwrap();
+    let values_len = values.len();
+    let validity_len = validity.len();
 
     // Get a mutable pointer to the `offsets`, and move the pointer
     // to the position, where a new value will be written
@@ -228,7 +292,17 @@ pub(crate) unsafe fn extend_from_trusted_len_iter<O, I, P>(
             let bytes = item.as_ref();
 
             // Calculate new offset value
-            length += O::from_usize(bytes.len()).unwrap();
+            length = match O::from_usize(bytes.len()).and_then(|len| length.checked_add(&len)) {
+                Some(length) => length,
+                None => {
+                    // the new offsets were never made visible (`set_len` was not called)
+                    values.truncate(values_len);
+                    while validity.len() > validity_len {
+                        validity.pop();
+                    }
+                    return Err(ArrowError::Overflow);
+                }
+            };
 
             // Push new values for `values` and `validity` buffer
             values.extend_from_slice(bytes);
@@ -253,6 +327,7 @@ pub(crate) unsafe fn extend_from_trusted_len_iter<O, I, P>(
 
     // We make sure to set the new length for the `offsets` buffer
     offsets.set_len(offsets.len() + additional);
+    Ok(())
 }
 
 /// Creates two [`Vec`]s from an iterator of `&[u8]`.
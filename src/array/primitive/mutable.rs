@@ -1,7 +1,7 @@
 use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{check_extend_from_array, extend_validity, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -275,6 +275,23 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     pub fn values_mut_slice(&mut self) -> &mut [T] {
         self.values.as_mut_slice()
     }
+
+    /// Returns the value at position `index`, or `None` if it is null.
+    /// # Panic
+    /// Panics iff `index >= self.len()`.
+    pub fn get(&self, index: usize) -> Option<T> {
+        assert!(
+            index < self.len(),
+            "index {} is out of bounds of a MutablePrimitiveArray of len {}",
+            index,
+            self.len()
+        );
+        if self.validity.as_ref().map(|x| x.get(index)).unwrap_or(true) {
+            Some(self.values[index])
+        } else {
+            None
+        }
+    }
 }
 
 /// Setters
@@ -283,9 +300,14 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
     /// Note that if it is the first time a null appears in this array,
     /// this initializes the validity bitmap (`O(N)`).
     /// # Panic
-    /// Panics iff index is larger than `self.len()`.
+    /// Panics iff `index >= self.len()`.
     pub fn set(&mut self, index: usize, value: Option<T>) {
-        assert!(index < self.len());
+        assert!(
+            index < self.len(),
+            "index {} is out of bounds of a MutablePrimitiveArray of len {}",
+            index,
+            self.len()
+        );
         // Safety:
         // we just checked bounds
         unsafe { self.set_unchecked(index, value) }
@@ -398,6 +420,26 @@ impl<T: NativeType> MutableArray for MutablePrimitiveArray<T> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional)
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+
+        extend_validity(
+            self.values.len(),
+            &mut self.validity,
+            array.validity(),
+            start,
+            len,
+        );
+        self.values
+            .extend_from_slice(&array.values()[start..start + len]);
+        Ok(())
+    }
 }
 
 impl<T: NativeType> MutablePrimitiveArray<T> {
@@ -30,6 +30,14 @@ pub fn check_offsets_minimal<O: Offset>(offsets: &[O], values_len: usize) -> usi
     len
 }
 
+// Whether `index` is a char boundary of the valid utf8 `values`. Out of bounds is considered a boundary.
+#[inline]
+fn is_char_boundary(values: &[u8], index: usize) -> bool {
+    // A valid code-point iff it does not start with 0b10xxxxxx
+    // Bit-magic taken from `std::str::is_char_boundary`
+    values.get(index).map_or(true, |&b| (b as i8) >= -0x40)
+}
+
 /// # Panics iff:
 /// * the `offsets` is not monotonically increasing, or
 /// * any slice of `values` between two consecutive pairs from `offsets` is invalid `utf8`, or
@@ -48,6 +56,10 @@ pub fn try_check_offsets_and_utf8<O: Offset>(offsets: &[O], values: &[u8]) -> Re
     } else {
         simdutf8::basic::from_utf8(values)?;
 
+        if offsets.first().map_or(false, |first| *first < O::zero()) {
+            return Err(ArrowError::oos("offsets must be non-negative"));
+        }
+
         for window in offsets.windows(2) {
             let start = window[0].to_usize();
             let end = window[1].to_usize();
@@ -57,14 +69,14 @@ pub fn try_check_offsets_and_utf8<O: Offset>(offsets: &[O], values: &[u8]) -> Re
                 return Err(ArrowError::oos("offsets must be monotonically increasing"));
             }
 
-            let first = values.get(start);
-
-            if let Some(&b) = first {
-                // A valid code-point iff it does not start with 0b10xxxxxx
-                // Bit-magic taken from `std::str::is_char_boundary`
-                if (b as i8) < -0x40 {
-                    return Err(ArrowError::oos("Non-valid char boundary detected"));
-                }
+            if !is_char_boundary(values, start) {
+                return Err(ArrowError::oos("Non-valid char boundary detected"));
+            }
+        }
+        // the last offset ends the last value: it must also be a boundary
+        if let Some(last) = offsets.last() {
+            if !is_char_boundary(values, last.to_usize()) {
+                return Err(ArrowError::oos("Non-valid char boundary detected"));
             }
         }
         // check bounds
@@ -88,10 +100,12 @@ pub fn check_offsets<O: Offset>(offsets: &[O], values_len: usize) {
     try_check_offsets(offsets, values_len).unwrap()
 }
 
-/// Checks that `offsets` is monotonically increasing, and all offsets are less than or equal to
-/// `values_len`.
+/// Checks that `offsets` is non-negative and monotonically increasing, and all offsets are less
+/// than or equal to `values_len`.
 pub fn try_check_offsets<O: Offset>(offsets: &[O], values_len: usize) -> Result<()> {
-    if offsets.windows(2).any(|window| window[0] > window[1]) {
+    if offsets.first().map_or(false, |first| *first < O::zero()) {
+        Err(ArrowError::oos("offsets must be non-negative"))
+    } else if offsets.windows(2).any(|window| window[0] > window[1]) {
         Err(ArrowError::oos("offsets must be monotonically increasing"))
     } else if offsets
         .last()
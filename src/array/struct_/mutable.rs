@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{check_extend_from_array, extend_validity, Array, MutableArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::StructArray;
+
+/// The mutable version of [`StructArray`].
+///
+/// Rows are built by pushing a value to every child (see [`MutableStructArray::value`])
+/// and then committing the row with [`MutableStructArray::push`].
+/// # Example
+/// ```
+/// use arrow2::array::*;
+/// use arrow2::datatypes::{DataType, Field};
+///
+/// let data_type = DataType::Struct(vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ]);
+/// let values: Vec<Box<dyn MutableArray>> = vec![
+///     Box::new(MutablePrimitiveArray::<i32>::new()),
+///     Box::new(MutableUtf8Array::<i32>::new()),
+/// ];
+/// let mut array = MutableStructArray::new(data_type, values);
+///
+/// array.value::<MutablePrimitiveArray<i32>>(0).push(Some(1));
+/// array.value::<MutableUtf8Array<i32>>(1).push(Some("a"));
+/// array.push(true);
+/// array.push_null();
+///
+/// let array: StructArray = array.into();
+/// assert_eq!(array.len(), 2);
+/// assert!(array.is_null(1));
+/// ```
+#[derive(Debug)]
+pub struct MutableStructArray {
+    data_type: DataType,
+    values: Vec<Box<dyn MutableArray>>,
+    validity: Option<MutableBitmap>,
+    length: usize,
+}
+
+impl From<MutableStructArray> for StructArray {
+    fn from(mut other: MutableStructArray) -> Self {
+        StructArray::from_data(
+            other.data_type,
+            other.values.iter_mut().map(|x| x.as_arc()).collect(),
+            other.validity.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableStructArray {
+    /// Creates a new [`MutableStructArray`] from a [`DataType`] and its (empty) children.
+    /// # Errors
+    /// This function errors iff:
+    /// * `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Struct`].
+    /// * the children of `data_type` are empty
+    /// * the values's len is different from children's length
+    /// * any of the values's data type is different from its corresponding children' data type
+    /// * any of the values is not empty
+    pub fn try_new(data_type: DataType, values: Vec<Box<dyn MutableArray>>) -> Result<Self> {
+        let fields = StructArray::try_get_fields(&data_type)?;
+        if fields.is_empty() {
+            return Err(ArrowError::oos(
+                "A MutableStructArray must contain at least one field",
+            ));
+        }
+        if fields.len() != values.len() {
+            return Err(ArrowError::oos(
+                "A MutableStructArray must a number of fields in its DataType equal to the number of child values",
+            ));
+        }
+        fields
+            .iter()
+            .zip(values.iter())
+            .enumerate()
+            .try_for_each(|(index, (field, child))| {
+                if field.data_type() != child.data_type() {
+                    Err(ArrowError::oos(format!(
+                        "The children DataTypes of a MutableStructArray must equal the children data types. However, the field {} has data type {:?} but the value has data type {:?}",
+                        index,
+                        field.data_type(),
+                        child.data_type()
+                    )))
+                } else if !child.is_empty() {
+                    Err(ArrowError::oos(format!(
+                        "The children of a MutableStructArray must be empty. However, the value {} has a length of {}",
+                        index,
+                        child.len()
+                    )))
+                } else {
+                    Ok(())
+                }
+            })?;
+
+        Ok(Self {
+            data_type,
+            values,
+            validity: None,
+            length: 0,
+        })
+    }
+
+    /// Creates a new [`MutableStructArray`] from a [`DataType`] and its (empty) children.
+    /// # Panics
+    /// This function panics iff [`MutableStructArray::try_new`] errors.
+    pub fn new(data_type: DataType, values: Vec<Box<dyn MutableArray>>) -> Self {
+        Self::try_new(data_type, values).unwrap()
+    }
+
+    /// The inner values
+    pub fn values(&self) -> &[Box<dyn MutableArray>] {
+        &self.values
+    }
+
+    pub(crate) fn mut_values(&mut self) -> &mut [Box<dyn MutableArray>] {
+        &mut self.values
+    }
+
+    /// Returns a mutable reference to the child at `index`, downcasted to `A`.
+    /// # Panics
+    /// This function panics iff `index` is out of bounds or the child is not an `A`.
+    pub fn value<A: MutableArray + 'static>(&mut self, index: usize) -> &mut A {
+        self.values[index]
+            .as_mut_any()
+            .downcast_mut::<A>()
+            .expect("The child of a MutableStructArray is not of the requested type")
+    }
+
+    fn init_validity(&mut self) {
+        let mut validity = MutableBitmap::with_capacity(self.length + 1);
+        validity.extend_constant(self.length, true);
+        validity.push(false);
+        self.validity = Some(validity)
+    }
+
+    /// Commits a new row, whose values were pushed to every child.
+    /// # Errors
+    /// This function errors iff any of the children does not have exactly
+    /// one more value than the number of rows of this array. The array is
+    /// left unchanged on error.
+    pub fn try_push(&mut self, valid: bool) -> Result<()> {
+        let len = self.length + 1;
+        if let Some((index, child)) = self
+            .values
+            .iter()
+            .enumerate()
+            .find(|(_, child)| child.len() != len)
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "All children of a MutableStructArray must have the same length after a push. However, the value {} has a length of {}, which is different from {}",
+                index,
+                child.len(),
+                len
+            )));
+        }
+        match &mut self.validity {
+            Some(validity) => validity.push(valid),
+            None => {
+                if !valid {
+                    self.init_validity()
+                }
+            }
+        }
+        self.length = len;
+        Ok(())
+    }
+
+    /// Commits a new row, whose values were pushed to every child.
+    /// # Panics
+    /// This function panics iff [`MutableStructArray::try_push`] errors.
+    pub fn push(&mut self, valid: bool) {
+        self.try_push(valid).unwrap()
+    }
+
+    /// Pushes a null row, pushing a null to every child.
+    pub fn push_null(&mut self) {
+        self.values.iter_mut().for_each(|x| x.push_null());
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+        self.length += 1;
+    }
+
+    /// Shrinks the capacity of the [`MutableStructArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.values.iter_mut().for_each(|x| x.shrink_to_fit());
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+}
+
+impl MutableArray for MutableStructArray {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.length = 0;
+        Box::new(StructArray::from_data(
+            self.data_type.clone(),
+            self.values.iter_mut().map(|x| x.as_arc()).collect(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.length = 0;
+        Arc::new(StructArray::from_data(
+            self.data_type.clone(),
+            self.values.iter_mut().map(|x| x.as_arc()).collect(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        ))
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.iter_mut().for_each(|x| x.reserve(additional));
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+
+        self.values
+            .iter_mut()
+            .zip(array.values().iter())
+            .try_for_each(|(mutable, values)| {
+                mutable.try_extend_from_array(values.as_ref(), start, len)
+            })?;
+        extend_validity(
+            self.length,
+            &mut self.validity,
+            array.validity(),
+            start,
+            len,
+        );
+        self.length += len;
+        Ok(())
+    }
+}
@@ -45,6 +45,11 @@ impl UnionArray {
     /// * `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Union`].
     /// * the fields's len is different from the `data_type`'s children's length
     /// * any of the values's data type is different from its corresponding children' data type
+    /// * `offsets` is `None` when the union is dense or `Some` when it is sparse
+    /// * any of the `types` does not correspond to a field
+    /// * dense: the number of `offsets` is different from the number of `types`
+    /// * dense: any of the `offsets` is out of bounds of its field
+    /// * sparse: any of the fields has fewer slots than `types`
     pub fn try_new(
         data_type: DataType,
         types: Buffer<i8>,
@@ -80,7 +85,7 @@ impl UnionArray {
             ));
         }
 
-        let fields_hash = ids.as_ref().map(|ids| {
+        let fields_hash: Option<HashMap<i8, FieldEntry>> = ids.as_ref().map(|ids| {
             ids.iter()
                 .map(|x| *x as i8)
                 .enumerate()
@@ -89,9 +94,50 @@ impl UnionArray {
                 .collect()
         });
 
-        // not validated:
-        // * `offsets` is valid
-        // * max id < fields.len()
+        // every type id must map to a field
+        let field_index = |type_: i8| match &fields_hash {
+            Some(hash) => hash.get(&type_).map(|x| x.0),
+            None if type_ >= 0 && (type_ as usize) < fields.len() => Some(type_ as usize),
+            None => None,
+        };
+        let indices = types
+            .iter()
+            .map(|type_| {
+                field_index(*type_).ok_or_else(|| {
+                    ArrowError::oos(format!(
+                        "The type id {} of a UnionArray does not correspond to any of its fields",
+                        type_
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        // every slot must be within the bounds of its field
+        if let Some(offsets) = &offsets {
+            if offsets.len() != types.len() {
+                return Err(ArrowError::oos(
+                    "In a dense UnionArray, the number of offsets must equal the number of types",
+                ));
+            }
+            offsets
+                .iter()
+                .zip(indices.iter())
+                .try_for_each(|(offset, index)| {
+                    if *offset < 0 || *offset as usize >= fields[*index].len() {
+                        Err(ArrowError::oos(format!(
+                            "The offset {} of a dense UnionArray is out of bounds of its field {}",
+                            offset, index
+                        )))
+                    } else {
+                        Ok(())
+                    }
+                })?;
+        } else if fields.iter().any(|field| field.len() < types.len()) {
+            return Err(ArrowError::oos(
+                "In a sparse UnionArray, every field must have at least as many slots as types",
+            ));
+        }
+
         Ok(Self {
             data_type,
             fields_hash,
@@ -108,6 +154,7 @@ impl UnionArray {
     /// * `data_type`'s physical type is not [`crate::datatypes::PhysicalType::Union`].
     /// * the fields's len is different from the `data_type`'s children's length
     /// * any of the values's data type is different from its corresponding children' data type
+    /// * the `types` or the `offsets` are invalid (see [`UnionArray::try_new`])
     pub fn new(
         data_type: DataType,
         types: Buffer<i8>,
@@ -129,7 +176,7 @@ impl UnionArray {
 
     /// Creates a new null [`UnionArray`].
     pub fn new_null(data_type: DataType, length: usize) -> Self {
-        if let DataType::Union(f, _, mode) = &data_type {
+        if let DataType::Union(f, _, mode) = data_type.to_logical_type() {
             let fields = f
                 .iter()
                 .map(|x| new_null_array(x.data_type().clone(), length).into())
@@ -152,7 +199,7 @@ impl UnionArray {
 
     /// Creates a new empty [`UnionArray`].
     pub fn new_empty(data_type: DataType) -> Self {
-        if let DataType::Union(f, _, mode) = &data_type {
+        if let DataType::Union(f, _, mode) = data_type.to_logical_type() {
             let fields = f
                 .iter()
                 .map(|x| new_empty_array(x.data_type().clone()).into())
@@ -179,6 +226,20 @@ impl UnionArray {
 }
 
 impl UnionArray {
+    /// Returns a new [`UnionArray`] with a different logical type, e.g. an extension type over it.
+    /// This is `O(1)`.
+    /// # Panics
+    /// Panics iff the logical type of `data_type` differs from the logical type of `self`.
+    #[inline]
+    pub fn to(self, data_type: DataType) -> Self {
+        assert_eq!(
+            data_type.to_logical_type(),
+            self.data_type.to_logical_type(),
+            "UnionArray cannot change its logical type"
+        );
+        Self { data_type, ..self }
+    }
+
     /// Returns a slice of this [`UnionArray`].
     /// # Implementation
     /// This operation is `O(F)` where `F` is the number of fields.
@@ -244,8 +305,16 @@ impl UnionArray {
             .unwrap_or_else(|| &self.fields[type_ as usize])
     }
 
+    /// The offset of this array, i.e. the slot of its first element in its children
+    /// (sparse) or its offsets (dense).
+    #[inline]
+    pub(crate) fn slice_offset(&self) -> usize {
+        self.offset
+    }
+
     #[inline]
     fn field_slot(&self, index: usize) -> usize {
+        let index = self.offset + index;
         self.offsets()
             .as_ref()
             .map(|x| x[index] as usize)
@@ -1,5 +1,5 @@
 use crate::{
-    bitmap::Bitmap,
+    bitmap::{Bitmap, MutableBitmap},
     buffer::Buffer,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -8,7 +8,7 @@ use either::Either;
 
 use super::{
     specification::{try_check_offsets_and_utf8, try_check_offsets_bounds},
-    Array, GenericBinaryArray, Offset,
+    Array, BinaryArray, GenericBinaryArray, Offset,
 };
 
 mod ffi;
@@ -148,10 +148,94 @@ impl<O: Offset> Utf8Array<O> {
             DataType::Utf8
         }
     }
+
+    /// Returns a new [`Utf8Array`] with the offsets, values and validity of a [`BinaryArray`],
+    /// without copying them.
+    /// # Errors
+    /// This function errors iff the value of any slot (including null slots) is not valid utf8.
+    /// The error reports the index of the first such slot.
+    /// # Implementation
+    /// This function is `O(N)` - checking utf8 is `O(N)`
+    /// # Examples
+    /// ```
+    /// use arrow2::array::{BinaryArray, Utf8Array};
+    ///
+    /// let array = BinaryArray::<i32>::from(&[Some(b"hello".as_ref()), None]);
+    /// let array = Utf8Array::<i32>::try_from_binary(array).unwrap();
+    /// assert_eq!(array, Utf8Array::<i32>::from(&[Some("hello"), None]));
+    ///
+    /// let array = BinaryArray::<i32>::from_slice(&[b"a".as_ref(), b"\xff"]);
+    /// assert!(Utf8Array::<i32>::try_from_binary(array).is_err());
+    /// ```
+    pub fn try_from_binary(array: BinaryArray<O>) -> Result<Self> {
+        if let Some(index) = first_invalid_utf8(array.offsets(), array.values()) {
+            return Err(ArrowError::oos(format!(
+                "The value at index {} is not valid utf8",
+                index
+            )));
+        }
+        // Safety: we just checked that every slot is valid utf8
+        Ok(unsafe { Self::from_binary_unchecked(array) })
+    }
+
+    /// Returns a [`BinaryArray`] with the offsets, values and validity of this array,
+    /// without copying them.
+    pub fn into_binary(self) -> BinaryArray<O> {
+        // Safety: the offsets of a valid Utf8Array are valid offsets of the values
+        unsafe {
+            BinaryArray::from_data_unchecked(
+                BinaryArray::<O>::default_data_type(),
+                self.offsets,
+                self.values,
+                self.validity,
+            )
+        }
+    }
+}
+
+/// Returns the index of the first slot that is not valid utf8, if any.
+fn first_invalid_utf8<O: Offset>(offsets: &[O], values: &[u8]) -> Option<usize> {
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    let slots = &values[start..end];
+    if slots.is_ascii() {
+        return None;
+    }
+
+    // when all slots are valid utf8 and all offsets are char boundaries, every slot is valid
+    let boundaries = offsets.iter().all(|offset| {
+        // A valid code-point iff it does not start with 0b10xxxxxx
+        values
+            .get(offset.to_usize())
+            .map_or(true, |&b| (b as i8) >= -0x40)
+    });
+    if boundaries && simdutf8::basic::from_utf8(slots).is_ok() {
+        return None;
+    }
+
+    offsets.windows(2).position(|window| {
+        let slot = &values[window[0].to_usize()..window[1].to_usize()];
+        simdutf8::basic::from_utf8(slot).is_err()
+    })
 }
 
 // unsafe constructors
 impl<O: Offset> Utf8Array<O> {
+    /// Returns a new [`Utf8Array`] with the offsets, values and validity of a [`BinaryArray`],
+    /// without copying them nor checking for utf8-validity.
+    /// # Safety
+    /// This function is unsound iff the value of any slot (including null slots) is not valid utf8.
+    /// # Implementation
+    /// This function is `O(1)`
+    pub unsafe fn from_binary_unchecked(array: BinaryArray<O>) -> Self {
+        Self::from_data_unchecked(
+            Self::default_data_type(),
+            array.offsets().clone(),
+            array.values().clone(),
+            array.validity().cloned(),
+        )
+    }
+
     /// Creates a new [`Utf8Array`] without checking for offsets monotinicity nor utf8-validity
     ///
     /// # Errors
@@ -269,16 +353,53 @@ impl<O: Offset> Utf8Array<O> {
         }
     }
 
-    /// Sets the validity bitmap on this [`Utf8Array`].
+    /// Returns a new [`Utf8Array`] with a different logical type, e.g. an extension type over it.
+    /// This is `O(1)`.
+    /// # Panics
+    /// Panics iff the logical type of `data_type` differs from the logical type of `self`.
+    #[inline]
+    pub fn to(self, data_type: DataType) -> Self {
+        assert_eq!(
+            data_type.to_logical_type(),
+            self.data_type.to_logical_type(),
+            "Utf8Array cannot change its logical type"
+        );
+        Self { data_type, ..self }
+    }
+
+    /// Returns this [`Utf8Array`] with a new validity bitmap.
     /// # Panic
     /// This function panics iff `validity.len() != self.len()`.
-    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+    #[must_use]
+    pub fn with_validity(mut self, validity: Option<Bitmap>) -> Self {
+        self.set_validity(validity);
+        self
+    }
+
+    /// Sets the validity bitmap of this [`Utf8Array`].
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn set_validity(&mut self, validity: Option<Bitmap>) {
         if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
             panic!("validity should be as least as large as the array")
         }
-        let mut arr = self.clone();
-        arr.validity = validity;
-        arr
+        self.validity = validity;
+    }
+
+    /// Replaces the validity bitmap of this [`Utf8Array`] by the result of `f` over it,
+    /// e.g. to combine it with another mask. When it has no validity, `f` is applied over a bitmap whose slots are all valid.
+    /// # Panic
+    /// This function panics iff `f` returns a bitmap whose length is not `self.len()`.
+    pub fn apply_validity<F: Fn(&Bitmap) -> Bitmap>(&mut self, f: F) {
+        let validity = match self.validity.as_ref() {
+            Some(validity) => f(validity),
+            None => {
+                let mut validity = MutableBitmap::with_capacity(self.len());
+                validity.extend_constant(self.len(), true);
+                f(&validity.into())
+            }
+        };
+        self.set_validity(Some(validity));
     }
 
     /// Try to convert this `Utf8Array` to a `MutableUtf8Array`
@@ -437,7 +558,7 @@ impl<O: Offset> Array for Utf8Array<O> {
         Box::new(self.slice_unchecked(offset, length))
     }
     fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
-        Box::new(self.with_validity(validity))
+        Box::new(self.clone().with_validity(validity))
     }
 }
 
@@ -2,8 +2,9 @@ use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
     array::{
+        check_extend_from_array, extend_validity,
         specification::{check_offsets_and_utf8, check_offsets_minimal},
-        Array, MutableArray, Offset, TryExtend, TryPush,
+        try_extend_offsets, Array, MutableArray, Offset, TryExtend, TryPush,
     },
     bitmap::MutableBitmap,
     datatypes::DataType,
@@ -156,6 +157,16 @@ impl<O: Offset> MutableUtf8Array<O> {
         *self.offsets.last().unwrap()
     }
 
+    /// Returns the number of slots that can be pushed without re-allocating the offsets.
+    pub fn capacity(&self) -> usize {
+        self.offsets.capacity() - 1
+    }
+
+    /// Returns the number of bytes that can be pushed without re-allocating the values.
+    pub fn values_capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
     /// Pushes a new element to the array.
     /// # Panic
     /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
@@ -164,6 +175,33 @@ impl<O: Offset> MutableUtf8Array<O> {
         self.try_push(value).unwrap()
     }
 
+    /// Pushes a new element to the array without checking that its offsets do not overflow.
+    /// # Safety
+    /// The caller must ensure that the length of all values (in bytes), including `value`,
+    /// does not exceed `O` maximum value.
+    #[inline]
+    pub unsafe fn push_unchecked<T: AsRef<str>>(&mut self, value: Option<T>) {
+        match value {
+            Some(value) => {
+                self.values.extend_from_slice(value.as_ref().as_bytes());
+                let size = O::from_usize(self.values.len())
+                    .unwrap_or_else(|| std::hint::unreachable_unchecked());
+                self.offsets.push(size);
+
+                if let Some(validity) = &mut self.validity {
+                    validity.push(true)
+                }
+            }
+            None => {
+                self.offsets.push(self.last_offset());
+                match &mut self.validity {
+                    Some(validity) => validity.push(false),
+                    None => self.init_validity(),
+                }
+            }
+        }
+    }
+
     fn init_validity(&mut self) {
         let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
         validity.extend_constant(self.len(), true);
@@ -215,7 +253,7 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
         Box::new(unsafe {
             Utf8Array::from_data_unchecked(
                 self.data_type.clone(),
-                std::mem::take(&mut self.offsets).into(),
+                std::mem::replace(&mut self.offsets, vec![O::default()]).into(),
                 std::mem::take(&mut self.values).into(),
                 std::mem::take(&mut self.validity).map(|x| x.into()),
             )
@@ -229,7 +267,7 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
         Arc::new(unsafe {
             Utf8Array::from_data_unchecked(
                 self.data_type.clone(),
-                std::mem::take(&mut self.offsets).into(),
+                std::mem::replace(&mut self.offsets, vec![O::default()]).into(),
                 std::mem::take(&mut self.values).into(),
                 std::mem::take(&mut self.validity).map(|x| x.into()),
             )
@@ -260,6 +298,23 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.reserve(additional, 0)
+    }
+
+    fn try_extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        check_extend_from_array(&self.data_type, array, start, len)?;
+        let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+
+        let offsets = &array.offsets()[start..start + len + 1];
+        let values = &array.values()[offsets[0].to_usize()..offsets[len].to_usize()];
+        let length = self.offsets.len() - 1;
+        try_extend_offsets(&mut self.offsets, offsets)?;
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+        self.values.extend_from_slice(values);
+        Ok(())
+    }
 }
 
 impl<O: Offset, P: AsRef<str>> FromIterator<Option<P>> for MutableUtf8Array<O> {
@@ -271,13 +326,30 @@ impl<O: Offset, P: AsRef<str>> FromIterator<Option<P>> for MutableUtf8Array<O> {
 impl<O: Offset> MutableUtf8Array<O> {
     /// Extends the [`MutableUtf8Array`] from an iterator of values of trusted len.
     /// This differs from `extended_trusted_len` which accepts iterator of optional values.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
     #[inline]
     pub fn extend_trusted_len_values<I, P>(&mut self, iterator: I)
     where
         P: AsRef<str>,
         I: TrustedLen<Item = P>,
     {
-        unsafe { self.extend_trusted_len_values_unchecked(iterator) }
+        self.try_extend_trusted_len_values(iterator).unwrap()
+    }
+
+    /// Extends the [`MutableUtf8Array`] from an iterator of values of trusted len.
+    /// This differs from `try_extend_trusted_len` which accepts iterator of optional values.
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged. Use `DataType::LargeUtf8`
+    /// (i.e. `O = i64`) for larger arrays.
+    #[inline]
+    pub fn try_extend_trusted_len_values<I, P>(&mut self, iterator: I) -> Result<()>
+    where
+        P: AsRef<str>,
+        I: TrustedLen<Item = P>,
+    {
+        unsafe { self.try_extend_trusted_len_values_unchecked(iterator) }
     }
 
     /// Extends the [`MutableUtf8Array`] from an iterator of values.
@@ -299,10 +371,31 @@ impl<O: Offset> MutableUtf8Array<O> {
     /// Extends the [`MutableUtf8Array`] from an iterator of values of trusted len.
     /// This differs from `extended_trusted_len_unchecked` which accepts iterator of optional
     /// values.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
     /// # Safety
     /// The iterator must be trusted len.
     #[inline]
     pub unsafe fn extend_trusted_len_values_unchecked<I, P>(&mut self, iterator: I)
+    where
+        P: AsRef<str>,
+        I: Iterator<Item = P>,
+    {
+        self.try_extend_trusted_len_values_unchecked(iterator)
+            .unwrap()
+    }
+
+    /// Extends the [`MutableUtf8Array`] from an iterator of values of trusted len.
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged.
+    /// # Safety
+    /// The iterator must be trusted len.
+    #[inline]
+    pub unsafe fn try_extend_trusted_len_values_unchecked<I, P>(
+        &mut self,
+        iterator: I,
+    ) -> Result<()>
     where
         P: AsRef<str>,
         I: Iterator<Item = P>,
@@ -311,28 +404,62 @@ impl<O: Offset> MutableUtf8Array<O> {
         let additional = upper.expect("extend_trusted_len_values requires an upper limit");
 
         let iterator = iterator.map(StrAsBytes);
-        extend_from_trusted_len_values_iter(&mut self.offsets, &mut self.values, iterator);
+        try_extend_from_trusted_len_values_iter(&mut self.offsets, &mut self.values, iterator)?;
 
         if let Some(validity) = self.validity.as_mut() {
             validity.extend_constant(additional, true);
         }
+        Ok(())
     }
 
     /// Extends the [`MutableUtf8Array`] from an iterator of trusted len.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
     #[inline]
     pub fn extend_trusted_len<I, P>(&mut self, iterator: I)
     where
         P: AsRef<str>,
         I: TrustedLen<Item = Option<P>>,
     {
-        unsafe { self.extend_trusted_len_unchecked(iterator) }
+        self.try_extend_trusted_len(iterator).unwrap()
+    }
+
+    /// Extends the [`MutableUtf8Array`] from an iterator of trusted len.
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged. Use `DataType::LargeUtf8`
+    /// (i.e. `O = i64`) for larger arrays.
+    #[inline]
+    pub fn try_extend_trusted_len<I, P>(&mut self, iterator: I) -> Result<()>
+    where
+        P: AsRef<str>,
+        I: TrustedLen<Item = Option<P>>,
+    {
+        unsafe { self.try_extend_trusted_len_unchecked(iterator) }
     }
 
     /// Extends [`MutableUtf8Array`] from an iterator of trusted len.
+    /// # Panic
+    /// This operation panics iff the length of all values (in bytes) exceeds `O` maximum value.
     /// # Safety
     /// The iterator must be trusted len.
     #[inline]
     pub unsafe fn extend_trusted_len_unchecked<I, P>(&mut self, iterator: I)
+    where
+        P: AsRef<str>,
+        I: Iterator<Item = Option<P>>,
+    {
+        self.try_extend_trusted_len_unchecked(iterator).unwrap()
+    }
+
+    /// Extends [`MutableUtf8Array`] from an iterator of trusted len.
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged.
+    /// # Safety
+    /// The iterator must be trusted len.
+    #[inline]
+    pub unsafe fn try_extend_trusted_len_unchecked<I, P>(&mut self, iterator: I) -> Result<()>
     where
         P: AsRef<str>,
         I: Iterator<Item = Option<P>>,
@@ -344,7 +471,7 @@ impl<O: Offset> MutableUtf8Array<O> {
         }
 
         let iterator = iterator.map(|x| x.map(StrAsBytes));
-        extend_from_trusted_len_iter(
+        let result = try_extend_from_trusted_len_iter(
             &mut self.offsets,
             &mut self.values,
             self.validity.as_mut().unwrap(),
@@ -354,6 +481,7 @@ impl<O: Offset> MutableUtf8Array<O> {
         if self.validity.as_mut().unwrap().null_count() == 0 {
             self.validity = None;
         }
+        result
     }
 
     /// Creates a [`MutableUtf8Array`] from an iterator of trusted length.
@@ -482,15 +610,19 @@ impl<O: Offset, T: AsRef<str>> TryExtend<Option<T>> for MutableUtf8Array<O> {
 }
 
 impl<O: Offset, T: AsRef<str>> TryPush<Option<T>> for MutableUtf8Array<O> {
+    /// # Errors
+    /// Errors with [`ArrowError::Overflow`] iff the length of all values (in bytes) exceeds
+    /// `O` maximum value, in which case the array is left unchanged. Use `DataType::LargeUtf8`
+    /// (i.e. `O = i64`) for larger arrays.
     #[inline]
     fn try_push(&mut self, value: Option<T>) -> Result<()> {
         match value {
             Some(value) => {
                 let bytes = value.as_ref().as_bytes();
-                self.values.extend_from_slice(bytes);
-
-                let size = O::from_usize(self.values.len()).ok_or(ArrowError::Overflow)?;
+                let size =
+                    O::from_usize(self.values.len() + bytes.len()).ok_or(ArrowError::Overflow)?;
 
+                self.values.extend_from_slice(bytes);
                 self.offsets.push(size);
 
                 match &mut self.validity {
@@ -3,7 +3,7 @@ use std::ops::{BitAnd, BitOr, BitXor, Not};
 use crate::trusted_len::TrustedLen;
 
 use super::{
-    utils::{BitChunk, BitChunkIterExact, BitChunksExact},
+    utils::{remainder_mask, BitChunk, BitChunkIterExact, BitChunksExact},
     Bitmap,
 };
 
@@ -58,14 +58,15 @@ where
     let rem_a2 = a2_chunks.remainder();
     let rem_a3 = a3_chunks.remainder();
     let rem_a4 = a4_chunks.remainder();
+    let mask = remainder_mask::<u64>(a1_chunks.remainder_len());
 
     let chunks = a1_chunks
         .zip(a2_chunks)
         .zip(a3_chunks)
         .zip(a4_chunks)
         .map(|(((a1, a2), a3), a4)| op(a1, a2, a3, a4));
-    let buffer =
-        chunk_iter_to_vec(chunks.chain(std::iter::once(op(rem_a1, rem_a2, rem_a3, rem_a4))));
+    let rem = op(rem_a1, rem_a2, rem_a3, rem_a4) & mask;
+    let buffer = chunk_iter_to_vec(chunks.chain(std::iter::once(rem)));
 
     let length = a1.len();
 
@@ -86,13 +87,15 @@ where
     let rem_a1 = a1_chunks.remainder();
     let rem_a2 = a2_chunks.remainder();
     let rem_a3 = a3_chunks.remainder();
+    let mask = remainder_mask::<u64>(a1_chunks.remainder_len());
 
     let chunks = a1_chunks
         .zip(a2_chunks)
         .zip(a3_chunks)
         .map(|((a1, a2), a3)| op(a1, a2, a3));
 
-    let buffer = chunk_iter_to_vec(chunks.chain(std::iter::once(op(rem_a1, rem_a2, rem_a3))));
+    let rem = op(rem_a1, rem_a2, rem_a3) & mask;
+    let buffer = chunk_iter_to_vec(chunks.chain(std::iter::once(rem)));
 
     let length = a1.len();
 
@@ -109,12 +112,14 @@ where
     let rhs_chunks = rhs.chunks();
     let rem_lhs = lhs_chunks.remainder();
     let rem_rhs = rhs_chunks.remainder();
+    let mask = remainder_mask::<u64>(lhs_chunks.remainder_len());
 
     let chunks = lhs_chunks
         .zip(rhs_chunks)
         .map(|(left, right)| op(left, right));
 
-    let buffer = chunk_iter_to_vec(chunks.chain(std::iter::once(op(rem_lhs, rem_rhs))));
+    let rem = op(rem_lhs, rem_rhs) & mask;
+    let buffer = chunk_iter_to_vec(chunks.chain(std::iter::once(rem)));
 
     let length = lhs.len();
 
@@ -126,7 +131,7 @@ where
     I: BitChunkIterExact<u64>,
     F: Fn(u64) -> u64,
 {
-    let rem = op(iter.remainder());
+    let rem = op(iter.remainder()) & remainder_mask::<u64>(iter.remainder_len());
 
     let iterator = iter.map(op).chain(std::iter::once(rem));
 
@@ -162,21 +167,39 @@ pub(crate) fn align(bitmap: &Bitmap, new_offset: usize) -> Bitmap {
     bitmap.slice(new_offset, length)
 }
 
+/// Returns the bitwise `AND` of two [`Bitmap`]s, computed 64 bits at a time.
+/// The bitmaps may have different offsets.
+/// # Panic
+/// Panics iff `lhs.len() != rhs.len()`.
 #[inline]
-fn and(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
+pub fn and(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     binary(lhs, rhs, |x, y| x & y)
 }
 
+/// Returns the bitwise `OR` of two [`Bitmap`]s, computed 64 bits at a time.
+/// The bitmaps may have different offsets.
+/// # Panic
+/// Panics iff `lhs.len() != rhs.len()`.
 #[inline]
-fn or(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
+pub fn or(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     binary(lhs, rhs, |x, y| x | y)
 }
 
+/// Returns the bitwise `XOR` of two [`Bitmap`]s, computed 64 bits at a time.
+/// The bitmaps may have different offsets.
+/// # Panic
+/// Panics iff `lhs.len() != rhs.len()`.
 #[inline]
-fn xor(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
+pub fn xor(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     binary(lhs, rhs, |x, y| x ^ y)
 }
 
+/// Returns the bitwise `NOT` of a [`Bitmap`], computed 64 bits at a time.
+#[inline]
+pub fn not(bitmap: &Bitmap) -> Bitmap {
+    unary(bitmap, |x| !x)
+}
+
 fn eq(lhs: &Bitmap, rhs: &Bitmap) -> bool {
     if lhs.len() != rhs.len() {
         return false;
@@ -232,6 +255,6 @@ impl Not for &Bitmap {
     type Output = Bitmap;
 
     fn not(self) -> Bitmap {
-        unary(self, |a| !a)
+        not(self)
     }
 }
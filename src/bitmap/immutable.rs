@@ -1,11 +1,19 @@
 use either::Either;
 use std::iter::FromIterator;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
-use crate::{buffer::bytes::Bytes, trusted_len::TrustedLen};
+use crate::{
+    buffer::bytes::{Bytes, Deallocation},
+    trusted_len::TrustedLen,
+};
 
 use super::{
-    utils::{count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter},
+    utils::{
+        count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter,
+        SlicesIterator,
+    },
     MutableBitmap,
 };
 
@@ -15,14 +23,28 @@ use super::{
 /// * memory on this container is sharable across thread boundaries
 /// * Cloning [`Bitmap`] is `O(1)`
 /// * Slicing [`Bitmap`] is `O(1)`
-#[derive(Clone)]
+/// * [`Bitmap::null_count`] is `O(1)` after it was first called on a slice
 pub struct Bitmap {
     bytes: Arc<Bytes<u8>>,
     // both are measured in bits. They are used to bound the bitmap to a region of Bytes.
     offset: usize,
     length: usize,
-    // this is a cache: it must be computed on initialization
-    null_count: usize,
+    // this is a cache: `UNKNOWN_NULL_COUNT` until it is computed
+    null_count: AtomicUsize,
+}
+
+/// Sentinel of [`Bitmap`]'s cached null count when it was not computed yet
+const UNKNOWN_NULL_COUNT: usize = usize::MAX;
+
+impl Clone for Bitmap {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            offset: self.offset,
+            length: self.length,
+            null_count: AtomicUsize::new(self.null_count.load(Ordering::Relaxed)),
+        }
+    }
 }
 
 impl std::fmt::Debug for Bitmap {
@@ -74,10 +96,29 @@ impl Bitmap {
             length,
             offset: 0,
             bytes: Arc::new(bytes),
-            null_count,
+            null_count: AtomicUsize::new(null_count),
         }
     }
 
+    /// Creates a new [`Bitmap`] of `length` bits backed by the `bytes` bytes of `owner` starting at
+    /// byte `offset`, without copying them.
+    /// Returns `None` iff the region is out of bounds of `owner` or has less than `length` bits.
+    pub(crate) fn from_owner_region<O: AsRef<[u8]> + Send + Sync + 'static>(
+        owner: Arc<O>,
+        offset: usize,
+        bytes: usize,
+        length: usize,
+    ) -> Option<Self> {
+        if length > bytes.checked_mul(8)? {
+            return None;
+        }
+        let region = (*owner).as_ref().get(offset..offset.checked_add(bytes)?)?;
+        let ptr = NonNull::new(region.as_ptr() as *mut u8)?;
+        // Safety: the region is borrowed from `owner`, which is immutable and kept alive
+        let bytes = unsafe { Bytes::from_ffi(ptr, bytes, Deallocation::Owner(owner)) };
+        Some(Self::from_bytes(bytes, length))
+    }
+
     /// Creates a new [`Bitmap`] from [`Vec`] and a length.
     /// This function is `O(1)`
     /// # Panic
@@ -103,9 +144,35 @@ impl Bitmap {
     }
 
     /// Returns the number of unset bits on this [`Bitmap`].
+    /// # Implementation
+    /// The count of a sliced [`Bitmap`] is computed on the first call, and cached.
     #[inline]
     pub fn null_count(&self) -> usize {
-        self.null_count
+        match self.null_count.load(Ordering::Relaxed) {
+            UNKNOWN_NULL_COUNT => {
+                let null_count = count_zeros(&self.bytes, self.offset, self.length);
+                self.null_count.store(null_count, Ordering::Relaxed);
+                null_count
+            }
+            null_count => null_count,
+        }
+    }
+
+    /// Counts the unset bits starting from `offset` bits and for `length` bits.
+    /// # Panic
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn count_zeros(&self, offset: usize, length: usize) -> usize {
+        assert!(offset + length <= self.length);
+        count_zeros(&self.bytes, self.offset + offset, length)
+    }
+
+    /// Counts the set bits starting from `offset` bits and for `length` bits.
+    /// # Panic
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn count_ones(&self, offset: usize, length: usize) -> usize {
+        length - self.count_zeros(offset, length)
     }
 
     /// Slices `self`, offsetting by `offset` and truncating up to `length` bits.
@@ -123,17 +190,19 @@ impl Bitmap {
     /// The caller must ensure that `self.offset + offset + length <= self.len()`
     #[inline]
     pub unsafe fn slice_unchecked(mut self, offset: usize, length: usize) -> Self {
-        // count the smallest chunk
-        if length < self.length / 2 {
-            // count the null values in the slice
-            self.null_count = count_zeros(&self.bytes, self.offset + offset, length);
+        // the null count of the slice is known without counting when the slice is the whole
+        // bitmap or the bitmap has only set or only unset bits. Else it is counted lazily.
+        let null_count = *self.null_count.get_mut();
+        let null_count = if length == self.length {
+            null_count
+        } else if length == 0 || null_count == 0 {
+            0
+        } else if null_count == self.length {
+            length
         } else {
-            // subtract the null count of the chunks we slice off
-            let start_end = self.offset + offset + length;
-            let head_count = count_zeros(&self.bytes, self.offset, offset);
-            let tail_count = count_zeros(&self.bytes, start_end, self.length - length - offset);
-            self.null_count -= head_count + tail_count;
-        }
+            UNKNOWN_NULL_COUNT
+        };
+        *self.null_count.get_mut() = null_count;
         self.offset += offset;
         self.length = length;
         self
@@ -181,16 +250,24 @@ impl Bitmap {
     /// is not possible
     ///
     /// This operation returns a [`MutableBitmap`] iff:
-    /// * this [`Bitmap`] is not an offsetted slice of another [`Bitmap`]
+    /// * this [`Bitmap`] is not a slice of another [`Bitmap`]
     /// * this [`Bitmap`] has not been cloned (i.e. [`Arc`]`::get_mut` yields [`Some`])
     /// * this [`Bitmap`] was not imported from the c data interface (FFI)
     pub fn into_mut(mut self) -> Either<Self, MutableBitmap> {
+        let bytes_len = self.length.saturating_add(7) / 8;
         match (
             self.offset,
             Arc::get_mut(&mut self.bytes).and_then(|b| b.get_vec()),
         ) {
-            (0, Some(v)) => {
-                let data = std::mem::take(v);
+            (0, Some(v)) if v.len() == bytes_len => {
+                let mut data = std::mem::take(v);
+                // a slice may hide bits of the last byte: clear them
+                let bits = self.length % 8;
+                if bits > 0 {
+                    if let Some(last) = data.last_mut() {
+                        *last &= (1u8 << bits) - 1;
+                    }
+                }
                 Either::Right(MutableBitmap::from_vec(data, self.length))
             }
             _ => Either::Left(self),
@@ -216,9 +293,35 @@ impl FromIterator<bool> for Bitmap {
 impl Bitmap {
     /// Returns an iterator over bits in chunks of `T`, which is useful for
     /// bit operations.
+    ///
+    /// The chunks start at the first bit of this [`Bitmap`], also when it is sliced.
+    /// The last `self.len() % (size_of::<T>() * 8)` bits are in the remainder of the iterator.
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    /// let bitmap = Bitmap::from([true, false, true, true, false, false, false, false, true, true]);
+    /// let bitmap = bitmap.slice(1, 9);
+    /// let chunks = bitmap.chunks::<u8>();
+    /// assert_eq!(chunks.remainder(), 0b1);
+    /// assert_eq!(chunks.remainder_len(), 1);
+    /// assert_eq!(chunks.collect::<Vec<_>>(), vec![0b10000110]);
+    /// ```
     pub fn chunks<T: BitChunk>(&self) -> BitChunks<T> {
         BitChunks::new(&self.bytes, self.offset, self.length)
     }
+
+    /// Returns an iterator over the maximal runs of set bits of this [`Bitmap`] as
+    /// `(start, length)`, where `start` is relative to the first bit of this [`Bitmap`].
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    /// let bitmap = Bitmap::from([false, true, true, false, true, true, true, false]);
+    /// let bitmap = bitmap.slice(1, 6);
+    /// assert_eq!(bitmap.slices().collect::<Vec<_>>(), vec![(0, 2), (3, 3)]);
+    /// ```
+    pub fn slices(&self) -> SlicesIterator {
+        SlicesIterator::new(self)
+    }
 }
 
 impl Bitmap {
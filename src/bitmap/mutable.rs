@@ -4,8 +4,8 @@ use std::iter::FromIterator;
 use crate::bitmap::utils::{merge_reversed, set_bit_unchecked};
 use crate::trusted_len::TrustedLen;
 
-use super::utils::{count_zeros, fmt, get_bit, set, set_bit, BitmapIter};
-use super::Bitmap;
+use super::utils::{count_zeros, fmt, get_bit, set, set_bit, BitChunks, BitmapIter};
+use super::{chunk_iter_to_vec, Bitmap};
 
 /// A container to store booleans. [`MutableBitmap`] is semantically equivalent
 /// to [`Vec<bool>`], but each value is stored as a single bit, thereby achieving a compression of 8x.
@@ -85,6 +85,24 @@ impl MutableBitmap {
         self.length += 1;
     }
 
+    /// Pops the last bit from the [`MutableBitmap`].
+    /// Returns `None` if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<bool> {
+        if self.is_empty() {
+            return None;
+        }
+        self.length -= 1;
+        let value = self.get(self.length);
+        if self.length % 8 == 0 {
+            self.buffer.pop();
+        } else {
+            let byte = self.buffer.as_mut_slice().last_mut().unwrap();
+            *byte = set(*byte, self.length % 8, false);
+        }
+        Some(value)
+    }
+
     /// Returns the capacity of [`MutableBitmap`] in number of bits.
     #[inline]
     pub fn capacity(&self) -> usize {
@@ -110,6 +128,23 @@ impl MutableBitmap {
         count_zeros(&self.buffer, 0, self.length)
     }
 
+    /// Counts the unset bits starting from `offset` bits and for `length` bits.
+    /// # Panic
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn count_zeros(&self, offset: usize, length: usize) -> usize {
+        assert!(offset + length <= self.length);
+        count_zeros(&self.buffer, offset, length)
+    }
+
+    /// Counts the set bits starting from `offset` bits and for `length` bits.
+    /// # Panic
+    /// Panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn count_ones(&self, offset: usize, length: usize) -> usize {
+        length - self.count_zeros(offset, length)
+    }
+
     /// Returns the length of the [`MutableBitmap`].
     #[inline]
     pub fn len(&self) -> usize {
@@ -423,6 +458,9 @@ impl MutableBitmap {
     ) {
         // the length of the iterator throughout this function.
         let mut length = iterator.size_hint().1.unwrap();
+        if length == 0 {
+            return;
+        }
 
         let bit_offset = self.length % 8;
 
@@ -577,11 +615,36 @@ impl MutableBitmap {
         self.length += length;
     }
 
+    fn extend_shifted(&mut self, slice: &[u8], offset: usize, length: usize) {
+        // the source is not byte-aligned: shift it into aligned words
+        let mut chunks = BitChunks::<u64>::new(slice, offset, length);
+        let remainder_len = chunks.remainder_len();
+        let remainder = if remainder_len == 0 {
+            0
+        } else {
+            chunks.remainder() & (u64::MAX >> (64 - remainder_len))
+        };
+        let remainder_bytes = remainder_len.saturating_add(7) / 8;
+
+        if self.length % 8 == 0 {
+            self.buffer.reserve(length.saturating_add(7) / 8);
+            chunks
+                .by_ref()
+                .for_each(|chunk| self.buffer.extend_from_slice(&chunk.to_ne_bytes()));
+            self.buffer
+                .extend_from_slice(&remainder.to_ne_bytes()[..remainder_bytes]);
+            self.length += length;
+        } else {
+            let aligned = chunk_iter_to_vec(chunks.chain(std::iter::once(remainder)));
+            self.extend_unaligned(&aligned, 0, length);
+        }
+    }
+
     /// Extends the [`MutableBitmap`] from a slice of bytes with optional offset.
     /// This is the fastest way to extend a [`MutableBitmap`].
     /// # Implementation
     /// When both [`MutableBitmap`]'s length and `offset` are both multiples of 8,
-    /// this function performs a memcopy. Else, it extends [`MutableBitmap`] bit by bit.
+    /// this function performs a memcopy. Else, it shifts the bytes of `slice` 64 bits at a time.
     #[inline]
     pub fn extend_from_slice(&mut self, slice: &[u8], offset: usize, length: usize) {
         assert!(offset + length <= slice.len() * 8);
@@ -593,18 +656,26 @@ impl MutableBitmap {
         match (is_aligned, other_is_aligned) {
             (true, true) => self.extend_aligned(slice, offset, length),
             (false, true) => self.extend_unaligned(slice, offset, length),
-            // todo: further optimize the other branches.
-            _ => self.extend_from_trusted_len_iter(BitmapIter::new(slice, offset, length)),
+            _ => self.extend_shifted(slice, offset, length),
         }
         // internal invariant:
         debug_assert_eq!(self.length.saturating_add(7) / 8, self.buffer.len());
     }
 
-    /// Extends the [`MutableBitmap`] from a [`Bitmap`].
+    /// Extends the [`MutableBitmap`] from `length` bits of a [`Bitmap`] starting at `offset`.
+    /// # Panic
+    /// Panics iff `offset + length > bitmap.len()`.
+    #[inline]
+    pub fn extend_from_bitmap(&mut self, bitmap: &Bitmap, offset: usize, length: usize) {
+        assert!(offset + length <= bitmap.len());
+        let (slice, bitmap_offset, _) = bitmap.as_slice();
+        self.extend_from_slice(slice, bitmap_offset + offset, length);
+    }
+
+    /// Extends the [`MutableBitmap`] from a slice of booleans.
     #[inline]
-    pub fn extend_from_bitmap(&mut self, bitmap: &Bitmap) {
-        let (slice, offset, length) = bitmap.as_slice();
-        self.extend_from_slice(slice, offset, length);
+    pub fn extend_from_slice_of_bools(&mut self, slice: &[bool]) {
+        self.extend_from_trusted_len_iter(slice.iter().copied())
     }
 
     /// Returns the slice of bytes of this [`MutableBitmap`].
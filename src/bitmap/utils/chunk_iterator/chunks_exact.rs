@@ -2,9 +2,23 @@ use std::{convert::TryInto, slice::ChunksExact};
 
 use crate::trusted_len::TrustedLen;
 
-use super::{BitChunk, BitChunkIterExact};
+use super::{remainder_mask, BitChunk, BitChunkIterExact};
 
 /// An iterator over a slice of bytes in [`BitChunk`]s.
+///
+/// Contrarily to [`BitChunks`](super::BitChunks), the bits always start at the first bit of
+/// `slice`. When the bits of a bitmap start at a non-zero offset, use
+/// [`BitChunks`](super::BitChunks) instead.
+/// The last `len % (size_of::<T>() * 8)` bits are not yielded by the iterator; they are
+/// available via [`BitChunksExact::remainder`], whose bits past its `remainder_len` are unset.
+/// # Example
+/// ```
+/// use arrow2::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+/// let iter = BitChunksExact::<u8>::new(&[0b11110101, 0b11111110], 11);
+/// assert_eq!(iter.remainder(), 0b00000110);
+/// assert_eq!(iter.remainder_len(), 3);
+/// assert_eq!(iter.collect::<Vec<_>>(), vec![0b11110101]);
+/// ```
 #[derive(Debug)]
 pub struct BitChunksExact<'a, T: BitChunk> {
     iter: ChunksExact<'a, u8>,
@@ -14,7 +28,9 @@ pub struct BitChunksExact<'a, T: BitChunk> {
 }
 
 impl<'a, T: BitChunk> BitChunksExact<'a, T> {
-    /// Creates a new [`BitChunksExact`].
+    /// Creates a new [`BitChunksExact`] over the first `len` bits of `slice`.
+    /// # Panics
+    /// Panics iff `len > slice.len() * 8`.
     #[inline]
     pub fn new(slice: &'a [u8], len: usize) -> Self {
         assert!(len <= slice.len() * 8);
@@ -22,8 +38,8 @@ impl<'a, T: BitChunk> BitChunksExact<'a, T> {
 
         let split = (len / 8 / size_of) * size_of;
         let chunks = &slice[..split];
-        let remainder = &slice[split..];
         let remainder_len = len - chunks.len() * 8;
+        let remainder = &slice[split..split + remainder_len.saturating_add(7) / 8];
         let iter = chunks.chunks_exact(size_of);
 
         Self {
@@ -46,7 +62,7 @@ impl<'a, T: BitChunk> BitChunksExact<'a, T> {
         self.len() == 0
     }
 
-    /// Returns the remaining [`BitChunk`]. It is zero iff `len / 8 == 0`.
+    /// Returns the remaining [`BitChunk`], whose bits past the remainder's length are unset.
     #[inline]
     pub fn remainder(&self) -> T {
         let remainder_bytes = self.remainder;
@@ -64,7 +80,7 @@ impl<'a, T: BitChunk> BitChunksExact<'a, T> {
                 remainder
             }
         };
-        T::from_ne_bytes(remainder)
+        T::from_ne_bytes(remainder) & remainder_mask(self.remainder_len)
     }
 }
 
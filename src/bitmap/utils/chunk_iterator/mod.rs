@@ -26,6 +26,21 @@ pub trait BitChunkIterExact<B: BitChunk>: TrustedLen<Item = B> {
 
 /// This struct is used to efficiently iterate over bit masks by loading bytes on
 /// the stack with alignments of `uX`. This allows efficient iteration over bitmaps.
+///
+/// The bits start at `offset`, which does not need to be a multiple of 8: each item contains
+/// the next `size_of::<T>() * 8` bits, with the first bit as its least significant bit.
+/// The last `len % (size_of::<T>() * 8)` bits are not yielded by the iterator; they are
+/// available via [`BitChunks::remainder`], whose bits past [`BitChunks::remainder_len`] are
+/// unset.
+/// # Example
+/// ```
+/// use arrow2::bitmap::utils::BitChunks;
+/// // 10 bits starting at the 2nd bit of the first byte
+/// let iter = BitChunks::<u8>::new(&[0b11110101, 0b00000110], 1, 10);
+/// assert_eq!(iter.remainder(), 0b00000011);
+/// assert_eq!(iter.remainder_len(), 2);
+/// assert_eq!(iter.collect::<Vec<_>>(), vec![0b01111010]);
+/// ```
 #[derive(Debug)]
 pub struct BitChunks<'a, T: BitChunk> {
     chunk_iterator: std::slice::ChunksExact<'a, u8>,
@@ -39,6 +54,17 @@ pub struct BitChunks<'a, T: BitChunk> {
     phantom: std::marker::PhantomData<T>,
 }
 
+/// Returns a [`BitChunk`] whose lowest `len` bits are set, used to clear the bits of a
+/// remainder that are past the end of a bitmap.
+#[inline]
+pub(crate) fn remainder_mask<T: BitChunk>(len: usize) -> T {
+    if len == 0 {
+        T::zero()
+    } else {
+        !T::zero() >> (std::mem::size_of::<T>() * 8 - len)
+    }
+}
+
 /// writes `bytes` into `dst`.
 #[inline]
 fn copy_with_merge<T: BitChunk>(dst: &mut T::Bytes, bytes: &[u8], bit_offset: usize) {
@@ -54,7 +80,9 @@ fn copy_with_merge<T: BitChunk>(dst: &mut T::Bytes, bytes: &[u8], bit_offset: us
 }
 
 impl<'a, T: BitChunk> BitChunks<'a, T> {
-    /// Creates a [`BitChunks`].
+    /// Creates a [`BitChunks`] over the `len` bits of `slice` starting at bit `offset`.
+    /// # Panics
+    /// Panics iff `offset + len > slice.len() * 8`.
     pub fn new(slice: &'a [u8], offset: usize, len: usize) -> Self {
         assert!(offset + len <= slice.len() * 8);
 
@@ -133,7 +161,7 @@ impl<'a, T: BitChunk> BitChunks<'a, T> {
                 remainder
             }
         };
-        T::from_ne_bytes(remainder)
+        T::from_ne_bytes(remainder) & remainder_mask(self.remainder_len())
     }
 
     /// Returns the remainder bits in [`BitChunks::remainder`].
@@ -198,7 +226,7 @@ impl<T: BitChunk> BitChunkIterExact<T> for BitChunks<'_, T> {
 impl<T: BitChunk> ExactSizeIterator for BitChunks<'_, T> {
     #[inline]
     fn len(&self) -> usize {
-        self.chunk_iterator.len()
+        self.remaining
     }
 }
 
@@ -5,9 +5,7 @@ mod iterator;
 mod slice_iterator;
 mod zip_validity;
 
-use std::convert::TryInto;
-
-pub(crate) use chunk_iterator::merge_reversed;
+pub(crate) use chunk_iterator::{merge_reversed, remainder_mask};
 pub use chunk_iterator::{BitChunk, BitChunkIterExact, BitChunks, BitChunksExact};
 pub use fmt::fmt;
 pub use iterator::BitmapIter;
@@ -118,20 +116,18 @@ pub fn count_zeros(slice: &[u8], offset: usize, len: usize) -> usize {
         slice = &slice[..last_index];
     }
 
-    // finally, count any and all bytes in the middle in groups of 8
-    let mut chunks = slice.chunks_exact(8);
-    set_count += chunks
-        .by_ref()
-        .map(|chunk| {
-            let a = u64::from_ne_bytes(chunk.try_into().unwrap());
-            a.count_ones() as usize
-        })
+    // finally, count the bytes in the middle over aligned words
+    // Safety: every bit pattern of 8 bytes is a valid `u64`
+    let (head, words, tail) = unsafe { slice.align_to::<u64>() };
+    set_count += words
+        .iter()
+        .map(|word| word.count_ones() as usize)
         .sum::<usize>();
 
-    // and any bytes that do not fit in the group
-    set_count += chunks
-        .remainder()
+    // and any bytes before and after the aligned words
+    set_count += head
         .iter()
+        .chain(tail.iter())
         .map(|byte| byte.count_ones() as usize)
         .sum::<usize>();
 
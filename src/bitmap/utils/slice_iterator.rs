@@ -13,6 +13,17 @@ enum State {
 /// This is the most efficient method to extract slices of values from arrays
 /// with a validity bitmap.
 /// For example, the bitmap `00101111` returns `[(0,4), (6,1)]`
+///
+/// Each item is a maximal run of set bits as `(start, length)`. `start` is relative to the first
+/// bit of the [`Bitmap`], also when the [`Bitmap`] is sliced.
+/// # Example
+/// ```
+/// use arrow2::bitmap::{utils::SlicesIterator, Bitmap};
+/// let bitmap = Bitmap::from([true, true, false, true, true, false, true]).slice(1, 6);
+/// let iter = SlicesIterator::new(&bitmap);
+/// assert_eq!(iter.slots(), 4);
+/// assert_eq!(iter.collect::<Vec<_>>(), vec![(0, 1), (2, 2), (5, 1)]);
+/// ```
 #[derive(Debug, Clone)]
 pub struct SlicesIterator<'a> {
     values: std::slice::Iter<'a, u8>,
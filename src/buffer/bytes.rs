@@ -1,6 +1,7 @@
 //! This module contains an implementation of a contiguous immutable memory region that knows
 //! how to de-allocate itself, [`Bytes`].
 
+use std::any::Any;
 use std::{fmt::Debug, fmt::Formatter};
 use std::{ptr::NonNull, sync::Arc};
 
@@ -14,6 +15,10 @@ pub enum Deallocation {
     Native,
     // Foreign interface, via a callback
     Foreign(Arc<ffi::InternalArrowArray>),
+    /// Memory owned by a Rust object, released when the last reference to the object is dropped
+    // the field is never read: it keeps the owner alive while the memory is in use
+    #[allow(dead_code)]
+    Owner(Arc<dyn Any + Send + Sync>),
 }
 
 impl Debug for Deallocation {
@@ -25,6 +30,9 @@ impl Debug for Deallocation {
             Deallocation::Foreign(_) => {
                 write!(f, "Deallocation::Foreign {{ capacity: unknown }}")
             }
+            Deallocation::Owner(_) => {
+                write!(f, "Deallocation::Owner {{ capacity: unknown }}")
+            }
         }
     }
 }
@@ -58,14 +66,14 @@ impl<T: NativeType> Bytes<T> {
     ///
     /// # Panics
     ///
-    /// This function panics if the give deallocation not is `Deallocation::Foreign`
+    /// This function panics if the give deallocation is `Deallocation::Native`
     #[inline]
     pub unsafe fn from_ffi(
         ptr: std::ptr::NonNull<T>,
         len: usize,
         deallocation: Deallocation,
     ) -> Self {
-        assert!(matches!(deallocation, Deallocation::Foreign(_)));
+        assert!(!matches!(deallocation, Deallocation::Native));
         // This line is technically outside the assumptions of `Vec::from_raw_parts`, since
         // `ptr` was not allocated by `Vec`. However, one of the invariants of this struct
         // is that we do not expose this region as a `Vec`; we only use `Vec` on it to provide
@@ -96,7 +104,7 @@ impl<T: NativeType> Bytes<T> {
     /// Returns `None` if allocated by a foreign interface.
     pub fn get_vec(&mut self) -> Option<&mut Vec<T>> {
         match &self.deallocation {
-            Deallocation::Foreign(_) => None,
+            Deallocation::Foreign(_) | Deallocation::Owner(_) => None,
             // Safety:
             // The allocation is native so we can share the vec
             Deallocation::Native => Some(unsafe { self.data.mut_vec() }),
@@ -109,6 +117,8 @@ impl<T: NativeType> Drop for Bytes<T> {
         match self.deallocation {
             // a foreign interface knows how to deallocate itself
             Deallocation::Foreign(_) => {}
+            // the owner is released when the last reference to it is dropped
+            Deallocation::Owner(_) => {}
             Deallocation::Native => {
                 // Safety:
                 // the allocation is native, so we can safely drop
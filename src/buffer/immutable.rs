@@ -1,9 +1,13 @@
 use either::Either;
-use std::{iter::FromIterator, sync::Arc, usize};
+use std::{any::Any, iter::FromIterator, ptr::NonNull, sync::Arc};
 
-use crate::{trusted_len::TrustedLen, types::NativeType};
+use crate::{
+    error::{ArrowError, Result},
+    trusted_len::TrustedLen,
+    types::NativeType,
+};
 
-use super::bytes::Bytes;
+use super::bytes::{Bytes, Deallocation};
 
 /// [`Buffer`] is a contiguous memory region that can
 /// be shared across thread boundaries.
@@ -60,6 +64,103 @@ impl<T: NativeType> Buffer<T> {
         data.as_ref().to_vec().into()
     }
 
+    /// Creates a [`Buffer`] backed by `len` items starting at `ptr`, a memory region owned by
+    /// `owner`, without copying it. `owner` is kept alive until the last [`Buffer`] referencing the
+    /// region is dropped.
+    ///
+    /// This can be used to back arrays by memory owned by other code, e.g. a memory-mapped file.
+    /// # Errors
+    /// This function errors iff `len > 0` and `ptr` is null or is not aligned to the alignment
+    /// of `T`.
+    /// # Safety
+    /// The caller must ensure that:
+    /// * `ptr` is valid for reads of `len * size_of::<T>()` bytes for as long as `owner` is alive
+    /// * the region is not mutated for as long as `owner` is alive
+    pub unsafe fn from_foreign(
+        ptr: *const T,
+        len: usize,
+        owner: Arc<dyn Any + Send + Sync>,
+    ) -> Result<Self> {
+        if len == 0 {
+            return Ok(Self::new());
+        }
+        let ptr = NonNull::new(ptr as *mut T).ok_or_else(|| {
+            ArrowError::InvalidArgumentError("A foreign buffer cannot be null".to_string())
+        })?;
+        if !(ptr.as_ptr() as usize).is_multiple_of(std::mem::align_of::<T>()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A foreign buffer of {} must be aligned to {} bytes",
+                std::any::type_name::<T>(),
+                std::mem::align_of::<T>()
+            )));
+        }
+        Ok(Self::from_bytes(Bytes::from_ffi(
+            ptr,
+            len,
+            Deallocation::Owner(owner),
+        )))
+    }
+
+    /// Creates a [`Buffer`] backed by the bytes of `owner`, e.g. a memory-mapped file or a
+    /// `bytes::Bytes`, without copying them.
+    /// # Errors
+    /// This function errors iff the bytes are not aligned to the alignment of `T` or their length
+    /// is not a multiple of the size of `T`.
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow2::buffer::Buffer;
+    /// # fn main() -> arrow2::error::Result<()> {
+    /// let owner = Arc::new(vec![1u8, 2, 3]);
+    /// let buffer = Buffer::<u8>::from_owner(owner.clone())?;
+    /// assert_eq!(buffer.as_slice(), &[1, 2, 3]);
+    /// assert_eq!(buffer.as_slice().as_ptr(), owner.as_ptr());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_owner<O: AsRef<[u8]> + Send + Sync + 'static>(owner: Arc<O>) -> Result<Self> {
+        let bytes: &[u8] = (*owner).as_ref();
+        let size = std::mem::size_of::<T>();
+        if !bytes.len().is_multiple_of(size) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A foreign buffer of {} must have a length multiple of {} bytes",
+                std::any::type_name::<T>(),
+                size
+            )));
+        }
+        let (ptr, len) = (bytes.as_ptr() as *const T, bytes.len() / size);
+        // Safety: the region is borrowed from `owner`, which is immutable and kept alive
+        unsafe { Self::from_foreign(ptr, len, owner) }
+    }
+
+    /// Creates a [`Buffer`] of `len` items backed by the bytes of `owner` starting at byte `offset`,
+    /// without copying them.
+    /// # Errors
+    /// This function errors iff the region is out of bounds of the bytes of `owner` or it is not
+    /// aligned to the alignment of `T`.
+    pub fn from_owner_region<O: AsRef<[u8]> + Send + Sync + 'static>(
+        owner: Arc<O>,
+        offset: usize,
+        len: usize,
+    ) -> Result<Self> {
+        let bytes: &[u8] = (*owner).as_ref();
+        let region = len
+            .checked_mul(std::mem::size_of::<T>())
+            .and_then(|size| offset.checked_add(size))
+            .and_then(|end| bytes.get(offset..end))
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "A foreign buffer of {} {} at byte {} is out of bounds of its {} bytes",
+                    len,
+                    std::any::type_name::<T>(),
+                    offset,
+                    bytes.len()
+                ))
+            })?;
+        // Safety: the region is borrowed from `owner`, which is immutable and kept alive
+        unsafe { Self::from_foreign(region.as_ptr() as *const T, len, owner) }
+    }
+
     /// Auxiliary method to create a new Buffer
     pub(crate) fn from_bytes(bytes: Bytes<T>) -> Self {
         let length = bytes.len();
@@ -135,11 +236,11 @@ impl<T: NativeType> Buffer<T> {
     /// is not possible
     ///
     /// This operation returns a [`Vec`] iff this [`Buffer`]:
-    /// * is not an offsetted slice of another [`Buffer`]
+    /// * is not a slice of another [`Buffer`]
     /// * has not been cloned (i.e. [`Arc`]`::get_mut` yields [`Some`])
     /// * has not been imported from the c data interface (FFI)
     pub fn into_mut(mut self) -> Either<Self, Vec<T>> {
-        if self.offset != 0 {
+        if self.offset != 0 || self.length != self.data.len() {
             Either::Left(self)
         } else {
             match Arc::get_mut(&mut self.data).and_then(|b| b.get_vec()) {
@@ -223,6 +324,13 @@ impl<T: NativeType> std::ops::Deref for Buffer<T> {
     }
 }
 
+impl<T: NativeType> AsRef<[T]> for Buffer<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
 impl<T: NativeType> FromIterator<T> for Buffer<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
@@ -1,4 +1,9 @@
 //! Contains [`Buffer`], an immutable container for all Arrow physical types (e.g. i32, f64).
+//!
+//! The mutable counterpart of [`Buffer`] is [`Vec`]: a [`Vec`] is converted to a [`Buffer`]
+//! without copying via [`From`]. Decoders that write directly into the allocation can use
+//! [`Vec::spare_capacity_mut`] followed by [`Vec::set_len`], whose contract is that the
+//! new length does not exceed the capacity and that all items up to it are initialized.
 
 mod immutable;
 
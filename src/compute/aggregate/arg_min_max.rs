@@ -0,0 +1,159 @@
+//! Contains kernels returning the index of the minimum or maximum value of an [`Array`].
+use std::cmp::Ordering;
+
+use crate::array::{ord, Array, BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::compute::sort::SortOptions;
+use crate::datatypes::{PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// Returns the index of the first non-null value of `iter` that is not preceded by any smaller
+/// value according to `cmp`.
+fn index_of_first<T, I, F>(iter: I, cmp: F) -> Option<usize>
+where
+    I: Iterator<Item = Option<T>>,
+    F: Fn(&T, &T) -> Ordering,
+{
+    iter.enumerate()
+        .filter_map(|(i, value)| value.map(|value| (i, value)))
+        .reduce(|best, candidate| {
+            // strictly less, so that ties keep the first occurrence
+            if cmp(&candidate.1, &best.1) == Ordering::Less {
+                candidate
+            } else {
+                best
+            }
+        })
+        .map(|(i, _)| i)
+}
+
+fn index_of_first_primitive<T, F>(
+    array: &PrimitiveArray<T>,
+    cmp: F,
+    descending: bool,
+) -> Option<usize>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    if descending {
+        index_of_first(array.iter(), |l, r| cmp(r, l))
+    } else {
+        index_of_first(array.iter(), |l, r| cmp(l, r))
+    }
+}
+
+fn index_of_first_utf8<O: Offset>(array: &Utf8Array<O>, descending: bool) -> Option<usize> {
+    if descending {
+        index_of_first(array.iter(), |l, r| r.cmp(l))
+    } else {
+        index_of_first(array.iter(), |l, r| l.cmp(r))
+    }
+}
+
+fn index_of_first_boolean(array: &BooleanArray, descending: bool) -> Option<usize> {
+    if descending {
+        index_of_first(array.iter(), |l, r| r.cmp(l))
+    } else {
+        index_of_first(array.iter(), |l, r| l.cmp(r))
+    }
+}
+
+macro_rules! dyn_primitive {
+    ($ty:ty, $array:expr, $cmp:expr, $descending:expr) => {{
+        let array = $array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        index_of_first_primitive(array, $cmp, $descending)
+    }};
+}
+
+fn index_of_sorted(array: &dyn Array, descending: bool) -> Result<Option<usize>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => index_of_first_boolean(array.as_any().downcast_ref().unwrap(), descending),
+        Primitive(PrimitiveType::Int8) => dyn_primitive!(i8, array, ord::total_cmp, descending),
+        Primitive(PrimitiveType::Int16) => dyn_primitive!(i16, array, ord::total_cmp, descending),
+        Primitive(PrimitiveType::Int32) => dyn_primitive!(i32, array, ord::total_cmp, descending),
+        Primitive(PrimitiveType::Int64) => dyn_primitive!(i64, array, ord::total_cmp, descending),
+        Primitive(PrimitiveType::Int128) => {
+            dyn_primitive!(i128, array, ord::total_cmp, descending)
+        }
+        Primitive(PrimitiveType::UInt8) => dyn_primitive!(u8, array, ord::total_cmp, descending),
+        Primitive(PrimitiveType::UInt16) => dyn_primitive!(u16, array, ord::total_cmp, descending),
+        Primitive(PrimitiveType::UInt32) => dyn_primitive!(u32, array, ord::total_cmp, descending),
+        Primitive(PrimitiveType::UInt64) => dyn_primitive!(u64, array, ord::total_cmp, descending),
+        Primitive(PrimitiveType::Float32) => {
+            dyn_primitive!(f32, array, ord::total_cmp_f32, descending)
+        }
+        Primitive(PrimitiveType::Float64) => {
+            dyn_primitive!(f64, array, ord::total_cmp_f64, descending)
+        }
+        Utf8 => index_of_first_utf8::<i32>(array.as_any().downcast_ref().unwrap(), descending),
+        LargeUtf8 => index_of_first_utf8::<i64>(array.as_any().downcast_ref().unwrap(), descending),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The index of the extremum does not support type {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
+
+/// Returns the index of the minimum value of `array`, or `None` if `array` is empty or
+/// all its values are null. Nulls are ignored.
+///
+/// When the minimum appears more than once, the index of its first occurrence is returned.
+/// Floats are compared using IEEE 754 totalOrder, like in the [`sort`](crate::compute::sort) kernels,
+/// so that a NaN is larger than any other value.
+/// # Errors
+/// Errors iff the type is not a primitive, utf8 or boolean.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::index_of_min;
+///
+/// let array = PrimitiveArray::from([Some(3i32), None, Some(1), Some(1)]);
+/// assert_eq!(index_of_min(&array).unwrap(), Some(2));
+/// ```
+pub fn index_of_min(array: &dyn Array) -> Result<Option<usize>> {
+    index_of_sorted(array, false)
+}
+
+/// Returns the index of the maximum value of `array`, or `None` if `array` is empty or
+/// all its values are null. Nulls are ignored.
+///
+/// When the maximum appears more than once, the index of its first occurrence is returned.
+/// Floats are compared using IEEE 754 totalOrder, like in the [`sort`](crate::compute::sort) kernels,
+/// so that a NaN is larger than any other value.
+/// # Errors
+/// Errors iff the type is not a primitive, utf8 or boolean.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::index_of_max;
+///
+/// let array = PrimitiveArray::from([Some(3.0f64), None, Some(f64::NAN), Some(1.0)]);
+/// assert_eq!(index_of_max(&array).unwrap(), Some(2));
+/// ```
+pub fn index_of_max(array: &dyn Array) -> Result<Option<usize>> {
+    index_of_sorted(array, true)
+}
+
+/// Returns the index of the value that [`sort`](crate::compute::sort) with `options` places first,
+/// i.e. the result of `ORDER BY ... LIMIT 1`: the index of the minimum when ascending
+/// and of the maximum when descending, or of the first null when `options.nulls_first`
+/// and `array` has nulls.
+///
+/// Ties are broken by the first occurrence. Returns `None` iff `array` is empty.
+/// # Errors
+/// Errors iff the type is not a primitive, utf8 or boolean.
+pub fn index_of_first_sorted(array: &dyn Array, options: &SortOptions) -> Result<Option<usize>> {
+    if options.nulls_first && array.null_count() > 0 {
+        return Ok((0..array.len()).find(|i| array.is_null(*i)));
+    }
+    let index = index_of_sorted(array, options.descending)?;
+    // all values are null and nulls are placed last
+    Ok(index.or_else(|| if array.is_empty() { None } else { Some(0) }))
+}
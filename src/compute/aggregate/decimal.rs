@@ -0,0 +1,94 @@
+//! Aggregations of [`DataType::Decimal`] arrays.
+//!
+//! The results are returned as [`PrimitiveScalar`]s whose [`DataType::Decimal`] carries
+//! the scale (and precision) of the value.
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::scalar::PrimitiveScalar;
+
+use super::super::utils::MAX_PRECISION;
+
+/// The largest value representable with [`MAX_PRECISION`] digits.
+const MAX_VALUE: i128 = 99_999_999_999_999_999_999_999_999_999_999_999_999;
+
+/// The [`DataType`] of the sum of a decimal array: the maximum precision and the scale of `array`.
+fn sum_data_type(array: &PrimitiveArray<i128>) -> DataType {
+    if let DataType::Decimal(_, scale) = array.data_type().to_logical_type() {
+        DataType::Decimal(MAX_PRECISION, *scale)
+    } else {
+        // the only logical type of an `i128` array is a decimal
+        unreachable!()
+    }
+}
+
+/// Returns the sum of the values of a decimal array as a scalar of
+/// [`DataType::Decimal`] with the maximum precision (38) and the scale of the array.
+/// Nulls are ignored; the scalar is null when the array is empty or all its values are null.
+/// # Error
+/// Errors iff the sum has more than 38 digits.
+///
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::sum_decimal;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let a = PrimitiveArray::from([Some(1_25i128), None, Some(-0_50)]).to(DataType::Decimal(5, 2));
+/// let result = sum_decimal(&a).unwrap();
+/// assert_eq!(result, PrimitiveScalar::new(DataType::Decimal(38, 2), Some(0_75)));
+/// ```
+pub fn sum_decimal(array: &PrimitiveArray<i128>) -> Result<PrimitiveScalar<i128>> {
+    let data_type = sum_data_type(array);
+    if array.null_count() == array.len() {
+        return Ok(PrimitiveScalar::new(data_type, None));
+    }
+
+    let overflow = || {
+        ArrowError::InvalidArgumentError(format!(
+            "The sum of the decimal array overflows the maximum precision ({})",
+            MAX_PRECISION
+        ))
+    };
+
+    let sum = array.iter().flatten().try_fold(0i128, |acc, value| {
+        acc.checked_add(*value).ok_or_else(overflow)
+    })?;
+    if sum.abs() > MAX_VALUE {
+        return Err(overflow());
+    }
+
+    Ok(PrimitiveScalar::new(data_type, Some(sum)))
+}
+
+/// Returns the sum of the values of a decimal array like [`sum_decimal`], wrapping around
+/// the bounds of `i128` instead of erroring on overflow.
+/// Note that the result may have more than 38 digits.
+pub fn wrapping_sum_decimal(array: &PrimitiveArray<i128>) -> PrimitiveScalar<i128> {
+    let data_type = sum_data_type(array);
+    if array.null_count() == array.len() {
+        return PrimitiveScalar::new(data_type, None);
+    }
+
+    let sum = array
+        .iter()
+        .flatten()
+        .fold(0i128, |acc, value| acc.wrapping_add(*value));
+
+    PrimitiveScalar::new(data_type, Some(sum))
+}
+
+/// Returns the minimum value of a decimal array as a scalar of the array's [`DataType`].
+/// Nulls are ignored; the scalar is null when the array is empty or all its values are null.
+pub fn min_decimal(array: &PrimitiveArray<i128>) -> PrimitiveScalar<i128> {
+    let min = array.iter().flatten().min().copied();
+    PrimitiveScalar::new(array.data_type().clone(), min)
+}
+
+/// Returns the maximum value of a decimal array as a scalar of the array's [`DataType`].
+/// Nulls are ignored; the scalar is null when the array is empty or all its values are null.
+pub fn max_decimal(array: &PrimitiveArray<i128>) -> PrimitiveScalar<i128> {
+    let max = array.iter().flatten().max().copied();
+    PrimitiveScalar::new(array.data_type().clone(), max)
+}
@@ -0,0 +1,60 @@
+//! Aggregations of [`DataType::Float16`](crate::datatypes::DataType::Float16) arrays.
+//!
+//! [`f16`] has no arithmetic of its own: values are accumulated in [`f32`], which represents
+//! every [`f16`] exactly, and the result is rounded back to [`f16`].
+use crate::array::{Array, PrimitiveArray};
+use crate::types::f16;
+
+/// Returns the sum of the values of `array`, accumulated in [`f32`].
+/// Returns `None` if the array is empty or only contains null values.
+///
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::sum_f16;
+/// use arrow2::types::f16;
+///
+/// // 2048 + 1 is not representable in `f16`, but the running sum is accumulated in `f32`
+/// let values = [2048.0f32, 1.0, 1.0].iter().map(|x| Some(f16::from_f32(*x)));
+/// let array = PrimitiveArray::<f16>::from_trusted_len_iter(values);
+/// assert_eq!(sum_f16(&array), Some(f16::from_f32(2050.0)));
+/// ```
+pub fn sum_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    let sum = if array.null_count() == 0 {
+        array.values().iter().map(|x| x.to_f32()).sum::<f32>()
+    } else {
+        array.iter().flatten().map(|x| x.to_f32()).sum::<f32>()
+    };
+    Some(f16::from_f32(sum))
+}
+
+fn reduce_f16<F: Fn(f32, f32) -> f32>(array: &PrimitiveArray<f16>, op: F) -> Option<f16> {
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    let value = if array.null_count() == 0 {
+        array.values().iter().map(|x| x.to_f32()).reduce(op)
+    } else {
+        array.iter().flatten().map(|x| x.to_f32()).reduce(op)
+    };
+    value.map(f16::from_f32)
+}
+
+/// Returns the minimum value of `array`. Like [`f32::min`], NaNs are ignored unless all
+/// values are NaN.
+/// Returns `None` if the array is empty or only contains null values.
+pub fn min_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    reduce_f16(array, f32::min)
+}
+
+/// Returns the maximum value of `array`. Like [`f32::max`], NaNs are ignored unless all
+/// values are NaN.
+/// Returns `None` if the array is empty or only contains null values.
+pub fn max_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    reduce_f16(array, f32::max)
+}
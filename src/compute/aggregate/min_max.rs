@@ -1,4 +1,5 @@
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
+use crate::compute::utils::boolean_words;
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::error::{ArrowError, Result};
 use crate::scalar::*;
@@ -31,7 +32,7 @@ pub trait SimdOrd<T> {
 }
 
 /// Helper to compute min/max of [`BinaryArray`]
-fn min_max_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
+fn reduce_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
     array: &BinaryArray<O>,
     cmp: F,
 ) -> Option<&[u8]> {
@@ -71,7 +72,7 @@ fn min_max_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
 }
 
 /// Helper to compute min/max of [`Utf8Array`]
-fn min_max_string<O: Offset, F: Fn(&str, &str) -> bool>(
+fn reduce_string<O: Offset, F: Fn(&str, &str) -> bool>(
     array: &Utf8Array<O>,
     cmp: F,
 ) -> Option<&str> {
@@ -234,6 +235,75 @@ where
     reduced.max_element()
 }
 
+fn nonnull_min_max_primitive<T>(values: &[T]) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let chunks = values.chunks_exact(T::Simd::LANES);
+    let remainder = chunks.remainder();
+
+    let (min, max) = chunks.fold(
+        (T::Simd::new_min(), T::Simd::new_max()),
+        |(min, max), chunk| {
+            let chunk = T::Simd::from_chunk(chunk);
+            (min.min(chunk), max.max(chunk))
+        },
+    );
+
+    let min = min.min(T::Simd::from_incomplete_chunk(remainder, T::Simd::MAX));
+    let max = max.max(T::Simd::from_incomplete_chunk(remainder, T::Simd::MIN));
+
+    (min.min_element(), max.max_element())
+}
+
+fn null_min_max_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+    I: BitChunkIterExact<<<T as Simd>::Simd as NativeSimd>::Chunk>,
+{
+    let mut chunks = values.chunks_exact(T::Simd::LANES);
+
+    let (min, max) = chunks.by_ref().zip(validity_masks.by_ref()).fold(
+        (T::Simd::new_min(), T::Simd::new_max()),
+        |(min, max), (chunk, validity_chunk)| {
+            let chunk = T::Simd::from_chunk(chunk);
+            let mask = || <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+            (
+                min.min(chunk.select(mask(), T::Simd::new_min())),
+                max.max(chunk.select(mask(), T::Simd::new_max())),
+            )
+        },
+    );
+
+    let validity_chunk = validity_masks.remainder();
+    let mask = || <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+    let remainder = T::Simd::from_incomplete_chunk(chunks.remainder(), T::Simd::MAX);
+    let min = min.min(remainder.select(mask(), T::Simd::new_min()));
+    let remainder = T::Simd::from_incomplete_chunk(chunks.remainder(), T::Simd::MIN);
+    let max = max.max(remainder.select(mask(), T::Simd::new_max()));
+
+    (min.min_element(), max.max_element())
+}
+
+/// # Panics
+/// iff `values.len() != bitmap.len()`.
+fn null_min_max_primitive<T>(values: &[T], bitmap: &Bitmap) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let (slice, offset, length) = bitmap.as_slice();
+    if offset == 0 {
+        let validity_masks = BitChunksExact::<<T::Simd as NativeSimd>::Chunk>::new(slice, length);
+        null_min_max_primitive_impl(values, validity_masks)
+    } else {
+        let validity_masks = bitmap.chunks::<<T::Simd as NativeSimd>::Chunk>();
+        null_min_max_primitive_impl(values, validity_masks)
+    }
+}
+
 /// Returns the minimum value in the array, according to the natural order.
 /// For floating point arrays any NaN values are considered to be greater than any other non-null value
 pub fn min_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
@@ -278,24 +348,97 @@ where
     })
 }
 
+/// Returns the minimum and maximum values in the array in a single pass,
+/// with the same semantics as [`min_primitive`] and [`max_primitive`].
+///
+/// Returns `None` if the array is empty or only contains null values.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::min_max_primitive;
+///
+/// let a = PrimitiveArray::from([Some(3i32), None, Some(-1), Some(5)]);
+/// assert_eq!(min_max_primitive(&a), Some((-1, 5)));
+/// ```
+pub fn min_max_primitive<T>(array: &PrimitiveArray<T>) -> Option<(T, T)>
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let null_count = array.null_count();
+
+    // Includes case array.len() == 0
+    if null_count == array.len() {
+        return None;
+    }
+    let values = array.values();
+
+    Some(if let Some(validity) = array.validity() {
+        null_min_max_primitive(values, validity)
+    } else {
+        nonnull_min_max_primitive(values)
+    })
+}
+
 /// Returns the maximum value in the binary array, according to the natural order.
+/// Nulls are ignored; returns `None` when the array is empty or all its values are null.
 pub fn max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
-    min_max_binary(array, |a, b| a < b)
+    reduce_binary(array, |a, b| a < b)
 }
 
 /// Returns the minimum value in the binary array, according to the natural order.
+/// Nulls are ignored; returns `None` when the array is empty or all its values are null.
 pub fn min_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
-    min_max_binary(array, |a, b| a > b)
+    reduce_binary(array, |a, b| a > b)
 }
 
 /// Returns the maximum value in the string array, according to the natural order.
+/// Nulls are ignored; returns `None` when the array is empty or all its values are null.
 pub fn max_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
-    min_max_string(array, |a, b| a < b)
+    reduce_string(array, |a, b| a < b)
 }
 
 /// Returns the minimum value in the string array, according to the natural order.
+/// Nulls are ignored; returns `None` when the array is empty or all its values are null.
 pub fn min_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
-    min_max_string(array, |a, b| a > b)
+    reduce_string(array, |a, b| a > b)
+}
+
+/// Helper to compute both the min and max of an iterator in a single pass
+fn reduce_min_max<'a, T, I>(iter: I) -> Option<(&'a T, &'a T)>
+where
+    T: PartialOrd + ?Sized,
+    I: Iterator<Item = &'a T>,
+{
+    iter.fold(None, |acc, item| match acc {
+        None => Some((item, item)),
+        Some((min, max)) => Some((
+            if min > item { item } else { min },
+            if max < item { item } else { max },
+        )),
+    })
+}
+
+/// Returns the minimum and maximum values in the binary array in a single pass,
+/// according to the natural order.
+/// Nulls are ignored; returns `None` when the array is empty or all its values are null.
+pub fn min_max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<(&[u8], &[u8])> {
+    if array.validity().is_some() {
+        reduce_min_max(array.iter().flatten())
+    } else {
+        reduce_min_max(array.values_iter())
+    }
+}
+
+/// Returns the minimum and maximum values in the string array in a single pass,
+/// according to the natural order.
+/// Nulls are ignored; returns `None` when the array is empty or all its values are null.
+pub fn min_max_string<O: Offset>(array: &Utf8Array<O>) -> Option<(&str, &str)> {
+    if array.validity().is_some() {
+        reduce_min_max(array.iter().flatten())
+    } else {
+        reduce_min_max(array.values_iter())
+    }
 }
 
 /// Returns the minimum value in the boolean array.
@@ -348,6 +491,37 @@ pub fn max_boolean(array: &BooleanArray) -> Option<bool> {
         .or(Some(false))
 }
 
+/// Returns the minimum and maximum values in the boolean array in a single pass over
+/// its words, stopping as soon as both `true` and `false` are found.
+/// Nulls are ignored; returns `None` when the array is empty or all its values are null.
+///
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::min_max_boolean,
+/// };
+///
+/// let a = BooleanArray::from(vec![Some(true), None, Some(false)]);
+/// assert_eq!(min_max_boolean(&a), Some((false, true)))
+/// ```
+pub fn min_max_boolean(array: &BooleanArray) -> Option<(bool, bool)> {
+    // short circuit if all nulls / zero length array
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    let mut has_true = false;
+    let mut has_false = false;
+    for (values, validity) in boolean_words(array) {
+        has_true |= values & validity != 0;
+        has_false |= !values & validity != 0;
+        if has_true && has_false {
+            break;
+        }
+    }
+    Some((!has_false, has_true))
+}
+
 macro_rules! dyn_primitive {
     ($ty:ty, $array:expr, $f:ident) => {{
         let array = $array
@@ -372,7 +546,7 @@ macro_rules! dyn_generic {
 /// # Error
 /// Errors iff the type does not support this operation.
 pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
-    Ok(match array.data_type() {
+    Ok(match array.data_type().to_logical_type() {
         DataType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, max_boolean),
         DataType::Int8 => dyn_primitive!(i8, array, max_primitive),
         DataType::Int16 => dyn_primitive!(i16, array, max_primitive),
@@ -391,9 +565,15 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         DataType::UInt16 => dyn_primitive!(u16, array, max_primitive),
         DataType::UInt32 => dyn_primitive!(u32, array, max_primitive),
         DataType::UInt64 => dyn_primitive!(u64, array, max_primitive),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => Box::new(PrimitiveScalar::new(
+            array.data_type().clone(),
+            super::max_f16(array.as_any().downcast_ref().unwrap()),
+        )),
         DataType::Float32 => dyn_primitive!(f32, array, max_primitive),
         DataType::Float64 => dyn_primitive!(f64, array, max_primitive),
+        DataType::Decimal(_, _) => {
+            Box::new(super::max_decimal(array.as_any().downcast_ref().unwrap()))
+        }
         DataType::Utf8 => dyn_generic!(Utf8Array<i32>, Utf8Scalar<i32>, array, max_string),
         DataType::LargeUtf8 => dyn_generic!(Utf8Array<i64>, Utf8Scalar<i64>, array, max_string),
         DataType::Binary => dyn_generic!(BinaryArray<i32>, BinaryScalar<i32>, array, max_binary),
@@ -413,7 +593,7 @@ pub fn max(array: &dyn Array) -> Result<Box<dyn Scalar>> {
 /// # Error
 /// Errors iff the type does not support this operation.
 pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
-    Ok(match array.data_type() {
+    Ok(match array.data_type().to_logical_type() {
         DataType::Boolean => dyn_generic!(BooleanArray, BooleanScalar, array, min_boolean),
         DataType::Int8 => dyn_primitive!(i8, array, min_primitive),
         DataType::Int16 => dyn_primitive!(i16, array, min_primitive),
@@ -432,9 +612,15 @@ pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         DataType::UInt16 => dyn_primitive!(u16, array, min_primitive),
         DataType::UInt32 => dyn_primitive!(u32, array, min_primitive),
         DataType::UInt64 => dyn_primitive!(u64, array, min_primitive),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => Box::new(PrimitiveScalar::new(
+            array.data_type().clone(),
+            super::min_f16(array.as_any().downcast_ref().unwrap()),
+        )),
         DataType::Float32 => dyn_primitive!(f32, array, min_primitive),
         DataType::Float64 => dyn_primitive!(f64, array, min_primitive),
+        DataType::Decimal(_, _) => {
+            Box::new(super::min_decimal(array.as_any().downcast_ref().unwrap()))
+        }
         DataType::Utf8 => dyn_generic!(Utf8Array<i32>, Utf8Scalar<i32>, array, min_string),
         DataType::LargeUtf8 => dyn_generic!(Utf8Array<i64>, Utf8Scalar<i64>, array, min_string),
         DataType::Binary => dyn_generic!(BinaryArray<i32>, BinaryScalar<i32>, array, min_binary),
@@ -443,7 +629,7 @@ pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         }
         _ => {
             return Err(ArrowError::InvalidArgumentError(format!(
-                "The `max` operator does not support type `{:?}`",
+                "The `min` operator does not support type `{:?}`",
                 array.data_type(),
             )))
         }
@@ -5,6 +5,21 @@ pub use sum::*;
 mod min_max;
 pub use min_max::*;
 
+mod decimal;
+pub use decimal::*;
+
+mod float16;
+pub use float16::*;
+
+mod variance;
+pub use variance::*;
+
+mod quantile;
+pub use quantile::*;
+
+mod arg_min_max;
+pub use arg_min_max::*;
+
 mod memory;
 pub use memory::*;
 mod simd;
@@ -0,0 +1,257 @@
+//! Contains the quantile (and median) of [`PrimitiveArray`]s, computed either exactly,
+//! by selection, or approximately, with a [t-digest](https://arxiv.org/abs/1902.04023).
+use num_traits::AsPrimitive;
+
+use crate::array::ord::total_cmp_f64;
+use crate::array::{Array, PrimitiveArray};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// The method used to compute a quantile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantileMethod {
+    /// The exact quantile, computed by selection in `O(n)` time and `O(n)` memory.
+    Exact,
+    /// An approximate quantile, computed with a t-digest in `O(n)` time and constant memory.
+    /// The rank of the result is typically within 1% of the requested rank, and closer
+    /// towards the tails.
+    Approximate,
+}
+
+/// The compression of the t-digest, i.e. the maximum number of centroids is of its order.
+const COMPRESSION: f64 = 100.0;
+/// The number of values buffered before they are merged into the centroids.
+const BUFFER_CAPACITY: usize = 5 * COMPRESSION as usize;
+
+#[derive(Debug, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Merging t-digest with the `k_1` scale function.
+#[derive(Debug)]
+struct TDigest {
+    centroids: Vec<Centroid>,
+    buffer: Vec<f64>,
+    count: usize,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self {
+            centroids: vec![],
+            buffer: Vec::with_capacity(BUFFER_CAPACITY),
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+/// The scale function `k_1`
+#[inline]
+fn scale(q: f64) -> f64 {
+    COMPRESSION / (2.0 * std::f64::consts::PI) * (2.0 * q - 1.0).asin()
+}
+
+/// The inverse of [`scale`], saturated at 1.
+#[inline]
+fn scale_inverse(k: f64) -> f64 {
+    let x = (k * 2.0 * std::f64::consts::PI / COMPRESSION).min(std::f64::consts::FRAC_PI_2);
+    (x.sin() + 1.0) / 2.0
+}
+
+impl TDigest {
+    #[inline]
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+        if self.buffer.len() == BUFFER_CAPACITY {
+            self.compress();
+        }
+    }
+
+    /// Merges the buffered values into the centroids.
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut points = self
+            .buffer
+            .drain(..)
+            .map(|mean| Centroid { mean, weight: 1.0 })
+            .chain(self.centroids.drain(..))
+            .collect::<Vec<_>>();
+        points.sort_unstable_by(|lhs, rhs| total_cmp_f64(&lhs.mean, &rhs.mean));
+
+        let total = self.count as f64;
+        let mut points = points.into_iter();
+        let mut current = points.next().unwrap();
+        // the weight of all centroids before `current`
+        let mut weight = 0.0;
+        let mut limit = total * scale_inverse(scale(0.0) + 1.0);
+        for point in points {
+            if weight + current.weight + point.weight <= limit {
+                current.weight += point.weight;
+                current.mean += (point.mean - current.mean) * point.weight / current.weight;
+            } else {
+                weight += current.weight;
+                self.centroids.push(current);
+                limit = total * scale_inverse(scale(weight / total) + 1.0);
+                current = point;
+            }
+        }
+        self.centroids.push(current);
+    }
+
+    fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.compress();
+        if self.count == 0 {
+            return None;
+        }
+        // each centroid is located at the centre of its weight; the minimum and maximum
+        // are at the centre of the first and last value. With centroids of a single value,
+        // this is the linear interpolation of the exact method.
+        let target = q * (self.count as f64 - 1.0) + 0.5;
+        let interpolate = |(x0, y0): (f64, f64), (x1, y1): (f64, f64)| {
+            if x1 <= x0 {
+                y1
+            } else {
+                y0 + (target - x0) / (x1 - x0) * (y1 - y0)
+            }
+        };
+
+        let mut previous = (0.5, self.min);
+        let mut weight = 0.0;
+        for centroid in &self.centroids {
+            let position = weight + centroid.weight / 2.0;
+            weight += centroid.weight;
+            if target <= position {
+                return Some(interpolate(previous, (position, centroid.mean)));
+            }
+            previous = (position, centroid.mean);
+        }
+        Some(interpolate(previous, (self.count as f64 - 0.5, self.max)))
+    }
+}
+
+/// Returns the exact quantile `q` of `values` by selection, following the linear rule.
+fn exact_quantile(mut values: Vec<f64>, q: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let position = q * (values.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let fraction = position - lower as f64;
+
+    let (_, lower_value, greater) = values.select_nth_unstable_by(lower, total_cmp_f64);
+    let lower_value = *lower_value;
+    if fraction == 0.0 {
+        return Some(lower_value);
+    }
+    // the next value in order is the smallest of the values greater than `lower_value`
+    let upper_value = greater
+        .iter()
+        .copied()
+        .min_by(total_cmp_f64)
+        .unwrap_or(lower_value);
+    Some(lower_value + fraction * (upper_value - lower_value))
+}
+
+/// Calls `f` with the valid, non-`NaN` values of `array`.
+fn for_each_value<T, F>(array: &PrimitiveArray<T>, mut f: F)
+where
+    T: NativeType + AsPrimitive<f64>,
+    F: FnMut(f64),
+{
+    let mut f = |x: f64| {
+        if !x.is_nan() {
+            f(x)
+        }
+    };
+    if array.null_count() > 0 {
+        array.iter().flatten().for_each(|x| f(x.as_()))
+    } else {
+        array.values().iter().for_each(|x| f(x.as_()))
+    }
+}
+
+fn check_quantile(q: f64) -> Result<()> {
+    if (0.0..=1.0).contains(&q) {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "The quantile must be in [0, 1], but it is {}",
+            q
+        )))
+    }
+}
+
+/// Returns the quantile `q` of the values of `arrays`, ignoring nulls and `NaN`s.
+///
+/// The arrays are processed one after the other: with [`QuantileMethod::Approximate`],
+/// the memory used is independent of their number and length.
+/// Returns `None` if the arrays have no values; see [`quantile`] for details.
+/// # Errors
+/// This function errors iff `q` is not in `[0, 1]`.
+pub fn quantile_chunked<T: NativeType + AsPrimitive<f64>>(
+    arrays: &[&PrimitiveArray<T>],
+    q: f64,
+    method: QuantileMethod,
+) -> Result<Option<f64>> {
+    check_quantile(q)?;
+    Ok(match method {
+        QuantileMethod::Exact => {
+            let mut values = Vec::with_capacity(arrays.iter().map(|array| array.len()).sum());
+            arrays
+                .iter()
+                .for_each(|array| for_each_value(array, |x| values.push(x)));
+            exact_quantile(values, q)
+        }
+        QuantileMethod::Approximate => {
+            let mut digest = TDigest::default();
+            arrays
+                .iter()
+                .for_each(|array| for_each_value(array, |x| digest.add(x)));
+            digest.quantile(q)
+        }
+    })
+}
+
+/// Returns the quantile `q` of the values of `array`, ignoring nulls and `NaN`s.
+///
+/// Quantiles between two values are linearly interpolated: the quantile `q` of `n` sorted
+/// values `x` is `x[i] + (h - i) * (x[i + 1] - x[i])`, where `h = q * (n - 1)` and `i = floor(h)`
+/// (the default of e.g. `numpy` and R).
+/// Returns `None` if the array has no values.
+/// # Errors
+/// This function errors iff `q` is not in `[0, 1]`.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::{quantile, QuantileMethod};
+///
+/// let array = PrimitiveArray::from([Some(4i32), None, Some(1), Some(3), Some(2)]);
+/// assert_eq!(quantile(&array, 0.5, QuantileMethod::Exact).unwrap(), Some(2.5));
+/// assert_eq!(quantile(&array, 0.25, QuantileMethod::Exact).unwrap(), Some(1.75));
+/// assert!(quantile(&array, 1.5, QuantileMethod::Exact).is_err());
+/// ```
+pub fn quantile<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    q: f64,
+    method: QuantileMethod,
+) -> Result<Option<f64>> {
+    quantile_chunked(&[array], q, method)
+}
+
+/// Returns the exact median of the values of `array`, ignoring nulls and `NaN`s.
+///
+/// Returns `None` if the array has no values.
+pub fn median<T: NativeType + AsPrimitive<f64>>(array: &PrimitiveArray<T>) -> Option<f64> {
+    quantile(array, 0.5, QuantileMethod::Exact).unwrap()
+}
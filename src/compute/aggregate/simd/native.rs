@@ -34,7 +34,7 @@ macro_rules! simd_add {
             fn simd_sum(self) -> $type {
                 let mut reduced = <$type>::default();
                 (0..<$simd>::LANES).for_each(|i| {
-                    reduced += self[i];
+                    reduced = <$type>::$add(reduced, self[i]);
                 });
                 reduced
             }
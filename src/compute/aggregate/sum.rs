@@ -1,6 +1,7 @@
 use std::ops::Add;
 
 use multiversion::multiversion;
+use num_traits::Float;
 
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, IntervalUnit};
@@ -33,12 +34,15 @@ where
     for chunk in simd_vals {
         reduced = reduced + *chunk;
     }
+    // the head and tail are added as lanes so that integers wrap regardless of the alignment
+    reduced = reduced + T::Simd::from_incomplete_chunk(head, T::default());
+    reduced = reduced + T::Simd::from_incomplete_chunk(tail, T::default());
 
-    reduced.simd_sum() + head.iter().copied().sum() + tail.iter().copied().sum()
+    reduced.simd_sum()
 }
 
 /// # Panics
-/// iff `values.len() != bitmap.len()` or the operation overflows.
+/// iff `values.len() != bitmap.len()`.
 #[multiversion]
 #[clone(target = "x86_64+avx")]
 fn null_sum_impl<T, I>(values: &[T], mut validity_masks: I) -> T
@@ -68,7 +72,7 @@ where
 }
 
 /// # Panics
-/// iff `values.len() != bitmap.len()` or the operation overflows.
+/// iff `values.len() != bitmap.len()`.
 fn null_sum<T>(values: &[T], bitmap: &Bitmap) -> T
 where
     T: NativeType + Simd,
@@ -84,7 +88,7 @@ where
     }
 }
 
-/// Returns the sum of values in the array.
+/// Returns the sum of values in the array. Integers wrap around on overflow.
 ///
 /// Returns `None` if the array is empty or only contains null values.
 pub fn sum_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
@@ -104,6 +108,64 @@ where
     }
 }
 
+/// The state of the Kahan-Babuška (Neumaier) summation
+#[derive(Default)]
+struct CompensatedSum<T> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T: Float> CompensatedSum<T> {
+    #[inline]
+    fn add(mut self, value: T) -> Self {
+        let sum = self.sum + value;
+        self.compensation = self.compensation
+            + if self.sum.abs() >= value.abs() {
+                (self.sum - sum) + value
+            } else {
+                (value - sum) + self.sum
+            };
+        self.sum = sum;
+        self
+    }
+
+    #[inline]
+    fn finish(self) -> T {
+        self.sum + self.compensation
+    }
+}
+
+/// Returns the sum of values in the array using the Kahan-Babuška (Neumaier) compensated
+/// summation, whose error is bounded independently of the number of values.
+///
+/// Unlike [`sum_primitive`], the result does not depend on how the values are chunked,
+/// at the cost of being slower.
+/// Returns `None` if the array is empty or only contains null values.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::sum_compensated;
+///
+/// let array = PrimitiveArray::from_slice([1e16f64, 1.0, 1.0, -1e16]);
+/// assert_eq!(sum_compensated(&array), Some(2.0));
+/// ```
+pub fn sum_compensated<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + Float,
+{
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    let state = CompensatedSum::<T>::default();
+    let state = if array.null_count() == 0 {
+        array.values().iter().fold(state, |acc, x| acc.add(*x))
+    } else {
+        array.iter().flatten().fold(state, |acc, x| acc.add(*x))
+    };
+    Some(state.finish())
+}
+
 macro_rules! dyn_sum {
     ($ty:ty, $array:expr) => {{
         let array = $array
@@ -135,15 +197,18 @@ pub fn can_sum(data_type: &DataType) -> bool {
             | UInt16
             | UInt32
             | UInt64
+            | Float16
             | Float32
             | Float64
+            | Decimal(_, _)
     )
 }
 
 /// Returns the sum of all elements in `array` as a [`Scalar`] of the same physical
-/// and logical types as `array`.
+/// and logical types as `array`. The exception are decimals, whose sum has the maximum
+/// precision (see [`sum_decimal`](super::sum_decimal)).
 /// # Error
-/// Errors iff the operation is not supported.
+/// Errors iff the operation is not supported or the sum of a decimal array overflows.
 pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type() {
         DataType::Int8 => dyn_sum!(i8, array),
@@ -163,9 +228,15 @@ pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         DataType::UInt16 => dyn_sum!(u16, array),
         DataType::UInt32 => dyn_sum!(u32, array),
         DataType::UInt64 => dyn_sum!(u64, array),
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => Box::new(PrimitiveScalar::new(
+            array.data_type().clone(),
+            super::sum_f16(array.as_any().downcast_ref().unwrap()),
+        )),
         DataType::Float32 => dyn_sum!(f32, array),
         DataType::Float64 => dyn_sum!(f64, array),
+        DataType::Decimal(_, _) => {
+            Box::new(super::sum_decimal(array.as_any().downcast_ref().unwrap())?)
+        }
         _ => {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "The `sum` operator does not support type `{:?}`",
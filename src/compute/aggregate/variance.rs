@@ -0,0 +1,139 @@
+//! Contains the mean, variance and standard deviation of [`PrimitiveArray`]s,
+//! computed in a single pass with [Welford's algorithm](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm).
+use num_traits::AsPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::types::NativeType;
+
+/// The kind of variance (and standard deviation) to compute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarianceKind {
+    /// The variance of a population, whose sum of squared deviations is divided by `n`.
+    Population,
+    /// The (unbiased) variance of a sample, whose sum of squared deviations is divided by `n - 1`.
+    Sample,
+}
+
+impl VarianceKind {
+    /// The delta degrees of freedom of this kind
+    #[inline]
+    fn ddof(&self) -> usize {
+        match self {
+            VarianceKind::Population => 0,
+            VarianceKind::Sample => 1,
+        }
+    }
+}
+
+/// The running state of Welford's algorithm
+#[derive(Debug, Default)]
+struct Welford {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Welford {
+    #[inline]
+    fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    #[inline]
+    fn update_masked<T: NativeType + AsPrimitive<f64>>(&mut self, values: &[T], mask: u64) {
+        match mask {
+            0 => {}
+            u64::MAX => values.iter().for_each(|x| self.update(x.as_())),
+            mask => values
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .for_each(|(_, x)| self.update(x.as_())),
+        }
+    }
+
+    fn variance(&self, kind: VarianceKind) -> Option<f64> {
+        let ddof = kind.ddof();
+        if self.count > ddof {
+            Some(self.m2 / (self.count - ddof) as f64)
+        } else {
+            None
+        }
+    }
+}
+
+fn welford<T: NativeType + AsPrimitive<f64>>(array: &PrimitiveArray<T>) -> Welford {
+    let mut state = Welford::default();
+    let values = array.values().as_slice();
+
+    match array.validity() {
+        Some(bitmap) if array.null_count() > 0 => {
+            let mut masks = bitmap.chunks::<u64>();
+            let mut chunks = values.chunks_exact(64);
+            chunks
+                .by_ref()
+                .zip(masks.by_ref())
+                .for_each(|(chunk, mask)| state.update_masked(chunk, mask));
+            state.update_masked(chunks.remainder(), masks.remainder());
+        }
+        _ => values.iter().for_each(|x| state.update(x.as_())),
+    }
+    state
+}
+
+/// Returns the arithmetic mean of the values of `array`, ignoring nulls.
+///
+/// Returns `None` if the array is empty or only contains null values.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::mean;
+///
+/// let array = PrimitiveArray::from([Some(1i32), None, Some(2), Some(6)]);
+/// assert_eq!(mean(&array), Some(3.0));
+/// ```
+pub fn mean<T: NativeType + AsPrimitive<f64>>(array: &PrimitiveArray<T>) -> Option<f64> {
+    let state = welford(array);
+    if state.count > 0 {
+        Some(state.mean)
+    } else {
+        None
+    }
+}
+
+/// Returns the variance of the values of `array` of a given [`VarianceKind`], ignoring nulls.
+///
+/// Returns `None` if the array has fewer non-null values than required by `kind`:
+/// one for [`VarianceKind::Population`] and two for [`VarianceKind::Sample`].
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::aggregate::{variance, VarianceKind};
+///
+/// let array = PrimitiveArray::from([Some(1.0f64), None, Some(2.0), Some(3.0)]);
+/// assert_eq!(variance(&array, VarianceKind::Population), Some(2.0 / 3.0));
+/// assert_eq!(variance(&array, VarianceKind::Sample), Some(1.0));
+///
+/// let array = PrimitiveArray::from([Some(1.0f64), None]);
+/// assert_eq!(variance(&array, VarianceKind::Sample), None);
+/// ```
+pub fn variance<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    kind: VarianceKind,
+) -> Option<f64> {
+    welford(array).variance(kind)
+}
+
+/// Returns the standard deviation of the values of `array` of a given [`VarianceKind`],
+/// ignoring nulls.
+///
+/// Returns `None` under the same conditions as [`variance`].
+pub fn stddev<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    kind: VarianceKind,
+) -> Option<f64> {
+    variance(array, kind).map(|x| x.sqrt())
+}
@@ -11,7 +11,8 @@ use crate::{
             ArrayAdd, ArrayCheckedAdd, ArrayOverflowingAdd, ArraySaturatingAdd, ArrayWrappingAdd,
         },
         arity::{
-            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_with_bitmap,
+            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_owned,
+            unary_with_bitmap,
         },
     },
 };
@@ -207,6 +208,27 @@ where
     unary(lhs, |a| a + rhs, lhs.data_type().clone())
 }
 
+/// Same as [`add_scalar`], but takes ownership of `lhs` and re-uses its allocation when
+/// it is not shared (see [`PrimitiveArray::into_mut`]).
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::add_scalar_owned;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(7)]);
+/// let result = add_scalar_owned(a, &1i32);
+/// let expected = PrimitiveArray::from([None, Some(8)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn add_scalar_owned<T>(lhs: PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Add<Output = T>,
+{
+    let rhs = *rhs;
+    unary_owned(lhs, |a| a + rhs)
+}
+
 /// Wrapping addition of a scalar T to a [`PrimitiveArray`] of type T.
 /// It do nothing if the result overflows.
 ///
@@ -1,25 +1,36 @@
 //! Definition of basic div operations with primitive arrays
 use std::ops::Div;
 
-use num_traits::{CheckedDiv, NumCast};
+use num_traits::{Bounded, CheckedDiv, NumCast, Zero};
 
 use crate::datatypes::PrimitiveType;
 use crate::{
     array::{Array, PrimitiveArray},
+    bitmap::Bitmap,
+    buffer::Buffer,
     compute::{
-        arithmetics::{ArrayCheckedDiv, ArrayDiv},
-        arity::{binary, binary_checked, unary, unary_checked},
+        arithmetics::{ArrayCheckedDiv, ArrayDiv, ArrayOverflowingDiv, ArraySaturatingDiv},
+        arity::{
+            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_with_bitmap,
+        },
         utils::check_same_len,
     },
+    error::{ArrowError, Result},
 };
 use strength_reduce::{
     StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU8,
 };
 
-use super::NativeArithmetics;
+use super::{check_non_zero_divisors, is_float, valid_divisors, NativeArithmetics};
 
 /// Divides two primitive arrays with the same type.
-/// Panics if the divisor is zero of one pair of values overflows.
+/// Null slots are not considered divisors, even when their (undefined) value is zero.
+/// Float divisions by zero follow IEEE 754 (e.g. `1.0 / 0.0 = inf`).
+/// # Error
+/// Errors iff the arrays have different lengths or, for integers, iff a non-null divisor
+/// is zero. The error reports the index of the first zero divisor.
+/// # Panic
+/// Panics iff the division of a pair of non-null values overflows (i.e. `MIN / -1`).
 ///
 /// # Examples
 /// ```
@@ -28,25 +39,21 @@ use super::NativeArithmetics;
 ///
 /// let a = Int32Array::from(&[Some(10), Some(1), Some(6)]);
 /// let b = Int32Array::from(&[Some(5), None, Some(6)]);
-/// let result = div(&a, &b);
+/// let result = div(&a, &b).unwrap();
 /// let expected = Int32Array::from(&[Some(2), None, Some(1)]);
-/// assert_eq!(result, expected)
+/// assert_eq!(result, expected);
+///
+/// let b = Int32Array::from(&[Some(5), None, Some(0)]);
+/// assert!(div(&a, &b).is_err());
 /// ```
-pub fn div<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+pub fn div<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
 where
-    T: NativeArithmetics + Div<Output = T>,
+    T: NativeArithmetics + Div<Output = T> + NumCast,
 {
-    if rhs.null_count() == 0 {
-        binary(lhs, rhs, lhs.data_type().clone(), |a, b| a / b)
-    } else {
-        check_same_len(lhs, rhs).unwrap();
-        let values = lhs.iter().zip(rhs.iter()).map(|(l, r)| match (l, r) {
-            (Some(l), Some(r)) => Some(*l / *r),
-            _ => None,
-        });
-
-        PrimitiveArray::from_trusted_len_iter(values).to(lhs.data_type().clone())
-    }
+    check_same_len(lhs, rhs)?;
+    check_non_zero_divisors(rhs)?;
+    let rhs = valid_divisors(lhs, rhs);
+    Ok(binary(lhs, &rhs, lhs.data_type().clone(), |a, b| a / b))
 }
 
 /// Checked division of two primitive arrays. If the result from the division
@@ -73,12 +80,87 @@ where
     binary_checked(lhs, rhs, lhs.data_type().clone(), op)
 }
 
+/// The only division of integers that overflows is `MIN / -1`, whose wrapped result is `MIN`.
+/// Division by zero is not an overflow and returns zero; callers must check it beforehand.
+#[inline]
+fn overflowing_div_op<T: CheckedDiv + Zero + Copy>(a: T, b: T) -> (T, bool) {
+    match a.checked_div(&b) {
+        Some(value) => (value, false),
+        None if b.is_zero() => (T::zero(), false),
+        None => (a, true),
+    }
+}
+
+/// Panics if any of the non-null (integer) values of `rhs` is zero.
+#[inline]
+fn assert_non_zero_divisors<T: NativeArithmetics>(rhs: &PrimitiveArray<T>) {
+    if let Err(error) = check_non_zero_divisors(rhs) {
+        panic!("{}", error)
+    }
+}
+
+/// Saturating division of two primitive arrays. If the result from the division
+/// overflows (i.e. `MIN / -1`), the result for the operation will be the saturated value.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::saturating_div;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(10i8)]);
+/// let b = Int8Array::from(&[Some(-1i8), Some(2i8)]);
+/// let result = saturating_div(&a, &b);
+/// let expected = Int8Array::from(&[Some(127i8), Some(5i8)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn saturating_div<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedDiv<Output = T> + Zero + Bounded,
+{
+    assert_non_zero_divisors(rhs);
+    let op = move |a: T, b: T| match overflowing_div_op(a, b) {
+        (_, true) => T::max_value(),
+        (value, false) => value,
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Overflowing division of two primitive arrays. If the result from the division
+/// overflows (i.e. `MIN / -1`), the result for the operation will be the wrapped value
+/// and the returned [`Bitmap`] will be set for that slot.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::overflowing_div;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(10i8)]);
+/// let b = Int8Array::from(&[Some(-1i8), Some(2i8)]);
+/// let (result, overflow) = overflowing_div(&a, &b);
+/// let expected = Int8Array::from(&[Some(-128i8), Some(5i8)]);
+/// assert_eq!(result, expected);
+/// assert_eq!(overflow, vec![true, false].into());
+/// ```
+pub fn overflowing_div<T>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> (PrimitiveArray<T>, Bitmap)
+where
+    T: NativeArithmetics + CheckedDiv<Output = T> + Zero,
+{
+    assert_non_zero_divisors(rhs);
+    binary_with_bitmap(lhs, rhs, lhs.data_type().clone(), overflowing_div_op)
+}
+
 // Implementation of ArrayDiv trait for PrimitiveArrays
 impl<T> ArrayDiv<PrimitiveArray<T>> for PrimitiveArray<T>
 where
-    T: NativeArithmetics + Div<Output = T>,
+    T: NativeArithmetics + Div<Output = T> + NumCast,
 {
-    fn div(&self, rhs: &PrimitiveArray<T>) -> Self {
+    fn div(&self, rhs: &PrimitiveArray<T>) -> Result<Self> {
         div(self, rhs)
     }
 }
@@ -93,8 +175,29 @@ where
     }
 }
 
+// Implementation of ArraySaturatingDiv trait for PrimitiveArrays
+impl<T> ArraySaturatingDiv<PrimitiveArray<T>> for PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedDiv<Output = T> + Zero + Bounded,
+{
+    fn saturating_div(&self, rhs: &PrimitiveArray<T>) -> Self {
+        saturating_div(self, rhs)
+    }
+}
+
+// Implementation of ArrayOverflowingDiv trait for PrimitiveArrays
+impl<T> ArrayOverflowingDiv<PrimitiveArray<T>> for PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedDiv<Output = T> + Zero,
+{
+    fn overflowing_div(&self, rhs: &PrimitiveArray<T>) -> (Self, Bitmap) {
+        overflowing_div(self, rhs)
+    }
+}
+
 /// Divide a primitive array of type T by a scalar T.
-/// Panics if the divisor is zero.
+/// # Error
+/// Errors iff the scalar is an integer zero, before any value is divided.
 ///
 /// # Examples
 /// ```
@@ -102,16 +205,23 @@ where
 /// use arrow2::array::Int32Array;
 ///
 /// let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
-/// let result = div_scalar(&a, &2i32);
+/// let result = div_scalar(&a, &2i32).unwrap();
 /// let expected = Int32Array::from(&[None, Some(3), None, Some(3)]);
-/// assert_eq!(result, expected)
+/// assert_eq!(result, expected);
+///
+/// assert!(div_scalar(&a, &0i32).is_err());
 /// ```
-pub fn div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+pub fn div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> Result<PrimitiveArray<T>>
 where
-    T: NativeArithmetics + Div<Output = T> + NumCast,
+    T: NativeArithmetics + Div<Output = T> + NumCast + Zero,
 {
+    if !is_float::<T>() && rhs.is_zero() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Division by zero".to_string(),
+        ));
+    }
     let rhs = *rhs;
-    match T::PRIMITIVE {
+    let result = match T::PRIMITIVE {
         PrimitiveType::UInt64 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u64>>().unwrap();
             let rhs = rhs.to_u64().unwrap();
@@ -161,8 +271,21 @@ where
                 .unwrap()
                 .clone()
         }
-        _ => unary(lhs, |a| a / rhs, lhs.data_type().clone()),
-    }
+        _ => match lhs.validity() {
+            // the (undefined) values of null slots may overflow, e.g. `MIN / -1`
+            Some(validity) => {
+                let values = lhs.values().iter().zip(validity.iter());
+                let values = values.map(|(a, is_valid)| if is_valid { *a / rhs } else { *a });
+                PrimitiveArray::from_data(
+                    lhs.data_type().clone(),
+                    Buffer::from_trusted_len_iter(values),
+                    Some(validity.clone()),
+                )
+            }
+            None => unary(lhs, |a| a / rhs, lhs.data_type().clone()),
+        },
+    };
+    Ok(result)
 }
 
 /// Checked division of a primitive array of type T by a scalar T. If the
@@ -191,9 +314,9 @@ where
 // Implementation of ArrayDiv trait for PrimitiveArrays with a scalar
 impl<T> ArrayDiv<T> for PrimitiveArray<T>
 where
-    T: NativeArithmetics + Div<Output = T> + NumCast,
+    T: NativeArithmetics + Div<Output = T> + NumCast + Zero,
 {
-    fn div(&self, rhs: &T) -> Self {
+    fn div(&self, rhs: &T) -> Result<Self> {
         div_scalar(self, rhs)
     }
 }
@@ -207,3 +330,79 @@ where
         checked_div_scalar(self, rhs)
     }
 }
+
+/// Saturating division of a primitive array of type T by a scalar T. If the
+/// result from the division overflows (i.e. `MIN / -1`), the result will be saturated.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::saturating_div_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), None, Some(10i8)]);
+/// let result = saturating_div_scalar(&a, &-1i8);
+/// let expected = Int8Array::from(&[Some(127i8), None, Some(-10i8)]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn saturating_div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedDiv<Output = T> + Zero + Bounded,
+{
+    let rhs = *rhs;
+    if rhs.is_zero() {
+        panic!("attempt to divide by zero")
+    }
+    let op = move |a: T| a.checked_div(&rhs).unwrap_or_else(T::max_value);
+
+    unary(lhs, op, lhs.data_type().clone())
+}
+
+/// Overflowing division of a primitive array of type T by a scalar T. If the
+/// result from the division overflows (i.e. `MIN / -1`), the result will be the wrapped
+/// value and the returned [`Bitmap`] will be set for that slot.
+/// Panics if the divisor is zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::overflowing_div_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-128i8), Some(10i8)]);
+/// let (result, overflow) = overflowing_div_scalar(&a, &-1i8);
+/// let expected = Int8Array::from(&[Some(-128i8), Some(-10i8)]);
+/// assert_eq!(result, expected);
+/// assert_eq!(overflow, vec![true, false].into());
+/// ```
+pub fn overflowing_div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> (PrimitiveArray<T>, Bitmap)
+where
+    T: NativeArithmetics + CheckedDiv<Output = T> + Zero,
+{
+    let rhs = *rhs;
+    if rhs.is_zero() {
+        panic!("attempt to divide by zero")
+    }
+    let op = move |a: T| overflowing_div_op(a, rhs);
+
+    unary_with_bitmap(lhs, op, lhs.data_type().clone())
+}
+
+// Implementation of ArraySaturatingDiv trait for PrimitiveArrays with a scalar
+impl<T> ArraySaturatingDiv<T> for PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedDiv<Output = T> + Zero + Bounded,
+{
+    fn saturating_div(&self, rhs: &T) -> Self {
+        saturating_div_scalar(self, rhs)
+    }
+}
+
+// Implementation of ArrayOverflowingDiv trait for PrimitiveArrays with a scalar
+impl<T> ArrayOverflowingDiv<T> for PrimitiveArray<T>
+where
+    T: NativeArithmetics + CheckedDiv<Output = T> + Zero,
+{
+    fn overflowing_div(&self, rhs: &T) -> (Self, Bitmap) {
+        overflowing_div_scalar(self, rhs)
+    }
+}
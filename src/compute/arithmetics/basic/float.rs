@@ -0,0 +1,106 @@
+//! Definition of elementary mathematical functions of floating point arrays.
+//!
+//! These functions follow IEEE 754 semantics: values outside of the domain of a
+//! function (e.g. `ln(-1.0)`) result in `NaN`, not in a null.
+use num_traits::{Float, NumCast};
+
+use crate::{array::PrimitiveArray, compute::arity::unary};
+
+use super::NativeArithmetics;
+
+macro_rules! float_unary {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        pub fn $name<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+        where
+            T: NativeArithmetics + Float,
+        {
+            unary(array, |x| x.$name(), array.data_type().clone())
+        }
+    };
+}
+
+float_unary!(
+    /// Returns the square root of each value of the array.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrow2::compute::arithmetics::basic::sqrt;
+    /// use arrow2::array::{Array, Float64Array};
+    ///
+    /// let a = Float64Array::from(&[Some(4.0), None, Some(-1.0)]);
+    /// let result = sqrt(&a);
+    /// assert_eq!(result.value(0), 2.0);
+    /// assert!(result.is_null(1));
+    /// assert!(result.value(2).is_nan());
+    /// ```
+    sqrt
+);
+float_unary!(
+    /// Returns the natural logarithm of each value of the array.
+    ln
+);
+float_unary!(
+    /// Returns the base 2 logarithm of each value of the array.
+    log2
+);
+float_unary!(
+    /// Returns the base 10 logarithm of each value of the array.
+    log10
+);
+float_unary!(
+    /// Returns `e` to the power of each value of the array.
+    exp
+);
+float_unary!(
+    /// Returns the sine of each value (in radians) of the array.
+    sin
+);
+float_unary!(
+    /// Returns the cosine of each value (in radians) of the array.
+    cos
+);
+float_unary!(
+    /// Returns the tangent of each value (in radians) of the array.
+    tan
+);
+float_unary!(
+    /// Rounds each value of the array to the nearest integer, rounding half-way cases away from `0.0`.
+    round
+);
+float_unary!(
+    /// Returns the largest integer less than or equal to each value of the array.
+    floor
+);
+float_unary!(
+    /// Returns the smallest integer greater than or equal to each value of the array.
+    ceil
+);
+float_unary!(
+    /// Returns the integer part of each value of the array.
+    trunc
+);
+
+/// Rounds each value of the array to `digits` decimal places, rounding half-way cases
+/// away from `0.0`. A negative `digits` rounds to the left of the decimal point.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::round_digits;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(1.255), None, Some(-1250.0)]);
+/// assert_eq!(round_digits(&a, 1), Float64Array::from(&[Some(1.3), None, Some(-1250.0)]));
+/// assert_eq!(round_digits(&a, -2), Float64Array::from(&[Some(0.0), None, Some(-1300.0)]));
+/// ```
+pub fn round_digits<T>(array: &PrimitiveArray<T>, digits: i32) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    let scale = <T as NumCast>::from(10).unwrap().powi(digits);
+    unary(
+        array,
+        |x| (x * scale).round() / scale,
+        array.data_type().clone(),
+    )
+}
@@ -9,6 +9,8 @@ mod add;
 pub use add::*;
 mod div;
 pub use div::*;
+mod float;
+pub use float::*;
 mod mul;
 pub use mul::*;
 mod pow;
@@ -20,9 +22,16 @@ pub use sub::*;
 
 use std::ops::Neg;
 
-use num_traits::{CheckedNeg, WrappingNeg};
+use num_traits::{Bounded, CheckedNeg, NumCast, Signed, WrappingNeg};
 
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{
+    array::PrimitiveArray,
+    buffer::Buffer,
+    compute::utils::combine_validities,
+    datatypes::PrimitiveType,
+    error::{ArrowError, Result},
+    types::NativeType,
+};
 
 use super::super::arity::{unary, unary_checked};
 
@@ -42,6 +51,82 @@ impl NativeArithmetics for i64 {}
 impl NativeArithmetics for f32 {}
 impl NativeArithmetics for f64 {}
 
+/// Whether `T` is a floating point type
+#[inline]
+fn is_float<T: NativeType>() -> bool {
+    matches!(
+        T::PRIMITIVE,
+        PrimitiveType::Float32 | PrimitiveType::Float64
+    )
+}
+
+/// Errors iff any of the non-null values of `rhs` is an integer zero, reporting the
+/// index of the first one. Null slots are not considered since their (undefined) value
+/// may be zero. Floats are never rejected since dividing them by zero is well defined.
+fn check_non_zero_divisors<T: NativeArithmetics>(rhs: &PrimitiveArray<T>) -> Result<()> {
+    if is_float::<T>() {
+        return Ok(());
+    }
+    // the default of every integer is zero
+    let zero = T::default();
+    if let Some(index) = rhs.iter().position(|x| x == Some(&zero)) {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "Division by zero at index {}",
+            index
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Errors iff a pair of non-null (signed integer) values overflows when divided, i.e. `MIN`
+/// divided by `-1`.
+fn check_non_overflowing_divisors<T: NativeArithmetics + NumCast + Bounded>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<()> {
+    // only signed integers represent `-1`
+    let minus_one = match T::from(-1) {
+        Some(minus_one) if !is_float::<T>() => minus_one,
+        _ => return Ok(()),
+    };
+    let min = T::min_value();
+    if lhs
+        .iter()
+        .zip(rhs.iter())
+        .any(|(a, b)| a == Some(&min) && b == Some(&minus_one))
+    {
+        Err(ArrowError::Overflow)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns `rhs` with the (integer) values of the slots that are null in `lhs` or `rhs` replaced
+/// by one, so that dividing the (undefined) values of null slots neither divides by zero nor
+/// overflows.
+fn valid_divisors<T: NativeArithmetics + NumCast>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> PrimitiveArray<T> {
+    match combine_validities(lhs.validity(), rhs.validity()) {
+        Some(validity) if !is_float::<T>() => {
+            let one = T::from(1).unwrap();
+            let values = rhs
+                .values()
+                .iter()
+                .zip(validity.iter())
+                .map(|(b, is_valid)| if is_valid { *b } else { one });
+            PrimitiveArray::from_data(
+                rhs.data_type().clone(),
+                Buffer::from_trusted_len_iter(values),
+                rhs.validity().cloned(),
+            )
+        }
+        _ => rhs.clone(),
+    }
+}
+
 /// Negates values from array.
 ///
 /// # Examples
@@ -99,3 +184,65 @@ where
 {
     unary(array, |a| a.wrapping_neg(), array.data_type().clone())
 }
+
+/// Returns the absolute value of each value of the array.
+/// # Panic
+/// This function panics iff the absolute value of a non-null (signed integer) value overflows
+/// (e.g. `abs(i8::MIN)`), regardless of whether overflow checks are enabled.
+/// Use [`checked_abs`] to handle such values.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::abs;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(7)]);
+/// let result = abs(&a);
+/// let expected = PrimitiveArray::from([None, Some(6), Some(7)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Signed + Bounded,
+{
+    if is_float::<T>() {
+        return unary(array, |a| a.abs(), array.data_type().clone());
+    }
+    let min = T::min_value();
+    if array.iter().any(|x| x == Some(&min)) {
+        panic!("{}", ArrowError::Overflow)
+    }
+    // null slots may hold `MIN`, whose absolute value overflows
+    unary(
+        array,
+        |a| if a == min { a } else { a.abs() },
+        array.data_type().clone(),
+    )
+}
+
+/// Checked absolute value of each value of the array. Values whose absolute value
+/// overflows (e.g. `i8::MIN`) are set to null.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_abs;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(-6), Some(i8::MIN)]);
+/// let result = checked_abs(&a);
+/// let expected = PrimitiveArray::from([None, Some(6), None]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn checked_abs<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Signed + CheckedNeg,
+{
+    let op = |a: T| {
+        if a.is_negative() {
+            a.checked_neg()
+        } else {
+            Some(a)
+        }
+    };
+    unary_checked(array, op, array.data_type().clone())
+}
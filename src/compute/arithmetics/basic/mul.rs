@@ -11,7 +11,8 @@ use crate::{
             ArrayCheckedMul, ArrayMul, ArrayOverflowingMul, ArraySaturatingMul, ArrayWrappingMul,
         },
         arity::{
-            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_with_bitmap,
+            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_owned,
+            unary_with_bitmap,
         },
     },
 };
@@ -208,6 +209,27 @@ where
     unary(lhs, |a| a * rhs, lhs.data_type().clone())
 }
 
+/// Same as [`mul_scalar`], but takes ownership of `lhs` and re-uses its allocation when
+/// it is not shared (see [`PrimitiveArray::into_mut`]).
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::mul_scalar_owned;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(6)]);
+/// let result = mul_scalar_owned(a, &2i32);
+/// let expected = PrimitiveArray::from([None, Some(12)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn mul_scalar_owned<T>(lhs: PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Mul<Output = T>,
+{
+    let rhs = *rhs;
+    unary_owned(lhs, |a| a * rhs)
+}
+
 /// Wrapping multiplication of a scalar T to a [`PrimitiveArray`] of type T.
 /// It do nothing if the result overflows.
 ///
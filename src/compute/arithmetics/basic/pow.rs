@@ -1,5 +1,5 @@
 //! Definition of basic pow operations with primitive arrays
-use num_traits::{checked_pow, CheckedMul, One, Pow};
+use num_traits::{checked_pow, CheckedMul, Float, One, Pow};
 
 use crate::{
     array::PrimitiveArray,
@@ -28,6 +28,25 @@ where
     unary(array, |x| x.pow(exponent), array.data_type().clone())
 }
 
+/// Raises an array of floats to the power of an integer exponent.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::powi_scalar;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from(&[Some(2.0), None, Some(-3.0)]);
+/// let actual = powi_scalar(&a, 3);
+/// let expected = Float64Array::from(&[Some(8.0), None, Some(-27.0)]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn powi_scalar<T>(array: &PrimitiveArray<T>, exponent: i32) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Float,
+{
+    unary(array, |x| x.powi(exponent), array.data_type().clone())
+}
+
 /// Checked operation of raising an array of primitives to the power of
 /// exponent. If the result from the multiplications overflows, the validity
 /// for that index is changed returned.
@@ -1,6 +1,6 @@
 use std::ops::Rem;
 
-use num_traits::{CheckedRem, NumCast};
+use num_traits::{Bounded, CheckedRem, NumCast, Zero};
 
 use crate::datatypes::PrimitiveType;
 use crate::{
@@ -8,33 +8,51 @@ use crate::{
     compute::{
         arithmetics::{ArrayCheckedRem, ArrayRem},
         arity::{binary, binary_checked, unary, unary_checked},
+        utils::check_same_len,
     },
+    error::{ArrowError, Result},
 };
 use strength_reduce::{
     StrengthReducedU16, StrengthReducedU32, StrengthReducedU64, StrengthReducedU8,
 };
 
-use super::NativeArithmetics;
+use super::{
+    check_non_overflowing_divisors, check_non_zero_divisors, is_float, valid_divisors,
+    NativeArithmetics,
+};
 
 /// Remainder of two primitive arrays with the same type.
-/// Panics if the divisor is zero of one pair of values overflows.
+/// Null slots are not considered divisors, even when their (undefined) value is zero.
+/// Float remainders by zero are `NaN`, as per IEEE 754.
+/// # Error
+/// Errors iff
+/// * the arrays have different lengths
+/// * for integers, a non-null divisor is zero. The error reports the index of the first one.
+/// * for signed integers, the remainder of a pair of non-null values overflows (`MIN % -1`)
 ///
 /// # Examples
 /// ```
 /// use arrow2::compute::arithmetics::basic::rem;
 /// use arrow2::array::Int32Array;
 ///
-/// let a = Int32Array::from(&[Some(10), Some(7)]);
-/// let b = Int32Array::from(&[Some(5), Some(6)]);
-/// let result = rem(&a, &b);
-/// let expected = Int32Array::from(&[Some(0), Some(1)]);
-/// assert_eq!(result, expected)
+/// let a = Int32Array::from(&[Some(10), Some(7), Some(1)]);
+/// let b = Int32Array::from(&[Some(5), Some(6), None]);
+/// let result = rem(&a, &b).unwrap();
+/// let expected = Int32Array::from(&[Some(0), Some(1), None]);
+/// assert_eq!(result, expected);
+///
+/// let b = Int32Array::from(&[Some(5), Some(0), None]);
+/// assert!(rem(&a, &b).is_err());
 /// ```
-pub fn rem<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+pub fn rem<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
 where
-    T: NativeArithmetics + Rem<Output = T>,
+    T: NativeArithmetics + Rem<Output = T> + NumCast + Bounded,
 {
-    binary(lhs, rhs, lhs.data_type().clone(), |a, b| a % b)
+    check_same_len(lhs, rhs)?;
+    check_non_zero_divisors(rhs)?;
+    check_non_overflowing_divisors(lhs, rhs)?;
+    let rhs = valid_divisors(lhs, rhs);
+    Ok(binary(lhs, &rhs, lhs.data_type().clone(), |a, b| a % b))
 }
 
 /// Checked remainder of two primitive arrays. If the result from the remainder
@@ -63,9 +81,9 @@ where
 
 impl<T> ArrayRem<PrimitiveArray<T>> for PrimitiveArray<T>
 where
-    T: NativeArithmetics + Rem<Output = T>,
+    T: NativeArithmetics + Rem<Output = T> + NumCast + Bounded,
 {
-    fn rem(&self, rhs: &PrimitiveArray<T>) -> Self {
+    fn rem(&self, rhs: &PrimitiveArray<T>) -> Result<Self> {
         rem(self, rhs)
     }
 }
@@ -80,7 +98,10 @@ where
 }
 
 /// Remainder a primitive array of type T by a scalar T.
-/// Panics if the divisor is zero.
+/// Float remainders by zero are `NaN`, as per IEEE 754.
+/// # Error
+/// Errors iff the scalar is an integer zero or, for signed integers, iff the remainder of a
+/// non-null value overflows (`MIN % -1`).
 ///
 /// # Examples
 /// ```
@@ -88,17 +109,33 @@ where
 /// use arrow2::array::Int32Array;
 ///
 /// let a = Int32Array::from(&[None, Some(6), None, Some(7)]);
-/// let result = rem_scalar(&a, &2i32);
+/// let result = rem_scalar(&a, &2i32).unwrap();
 /// let expected = Int32Array::from(&[None, Some(0), None, Some(1)]);
-/// assert_eq!(result, expected)
+/// assert_eq!(result, expected);
+///
+/// assert!(rem_scalar(&a, &0i32).is_err());
 /// ```
-pub fn rem_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+pub fn rem_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> Result<PrimitiveArray<T>>
 where
-    T: NativeArithmetics + Rem<Output = T> + NumCast,
+    T: NativeArithmetics + Rem<Output = T> + NumCast + Bounded + Zero,
 {
+    if !is_float::<T>() && rhs.is_zero() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Division by zero".to_string(),
+        ));
+    }
+    if !is_float::<T>() && T::from(-1) == Some(*rhs) {
+        let min = T::min_value();
+        if lhs.iter().any(|x| x == Some(&min)) {
+            return Err(ArrowError::Overflow);
+        }
+        // the remainder of every integer by `-1` is zero, including those of null slots,
+        // whose (undefined) value may be `MIN`
+        return Ok(unary(lhs, |_| T::zero(), lhs.data_type().clone()));
+    }
     let rhs = *rhs;
 
-    match T::PRIMITIVE {
+    let result = match T::PRIMITIVE {
         PrimitiveType::UInt64 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u64>>().unwrap();
             let rhs = rhs.to_u64().unwrap();
@@ -156,7 +193,8 @@ where
                 .clone()
         }
         _ => unary(lhs, |a| a % rhs, lhs.data_type().clone()),
-    }
+    };
+    Ok(result)
 }
 
 /// Checked remainder of a primitive array of type T by a scalar T. If the
@@ -184,9 +222,9 @@ where
 
 impl<T> ArrayRem<T> for PrimitiveArray<T>
 where
-    T: NativeArithmetics + Rem<Output = T> + NumCast,
+    T: NativeArithmetics + Rem<Output = T> + NumCast + Bounded + Zero,
 {
-    fn rem(&self, rhs: &T) -> Self {
+    fn rem(&self, rhs: &T) -> Result<Self> {
         rem_scalar(self, rhs)
     }
 }
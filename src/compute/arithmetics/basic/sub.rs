@@ -11,7 +11,8 @@ use crate::{
             ArrayCheckedSub, ArrayOverflowingSub, ArraySaturatingSub, ArraySub, ArrayWrappingSub,
         },
         arity::{
-            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_with_bitmap,
+            binary, binary_checked, binary_with_bitmap, unary, unary_checked, unary_owned,
+            unary_with_bitmap,
         },
     },
 };
@@ -207,6 +208,27 @@ where
     unary(lhs, |a| a - rhs, lhs.data_type().clone())
 }
 
+/// Same as [`sub_scalar`], but takes ownership of `lhs` and re-uses its allocation when
+/// it is not shared (see [`PrimitiveArray::into_mut`]).
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::sub_scalar_owned;
+/// use arrow2::array::PrimitiveArray;
+///
+/// let a = PrimitiveArray::from([None, Some(5)]);
+/// let result = sub_scalar_owned(a, &1i32);
+/// let expected = PrimitiveArray::from([None, Some(4)]);
+/// assert_eq!(result, expected)
+/// ```
+pub fn sub_scalar_owned<T>(lhs: PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Sub<Output = T>,
+{
+    let rhs = *rhs;
+    unary_owned(lhs, |a| a - rhs)
+}
+
 /// Wrapping subtraction of a scalar T to a [`PrimitiveArray`] of type T.
 /// It do nothing if the result overflows.
 ///
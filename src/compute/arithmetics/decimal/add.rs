@@ -1,19 +1,20 @@
 //! Defines the addition arithmetic kernels for [`PrimitiveArray`] representing decimals.
 use crate::{
-    array::PrimitiveArray,
+    array::{Array, PrimitiveArray},
     buffer::Buffer,
     compute::{
         arithmetics::{ArrayAdd, ArrayCheckedAdd, ArraySaturatingAdd},
-        arity::{binary, binary_checked},
+        arity::{binary, binary_checked, unary, unary_checked},
         utils::{check_same_len, combine_validities},
     },
 };
 use crate::{
     datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, check_precision, get_parameters, max_value, number_digits};
 
 /// Adds two decimal [`PrimitiveArray`] with the same precision and scale.
 /// # Error
@@ -136,6 +137,69 @@ pub fn checked_add(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> Pr
     binary_checked(lhs, rhs, lhs.data_type().clone(), op)
 }
 
+/// Adds a [`PrimitiveScalar`] to a decimal [`PrimitiveArray`] with the same precision and scale.
+/// # Error
+/// Errors iff the precision and scale of both arguments are different or the sum of a
+/// non-null value is larger than the possible number for the precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::add_scalar;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::scalar::PrimitiveScalar;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(1_00i128), None]).to(DataType::Decimal(5, 2));
+/// let b = PrimitiveScalar::from(Some(1_00i128)).to(DataType::Decimal(5, 2));
+///
+/// let result = add_scalar(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(2_00i128), None]).to(DataType::Decimal(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn add_scalar(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveScalar<i128>,
+) -> Result<PrimitiveArray<i128>> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type())?;
+
+    // the valid values that overflow are null in the checked result
+    let result = checked_add_scalar(lhs, rhs);
+    if rhs.is_valid() && result.null_count() != lhs.null_count() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Overflow in addition presented for precision {}",
+            precision
+        )));
+    }
+    Ok(result)
+}
+
+/// Checked addition of a [`PrimitiveScalar`] and a decimal [`PrimitiveArray`] with the same
+/// precision and scale. If the result is larger than the possible number with the selected
+/// precision (overflowing), then the validity for that index is changed to None.
+/// # Panic
+/// This function panics iff the precision and scale of both arguments are different.
+pub fn checked_add_scalar(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveScalar<i128>,
+) -> PrimitiveArray<i128> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let rhs = if let Some(rhs) = rhs.value() {
+        rhs
+    } else {
+        return PrimitiveArray::<i128>::new_null(lhs.data_type().clone(), lhs.len());
+    };
+
+    let max = max_value(precision);
+    let op = move |a: i128| {
+        a.checked_add(rhs)
+            .filter(|result| (-max..=max).contains(result))
+    };
+
+    unary_checked(lhs, op, lhs.data_type().clone())
+}
+
 // Implementation of ArrayAdd trait for PrimitiveArrays
 impl ArrayAdd<PrimitiveArray<i128>> for PrimitiveArray<i128> {
     fn add(&self, rhs: &PrimitiveArray<i128>) -> Self {
@@ -194,6 +258,7 @@ pub fn adaptive_add(
         // The resulting precision is mutable because it could change while
         // looping through the iterator
         let (mut res_p, res_s, diff) = adjusted_precision_scale(*lhs_p, *lhs_s, *rhs_p, *rhs_s);
+        check_precision(res_p)?;
 
         let shift = 10i128.pow(diff as u32);
         let mut max = max_value(res_p);
@@ -225,6 +290,7 @@ pub fn adaptive_add(
 
         let validity = combine_validities(lhs.validity(), rhs.validity());
 
+        check_precision(res_p)?;
         Ok(PrimitiveArray::<i128>::from_data(
             DataType::Decimal(res_p, res_s),
             values,
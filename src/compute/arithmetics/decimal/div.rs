@@ -14,7 +14,7 @@ use crate::{
     scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, check_precision, get_parameters, max_value, number_digits};
 
 /// Divide two decimal primitive arrays with the same precision and scale. If
 /// the precision and scale is different, then an InvalidArgumentError is
@@ -204,8 +204,8 @@ pub fn checked_div(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> Pr
 
 // Implementation of ArrayDiv trait for PrimitiveArrays
 impl ArrayDiv<PrimitiveArray<i128>> for PrimitiveArray<i128> {
-    fn div(&self, rhs: &PrimitiveArray<i128>) -> Self {
-        div(self, rhs)
+    fn div(&self, rhs: &PrimitiveArray<i128>) -> Result<Self> {
+        Ok(div(self, rhs))
     }
 }
 
@@ -254,6 +254,7 @@ pub fn adaptive_div(
         // The resulting precision is mutable because it could change while
         // looping through the iterator
         let (mut res_p, res_s, diff) = adjusted_precision_scale(*lhs_p, *lhs_s, *rhs_p, *rhs_s);
+        check_precision(res_p)?;
 
         let shift = 10i128.pow(diff as u32);
         let shift_1 = 10i128.pow(res_s as u32);
@@ -290,6 +291,7 @@ pub fn adaptive_div(
 
         let validity = combine_validities(lhs.validity(), rhs.validity());
 
+        check_precision(res_p)?;
         Ok(PrimitiveArray::<i128>::from_data(
             DataType::Decimal(res_p, res_s),
             values,
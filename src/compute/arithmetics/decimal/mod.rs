@@ -15,10 +15,28 @@ pub use sub::*;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
+use super::super::utils::MAX_PRECISION;
+
+/// Errors iff `precision` cannot be represented by a [`DataType::Decimal`]
+fn check_precision(precision: usize) -> Result<()> {
+    if precision > MAX_PRECISION {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "The resulting precision ({}) is larger than the maximum precision of a decimal ({})",
+            precision, MAX_PRECISION
+        )))
+    } else {
+        Ok(())
+    }
+}
+
 /// Maximum value that can exist with a selected precision
+/// (saturated to `i128::MAX` for precisions that do not fit in an `i128`)
 #[inline]
 fn max_value(precision: usize) -> i128 {
-    10i128.pow(precision as u32) - 1
+    10i128
+        .checked_pow(precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(i128::MAX)
 }
 
 // Calculates the number of digits in a i128 number
@@ -14,7 +14,7 @@ use crate::{
     scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, check_precision, get_parameters, max_value, number_digits};
 
 /// Multiply two decimal primitive arrays with the same precision and scale. If
 /// the precision and scale is different, then an InvalidArgumentError is
@@ -266,6 +266,7 @@ pub fn adaptive_mul(
         // The resulting precision is mutable because it could change while
         // looping through the iterator
         let (mut res_p, res_s, diff) = adjusted_precision_scale(*lhs_p, *lhs_s, *rhs_p, *rhs_s);
+        check_precision(res_p)?;
 
         let shift = 10i128.pow(diff as u32);
         let shift_1 = 10i128.pow(res_s as u32);
@@ -302,6 +303,7 @@ pub fn adaptive_mul(
 
         let validity = combine_validities(lhs.validity(), rhs.validity());
 
+        check_precision(res_p)?;
         Ok(PrimitiveArray::<i128>::from_data(
             DataType::Decimal(res_p, res_s),
             values,
@@ -1,18 +1,19 @@
 //! Defines the subtract arithmetic kernels for Decimal `PrimitiveArrays`.
 
 use crate::{
-    array::PrimitiveArray,
+    array::{Array, PrimitiveArray},
     buffer::Buffer,
     compute::{
         arithmetics::{ArrayCheckedSub, ArraySaturatingSub, ArraySub},
-        arity::{binary, binary_checked},
+        arity::{binary, binary_checked, unary, unary_checked},
         utils::{check_same_len, combine_validities},
     },
     datatypes::DataType,
     error::{ArrowError, Result},
+    scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adjusted_precision_scale, check_precision, get_parameters, max_value, number_digits};
 
 /// Subtract two decimal primitive arrays with the same precision and scale. If
 /// the precision and scale is different, then an InvalidArgumentError is
@@ -99,6 +100,69 @@ pub fn saturating_sub(
     binary(lhs, rhs, lhs.data_type().clone(), op)
 }
 
+/// Subs a [`PrimitiveScalar`] from a decimal [`PrimitiveArray`] with the same precision and scale.
+/// # Error
+/// Errors iff the precision and scale of both arguments are different or the difference of a
+/// non-null value is larger than the possible number for the precision.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::decimal::sub_scalar;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::scalar::PrimitiveScalar;
+/// use arrow2::datatypes::DataType;
+///
+/// let a = PrimitiveArray::from([Some(1i128), None]).to(DataType::Decimal(5, 2));
+/// let b = PrimitiveScalar::from(Some(1_00i128)).to(DataType::Decimal(5, 2));
+///
+/// let result = sub_scalar(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(-99i128), None]).to(DataType::Decimal(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn sub_scalar(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveScalar<i128>,
+) -> Result<PrimitiveArray<i128>> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type())?;
+
+    // the valid values that overflow are null in the checked result
+    let result = checked_sub_scalar(lhs, rhs);
+    if rhs.is_valid() && result.null_count() != lhs.null_count() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Overflow in subtraction presented for precision {}",
+            precision
+        )));
+    }
+    Ok(result)
+}
+
+/// Checked subtraction of a [`PrimitiveScalar`] and a decimal [`PrimitiveArray`] with the same
+/// precision and scale. If the result is larger than the possible number with the selected
+/// precision (overflowing), then the validity for that index is changed to None.
+/// # Panic
+/// This function panics iff the precision and scale of both arguments are different.
+pub fn checked_sub_scalar(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveScalar<i128>,
+) -> PrimitiveArray<i128> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let rhs = if let Some(rhs) = rhs.value() {
+        rhs
+    } else {
+        return PrimitiveArray::<i128>::new_null(lhs.data_type().clone(), lhs.len());
+    };
+
+    let max = max_value(precision);
+    let op = move |a: i128| {
+        a.checked_sub(rhs)
+            .filter(|result| (-max..=max).contains(result))
+    };
+
+    unary_checked(lhs, op, lhs.data_type().clone())
+}
+
 // Implementation of ArraySub trait for PrimitiveArrays
 impl ArraySub<PrimitiveArray<i128>> for PrimitiveArray<i128> {
     fn sub(&self, rhs: &PrimitiveArray<i128>) -> Self {
@@ -193,6 +257,7 @@ pub fn adaptive_sub(
         // The resulting precision is mutable because it could change while
         // looping through the iterator
         let (mut res_p, res_s, diff) = adjusted_precision_scale(*lhs_p, *lhs_s, *rhs_p, *rhs_s);
+        check_precision(res_p)?;
 
         let shift = 10i128.pow(diff as u32);
         let mut max = max_value(res_p);
@@ -225,6 +290,7 @@ pub fn adaptive_sub(
 
         let validity = combine_validities(lhs.validity(), rhs.validity());
 
+        check_precision(res_p)?;
         Ok(PrimitiveArray::<i128>::from_data(
             DataType::Decimal(res_p, res_s),
             values,
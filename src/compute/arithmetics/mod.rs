@@ -20,6 +20,7 @@ use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
     datatypes::{DataType, IntervalUnit, TimeUnit},
+    error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
 };
 
@@ -37,7 +38,7 @@ macro_rules! primitive {
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt, interval_date32 = $op_interval_date32:tt )? $(, timestamp = $op_timestamp:tt )? $(, durations = $op_durations:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
@@ -45,9 +46,16 @@ macro_rules! arith {
             (Int8, Int8) => primitive!(lhs, rhs, $op, i8),
             (Int16, Int16) => primitive!(lhs, rhs, $op, i16),
             (Int32, Int32) => primitive!(lhs, rhs, $op, i32),
-            (Int64, Int64) | (Duration(_), Duration(_)) => {
-                primitive!(lhs, rhs, $op, i64)
+            (Int64, Int64) => primitive!(lhs, rhs, $op, i64),
+            $ (
+            (Duration(_), Duration(_)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                time::$op_durations(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
+            )?
+            #[allow(unreachable_patterns)]
+            (Duration(_), Duration(_)) => primitive!(lhs, rhs, $op, i64),
             (UInt8, UInt8) => primitive!(lhs, rhs, $op, u8),
             (UInt16, UInt16) => primitive!(lhs, rhs, $op, u16),
             (UInt32, UInt32) => primitive!(lhs, rhs, $op, u32),
@@ -79,14 +87,20 @@ macro_rules! arith {
             }
             )?
             $ (
-            (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
+            (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+            | (Date64, Interval(IntervalUnit::MonthDayNano)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 time::$op_interval(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
+            (Date32, Interval(IntervalUnit::MonthDayNano)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                time::$op_interval_date32(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+            }
             )?
             $ (
-            (Timestamp(_, None), Timestamp(_, None)) => {
+            (Timestamp(_, _), Timestamp(_, _)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
@@ -130,7 +144,7 @@ macro_rules! primitive_scalar {
 // Macro to create a `match` statement with dynamic dispatch to functions based on
 // the array's logical types
 macro_rules! arith_scalar {
-    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, try_decimal = $op_try_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt, interval_date32 = $op_interval_date32:tt )? $(, timestamp = $op_timestamp:tt )? $(, durations = $op_durations:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
@@ -138,9 +152,16 @@ macro_rules! arith_scalar {
             (Int8, Int8) => primitive_scalar!(lhs, rhs, $op, i8),
             (Int16, Int16) => primitive_scalar!(lhs, rhs, $op, i16),
             (Int32, Int32) => primitive_scalar!(lhs, rhs, $op, i32),
-            (Int64, Int64) | (Duration(_), Duration(_)) => {
-                primitive_scalar!(lhs, rhs, $op, i64)
+            (Int64, Int64) => primitive_scalar!(lhs, rhs, $op, i64),
+            $ (
+            (Duration(_), Duration(_)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                time::$op_durations(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
+            )?
+            #[allow(unreachable_patterns)]
+            (Duration(_), Duration(_)) => primitive_scalar!(lhs, rhs, $op, i64),
             (UInt8, UInt8) => primitive_scalar!(lhs, rhs, $op, u8),
             (UInt16, UInt16) => primitive_scalar!(lhs, rhs, $op, u16),
             (UInt32, UInt32) => primitive_scalar!(lhs, rhs, $op, u32),
@@ -155,6 +176,13 @@ macro_rules! arith_scalar {
             }
             )?
             $ (
+            (Decimal(_, _), Decimal(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                decimal::$op_try_decimal(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+            }
+            )?
+            $ (
             (Time32(TimeUnit::Second), Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
             | (Date32, Duration(_)) => {
@@ -172,14 +200,20 @@ macro_rules! arith_scalar {
             }
             )?
             $ (
-            (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
+            (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+            | (Date64, Interval(IntervalUnit::MonthDayNano)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 time::$op_interval(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
+            (Date32, Interval(IntervalUnit::MonthDayNano)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                time::$op_interval_date32(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+            }
             )?
             $ (
-            (Timestamp(_, None), Timestamp(_, None)) => {
+            (Timestamp(_, _), Timestamp(_, _)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
@@ -205,8 +239,11 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         lhs,
         rhs,
         add,
+        decimal = add,
         duration = add_duration,
-        interval = add_interval
+        interval = add_interval,
+        interval_date32 = add_interval_date32,
+        durations = add_durations
     )
 }
 
@@ -216,13 +253,17 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// * the opertion is not supported for the logical types (use [`can_add`] to check)
 /// * the arrays have a different length
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
+/// * the decimals have different precisions or scales, or the sum exceeds their precision
 pub fn add_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
     arith_scalar!(
         lhs,
         rhs,
         add_scalar,
+        try_decimal = add_scalar,
         duration = add_duration_scalar,
-        interval = add_interval_scalar
+        interval = add_interval_scalar,
+        interval_date32 = add_interval_date32_scalar,
+        durations = add_durations_scalar
     )
 }
 
@@ -251,6 +292,8 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
             | (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano))
+            | (Date32, Interval(IntervalUnit::MonthDayNano))
+            | (Date64, Interval(IntervalUnit::MonthDayNano))
     )
 }
 
@@ -267,7 +310,8 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         sub,
         decimal = sub,
         duration = subtract_duration,
-        timestamp = subtract_timestamps
+        timestamp = subtract_timestamps,
+        durations = sub_durations
     )
 }
 
@@ -277,13 +321,16 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// * the opertion is not supported for the logical types (use [`can_sub`] to check)
 /// * the arrays have a different length
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
+/// * the decimals have different precisions or scales, or the difference exceeds their precision
 pub fn sub_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
     arith_scalar!(
         lhs,
         rhs,
         sub_scalar,
+        try_decimal = sub_scalar,
         duration = sub_duration_scalar,
-        timestamp = sub_timestamps_scalar
+        timestamp = sub_timestamps_scalar,
+        durations = sub_durations_scalar
     )
 }
 
@@ -311,7 +358,7 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (Time64(TimeUnit::Microsecond), Duration(_))
             | (Time64(TimeUnit::Nanosecond), Duration(_))
             | (Timestamp(_, _), Duration(_))
-            | (Timestamp(_, None), Timestamp(_, None))
+            | (Timestamp(_, _), Timestamp(_, _))
     )
 }
 
@@ -351,21 +398,97 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
     )
 }
 
+// Macro to create a `match` statement with dynamic dispatch to fallible functions based on
+// the array's logical types
+macro_rules! try_arith {
+    ($lhs:expr, $rhs:expr, $op:tt, $primitive:tt $(, decimal = $op_decimal:tt)?) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        use DataType::*;
+        match (lhs.data_type(), rhs.data_type()) {
+            (Int8, Int8) => $primitive!(lhs, rhs, $op, i8),
+            (Int16, Int16) => $primitive!(lhs, rhs, $op, i16),
+            (Int32, Int32) => $primitive!(lhs, rhs, $op, i32),
+            (Int64, Int64) => $primitive!(lhs, rhs, $op, i64),
+            (UInt8, UInt8) => $primitive!(lhs, rhs, $op, u8),
+            (UInt16, UInt16) => $primitive!(lhs, rhs, $op, u16),
+            (UInt32, UInt32) => $primitive!(lhs, rhs, $op, u32),
+            (UInt64, UInt64) => $primitive!(lhs, rhs, $op, u64),
+            (Float32, Float32) => $primitive!(lhs, rhs, $op, f32),
+            (Float64, Float64) => $primitive!(lhs, rhs, $op, f64),
+            $(
+            (Decimal(_, _), Decimal(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Ok(Box::new(decimal::$op_decimal(lhs, rhs)) as Box<dyn Array>)
+            }
+            )?
+            _ => Err(ArrowError::NotYetImplemented(format!(
+                "{} of {:?} with {:?} is not supported",
+                stringify!($op),
+                lhs.data_type(),
+                rhs.data_type()
+            ))),
+        }
+    }};
+}
+
+// Macro to evaluate match branch in fallible arithmetic function.
+macro_rules! try_primitive {
+    ($lhs:expr, $rhs:expr, $op:tt, $type:ty) => {{
+        let lhs = $lhs.as_any().downcast_ref().unwrap();
+        let rhs = $rhs.as_any().downcast_ref().unwrap();
+
+        basic::$op::<$type>(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>)
+    }};
+}
+
+// Macro to evaluate match branch in fallible arithmetic function with a scalar.
+macro_rules! try_primitive_scalar {
+    ($lhs:expr, $rhs:expr, $op:tt, $type:ty) => {{
+        let lhs = $lhs
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$type>>()
+            .unwrap();
+        let rhs = $rhs
+            .as_any()
+            .downcast_ref::<PrimitiveScalar<$type>>()
+            .unwrap();
+
+        if let Some(rhs) = rhs.value() {
+            basic::$op::<$type>(lhs, &rhs).map(|x| Box::new(x) as Box<dyn Array>)
+        } else {
+            Ok(Box::new(PrimitiveArray::<$type>::new_null(
+                lhs.data_type().clone(),
+                lhs.len(),
+            )) as Box<dyn Array>)
+        }
+    }};
+}
+
 /// Divide of two [`Array`]s.
-/// # Panic
-/// This function panics iff
+/// # Error
+/// This function errors iff
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
 /// * the arrays have a different length
-pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(lhs, rhs, div, decimal = div)
+/// * a non-null integer divisor is zero
+pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    try_arith!(lhs, rhs, div, try_primitive, decimal = div)
 }
 
 /// Divide an [`Array`] with a [`Scalar`].
-/// # Panic
-/// This function panics iff
+/// # Error
+/// This function errors iff
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
-pub fn div_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
-    arith_scalar!(lhs, rhs, div_scalar, decimal = div_scalar)
+/// * the scalar is an integer zero
+pub fn div_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    try_arith!(
+        lhs,
+        rhs,
+        div_scalar,
+        try_primitive_scalar,
+        decimal = div_scalar
+    )
 }
 
 /// Returns whether two [`DataType`]s can be divided by [`div`].
@@ -374,12 +497,14 @@ pub fn can_div(lhs: &DataType, rhs: &DataType) -> bool {
 }
 
 /// Remainder of two [`Array`]s.
-/// # Panic
-/// This function panics iff
+/// # Error
+/// This function errors iff
 /// * the opertion is not supported for the logical types (use [`can_rem`] to check)
 /// * the arrays have a different length
-pub fn rem(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(lhs, rhs, rem)
+/// * a non-null integer divisor is zero
+/// * the remainder of a pair of non-null signed integers overflows (`MIN % -1`)
+pub fn rem(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    try_arith!(lhs, rhs, rem, try_primitive)
 }
 
 /// Returns whether two [`DataType`]s "can be remainder" by [`rem`].
@@ -414,7 +539,9 @@ macro_rules! with_match_negatable {(
         Int128 => __with_ty__! { i128 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
-        UInt8 | UInt16 | UInt32 | UInt64=> todo!(),
+        Int256 | UInt8 | UInt16 | UInt32 | UInt64 | Float16 => {
+            unreachable!("`neg` does not support {:?}; use `can_neg` to check", $key_type)
+        }
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -441,7 +568,10 @@ pub fn neg(array: &dyn Array) -> Box<dyn Array> {
 
             Box::new(DictionaryArray::<$T>::from_data(array.keys().clone(), values)) as Box<dyn Array>
         }),
-        _ => todo!(),
+        _ => unreachable!(
+            "`neg` does not support {:?}; use `can_neg` to check",
+            array.data_type()
+        ),
     }
 }
 
@@ -459,6 +589,7 @@ pub fn can_neg(data_type: &DataType) -> bool {
             | Primitive(Int16)
             | Primitive(Int32)
             | Primitive(Int64)
+            | Primitive(Int128)
             | Primitive(Float64)
             | Primitive(Float32)
             | Primitive(DaysMs)
@@ -559,7 +690,7 @@ pub trait ArrayOverflowingMul<Rhs>: Sized {
 /// Defines basic division operation for primitive arrays
 pub trait ArrayDiv<Rhs>: Sized {
     /// division
-    fn div(&self, rhs: &Rhs) -> Self;
+    fn div(&self, rhs: &Rhs) -> Result<Self>;
 }
 
 /// Defines checked division operation for primitive arrays
@@ -568,10 +699,22 @@ pub trait ArrayCheckedDiv<Rhs>: Sized {
     fn checked_div(&self, rhs: &Rhs) -> Self;
 }
 
+/// Defines saturating division operation for primitive arrays
+pub trait ArraySaturatingDiv<Rhs>: Sized {
+    /// saturating division
+    fn saturating_div(&self, rhs: &Rhs) -> Self;
+}
+
+/// Defines overflowing division operation for primitive arrays
+pub trait ArrayOverflowingDiv<Rhs>: Sized {
+    /// overflowing division
+    fn overflowing_div(&self, rhs: &Rhs) -> (Self, Bitmap);
+}
+
 /// Defines basic reminder operation for primitive arrays
 pub trait ArrayRem<Rhs>: Sized {
     /// remainder
-    fn rem(&self, rhs: &Rhs) -> Self;
+    fn rem(&self, rhs: &Rhs) -> Result<Self>;
 }
 
 /// Defines checked reminder operation for primitive arrays
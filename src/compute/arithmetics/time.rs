@@ -15,7 +15,11 @@ use num_traits::AsPrimitive;
 
 use crate::{
     array::PrimitiveArray,
-    compute::arity::{binary, unary},
+    buffer::Buffer,
+    compute::{
+        arity::{binary, binary_checked, unary},
+        utils::{check_same_len, combine_validities},
+    },
     datatypes::{DataType, TimeUnit},
     error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
@@ -222,9 +226,42 @@ where
     unary(time, op, time.data_type().clone())
 }
 
+/// Returns the finest of two [`TimeUnit`]s alongside the (integer) factors that convert
+/// values in `lhs` and `rhs` to it. Converting to a finer unit is lossless.
+fn finest_time_unit(lhs: TimeUnit, rhs: TimeUnit) -> (TimeUnit, i64, i64) {
+    let lhs_multiple = temporal_conversions::time_unit_multiple(lhs);
+    let rhs_multiple = temporal_conversions::time_unit_multiple(rhs);
+    if lhs_multiple >= rhs_multiple {
+        (lhs, 1, lhs_multiple / rhs_multiple)
+    } else {
+        (rhs, rhs_multiple / lhs_multiple, 1)
+    }
+}
+
+/// Returns the finest of the time units of two [`DataType::Timestamp`]s alongside the
+/// factors that convert `lhs` and `rhs` to it (see [`finest_time_unit`]).
+fn timestamps_scale(lhs: &DataType, rhs: &DataType) -> Result<(TimeUnit, i64, i64)> {
+    match (lhs.to_logical_type(), rhs.to_logical_type()) {
+        (DataType::Timestamp(lhs_unit, _), DataType::Timestamp(rhs_unit, _)) => {
+            Ok(finest_time_unit(*lhs_unit, *rhs_unit))
+        }
+        (lhs, rhs) => Err(ArrowError::InvalidArgumentError(format!(
+            "The difference between timestamps requires two timestamps (got {:?} and {:?})",
+            lhs, rhs
+        ))),
+    }
+}
+
 /// Calculates the difference between two timestamps returning an array of type
-/// Duration. The timeunit enum is used to scale correctly both arrays;
-/// subtracting seconds with seconds, or milliseconds with milliseconds.
+/// [`DataType::Duration`]. When their time units differ, the values are converted
+/// to the finest of the two units, which is also the unit of the result.
+///
+/// Timestamps are stored relative to UTC and thus represent absolute instants:
+/// timestamps with different timezones can be subtracted.
+/// # Error
+/// Errors iff the arrays are not timestamps or have different lengths.
+/// The conversion to the finest unit and the difference wrap around on overflow
+/// (use [`checked_subtract_timestamps`] to detect it).
 ///
 /// # Examples
 /// ```
@@ -257,61 +294,364 @@ pub fn subtract_timestamps(
     lhs: &PrimitiveArray<i64>,
     rhs: &PrimitiveArray<i64>,
 ) -> Result<PrimitiveArray<i64>> {
-    // Matching on both data types from both arrays.
-    // Both timestamps have a Timeunit enum in its data type.
-    // This enum is used to adjust the scale between the timestamps.
-    match (lhs.data_type(), rhs.data_type()) {
-        // Naive timestamp comparison. It doesn't take into account timezones
-        // from the Timestamp timeunit.
-        (DataType::Timestamp(timeunit_a, None), DataType::Timestamp(timeunit_b, None)) => {
-            // Closure for the binary operation. The closure contains the scale
-            // required to calculate the difference between the timestamps.
-            let scale = temporal_conversions::timeunit_scale(*timeunit_a, *timeunit_b);
-            let op = move |a, b| a - (b as f64 * scale) as i64;
-
-            Ok(binary(lhs, rhs, DataType::Duration(*timeunit_a), op))
-        }
-        _ => Err(ArrowError::InvalidArgumentError(
-            "Incorrect data type for the arguments".to_string(),
-        )),
-    }
+    let (time_unit, lhs_scale, rhs_scale) = timestamps_scale(lhs.data_type(), rhs.data_type())?;
+    check_same_len(lhs, rhs)?;
+
+    Ok(binary(
+        lhs,
+        rhs,
+        DataType::Duration(time_unit),
+        move |a, b| {
+            a.wrapping_mul(lhs_scale)
+                .wrapping_sub(b.wrapping_mul(rhs_scale))
+        },
+    ))
+}
+
+/// Checked version of [`subtract_timestamps`]: differences whose conversion to the finest
+/// unit or subtraction overflows are null.
+/// # Error
+/// Errors iff the arrays are not timestamps or have different lengths.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::checked_subtract_timestamps;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let a = PrimitiveArray::from([Some(i64::MAX), Some(2i64)])
+///     .to(DataType::Timestamp(TimeUnit::Second, None));
+/// let b = PrimitiveArray::from([Some(0i64), Some(1_000i64)])
+///     .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+///
+/// let result = checked_subtract_timestamps(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([None, Some(1_000i64)])
+///     .to(DataType::Duration(TimeUnit::Millisecond));
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_subtract_timestamps(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    let (time_unit, lhs_scale, rhs_scale) = timestamps_scale(lhs.data_type(), rhs.data_type())?;
+    check_same_len(lhs, rhs)?;
+
+    Ok(binary_checked(
+        lhs,
+        rhs,
+        DataType::Duration(time_unit),
+        move |a, b| {
+            a.checked_mul(lhs_scale)?
+                .checked_sub(b.checked_mul(rhs_scale)?)
+        },
+    ))
 }
 
-/// Calculates the difference between two timestamps as [`DataType::Duration`] with the same time scale.
+/// Calculates the difference between a timestamp array and a timestamp scalar as
+/// [`DataType::Duration`]. See [`subtract_timestamps`] for details.
 pub fn sub_timestamps_scalar(
     lhs: &PrimitiveArray<i64>,
     rhs: &PrimitiveScalar<i64>,
 ) -> Result<PrimitiveArray<i64>> {
-    let (scale, timeunit_a) =
-        if let (DataType::Timestamp(timeunit_a, None), DataType::Timestamp(timeunit_b, None)) =
-            (lhs.data_type(), rhs.data_type())
-        {
-            (
-                temporal_conversions::timeunit_scale(*timeunit_a, *timeunit_b),
-                timeunit_a,
-            )
-        } else {
-            return Err(ArrowError::InvalidArgumentError(
-                "sub_timestamps_scalar requires both arguments to be timestamps without timezone"
-                    .to_string(),
-            ));
-        };
+    let (time_unit, lhs_scale, rhs_scale) = timestamps_scale(lhs.data_type(), rhs.data_type())?;
+    let data_type = DataType::Duration(time_unit);
 
     let rhs = if let Some(value) = rhs.value() {
-        value
+        value.wrapping_mul(rhs_scale)
     } else {
-        return Ok(PrimitiveArray::<i64>::new_null(
-            lhs.data_type().clone(),
-            lhs.len(),
-        ));
+        return Ok(PrimitiveArray::<i64>::new_null(data_type, lhs.len()));
+    };
+
+    Ok(unary(
+        lhs,
+        move |a| a.wrapping_mul(lhs_scale).wrapping_sub(rhs),
+        data_type,
+    ))
+}
+
+/// Calculates the difference between two [`DataType::Date32`] arrays as a
+/// [`DataType::Duration`] of [`TimeUnit::Second`] (i.e. multiples of a day), since
+/// durations in days do not exist.
+/// # Error
+/// Errors iff the arrays are not [`DataType::Date32`] or have different lengths.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::subtract_date32;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let a = PrimitiveArray::from([Some(10i32), None, Some(-1i32)]).to(DataType::Date32);
+/// let b = PrimitiveArray::from([Some(9i32), Some(1i32), Some(0i32)]).to(DataType::Date32);
+///
+/// let result = subtract_date32(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(86_400i64), None, Some(-86_400i64)])
+///     .to(DataType::Duration(TimeUnit::Second));
+/// assert_eq!(result, expected);
+/// ```
+pub fn subtract_date32(
+    lhs: &PrimitiveArray<i32>,
+    rhs: &PrimitiveArray<i32>,
+) -> Result<PrimitiveArray<i64>> {
+    check_dates(lhs.data_type(), rhs.data_type(), &DataType::Date32)?;
+    check_same_len(lhs, rhs)?;
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    // the difference of two `i32` always fits in an `i64`, also when multiplied by the seconds in a day
+    let values = lhs
+        .values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(a, b)| (*a as i64 - *b as i64) * temporal_conversions::SECONDS_IN_DAY);
+    let values = Buffer::from_trusted_len_iter(values);
+
+    Ok(PrimitiveArray::from_data(
+        DataType::Duration(TimeUnit::Second),
+        values,
+        validity,
+    ))
+}
+
+/// Calculates the difference between two [`DataType::Date64`] arrays as a
+/// [`DataType::Duration`] of [`TimeUnit::Millisecond`].
+/// # Error
+/// Errors iff the arrays are not [`DataType::Date64`] or have different lengths.
+/// The difference wraps around on overflow (use [`checked_subtract_date64`] to detect it).
+pub fn subtract_date64(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    check_dates(lhs.data_type(), rhs.data_type(), &DataType::Date64)?;
+    check_same_len(lhs, rhs)?;
+
+    Ok(binary(
+        lhs,
+        rhs,
+        DataType::Duration(TimeUnit::Millisecond),
+        |a, b| a.wrapping_sub(b),
+    ))
+}
+
+/// Checked version of [`subtract_date64`]: differences that overflow are null.
+/// # Error
+/// Errors iff the arrays are not [`DataType::Date64`] or have different lengths.
+pub fn checked_subtract_date64(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    check_dates(lhs.data_type(), rhs.data_type(), &DataType::Date64)?;
+    check_same_len(lhs, rhs)?;
+
+    Ok(binary_checked(
+        lhs,
+        rhs,
+        DataType::Duration(TimeUnit::Millisecond),
+        |a, b| a.checked_sub(b),
+    ))
+}
+
+fn check_dates(lhs: &DataType, rhs: &DataType, expected: &DataType) -> Result<()> {
+    let lhs = lhs.to_logical_type();
+    let rhs = rhs.to_logical_type();
+    if lhs != expected || rhs != expected {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The difference between dates requires two {:?} (got {:?} and {:?})",
+            expected, lhs, rhs
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the finest of the time units of two [`DataType::Duration`]s alongside the
+/// (integer) factors that convert `lhs` and `rhs` to it. Converting to a finer unit is lossless.
+fn durations_scale(lhs: &DataType, rhs: &DataType) -> Result<(TimeUnit, i64, i64)> {
+    match (lhs.to_logical_type(), rhs.to_logical_type()) {
+        (DataType::Duration(lhs_unit), DataType::Duration(rhs_unit)) => {
+            Ok(finest_time_unit(*lhs_unit, *rhs_unit))
+        }
+        (lhs, rhs) => Err(ArrowError::InvalidArgumentError(format!(
+            "Arithmetic between durations requires two durations (got {:?} and {:?})",
+            lhs, rhs
+        ))),
+    }
+}
+
+fn durations_op<F>(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+    op: F,
+) -> Result<PrimitiveArray<i64>>
+where
+    F: Fn(i64, i64) -> i64,
+{
+    let (time_unit, lhs_scale, rhs_scale) = durations_scale(lhs.data_type(), rhs.data_type())?;
+    check_same_len(lhs, rhs)?;
+
+    Ok(binary(
+        lhs,
+        rhs,
+        DataType::Duration(time_unit),
+        move |a, b| op(a.wrapping_mul(lhs_scale), b.wrapping_mul(rhs_scale)),
+    ))
+}
+
+fn checked_durations_op<F>(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+    op: F,
+) -> Result<PrimitiveArray<i64>>
+where
+    F: Fn(i64, i64) -> Option<i64>,
+{
+    let (time_unit, lhs_scale, rhs_scale) = durations_scale(lhs.data_type(), rhs.data_type())?;
+    check_same_len(lhs, rhs)?;
+
+    Ok(binary_checked(
+        lhs,
+        rhs,
+        DataType::Duration(time_unit),
+        move |a, b| op(a.checked_mul(lhs_scale)?, b.checked_mul(rhs_scale)?),
+    ))
+}
+
+fn durations_op_scalar<F>(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveScalar<i64>,
+    op: F,
+) -> Result<PrimitiveArray<i64>>
+where
+    F: Fn(i64, i64) -> i64,
+{
+    let (time_unit, lhs_scale, rhs_scale) = durations_scale(lhs.data_type(), rhs.data_type())?;
+    let data_type = DataType::Duration(time_unit);
+
+    let rhs = if let Some(rhs) = rhs.value() {
+        rhs.wrapping_mul(rhs_scale)
+    } else {
+        return Ok(PrimitiveArray::<i64>::new_null(data_type, lhs.len()));
     };
 
-    let op = move |a| a - (rhs as f64 * scale) as i64;
+    Ok(unary(
+        lhs,
+        move |a| op(a.wrapping_mul(lhs_scale), rhs),
+        data_type,
+    ))
+}
+
+/// Adds two [`DataType::Duration`] arrays. When their time units differ, the values are
+/// converted to the finest of the two units, which is also the unit of the result.
+/// # Error
+/// Errors iff the arrays are not durations or have different lengths.
+/// The conversion to the finest unit and the sum wrap around on overflow
+/// (use [`checked_add_durations`] to detect it).
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::add_durations;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let a = PrimitiveArray::from([Some(1i64), None, Some(3i64)])
+///     .to(DataType::Duration(TimeUnit::Second));
+/// let b = PrimitiveArray::from([Some(500i64), Some(100i64), Some(0i64)])
+///     .to(DataType::Duration(TimeUnit::Millisecond));
+///
+/// let result = add_durations(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(1_500i64), None, Some(3_000i64)])
+///     .to(DataType::Duration(TimeUnit::Millisecond));
+/// assert_eq!(result, expected);
+/// ```
+pub fn add_durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    durations_op(lhs, rhs, i64::wrapping_add)
+}
+
+/// Checked version of [`add_durations`]: sums whose conversion to the finest unit or addition
+/// overflows are null.
+/// # Error
+/// Errors iff the arrays are not durations or have different lengths.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::checked_add_durations;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let a = PrimitiveArray::from([Some(i64::MAX), Some(1i64)])
+///     .to(DataType::Duration(TimeUnit::Second));
+/// let b = PrimitiveArray::from([Some(0i64), Some(500i64)])
+///     .to(DataType::Duration(TimeUnit::Millisecond));
+///
+/// let result = checked_add_durations(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([None, Some(1_500i64)])
+///     .to(DataType::Duration(TimeUnit::Millisecond));
+/// assert_eq!(result, expected);
+/// ```
+pub fn checked_add_durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    checked_durations_op(lhs, rhs, i64::checked_add)
+}
 
-    Ok(unary(lhs, op, DataType::Duration(*timeunit_a)))
+/// Adds a [`DataType::Duration`] scalar to a [`DataType::Duration`] array.
+/// See [`add_durations`] for details.
+pub fn add_durations_scalar(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveScalar<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    durations_op_scalar(lhs, rhs, i64::wrapping_add)
+}
+
+/// Subtracts two [`DataType::Duration`] arrays. When their time units differ, the values are
+/// converted to the finest of the two units, which is also the unit of the result.
+/// # Error
+/// Errors iff the arrays are not durations or have different lengths.
+/// The conversion to the finest unit and the difference wrap around on overflow
+/// (use [`checked_sub_durations`] to detect it).
+pub fn sub_durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    durations_op(lhs, rhs, i64::wrapping_sub)
 }
 
-/// Adds an interval to a [`DataType::Timestamp`].
+/// Checked version of [`sub_durations`]: differences whose conversion to the finest unit or
+/// subtraction overflows are null.
+/// # Error
+/// Errors iff the arrays are not durations or have different lengths.
+pub fn checked_sub_durations(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveArray<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    checked_durations_op(lhs, rhs, i64::checked_sub)
+}
+
+/// Subtracts a [`DataType::Duration`] scalar from a [`DataType::Duration`] array.
+/// See [`sub_durations`] for details.
+pub fn sub_durations_scalar(
+    lhs: &PrimitiveArray<i64>,
+    rhs: &PrimitiveScalar<i64>,
+) -> Result<PrimitiveArray<i64>> {
+    durations_op_scalar(lhs, rhs, i64::wrapping_sub)
+}
+
+/// The [`TimeUnit`] of a [`DataType::Timestamp`] without timezone or of a [`DataType::Date64`]
+fn naive_time_unit(data_type: &DataType) -> TimeUnit {
+    match data_type.to_logical_type() {
+        DataType::Timestamp(time_unit, None) => *time_unit,
+        DataType::Date64 => TimeUnit::Millisecond,
+        _ => unreachable!(),
+    }
+}
+
+/// Adds an interval to a [`DataType::Timestamp`] or [`DataType::Date64`].
+/// Months are added in the calendar, clamping the day of the month to the
+/// last day of the resulting month (e.g. 2021-01-31 + 1 month = 2021-02-28);
+/// days and nanoseconds are then added linearly.
 pub fn add_interval(
     timestamp: &PrimitiveArray<i64>,
     interval: &PrimitiveArray<months_days_ns>,
@@ -346,14 +686,13 @@ pub fn add_interval(
                     ))
                 }
                 #[cfg(not(feature = "chrono-tz"))]
-                _ => Err(ArrowError::InvalidArgumentError(format!(
-                    "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
-                    timezone_str
-                ))),
+                _ => Err(crate::temporal_conversions::chrono_tz_not_active(
+                    timezone_str,
+                )),
             }
         }
-        DataType::Timestamp(time_unit, None) => {
-            let time_unit = *time_unit;
+        DataType::Timestamp(_, None) | DataType::Date64 => {
+            let time_unit = naive_time_unit(timestamp.data_type());
             Ok(binary(
                 timestamp,
                 interval,
@@ -364,12 +703,14 @@ pub fn add_interval(
             ))
         }
         _ => Err(ArrowError::InvalidArgumentError(
-            "Adding an interval is only supported for `DataType::Timestamp`".to_string(),
+            "Adding an interval is only supported for `DataType::Timestamp` and `DataType::Date64`"
+                .to_string(),
         )),
     }
 }
 
-/// Adds an interval to a [`DataType::Timestamp`].
+/// Adds an interval to a [`DataType::Timestamp`] or [`DataType::Date64`].
+/// See [`add_interval`] for details.
 pub fn add_interval_scalar(
     timestamp: &PrimitiveArray<i64>,
     interval: &PrimitiveScalar<months_days_ns>,
@@ -411,14 +752,13 @@ pub fn add_interval_scalar(
                     ))
                 }
                 #[cfg(not(feature = "chrono-tz"))]
-                _ => Err(ArrowError::InvalidArgumentError(format!(
-                    "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
-                    timezone_str
-                ))),
+                _ => Err(crate::temporal_conversions::chrono_tz_not_active(
+                    timezone_str,
+                )),
             }
         }
-        DataType::Timestamp(time_unit, None) => {
-            let time_unit = *time_unit;
+        DataType::Timestamp(_, None) | DataType::Date64 => {
+            let time_unit = naive_time_unit(timestamp.data_type());
             Ok(unary(
                 timestamp,
                 |timestamp| {
@@ -428,7 +768,78 @@ pub fn add_interval_scalar(
             ))
         }
         _ => Err(ArrowError::InvalidArgumentError(
-            "Adding an interval is only supported for `DataType::Timestamp`".to_string(),
+            "Adding an interval is only supported for `DataType::Timestamp` and `DataType::Date64`"
+                .to_string(),
         )),
     }
 }
+
+/// Adds an interval to a [`DataType::Date32`].
+/// Months are added in the calendar, clamping the day of the month to the
+/// last day of the resulting month (e.g. 2021-01-31 + 1 month = 2021-02-28);
+/// days are then added linearly and nanoseconds are added as whole days,
+/// rounded towards negative infinity.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::time::add_interval_date32;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::datatypes::{DataType, IntervalUnit};
+/// use arrow2::types::months_days_ns;
+///
+/// // 2021-01-31
+/// let date = PrimitiveArray::from([Some(18658i32), None]).to(DataType::Date32);
+/// let interval = PrimitiveArray::from([Some(months_days_ns::new(1, 0, 0)); 2])
+///     .to(DataType::Interval(IntervalUnit::MonthDayNano));
+///
+/// let result = add_interval_date32(&date, &interval).unwrap();
+/// // 2021-02-28
+/// let expected = PrimitiveArray::from([Some(18686i32), None]).to(DataType::Date32);
+/// assert_eq!(result, expected);
+/// ```
+pub fn add_interval_date32(
+    date: &PrimitiveArray<i32>,
+    interval: &PrimitiveArray<months_days_ns>,
+) -> Result<PrimitiveArray<i32>> {
+    if date.data_type().to_logical_type() != &DataType::Date32 {
+        return Err(ArrowError::InvalidArgumentError(
+            "add_interval_date32 requires a `DataType::Date32`".to_string(),
+        ));
+    }
+    check_same_len(date, interval)?;
+
+    Ok(binary(
+        date,
+        interval,
+        date.data_type().clone(),
+        temporal_conversions::add_date32_interval,
+    ))
+}
+
+/// Adds an interval to a [`DataType::Date32`].
+/// See [`add_interval_date32`] for details.
+pub fn add_interval_date32_scalar(
+    date: &PrimitiveArray<i32>,
+    interval: &PrimitiveScalar<months_days_ns>,
+) -> Result<PrimitiveArray<i32>> {
+    if date.data_type().to_logical_type() != &DataType::Date32 {
+        return Err(ArrowError::InvalidArgumentError(
+            "add_interval_date32_scalar requires a `DataType::Date32`".to_string(),
+        ));
+    }
+
+    let interval = if let Some(interval) = interval.value() {
+        interval
+    } else {
+        return Ok(PrimitiveArray::<i32>::new_null(
+            date.data_type().clone(),
+            date.len(),
+        ));
+    };
+
+    Ok(unary(
+        date,
+        |date| temporal_conversions::add_date32_interval(date, interval),
+        date.data_type().clone(),
+    ))
+}
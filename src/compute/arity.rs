@@ -1,5 +1,7 @@
 //! Defines kernels suitable to perform operations to primitive arrays.
 
+use either::Either;
+
 use super::utils::{check_same_len, combine_validities};
 use crate::{
     array::PrimitiveArray,
@@ -32,6 +34,30 @@ where
     PrimitiveArray::<O>::from_data(data_type, values, array.validity().cloned())
 }
 
+/// Same as [`unary`] but takes ownership of `array` and applies `op` in place whenever
+/// `array` can be converted into a [`MutablePrimitiveArray`](crate::array::MutablePrimitiveArray) (see [`PrimitiveArray::into_mut`]),
+/// thereby re-using its allocation. Otherwise, a new array is allocated.
+///
+/// # Implementation
+/// As in [`unary`], the function is applied to all values, including those on null slots.
+#[inline]
+pub fn unary_owned<I, F>(array: PrimitiveArray<I>, op: F) -> PrimitiveArray<I>
+where
+    I: NativeType,
+    F: Fn(I) -> I,
+{
+    match array.into_mut() {
+        Either::Left(array) => unary(&array, op, array.data_type().clone()),
+        Either::Right(mut array) => {
+            array
+                .values_mut_slice()
+                .iter_mut()
+                .for_each(|v| *v = op(*v));
+            array.into()
+        }
+    }
+}
+
 /// Version of unary that checks for errors in the closure used to create the
 /// buffer
 pub fn try_unary<I, F, O>(
@@ -123,8 +149,8 @@ where
 /// Applies a binary operations to two primitive arrays. This is the fastest
 /// way to perform an operation on two primitive array when the benefits of a
 /// vectorized operation outweighs the cost of branching nulls and non-nulls.
-/// # Errors
-/// This function errors iff the arrays have a different length.
+/// # Panic
+/// This function panics iff the arrays have a different length.
 /// # Implementation
 /// This will apply the function for all values, including those on null slots.
 /// This implies that the operation must be infallible for any value of the
@@ -1,6 +1,8 @@
-//! Contains bitwise operators: [`or`], [`and`], [`xor`] and [`not`].
+//! Contains bitwise operators: [`or`], [`and`], [`xor`], [`not`], [`shift_left`] and [`shift_right`].
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
+use num_traits::{CheckedShl, CheckedShr, PrimInt};
+
 use crate::array::PrimitiveArray;
 use crate::compute::arity::{binary, unary};
 use crate::types::NativeType;
@@ -73,3 +75,55 @@ where
 {
     unary(lhs, |a| a & *rhs, lhs.data_type().clone())
 }
+
+/// Shifts every value of a [`PrimitiveArray`] `rhs` bits to the left.
+/// Shifting by the number of bits of `T` or more results in zero.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::bitwise::shift_left;
+/// use arrow2::array::UInt8Array;
+///
+/// let a = UInt8Array::from(&[Some(1), None, Some(0b1000_0001)]);
+/// assert_eq!(shift_left(&a, 1), UInt8Array::from(&[Some(2), None, Some(2)]));
+/// assert_eq!(shift_left(&a, 8), UInt8Array::from(&[Some(0), None, Some(0)]));
+/// ```
+pub fn shift_left<T>(lhs: &PrimitiveArray<T>, rhs: u32) -> PrimitiveArray<T>
+where
+    T: NativeType + PrimInt + CheckedShl,
+{
+    unary(
+        lhs,
+        |a| a.checked_shl(rhs).unwrap_or_else(T::zero),
+        lhs.data_type().clone(),
+    )
+}
+
+/// Shifts every value of a [`PrimitiveArray`] `rhs` bits to the right.
+/// The shift is arithmetic (sign-filling) for signed integers and logical (zero-filling)
+/// for unsigned integers.
+/// Shifting by the number of bits of `T` or more results in a value with all bits set to
+/// the fill bit, i.e. `0` for unsigned and non-negative values and `-1` for negative values.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::bitwise::shift_right;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(4), None, Some(-4)]);
+/// assert_eq!(shift_right(&a, 1), Int8Array::from(&[Some(2), None, Some(-2)]));
+/// assert_eq!(shift_right(&a, 8), Int8Array::from(&[Some(0), None, Some(-1)]));
+/// ```
+pub fn shift_right<T>(lhs: &PrimitiveArray<T>, rhs: u32) -> PrimitiveArray<T>
+where
+    T: NativeType + PrimInt + CheckedShr,
+{
+    let last_bit = (T::zero().count_zeros() - 1) as usize;
+    // shifting by the last bit leaves only the fill bit in every position except (for
+    // unsigned integers) the lowest one, which the extra shift clears.
+    let op = move |a: T| {
+        a.checked_shr(rhs)
+            .unwrap_or_else(|| (a >> last_bit) >> 1usize)
+    };
+    unary(lhs, op, lhs.data_type().clone())
+}
@@ -1,11 +1,11 @@
-//! null-preserving operators such as [`and`], [`or`] and [`not`].
+//! null-preserving operators such as [`and`], [`or`], [`xor`] and [`not`].
 use crate::array::{Array, BooleanArray};
-use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::bitmap::{self, Bitmap, MutableBitmap};
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 use crate::scalar::BooleanScalar;
 
-use super::utils::combine_validities;
+use super::utils::{any_word, combine_validities};
 
 /// Helper function to implement binary kernels
 fn binary_boolean_kernel<F>(lhs: &BooleanArray, rhs: &BooleanArray, op: F) -> Result<BooleanArray>
@@ -20,10 +20,7 @@ where
 
     let validity = combine_validities(lhs.validity(), rhs.validity());
 
-    let left_buffer = lhs.values();
-    let right_buffer = rhs.values();
-
-    let values = op(left_buffer, right_buffer);
+    let values = op(lhs.values(), rhs.values());
 
     Ok(BooleanArray::from_data(DataType::Boolean, values, validity))
 }
@@ -46,7 +43,7 @@ where
 /// # }
 /// ```
 pub fn and(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
-    binary_boolean_kernel(lhs, rhs, |lhs, rhs| lhs & rhs)
+    binary_boolean_kernel(lhs, rhs, bitmap::and)
 }
 
 /// Performs `OR` operation on two arrays. If either left or right value is null then the
@@ -67,7 +64,28 @@ pub fn and(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
 /// # }
 /// ```
 pub fn or(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
-    binary_boolean_kernel(lhs, rhs, |lhs, rhs| lhs | rhs)
+    binary_boolean_kernel(lhs, rhs, bitmap::or)
+}
+
+/// Performs `XOR` operation on two arrays. If either left or right value is null then the
+/// result is also null.
+/// # Error
+/// This function errors when the arrays have different lengths.
+/// # Example
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::error::Result;
+/// use arrow2::compute::boolean::xor;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(vec![Some(false), Some(true), None]);
+/// let b = BooleanArray::from(vec![Some(true), Some(true), Some(false)]);
+/// let xor_ab = xor(&a, &b)?;
+/// assert_eq!(xor_ab, BooleanArray::from(vec![Some(true), Some(false), None]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn xor(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
+    binary_boolean_kernel(lhs, rhs, bitmap::xor)
 }
 
 /// Performs unary `NOT` operation on an arrays. If value is null then the result is also
@@ -83,12 +101,15 @@ pub fn or(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
 /// # }
 /// ```
 pub fn not(array: &BooleanArray) -> BooleanArray {
-    let values = !array.values();
+    let values = bitmap::not(array.values());
     let validity = array.validity().cloned();
     BooleanArray::from_data(DataType::Boolean, values, validity)
 }
 
 /// Returns a non-null [BooleanArray] with whether each value of the array is null.
+///
+/// All slots of a [`NullArray`](crate::array::NullArray) are null, and the nulls of a
+/// [`DictionaryArray`](crate::array::DictionaryArray) are the nulls of its keys.
 /// # Error
 /// This function never errors.
 /// # Example
@@ -105,14 +126,22 @@ pub fn is_null(input: &dyn Array) -> BooleanArray {
     let len = input.len();
 
     let values = match input.validity() {
-        None => MutableBitmap::from_len_zeroed(len).into(),
-        Some(buffer) => !buffer,
+        None if input.data_type() == &DataType::Null => {
+            let mut mutable = MutableBitmap::new();
+            mutable.extend_constant(len, true);
+            mutable.into()
+        }
+        None => Bitmap::new_zeroed(len),
+        Some(buffer) => bitmap::not(buffer),
     };
 
     BooleanArray::from_data(DataType::Boolean, values, None)
 }
 
 /// Returns a non-null [BooleanArray] with whether each value of the array is not null.
+///
+/// All slots of a [`NullArray`](crate::array::NullArray) are null, and the nulls of a
+/// [`DictionaryArray`](crate::array::DictionaryArray) are the nulls of its keys.
 /// # Example
 /// ```rust
 /// use arrow2::array::BooleanArray;
@@ -125,6 +154,7 @@ pub fn is_null(input: &dyn Array) -> BooleanArray {
 /// ```
 pub fn is_not_null(input: &dyn Array) -> BooleanArray {
     let values = match input.validity() {
+        None if input.data_type() == &DataType::Null => Bitmap::new_zeroed(input.len()),
         None => {
             let mut mutable = MutableBitmap::new();
             mutable.extend_constant(input.len(), true);
@@ -186,24 +216,45 @@ pub fn or_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray {
     }
 }
 
-/// Check if any of the values in the array is `true`
-pub fn any(array: &BooleanArray) -> bool {
-    if array.is_empty() {
-        false
-    } else if array.validity().is_some() {
-        array.into_iter().any(|v| v == Some(true))
-    } else {
-        let vals = array.values();
-        vals.null_count() != vals.len()
+/// Performs `XOR` operation on an array and a scalar value. If either left or right value
+/// is null then the result is also null.
+/// # Example
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean::xor_scalar;
+/// use arrow2::scalar::BooleanScalar;
+/// # fn main() {
+/// let array = BooleanArray::from_slice(&[false, false, true, true]);
+/// let scalar = BooleanScalar::new(Some(true));
+/// let result = xor_scalar(&array, &scalar);
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false, false]));
+/// # }
+/// ```
+pub fn xor_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray {
+    match scalar.value() {
+        Some(true) => not(array),
+        Some(false) => array.clone(),
+        None => BooleanArray::new_null(DataType::Boolean, array.len()),
     }
 }
 
-/// Check if all of the values in the array are `true`
+/// Check if any of the values in the array is `true`. Nulls are ignored.
+///
+/// This stops at the first 64 slots that contain a `true`.
+/// See [`boolean_kleene::any`](crate::compute::boolean_kleene::any) for a null-aware version.
+pub fn any(array: &BooleanArray) -> bool {
+    any_word(array, |values, validity| values & validity)
+}
+
+/// Check if all of the values in the array are `true`.
+/// Returns `false` if the array is empty or has nulls.
+///
+/// This stops at the first 64 slots that contain a `false`.
+/// See [`boolean_kleene::all`](crate::compute::boolean_kleene::all) for a null-aware version.
 pub fn all(array: &BooleanArray) -> bool {
     if array.is_empty() || array.null_count() > 0 {
         false
     } else {
-        let vals = array.values();
-        vals.null_count() == 0
+        !any_word(array, |values, validity| !values & validity)
     }
 }
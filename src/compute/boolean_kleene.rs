@@ -2,8 +2,10 @@
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 use crate::scalar::BooleanScalar;
+
+use super::utils::any_word;
 use crate::{
-    array::BooleanArray,
+    array::{Array, BooleanArray},
     bitmap::{binary, quaternary, ternary, unary, Bitmap, MutableBitmap},
 };
 
@@ -250,3 +252,57 @@ pub fn and_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray
         }
     }
 }
+
+/// Returns whether any of the values in the array is `true`, following
+/// [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics):
+/// * `Some(true)` if any of the values is `true`
+/// * `None` if none of its values is `true` and the array has nulls
+/// * `Some(false)` otherwise, including when the array is empty
+///
+/// This stops at the first 64 slots that contain a `true`.
+/// # Example
+///
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean_kleene::any;
+///
+/// assert_eq!(any(&BooleanArray::from(&[Some(false), None, Some(true)])), Some(true));
+/// assert_eq!(any(&BooleanArray::from(&[Some(false), None])), None);
+/// assert_eq!(any(&BooleanArray::from_slice(&[false, false])), Some(false));
+/// ```
+pub fn any(array: &BooleanArray) -> Option<bool> {
+    if any_word(array, |values, validity| values & validity) {
+        Some(true)
+    } else if array.null_count() > 0 {
+        None
+    } else {
+        Some(false)
+    }
+}
+
+/// Returns whether all of the values in the array are `true`, following
+/// [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics):
+/// * `Some(false)` if any of the values is `false`
+/// * `None` if none of its values is `false` and the array has nulls
+/// * `Some(true)` otherwise, including when the array is empty
+///
+/// This stops at the first 64 slots that contain a `false`.
+/// # Example
+///
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean_kleene::all;
+///
+/// assert_eq!(all(&BooleanArray::from(&[Some(true), None, Some(false)])), Some(false));
+/// assert_eq!(all(&BooleanArray::from(&[Some(true), None])), None);
+/// assert_eq!(all(&BooleanArray::from_slice(&[true, true])), Some(true));
+/// ```
+pub fn all(array: &BooleanArray) -> Option<bool> {
+    if any_word(array, |values, validity| !values & validity) {
+        Some(false)
+    } else if array.null_count() > 0 {
+        None
+    } else {
+        Some(true)
+    }
+}
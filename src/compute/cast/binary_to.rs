@@ -1,9 +1,9 @@
-use std::convert::TryFrom;
-
 use crate::error::{ArrowError, Result};
-use crate::{array::*, buffer::Buffer, datatypes::DataType, types::NativeType};
+use crate::{
+    array::*, bitmap::MutableBitmap, buffer::Buffer, datatypes::DataType, types::NativeType,
+};
 
-use super::CastOptions;
+use super::{narrow_offsets, CastOptions};
 
 /// Conversion of binary
 pub fn binary_to_large_binary(from: &BinaryArray<i32>, to_data_type: DataType) -> BinaryArray<i64> {
@@ -14,16 +14,15 @@ pub fn binary_to_large_binary(from: &BinaryArray<i32>, to_data_type: DataType) -
 }
 
 /// Conversion of binary
+/// # Errors
+/// This function errors if the values of `from` do not fit in `i32` offsets.
 pub fn binary_large_to_binary(
     from: &BinaryArray<i64>,
     to_data_type: DataType,
 ) -> Result<BinaryArray<i32>> {
-    let values = from.values().clone();
-    let _ =
-        i32::try_from(*from.offsets().last().unwrap()).map_err(ArrowError::from_external_error)?;
+    let (offsets, start, length) = narrow_offsets(from.offsets())?;
+    let values = from.values().clone().slice(start, length);
 
-    let offsets = from.offsets().iter().map(|x| *x as i32);
-    let offsets = Buffer::from_trusted_len_iter(offsets);
     Ok(BinaryArray::<i32>::from_data(
         to_data_type,
         offsets,
@@ -32,6 +31,110 @@ pub fn binary_large_to_binary(
     ))
 }
 
+/// Conversion of binary to utf8, making any value that is not valid utf8 `None`.
+///
+/// This is zero-copy when every value is valid utf8.
+pub fn binary_to_utf8<O: Offset>(from: &BinaryArray<O>) -> Utf8Array<O> {
+    Utf8Array::<O>::try_from_binary(from.clone()).unwrap_or_else(|_| {
+        let iter = from
+            .iter()
+            .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
+        Utf8Array::<O>::from_trusted_len_iter(iter)
+    })
+}
+
+pub(super) fn binary_to_utf8_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    if options.strict {
+        Ok(Box::new(Utf8Array::<O>::try_from_binary(from.clone())?))
+    } else {
+        Ok(Box::new(binary_to_utf8(from)))
+    }
+}
+
+/// Casts a [`BinaryArray`] to a [`FixedSizeBinaryArray`] of `to_data_type`, making any
+/// value whose length differs from the size of `to_data_type` `None`.
+/// # Panics
+/// This function panics if `to_data_type` is not a [`DataType::FixedSizeBinary`].
+pub fn binary_to_fixed_size_binary<O: Offset>(
+    from: &BinaryArray<O>,
+    to_data_type: DataType,
+) -> FixedSizeBinaryArray {
+    let size = FixedSizeBinaryArray::get_size(&to_data_type);
+
+    let mut values = Vec::<u8>::with_capacity(from.len() * size);
+    let mut validity = MutableBitmap::with_capacity(from.len());
+    for value in from.iter() {
+        match value {
+            Some(value) if value.len() == size => {
+                values.extend_from_slice(value);
+                validity.push(true);
+            }
+            _ => {
+                values.resize(values.len() + size, 0);
+                validity.push(false);
+            }
+        }
+    }
+
+    FixedSizeBinaryArray::from_data(to_data_type, values.into(), validity.into())
+}
+
+pub(super) fn binary_to_fixed_size_binary_dyn<O: Offset>(
+    from: &dyn Array,
+    to_data_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let to = binary_to_fixed_size_binary(from, to_data_type.clone());
+
+    if options.strict && to.null_count() != from.null_count() {
+        let index = (0..from.len())
+            .find(|i| from.is_valid(*i) && to.is_null(*i))
+            .unwrap();
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The value at index {} has {} bytes and does not fit in a {:?}",
+            index,
+            from.value(index).len(),
+            to.data_type()
+        )));
+    }
+    Ok(Box::new(to))
+}
+
+/// Conversion of [`FixedSizeBinaryArray`] to [`BinaryArray`].
+/// # Errors
+/// This function errors with [`ArrowError::Overflow`] if the values of `from` do not fit
+/// in `O` offsets.
+pub fn fixed_size_binary_binary<O: Offset>(
+    from: &FixedSizeBinaryArray,
+    to_data_type: DataType,
+) -> Result<BinaryArray<O>> {
+    let values = from.values().clone();
+    let size = from.size();
+    let offsets = (0..=from.len())
+        .map(|i| O::from_usize(i * size).ok_or(ArrowError::Overflow))
+        .collect::<Result<Vec<_>>>()?
+        .into();
+    Ok(BinaryArray::<O>::from_data(
+        to_data_type,
+        offsets,
+        values,
+        from.validity().cloned(),
+    ))
+}
+
+pub(super) fn fixed_size_binary_binary_dyn<O: Offset>(
+    from: &dyn Array,
+    to_data_type: &DataType,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    fixed_size_binary_binary::<O>(from, to_data_type.clone()).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
 /// Casts a [`BinaryArray`] to a [`PrimitiveArray`] at best-effort using `lexical_core::parse_partial`, making any uncastable value as zero.
 pub fn partial_binary_to_primitive<O: Offset, T>(
     from: &BinaryArray<O>,
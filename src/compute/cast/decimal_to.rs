@@ -1,9 +1,47 @@
 use num_traits::{AsPrimitive, Float, NumCast};
 
-use crate::error::Result;
-use crate::types::NativeType;
+use crate::error::{ArrowError, Result};
+use crate::types::{i256, NativeType};
 use crate::{array::*, datatypes::DataType};
 
+use super::CastOptions;
+
+/// How the digits dropped when reducing the scale of a decimal are rounded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DecimalRounding {
+    /// The dropped digits are discarded (e.g. `1.25 -> 1.2` and `-1.25 -> -1.2`)
+    #[default]
+    Truncate,
+    /// Rounds to the nearest value, and ties away from zero (e.g. `1.25 -> 1.3` and `-1.25 -> -1.3`)
+    HalfAwayFromZero,
+}
+
+/// The largest absolute value representable by a decimal of `precision`
+#[inline]
+pub(super) fn max_for_precision(precision: usize) -> i128 {
+    10_i128
+        .checked_pow(precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(i128::MAX)
+}
+
+/// Returns the (integer) decimal `x` divided by `factor`, rounded according to `rounding`
+#[inline]
+pub(super) fn div_round(x: i128, factor: i128, rounding: DecimalRounding) -> i128 {
+    let quotient = x / factor;
+    match rounding {
+        DecimalRounding::Truncate => quotient,
+        DecimalRounding::HalfAwayFromZero => {
+            let remainder = (x % factor).abs();
+            if remainder >= factor - remainder {
+                quotient + x.signum()
+            } else {
+                quotient
+            }
+        }
+    }
+}
+
 #[inline]
 fn decimal_to_decimal_impl<F: Fn(i128) -> Option<i128>>(
     from: &PrimitiveArray<i128>,
@@ -11,31 +49,44 @@ fn decimal_to_decimal_impl<F: Fn(i128) -> Option<i128>>(
     to_precision: usize,
     to_scale: usize,
 ) -> PrimitiveArray<i128> {
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
-
-    let values = from.iter().map(|x| {
-        x.and_then(|x| {
-            op(*x).and_then(|x| {
-                if x > max_for_precision || x < min_for_precision {
-                    None
-                } else {
-                    Some(x)
-                }
-            })
-        })
-    });
+    let max_for_precision = max_for_precision(to_precision);
+
+    let values = from
+        .iter()
+        .map(|x| x.and_then(|x| op(*x).filter(|x| x.abs() <= max_for_precision)));
     PrimitiveArray::<i128>::from_trusted_len_iter(values)
         .to(DataType::Decimal(to_precision, to_scale))
 }
 
-/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
+/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow.
+/// Digits dropped when reducing the scale are truncated.
 pub fn decimal_to_decimal(
     from: &PrimitiveArray<i128>,
     to_precision: usize,
     to_scale: usize,
+) -> PrimitiveArray<i128> {
+    decimal_to_decimal_with_rounding(from, to_precision, to_scale, DecimalRounding::Truncate)
+}
+
+/// Returns a [`PrimitiveArray<i128>`] with the casted values, where the digits dropped when
+/// reducing the scale are rounded according to `rounding`. Values are `None` when they do not
+/// fit in `to_precision`.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::cast::{decimal_to_decimal_with_rounding, DecimalRounding};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = PrimitiveArray::from([Some(1_25i128), Some(-1_25), Some(999_99)]).to(DataType::Decimal(5, 2));
+/// let result = decimal_to_decimal_with_rounding(&array, 3, 1, DecimalRounding::HalfAwayFromZero);
+/// let expected = PrimitiveArray::from([Some(1_3i128), Some(-1_3), None]).to(DataType::Decimal(3, 1));
+/// assert_eq!(result, expected);
+/// ```
+pub fn decimal_to_decimal_with_rounding(
+    from: &PrimitiveArray<i128>,
+    to_precision: usize,
+    to_scale: usize,
+    rounding: DecimalRounding,
 ) -> PrimitiveArray<i128> {
     let (from_precision, from_scale) =
         if let DataType::Decimal(p, s) = from.data_type().to_logical_type() {
@@ -52,18 +103,29 @@ pub fn decimal_to_decimal(
     // a number will never overflow (validity is preserved)
 
     if from_scale > to_scale {
-        let factor = 10_i128.pow((from_scale - to_scale) as u32);
+        let factor = 10_i128.checked_pow((from_scale - to_scale) as u32);
         decimal_to_decimal_impl(
             from,
-            |x: i128| x.checked_div(factor),
+            |x: i128| {
+                // a factor larger than any i128 results in zero
+                Some(
+                    factor
+                        .map(|factor| div_round(x, factor, rounding))
+                        .unwrap_or(0),
+                )
+            },
             to_precision,
             to_scale,
         )
     } else {
-        let factor = 10_i128.pow((to_scale - from_scale) as u32);
+        let factor = 10_i128.checked_pow((to_scale - from_scale) as u32);
         decimal_to_decimal_impl(
             from,
-            |x: i128| x.checked_mul(factor),
+            |x: i128| {
+                factor
+                    .and_then(|factor| x.checked_mul(factor))
+                    .or(if x == 0 { Some(0) } else { None })
+            },
             to_precision,
             to_scale,
         )
@@ -74,9 +136,258 @@ pub(super) fn decimal_to_decimal_dyn(
     from: &dyn Array,
     to_precision: usize,
     to_scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    let to = decimal_to_decimal_with_rounding(from, to_precision, to_scale, options.rounding);
+    if options.strict && to.null_count() != from.null_count() {
+        let index = (0..from.len())
+            .find(|i| from.is_valid(*i) && to.is_null(*i))
+            .unwrap();
+        let from_utf8 = decimal_to_utf8::<i32>(&from.slice(index, 1));
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The value {} at index {} does not fit in a {:?}",
+            from_utf8.value(0),
+            index,
+            to.data_type()
+        )));
+    }
+    Ok(Box::new(to))
+}
+
+/// The largest absolute value representable by a 256-bit decimal of `precision`
+#[inline]
+fn max256_for_precision(precision: usize) -> i256 {
+    i256::from(10)
+        .checked_pow(precision as u32)
+        .and_then(|x| x.checked_sub(i256::from(1)))
+        .unwrap_or(i256::MAX)
+}
+
+/// Returns the absolute value of `x`, saturating at [`i256::MAX`]
+#[inline]
+fn abs256(x: i256) -> i256 {
+    if x.is_negative() {
+        x.checked_neg().unwrap_or(i256::MAX)
+    } else {
+        x
+    }
+}
+
+/// Returns the (integer) decimal `x` divided by `factor`, rounded according to `rounding`
+#[inline]
+fn div_round256(x: i256, factor: i256, rounding: DecimalRounding) -> i256 {
+    let quotient = x.checked_div(factor).unwrap();
+    match rounding {
+        DecimalRounding::Truncate => quotient,
+        DecimalRounding::HalfAwayFromZero => {
+            let remainder = abs256(x.checked_rem(factor).unwrap());
+            if remainder >= factor.checked_sub(remainder).unwrap() {
+                let one = if x.is_negative() { -1 } else { 1 };
+                quotient.checked_add(i256::from(one)).unwrap()
+            } else {
+                quotient
+            }
+        }
+    }
+}
+
+/// Returns a [`PrimitiveArray<i256>`] of `DataType::Decimal256(to_precision, to_scale)` with
+/// the casted values, where the digits dropped when reducing the scale are rounded according
+/// to `rounding`. Values are `None` when they do not fit in `to_precision`.
+pub fn decimal256_to_decimal256(
+    from: &PrimitiveArray<i256>,
+    to_precision: usize,
+    to_scale: usize,
+    rounding: DecimalRounding,
+) -> PrimitiveArray<i256> {
+    let (from_precision, from_scale) =
+        if let DataType::Decimal256(p, s) = from.data_type().to_logical_type() {
+            (*p, *s)
+        } else {
+            panic!("internal error: i256 is always a decimal")
+        };
+    let to_type = DataType::Decimal256(to_precision, to_scale);
+
+    if to_scale == from_scale && to_precision >= from_precision {
+        // fast path
+        return from.clone().to(to_type);
+    }
+
+    let max_for_precision = max256_for_precision(to_precision);
+    let ten = i256::from(10);
+    let zero = i256::default();
+    let op: Box<dyn Fn(i256) -> Option<i256>> = if from_scale > to_scale {
+        let factor = ten.checked_pow((from_scale - to_scale) as u32);
+        // a factor larger than any i256 results in zero
+        Box::new(move |x| Some(factor.map(|f| div_round256(x, f, rounding)).unwrap_or(zero)))
+    } else {
+        let factor = ten.checked_pow((to_scale - from_scale) as u32);
+        Box::new(move |x| {
+            factor
+                .and_then(|factor| x.checked_mul(factor))
+                .or(if x == zero { Some(zero) } else { None })
+        })
+    };
+
+    let values = from
+        .iter()
+        .map(|x| x.and_then(|x| op(*x).filter(|x| abs256(*x) <= max_for_precision)));
+    PrimitiveArray::<i256>::from_trusted_len_iter(values).to(to_type)
+}
+
+/// Returns a [`PrimitiveArray<i256>`] of `DataType::Decimal256(to_precision, to_scale)` with
+/// the casted values, where the digits dropped when reducing the scale are rounded according
+/// to `rounding`. Values are `None` when they do not fit in `to_precision`.
+///
+/// Widening to the same scale and an equal or larger precision never overflows.
+/// # Examples
+/// ```
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::cast::{decimal_to_decimal256, DecimalRounding};
+/// use arrow2::datatypes::DataType;
+/// use arrow2::types::i256;
+///
+/// let array = PrimitiveArray::from([Some(1_25i128), None]).to(DataType::Decimal(5, 2));
+/// let result = decimal_to_decimal256(&array, 40, 3, DecimalRounding::Truncate);
+/// let expected = PrimitiveArray::from([Some(i256::from(1_250)), None]).to(DataType::Decimal256(40, 3));
+/// assert_eq!(result, expected);
+/// ```
+pub fn decimal_to_decimal256(
+    from: &PrimitiveArray<i128>,
+    to_precision: usize,
+    to_scale: usize,
+    rounding: DecimalRounding,
+) -> PrimitiveArray<i256> {
+    let (from_precision, from_scale) =
+        if let DataType::Decimal(p, s) = from.data_type().to_logical_type() {
+            (*p, *s)
+        } else {
+            panic!("internal error: i128 is always a decimal")
+        };
+
+    let values = from.values().iter().map(|x| i256::from(*x)).collect();
+    let from = PrimitiveArray::<i256>::from_data(
+        DataType::Decimal256(from_precision, from_scale),
+        values,
+        from.validity().cloned(),
+    );
+    decimal256_to_decimal256(&from, to_precision, to_scale, rounding)
+}
+
+/// Returns a [`PrimitiveArray<i128>`] of `DataType::Decimal(to_precision, to_scale)` with
+/// the casted values, where the digits dropped when reducing the scale are rounded according
+/// to `rounding`. Values are `None` when they do not fit in `to_precision`.
+pub fn decimal256_to_decimal(
+    from: &PrimitiveArray<i256>,
+    to_precision: usize,
+    to_scale: usize,
+    rounding: DecimalRounding,
+) -> PrimitiveArray<i128> {
+    let to = decimal256_to_decimal256(from, to_precision, to_scale, rounding);
+
+    let values = to.iter().map(|x| x.and_then(|x| x.to_i128()));
+    PrimitiveArray::<i128>::from_trusted_len_iter(values)
+        .to(DataType::Decimal(to_precision, to_scale))
+}
+
+/// Errors iff `options.strict` and a valid value of `from` is null in `to`, where
+/// `format` returns the value of `from` at an index as a string.
+fn check_strict<F: Fn(usize) -> String>(
+    from: &dyn Array,
+    to: &dyn Array,
+    options: CastOptions,
+    format: F,
+) -> Result<()> {
+    if options.strict && to.null_count() != from.null_count() {
+        let index = (0..from.len())
+            .find(|i| from.is_valid(*i) && to.is_null(*i))
+            .unwrap();
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The value {} at index {} does not fit in a {:?}",
+            format(index),
+            index,
+            to.data_type()
+        )));
+    }
+    Ok(())
+}
+
+fn format_decimal256(from: &PrimitiveArray<i256>, index: usize) -> String {
+    let mut string = String::new();
+    get_value_display(from, "")(&mut string, index).unwrap();
+    string
+}
+
+pub(super) fn decimal_to_decimal256_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    let to = decimal_to_decimal256(from, to_precision, to_scale, options.rounding);
+    check_strict(from, &to, options, |index| {
+        decimal_to_utf8::<i32>(&from.slice(index, 1))
+            .value(0)
+            .to_string()
+    })?;
+    Ok(Box::new(to))
+}
+
+pub(super) fn decimal256_to_decimal_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    let to = decimal256_to_decimal(from, to_precision, to_scale, options.rounding);
+    check_strict(from, &to, options, |index| format_decimal256(from, index))?;
+    Ok(Box::new(to))
+}
+
+pub(super) fn decimal256_to_decimal256_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(decimal_to_decimal(from, to_precision, to_scale)))
+    let to = decimal256_to_decimal256(from, to_precision, to_scale, options.rounding);
+    check_strict(from, &to, options, |index| format_decimal256(from, index))?;
+    Ok(Box::new(to))
+}
+
+/// Returns the decimal `value` of `scale` as a string, e.g. `-1234` of scale 2 is `"-12.34"`
+fn format_decimal(value: i128, scale: usize) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    // left-pad with zeros so that there is at least one integer digit
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Returns a [`Utf8Array`] with the decimal values formatted with their scale,
+/// e.g. `-1234` of `Decimal(5, 2)` is `"-12.34"`.
+pub fn decimal_to_utf8<O: Offset>(from: &PrimitiveArray<i128>) -> Utf8Array<O> {
+    let scale = if let DataType::Decimal(_, s) = from.data_type().to_logical_type() {
+        *s
+    } else {
+        panic!("internal error: i128 is always a decimal")
+    };
+
+    let iter = from.iter().map(|x| x.map(|x| format_decimal(*x, scale)));
+    Utf8Array::<O>::from_trusted_len_iter(iter)
+}
+
+pub(super) fn decimal_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_utf8::<O>(from)))
 }
 
 /// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
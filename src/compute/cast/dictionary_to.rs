@@ -49,7 +49,7 @@ pub fn wrapping_dictionary_to_dictionary_values<K: DictionaryKey>(
         values_type,
         CastOptions {
             wrapped: true,
-            partial: false,
+            ..Default::default()
         },
     )?
     .into();
@@ -137,8 +137,8 @@ where
     // use the take kernel to expand out the dictionary
     let values = cast(values, to_type, options)?;
 
-    // take requires first casting i32
-    let indices = primitive_to_primitive::<_, i32>(keys, &DataType::Int32);
+    // take requires first casting i64
+    let indices = primitive_to_primitive::<_, i64>(keys, &DataType::Int64);
 
     take(values.as_ref(), &indices)
 }
@@ -5,6 +5,7 @@ mod boolean_to;
 mod decimal_to;
 mod dictionary_to;
 mod primitive_to;
+mod struct_to;
 mod utf8_to;
 
 pub use binary_to::*;
@@ -12,17 +13,19 @@ pub use boolean_to::*;
 pub use decimal_to::*;
 pub use dictionary_to::*;
 pub use primitive_to::*;
+pub use struct_to::*;
 pub use utf8_to::*;
 
 use crate::{
     array::*,
+    buffer::Buffer,
     datatypes::*,
     error::{ArrowError, Result},
 };
 
 /// options defining how Cast kernels behave
 #[derive(Clone, Copy, Debug, Default)]
-pub struct CastOptions {
+pub struct CastOptions<'a> {
     /// default to false
     /// whether an overflowing cast should be converted to `None` (default), or be wrapped (i.e. `256i16 as u8 = 0` vectorized).
     /// Settings this to `true` is 5-6x faster for numeric types.
@@ -30,9 +33,34 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to `None`
+    /// the [chrono format](chrono::format::strftime) used to parse strings to dates and timestamps.
+    /// When `None`, dates are parsed as `%Y-%m-%d`, `Date64` as `%Y-%m-%dT%H:%M:%S%.f` and timestamps as RFC3339.
+    pub format: Option<&'a str>,
+    /// default to false
+    /// whether a value that cannot be cast errors instead of being converted to `None`.
+    /// This applies to casts from strings to numbers, booleans, dates, timestamps and decimals,
+    /// between decimals and from binary to fixed-size binary and to utf8.
+    pub strict: bool,
+    /// default to [`DecimalRounding::Truncate`]
+    /// how the digits dropped when casting to a decimal of smaller scale are rounded.
+    pub rounding: DecimalRounding,
+    /// default to false
+    /// whether casts between naive and timezone-aware timestamps keep the wall-clock time
+    /// (e.g. the naive `12:00` is `11:00 UTC` in `+01:00`) instead of the instant, which
+    /// interprets naive timestamps as UTC.
+    pub wall_clock: bool,
+    /// default to false
+    /// whether casting a struct to a struct with a nullable field that does not exist in the
+    /// source fills it with nulls instead of erroring.
+    pub null_missing_fields: bool,
+    /// default to false
+    /// whether casting a struct to a struct drops the source fields that do not exist in the
+    /// target instead of erroring.
+    pub drop_extra_fields: bool,
 }
 
-impl CastOptions {
+impl CastOptions<'_> {
     fn with_wrapped(&self, v: bool) -> Self {
         let mut option = *self;
         option.wrapped = v;
@@ -68,16 +96,35 @@ macro_rules! primitive_dyn {
     }};
 }
 
+/// Whether `timezone` can be parsed to an offset.
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for `timezone`.
+fn is_parsable_timezone(timezone: &str) -> bool {
+    #[cfg(feature = "chrono-tz")]
+    let parsable = crate::temporal_conversions::parse_offset_tz(timezone).is_ok();
+    #[cfg(not(feature = "chrono-tz"))]
+    let parsable = false;
+    parsable || crate::temporal_conversions::parse_offset(timezone).is_ok()
+}
+
 /// Return true if a value of type `from_type` can be cast into a
 /// value of `to_type`. Note that such as cast may be lossy.
 ///
 /// If this function returns true to stay consistent with the `cast` kernel below.
+/// Casts that only succeed with non-default [`CastOptions`] (e.g. structs with missing or
+/// extra fields) are not castable.
 pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     use self::DataType::*;
     if from_type == to_type {
         return true;
     }
 
+    if let Extension(_, inner, _) = from_type {
+        return can_cast_types(inner, to_type);
+    }
+    if let Extension(_, inner, _) = to_type {
+        return can_cast_types(from_type, inner);
+    }
+
     match (from_type, to_type) {
         (
             Null,
@@ -89,6 +136,20 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
             Null,
         ) => true,
+        // as in `cast` with the default options, the fields must match by name
+        (Struct(from_fields), Struct(to_fields)) => {
+            from_fields
+                .iter()
+                .all(|from_field| to_fields.iter().any(|x| x.name == from_field.name))
+                && to_fields.iter().all(|to_field| {
+                    match from_fields.iter().find(|x| x.name == to_field.name) {
+                        Some(from_field) => {
+                            can_cast_types(&from_field.data_type, &to_field.data_type)
+                        }
+                        None => false,
+                    }
+                })
+        }
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (List(list_from), List(list_to)) => {
@@ -97,15 +158,22 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (LargeList(list_from), LargeList(list_to)) => {
             can_cast_types(&list_from.data_type, &list_to.data_type)
         }
-        (List(list_from), LargeList(list_to)) if list_from == list_to => true,
-        (LargeList(list_from), List(list_to)) if list_from == list_to => true,
+        (List(list_from), LargeList(list_to)) => {
+            can_cast_types(&list_from.data_type, &list_to.data_type)
+        }
+        (LargeList(list_from), List(list_to)) => {
+            can_cast_types(&list_from.data_type, &list_to.data_type)
+        }
         (_, List(list_to)) => can_cast_types(from_type, &list_to.data_type),
         (Dictionary(_, from_value_type, _), Dictionary(_, to_value_type, _)) => {
             can_cast_types(from_value_type, to_value_type)
         }
         (Dictionary(_, value_type, _), _) => can_cast_types(value_type, to_type),
-        (_, Dictionary(_, value_type, _)) => can_cast_types(from_type, value_type),
+        (_, Dictionary(_, value_type, _)) => {
+            can_pack_dictionary(value_type) && can_cast_types(from_type, value_type)
+        }
 
+        (Utf8 | LargeUtf8, Boolean) => true,
         (_, Boolean) => is_numeric(from_type),
         (Boolean, _) => {
             is_numeric(to_type)
@@ -116,22 +184,31 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         }
 
         (Utf8, Date32) => true,
+        (Utf8, Decimal(_, _)) => true,
         (Utf8, Date64) => true,
-        (Utf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
+        (Utf8, Timestamp(TimeUnit::Nanosecond, None)) => true,
+        (Utf8, Timestamp(TimeUnit::Nanosecond, Some(tz))) => is_parsable_timezone(tz),
         (Utf8, LargeUtf8) => true,
+        (Utf8, Binary) => true,
         (Utf8, _) => is_numeric(to_type),
         (LargeUtf8, Date32) => true,
+        (LargeUtf8, Decimal(_, _)) => true,
         (LargeUtf8, Date64) => true,
-        (LargeUtf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
+        (LargeUtf8, Timestamp(TimeUnit::Nanosecond, None)) => true,
+        (LargeUtf8, Timestamp(TimeUnit::Nanosecond, Some(tz))) => is_parsable_timezone(tz),
         (LargeUtf8, Utf8) => true,
+        (LargeUtf8, LargeBinary) => true,
         (LargeUtf8, _) => is_numeric(to_type),
-        (Timestamp(_, _), Utf8) => true,
-        (Timestamp(_, _), LargeUtf8) => true,
+        (Timestamp(_, None), Utf8 | LargeUtf8) => true,
+        (Timestamp(_, Some(tz)), Utf8 | LargeUtf8) => is_parsable_timezone(tz),
+        (Decimal(_, _), Utf8) => true,
+        (Decimal(_, _), LargeUtf8) => true,
         (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
-        (_, LargeUtf8) => is_numeric(from_type) || from_type == &Binary,
+        (_, LargeUtf8) => is_numeric(from_type) || matches!(from_type, Binary | LargeBinary),
 
-        (Binary, _) => is_numeric(to_type) || to_type == &LargeBinary,
-        (LargeBinary, _) => is_numeric(to_type) || to_type == &Binary,
+        (Binary, _) => is_numeric(to_type) || matches!(to_type, LargeBinary | FixedSizeBinary(_)),
+        (LargeBinary, _) => is_numeric(to_type) || matches!(to_type, Binary | FixedSizeBinary(_)),
+        (FixedSizeBinary(_), Binary | LargeBinary) => true,
         (_, Binary) => is_numeric(from_type),
         (_, LargeBinary) => is_numeric(from_type),
 
@@ -234,6 +311,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float32, Int64) => true,
         (Float32, Float64) => true,
         (Float32, Decimal(_, _)) => true,
+        (Float32, Float16) => true,
 
         (Float64, UInt8) => true,
         (Float64, UInt16) => true,
@@ -245,6 +323,10 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Float64, Int64) => true,
         (Float64, Float32) => true,
         (Float64, Decimal(_, _)) => true,
+        (Float64, Float16) => true,
+
+        (Float16, Float32) => true,
+        (Float16, Float64) => true,
 
         (
             Decimal(_, _),
@@ -260,6 +342,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Float64
             | Decimal(_, _),
         ) => true,
+        (Decimal(_, _) | Decimal256(_, _), Decimal(_, _) | Decimal256(_, _)) => true,
         // end numeric casts
 
         // temporal casts
@@ -315,57 +398,110 @@ fn cast_list<O: Offset>(
     ))
 }
 
-fn cast_list_to_large_list(array: &ListArray<i32>, to_type: &DataType) -> ListArray<i64> {
-    let offets = array
+fn cast_list_to_large_list(
+    array: &ListArray<i32>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<i64>> {
+    let offsets = array
         .offsets()
         .iter()
         .map(|x| *x as i64)
         .collect::<Vec<_>>()
         .into();
+    let values = cast(
+        array.values().as_ref(),
+        ListArray::<i64>::get_child_type(to_type),
+        options,
+    )?
+    .into();
 
-    ListArray::<i64>::from_data(
+    Ok(ListArray::<i64>::from_data(
         to_type.clone(),
-        offets,
-        array.values().clone(),
+        offsets,
+        values,
         array.validity().cloned(),
-    )
+    ))
 }
 
-fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i32> {
-    let offsets = array
-        .offsets()
-        .iter()
-        .map(|x| *x as i32)
-        .collect::<Vec<_>>()
-        .into();
+fn cast_large_to_list(
+    array: &ListArray<i64>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<i32>> {
+    let (offsets, start, length) = narrow_offsets(array.offsets())?;
+    let values = array.values().slice(start, length);
+    let values = cast(
+        values.as_ref(),
+        ListArray::<i32>::get_child_type(to_type),
+        options,
+    )?
+    .into();
 
-    ListArray::<i32>::from_data(
+    Ok(ListArray::<i32>::from_data(
         to_type.clone(),
         offsets,
-        array.values().clone(),
+        values,
         array.validity().cloned(),
-    )
+    ))
+}
+
+/// Rebases `offsets` to start at zero and narrows them to `i32`, returning the new offsets
+/// and the `(start, length)` of the values they span.
+/// # Errors
+/// Errors if the values spanned by `offsets` do not fit in `i32` offsets.
+fn narrow_offsets(offsets: &Buffer<i64>) -> Result<(Buffer<i32>, usize, usize)> {
+    let start = offsets[0];
+    let length = offsets[offsets.len() - 1] - start;
+    if length > i32::MAX as i64 {
+        return Err(ArrowError::Overflow);
+    }
+
+    let offsets = offsets.iter().map(|x| (*x - start) as i32);
+    Ok((
+        Buffer::from_trusted_len_iter(offsets),
+        start as usize,
+        length as usize,
+    ))
 }
 
 /// Cast `array` to the provided data type and return a new [`Array`] with
 /// type `to_type`, if possible.
 ///
 /// Behavior:
-/// * PrimitiveArray to PrimitiveArray: overflowing cast will be None
+/// * PrimitiveArray to PrimitiveArray: overflowing cast will be None, including `NaN` and
+///   infinite floats cast to integers. When `options.wrapped`, numbers are cast with `as`,
+///   i.e. integers wrap around and floats saturate (`NaN` is `0`)
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
-/// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
+/// * Utf8 to numeric: leading and trailing whitespace and a leading `+` are ignored; strings that
+///   can't be parsed to numbers return null, or error when `options.strict`, and float strings
 ///   in integer casts return null
+/// * Utf8 to boolean: see [`utf8_to_boolean`]; strings that can't be parsed return null, or
+///   error when `options.strict`
+/// * Utf8 to dates and timestamps: strings are parsed with `options.format`; strings that can't be
+///   parsed return null, or error when `options.strict`
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
+/// * List to LargeList, Utf8 to LargeUtf8 and Binary to LargeBinary (and vice-versa): the offsets
+///   are converted; narrowing errors if the values do not fit in `i32` offsets
+/// * Binary to FixedSizeBinary: values of a different size return null, or error when
+///   `options.strict`
+/// * Binary to Utf8: values that are not valid utf8 return null, or error when `options.strict`;
+///   zero-copy when all values are valid. Utf8 to Binary is zero-copy
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Timestamp to Timestamp: values are re-scaled to the new unit (overflows are null) and
+///   the timezone is replaced, keeping the instant; naive timestamps are interpreted as UTC
+///   unless `options.wall_clock`
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Struct to Struct: the fields are matched by name and each child is cast; see [`struct_to_struct`]
+/// * Extension to/from any type: cast through the extension's storage type; zero-copy
+///   between an extension type and its storage type
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * From `StructArray` to other types, and to `StructArray` from other types
 /// * List to primitive
-/// * Utf8 to boolean
 /// * Interval and duration
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
     use DataType::*;
@@ -376,6 +512,16 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         return Ok(clone(array));
     }
 
+    // extension types are cast through their storage types
+    if let Extension(_, inner, _) = from_type {
+        let array = to_data_type(array, inner.as_ref().clone());
+        return cast(array.as_ref(), to_type, options);
+    }
+    if let Extension(_, inner, _) = to_type {
+        let array = cast(array, inner, options)?;
+        return Ok(to_data_type(array.as_ref(), to_type.clone()));
+    }
+
     let as_options = options.with_wrapped(true);
     match (from_type, to_type) {
         (
@@ -388,6 +534,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
             Null,
         ) => Ok(new_null_array(to_type.clone(), array.len())),
+        (Struct(_), Struct(_)) => struct_to_struct_dyn(array, to_type, options),
         (Struct(_), _) => Err(ArrowError::NotYetImplemented(
             "Cannot cast from struct to other types".to_string(),
         )),
@@ -402,16 +549,14 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             cast_list::<i64>(array.as_any().downcast_ref().unwrap(), to_type, options)
                 .map(|x| Box::new(x) as Box<dyn Array>)
         }
-        (List(lhs), LargeList(rhs)) if lhs == rhs => Ok(cast_list_to_large_list(
-            array.as_any().downcast_ref().unwrap(),
-            to_type,
-        ))
-        .map(|x| Box::new(x) as Box<dyn Array>),
-        (LargeList(lhs), List(rhs)) if lhs == rhs => Ok(cast_large_to_list(
-            array.as_any().downcast_ref().unwrap(),
-            to_type,
-        ))
-        .map(|x| Box::new(x) as Box<dyn Array>),
+        (List(_), LargeList(_)) => {
+            cast_list_to_large_list(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (LargeList(_), List(_)) => {
+            cast_large_to_list(array.as_any().downcast_ref().unwrap(), to_type, options)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
 
         (_, List(to)) => {
             // cast primitive to list's primitive
@@ -442,6 +587,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_boolean_dyn::<i64>(array, to_type.clone()),
             Float32 => primitive_to_boolean_dyn::<f32>(array, to_type.clone()),
             Float64 => primitive_to_boolean_dyn::<f64>(array, to_type.clone()),
+            Utf8 => utf8_to_boolean_dyn::<i32>(array, options),
+            LargeUtf8 => utf8_to_boolean_dyn::<i64>(array, options),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -479,14 +626,20 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i32, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i32, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type, options),
-            Date32 => utf8_to_date32_dyn::<i32>(array),
-            Date64 => utf8_to_date64_dyn::<i32>(array),
+            Date32 => utf8_to_date32_dyn::<i32>(array, options),
+            Decimal(p, s) => utf8_to_decimal_dyn::<i32>(array, *p, *s, options),
+            Date64 => utf8_to_date64_dyn::<i32>(array, options),
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
                 array.as_any().downcast_ref().unwrap(),
             ))),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i32>(array),
+            Binary => Ok(Box::new(utf8_to_binary::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+            ))),
+            Timestamp(TimeUnit::Nanosecond, None) => {
+                utf8_to_naive_timestamp_ns_dyn::<i32>(array, options)
+            }
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
-                utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
+                utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone(), options)
             }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
@@ -504,13 +657,19 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i64, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type, options),
-            Date32 => utf8_to_date32_dyn::<i64>(array),
-            Date64 => utf8_to_date64_dyn::<i64>(array),
+            Date32 => utf8_to_date32_dyn::<i64>(array, options),
+            Decimal(p, s) => utf8_to_decimal_dyn::<i64>(array, *p, *s, options),
+            Date64 => utf8_to_date64_dyn::<i64>(array, options),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())
                 .map(|x| Box::new(x) as Box<dyn Array>),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i64>(array),
+            LargeBinary => Ok(Box::new(utf8_to_binary::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+            ))),
+            Timestamp(TimeUnit::Nanosecond, None) => {
+                utf8_to_naive_timestamp_ns_dyn::<i64>(array, options)
+            }
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
-                utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
+                utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone(), options)
             }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
@@ -529,17 +688,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i32>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i32>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i32>(array),
-            Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i32>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
-            }
+            Decimal(_, _) => decimal_to_utf8_dyn::<i32>(array),
+            Binary => binary_to_utf8_dyn::<i32>(array, options),
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(timestamp_to_utf8::<i32>(from, *from_unit, tz)?))
@@ -565,17 +715,14 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i64>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i64>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i64>(array),
+            Decimal(_, _) => decimal_to_utf8_dyn::<i64>(array),
             Binary => {
-                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
-
-                // perf todo: the offsets are equal; we can speed-up this
-                let iter = array
-                    .iter()
-                    .map(|x| x.and_then(|x| simdutf8::basic::from_utf8(x).ok()));
-
-                let array = Utf8Array::<i64>::from_trusted_len_iter(iter);
-                Ok(Box::new(array))
+                let array = binary_to_utf8_dyn::<i32>(array, options)?;
+                Ok(Box::new(utf8_to_large_utf8(
+                    array.as_any().downcast_ref().unwrap(),
+                )))
             }
+            LargeBinary => binary_to_utf8_dyn::<i64>(array, options),
             Timestamp(from_unit, Some(tz)) => {
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(timestamp_to_utf8::<i64>(from, *from_unit, tz)?))
@@ -605,6 +752,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 array.as_any().downcast_ref().unwrap(),
                 to_type.clone(),
             ))),
+            FixedSizeBinary(_) => binary_to_fixed_size_binary_dyn::<i32>(array, to_type, options),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -626,6 +774,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 binary_large_to_binary(array.as_any().downcast_ref().unwrap(), to_type.clone())
                     .map(|x| Box::new(x) as Box<dyn Array>)
             }
+            FixedSizeBinary(_) => binary_to_fixed_size_binary_dyn::<i64>(array, to_type, options),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -643,6 +792,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_binary_dyn::<i64, i32>(array),
             Float32 => primitive_to_binary_dyn::<f32, i32>(array),
             Float64 => primitive_to_binary_dyn::<f64, i32>(array),
+            FixedSizeBinary(_) => fixed_size_binary_binary_dyn::<i32>(array, to_type),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -660,6 +810,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_binary_dyn::<i64, i64>(array),
             Float32 => primitive_to_binary_dyn::<f32, i64>(array),
             Float64 => primitive_to_binary_dyn::<f64, i64>(array),
+            FixedSizeBinary(_) => fixed_size_binary_binary_dyn::<i64>(array, to_type),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -765,6 +916,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Float32, Int64) => primitive_to_primitive_dyn::<f32, i64>(array, to_type, options),
         (Float32, Float64) => primitive_to_primitive_dyn::<f32, f64>(array, to_type, as_options),
         (Float32, Decimal(p, s)) => float_to_decimal_dyn::<f32>(array, *p, *s),
+        (Float32, Float16) => primitive_dyn!(array, f32_to_f16),
 
         (Float64, UInt8) => primitive_to_primitive_dyn::<f64, u8>(array, to_type, options),
         (Float64, UInt16) => primitive_to_primitive_dyn::<f64, u16>(array, to_type, options),
@@ -776,6 +928,10 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type, options),
         (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type, options),
         (Float64, Decimal(p, s)) => float_to_decimal_dyn::<f64>(array, *p, *s),
+        (Float64, Float16) => primitive_dyn!(array, f64_to_f16),
+
+        (Float16, Float32) => primitive_dyn!(array, f16_to_f32),
+        (Float16, Float64) => primitive_dyn!(array, f16_to_f64),
 
         (Decimal(_, _), UInt8) => decimal_to_integer_dyn::<u8>(array),
         (Decimal(_, _), UInt16) => decimal_to_integer_dyn::<u16>(array),
@@ -787,7 +943,18 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array),
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
-        (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
+        (Decimal(_, _), Decimal(to_p, to_s)) => {
+            decimal_to_decimal_dyn(array, *to_p, *to_s, options)
+        }
+        (Decimal(_, _), Decimal256(to_p, to_s)) => {
+            decimal_to_decimal256_dyn(array, *to_p, *to_s, options)
+        }
+        (Decimal256(_, _), Decimal(to_p, to_s)) => {
+            decimal256_to_decimal_dyn(array, *to_p, *to_s, options)
+        }
+        (Decimal256(_, _), Decimal256(to_p, to_s)) => {
+            decimal256_to_decimal256_dyn(array, *to_p, *to_s, options)
+        }
         // end numeric casts
 
         // temporal casts
@@ -834,6 +1001,16 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         }
         (Timestamp(_, _), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Int64, Timestamp(_, _)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
+        (Timestamp(from_unit, None), Timestamp(to_unit, Some(tz))) if options.wall_clock => {
+            let from = array.as_any().downcast_ref().unwrap();
+            naive_timestamp_to_timestamp(from, *from_unit, *to_unit, tz)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (Timestamp(from_unit, Some(tz)), Timestamp(to_unit, None)) if options.wall_clock => {
+            let from = array.as_any().downcast_ref().unwrap();
+            timestamp_to_naive_timestamp(from, *from_unit, *to_unit, tz)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
         (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => {
             primitive_dyn!(array, timestamp_to_timestamp, *from_unit, *to_unit, tz)
         }
@@ -850,6 +1027,19 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
     }
 }
 
+/// Whether arrays of `value_type` can be encoded into a [`DictionaryArray`].
+fn can_pack_dictionary(value_type: &DataType) -> bool {
+    use PrimitiveType::*;
+    matches!(
+        value_type.to_physical_type(),
+        PhysicalType::Primitive(Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64)
+            | PhysicalType::Utf8
+            | PhysicalType::LargeUtf8
+            | PhysicalType::Binary
+            | PhysicalType::LargeBinary
+    )
+}
+
 /// Attempts to encode an array into an `ArrayDictionary` with index
 /// type K and value (dictionary) type value_type
 ///
@@ -859,21 +1049,22 @@ fn cast_to_dictionary<K: DictionaryKey>(
     dict_value_type: &DataType,
     options: CastOptions,
 ) -> Result<Box<dyn Array>> {
+    use PrimitiveType::*;
     let array = cast(array, dict_value_type, options)?;
     let array = array.as_ref();
-    match *dict_value_type {
-        DataType::Int8 => primitive_to_dictionary_dyn::<i8, K>(array),
-        DataType::Int16 => primitive_to_dictionary_dyn::<i16, K>(array),
-        DataType::Int32 => primitive_to_dictionary_dyn::<i32, K>(array),
-        DataType::Int64 => primitive_to_dictionary_dyn::<i64, K>(array),
-        DataType::UInt8 => primitive_to_dictionary_dyn::<u8, K>(array),
-        DataType::UInt16 => primitive_to_dictionary_dyn::<u16, K>(array),
-        DataType::UInt32 => primitive_to_dictionary_dyn::<u32, K>(array),
-        DataType::UInt64 => primitive_to_dictionary_dyn::<u64, K>(array),
-        DataType::Utf8 => utf8_to_dictionary_dyn::<i32, K>(array),
-        DataType::LargeUtf8 => utf8_to_dictionary_dyn::<i64, K>(array),
-        DataType::Binary => binary_to_dictionary_dyn::<i32, K>(array),
-        DataType::LargeBinary => binary_to_dictionary_dyn::<i64, K>(array),
+    match dict_value_type.to_physical_type() {
+        PhysicalType::Primitive(Int8) => primitive_to_dictionary_dyn::<i8, K>(array),
+        PhysicalType::Primitive(Int16) => primitive_to_dictionary_dyn::<i16, K>(array),
+        PhysicalType::Primitive(Int32) => primitive_to_dictionary_dyn::<i32, K>(array),
+        PhysicalType::Primitive(Int64) => primitive_to_dictionary_dyn::<i64, K>(array),
+        PhysicalType::Primitive(UInt8) => primitive_to_dictionary_dyn::<u8, K>(array),
+        PhysicalType::Primitive(UInt16) => primitive_to_dictionary_dyn::<u16, K>(array),
+        PhysicalType::Primitive(UInt32) => primitive_to_dictionary_dyn::<u32, K>(array),
+        PhysicalType::Primitive(UInt64) => primitive_to_dictionary_dyn::<u64, K>(array),
+        PhysicalType::Utf8 => utf8_to_dictionary_dyn::<i32, K>(array),
+        PhysicalType::LargeUtf8 => utf8_to_dictionary_dyn::<i64, K>(array),
+        PhysicalType::Binary => binary_to_dictionary_dyn::<i32, K>(array),
+        PhysicalType::LargeBinary => binary_to_dictionary_dyn::<i64, K>(array),
         _ => Err(ArrowError::NotYetImplemented(format!(
             "Unsupported output type for dictionary packing: {:?}",
             dict_value_type
@@ -1,15 +1,16 @@
 use std::hash::Hash;
 
+use chrono::{LocalResult, Offset as _};
 use num_traits::{AsPrimitive, Float, ToPrimitive};
 
 use crate::error::Result;
 use crate::{
     array::*,
     bitmap::Bitmap,
-    compute::arity::unary,
+    compute::arity::{unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
-    types::NativeType,
+    types::{f16, NativeType},
 };
 
 use super::CastOptions;
@@ -307,14 +308,26 @@ pub fn primitive_to_dictionary<T: NativeType + Eq + Hash, K: DictionaryKey>(
     Ok(array.into())
 }
 
-/// Get the time unit as a multiple of a second
-const fn time_unit_multiple(unit: TimeUnit) -> i64 {
-    match unit {
-        TimeUnit::Second => 1,
-        TimeUnit::Millisecond => MILLISECONDS,
-        TimeUnit::Microsecond => MICROSECONDS,
-        TimeUnit::Nanosecond => NANOSECONDS,
-    }
+/// Conversion of half-precision floats
+pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
+    unary(from, |x| x.to_f32(), DataType::Float32)
+}
+
+/// Conversion of half-precision floats
+pub fn f16_to_f64(from: &PrimitiveArray<f16>) -> PrimitiveArray<f64> {
+    unary(from, |x| x.to_f64(), DataType::Float64)
+}
+
+/// Conversion to half-precision floats, rounding to the nearest [`f16`].
+/// Values out of the range of [`f16`] become infinite.
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, DataType::Float16)
+}
+
+/// Conversion to half-precision floats, rounding to the nearest [`f16`].
+/// Values out of the range of [`f16`] become infinite.
+pub fn f64_to_f16(from: &PrimitiveArray<f64>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f64, DataType::Float16)
 }
 
 /// Conversion of dates
@@ -399,6 +412,10 @@ pub fn time64_to_time32(
 }
 
 /// Conversion of timestamp
+///
+/// The values are only re-scaled to `to_unit`: the timezone `tz` is metadata and does not
+/// change the instant each value represents.
+/// Values that overflow when converted to a finer unit are null.
 pub fn timestamp_to_timestamp(
     from: &PrimitiveArray<i64>,
     from_unit: TimeUnit,
@@ -412,10 +429,119 @@ pub fn timestamp_to_timestamp(
     if from_size >= to_size {
         unary(from, |x| (x / (from_size / to_size)), to_type)
     } else {
-        unary(from, |x| (x * (to_size / from_size)), to_type)
+        let multiple = to_size / from_size;
+        unary_checked(from, |x| x.checked_mul(multiple), to_type)
     }
 }
 
+/// Shifts every value of `from` by the offset of `timezone` at that value,
+/// from UTC to local time when `to_local`, and from local time to UTC otherwise.
+fn wall_clock_impl<T: chrono::TimeZone>(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: &T,
+    to_local: bool,
+) -> PrimitiveArray<i64> {
+    let multiple = time_unit_multiple(time_unit);
+    let op = |x: i64| {
+        // out of range values, including the (undefined) values of null slots, are null
+        let datetime = timestamp_to_naive_datetime_opt(x, time_unit)?;
+        let offset = if to_local {
+            timezone
+                .offset_from_utc_datetime(&datetime)
+                .fix()
+                .local_minus_utc()
+        } else {
+            match timezone.offset_from_local_datetime(&datetime) {
+                LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => {
+                    -offset.fix().local_minus_utc()
+                }
+                LocalResult::None => return None,
+            }
+        };
+        x.checked_add((offset as i64).checked_mul(multiple)?)
+    };
+    unary_checked(from, op, from.data_type().clone())
+}
+
+#[cfg(feature = "chrono-tz")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
+fn chrono_tz_wall_clock(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    to_local: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(wall_clock_impl(from, time_unit, &timezone, to_local))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_wall_clock(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: bool,
+) -> Result<PrimitiveArray<i64>> {
+    Err(crate::temporal_conversions::chrono_tz_not_active(
+        timezone_str,
+    ))
+}
+
+fn wall_clock(
+    from: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    to_local: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset(timezone_str);
+
+    if let Ok(timezone) = timezone {
+        Ok(wall_clock_impl(from, time_unit, &timezone, to_local))
+    } else {
+        chrono_tz_wall_clock(from, time_unit, timezone_str, to_local)
+    }
+}
+
+/// Converts a naive timestamp to a timestamp with `timezone` that has the same wall-clock
+/// time, e.g. the naive `2021-01-01 12:00:00` is `2021-01-01 11:00:00 UTC` in `+01:00`.
+///
+/// Local times that do not exist in `timezone` (e.g. in a daylight saving time gap) are null
+/// and ambiguous ones are resolved to the earliest instant.
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for `timezone`.
+/// # Errors
+/// This function errors iff `timezone` is not parsable to an offset.
+pub fn naive_timestamp_to_timestamp(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+    timezone: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let utc = wall_clock(from, from_unit, timezone, false)?;
+    Ok(timestamp_to_timestamp(
+        &utc,
+        from_unit,
+        to_unit,
+        &Some(timezone.to_string()),
+    ))
+}
+
+/// Converts a timestamp with `timezone` to a naive timestamp with its wall-clock time
+/// in `timezone`, e.g. `2021-01-01 11:00:00 UTC` in `+01:00` is the naive `2021-01-01 12:00:00`.
+///
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for `timezone`.
+/// # Errors
+/// This function errors iff `timezone` is not parsable to an offset.
+pub fn timestamp_to_naive_timestamp(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+    timezone: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let local = wall_clock(from, from_unit, timezone, true)?;
+    Ok(timestamp_to_timestamp(&local, from_unit, to_unit, &None))
+}
+
 fn timestamp_to_utf8_impl<O: Offset, T: chrono::TimeZone>(
     from: &PrimitiveArray<i64>,
     time_unit: TimeUnit,
@@ -487,11 +613,9 @@ fn chrono_tz_timestamp_to_utf8<O: Offset>(
     _: TimeUnit,
     timezone_str: &str,
 ) -> Result<Utf8Array<O>> {
-    use crate::error::ArrowError;
-    Err(ArrowError::InvalidArgumentError(format!(
-        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
-        timezone_str
-    )))
+    Err(crate::temporal_conversions::chrono_tz_not_active(
+        timezone_str,
+    ))
 }
 
 /// Returns a [`Utf8Array`] where every element is the utf8 representation of the timestamp in the rfc3339 format.
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{new_null_array, Array, StructArray},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::{cast, CastOptions};
+
+/// Casts a [`StructArray`] to a [`StructArray`] of `to_type` by matching the fields by name
+/// and casting each child to the data type of its corresponding field.
+///
+/// The validity of `from` is preserved.
+/// # Errors
+/// This function errors if:
+/// * a field of `to_type` does not exist in `from`, unless `options.null_missing_fields`
+///   and the field is nullable, in which case it is filled with nulls
+/// * a field of `from` does not exist in `to_type`, unless `options.drop_extra_fields`
+/// * any of the children cannot be cast
+pub fn struct_to_struct(
+    from: &StructArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<StructArray> {
+    let from_fields = from.fields();
+    let to_fields = StructArray::get_fields(to_type);
+
+    if !options.drop_extra_fields {
+        if let Some(field) = from_fields
+            .iter()
+            .find(|from_field| !to_fields.iter().any(|x| x.name == from_field.name))
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The field \"{}\" does not exist in {:?}",
+                field.name, to_type
+            )));
+        }
+    }
+
+    let values = to_fields
+        .iter()
+        .map(
+            |to_field| match from_fields.iter().position(|x| x.name == to_field.name) {
+                Some(index) => {
+                    cast(from.values()[index].as_ref(), &to_field.data_type, options).map(Arc::from)
+                }
+                None if options.null_missing_fields && to_field.is_nullable => Ok(Arc::from(
+                    new_null_array(to_field.data_type.clone(), from.len()),
+                )),
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "The field \"{}\" does not exist in {:?}",
+                    to_field.name,
+                    from.data_type()
+                ))),
+            },
+        )
+        .collect::<Result<Vec<_>>>()?;
+
+    StructArray::try_new(to_type.clone(), values, from.validity().cloned())
+}
+
+pub(super) fn struct_to_struct_dyn(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    struct_to_struct(from, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
@@ -1,5 +1,3 @@
-use std::convert::TryFrom;
-
 use chrono::Datelike;
 
 use crate::{
@@ -13,23 +11,40 @@ use crate::{
     types::NativeType,
 };
 
-use super::CastOptions;
+use super::decimal_to::max_for_precision;
+use super::{narrow_offsets, CastOptions, DecimalRounding};
 
 const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
+/// Trims leading and trailing ASCII whitespace from `value`.
+#[inline]
+fn trim_ascii(value: &str) -> &str {
+    value.trim_matches(|c: char| c.is_ascii_whitespace())
+}
+
+/// Parses `value` to a number after trimming ASCII whitespace, accepting a leading `+`.
+#[inline]
+fn parse_number<T: lexical_core::FromLexical>(value: &str) -> Option<T> {
+    let value = trim_ascii(value);
+    lexical_core::parse(value.as_bytes()).ok()
+}
+
 /// Casts a [`Utf8Array`] to a [`PrimitiveArray`], making any uncastable value a Null.
+///
+/// Leading and trailing ASCII whitespace is ignored and a leading `+` is accepted,
+/// e.g. `" +12 "` is `12`. Empty strings are null.
 pub fn utf8_to_primitive<O: Offset, T>(from: &Utf8Array<O>, to: &DataType) -> PrimitiveArray<T>
 where
     T: NativeType + lexical_core::FromLexical,
 {
-    let iter = from
-        .iter()
-        .map(|x| x.and_then::<T, _>(|x| lexical_core::parse(x.as_bytes()).ok()));
+    let iter = from.iter().map(|x| x.and_then::<T, _>(parse_number));
 
     PrimitiveArray::<T>::from_trusted_len_iter(iter).to(to.clone())
 }
 
 /// Casts a [`Utf8Array`] to a [`PrimitiveArray`] at best-effort using `lexical_core::parse_partial`, making any uncastable value as zero.
+///
+/// Leading ASCII whitespace is ignored.
 pub fn partial_utf8_to_primitive<O: Offset, T>(
     from: &Utf8Array<O>,
     to: &DataType,
@@ -38,7 +53,10 @@ where
     T: NativeType + lexical_core::FromLexical,
 {
     let iter = from.iter().map(|x| {
-        x.and_then::<T, _>(|x| lexical_core::parse_partial(x.as_bytes()).ok().map(|x| x.0))
+        x.and_then::<T, _>(|x| {
+            let x = x.trim_start_matches(|c: char| c.is_ascii_whitespace());
+            lexical_core::parse_partial(x.as_bytes()).ok().map(|x| x.0)
+        })
     });
 
     PrimitiveArray::<T>::from_trusted_len_iter(iter).to(to.clone())
@@ -56,10 +74,54 @@ where
     if options.partial {
         Ok(Box::new(partial_utf8_to_primitive::<O, T>(from, to)))
     } else {
-        Ok(Box::new(utf8_to_primitive::<O, T>(from, to)))
+        finish_parsed(from, utf8_to_primitive::<O, T>(from, to), options)
+    }
+}
+
+/// Parses `value` to a boolean, see [`utf8_to_boolean`].
+#[inline]
+fn parse_boolean(value: &str) -> Option<bool> {
+    const TRUE: [&str; 6] = ["true", "t", "yes", "y", "on", "1"];
+    const FALSE: [&str; 6] = ["false", "f", "no", "n", "off", "0"];
+
+    let value = trim_ascii(value);
+    if TRUE.iter().any(|x| x.eq_ignore_ascii_case(value)) {
+        Some(true)
+    } else if FALSE.iter().any(|x| x.eq_ignore_ascii_case(value)) {
+        Some(false)
+    } else {
+        None
     }
 }
 
+/// Casts a [`Utf8Array`] to a [`BooleanArray`], making any uncastable value a Null.
+///
+/// The values are matched case-insensitively and ignoring leading and trailing ASCII whitespace:
+/// * `"true"`, `"t"`, `"yes"`, `"y"`, `"on"` and `"1"` are `true`
+/// * `"false"`, `"f"`, `"no"`, `"n"`, `"off"` and `"0"` are `false`
+/// # Examples
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::cast::utf8_to_boolean;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("TRUE"), Some(" 0 "), Some(""), None]);
+/// let result = utf8_to_boolean(&array);
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None, None]));
+/// ```
+pub fn utf8_to_boolean<O: Offset>(from: &Utf8Array<O>) -> BooleanArray {
+    let iter = from.iter().map(|x| x.and_then(parse_boolean));
+
+    BooleanArray::from_trusted_len_iter(iter)
+}
+
+pub(super) fn utf8_to_boolean_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    finish_parsed(from, utf8_to_boolean::<O>(from), options)
+}
+
 /// Casts a [`Utf8Array`] to a Date32 primitive, making any uncastable value a Null.
 pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     let iter = from.iter().map(|x| {
@@ -72,9 +134,42 @@ pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     PrimitiveArray::<i32>::from_trusted_len_iter(iter).to(DataType::Date32)
 }
 
-pub(super) fn utf8_to_date32_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+/// Casts a [`Utf8Array`] to a Date32 primitive using a [chrono format](chrono::format::strftime),
+/// making any uncastable value a Null.
+/// # Examples
+/// ```
+/// use arrow2::array::{Utf8Array, PrimitiveArray};
+/// use arrow2::compute::cast::utf8_to_date32_with_format;
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("02/01/1970"), Some("1970-01-02"), None]);
+/// let expected = PrimitiveArray::from([Some(1), None, None]).to(DataType::Date32);
+/// assert_eq!(utf8_to_date32_with_format(&array, "%d/%m/%Y"), expected);
+/// ```
+pub fn utf8_to_date32_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    fmt: &str,
+) -> PrimitiveArray<i32> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| {
+            chrono::NaiveDate::parse_from_str(x, fmt)
+                .ok()
+                .map(|x| x.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+        })
+    });
+    PrimitiveArray::<i32>::from_trusted_len_iter(iter).to(DataType::Date32)
+}
+
+pub(super) fn utf8_to_date32_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_date32::<O>(from)))
+    let to = match options.format {
+        Some(fmt) => utf8_to_date32_with_format::<O>(from, fmt),
+        None => utf8_to_date32::<O>(from),
+    };
+    finish_parsed(from, to, options)
 }
 
 /// Casts a [`Utf8Array`] to a Date64 primitive, making any uncastable value a Null.
@@ -89,9 +184,136 @@ pub fn utf8_to_date64<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i64> {
     PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(DataType::Date64)
 }
 
-pub(super) fn utf8_to_date64_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+/// Casts a [`Utf8Array`] to a Date64 primitive using a [chrono format](chrono::format::strftime),
+/// making any uncastable value a Null.
+/// Formats without time (e.g. `%d/%m/%Y`) are parsed to midnight.
+pub fn utf8_to_date64_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    fmt: &str,
+) -> PrimitiveArray<i64> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| {
+            chrono::NaiveDateTime::parse_from_str(x, fmt)
+                .ok()
+                .or_else(|| {
+                    chrono::NaiveDate::parse_from_str(x, fmt)
+                        .ok()
+                        .and_then(|x| x.and_hms_opt(0, 0, 0))
+                })
+                .map(|x| x.and_utc().timestamp_millis())
+        })
+    });
+    PrimitiveArray::<i64>::from_trusted_len_iter(iter).to(DataType::Date64)
+}
+
+pub(super) fn utf8_to_date64_dyn<O: Offset>(
+    from: &dyn Array,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_date64::<O>(from)))
+    let to = match options.format {
+        Some(fmt) => utf8_to_date64_with_format::<O>(from, fmt),
+        None => utf8_to_date64::<O>(from),
+    };
+    finish_parsed(from, to, options)
+}
+
+/// Returns `to` or, when `options.strict`, errors on the first
+/// value of `from` that was not parsed.
+fn finish_parsed<O: Offset, A: Array + 'static>(
+    from: &Utf8Array<O>,
+    to: A,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    if options.strict && to.null_count() != from.null_count() {
+        let index = (0..from.len())
+            .find(|i| from.is_valid(*i) && to.is_null(*i))
+            .unwrap();
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot parse the value \"{}\" at index {} to {:?}",
+            from.value(index),
+            index,
+            to.data_type()
+        )));
+    }
+    Ok(Box::new(to))
+}
+
+/// Parses a decimal number such as `"-123.4500"`, `"+1"` or `".5"` to an integer of `scale`.
+fn parse_decimal(value: &str, scale: usize, rounding: DecimalRounding) -> Option<i128> {
+    let (negative, value) = match value.as_bytes().first() {
+        Some(b'-') => (true, &value[1..]),
+        Some(b'+') => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if !integer
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|x| x.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let push = |acc: i128, digit: u8| acc.checked_mul(10)?.checked_add((digit - b'0') as i128);
+
+    let mut result = integer.bytes().try_fold(0i128, push)?;
+    result = fraction.bytes().take(scale).try_fold(result, push)?;
+    // pad the missing fractional digits with zeros
+    for _ in fraction.len()..scale {
+        result = result.checked_mul(10)?;
+    }
+    // round the dropped fractional digits
+    if rounding == DecimalRounding::HalfAwayFromZero
+        && fraction.as_bytes().get(scale).map(|x| *x >= b'5') == Some(true)
+    {
+        result = result.checked_add(1)?;
+    }
+
+    Some(if negative { -result } else { result })
+}
+
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray<i128>`] of [`DataType::Decimal`], making any uncastable
+/// value a Null. Values are uncastable when they are not decimal numbers (scientific notation is not supported)
+/// or they do not fit in `precision`. Fractional digits beyond `scale` are rounded according to `rounding`.
+/// # Examples
+/// ```
+/// use arrow2::array::{Utf8Array, PrimitiveArray};
+/// use arrow2::compute::cast::{utf8_to_decimal, DecimalRounding};
+/// use arrow2::datatypes::DataType;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("123.4500"), Some("+.5"), Some("-1"), Some("1e2")]);
+/// let result = utf8_to_decimal(&array, 5, 2, DecimalRounding::Truncate);
+/// let expected = PrimitiveArray::from([Some(123_45i128), Some(0_50), Some(-1_00), None])
+///     .to(DataType::Decimal(5, 2));
+/// assert_eq!(result, expected);
+/// ```
+pub fn utf8_to_decimal<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+    rounding: DecimalRounding,
+) -> PrimitiveArray<i128> {
+    let max = max_for_precision(precision);
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_decimal(x, scale, rounding))
+            .filter(|x| x.abs() <= max)
+    });
+    PrimitiveArray::<i128>::from_trusted_len_iter(iter).to(DataType::Decimal(precision, scale))
+}
+
+pub(super) fn utf8_to_decimal_dyn<O: Offset>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    let to = utf8_to_decimal::<O>(from, precision, scale, options.rounding);
+    finish_parsed(from, to, options)
 }
 
 pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
@@ -116,12 +338,14 @@ pub fn utf8_to_dictionary<O: Offset, K: DictionaryKey>(
 
 pub(super) fn utf8_to_naive_timestamp_ns_dyn<O: Offset>(
     from: &dyn Array,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_naive_timestamp_ns::<O>(from)))
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let to = utf8_to_naive_timestamp_ns_(from, options.format.unwrap_or(RFC3339));
+    finish_parsed(from, to, options)
 }
 
-/// [`crate::temporal_conversions::utf8_to_timestamp_ns`] applied for RFC3339 formatting
+/// [`crate::temporal_conversions::utf8_to_naive_timestamp_ns`] applied for RFC3339 formatting
 pub fn utf8_to_naive_timestamp_ns<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i64> {
     utf8_to_naive_timestamp_ns_(from, RFC3339)
 }
@@ -129,11 +353,11 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(from: &Utf8Array<O>) -> PrimitiveAr
 pub(super) fn utf8_to_timestamp_ns_dyn<O: Offset>(
     from: &dyn Array,
     timezone: String,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    utf8_to_timestamp_ns::<O>(from, timezone)
-        .map(Box::new)
-        .map(|x| x as Box<dyn Array>)
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let to = utf8_to_timestamp_ns_(from, options.format.unwrap_or(RFC3339), timezone)?;
+    finish_parsed(from, to, options)
 }
 
 /// [`crate::temporal_conversions::utf8_to_timestamp_ns`] applied for RFC3339 formatting
@@ -144,6 +368,11 @@ pub fn utf8_to_timestamp_ns<O: Offset>(
     utf8_to_timestamp_ns_(from, RFC3339, timezone)
 }
 
+/// Conversion of utf8 to binary. This operation is zero-copy.
+pub fn utf8_to_binary<O: Offset>(from: &Utf8Array<O>) -> BinaryArray<O> {
+    from.clone().into_binary()
+}
+
 /// Conversion of utf8
 pub fn utf8_to_large_utf8(from: &Utf8Array<i32>) -> Utf8Array<i64> {
     let data_type = Utf8Array::<i64>::default_data_type();
@@ -160,19 +389,14 @@ pub fn utf8_to_large_utf8(from: &Utf8Array<i32>) -> Utf8Array<i64> {
 }
 
 /// Conversion of utf8
+/// # Errors
+/// This function errors if the values of `from` do not fit in `i32` offsets.
 pub fn utf8_large_to_utf8(from: &Utf8Array<i64>) -> Result<Utf8Array<i32>> {
     let data_type = Utf8Array::<i32>::default_data_type();
     let validity = from.validity().cloned();
-    let values = from.values().clone();
-    let _ =
-        i32::try_from(*from.offsets().last().unwrap()).map_err(ArrowError::from_external_error)?;
+    let (offsets, start, length) = narrow_offsets(from.offsets())?;
+    let values = from.values().clone().slice(start, length);
 
-    let offsets = from
-        .offsets()
-        .iter()
-        .map(|x| *x as i32)
-        .collect::<Vec<_>>()
-        .into();
     // Safety: sound because `offsets` fulfills the same invariants as `from.offsets()`
     Ok(unsafe { Utf8Array::<i32>::from_data_unchecked(data_type, offsets, values, validity) })
 }
@@ -0,0 +1,151 @@
+//! Contains the operator [`coalesce`].
+use crate::array::{growable::make_growable, Array, PrimitiveArray};
+use crate::bitmap::{utils::SlicesIterator, Bitmap};
+use crate::buffer::Buffer;
+use crate::datatypes::PhysicalType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// Returns, for every slot, the value of the first array in `arrays` whose slot is not null
+/// (i.e. SQL's `COALESCE`). Slots that are null in every array are null.
+///
+/// The validities are scanned a word at a time and the values are copied in runs.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::coalesce::coalesce;
+/// use arrow2::array::Int32Array;
+///
+/// # fn main() -> Result<()> {
+/// let a = Int32Array::from(&[Some(1), None, None]);
+/// let b = Int32Array::from(&[Some(4), Some(5), None]);
+/// let c = Int32Array::from(&[Some(7), Some(8), None]);
+/// let result = coalesce(&[&a, &b, &c])?;
+///
+/// let expected = Int32Array::from(&[Some(1), Some(5), None]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Error
+/// Errors iff `arrays` is empty or its arrays have different data types or lengths.
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    check_arguments(arrays)?;
+    let len = arrays[0].len();
+
+    // arrays after the first one without nulls are never used
+    let arrays = match arrays.iter().position(|x| x.null_count() == 0) {
+        Some(0) => return Ok(arrays[0].slice(0, len)),
+        Some(last) => &arrays[..=last],
+        None => arrays,
+    };
+
+    if let [lhs, rhs] = arrays {
+        if let PhysicalType::Primitive(primitive) = lhs.data_type().to_physical_type() {
+            return with_match_primitive_type!(primitive, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Ok(Box::new(coalesce_primitive::<$T>(lhs, rhs)?))
+            });
+        }
+    }
+
+    // the runs `(start, len, index)` of slots taken from the array at `index`
+    let mut runs = vec![];
+    // the slots that are null in all arrays so far (`None` before the first array)
+    let mut remaining: Option<Bitmap> = None;
+    for (index, array) in arrays.iter().enumerate() {
+        // `None` iff the array has no nulls, which can only be the last array
+        let validity = array.validity().filter(|x| x.null_count() > 0);
+        let taken = match (&remaining, validity) {
+            (None, Some(validity)) => validity.clone(),
+            (Some(remaining), Some(validity)) => remaining & validity,
+            (Some(remaining), None) => remaining.clone(),
+            (None, None) => unreachable!(),
+        };
+        runs.extend(SlicesIterator::new(&taken).map(|(start, len)| (start, len, index)));
+
+        let validity = match validity {
+            Some(validity) => validity,
+            None => break,
+        };
+        let new_remaining = match &remaining {
+            Some(remaining) => remaining & &!validity,
+            None => !validity,
+        };
+        if new_remaining.null_count() == len {
+            break;
+        }
+        remaining = Some(new_remaining);
+    }
+    runs.sort_unstable();
+
+    let mut growable = make_growable(arrays, true, len);
+    let mut end = 0;
+    for (start, len, index) in runs {
+        if start != end {
+            growable.extend_validity(start - end);
+        }
+        growable.extend(index, start, len);
+        end = start + len;
+    }
+    if end != len {
+        growable.extend_validity(len - end);
+    }
+    Ok(growable.as_box())
+}
+
+/// Returns, for every slot, the value of `lhs` if it is not null and the value of `rhs` otherwise.
+/// See [`coalesce`] for details.
+/// # Error
+/// Errors iff the arrays have different data types or lengths.
+pub fn coalesce_primitive<T: NativeType>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>> {
+    check_arguments(&[lhs, rhs])?;
+
+    let lhs_validity = if let Some(validity) = lhs.validity() {
+        validity
+    } else {
+        return Ok(lhs.clone());
+    };
+
+    let values = lhs_validity
+        .iter()
+        .zip(lhs.values().iter().zip(rhs.values().iter()))
+        .map(|(is_valid, (lhs, rhs))| if is_valid { *lhs } else { *rhs });
+    let values = Buffer::from_trusted_len_iter(values);
+
+    let validity = rhs.validity().map(|rhs| lhs_validity | rhs);
+
+    Ok(PrimitiveArray::from_data(
+        lhs.data_type().clone(),
+        values,
+        validity,
+    ))
+}
+
+fn check_arguments(arrays: &[&dyn Array]) -> Result<()> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("Coalesce requires at least one array".to_string())
+    })?;
+    for array in arrays {
+        if array.data_type() != first.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Coalesce requires all arrays to have the same data type ({:?} != {:?})",
+                first.data_type(),
+                array.data_type()
+            )));
+        }
+        if array.len() != first.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Coalesce requires all arrays to have the same length ({} != {})",
+                first.len(),
+                array.len()
+            )));
+        }
+    }
+    Ok(())
+}
@@ -50,6 +50,7 @@ use crate::scalar::*;
 
 pub mod binary;
 pub mod boolean;
+pub mod nested;
 pub mod primitive;
 pub mod utf8;
 
@@ -69,18 +70,21 @@ macro_rules! match_eq_ord {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
+    use crate::types::{f16, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => todo!(),
         MonthDayNano => todo!(),
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -91,19 +95,21 @@ macro_rules! match_eq {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns};
+    use crate::types::{days_ms, f16, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -162,10 +168,13 @@ macro_rules! compare {
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type (up to the names of list items)
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::eq(lhs, rhs);
+    }
     compare!(lhs, rhs, eq, match_eq)
 }
 
@@ -173,10 +182,13 @@ pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type (up to the names of list items)
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::eq_and_validity(lhs, rhs);
+    }
     compare!(lhs, rhs, eq_and_validity, match_eq)
 }
 
@@ -189,10 +201,13 @@ pub fn can_eq(data_type: &DataType) -> bool {
 /// Use [`can_neq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type (up to the names of list items)
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::neq(lhs, rhs);
+    }
     compare!(lhs, rhs, neq, match_eq)
 }
 
@@ -200,10 +215,13 @@ pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
 /// Use [`can_neq`] to check whether the operation is valid
 /// # Panic
 /// Panics iff either:
-/// * the arrays do not have have the same logical type
+/// * the arrays do not have have the same logical type (up to the names of list items)
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if nested::is_nested(lhs.data_type()) {
+        return nested::neq_and_validity(lhs, rhs);
+    }
     compare!(lhs, rhs, neq_and_validity, match_eq)
 }
 
@@ -472,11 +490,13 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Binary
             | DataType::LargeBinary
     )
@@ -485,6 +505,7 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
 // The list of operations currently supported.
 fn can_partial_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
+        || nested::is_nested(data_type)
         || matches!(
             data_type.to_logical_type(),
             DataType::Interval(IntervalUnit::DayTime)
@@ -0,0 +1,272 @@
+//! Comparison functions for nested arrays ([`ListArray`], [`FixedSizeListArray`] and [`StructArray`])
+//!
+//! Two slots are equal when both are valid and their values are equal:
+//! * two list slots are equal when they have the same length and their items are pairwise equal
+//! * two struct slots are equal when all their fields are equal
+//!
+//! Like for the other types, a null slot on either side yields a null.
+//! Nulls _inside_ the values of a slot (e.g. `[1, None]`) are compared as values, i.e.
+//! `[1, None] == [1, None]` is `true`.
+//!
+//! The names of the fields of list items (e.g. `item` or `element`) are not compared.
+use crate::{
+    array::{clone, Array, BooleanArray, FixedSizeListArray, ListArray, Offset, StructArray},
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType},
+};
+
+use super::super::utils::combine_validities;
+use super::{finish_eq_validities, finish_neq_validities};
+
+/// Evaluates `op(i)` for every slot `i` of two nested arrays.
+fn compare_op<F>(lhs: &dyn Array, rhs: &dyn Array, op: F) -> BooleanArray
+where
+    F: Fn(usize) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+
+    let values = (0..lhs.len()).map(op).collect::<Bitmap>();
+
+    BooleanArray::from_data(DataType::Boolean, values, validity)
+}
+
+#[inline]
+fn list_value_eq<O: Offset>(lhs: &ListArray<O>, rhs: &ListArray<O>, i: usize) -> bool {
+    lhs.value(i).as_ref() == rhs.value(i).as_ref()
+}
+
+#[inline]
+fn fixed_size_list_value_eq(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray, i: usize) -> bool {
+    lhs.value(i).as_ref() == rhs.value(i).as_ref()
+}
+
+/// Returns whether each slot of the fields of `lhs` equals the one of `rhs`, ignoring the validity of
+/// the [`StructArray`]s themselves.
+fn struct_values_eq(lhs: &StructArray, rhs: &StructArray) -> Bitmap {
+    assert_eq!(lhs.len(), rhs.len());
+    lhs.values()
+        .iter()
+        .zip(rhs.values().iter())
+        .map(|(lhs, rhs)| field_values_eq(lhs.as_ref(), rhs.as_ref()))
+        .reduce(|acc, x| &acc & &x)
+        // a `StructArray` has at least one field
+        .unwrap()
+}
+
+/// Compares two fields slot by slot, where two null slots are equal.
+fn field_values_eq(lhs: &dyn Array, rhs: &dyn Array) -> Bitmap {
+    if super::can_eq(lhs.data_type()) {
+        // the output of `eq_and_validity` has no nulls
+        super::eq_and_validity(lhs, rhs).values().clone()
+    } else {
+        // types without a comparison kernel are compared slot by slot
+        (0..lhs.len())
+            .map(|i| lhs.slice(i, 1).as_ref() == rhs.slice(i, 1).as_ref())
+            .collect()
+    }
+}
+
+/// Perform `lhs == rhs` operation on two [`ListArray`]s.
+pub fn list_eq<O: Offset>(lhs: &ListArray<O>, rhs: &ListArray<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |i| list_value_eq(lhs, rhs, i))
+}
+
+/// Perform `lhs != rhs` operation on two [`ListArray`]s.
+pub fn list_neq<O: Offset>(lhs: &ListArray<O>, rhs: &ListArray<O>) -> BooleanArray {
+    compare_op(lhs, rhs, |i| !list_value_eq(lhs, rhs, i))
+}
+
+/// Perform `lhs == rhs` operation on two [`FixedSizeListArray`]s.
+pub fn fixed_size_list_eq(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> BooleanArray {
+    compare_op(lhs, rhs, |i| fixed_size_list_value_eq(lhs, rhs, i))
+}
+
+/// Perform `lhs != rhs` operation on two [`FixedSizeListArray`]s.
+pub fn fixed_size_list_neq(lhs: &FixedSizeListArray, rhs: &FixedSizeListArray) -> BooleanArray {
+    compare_op(lhs, rhs, |i| !fixed_size_list_value_eq(lhs, rhs, i))
+}
+
+/// Perform `lhs == rhs` operation on two [`StructArray`]s.
+pub fn struct_eq(lhs: &StructArray, rhs: &StructArray) -> BooleanArray {
+    let values = struct_values_eq(lhs, rhs);
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    BooleanArray::from_data(DataType::Boolean, values, validity)
+}
+
+/// Perform `lhs != rhs` operation on two [`StructArray`]s.
+pub fn struct_neq(lhs: &StructArray, rhs: &StructArray) -> BooleanArray {
+    let values = !&struct_values_eq(lhs, rhs);
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    BooleanArray::from_data(DataType::Boolean, values, validity)
+}
+
+macro_rules! dyn_nested {
+    ($lhs:expr, $rhs:expr, $list:ident, $fixed_size_list:ident, $struct:ident) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        match lhs.data_type().to_physical_type() {
+            PhysicalType::List => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                $list::<i32>(lhs, rhs)
+            }
+            PhysicalType::LargeList => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                $list::<i64>(lhs, rhs)
+            }
+            PhysicalType::FixedSizeList => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                $fixed_size_list(lhs, rhs)
+            }
+            PhysicalType::Struct => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                $struct(lhs, rhs)
+            }
+            _ => unreachable!(),
+        }
+    }};
+}
+
+/// Whether `lhs` and `rhs` are equal up to the names of the fields of list items, which vary
+/// between producers (e.g. `item` or `element`).
+fn is_same_type(lhs: &DataType, rhs: &DataType) -> bool {
+    use DataType::*;
+    match (lhs.to_logical_type(), rhs.to_logical_type()) {
+        (List(lhs), List(rhs)) | (LargeList(lhs), LargeList(rhs)) => {
+            lhs.is_nullable == rhs.is_nullable
+                && lhs.metadata == rhs.metadata
+                && is_same_type(&lhs.data_type, &rhs.data_type)
+        }
+        (FixedSizeList(lhs, lhs_size), FixedSizeList(rhs, rhs_size)) => {
+            lhs_size == rhs_size
+                && lhs.is_nullable == rhs.is_nullable
+                && lhs.metadata == rhs.metadata
+                && is_same_type(&lhs.data_type, &rhs.data_type)
+        }
+        (Struct(lhs), Struct(rhs)) => {
+            lhs.len() == rhs.len()
+                && lhs.iter().zip(rhs.iter()).all(|(lhs, rhs)| {
+                    lhs.name == rhs.name
+                        && lhs.is_nullable == rhs.is_nullable
+                        && lhs.metadata == rhs.metadata
+                        && is_same_type(&lhs.data_type, &rhs.data_type)
+                })
+        }
+        (lhs, rhs) => lhs == rhs,
+    }
+}
+
+/// Returns `array` with `data_type`, which must be the same as its own up to [`is_same_type`].
+fn with_data_type(array: &dyn Array, data_type: &DataType) -> Box<dyn Array> {
+    fn list<O: Offset>(array: &dyn Array, data_type: &DataType) -> Box<dyn Array> {
+        let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+        let values = with_data_type(
+            array.values().as_ref(),
+            ListArray::<O>::get_child_type(data_type),
+        );
+        Box::new(ListArray::<O>::from_data(
+            data_type.clone(),
+            array.offsets().clone(),
+            values.into(),
+            array.validity().cloned(),
+        ))
+    }
+
+    match array.data_type().to_physical_type() {
+        PhysicalType::List => list::<i32>(array, data_type),
+        PhysicalType::LargeList => list::<i64>(array, data_type),
+        PhysicalType::FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let (field, _) = FixedSizeListArray::get_child_and_size(data_type);
+            let values = with_data_type(array.values().as_ref(), field.data_type());
+            Box::new(FixedSizeListArray::from_data(
+                data_type.clone(),
+                values.into(),
+                array.validity().cloned(),
+            ))
+        }
+        PhysicalType::Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .zip(StructArray::get_fields(data_type))
+                .map(|(array, field)| with_data_type(array.as_ref(), field.data_type()).into())
+                .collect();
+            Box::new(StructArray::from_data(
+                data_type.clone(),
+                values,
+                array.validity().cloned(),
+            ))
+        }
+        _ => clone(array),
+    }
+}
+
+/// Applies `op` to `lhs` and `rhs`, first giving `rhs` the data type of `lhs` when they only
+/// differ in the names of list items.
+/// # Panic
+/// Panics iff the arrays do not have the same logical type up to the names of list items.
+fn coerced<F>(lhs: &dyn Array, rhs: &dyn Array, op: F) -> BooleanArray
+where
+    F: Fn(&dyn Array, &dyn Array) -> BooleanArray,
+{
+    if lhs.data_type() == rhs.data_type() {
+        return op(lhs, rhs);
+    }
+    assert!(
+        is_same_type(lhs.data_type(), rhs.data_type()),
+        "Comparison between {:?} and {:?} is not supported",
+        lhs.data_type(),
+        rhs.data_type()
+    );
+    let rhs = with_data_type(rhs, lhs.data_type());
+    op(lhs, rhs.as_ref())
+}
+
+/// `==` between two nested [`Array`]s
+pub(super) fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    coerced(lhs, rhs, |lhs, rhs| {
+        dyn_nested!(lhs, rhs, list_eq, fixed_size_list_eq, struct_eq)
+    })
+}
+
+/// `!=` between two nested [`Array`]s
+pub(super) fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    coerced(lhs, rhs, |lhs, rhs| {
+        dyn_nested!(lhs, rhs, list_neq, fixed_size_list_neq, struct_neq)
+    })
+}
+
+/// `==` between two nested [`Array`]s and includes validities in comparison.
+pub(super) fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    let out = eq(
+        lhs.with_validity(None).as_ref(),
+        rhs.with_validity(None).as_ref(),
+    );
+    finish_eq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// `!=` between two nested [`Array`]s and includes validities in comparison.
+pub(super) fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    let out = neq(
+        lhs.with_validity(None).as_ref(),
+        rhs.with_validity(None).as_ref(),
+    );
+    finish_neq_validities(out, lhs.validity().cloned(), rhs.validity().cloned())
+}
+
+/// Whether `data_type` is a nested type supported by this module
+pub(super) fn is_nested(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::List
+            | PhysicalType::LargeList
+            | PhysicalType::FixedSizeList
+            | PhysicalType::Struct
+    )
+}
@@ -7,9 +7,17 @@ use crate::{
     types::NativeType,
 };
 
+use multiversion::multiversion;
+
 use super::super::utils::combine_validities;
 use super::simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
+// The chunked comparisons below are cloned for AVX2 and SSE4.1 and the best available clone
+// is selected at runtime. Without the `simd` feature the 8-lane types are plain arrays, which
+// the compiler auto-vectorizes when compiled with those target features.
+#[multiversion]
+#[clone(target = "[x86|x86_64]+avx+avx2")]
+#[clone(target = "[x86|x86_64]+sse4.1")]
 pub(crate) fn compare_values_op<T, F>(lhs: &[T], rhs: &[T], op: F) -> MutableBitmap
 where
     T: NativeType + Simd8,
@@ -38,6 +46,9 @@ where
     MutableBitmap::from_vec(values, lhs.len())
 }
 
+#[multiversion]
+#[clone(target = "[x86|x86_64]+avx+avx2")]
+#[clone(target = "[x86|x86_64]+sse4.1")]
 pub(crate) fn compare_values_op_scalar<T, F>(lhs: &[T], rhs: T, op: F) -> MutableBitmap
 where
     T: NativeType + Simd8,
@@ -108,8 +119,8 @@ where
 {
     let validity_lhs = lhs.validity().cloned();
     let validity_rhs = rhs.validity().cloned();
-    let lhs = lhs.with_validity(None);
-    let rhs = rhs.with_validity(None);
+    let lhs = lhs.clone().with_validity(None);
+    let rhs = rhs.clone().with_validity(None);
     let out = compare_op(&lhs, &rhs, |a, b| a.eq(b));
 
     finish_eq_validities(out, validity_lhs, validity_rhs)
@@ -131,7 +142,7 @@ where
     T::Simd: Simd8PartialEq,
 {
     let validity = lhs.validity().cloned();
-    let lhs = lhs.with_validity(None);
+    let lhs = lhs.clone().with_validity(None);
     let out = compare_op_scalar(&lhs, rhs, |a, b| a.eq(b));
 
     finish_eq_validities(out, validity, None)
@@ -154,8 +165,8 @@ where
 {
     let validity_lhs = lhs.validity().cloned();
     let validity_rhs = rhs.validity().cloned();
-    let lhs = lhs.with_validity(None);
-    let rhs = rhs.with_validity(None);
+    let lhs = lhs.clone().with_validity(None);
+    let rhs = rhs.clone().with_validity(None);
     let out = compare_op(&lhs, &rhs, |a, b| a.neq(b));
 
     finish_neq_validities(out, validity_lhs, validity_rhs)
@@ -177,7 +188,7 @@ where
     T::Simd: Simd8PartialEq,
 {
     let validity = lhs.validity().cloned();
-    let lhs = lhs.with_validity(None);
+    let lhs = lhs.clone().with_validity(None);
     let out = compare_op_scalar(&lhs, rhs, |a, b| a.neq(b));
 
     finish_neq_validities(out, validity, None)
@@ -123,6 +123,9 @@ macro_rules! simd8_native_all {
     };
 }
 
+// By default, the lanes are plain arrays (stable Rust), whose comparisons are vectorized by the
+// compiler in the runtime-dispatched kernels of `primitive`. The `simd` feature (nightly) uses
+// `packed_simd` instead.
 #[cfg(not(feature = "simd"))]
 mod native;
 #[cfg(not(feature = "simd"))]
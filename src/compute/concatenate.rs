@@ -13,10 +13,23 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
-use crate::array::{growable::make_growable, Array};
+use std::sync::Arc;
+
+use crate::array::{
+    growable::make_growable, Array, DictionaryArray, DictionaryKey, PrimitiveArray,
+};
+use crate::compute::unique::dedup;
+use crate::datatypes::PhysicalType;
 use crate::error::{ArrowError, Result};
 
 /// Concatenate multiple [Array] of the same type into a single [`Array`].
+///
+/// [`DictionaryArray`]s with different values are concatenated into a [`DictionaryArray`]
+/// whose values are the distinct values of all of them, and whose keys are remapped
+/// accordingly. When all of them share the same values, only their keys are concatenated.
+/// # Errors
+/// This function errors iff `arrays` is empty, the arrays have different data types, or
+/// the distinct values of [`DictionaryArray`]s do not fit in their key type.
 pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
     if arrays.is_empty() {
         return Err(ArrowError::InvalidArgumentError(
@@ -33,6 +46,16 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
         ));
     }
 
+    if let PhysicalType::Dictionary(key_type) = arrays[0].data_type().to_physical_type() {
+        return match_integer_type!(key_type, |$T| {
+            let arrays = arrays
+                .iter()
+                .map(|array| array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap())
+                .collect::<Vec<_>>();
+            concatenate_dictionaries::<$T>(&arrays).map(|array| Box::new(array) as Box<dyn Array>)
+        });
+    }
+
     let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
     let capacity = lengths.iter().sum();
 
@@ -44,3 +67,66 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
 
     Ok(mutable.as_box())
 }
+
+fn concatenate_dictionaries<K: DictionaryKey>(
+    arrays: &[&DictionaryArray<K>],
+) -> Result<DictionaryArray<K>> {
+    let data_type = arrays[0].data_type().clone();
+    let keys = arrays
+        .iter()
+        .map(|array| array.keys() as &dyn Array)
+        .collect::<Vec<_>>();
+
+    let values = arrays[0].values();
+    if arrays
+        .iter()
+        .all(|array| Arc::ptr_eq(array.values(), values))
+    {
+        // same values: the keys remain valid
+        let keys = concatenate(&keys)?;
+        let keys = keys
+            .as_any()
+            .downcast_ref::<PrimitiveArray<K>>()
+            .unwrap()
+            .clone();
+        return Ok(DictionaryArray::from_data(keys, values.clone()).to(data_type));
+    }
+
+    let values = arrays
+        .iter()
+        .map(|array| array.values().as_ref())
+        .collect::<Vec<_>>();
+    let offsets = values
+        .iter()
+        .scan(0, |offset, values| {
+            let current = *offset;
+            *offset += values.len();
+            Some(current)
+        })
+        .collect::<Vec<_>>();
+    let values = concatenate(&values)?;
+
+    // `remap[offsets[i] + key]` is the new key of `key` of the array `i`
+    let (values, remap) = dedup(values.as_ref())?;
+    if !values.is_empty() && K::from_usize(values.len() - 1).is_none() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The concatenated dictionary has {} distinct values, which do not fit in keys of type {:?}. Use a wider key type.",
+            values.len(),
+            K::KEY_TYPE
+        )));
+    }
+
+    let keys = arrays
+        .iter()
+        .zip(offsets)
+        .flat_map(|(array, offset)| {
+            let remap = &remap;
+            array.keys().iter().map(move |key| {
+                key.map(|key| K::from_usize(remap[offset + key.to_usize().unwrap()]).unwrap())
+            })
+        })
+        .collect::<PrimitiveArray<K>>()
+        .to(K::PRIMITIVE.into());
+
+    Ok(DictionaryArray::from_data(keys, values.into()).to(data_type))
+}
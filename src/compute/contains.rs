@@ -1,9 +1,15 @@
-//! Declares the [`contains`] operator
+//! Declares the [`contains`] and [`is_in`] operators
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, ListArray, Offset, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, ListArray, Offset,
+        PrimitiveArray, Utf8Array,
+    },
     bitmap::Bitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType},
     error::{ArrowError, Result},
     types::NativeType,
 };
@@ -196,3 +202,221 @@ pub fn contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
         ))),
     }
 }
+
+/// Options of [`is_in_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IsInOptions {
+    /// Whether the nulls of the set are ignored, i.e. a value not in the set is `false`
+    /// regardless of whether the set contains a null.
+    /// When `false` (the default), a value not in a set containing a null is null, as in SQL,
+    /// where `1 IN (2, NULL)` is `NULL`.
+    pub ignore_set_nulls: bool,
+}
+
+/// Returns a [`BooleanArray`] whose values are `found` and whose validity follows [`IsInOptions`].
+fn finish_is_in(
+    found: Bitmap,
+    validity: Option<&Bitmap>,
+    set_has_nulls: bool,
+    options: IsInOptions,
+) -> BooleanArray {
+    let validity = if set_has_nulls && !options.ignore_set_nulls {
+        // values not found are unknown
+        combine_validities(validity, Some(&found))
+    } else {
+        validity.cloned()
+    };
+    BooleanArray::from_data(DataType::Boolean, found, validity)
+}
+
+fn is_in_primitive<T: NativeType + PartialOrd>(
+    values: &PrimitiveArray<T>,
+    set: &PrimitiveArray<T>,
+    options: IsInOptions,
+) -> BooleanArray {
+    // values without an order (e.g. `NaN`) are not equal to any value, like with `==`
+    let mut sorted = set
+        .iter()
+        .flatten()
+        .filter(|x| x.partial_cmp(x).is_some())
+        .copied()
+        .collect::<Vec<_>>();
+    sorted.sort_unstable_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap());
+
+    let found = values.values().iter().map(|x| {
+        sorted
+            .binary_search_by(|probe| probe.partial_cmp(x).unwrap_or(Ordering::Less))
+            .is_ok()
+    });
+    let found = Bitmap::from_trusted_len_iter(found);
+
+    finish_is_in(found, values.validity(), set.null_count() > 0, options)
+}
+
+fn is_in_utf8<O: Offset>(
+    values: &Utf8Array<O>,
+    set: &Utf8Array<O>,
+    options: IsInOptions,
+) -> BooleanArray {
+    let hashed = set.iter().flatten().collect::<HashSet<_>>();
+
+    let found = values.values_iter().map(|x| hashed.contains(x));
+    let found = Bitmap::from_trusted_len_iter(found);
+
+    finish_is_in(found, values.validity(), set.null_count() > 0, options)
+}
+
+fn is_in_binary<O: Offset>(
+    values: &BinaryArray<O>,
+    set: &BinaryArray<O>,
+    options: IsInOptions,
+) -> BooleanArray {
+    let hashed = set.iter().flatten().collect::<HashSet<_>>();
+
+    let found = values.values_iter().map(|x| hashed.contains(x));
+    let found = Bitmap::from_trusted_len_iter(found);
+
+    finish_is_in(found, values.validity(), set.null_count() > 0, options)
+}
+
+fn is_in_dictionary<K: DictionaryKey>(
+    values: &DictionaryArray<K>,
+    set: &dyn Array,
+    options: IsInOptions,
+) -> Result<BooleanArray> {
+    // probe every value of the dictionary once
+    let dictionary = is_in_with(values.values().as_ref(), set, options)?;
+
+    let keys = values.keys();
+    let found = keys.iter().map(|key| {
+        key.map(|key| dictionary.value(key.to_usize().unwrap()))
+            .unwrap_or(false)
+    });
+    let found = Bitmap::from_trusted_len_iter(found);
+    let validity = keys.iter().map(|key| {
+        key.map(|key| dictionary.is_valid(key.to_usize().unwrap()))
+            .unwrap_or(false)
+    });
+    let validity = Bitmap::from_trusted_len_iter(validity);
+    let validity = if validity.null_count() > 0 {
+        Some(validity)
+    } else {
+        None
+    };
+
+    Ok(BooleanArray::from_data(DataType::Boolean, found, validity))
+}
+
+macro_rules! with_match_ordered_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => return Err(ArrowError::NotYetImplemented(format!(
+            "is_in is not supported for type {:?}",
+            $key_type
+        )))
+    }
+})}
+
+/// Returns whether each value of `values` is in `set`, following SQL semantics for nulls:
+/// * a null value yields null
+/// * a value not in `set` yields null when `set` contains a null, and `false` otherwise
+///
+/// `values` and `set` must have the same logical type, or, when `values` is a [`DictionaryArray`],
+/// `set` must have the logical type of its values.
+/// Use [`is_in_with`] to ignore the nulls of `set`.
+/// # Errors
+/// This function errors iff the types are not equal or are not supported
+/// (primitive, `[Large]Utf8`, `[Large]Binary` and dictionaries of them).
+/// # Example
+/// ```rust
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::contains::is_in;
+///
+/// let values = Int32Array::from(&[Some(1), Some(2), None]);
+///
+/// let set = Int32Array::from_slice(&[2, 3]);
+/// let result = is_in(&values, &set).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(false), Some(true), None]));
+///
+/// let set = Int32Array::from(&[Some(2), None]);
+/// let result = is_in(&values, &set).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[None, Some(true), None]));
+/// ```
+pub fn is_in(values: &dyn Array, set: &dyn Array) -> Result<BooleanArray> {
+    is_in_with(values, set, IsInOptions::default())
+}
+
+/// Returns whether each value of `values` is in `set`, with the null semantics of `options`.
+/// See [`is_in`] for details.
+/// # Errors
+/// This function errors iff the types are not equal or are not supported.
+pub fn is_in_with(
+    values: &dyn Array,
+    set: &dyn Array,
+    options: IsInOptions,
+) -> Result<BooleanArray> {
+    if let PhysicalType::Dictionary(key_type) = values.data_type().to_physical_type() {
+        return match_integer_type!(key_type, |$T| {
+            let values = values.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            is_in_dictionary(values, set, options)
+        });
+    }
+    if values.data_type().to_logical_type() != set.data_type().to_logical_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "is_in requires values and set of the same logical type (\"{:?}\" != \"{:?}\")",
+            values.data_type(),
+            set.data_type()
+        )));
+    }
+
+    use PhysicalType::*;
+    Ok(match values.data_type().to_physical_type() {
+        Primitive(primitive) => with_match_ordered_primitive_type!(primitive, |$T| {
+            is_in_primitive::<$T>(
+                values.as_any().downcast_ref().unwrap(),
+                set.as_any().downcast_ref().unwrap(),
+                options,
+            )
+        }),
+        Utf8 => is_in_utf8::<i32>(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            options,
+        ),
+        LargeUtf8 => is_in_utf8::<i64>(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            options,
+        ),
+        Binary => is_in_binary::<i32>(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            options,
+        ),
+        LargeBinary => is_in_binary::<i64>(
+            values.as_any().downcast_ref().unwrap(),
+            set.as_any().downcast_ref().unwrap(),
+            options,
+        ),
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "is_in is not supported for type {:?}",
+                t
+            )))
+        }
+    })
+}
@@ -1,5 +1,26 @@
 //! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators.
-//! // multiversion does not copy documentation, causing a false positive
+//!
+//! Hashes are consistent with equality: two slots that are equal (e.g. via
+//! [`eq`](crate::compute::comparison::eq)) have the same hash, regardless of the arrays'
+//! offsets or encoding (e.g. a [`DictionaryArray`] hashes like its decoded values).
+//! Every null slot hashes to [`NULL_HASH`].
+//!
+//! Multiple columns are hashed by folding them into the same hashes via [`hash_combine`]:
+//! ```
+//! use arrow2::array::{Int32Array, Utf8Array};
+//! use arrow2::compute::hash::hash_combine;
+//!
+//! let a = Int32Array::from_slice(&[1, 2, 1]);
+//! let b = Utf8Array::<i32>::from_slice(&["a", "b", "a"]);
+//!
+//! let mut hashes = vec![0; 3];
+//! hash_combine(&a, &mut hashes).unwrap();
+//! hash_combine(&b, &mut hashes).unwrap();
+//!
+//! assert_eq!(hashes[0], hashes[2]);
+//! assert_ne!(hashes[0], hashes[1]);
+//! ```
+// multiversion does not copy documentation, causing a false positive
 #![allow(missing_docs)]
 use ahash::{CallHasher, RandomState};
 use multiversion::multiversion;
@@ -12,54 +33,222 @@ macro_rules! new_state {
 }
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
-    buffer::Buffer,
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeBinaryArray,
+        FixedSizeListArray, ListArray, Offset, PrimitiveArray, StructArray, Utf8Array,
+    },
+    bitmap::Bitmap,
     datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{ArrowError, Result},
-    types::NativeType,
+    types::{f16, NativeType},
 };
 
-use super::arity::unary;
+/// The hash of every null slot, regardless of the [`DataType`].
+pub const NULL_HASH: u64 = 0;
+
+/// Combines two hashes into a new one. The combination is not commutative,
+/// so that e.g. `[a, b]` and `[b, a]` hash differently.
+#[inline]
+pub fn combine_hashes(lhs: u64, rhs: u64) -> u64 {
+    lhs ^ rhs
+        .wrapping_add(0x9e37_79b9_7f4a_7c15)
+        .wrapping_add(lhs << 6)
+        .wrapping_add(lhs >> 2)
+}
+
+/// Writes the hash of each slot into `hashes`, or combines it with the existing hash when
+/// `combine` is true. Null slots are hashed as [`NULL_HASH`].
+#[inline]
+fn write_hashes<I: Iterator<Item = u64>>(
+    values: I,
+    validity: Option<&Bitmap>,
+    hashes: &mut [u64],
+    combine: bool,
+) {
+    let write = |hash: &mut u64, value: u64| {
+        *hash = if combine {
+            combine_hashes(*hash, value)
+        } else {
+            value
+        }
+    };
+    match validity {
+        Some(validity) => hashes.iter_mut().zip(values.zip(validity.iter())).for_each(
+            |(hash, (value, is_valid))| write(hash, if is_valid { value } else { NULL_HASH }),
+        ),
+        None => hashes
+            .iter_mut()
+            .zip(values)
+            .for_each(|(hash, value)| write(hash, value)),
+    }
+}
+
+#[inline]
+fn to_array(hashes: Vec<u64>, validity: Option<&Bitmap>) -> PrimitiveArray<u64> {
+    PrimitiveArray::<u64>::from_data(DataType::UInt64, hashes.into(), validity.cloned())
+}
 
 #[multiversion]
 #[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
-/// Element-wise hash of a [`PrimitiveArray`]. Validity is preserved.
-pub fn hash_primitive<T: NativeType + Hash>(array: &PrimitiveArray<T>) -> PrimitiveArray<u64> {
+fn primitive_into<T: NativeType + Hash>(
+    array: &PrimitiveArray<T>,
+    hashes: &mut [u64],
+    combine: bool,
+) {
     let state = new_state!();
 
-    unary(array, |x| T::get_hash(&x, &state), DataType::UInt64)
+    let values = array.values().iter().map(|x| T::get_hash(x, &state));
+    write_hashes(values, array.validity(), hashes, combine)
 }
 
 #[multiversion]
 #[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
-/// Element-wise hash of a [`BooleanArray`]. Validity is preserved.
-pub fn hash_boolean(array: &BooleanArray) -> PrimitiveArray<u64> {
+fn float16_into(array: &PrimitiveArray<f16>, hashes: &mut [u64], combine: bool) {
     let state = new_state!();
 
-    let iter = array.values_iter().map(|x| u8::get_hash(&x, &state));
-    let values = Buffer::from_trusted_len_iter(iter);
-    PrimitiveArray::<u64>::from_data(DataType::UInt64, values, array.validity().cloned())
+    // `-0.0 == 0.0` and thus they must hash equally
+    let values = array
+        .values()
+        .iter()
+        .map(|x| u32::get_hash(&(x.to_f32() + 0.0).to_bits(), &state));
+    write_hashes(values, array.validity(), hashes, combine)
 }
 
 #[multiversion]
 #[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
-/// Element-wise hash of a [`Utf8Array`]. Validity is preserved.
-pub fn hash_utf8<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<u64> {
+fn float32_into(array: &PrimitiveArray<f32>, hashes: &mut [u64], combine: bool) {
+    let state = new_state!();
+
+    // `-0.0 == 0.0` and thus they must hash equally
+    let values = array
+        .values()
+        .iter()
+        .map(|x| u32::get_hash(&(x + 0.0).to_bits(), &state));
+    write_hashes(values, array.validity(), hashes, combine)
+}
+
+#[multiversion]
+#[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
+fn float64_into(array: &PrimitiveArray<f64>, hashes: &mut [u64], combine: bool) {
+    let state = new_state!();
+
+    // `-0.0 == 0.0` and thus they must hash equally
+    let values = array
+        .values()
+        .iter()
+        .map(|x| u64::get_hash(&(x + 0.0).to_bits(), &state));
+    write_hashes(values, array.validity(), hashes, combine)
+}
+
+#[multiversion]
+#[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
+fn boolean_into(array: &BooleanArray, hashes: &mut [u64], combine: bool) {
     let state = new_state!();
 
-    let iter = array
+    let values = array.values_iter().map(|x| u8::get_hash(&x, &state));
+    write_hashes(values, array.validity(), hashes, combine)
+}
+
+#[multiversion]
+#[clone(target = "x86_64+aes+sse3+ssse3+avx+avx2")]
+fn utf8_into<O: Offset>(array: &Utf8Array<O>, hashes: &mut [u64], combine: bool) {
+    let state = new_state!();
+
+    let values = array
         .values_iter()
         .map(|x| <[u8]>::get_hash(&x.as_bytes(), &state));
-    let values = Buffer::from_trusted_len_iter(iter);
-    PrimitiveArray::<u64>::from_data(DataType::UInt64, values, array.validity().cloned())
+    write_hashes(values, array.validity(), hashes, combine)
 }
 
-/// Element-wise hash of a [`BinaryArray`]. Validity is preserved.
-pub fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<u64> {
+fn binary_into<O: Offset>(array: &BinaryArray<O>, hashes: &mut [u64], combine: bool) {
+    let state = new_state!();
+
+    let values = array.values_iter().map(|x| <[u8]>::get_hash(&x, &state));
+    write_hashes(values, array.validity(), hashes, combine)
+}
+
+fn fixed_size_binary_into(array: &FixedSizeBinaryArray, hashes: &mut [u64], combine: bool) {
+    let state = new_state!();
+
+    let values = (0..array.len()).map(|i| <[u8]>::get_hash(&array.value(i), &state));
+    write_hashes(values, array.validity(), hashes, combine)
+}
+
+fn dictionary_into<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    hashes: &mut [u64],
+    combine: bool,
+) -> Result<()> {
+    let values = array.values().as_ref();
+    let mut values_hashes = vec![0; values.len()];
+    hash_into(values, &mut values_hashes, false)?;
+
+    // the keys of null slots are not necessarily valid indices
+    let values = array.keys().values().iter().map(|key| {
+        key.to_usize()
+            .and_then(|key| values_hashes.get(key))
+            .copied()
+            .unwrap_or(NULL_HASH)
+    });
+    write_hashes(values, array.validity(), hashes, combine);
+    Ok(())
+}
+
+/// The hash of a list of `hashes`, order-dependent and including its length.
+#[inline]
+fn hash_list(hashes: &[u64], state: &RandomState) -> u64 {
+    hashes
+        .iter()
+        .fold(usize::get_hash(&hashes.len(), state), |acc, hash| {
+            combine_hashes(acc, *hash)
+        })
+}
+
+fn list_into<O: Offset>(array: &ListArray<O>, hashes: &mut [u64], combine: bool) -> Result<()> {
+    let state = new_state!();
+
+    let offsets = array.offsets();
+    let start = offsets[0].to_usize();
+    let end = offsets[offsets.len() - 1].to_usize();
+    let values = array.values().slice(start, end - start);
+    let mut values_hashes = vec![0; values.len()];
+    hash_into(values.as_ref(), &mut values_hashes, false)?;
+
+    let values = offsets.windows(2).map(|w| {
+        let (lhs, rhs) = (w[0].to_usize() - start, w[1].to_usize() - start);
+        hash_list(&values_hashes[lhs..rhs], &state)
+    });
+    write_hashes(values, array.validity(), hashes, combine);
+    Ok(())
+}
+
+fn fixed_size_list_into(
+    array: &FixedSizeListArray,
+    hashes: &mut [u64],
+    combine: bool,
+) -> Result<()> {
     let state = new_state!();
-    let iter = array.values_iter().map(|x| <[u8]>::get_hash(&x, &state));
-    let values = Buffer::from_trusted_len_iter(iter);
-    PrimitiveArray::<u64>::from_data(DataType::UInt64, values, array.validity().cloned())
+
+    let size = FixedSizeListArray::get_child_and_size(array.data_type()).1;
+    let values = array.values().as_ref();
+    let mut values_hashes = vec![0; values.len()];
+    hash_into(values, &mut values_hashes, false)?;
+
+    let values =
+        (0..array.len()).map(|i| hash_list(&values_hashes[i * size..(i + 1) * size], &state));
+    write_hashes(values, array.validity(), hashes, combine);
+    Ok(())
+}
+
+fn struct_into(array: &StructArray, hashes: &mut [u64], combine: bool) -> Result<()> {
+    let mut values_hashes = vec![0; array.len()];
+    array
+        .values()
+        .iter()
+        .try_for_each(|field| hash_into(field.as_ref(), &mut values_hashes, true))?;
+
+    write_hashes(values_hashes.into_iter(), array.validity(), hashes, combine);
+    Ok(())
 }
 
 macro_rules! with_match_primitive_type {(
@@ -67,51 +256,127 @@ macro_rules! with_match_primitive_type {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::days_ms;
+    use crate::types::{days_ms, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
+        MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
-        _ => return Err(ArrowError::NotYetImplemented(format!(
-            "Hash not implemented for type {:?}",
-            $key_type
-        )))
+        Float16 | Float32 | Float64 => unreachable!(),
     }
 })}
 
-/// Returns the element-wise hash of an [`Array`]. Validity is preserved.
-/// Supported DataTypes:
-/// * Boolean types
-/// * All primitive types except `Float32` and `Float64`
-/// * `[Large]Utf8`;
-/// * `[Large]Binary`.
-/// # Errors
-/// This function errors whenever it does not support the specific `DataType`.
-pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
+fn hash_into(array: &dyn Array, hashes: &mut [u64], combine: bool) -> Result<()> {
     use PhysicalType::*;
-    Ok(match array.data_type().to_physical_type() {
-        Boolean => hash_boolean(array.as_any().downcast_ref().unwrap()),
+    match array.data_type().to_physical_type() {
+        Null => write_hashes((0..array.len()).map(|_| NULL_HASH), None, hashes, combine),
+        Boolean => boolean_into(array.as_any().downcast_ref().unwrap(), hashes, combine),
+        Primitive(PrimitiveType::Float16) => {
+            float16_into(array.as_any().downcast_ref().unwrap(), hashes, combine)
+        }
+        Primitive(PrimitiveType::Float32) => {
+            float32_into(array.as_any().downcast_ref().unwrap(), hashes, combine)
+        }
+        Primitive(PrimitiveType::Float64) => {
+            float64_into(array.as_any().downcast_ref().unwrap(), hashes, combine)
+        }
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
-            hash_primitive::<$T>(array.as_any().downcast_ref().unwrap())
+            primitive_into::<$T>(array.as_any().downcast_ref().unwrap(), hashes, combine)
+        }),
+        Binary => binary_into::<i32>(array.as_any().downcast_ref().unwrap(), hashes, combine),
+        LargeBinary => binary_into::<i64>(array.as_any().downcast_ref().unwrap(), hashes, combine),
+        FixedSizeBinary => {
+            fixed_size_binary_into(array.as_any().downcast_ref().unwrap(), hashes, combine)
+        }
+        Utf8 => utf8_into::<i32>(array.as_any().downcast_ref().unwrap(), hashes, combine),
+        LargeUtf8 => utf8_into::<i64>(array.as_any().downcast_ref().unwrap(), hashes, combine),
+        List => list_into::<i32>(array.as_any().downcast_ref().unwrap(), hashes, combine)?,
+        LargeList => list_into::<i64>(array.as_any().downcast_ref().unwrap(), hashes, combine)?,
+        FixedSizeList => {
+            fixed_size_list_into(array.as_any().downcast_ref().unwrap(), hashes, combine)?
+        }
+        Struct => struct_into(array.as_any().downcast_ref().unwrap(), hashes, combine)?,
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            dictionary_into::<$T>(array.as_any().downcast_ref().unwrap(), hashes, combine)?
         }),
-        Binary => hash_binary::<i32>(array.as_any().downcast_ref().unwrap()),
-        LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
-        Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
-        LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
         t => {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Hash not implemented for type {:?}",
                 t
             )))
         }
-    })
+    };
+    Ok(())
+}
+
+/// Element-wise hash of a [`PrimitiveArray`]. Validity is preserved.
+pub fn hash_primitive<T: NativeType + Hash>(array: &PrimitiveArray<T>) -> PrimitiveArray<u64> {
+    let mut hashes = vec![0; array.len()];
+    primitive_into(array, &mut hashes, false);
+    to_array(hashes, array.validity())
+}
+
+/// Element-wise hash of a [`BooleanArray`]. Validity is preserved.
+pub fn hash_boolean(array: &BooleanArray) -> PrimitiveArray<u64> {
+    let mut hashes = vec![0; array.len()];
+    boolean_into(array, &mut hashes, false);
+    to_array(hashes, array.validity())
+}
+
+/// Element-wise hash of a [`Utf8Array`]. Validity is preserved.
+pub fn hash_utf8<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<u64> {
+    let mut hashes = vec![0; array.len()];
+    utf8_into(array, &mut hashes, false);
+    to_array(hashes, array.validity())
+}
+
+/// Element-wise hash of a [`BinaryArray`]. Validity is preserved.
+pub fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<u64> {
+    let mut hashes = vec![0; array.len()];
+    binary_into(array, &mut hashes, false);
+    to_array(hashes, array.validity())
+}
+
+/// Returns the element-wise hash of an [`Array`]. Validity is preserved and
+/// the values of null slots are [`NULL_HASH`].
+/// Supported DataTypes:
+/// * `Null` and `Boolean`
+/// * All primitive types
+/// * `[Large]Utf8`, `[Large]Binary` and `FixedSizeBinary`
+/// * `Dictionary` whose values are supported
+/// * `[Large]List`, `FixedSizeList` and `Struct` whose children are supported
+/// # Errors
+/// This function errors whenever it does not support the specific `DataType`.
+pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
+    let mut hashes = vec![0; array.len()];
+    hash_into(array, &mut hashes, false)?;
+    Ok(to_array(hashes, array.validity()))
+}
+
+/// Combines the element-wise hash of an [`Array`] into `hashes` via [`combine_hashes`].
+///
+/// Hashing multiple arrays is a fold of this function over them, starting from
+/// an initial (e.g. zeroed or seeded) `hashes`.
+/// # Errors
+/// This function errors iff `hashes` and `array` have different lengths or
+/// [`hash`] does not support the `DataType`.
+pub fn hash_combine(array: &dyn Array, hashes: &mut [u64]) -> Result<()> {
+    if array.len() != hashes.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "hash_combine requires hashes of the same length as the array ({} != {})",
+            hashes.len(),
+            array.len()
+        )));
+    }
+    hash_into(array, hashes, true)
 }
 
 /// Checks if an array of type `datatype` can be used in [`hash`].
@@ -123,27 +388,27 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
 ///
 /// let data_type = DataType::Int8;
 /// assert_eq!(can_hash(&data_type), true);
-
-/// let data_type = DataType::Null;
+///
+/// let data_type = DataType::Union(vec![], None, arrow2::datatypes::UnionMode::Dense);
 /// assert_eq!(can_hash(&data_type), false);
 /// ```
 pub fn can_hash(data_type: &DataType) -> bool {
-    matches!(
-        data_type.to_physical_type(),
-        PhysicalType::Boolean
-            | PhysicalType::Primitive(PrimitiveType::Int8)
-            | PhysicalType::Primitive(PrimitiveType::Int16)
-            | PhysicalType::Primitive(PrimitiveType::Int32)
-            | PhysicalType::Primitive(PrimitiveType::Int64)
-            | PhysicalType::Primitive(PrimitiveType::Int128)
-            | PhysicalType::Primitive(PrimitiveType::DaysMs)
-            | PhysicalType::Primitive(PrimitiveType::UInt8)
-            | PhysicalType::Primitive(PrimitiveType::UInt16)
-            | PhysicalType::Primitive(PrimitiveType::UInt32)
-            | PhysicalType::Primitive(PrimitiveType::UInt64)
-            | PhysicalType::Binary
-            | PhysicalType::LargeBinary
-            | PhysicalType::Utf8
-            | PhysicalType::LargeUtf8
-    )
+    match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => can_hash(values.as_ref()),
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            can_hash(field.data_type())
+        }
+        DataType::Struct(fields) => fields.iter().all(|field| can_hash(field.data_type())),
+        _ => matches!(
+            data_type.to_physical_type(),
+            PhysicalType::Null
+                | PhysicalType::Boolean
+                | PhysicalType::Primitive(_)
+                | PhysicalType::Binary
+                | PhysicalType::LargeBinary
+                | PhysicalType::FixedSizeBinary
+                | PhysicalType::Utf8
+                | PhysicalType::LargeUtf8
+        ),
+    }
 }
@@ -1,10 +1,17 @@
-//! Contains the operator [`if_then_else`].
-use crate::array::{growable, Array, BooleanArray};
+//! Contains the operators [`if_then_else`] and [`if_then_else_primitive`].
+use crate::array::{growable, Array, BooleanArray, PrimitiveArray};
 use crate::bitmap::utils::SlicesIterator;
+use crate::buffer::Buffer;
+use crate::compute::utils::combine_validities;
+use crate::datatypes::PhysicalType;
 use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
 
 /// Returns the values from `lhs` if the predicate is `true` or from the `rhs` if the predicate is false
 /// Returns `None` if the predicate is `None`.
+///
+/// Runs of equal predicate slots are copied in bulk; primitive arrays are selected via
+/// [`if_then_else_primitive`].
 /// # Example
 /// ```rust
 /// # use arrow2::error::Result;
@@ -28,6 +35,95 @@ pub fn if_then_else(
     lhs: &dyn Array,
     rhs: &dyn Array,
 ) -> Result<Box<dyn Array>> {
+    check_arguments(predicate, lhs, rhs)?;
+
+    if let PhysicalType::Primitive(primitive) = lhs.data_type().to_physical_type() {
+        return with_match_primitive_type!(primitive, |$T| {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            Ok(Box::new(if_then_else_primitive::<$T>(predicate, lhs, rhs)?))
+        });
+    }
+
+    let validity = predicate.validity().filter(|x| x.null_count() > 0);
+    let mut growable = growable::make_growable(&[lhs, rhs], validity.is_some(), lhs.len());
+
+    // extends the predicate's slots `[start, start + len)`, that are not `true`
+    let extend_falsy = |growable: &mut dyn growable::Growable, start: usize, len: usize| {
+        if let Some(validity) = validity {
+            // false slots are taken from `rhs`, null slots are null
+            let validity = validity.clone().slice(start, len);
+            let mut end_valid = 0;
+            for (valid_start, valid_len) in SlicesIterator::new(&validity) {
+                if valid_start != end_valid {
+                    growable.extend_validity(valid_start - end_valid);
+                }
+                growable.extend(1, start + valid_start, valid_len);
+                end_valid = valid_start + valid_len;
+            }
+            if end_valid != len {
+                growable.extend_validity(len - end_valid);
+            }
+        } else {
+            growable.extend(1, start, len);
+        }
+    };
+
+    // the values of null slots are arbitrary: the `true`s are the valid `true` slots
+    let truthy = match validity {
+        Some(validity) => predicate.values() & validity,
+        None => predicate.values().clone(),
+    };
+    let mut start_falsy = 0;
+    for (start, len) in SlicesIterator::new(&truthy) {
+        if start != start_falsy {
+            extend_falsy(growable.as_mut(), start_falsy, start - start_falsy);
+        };
+        growable.extend(0, start, len);
+        start_falsy = start + len;
+    }
+    if start_falsy != lhs.len() {
+        extend_falsy(growable.as_mut(), start_falsy, lhs.len() - start_falsy);
+    }
+    Ok(growable.as_box())
+}
+
+/// Returns the values from `lhs` if the predicate is `true` or from the `rhs` if the predicate is false
+/// Returns `None` if the predicate is `None`.
+///
+/// Unlike [`if_then_else`], this selects between the values of both arrays in a single pass.
+/// # Error
+/// Errors iff the arrays have different lengths or data types.
+pub fn if_then_else_primitive<T: NativeType>(
+    predicate: &BooleanArray,
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>> {
+    check_arguments(predicate, lhs, rhs)?;
+
+    let mask = predicate.values();
+    let values = mask
+        .iter()
+        .zip(lhs.values().iter().zip(rhs.values().iter()))
+        .map(|(mask, (lhs, rhs))| if mask { *lhs } else { *rhs });
+    let values = Buffer::from_trusted_len_iter(values);
+
+    let validity = match (lhs.validity(), rhs.validity()) {
+        (None, None) => None,
+        (Some(lhs), None) => Some(&(mask & lhs) | &!mask),
+        (None, Some(rhs)) => Some(mask | &(&!mask & rhs)),
+        (Some(lhs), Some(rhs)) => Some(&(mask & lhs) | &(&!mask & rhs)),
+    };
+    let validity = combine_validities(validity.as_ref(), predicate.validity());
+
+    Ok(PrimitiveArray::from_data(
+        lhs.data_type().clone(),
+        values,
+        validity,
+    ))
+}
+
+fn check_arguments(predicate: &BooleanArray, lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
     if lhs.data_type() != rhs.data_type() {
         return Err(ArrowError::InvalidArgumentError(format!(
             "If then else requires the arguments to have the same datatypes ({:?} != {:?})",
@@ -43,33 +139,5 @@ pub fn if_then_else(
             rhs.len()
         )));
     }
-
-    let result = if predicate.null_count() > 0 {
-        let mut growable = growable::make_growable(&[lhs, rhs], true, lhs.len());
-        for (i, v) in predicate.iter().enumerate() {
-            match v {
-                Some(v) => growable.extend(!v as usize, i, 1),
-                None => growable.extend_validity(1),
-            }
-        }
-        growable.as_box()
-    } else {
-        let mut growable = growable::make_growable(&[lhs, rhs], false, lhs.len());
-        let mut start_falsy = 0;
-        let mut total_len = 0;
-        for (start, len) in SlicesIterator::new(predicate.values()) {
-            if start != start_falsy {
-                growable.extend(1, start_falsy, start - start_falsy);
-                total_len += start - start_falsy;
-            };
-            growable.extend(0, start, len);
-            total_len += len;
-            start_falsy = start + len;
-        }
-        if total_len != lhs.len() {
-            growable.extend(1, total_len, lhs.len() - total_len);
-        }
-        growable.as_box()
-    };
-    Ok(result)
+    Ok(())
 }
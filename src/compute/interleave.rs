@@ -0,0 +1,90 @@
+//! Contains the interleave kernel, that builds an [`Array`] from rows of multiple arrays.
+//!
+//! Example:
+//!
+//! ```
+//! use arrow2::array::Int32Array;
+//! use arrow2::compute::interleave::interleave;
+//!
+//! let a = Int32Array::from_slice(&[1, 2, 3]);
+//! let b = Int32Array::from(&[Some(4), None]);
+//!
+//! let result = interleave(&[&a, &b], &[(1, 0), (0, 2), (1, 1), (0, 0)]).unwrap();
+//! let expected = Int32Array::from(&[Some(4), Some(3), None, Some(1)]);
+//! assert_eq!(expected, result.as_ref());
+//! ```
+
+use crate::array::{growable::make_growable, Array};
+use crate::error::{ArrowError, Result};
+
+/// Returns a new [`Array`] whose rows are the rows of `arrays` referenced by `indices`,
+/// where `(i, j)` is the row `j` of the array `i`.
+///
+/// Consecutive rows of the same array are copied in a single step, so that runs
+/// of indices (e.g. as produced by a merge) are copied efficiently.
+///
+/// [`DictionaryArray`](crate::array::DictionaryArray)s are supported regardless of whether
+/// they share their values: the values of the result are the values of all `arrays`,
+/// one after the other and without deduplication, and its keys point to them.
+/// # Errors
+/// This function errors iff:
+/// * `arrays` is empty
+/// * the arrays have different [`DataType`](crate::datatypes::DataType)s
+/// * an index is out of bounds
+pub fn interleave(arrays: &[&dyn Array], indices: &[(usize, usize)]) -> Result<Box<dyn Array>> {
+    if arrays.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "interleave requires input of at least one array".to_string(),
+        ));
+    }
+
+    let data_type = arrays[0].data_type();
+    if arrays.iter().any(|array| array.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to interleave arrays of different data types.".to_string(),
+        ));
+    }
+    if let Some((array, row)) = indices
+        .iter()
+        .find(|(array, row)| *array >= arrays.len() || *row >= arrays[*array].len())
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            if *array >= arrays.len() {
+                format!(
+                    "The index ({}, {}) is out of bounds: there are {} arrays",
+                    array,
+                    row,
+                    arrays.len()
+                )
+            } else {
+                format!(
+                    "The index ({}, {}) is out of bounds: the array {} has {} rows",
+                    array,
+                    row,
+                    array,
+                    arrays[*array].len()
+                )
+            },
+        ));
+    }
+
+    let mut growable = make_growable(arrays, false, indices.len());
+
+    let mut indices = indices.iter();
+    if let Some(&(mut array, mut start)) = indices.next() {
+        let mut len = 1;
+        for &(next_array, row) in indices {
+            if next_array == array && row == start + len {
+                len += 1;
+            } else {
+                growable.extend(array, start, len);
+                array = next_array;
+                start = row;
+                len = 1;
+            }
+        }
+        growable.extend(array, start, len);
+    }
+
+    Ok(growable.as_box())
+}
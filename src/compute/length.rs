@@ -15,27 +15,29 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines kernel for length of composite arrays
+//! Defines kernels for the length of composite arrays, in bytes ([`length`]),
+//! bits ([`bit_length`]) and characters ([`char_length`]).
+
+use std::convert::TryFrom;
 
 use crate::{
     array::*,
+    bitmap::Bitmap,
     buffer::Buffer,
     datatypes::DataType,
     error::{ArrowError, Result},
     types::NativeType,
 };
 
-fn unary_offsets_string<O, F>(array: &Utf8Array<O>, op: F) -> PrimitiveArray<O>
+fn unary_offsets<O, F>(offsets: &[O], validity: Option<&Bitmap>, op: F) -> Result<PrimitiveArray<O>>
 where
     O: Offset + NativeType,
-    F: Fn(O) -> O,
+    F: Fn(O) -> Option<O>,
 {
-    let values = array
-        .offsets()
+    let values = offsets
         .windows(2)
-        .map(|offset| op(offset[1] - offset[0]));
-
-    let values = Buffer::from_trusted_len_iter(values);
+        .map(|offset| op(offset[1] - offset[0]).ok_or(ArrowError::Overflow))
+        .collect::<Result<Vec<_>>>()?;
 
     let data_type = if O::is_large() {
         DataType::Int64
@@ -43,27 +45,120 @@ where
         DataType::Int32
     };
 
-    PrimitiveArray::<O>::from_data(data_type, values, array.validity().cloned())
+    Ok(PrimitiveArray::<O>::from_data(
+        data_type,
+        values.into(),
+        validity.cloned(),
+    ))
 }
 
-/// Returns an array of integers with the number of bytes on each string of the array.
-pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
+fn fixed_size_binary_length<F: Fn(i32) -> Option<i32>>(
+    array: &FixedSizeBinaryArray,
+    op: F,
+) -> Result<PrimitiveArray<i32>> {
+    let size = i32::try_from(array.size())
+        .ok()
+        .and_then(op)
+        .ok_or(ArrowError::Overflow)?;
+    let values = vec![size; array.len()];
+    Ok(PrimitiveArray::<i32>::from_data(
+        DataType::Int32,
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Applies `op` to the lengths of `array`, which returns `None` when the result overflows.
+fn length_op(array: &dyn Array, name: &str, op: fn(i64) -> Option<i64>) -> Result<Box<dyn Array>> {
+    let op32 = |x: i32| op(x as i64).and_then(|x| i32::try_from(x).ok());
     match array.data_type() {
         DataType::Utf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            Ok(Box::new(unary_offsets_string::<i32, _>(array, |x| x)))
+            unary_offsets(array.offsets(), array.validity(), op32)
+                .map(|x| Box::new(x) as Box<dyn Array>)
         }
         DataType::LargeUtf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-            Ok(Box::new(unary_offsets_string::<i64, _>(array, |x| x)))
+            unary_offsets(array.offsets(), array.validity(), op)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            unary_offsets(array.offsets(), array.validity(), op32)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            unary_offsets(array.offsets(), array.validity(), op)
+                .map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            fixed_size_binary_length(array, op32).map(|x| Box::new(x) as Box<dyn Array>)
         }
         _ => Err(ArrowError::InvalidArgumentError(format!(
-            "length not supported for {:?}",
+            "{} not supported for {:?}",
+            name,
             array.data_type()
         ))),
     }
 }
 
+/// Returns an array of integers with the number of bytes on each string or binary of the array.
+///
+/// The result is [`DataType::Int64`] for [`DataType::LargeUtf8`] and [`DataType::LargeBinary`],
+/// and [`DataType::Int32`] otherwise. Every slot of a [`DataType::FixedSizeBinary`] has its size.
+pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    length_op(array, "length", Some)
+}
+
+/// Returns an array of integers with the number of bits on each string or binary of the array.
+///
+/// The types of the result are the same as in [`length`].
+/// # Errors
+/// This function errors with [`ArrowError::Overflow`] if a number of bits does not fit in
+/// the type of the result, e.g. a [`DataType::Utf8`] value with more than `i32::MAX / 8` bytes.
+pub fn bit_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    length_op(array, "bit_length", |x| x.checked_mul(8))
+}
+
+/// Returns an array of integers with the number of characters (unicode scalar values) on
+/// each string of the array, e.g. SQL's `CHAR_LENGTH`.
+///
+/// # Examples
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::length::char_length;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("straße"), None, Some("💖")]);
+/// assert_eq!(char_length(&array), Int32Array::from(&[Some(6), None, Some(1)]));
+/// ```
+pub fn char_length<O: Offset + NativeType>(array: &Utf8Array<O>) -> PrimitiveArray<O> {
+    // a character starts at every byte that is not a continuation byte (`0b10xxxxxx`)
+    let values = array.values_iter().map(|value| {
+        let count = value
+            .as_bytes()
+            .iter()
+            .filter(|&&byte| (byte as i8) >= -0x40)
+            .count();
+        // the number of characters is at most the number of bytes, which fits in `O`
+        O::from_usize(count).unwrap()
+    });
+
+    let values = Buffer::from_trusted_len_iter(values);
+
+    let data_type = if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+
+    PrimitiveArray::<O>::from_data(data_type, values, array.validity().cloned())
+}
+
 /// Checks if an array of type `datatype` can perform length operation
 ///
 /// # Examples
@@ -78,5 +173,12 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
 /// assert_eq!(can_length(&data_type), false);
 /// ```
 pub fn can_length(data_type: &DataType) -> bool {
-    matches!(data_type, DataType::Utf8 | DataType::LargeUtf8)
+    matches!(
+        data_type,
+        DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Binary
+            | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
+    )
 }
@@ -1,4 +1,13 @@
-//! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`].
+//! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`], and their
+//! case-insensitive counterparts such as [`ilike_utf8`] and [`ilike_utf8_scalar`].
+//!
+//! There are two wildcards supported:
+//!
+//! * `%` - The percent sign represents zero, one, or multiple characters
+//! * `_` - The underscore represents a single character
+//!
+//! A wildcard preceded by the escape character (by default `\`) is matched literally,
+//! e.g. `100\%` only matches `100%`. The escape character itself is escaped by repeating it.
 use std::collections::HashMap;
 
 use regex::bytes::Regex as BytesRegex;
@@ -7,24 +16,154 @@ use regex::Regex;
 use crate::{
     array::{BinaryArray, BooleanArray, Offset, Utf8Array},
     bitmap::Bitmap,
+    compute::substring::{
+        contains_binary_scalar, contains_utf8_scalar, ends_with_binary_scalar,
+        ends_with_utf8_scalar, starts_with_binary_scalar, starts_with_utf8_scalar,
+    },
     compute::utils::combine_validities,
     datatypes::DataType,
     error::{ArrowError, Result},
 };
 
-#[inline]
-fn is_like_pattern(c: char) -> bool {
-    c == '%' || c == '_'
+/// The default escape character of LIKE patterns
+const ESCAPE: char = '\\';
+
+/// An element of a LIKE pattern
+enum Token {
+    /// A character that matches itself
+    Literal(char),
+    /// `%`
+    Any,
+    /// `_`
+    One,
+}
+
+fn tokenize(pattern: &str, escape: Option<char>) -> Result<Vec<Token>> {
+    let mut tokens = Vec::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if Some(c) == escape => {
+                let c = chars.next().ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "The LIKE pattern \"{}\" ends with the escape character",
+                        pattern
+                    ))
+                })?;
+                Token::Literal(c)
+            }
+            '%' => Token::Any,
+            '_' => Token::One,
+            c => Token::Literal(c),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// A compiled LIKE pattern. Patterns whose only wildcards are a leading and/or a trailing `%`
+/// are evaluated without a regex.
+enum Matcher<R> {
+    Equal(String),
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+    Regex(R),
+}
+
+fn literal(tokens: &[Token]) -> Option<String> {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Literal(c) => Some(*c),
+            _ => None,
+        })
+        .collect()
+}
+
+fn compile<R, F>(
+    pattern: &str,
+    escape: Option<char>,
+    case_insensitive: bool,
+    build: F,
+) -> Result<Matcher<R>>
+where
+    F: Fn(&str) -> std::result::Result<R, regex::Error>,
+{
+    let tokens = tokenize(pattern, escape)?;
+
+    if !case_insensitive {
+        let is_any = |token: Option<&Token>| matches!(token, Some(Token::Any));
+        let starts_with_any = is_any(tokens.first());
+        let ends_with_any = is_any(tokens.last()) && tokens.len() > starts_with_any as usize;
+        let start = starts_with_any as usize;
+        let end = tokens.len() - ends_with_any as usize;
+        if let Some(literal) = literal(&tokens[start..end]) {
+            return Ok(match (starts_with_any, ends_with_any) {
+                (false, false) => Matcher::Equal(literal),
+                (false, true) => Matcher::StartsWith(literal),
+                (true, false) => Matcher::EndsWith(literal),
+                (true, true) => Matcher::Contains(literal),
+            });
+        }
+    }
+
+    let mut re_pattern = String::with_capacity(pattern.len() + 10);
+    re_pattern.push_str(if case_insensitive { "(?is)^" } else { "(?s)^" });
+    let mut buffer = [0; 4];
+    for token in tokens {
+        match token {
+            Token::Literal(c) => re_pattern.push_str(&regex::escape(c.encode_utf8(&mut buffer))),
+            Token::Any => re_pattern.push_str(".*"),
+            Token::One => re_pattern.push('.'),
+        }
+    }
+    re_pattern.push('$');
+
+    build(&re_pattern).map(Matcher::Regex).map_err(|e| {
+        ArrowError::InvalidArgumentError(format!("Unable to build regex from LIKE pattern: {}", e))
+    })
 }
 
-fn replace_pattern(pattern: &str) -> String {
-    pattern.replace('%', ".*").replace('_', ".")
+impl Matcher<Regex> {
+    fn is_match(&self, value: &str) -> bool {
+        match self {
+            Matcher::Equal(literal) => value == literal,
+            Matcher::StartsWith(literal) => value.starts_with(literal.as_str()),
+            Matcher::EndsWith(literal) => value.ends_with(literal.as_str()),
+            Matcher::Contains(literal) => value.contains(literal.as_str()),
+            Matcher::Regex(re) => re.is_match(value),
+        }
+    }
+}
+
+impl Matcher<BytesRegex> {
+    fn is_match(&self, value: &[u8]) -> bool {
+        match self {
+            Matcher::Equal(literal) => value == literal.as_bytes(),
+            Matcher::StartsWith(literal) => value.starts_with(literal.as_bytes()),
+            Matcher::EndsWith(literal) => value.ends_with(literal.as_bytes()),
+            Matcher::Contains(literal) => memchr::memmem::find(value, literal.as_bytes()).is_some(),
+            Matcher::Regex(re) => re.is_match(value),
+        }
+    }
+}
+
+/// Applies `op` to the values of `array`, the result of a [`crate::compute::substring`] kernel.
+fn apply_op<F: Fn(bool) -> bool>(array: BooleanArray, op: F) -> Bitmap {
+    let values = array.values();
+    if op(true) {
+        values.clone()
+    } else {
+        !values
+    }
 }
 
 #[inline]
 fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &Utf8Array<O>,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     if lhs.len() != rhs.len() {
@@ -41,20 +180,14 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
         Bitmap::try_from_trusted_len_iter(lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| {
             match (lhs, rhs) {
                 (Some(lhs), Some(pattern)) => {
-                    let pattern = if let Some(pattern) = map.get(pattern) {
-                        pattern
+                    let matcher = if let Some(matcher) = map.get(pattern) {
+                        matcher
                     } else {
-                        let re_pattern = replace_pattern(pattern);
-                        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
-                        map.insert(pattern, re);
+                        let matcher = compile(pattern, Some(ESCAPE), case_insensitive, Regex::new)?;
+                        map.insert(pattern, matcher);
                         map.get(pattern).unwrap()
                     };
-                    Result::Ok(op(pattern.is_match(lhs)))
+                    Result::Ok(op(matcher.is_match(lhs)))
                 }
                 _ => Ok(false),
             }
@@ -70,6 +203,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` are matched literally.
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -86,7 +220,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false, true]));
 /// ```
 pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| x)
+    a_like_utf8(lhs, rhs, false, |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`].
@@ -96,35 +230,39 @@ pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<Bo
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| !x)
+    a_like_utf8(lhs, rhs, false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation on two [`Utf8Array`], i.e. a case-insensitive
+/// (unicode) [`like_utf8`].
+pub fn ilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation on two [`Utf8Array`].
+pub fn nilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, true, |x| !x)
 }
 
 fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &str,
+    escape: Option<char>,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
 
-    let values = if !rhs.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| x == rhs))
-    } else if rhs.ends_with('%') && !rhs[..rhs.len() - 1].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if rhs.starts_with('%') && !rhs[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
-    } else {
-        let re_pattern = replace_pattern(rhs);
-        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+    let values = match compile(rhs, escape, case_insensitive, Regex::new)? {
+        Matcher::Equal(literal) => {
+            Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == literal)))
+        }
+        Matcher::StartsWith(literal) => apply_op(starts_with_utf8_scalar(lhs, &literal), op),
+        Matcher::EndsWith(literal) => apply_op(ends_with_utf8_scalar(lhs, &literal), op),
+        Matcher::Contains(literal) => apply_op(contains_utf8_scalar(lhs, &literal), op),
+        Matcher::Regex(re) => {
+            Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+        }
     };
     Ok(BooleanArray::from_data(
         DataType::Boolean,
@@ -140,10 +278,10 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` are matched literally; use [`like_utf8_scalar_with_escape`]
+/// for other escape characters.
 /// # Error
-/// Errors iff:
-/// * the arrays have a different length
-/// * any of the patterns is not valid
+/// Errors iff the pattern is not valid, e.g. when it ends with the escape character.
 /// # Example
 /// ```
 /// use arrow2::array::{Utf8Array, BooleanArray};
@@ -153,9 +291,28 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 ///
 /// let result = like_utf8_scalar(&array, &"A%").unwrap();
 /// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false]));
+///
+/// let array = Utf8Array::<i32>::from_slice(&["100%", "1000"]);
+///
+/// let result = like_utf8_scalar(&array, r"100\%").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false]));
 /// ```
 pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| x)
+    a_like_utf8_scalar(lhs, rhs, Some(ESCAPE), false, |x| x)
+}
+
+/// Returns `lhs LIKE rhs ESCAPE escape` operation.
+///
+/// Wildcards preceded by `escape` are matched literally. When `escape` is `None`,
+/// every `%` and `_` of `rhs` is a wildcard.
+/// # Error
+/// Errors iff the pattern is not valid, e.g. when it ends with the escape character.
+pub fn like_utf8_scalar_with_escape<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &str,
+    escape: Option<char>,
+) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, escape, false, |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation.
@@ -165,7 +322,58 @@ pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<Bool
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 pub fn nlike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| !x)
+    a_like_utf8_scalar(lhs, rhs, Some(ESCAPE), false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation, i.e. a case-insensitive (unicode) [`like_utf8_scalar`].
+/// # Error
+/// Errors iff the pattern is not valid, e.g. when it ends with the escape character.
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8_scalar;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["ARROW", "Straße", "parquet"]);
+///
+/// let result = ilike_utf8_scalar(&array, "%r_w").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false, false]));
+///
+/// let result = ilike_utf8_scalar(&array, "STRASSE").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[false, false, false]));
+///
+/// let result = ilike_utf8_scalar(&array, "STRAẞE").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[false, true, false]));
+/// ```
+pub fn ilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, Some(ESCAPE), true, |x| x)
+}
+
+/// Returns `lhs ILIKE rhs ESCAPE escape` operation.
+///
+/// Wildcards preceded by `escape` are matched literally. When `escape` is `None`,
+/// every `%` and `_` of `rhs` is a wildcard.
+/// # Error
+/// Errors iff the pattern is not valid, e.g. when it ends with the escape character.
+pub fn ilike_utf8_scalar_with_escape<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &str,
+    escape: Option<char>,
+) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, escape, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation.
+pub fn nilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, Some(ESCAPE), true, |x| !x)
+}
+
+fn binary_pattern(pattern: &[u8]) -> Result<&str> {
+    simdutf8::basic::from_utf8(pattern).map_err(|e| {
+        ArrowError::InvalidArgumentError(format!(
+            "Unable to convert the LIKE pattern to string: {}",
+            e
+        ))
+    })
 }
 
 #[inline]
@@ -188,21 +396,19 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
         Bitmap::try_from_trusted_len_iter(lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| {
             match (lhs, rhs) {
                 (Some(lhs), Some(pattern)) => {
-                    let pattern = if let Some(pattern) = map.get(pattern) {
-                        pattern
+                    let matcher = if let Some(matcher) = map.get(pattern) {
+                        matcher
                     } else {
-                        let re_pattern = simdutf8::basic::from_utf8(pattern).unwrap();
-                        let re_pattern = replace_pattern(re_pattern);
-                        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
-                        map.insert(pattern, re);
+                        let matcher = compile(
+                            binary_pattern(pattern)?,
+                            Some(ESCAPE),
+                            false,
+                            BytesRegex::new,
+                        )?;
+                        map.insert(pattern, matcher);
                         map.get(pattern).unwrap()
                     };
-                    Result::Ok(op(pattern.is_match(lhs)))
+                    Result::Ok(op(matcher.is_match(lhs)))
                 }
                 _ => Ok(false),
             }
@@ -218,6 +424,7 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 ///
+/// Wildcards preceded by `\` are matched literally.
 /// # Error
 /// Errors iff:
 /// * the arrays have a different length
@@ -254,32 +461,22 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
     op: F,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
-    let pattern = simdutf8::basic::from_utf8(rhs).map_err(|e| {
-        ArrowError::InvalidArgumentError(format!(
-            "Unable to convert the LIKE pattern to string: {}",
-            e
-        ))
-    })?;
-
-    let values = if !pattern.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| x == rhs))
-    } else if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        let starts_with = &rhs[..rhs.len() - 1];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.starts_with(starts_with))))
-    } else if pattern.starts_with('%') && !pattern[1..].contains(is_like_pattern) {
-        // fast path, can use ends_with
-        let ends_with = &rhs[1..];
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
-    } else {
-        let re_pattern = replace_pattern(pattern);
-        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+    let pattern = binary_pattern(rhs)?;
+
+    let values = match compile(pattern, Some(ESCAPE), false, BytesRegex::new)? {
+        Matcher::Equal(literal) => {
+            Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == literal.as_bytes())))
+        }
+        Matcher::StartsWith(literal) => {
+            apply_op(starts_with_binary_scalar(lhs, literal.as_bytes()), op)
+        }
+        Matcher::EndsWith(literal) => {
+            apply_op(ends_with_binary_scalar(lhs, literal.as_bytes()), op)
+        }
+        Matcher::Contains(literal) => apply_op(contains_binary_scalar(lhs, literal.as_bytes()), op),
+        Matcher::Regex(re) => {
+            Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+        }
     };
     Ok(BooleanArray::from_data(
         DataType::Boolean,
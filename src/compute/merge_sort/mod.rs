@@ -235,6 +235,9 @@ fn recursive_merge_sort(slices: &[&[MergeSlice]], comparator: &Comparator) -> Ve
 
 /// An iterator adapter that merge-sorts two iterators of `MergeSlice` into a single `MergeSlice`
 /// such that the resulting `MergeSlice`s are ordered according to `comparator`.
+///
+/// The resulting slices are maximal: slices are never empty and two consecutive slices
+/// are never contiguous slots of the same array.
 pub struct MergeSortSlices<'a, L, R>
 where
     L: Iterator<Item = &'a MergeSlice>,
@@ -252,6 +255,9 @@ where
     current_start: usize,
     current_len: usize,
     current_is_left: bool,
+
+    // the next slice, already computed when checking whether it extends the current one
+    pending: Option<MergeSlice>,
 }
 
 impl<'a, L, R> MergeSortSlices<'a, L, R>
@@ -270,6 +276,7 @@ where
             current_start: 0,
             current_len: 0,
             current_is_left: true,
+            pending: None,
         }
     }
 
@@ -317,18 +324,31 @@ where
     }
 }
 
-impl<'a, L, R> Iterator for MergeSortSlices<'a, L, R>
+impl<'a, L, R> MergeSortSlices<'a, L, R>
 where
     L: Iterator<Item = &'a MergeSlice>,
     R: Iterator<Item = &'a MergeSlice>,
 {
-    type Item = MergeSlice;
-
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Returns the next slice from either side, which may be empty or contiguous to the previous one.
+    fn next_slice(&mut self) -> Option<MergeSlice> {
         if !self.has_started {
             // first call of `next`
             self.next_left();
             self.next_right();
+            if let (Some((left_slice, left_index)), Some((right_slice, right_index))) =
+                (self.left, self.right)
+            {
+                let ordering =
+                    (self.comparator)(left_slice.0, left_index, right_slice.0, right_index);
+                if ordering == Ordering::Greater {
+                    self.current_is_left = false;
+                    self.current_start = right_index;
+                } else {
+                    self.current_is_left = true;
+                    self.current_start = left_index;
+                }
+            }
+            self.has_started = true;
         }
 
         match (self.left, self.right) {
@@ -369,19 +389,6 @@ where
             }
             // both sides have elements
             (Some((left_slice, mut left_index)), Some((right_slice, mut right_index))) => {
-                if !self.has_started {
-                    let ordering =
-                        (self.comparator)(left_slice.0, left_index, right_slice.0, right_index);
-                    if ordering == Ordering::Greater {
-                        self.current_is_left = false;
-                        self.current_start = right_index;
-                    } else {
-                        self.current_is_left = true;
-                        self.current_start = left_index;
-                    }
-                    self.has_started = true;
-                }
-
                 // advance left_index or right_index until the next split
                 while (left_index < left_slice.1 + left_slice.2)
                     && (right_index < right_slice.1 + right_slice.2)
@@ -447,6 +454,40 @@ where
     }
 }
 
+impl<'a, L, R> Iterator for MergeSortSlices<'a, L, R>
+where
+    L: Iterator<Item = &'a MergeSlice>,
+    R: Iterator<Item = &'a MergeSlice>,
+{
+    type Item = MergeSlice;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current = match self.pending.take() {
+            Some(slice) => slice,
+            None => self.next_slice()?,
+        };
+        // coalesce the following slices while they extend `current`
+        while let Some(next) = self.next_slice() {
+            if current.2 == 0 {
+                current = next;
+            } else if next.2 == 0 {
+                continue;
+            } else if next.0 == current.0 && next.1 == current.1 + current.2 {
+                current.2 += next.2;
+            } else {
+                self.pending = Some(next);
+                break;
+            }
+        }
+        // `current` is only empty when all slices were empty
+        if current.2 > 0 {
+            Some(current)
+        } else {
+            None
+        }
+    }
+}
+
 /// Given two iterators of slices representing two sets of sorted [`Array`]s, and a `comparator` bound to those [`Array`]s,
 /// returns a new iterator of slices denoting how to `take` slices from each of the arrays such that the resulting
 /// array is sorted according to `comparator`
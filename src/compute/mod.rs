@@ -30,6 +30,9 @@ pub mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
 pub mod cast;
+#[cfg(feature = "compute_coalesce")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_coalesce")))]
+pub mod coalesce;
 #[cfg(feature = "compute_comparison")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_comparison")))]
 pub mod comparison;
@@ -48,6 +51,9 @@ pub mod hash;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
+#[cfg(feature = "compute_interleave")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_interleave")))]
+pub mod interleave;
 #[cfg(feature = "compute_length")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_length")))]
 pub mod length;
@@ -69,6 +75,9 @@ pub mod partition;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_search_sorted")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_search_sorted")))]
+pub mod search_sorted;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
@@ -81,6 +90,9 @@ pub mod take;
 #[cfg(feature = "compute_temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal")))]
 pub mod temporal;
+#[cfg(feature = "compute_unique")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_unique")))]
+pub mod unique;
 #[cfg(feature = "compute_utf8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_utf8")))]
 pub mod utf8;
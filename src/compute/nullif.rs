@@ -1,18 +1,31 @@
-//! Contains the operator [`nullif`].
-use crate::array::PrimitiveArray;
+//! Contains the operator [`nullif`], which nulls the slots of an array that are equal to
+//! another array, and [`nullif_scalar`], which nulls those equal to a scalar.
+//! Both dispatch on the [`DataType`] to the typed kernels of this module (e.g. [`nullif_utf8`]).
+use crate::array::{BooleanArray, DictionaryArray, Offset, PrimitiveArray, Utf8Array};
 use crate::bitmap::Bitmap;
 use crate::compute::comparison::{
-    primitive_compare_values_op, primitive_compare_values_op_scalar, Simd8, Simd8PartialEq,
+    self, primitive_compare_values_op, primitive_compare_values_op_scalar, Simd8, Simd8PartialEq,
 };
 use crate::datatypes::DataType;
-use crate::scalar::PrimitiveScalar;
-use crate::scalar::Scalar;
+use crate::scalar::{BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
 use crate::{array::Array, types::NativeType};
 
+use super::take::take_boolean;
 use super::utils::combine_validities;
 
+/// Returns `validity` with every slot where `equal` is `true` unset.
+/// Slots where `equal` is null (e.g. because `rhs` is null) are kept.
+fn nullif_validity(validity: Option<&Bitmap>, equal: &BooleanArray) -> Option<Bitmap> {
+    let equal = match equal.validity() {
+        Some(equal_validity) => equal.values() & equal_validity,
+        None => equal.values().clone(),
+    };
+    combine_validities(validity, Some(&!&equal))
+}
+
 /// Returns an array whose validity is null iff `lhs == rhs` or `lhs` is null.
-/// This has the same semantics as postgres - the validity of the rhs is ignored.
+/// This has the same semantics as postgres: slots where `rhs` is null are never equal,
+/// so they keep the value of `lhs`.
 /// # Panic
 /// This function panics iff
 /// * The arguments do not have the same logical type
@@ -37,10 +50,15 @@ where
     T: NativeType + Simd8,
     T::Simd: Simd8PartialEq,
 {
-    let equal = primitive_compare_values_op(lhs.values(), rhs.values(), |lhs, rhs| lhs.neq(rhs));
-    let equal: Option<Bitmap> = equal.into();
+    let not_equal: Bitmap =
+        primitive_compare_values_op(lhs.values(), rhs.values(), |lhs, rhs| lhs.neq(rhs)).into();
+    // slots where `rhs` is null are never equal
+    let not_equal = match rhs.validity() {
+        Some(rhs_validity) => &not_equal | &!rhs_validity,
+        None => not_equal,
+    };
 
-    let validity = combine_validities(lhs.validity(), equal.as_ref());
+    let validity = combine_validities(lhs.validity(), Some(&not_equal));
 
     PrimitiveArray::<T>::from_data(lhs.data_type().clone(), lhs.values().clone(), validity)
 }
@@ -78,10 +96,100 @@ where
     PrimitiveArray::<T>::from_data(lhs.data_type().clone(), lhs.values().clone(), validity)
 }
 
+/// Returns a [`Utf8Array`] whose validity is null iff `lhs == rhs` or `lhs` is null.
+///
+/// This has the same semantics as postgres: slots where `rhs` is null are never equal,
+/// so they keep the value of `lhs`.
+/// The values and offsets of `lhs` are re-used; only its validity is changed.
+/// # Panic
+/// This function panics iff the arguments do not have the same length
+/// # Example
+/// ```rust
+/// # use arrow2::array::Utf8Array;
+/// # use arrow2::compute::nullif::nullif_utf8;
+/// let lhs = Utf8Array::<i32>::from(&[None, Some("a"), Some("b"), Some("c")]);
+/// let rhs = Utf8Array::<i32>::from(&[Some("a"), None, Some("b"), Some("d")]);
+/// let result = nullif_utf8(&lhs, &rhs);
+///
+/// let expected = Utf8Array::<i32>::from(&[None, Some("a"), None, Some("c")]);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn nullif_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Utf8Array<O> {
+    let equal = comparison::utf8::eq(lhs, rhs);
+    lhs.clone()
+        .with_validity(nullif_validity(lhs.validity(), &equal))
+}
+
+/// Returns a [`Utf8Array`] whose validity is null iff `lhs == rhs` or `lhs` is null.
+///
+/// This has the same semantics as postgres.
+/// # Example
+/// ```rust
+/// # use arrow2::array::Utf8Array;
+/// # use arrow2::compute::nullif::nullif_utf8_scalar;
+/// let lhs = Utf8Array::<i32>::from(&[None, Some("a"), Some("b")]);
+/// let result = nullif_utf8_scalar(&lhs, "a");
+///
+/// let expected = Utf8Array::<i32>::from(&[None, None, Some("b")]);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn nullif_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Utf8Array<O> {
+    let equal = comparison::utf8::eq_scalar(lhs, rhs);
+    lhs.clone()
+        .with_validity(nullif_validity(lhs.validity(), &equal))
+}
+
+/// Returns a [`BooleanArray`] whose validity is null iff `lhs == rhs` or `lhs` is null.
+///
+/// This has the same semantics as postgres: slots where `rhs` is null are never equal,
+/// so they keep the value of `lhs`.
+/// # Panic
+/// This function panics iff the arguments do not have the same length
+/// # Example
+/// ```rust
+/// # use arrow2::array::BooleanArray;
+/// # use arrow2::compute::nullif::nullif_boolean;
+/// let lhs = BooleanArray::from(&[None, Some(true), Some(true), Some(false)]);
+/// let rhs = BooleanArray::from(&[Some(true), None, Some(true), Some(true)]);
+/// let result = nullif_boolean(&lhs, &rhs);
+///
+/// let expected = BooleanArray::from(&[None, Some(true), None, Some(false)]);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn nullif_boolean(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray {
+    let equal = comparison::boolean::eq(lhs, rhs);
+    lhs.clone()
+        .with_validity(nullif_validity(lhs.validity(), &equal))
+}
+
+/// Returns a [`BooleanArray`] whose validity is null iff `lhs == rhs` or `lhs` is null.
+///
+/// This has the same semantics as postgres.
+/// # Example
+/// ```rust
+/// # use arrow2::array::BooleanArray;
+/// # use arrow2::compute::nullif::nullif_boolean_scalar;
+/// let lhs = BooleanArray::from(&[None, Some(true), Some(false)]);
+/// let result = nullif_boolean_scalar(&lhs, false);
+///
+/// let expected = BooleanArray::from(&[None, Some(true), None]);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn nullif_boolean_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
+    let equal = comparison::boolean::eq_scalar(lhs, rhs);
+    lhs.clone()
+        .with_validity(nullif_validity(lhs.validity(), &equal))
+}
+
 /// Returns an [`Array`] with the same type as `lhs` and whose validity
 /// is null iff either `lhs == rhs` or `lhs` is null.
 ///
-/// This has the same semantics as postgres - the validity of the rhs is ignored.
+/// This has the same semantics as postgres: slots where `rhs` is null are never equal,
+/// so they keep the value of `lhs`.
 /// # Panics
 /// This function panics iff
 /// * The arguments do not have the same logical type
@@ -114,17 +222,35 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
                 rhs.as_any().downcast_ref().unwrap(),
             ))
         }),
+        Boolean => Box::new(nullif_boolean(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        )),
+        Utf8 => Box::new(nullif_utf8::<i32>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        )),
+        LargeUtf8 => Box::new(nullif_utf8::<i64>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        )),
+        _ if comparison::can_eq(lhs.data_type()) => {
+            let equal = comparison::eq(lhs, rhs);
+            lhs.with_validity(nullif_validity(lhs.validity(), &equal))
+        }
         other => unimplemented!("Nullif is not implemented for physical type {:?}", other),
     }
 }
 
 /// Returns an [`Array`] with the same type as `lhs` and whose validity
 /// is null iff either `lhs == rhs` or `lhs` is null.
+///
+/// This has the same semantics as postgres - when `rhs` is null, `lhs` is returned unchanged.
+/// A [`DataType::Dictionary`] is compared against a scalar of the type of its values.
 /// # Panics
 /// iff
-/// * Scalar is null
-/// * lhs and rhs do not have the same type
-/// * The physical type is not supported for this operation (use [`can_nullif`] to check)
+/// * lhs and rhs do not have the same type (or `rhs` is not of the values' type of a dictionary)
+/// * The physical type is not supported for this operation (use [`can_nullif_scalar`] to check)
 /// # Example
 /// ```rust
 /// # use arrow2::array::Int32Array;
@@ -142,27 +268,69 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// # }
 /// ```
 pub fn nullif_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
-    assert_eq!(lhs.data_type(), rhs.data_type());
+    // a dictionary is compared against a scalar of its values' type
+    match lhs.data_type().to_logical_type() {
+        DataType::Dictionary(_, values, _) => assert_eq!(values.as_ref(), rhs.data_type()),
+        data_type => assert_eq!(data_type, rhs.data_type()),
+    }
+    if !rhs.is_valid() {
+        return lhs.with_validity(lhs.validity().cloned());
+    }
     use crate::datatypes::PhysicalType::*;
     match lhs.data_type().to_physical_type() {
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let scalar = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
-            let scalar = scalar.value().expect("Scalar to be non-null");
 
             Box::new(primitive_nullif_scalar::<$T>(
                 lhs.as_any().downcast_ref().unwrap(),
-                scalar,
+                scalar.value().unwrap(),
             ))
         }),
+        Boolean => {
+            let scalar = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            Box::new(nullif_boolean_scalar(
+                lhs.as_any().downcast_ref().unwrap(),
+                scalar.value().unwrap(),
+            ))
+        }
+        Utf8 => {
+            let scalar = rhs.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            Box::new(nullif_utf8_scalar::<i32>(
+                lhs.as_any().downcast_ref().unwrap(),
+                scalar.value().unwrap(),
+            ))
+        }
+        LargeUtf8 => {
+            let scalar = rhs.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            Box::new(nullif_utf8_scalar::<i64>(
+                lhs.as_any().downcast_ref().unwrap(),
+                scalar.value().unwrap(),
+            ))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let equal = comparison::eq_scalar(lhs.values().as_ref(), rhs);
+            let equal = take_boolean(&equal, lhs.keys());
+
+            Box::new(lhs.clone().with_validity(nullif_validity(lhs.validity(), &equal)))
+        }),
+        _ if comparison::can_eq_scalar(lhs.data_type()) => {
+            let equal = comparison::eq_scalar(lhs, rhs);
+            lhs.with_validity(nullif_validity(lhs.validity(), &equal))
+        }
         other => unimplemented!("Nullif is not implemented for physical type {:?}", other),
     }
 }
 
-/// Returns whether [`nullif`] and [`nullif_scalar`] is implemented for the datatypes.
+/// Returns whether [`nullif`] is implemented for the datatypes.
 pub fn can_nullif(lhs: &DataType, rhs: &DataType) -> bool {
     if lhs != rhs {
         return false;
     };
-    use crate::datatypes::PhysicalType;
-    matches!(lhs.to_physical_type(), PhysicalType::Primitive(_))
+    comparison::can_eq(lhs)
+}
+
+/// Returns whether [`nullif_scalar`] is implemented for the datatype.
+pub fn can_nullif_scalar(data_type: &DataType) -> bool {
+    comparison::can_eq_scalar(data_type)
 }
@@ -18,7 +18,9 @@
 //! Defines partition kernel for [`crate::array::Array`]
 
 use crate::array::ord::DynComparator;
-use crate::compute::sort::{build_compare, SortColumn};
+use crate::array::{Array, DictionaryArray};
+use crate::compute::sort::{build_compare, SortColumn, SortOptions};
+use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 use std::cmp::Ordering;
 use std::iter::Iterator;
@@ -32,13 +34,63 @@ use std::ops::Range;
 ///
 /// The returned vec would be of size k where k is cardinality of the sorted values; Consecutive
 /// values will be connected: (a, b) and (b, c), where start = 0 and end = n for the first and last
-/// range.
+/// range. Consecutive nulls are equal and thus belong to the same range. An array without rows
+/// yields no ranges.
+///
+/// Columns of ordered dictionaries (i.e. whose [`DataType::Dictionary`] is sorted) are
+/// compared by their keys, which is equivalent to, and cheaper than, comparing their values.
+/// # Errors
+/// This function errors iff `columns` is empty, the columns have different lengths
+/// or a column's [`DataType`] cannot be compared.
+/// # Example
+/// ```rust
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::partition::lexicographical_partition_ranges;
+/// use arrow2::compute::sort::SortColumn;
+///
+/// let a = Int32Array::from(&[None, None, Some(1), Some(1), Some(2)]);
+/// let b = Int32Array::from_slice(&[0, 0, 0, 1, 1]);
+/// let columns = [
+///     SortColumn { values: &a, options: None },
+///     SortColumn { values: &b, options: None },
+/// ];
+/// let ranges = lexicographical_partition_ranges(&columns).unwrap();
+///
+/// assert_eq!(ranges.collect::<Vec<_>>(), vec![0..2, 2..3, 3..4, 4..5]);
+/// ```
 pub fn lexicographical_partition_ranges(
     columns: &[SortColumn],
 ) -> Result<impl Iterator<Item = Range<usize>>> {
     LexicographicalPartitionIterator::try_new(columns)
 }
 
+/// Given an already sorted array, find the ranges of equal values.
+///
+/// This is [`lexicographical_partition_ranges`] over a single column.
+/// # Errors
+/// This function errors iff the [`DataType`] of `values` cannot be compared.
+pub fn partition_ranges(
+    values: &dyn Array,
+    options: Option<SortOptions>,
+) -> Result<impl Iterator<Item = Range<usize>>> {
+    LexicographicalPartitionIterator::try_new(&[SortColumn { values, options }])
+}
+
+/// Returns an array whose order is the same as `array`'s: the keys of an ordered dictionary,
+/// `array` otherwise.
+fn ordered_values(array: &dyn Array) -> &dyn Array {
+    match array.data_type() {
+        DataType::Dictionary(key_type, _, true) => match_integer_type!(key_type, |$T| {
+            array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap()
+                .keys() as &dyn Array
+        }),
+        _ => array,
+    }
+}
+
 struct LexicographicalPartitionIterator {
     comparator: DynComparator,
     num_rows: usize,
@@ -63,7 +115,7 @@ impl LexicographicalPartitionIterator {
 
         let comparators = columns
             .iter()
-            .map(|x| build_compare(x.values, x.options.unwrap_or_default()))
+            .map(|x| build_compare(ordered_values(x.values), x.options.unwrap_or_default()))
             .collect::<Result<Vec<_>>>()?;
 
         let comparator = Box::new(move |a_idx: usize, b_idx: usize| -> Ordering {
@@ -1,4 +1,5 @@
-//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`].
+//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`],
+//! as well as [`regex_extract`] and [`regex_replace`].
 
 use std::collections::HashMap;
 
@@ -85,3 +86,82 @@ fn unary_utf8_boolean<O: Offset, F: Fn(&str) -> bool>(
     let values = Bitmap::from_trusted_len_iter(iterator);
     BooleanArray::from_data(DataType::Boolean, values, validity)
 }
+
+fn compile(regex: &str) -> Result<Regex> {
+    Regex::new(regex)
+        .map_err(|e| ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e)))
+}
+
+/// Extracts the capture group `group_index` of the first match of `regex` on each value
+/// of `values`. Group `0` is the whole match.
+///
+/// A value is null when it is null in `values`, when `regex` does not match it,
+/// or when the group does not participate in the match.
+/// # Error
+/// Errors iff `regex` is invalid or it has no capture group `group_index`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_match::regex_extract;
+///
+/// let strings = Utf8Array::<i32>::from(&[Some("key=a"), None, Some("key"), Some("key=b")]);
+///
+/// let result = regex_extract(&strings, "key=(?P<value>.)", 1).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("a"), None, None, Some("b")]));
+/// ```
+pub fn regex_extract<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &str,
+    group_index: usize,
+) -> Result<Utf8Array<O>> {
+    let regex = compile(regex)?;
+    if group_index >= regex.captures_len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The regex \"{}\" has no capture group {}",
+            regex, group_index
+        )));
+    }
+
+    let iterator = values.iter().map(|value| {
+        value
+            .and_then(|value| regex.captures(value))
+            .and_then(|captures| captures.get(group_index))
+            .map(|group| group.as_str())
+    });
+    Ok(Utf8Array::from_trusted_len_iter(iterator))
+}
+
+/// Replaces the first `max_replacements` non-overlapping matches of `regex` on each value
+/// of `values` by `replacement`. Like in [`crate::compute::utf8::replace_n`], no match is
+/// replaced when `max_replacements` is `0`; use `usize::MAX` to replace all matches.
+///
+/// `replacement` may reference capture groups, e.g. `$1` or `${name}`, as in [`Regex::replacen`].
+/// Nulls in `values` remain null.
+/// # Error
+/// Errors iff `regex` is invalid.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::regex_match::regex_replace;
+///
+/// let strings = Utf8Array::<i32>::from(&[Some("a-b-c"), None]);
+///
+/// let result = regex_replace(&strings, "-", "+", 1).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("a+b-c"), None]));
+/// ```
+pub fn regex_replace<O: Offset>(
+    values: &Utf8Array<O>,
+    regex: &str,
+    replacement: &str,
+    max_replacements: usize,
+) -> Result<Utf8Array<O>> {
+    let regex = compile(regex)?;
+    if max_replacements == 0 {
+        return Ok(values.clone());
+    }
+
+    let iterator = values
+        .iter()
+        .map(|value| value.map(|value| regex.replacen(value, max_replacements, replacement)));
+    Ok(Utf8Array::from_trusted_len_iter(iterator))
+}
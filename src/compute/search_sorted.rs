@@ -0,0 +1,175 @@
+//! Contains the operator [`search_sorted`], that finds the indices at which values
+//! must be inserted into a sorted array to keep it sorted.
+use std::cmp::Ordering;
+
+use crate::array::ord::{build_compare, total_cmp, total_cmp_f32, total_cmp_f64};
+use crate::array::{Array, PrimitiveArray};
+use crate::compute::sort::SortOptions;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// The side of a run of equal values that [`search_sorted`] returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The index of the first value not ordered before the probe
+    Left,
+    /// The index of the first value ordered after the probe
+    Right,
+}
+
+/// Returns the first index in `range` whose value is not (`Side::Left`)
+/// or is (`Side::Right`) ordered after the probe, where `cmp(i)` is the order of
+/// the value at `i` with respect to the probe.
+#[inline]
+fn search<F: Fn(usize) -> Ordering>(mut start: usize, mut end: usize, side: Side, cmp: F) -> usize {
+    while start < end {
+        let mid = start + (end - start) / 2;
+        let is_before = match side {
+            Side::Left => cmp(mid) == Ordering::Less,
+            Side::Right => cmp(mid) != Ordering::Greater,
+        };
+        if is_before {
+            start = mid + 1;
+        } else {
+            end = mid;
+        }
+    }
+    start
+}
+
+/// Searches every probe in the valid region of `sorted`, where `cmp(i, j)` compares the
+/// value of `sorted` at `i` with the probe at `j`.
+fn search_sorted_impl<F: Fn(usize, usize) -> Ordering>(
+    sorted: &dyn Array,
+    probes: &dyn Array,
+    side: Side,
+    options: &SortOptions,
+    cmp: F,
+) -> PrimitiveArray<u64> {
+    let len = sorted.len();
+    let null_count = sorted.null_count();
+    let (start, end) = if options.nulls_first {
+        (null_count, len)
+    } else {
+        (0, len - null_count)
+    };
+    // nulls are equal between themselves and ordered before or after all values
+    let null_index = match (side, options.nulls_first) {
+        (Side::Left, true) => 0,
+        (Side::Right, true) => null_count,
+        (Side::Left, false) => len - null_count,
+        (Side::Right, false) => len,
+    };
+
+    let indices = (0..probes.len())
+        .map(|j| {
+            if probes.is_null(j) {
+                null_index
+            } else if options.descending {
+                search(start, end, side, |i| cmp(i, j).reverse())
+            } else {
+                search(start, end, side, |i| cmp(i, j))
+            }
+        })
+        .map(|index| index as u64)
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::<u64>::from_data(DataType::UInt64, indices.into(), None)
+}
+
+fn search_sorted_primitive<T, F>(
+    sorted: &dyn Array,
+    probes: &dyn Array,
+    side: Side,
+    options: &SortOptions,
+    cmp: F,
+) -> PrimitiveArray<u64>
+where
+    T: NativeType,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let sorted_values = sorted
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .values();
+    let probes_values = probes
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .values();
+    search_sorted_impl(sorted, probes, side, options, |i, j| {
+        cmp(&sorted_values[i], &probes_values[j])
+    })
+}
+
+/// Returns, for every value of `probes`, the index at which it must be inserted into `sorted`
+/// for `sorted` to remain sorted, as `numpy.searchsorted`.
+///
+/// `sorted` *MUST* be sorted according to `options`, e.g. by [`sort`](crate::compute::sort::sort).
+/// Null probes are placed in the region of the nulls of `sorted`: at its start when
+/// `options.nulls_first`, and at its end otherwise. Floats are ordered by their total order,
+/// as when they are sorted.
+/// # Errors
+/// This function errors iff `sorted` and `probes` have different [`DataType`]s or
+/// their [`DataType`] cannot be compared.
+/// # Example
+/// ```rust
+/// use arrow2::array::{Int32Array, UInt64Array};
+/// use arrow2::compute::search_sorted::{search_sorted, Side};
+/// use arrow2::compute::sort::SortOptions;
+///
+/// let sorted = Int32Array::from(&[None, Some(1), Some(2), Some(2), Some(3)]);
+/// let probes = Int32Array::from(&[Some(2), Some(0), Some(4), None]);
+/// let options = SortOptions::default();
+///
+/// let left = search_sorted(&sorted, &probes, Side::Left, &options).unwrap();
+/// assert_eq!(left, UInt64Array::from_slice(&[2, 1, 5, 0]));
+///
+/// let right = search_sorted(&sorted, &probes, Side::Right, &options).unwrap();
+/// assert_eq!(right, UInt64Array::from_slice(&[4, 1, 5, 1]));
+/// ```
+pub fn search_sorted(
+    sorted: &dyn Array,
+    probes: &dyn Array,
+    side: Side,
+    options: &SortOptions,
+) -> Result<PrimitiveArray<u64>> {
+    if sorted.data_type() != probes.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "search_sorted requires arrays of the same type (\"{:?}\" != \"{:?}\")",
+            sorted.data_type(),
+            probes.data_type()
+        )));
+    }
+
+    use PrimitiveType::*;
+    Ok(match sorted.data_type().to_physical_type() {
+        PhysicalType::Primitive(primitive) => match primitive {
+            Int8 => search_sorted_primitive::<i8, _>(sorted, probes, side, options, total_cmp),
+            Int16 => search_sorted_primitive::<i16, _>(sorted, probes, side, options, total_cmp),
+            Int32 => search_sorted_primitive::<i32, _>(sorted, probes, side, options, total_cmp),
+            Int64 => search_sorted_primitive::<i64, _>(sorted, probes, side, options, total_cmp),
+            Int128 => search_sorted_primitive::<i128, _>(sorted, probes, side, options, total_cmp),
+            UInt8 => search_sorted_primitive::<u8, _>(sorted, probes, side, options, total_cmp),
+            UInt16 => search_sorted_primitive::<u16, _>(sorted, probes, side, options, total_cmp),
+            UInt32 => search_sorted_primitive::<u32, _>(sorted, probes, side, options, total_cmp),
+            UInt64 => search_sorted_primitive::<u64, _>(sorted, probes, side, options, total_cmp),
+            Float32 => {
+                search_sorted_primitive::<f32, _>(sorted, probes, side, options, total_cmp_f32)
+            }
+            Float64 => {
+                search_sorted_primitive::<f64, _>(sorted, probes, side, options, total_cmp_f64)
+            }
+            _ => {
+                let cmp = build_compare(sorted, probes)?;
+                search_sorted_impl(sorted, probes, side, options, cmp)
+            }
+        },
+        _ => {
+            let cmp = build_compare(sorted, probes)?;
+            search_sorted_impl(sorted, probes, side, options, cmp)
+        }
+    })
+}
@@ -7,7 +7,7 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::{
     array::*,
-    types::{Index, NativeType},
+    types::{i256, Index, NativeType},
 };
 
 mod binary;
@@ -15,10 +15,12 @@ mod boolean;
 mod common;
 mod lex_sort;
 mod primitive;
+mod rank;
 mod utf8;
 
 pub(crate) use lex_sort::build_compare;
 pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
+pub use rank::{rank, RankMethod};
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -61,6 +63,8 @@ pub fn sort(
         DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options, limit),
+        DataType::Decimal(_, _) => dyn_sort!(i128, values, ord::total_cmp, options, limit),
+        DataType::Decimal256(_, _) => dyn_sort!(i256, values, ord::total_cmp, options, limit),
         DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options, limit),
         _ => {
@@ -129,6 +133,12 @@ pub fn sort_to_indices<I: Index>(
         DataType::UInt16 => dyn_sort_indices!(I, u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort_indices!(I, u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort_indices!(I, u64, values, ord::total_cmp, options, limit),
+        DataType::Decimal(_, _) => {
+            dyn_sort_indices!(I, i128, values, ord::total_cmp, options, limit)
+        }
+        DataType::Decimal256(_, _) => {
+            dyn_sort_indices!(I, i256, values, ord::total_cmp, options, limit)
+        }
         DataType::Float32 => dyn_sort_indices!(I, f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort_indices!(I, f64, values, ord::total_cmp_f64, options, limit),
         DataType::Utf8 => Ok(utf8::indices_sorted_unstable_by::<I, i32>(
@@ -265,6 +275,8 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::UInt64
         | DataType::Float32
         | DataType::Float64
+        | DataType::Decimal(_, _)
+        | DataType::Decimal256(_, _)
         | DataType::Utf8
         | DataType::LargeUtf8
         | DataType::Binary
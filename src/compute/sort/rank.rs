@@ -0,0 +1,92 @@
+use std::cmp::Ordering;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::Result;
+
+use super::{build_compare, sort_to_indices, SortOptions};
+
+/// The rank that equal values receive in [`rank`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankMethod {
+    /// Equal values receive the lowest rank of their group, and the next group
+    /// skips the ranks in between (SQL's `RANK()`), e.g. `[1, 2, 2, 4]`.
+    Min,
+    /// Equal values receive the same rank, and the next group receives the next rank
+    /// (SQL's `DENSE_RANK()`), e.g. `[1, 2, 2, 3]`.
+    Dense,
+    /// Every value receives a distinct rank, and equal values are ranked by position
+    /// (SQL's `ROW_NUMBER()`), e.g. `[1, 2, 3, 4]`.
+    Ordinal,
+}
+
+/// Returns the (1-based) rank of every value of `array` when sorted according to `options`.
+///
+/// Nulls are equal between themselves and are ranked first or last according to
+/// `options.nulls_first`.
+/// # Errors
+/// Errors if the [`DataType`] is not supported.
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, UInt64Array};
+/// use arrow2::compute::sort::{rank, RankMethod, SortOptions};
+///
+/// let array = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("b"), Some("c")]);
+/// let options = SortOptions::default();
+///
+/// let ranks = rank(&array, &options, RankMethod::Min).unwrap();
+/// assert_eq!(ranks, UInt64Array::from_slice(&[3, 1, 2, 3, 5]));
+///
+/// let ranks = rank(&array, &options, RankMethod::Dense).unwrap();
+/// assert_eq!(ranks, UInt64Array::from_slice(&[3, 1, 2, 3, 4]));
+///
+/// let ranks = rank(&array, &options, RankMethod::Ordinal).unwrap();
+/// assert_eq!(ranks, UInt64Array::from_slice(&[3, 1, 2, 4, 5]));
+/// ```
+pub fn rank(
+    array: &dyn Array,
+    options: &SortOptions,
+    method: RankMethod,
+) -> Result<PrimitiveArray<u64>> {
+    let mut indices = sort_to_indices::<u64>(array, options, None)?
+        .values()
+        .iter()
+        .map(|x| *x as usize)
+        .collect::<Vec<_>>();
+    let cmp = build_compare(array, *options)?;
+
+    let len = indices.len();
+    let mut ranks = vec![0u64; len];
+    let mut start = 0;
+    let mut dense = 0;
+    while start < len {
+        // the run of values equal to the first
+        let end = start
+            + 1
+            + indices[start + 1..]
+                .iter()
+                .take_while(|i| cmp(indices[start], **i) == Ordering::Equal)
+                .count();
+        dense += 1;
+
+        let run = &mut indices[start..end];
+        match method {
+            RankMethod::Min => run.iter().for_each(|i| ranks[*i] = start as u64 + 1),
+            RankMethod::Dense => run.iter().for_each(|i| ranks[*i] = dense),
+            RankMethod::Ordinal => {
+                // the sort is unstable: order equal values by their position
+                run.sort_unstable();
+                run.iter()
+                    .enumerate()
+                    .for_each(|(offset, i)| ranks[*i] = (start + offset) as u64 + 1)
+            }
+        }
+        start = end;
+    }
+
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        ranks.into(),
+        None,
+    ))
+}
@@ -15,52 +15,56 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines kernel to extract a substring of a \[Large\]StringArray
+//! Defines kernel to extract a substring of a \[Large\]StringArray, as well as
+//! kernels to check whether values start with, end with or contain a substring.
+
+use memchr::memmem::Finder;
 
 use crate::{
     array::*,
+    bitmap::Bitmap,
+    compute::utils::{check_same_len, combine_validities},
     datatypes::DataType,
     error::{ArrowError, Result},
 };
 
-fn utf8_substring<O: Offset>(array: &Utf8Array<O>, start: O, length: &Option<O>) -> Utf8Array<O> {
-    let length = length.map(|v| v.to_usize());
-
-    let iter = array.values_iter().map(|str_val| {
-        // compute where we should start slicing this entry.
-        let start = if start >= O::zero() {
-            start.to_usize()
-        } else {
-            let start = (O::zero() - start).to_usize();
-            str_val
-                .char_indices()
-                .rev()
-                .nth(start)
-                .map(|(idx, _)| idx + 1)
-                .unwrap_or(0)
-        };
-
-        let mut iter_chars = str_val.char_indices();
-        if let Some((start_idx, _)) = iter_chars.nth(start) {
-            // length of the str
-            let len_end = str_val.len() - start_idx;
-
-            // length to slice
-            let length = length.unwrap_or(len_end);
+/// Returns the index of the start of the substring in a value of `len` items (bytes or characters)
+/// and the number of items of the substring.
+#[inline]
+fn substring_range(len: usize, start: i64, length: Option<u64>) -> (usize, usize) {
+    let start = if start >= 0 {
+        usize::try_from(start).unwrap_or(usize::MAX).min(len)
+    } else {
+        len.saturating_sub(usize::try_from(start.unsigned_abs()).unwrap_or(usize::MAX))
+    };
+    let remaining = len - start;
+    let length = length.map_or(remaining, |length| {
+        usize::try_from(length).unwrap_or(usize::MAX).min(remaining)
+    });
+    (start, length)
+}
 
-            if length == 0 {
-                return "";
-            }
-            // compute
-            let end_idx = iter_chars
-                .nth(length.saturating_sub(1))
-                .map(|(idx, _)| idx)
-                .unwrap_or(str_val.len());
+fn utf8_substring<O: Offset>(
+    array: &Utf8Array<O>,
+    start: i64,
+    length: Option<u64>,
+) -> Utf8Array<O> {
+    let iter = array.values_iter().map(|value| {
+        // indexes are in characters, which are bytes in ascii values
+        if value.is_ascii() {
+            let (start, length) = substring_range(value.len(), start, length);
+            return &value[start..start + length];
+        }
+        let (start, length) = substring_range(value.chars().count(), start, length);
 
-            &str_val[start_idx..end_idx]
+        let mut indices = value.char_indices().map(|(i, _)| i).skip(start);
+        let start = indices.next().unwrap_or(value.len());
+        let end = if length == 0 {
+            start
         } else {
-            ""
-        }
+            indices.nth(length - 1).unwrap_or(value.len())
+        };
+        &value[start..end]
     });
 
     let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
@@ -69,8 +73,8 @@ fn utf8_substring<O: Offset>(array: &Utf8Array<O>, start: O, length: &Option<O>)
 
 fn binary_substring<O: Offset>(
     array: &BinaryArray<O>,
-    start: O,
-    length: &Option<O>,
+    start: i64,
+    length: Option<u64>,
 ) -> BinaryArray<O> {
     let validity = array.validity();
     let offsets = array.offsets();
@@ -79,33 +83,17 @@ fn binary_substring<O: Offset>(
     let mut new_offsets = Vec::<O>::with_capacity(array.len() + 1);
     let mut new_values = Vec::<u8>::new(); // we have no way to estimate how much this will be.
 
-    let mut length_so_far = O::zero();
-    new_offsets.push(length_so_far);
+    new_offsets.push(O::zero());
 
     offsets.windows(2).for_each(|windows| {
-        let length_i: O = windows[1] - windows[0];
-
-        // compute where we should start slicing this entry
-        let start = windows[0]
-            + if start >= O::zero() {
-                start
-            } else {
-                length_i + start
-            };
-        let start = start.max(windows[0]).min(windows[1]);
-
-        let length: O = length
-            .unwrap_or(length_i)
-            // .max(0) is not needed as it is guaranteed
-            .min(windows[1] - start); // so we do not go beyond this entry
-        length_so_far += length;
-        new_offsets.push(length_so_far);
-
-        // we need usize for ranges
-        let start = start.to_usize();
-        let length = length.to_usize();
+        let offset = windows[0].to_usize();
+        let len = windows[1].to_usize() - offset;
 
+        let (start, length) = substring_range(len, start, length);
+        let start = offset + start;
         new_values.extend_from_slice(&values[start..start + length]);
+        // the values are a subset of the original values, so they fit in `O`
+        new_offsets.push(O::from_usize(new_values.len()).unwrap());
     });
 
     BinaryArray::<O>::from_data(
@@ -117,8 +105,30 @@ fn binary_substring<O: Offset>(
 }
 
 /// Returns an ArrayRef with a substring starting from `start` and with optional length `length` of each of the elements in `array`.
-/// `start` can be negative, in which case the start counts from the end of the string.
-/// this function errors when the passed array is not a \[Large\]String array.
+///
+/// * \[Large\]String arrays are indexed by characters (unicode scalar values) and binary arrays by bytes,
+///   so that a substring of a string never splits a character.
+/// * `start` can be negative, in which case the start counts from the end of the string,
+///   e.g. `-3` are the last three characters. Starts before the beginning of a value are clamped to it.
+/// * A `start` beyond the end of a value results in an empty value.
+/// * A `length` of `None` means until the end of each value.
+///
+/// this function errors when the passed array is not a \[Large\]String or \[Large\]Binary array.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::substring::substring;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("arrow2"), None, Some("año"), Some("a")]);
+///
+/// let result = substring(&array, -3, &None).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("ow2"), None, Some("año"), Some("a")]);
+/// assert_eq!(expected, result.as_ref());
+///
+/// let result = substring(&array, 1, &Some(2)).unwrap();
+/// let expected = Utf8Array::<i32>::from(&[Some("rr"), None, Some("ño"), Some("")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
 pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<Box<dyn Array>> {
     match array.data_type() {
         DataType::Binary => Ok(Box::new(binary_substring(
@@ -126,8 +136,8 @@ pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<
                 .as_any()
                 .downcast_ref::<BinaryArray<i32>>()
                 .expect("A binary is expected"),
-            start as i32,
-            &length.map(|e| e as i32),
+            start,
+            *length,
         ))),
         DataType::LargeBinary => Ok(Box::new(binary_substring(
             array
@@ -135,7 +145,7 @@ pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<
                 .downcast_ref::<BinaryArray<i64>>()
                 .expect("A large binary is expected"),
             start,
-            &length.map(|e| e as i64),
+            *length,
         ))),
         DataType::LargeUtf8 => Ok(Box::new(utf8_substring(
             array
@@ -143,15 +153,15 @@ pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
             start,
-            &length.map(|e| e as i64),
+            *length,
         ))),
         DataType::Utf8 => Ok(Box::new(utf8_substring(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i32>>()
                 .expect("A string is expected"),
-            start as i32,
-            &length.map(|e| e as i32),
+            start,
+            *length,
         ))),
         _ => Err(ArrowError::InvalidArgumentError(format!(
             "substring does not support type {:?}",
@@ -179,3 +189,145 @@ pub fn can_substring(data_type: &DataType) -> bool {
         DataType::LargeUtf8 | DataType::Utf8 | DataType::LargeBinary | DataType::Binary
     )
 }
+
+#[inline]
+fn contains_bytes(value: &[u8], needle: &[u8]) -> bool {
+    memchr::memmem::find(value, needle).is_some()
+}
+
+macro_rules! binary_predicate {
+    ($lhs:expr, $rhs:expr, $op:expr) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        check_same_len(lhs, rhs)?;
+        let validity = combine_validities(lhs.validity(), rhs.validity());
+
+        let op = $op;
+        let values = Bitmap::from_trusted_len_iter(
+            lhs.values_iter()
+                .zip(rhs.values_iter())
+                .map(|(lhs, rhs)| op(lhs.as_ref(), rhs.as_ref())),
+        );
+        Ok(BooleanArray::from_data(DataType::Boolean, values, validity))
+    }};
+}
+
+macro_rules! scalar_predicate {
+    ($lhs:expr, $op:expr) => {{
+        let lhs = $lhs;
+        let op = $op;
+        let values = Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.as_ref())));
+        BooleanArray::from_data(DataType::Boolean, values, lhs.validity().cloned())
+    }};
+}
+
+/// Returns whether each value of `lhs` starts with the corresponding value of `rhs`.
+/// A null on either side results in a null.
+/// # Error
+/// Errors iff the arrays have a different length.
+pub fn starts_with_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    binary_predicate!(lhs, rhs, |x: &[u8], y: &[u8]| x.starts_with(y))
+}
+
+/// Returns whether each value of `lhs` ends with the corresponding value of `rhs`.
+/// A null on either side results in a null.
+/// # Error
+/// Errors iff the arrays have a different length.
+pub fn ends_with_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    binary_predicate!(lhs, rhs, |x: &[u8], y: &[u8]| x.ends_with(y))
+}
+
+/// Returns whether each value of `lhs` contains the corresponding value of `rhs`.
+/// A null on either side results in a null.
+/// # Error
+/// Errors iff the arrays have a different length.
+pub fn contains_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    binary_predicate!(lhs, rhs, contains_bytes)
+}
+
+/// Returns whether each value of `lhs` starts with `rhs`.
+/// # Examples
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::substring::starts_with_utf8_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("Arrow"), None, Some("arrow")]);
+///
+/// let result = starts_with_utf8_scalar(&array, "Ar");
+/// assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+/// ```
+pub fn starts_with_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    let rhs = rhs.as_bytes();
+    scalar_predicate!(lhs, |x: &[u8]| x.starts_with(rhs))
+}
+
+/// Returns whether each value of `lhs` ends with `rhs`.
+pub fn ends_with_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    let rhs = rhs.as_bytes();
+    scalar_predicate!(lhs, |x: &[u8]| x.ends_with(rhs))
+}
+
+/// Returns whether each value of `lhs` contains `rhs`.
+/// # Examples
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::substring::contains_utf8_scalar;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("Arrow"), None, Some("Parquet")]);
+///
+/// let result = contains_utf8_scalar(&array, "rr");
+/// assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+/// ```
+pub fn contains_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    let finder = Finder::new(rhs.as_bytes());
+    scalar_predicate!(lhs, |x: &[u8]| finder.find(x).is_some())
+}
+
+/// Returns whether each value of `lhs` starts with the corresponding value of `rhs`.
+/// A null on either side results in a null.
+/// # Error
+/// Errors iff the arrays have a different length.
+pub fn starts_with_binary<O: Offset>(
+    lhs: &BinaryArray<O>,
+    rhs: &BinaryArray<O>,
+) -> Result<BooleanArray> {
+    binary_predicate!(lhs, rhs, |x: &[u8], y: &[u8]| x.starts_with(y))
+}
+
+/// Returns whether each value of `lhs` ends with the corresponding value of `rhs`.
+/// A null on either side results in a null.
+/// # Error
+/// Errors iff the arrays have a different length.
+pub fn ends_with_binary<O: Offset>(
+    lhs: &BinaryArray<O>,
+    rhs: &BinaryArray<O>,
+) -> Result<BooleanArray> {
+    binary_predicate!(lhs, rhs, |x: &[u8], y: &[u8]| x.ends_with(y))
+}
+
+/// Returns whether each value of `lhs` contains the corresponding value of `rhs`.
+/// A null on either side results in a null.
+/// # Error
+/// Errors iff the arrays have a different length.
+pub fn contains_binary<O: Offset>(
+    lhs: &BinaryArray<O>,
+    rhs: &BinaryArray<O>,
+) -> Result<BooleanArray> {
+    binary_predicate!(lhs, rhs, contains_bytes)
+}
+
+/// Returns whether each value of `lhs` starts with `rhs`.
+pub fn starts_with_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray {
+    scalar_predicate!(lhs, |x: &[u8]| x.starts_with(rhs))
+}
+
+/// Returns whether each value of `lhs` ends with `rhs`.
+pub fn ends_with_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray {
+    scalar_predicate!(lhs, |x: &[u8]| x.ends_with(rhs))
+}
+
+/// Returns whether each value of `lhs` contains `rhs`.
+pub fn contains_binary_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray {
+    let finder = Finder::new(rhs);
+    scalar_predicate!(lhs, |x: &[u8]| finder.find(x).is_some())
+}
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::array::{DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
 
 use super::primitive::take as take_primitive;
 use super::Index;
@@ -30,5 +30,7 @@ where
     I: Index,
 {
     let keys = take_primitive::<K, I>(values.keys(), indices);
-    DictionaryArray::<K>::from_data(keys, values.values().clone())
+    // Safety: the non-null keys are a subset of the (valid) non-null keys of `values`
+    unsafe { DictionaryArray::<K>::new_unchecked(keys, values.values().clone()) }
+        .to(values.data_type().clone())
 }
@@ -0,0 +1,22 @@
+use crate::array::{
+    growable::{Growable, GrowableFixedSizeBinary},
+    FixedSizeBinaryArray, PrimitiveArray,
+};
+
+use super::Index;
+
+/// `take` implementation for [`FixedSizeBinaryArray`]s
+pub fn take<O: Index>(
+    values: &FixedSizeBinaryArray,
+    indices: &PrimitiveArray<O>,
+) -> FixedSizeBinaryArray {
+    let use_validity = values.validity().is_some() || indices.validity().is_some();
+    let mut growable = GrowableFixedSizeBinary::new(vec![values], use_validity, indices.len());
+
+    indices.iter().for_each(|index| match index {
+        Some(index) => growable.extend(0, index.to_usize(), 1),
+        None => growable.extend_validity(1),
+    });
+
+    growable.into()
+}
@@ -27,6 +27,7 @@ use crate::{
 mod binary;
 mod boolean;
 mod dict;
+mod fixed_size_binary;
 mod generic_binary;
 mod list;
 mod primitive;
@@ -72,6 +73,10 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let values = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(binary::take::<i64, _>(values, indices)))
         }
+        FixedSizeBinary => {
+            let values = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(fixed_size_binary::take::<O>(values, indices)))
+        }
         Dictionary(key_type) => {
             match_integer_type!(key_type, |$T| {
                 let values = values.as_any().downcast_ref().unwrap();
@@ -106,7 +111,7 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
 /// ```
 pub fn can_take(data_type: &DataType) -> bool {
     matches!(
-        data_type,
+        data_type.to_logical_type(),
         DataType::Null
             | DataType::Boolean
             | DataType::Int8
@@ -128,10 +133,12 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::Float32
             | DataType::Float64
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Binary
             | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
             | DataType::Struct(_)
             | DataType::List(_)
             | DataType::LargeList(_)
@@ -80,9 +80,13 @@ fn take_values_indices_validity<T: NativeType, I: Index>(
     let values_validity = values.validity().unwrap();
 
     let values_values = values.values();
-    let values = indices
-        .iter()
-        .map(|index| match index {
+
+    // `indices.iter()` is not `TrustedLen` for `std`, so we write directly into the allocation
+    // instead of `collect`ing, which would check the capacity on every item.
+    let mut values = Vec::<T>::with_capacity(indices.len());
+    let dst = &mut values.spare_capacity_mut()[..indices.len()];
+    dst.iter_mut().zip(indices.iter()).for_each(|(dst, index)| {
+        let value = match index {
             Some(index) => {
                 let index = index.to_usize();
                 bitmap.push(values_validity.get_bit(index));
@@ -92,8 +96,13 @@ fn take_values_indices_validity<T: NativeType, I: Index>(
                 bitmap.push(false);
                 T::default()
             }
-        })
-        .collect::<Vec<_>>();
+        };
+        dst.write(value);
+    });
+    // Safety: the loop above initialized all `indices.len()` items, which is within the
+    // capacity. If it panics (out of bounds index), the length is left at 0.
+    unsafe { values.set_len(indices.len()) };
+
     (values.into(), bitmap.into())
 }
 
@@ -16,8 +16,14 @@
 // under the License.
 
 //! Defines temporal kernels for time and date related functions.
+//!
+//! Timestamps with a timezone are interpreted in that timezone; timestamps without a
+//! timezone and dates are interpreted in UTC.
 
-use chrono::{Datelike, Timelike};
+use std::fmt::Write;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Datelike, Offset, Timelike};
 
 use crate::array::*;
 use crate::datatypes::*;
@@ -25,7 +31,7 @@ use crate::error::{ArrowError, Result};
 use crate::temporal_conversions::*;
 use crate::types::NativeType;
 
-use super::arity::unary;
+use super::arity::{unary, unary_checked};
 
 // Create and implement a trait that converts chrono's `Weekday`
 // type into `u32`
@@ -49,6 +55,31 @@ trait U32IsoWeek: Datelike {
 impl U32IsoWeek for chrono::NaiveDateTime {}
 impl<T: chrono::TimeZone> U32IsoWeek for chrono::DateTime<T> {}
 
+// Create and implement a trait that computes the quarter of a `Datelike`
+trait U32Quarter: Datelike {
+    fn u32_quarter(&self) -> u32 {
+        (self.month() - 1) / 3 + 1
+    }
+}
+
+impl U32Quarter for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> U32Quarter for chrono::DateTime<T> {}
+
+// Create and implement a trait that computes the sub-second parts of a `Timelike`
+trait U32SubSecond: Timelike {
+    fn u32_millisecond(&self) -> u32 {
+        self.nanosecond() / 1_000_000
+    }
+
+    fn u32_microsecond(&self) -> u32 {
+        self.nanosecond() / 1_000
+    }
+}
+
+impl U32SubSecond for chrono::NaiveTime {}
+impl U32SubSecond for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> U32SubSecond for chrono::DateTime<T> {}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Datelike` methods on Arrays
 macro_rules! date_like {
@@ -104,11 +135,27 @@ pub fn weekday(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
 
 /// Extracts ISO week of a temporal array as [`PrimitiveArray<u32>`]
 /// Value ranges from 1 to 53 (Last week depends on the year).
+/// Note that the first days of January may belong to the last week of the previous year,
+/// and the last days of December to the first week of the next year.
 /// Use [`can_iso_week`] to check if this operation is supported for the target [`DataType`]
 pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     date_like!(u32_iso_week, array, DataType::UInt32)
 }
 
+/// Extracts the day of the year of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 1 to 366 (Last day depends on the year).
+/// Use [`can_ordinal`] to check if this operation is supported for the target [`DataType`].
+pub fn ordinal(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(ordinal, array, DataType::UInt32)
+}
+
+/// Extracts the quarters of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 1 to 4.
+/// Use [`can_quarter`] to check if this operation is supported for the target [`DataType`].
+pub fn quarter(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    date_like!(u32_quarter, array, DataType::UInt32)
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -159,12 +206,412 @@ pub fn second(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     time_like!(second, array, DataType::UInt32)
 }
 
+/// Extracts the milliseconds of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 0 to 999.
+/// Use [`can_millisecond`] to check if this operation is supported for the target [`DataType`].
+pub fn millisecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    time_like!(u32_millisecond, array, DataType::UInt32)
+}
+
+/// Extracts the microseconds of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 0 to 999_999.
+/// Use [`can_microsecond`] to check if this operation is supported for the target [`DataType`].
+pub fn microsecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
+    time_like!(u32_microsecond, array, DataType::UInt32)
+}
+
 /// Extracts the nanoseconds of a temporal array as [`PrimitiveArray<u32>`].
+/// Value ranges from 0 to 999_999_999.
 /// Use [`can_nanosecond`] to check if this operation is supported for the target [`DataType`].
 pub fn nanosecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     time_like!(nanosecond, array, DataType::UInt32)
 }
 
+/// The granularity to which [`date_trunc`] truncates temporal values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Granularity {
+    /// Truncates to the first day of the year
+    Year,
+    /// Truncates to the first day of the quarter
+    Quarter,
+    /// Truncates to the first day of the month
+    Month,
+    /// Truncates to the Monday of the (ISO) week
+    Week,
+    /// Truncates to midnight
+    Day,
+    /// Truncates to the hour
+    Hour,
+    /// Truncates to the minute
+    Minute,
+    /// Truncates to the second
+    Second,
+}
+
+impl Granularity {
+    /// The length of this granularity in seconds and its offset in seconds from the UNIX epoch,
+    /// or `None` when it has no fixed length (months, quarters and years).
+    fn fixed(self) -> Option<(i64, i64)> {
+        match self {
+            // 1970-01-05 is the first Monday after the epoch
+            Granularity::Week => Some((7 * SECONDS_IN_DAY, 4 * SECONDS_IN_DAY)),
+            Granularity::Day => Some((SECONDS_IN_DAY, 0)),
+            Granularity::Hour => Some((60 * 60, 0)),
+            Granularity::Minute => Some((60, 0)),
+            Granularity::Second => Some((1, 0)),
+            _ => None,
+        }
+    }
+}
+
+/// Truncates a [`chrono::NaiveDate`] to `granularity`. Granularities smaller than a day
+/// are a no-op. Returns `None` iff the truncated date is out of the range of dates.
+fn truncate_date(date: chrono::NaiveDate, granularity: Granularity) -> Option<chrono::NaiveDate> {
+    match granularity {
+        Granularity::Year => date.with_ordinal(1),
+        Granularity::Quarter => date.with_day(1)?.with_month((date.month() - 1) / 3 * 3 + 1),
+        Granularity::Month => date.with_day(1),
+        Granularity::Week => date.checked_sub_signed(chrono::Duration::days(
+            date.weekday().num_days_from_monday() as i64,
+        )),
+        _ => Some(date),
+    }
+}
+
+/// Truncates a [`chrono::NaiveDateTime`] to `granularity`.
+fn truncate_datetime(
+    datetime: chrono::NaiveDateTime,
+    granularity: Granularity,
+) -> Option<chrono::NaiveDateTime> {
+    let time = datetime.time();
+    let (hour, minute, second) = match granularity {
+        Granularity::Hour => (time.hour(), 0, 0),
+        Granularity::Minute => (time.hour(), time.minute(), 0),
+        Granularity::Second => (time.hour(), time.minute(), time.second()),
+        _ => (0, 0, 0),
+    };
+    truncate_date(datetime.date(), granularity)?.and_hms_opt(hour, minute, second)
+}
+
+/// Truncates timestamps in `time_unit` that are `offset` seconds ahead of UTC to a
+/// granularity of fixed length (see [`Granularity::fixed`]) using integer arithmetic.
+fn trunc_fixed(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    offset: i64,
+    (length, origin): (i64, i64),
+) -> PrimitiveArray<i64> {
+    let multiple = time_unit_multiple(time_unit);
+    let length = length * multiple;
+    let shift = (offset - origin) * multiple;
+    // `div_euclid` rounds towards negative infinity, also for timestamps before the epoch.
+    // Truncated values out of the range of `i64` are null.
+    unary_checked(
+        array,
+        |x| {
+            x.checked_add(shift)?
+                .div_euclid(length)
+                .checked_mul(length)?
+                .checked_sub(shift)
+        },
+        array.data_type().clone(),
+    )
+}
+
+/// Truncates timestamps in `time_unit` to `granularity` in the local time of `timezone`.
+/// A truncated local time that does not exist in `timezone` (e.g. it falls on a DST gap)
+/// keeps the offset of the original timestamp. Timestamps out of the range of dates are null.
+fn trunc_impl<T: chrono::TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: &T,
+    granularity: Granularity,
+) -> PrimitiveArray<i64> {
+    let multiple = time_unit_multiple(time_unit);
+    let op = |x| {
+        // the (undefined) values of null slots may also be out of range
+        let datetime = timestamp_to_naive_datetime_opt(x, time_unit)?;
+        let offset = chrono::Duration::seconds(
+            timezone
+                .offset_from_utc_datetime(&datetime)
+                .fix()
+                .local_minus_utc() as i64,
+        );
+        let local = truncate_datetime(datetime.checked_add_signed(offset)?, granularity)?;
+        let utc = match timezone.from_local_datetime(&local).earliest() {
+            Some(utc) => utc.naive_utc(),
+            None => local.checked_sub_signed(offset)?,
+        };
+        utc.and_utc().timestamp().checked_mul(multiple)
+    };
+    unary_checked(array, op, array.data_type().clone())
+}
+
+#[cfg(feature = "chrono-tz")]
+fn chrono_tz_trunc(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    granularity: Granularity,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(trunc_impl(array, time_unit, &timezone, granularity))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_trunc(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: Granularity,
+) -> Result<PrimitiveArray<i64>> {
+    Err(crate::temporal_conversions::chrono_tz_not_active(
+        timezone_str,
+    ))
+}
+
+fn trunc_timestamp(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: Option<&str>,
+    granularity: Granularity,
+) -> Result<PrimitiveArray<i64>> {
+    let offset = timezone.map(parse_offset).transpose();
+    match (offset, granularity.fixed()) {
+        (Ok(offset), Some(fixed)) => {
+            let offset = offset.map(|x| x.local_minus_utc() as i64).unwrap_or(0);
+            Ok(trunc_fixed(array, time_unit, offset, fixed))
+        }
+        (Ok(offset), None) => {
+            let offset = offset.unwrap_or_else(|| chrono::FixedOffset::east_opt(0).unwrap());
+            Ok(trunc_impl(array, time_unit, &offset, granularity))
+        }
+        (Err(_), _) => chrono_tz_trunc(array, time_unit, timezone.unwrap(), granularity),
+    }
+}
+
+/// Truncates the values of a temporal array to `granularity`, e.g. `2021-05-24T17:25:30`
+/// truncated to [`Granularity::Month`] is `2021-05-01T00:00:00`.
+///
+/// The result has the same [`DataType`] (including the timezone) as `array`.
+/// Timestamps with a timezone are truncated in the local time of that timezone; truncating
+/// [`DataType::Date32`] to a granularity smaller than a day is a no-op.
+/// Use [`can_date_trunc`] to check if this operation is supported for the target [`DataType`].
+/// # Example
+/// ```
+/// use arrow2::array::Int64Array;
+/// use arrow2::compute::temporal::{date_trunc, Granularity};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, None);
+/// // 1969-12-31T23:59:59 and 2021-05-24T17:25:30
+/// let array = Int64Array::from(&[Some(-1), None, Some(1621877130)]).to(data_type.clone());
+///
+/// let result = date_trunc(&array, Granularity::Day).unwrap();
+/// // 1969-12-31T00:00:00 and 2021-05-24T00:00:00
+/// let expected = Int64Array::from(&[Some(-86400), None, Some(1621814400)]).to(data_type);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+/// # Errors
+/// This function errors if `array` is not a date or timestamp, or its timezone cannot be parsed.
+pub fn date_trunc(array: &dyn Array, granularity: Granularity) -> Result<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            let result = match granularity.fixed() {
+                Some((length, origin)) if length > SECONDS_IN_DAY => {
+                    let length = (length / SECONDS_IN_DAY) as i32;
+                    let origin = (origin / SECONDS_IN_DAY) as i32;
+                    unary_checked(
+                        array,
+                        |x| {
+                            x.checked_sub(origin)?
+                                .div_euclid(length)
+                                .checked_mul(length)?
+                                .checked_add(origin)
+                        },
+                        array.data_type().clone(),
+                    )
+                }
+                Some(_) => array.clone(),
+                None => unary_checked(
+                    array,
+                    |x| {
+                        let days = EPOCH_DAYS_FROM_CE.checked_add(x)?;
+                        let date = chrono::NaiveDate::from_num_days_from_ce_opt(days)?;
+                        let date = truncate_date(date, granularity)?;
+                        Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+                    },
+                    array.data_type().clone(),
+                ),
+            };
+            Ok(Box::new(result))
+        }
+        DataType::Date64 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let result = trunc_timestamp(array, TimeUnit::Millisecond, None, granularity)?;
+            Ok(Box::new(result))
+        }
+        DataType::Timestamp(time_unit, timezone) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let result = trunc_timestamp(array, *time_unit, timezone.as_deref(), granularity)?;
+            Ok(Box::new(result))
+        }
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "\"date_trunc\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// Parses `format` into chrono [`Item`]s, erroring if it is not a valid
+/// [chrono format](chrono::format::strftime).
+fn parse_format(format: &str) -> Result<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.iter().any(|x| matches!(x, Item::Error)) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "\"{}\" is not a valid format",
+            format
+        )));
+    }
+    Ok(items)
+}
+
+/// Formats every value of `array` with `write`, re-using a single buffer for all values.
+fn strftime_impl<T, F>(array: &PrimitiveArray<T>, format: &str, write: F) -> Result<Utf8Array<i32>>
+where
+    T: NativeType,
+    F: Fn(T, &mut String) -> std::fmt::Result,
+{
+    let mut result = MutableUtf8Array::<i32>::with_capacity(array.len());
+    let mut buffer = String::new();
+    for value in array.iter() {
+        if let Some(value) = value {
+            buffer.clear();
+            write(*value, &mut buffer).map_err(|_| {
+                ArrowError::InvalidArgumentError(format!(
+                    "\"{}\" cannot format values of type {:?}",
+                    format,
+                    array.data_type()
+                ))
+            })?;
+            result.push(Some(&buffer));
+        } else {
+            result.push::<&str>(None);
+        }
+    }
+    Ok(result.into())
+}
+
+fn strftime_tz<T>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: &T,
+    format: &str,
+    items: &[Item],
+) -> Result<Utf8Array<i32>>
+where
+    T: chrono::TimeZone,
+    T::Offset: std::fmt::Display,
+{
+    strftime_impl(array, format, |x, buffer| {
+        let datetime = timestamp_to_datetime(x, time_unit, timezone);
+        write!(buffer, "{}", datetime.format_with_items(items.iter()))
+    })
+}
+
+#[cfg(feature = "chrono-tz")]
+fn chrono_tz_strftime(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    format: &str,
+    items: &[Item],
+) -> Result<Utf8Array<i32>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    strftime_tz(array, time_unit, &timezone, format, items)
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_strftime(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: &str,
+    _: &[Item],
+) -> Result<Utf8Array<i32>> {
+    Err(crate::temporal_conversions::chrono_tz_not_active(
+        timezone_str,
+    ))
+}
+
+/// Formats the values of a temporal array with a [chrono format](chrono::format::strftime).
+///
+/// Timestamps with a timezone are formatted in that timezone; timestamps without a timezone
+/// and dates are formatted as naive datetimes, which is compatible with parsing them back
+/// via [`cast`](crate::compute::cast::cast) with the same format.
+/// Use [`can_strftime`] to check if this operation is supported for the target [`DataType`].
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::temporal::strftime;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+/// let array = Int64Array::from(&[Some(1621877130), None]).to(data_type);
+///
+/// let result = strftime(&array, "%d/%m/%Y %H:%M %z").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("24/05/2021 18:25 +0100"), None]));
+/// ```
+/// # Errors
+/// This function errors if:
+/// * `format` is not a valid format
+/// * `format` requires a timezone (e.g. `%z`) and `array` has no timezone
+/// * `array` is not a date or timestamp, or its timezone cannot be parsed
+pub fn strftime(array: &dyn Array, format: &str) -> Result<Utf8Array<i32>> {
+    let items = parse_format(format)?;
+    let items = items.as_slice();
+
+    match array.data_type().to_logical_type() {
+        DataType::Date32 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            strftime_impl(array, format, |x, buffer| {
+                let datetime = date32_to_datetime(x);
+                write!(buffer, "{}", datetime.format_with_items(items.iter()))
+            })
+        }
+        DataType::Date64 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            strftime_impl(array, format, |x, buffer| {
+                let datetime = date64_to_datetime(x);
+                write!(buffer, "{}", datetime.format_with_items(items.iter()))
+            })
+        }
+        DataType::Timestamp(time_unit, None) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            strftime_impl(array, format, |x, buffer| {
+                let datetime = timestamp_to_naive_datetime(x, *time_unit);
+                write!(buffer, "{}", datetime.format_with_items(items.iter()))
+            })
+        }
+        DataType::Timestamp(time_unit, Some(timezone_str)) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            if let Ok(timezone) = parse_offset(timezone_str) {
+                strftime_tz(array, *time_unit, &timezone, format, items)
+            } else {
+                chrono_tz_strftime(array, *time_unit, timezone_str, format, items)
+            }
+        }
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "\"strftime\" does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
 fn date_variants<F, O>(array: &dyn Array, data_type: DataType, op: F) -> Result<PrimitiveArray<O>>
 where
     O: NativeType,
@@ -266,10 +713,9 @@ where
     O: NativeType,
     F: Fn(chrono::DateTime<chrono::FixedOffset>) -> O,
 {
-    Err(ArrowError::InvalidArgumentError(format!(
-        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
-        timezone_str
-    )))
+    Err(crate::temporal_conversions::chrono_tz_not_active(
+        timezone_str,
+    ))
 }
 
 fn extract_impl<T, A, F>(
@@ -353,6 +799,26 @@ pub fn can_iso_week(data_type: &DataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `data_type` can perform ordinal operation
+pub fn can_ordinal(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform quarter operation
+pub fn can_quarter(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform [`date_trunc`]
+pub fn can_date_trunc(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
+/// Checks if an array of type `data_type` can perform [`strftime`]
+pub fn can_strftime(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
 fn can_date(data_type: &DataType) -> bool {
     matches!(
         data_type,
@@ -384,6 +850,16 @@ pub fn can_second(data_type: &DataType) -> bool {
     can_time(data_type)
 }
 
+/// Checks if an array of type `datatype` can perform millisecond operation
+pub fn can_millisecond(data_type: &DataType) -> bool {
+    can_time(data_type)
+}
+
+/// Checks if an array of type `datatype` can perform microsecond operation
+pub fn can_microsecond(data_type: &DataType) -> bool {
+    can_time(data_type)
+}
+
 /// Checks if an array of type `datatype` can perform nanosecond operation
 pub fn can_nanosecond(data_type: &DataType) -> bool {
     can_time(data_type)
@@ -0,0 +1,258 @@
+//! Contains the operators [`unique`] and [`value_counts`].
+//!
+//! Rows are grouped by their hash (see [`hash`](crate::compute::hash::hash)) and are only
+//! compared when their hashes collide. A [`DictionaryArray`] is grouped by its keys, so
+//! that its values are only compared when two different keys have the same hash.
+use hash_hasher::HashedMap;
+use num_traits::ToPrimitive;
+use std::collections::hash_map::Entry;
+
+use crate::array::growable::make_growable;
+use crate::array::*;
+use crate::compute::hash::hash;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+
+type Equal<'a> = Box<dyn Fn(usize, usize) -> bool + 'a>;
+
+/// Returns a function that returns whether two valid slots of `array` are equal.
+fn build_equal(array: &dyn Array) -> Result<Equal<'_>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Null => Box::new(|_, _| true),
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        // NaNs are equal between themselves so that they are only reported once
+        Primitive(PrimitiveType::Float32) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f32>>()
+                .unwrap()
+                .values();
+            Box::new(move |i, j| {
+                values[i] == values[j] || (values[i].is_nan() && values[j].is_nan())
+            })
+        }
+        Primitive(PrimitiveType::Float64) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap()
+                .values();
+            Box::new(move |i, j| {
+                values[i] == values[j] || (values[i].is_nan() && values[j].is_nan())
+            })
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$T>>()
+                .unwrap()
+                .values();
+            Box::new(move |i, j| values[i] == values[j])
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            let keys = array.keys().values();
+            let values = build_equal(array.values().as_ref())?;
+            Box::new(move |i, j| {
+                let (i, j) = (keys[i].to_usize().unwrap(), keys[j].to_usize().unwrap());
+                i == j || values(i, j)
+            })
+        }),
+        // slower path: compare the slots as arrays
+        List | LargeList | FixedSizeList | Struct => {
+            Box::new(move |i, j| array.slice(i, 1).as_ref() == array.slice(j, 1).as_ref())
+        }
+        Union | Map => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "unique is not implemented for type {:?}",
+                array.data_type()
+            )))
+        }
+    })
+}
+
+/// The distinct rows of an array, in the order they are first seen.
+#[derive(Default)]
+struct Groups {
+    // the first row of each group
+    firsts: Vec<usize>,
+    // the number of rows of each group
+    counts: Vec<u64>,
+    // the next group with the same hash, if any
+    next: Vec<Option<usize>>,
+}
+
+impl Groups {
+    fn push(&mut self, row: usize) -> usize {
+        self.firsts.push(row);
+        self.counts.push(1);
+        self.next.push(None);
+        self.firsts.len() - 1
+    }
+}
+
+/// Groups the rows of `array` by equality, calling `on_row` with the group of every row.
+/// All null slots belong to a single group.
+fn group<F: FnMut(usize)>(array: &dyn Array, mut on_row: F) -> Result<Groups> {
+    let (hashes, is_valid): (Vec<u64>, Box<dyn Fn(usize) -> bool + '_>) =
+        match array.data_type().to_physical_type() {
+            PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                let keys = array.keys();
+                let values = array.values().as_ref();
+                // hash the (usually fewer) values once and re-use them for every key
+                let values_hashes = hash(values)?;
+                let values_hashes = values_hashes.values();
+                let hashes = keys
+                    .iter()
+                    .map(|key| {
+                        key.map(|key| values_hashes[key.to_usize().unwrap()])
+                            .unwrap_or_default()
+                    })
+                    .collect();
+                let is_valid = Box::new(move |row| {
+                    keys.is_valid(row) && values.is_valid(keys.value(row).to_usize().unwrap())
+                });
+                (hashes, is_valid)
+            }),
+            PhysicalType::Null => (vec![0; array.len()], Box::new(|_| false)),
+            _ => (
+                hash(array)?.values().to_vec(),
+                Box::new(move |row| array.is_valid(row)),
+            ),
+        };
+    let equal = build_equal(array)?;
+
+    let mut groups = Groups::default();
+    let mut null_group = None;
+    let mut map = HashedMap::<u64, usize>::default();
+    for (row, hash) in hashes.into_iter().enumerate() {
+        if !is_valid(row) {
+            let group = match null_group {
+                Some(group) => {
+                    groups.counts[group] += 1;
+                    group
+                }
+                None => *null_group.insert(groups.push(row)),
+            };
+            on_row(group);
+            continue;
+        }
+        let group = match map.entry(hash) {
+            Entry::Vacant(entry) => *entry.insert(groups.push(row)),
+            Entry::Occupied(entry) => {
+                // walk the groups sharing this hash until one is equal
+                let mut group = *entry.get();
+                loop {
+                    if equal(groups.firsts[group], row) {
+                        groups.counts[group] += 1;
+                        break group;
+                    }
+                    match groups.next[group] {
+                        Some(next) => group = next,
+                        None => {
+                            let new = groups.push(row);
+                            groups.next[group] = Some(new);
+                            break new;
+                        }
+                    }
+                }
+            }
+        };
+        on_row(group);
+    }
+    Ok(groups)
+}
+
+/// Returns the rows of `array` at `rows`.
+fn select(array: &dyn Array, rows: &[usize]) -> Box<dyn Array> {
+    let mut growable = make_growable(&[array], array.null_count() > 0, rows.len());
+    rows.iter().for_each(|row| growable.extend(0, *row, 1));
+    growable.as_box()
+}
+
+/// Returns the distinct values of `array`, in the order they are first seen.
+/// All nulls are reported as a single null value.
+/// # Errors
+/// This function errors iff the [`DataType`] of `array` is not supported
+/// (`Union` and `Map`).
+/// # Example
+/// ```rust
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::unique::unique;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("b"), None]);
+/// let result = unique(&array).unwrap();
+///
+/// let expected = Utf8Array::<i32>::from(&[Some("b"), None, Some("a")]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn unique(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let groups = group(array, |_| {})?;
+    Ok(select(array, &groups.firsts))
+}
+
+/// Returns the distinct values of `array`, in the order they are first seen,
+/// and the number of times each of them occurs in `array`.
+/// All nulls are reported as a single null value with the number of nulls.
+/// # Errors
+/// This function errors iff the [`DataType`] of `array` is not supported
+/// (`Union` and `Map`).
+/// # Example
+/// ```rust
+/// use arrow2::array::{Int32Array, UInt64Array};
+/// use arrow2::compute::unique::value_counts;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(2), Some(1), None, Some(1)]);
+/// let (values, counts) = value_counts(&array).unwrap();
+///
+/// assert_eq!(Int32Array::from(&[Some(1), None, Some(2)]), values.as_ref());
+/// assert_eq!(UInt64Array::from_slice(&[3, 2, 1]), counts);
+/// ```
+pub fn value_counts(array: &dyn Array) -> Result<(Box<dyn Array>, PrimitiveArray<u64>)> {
+    let groups = group(array, |_| {})?;
+    let values = select(array, &groups.firsts);
+    let counts = PrimitiveArray::<u64>::from_data(DataType::UInt64, groups.counts.into(), None);
+    Ok((values, counts))
+}
+
+/// Returns the distinct values of `array`, in the order they are first seen,
+/// and, for every row of `array`, the index of its value in them.
+pub(crate) fn dedup(array: &dyn Array) -> Result<(Box<dyn Array>, Vec<usize>)> {
+    let mut indices = Vec::with_capacity(array.len());
+    let groups = group(array, |group| indices.push(group))?;
+    Ok((select(array, &groups.firsts), indices))
+}
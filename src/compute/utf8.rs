@@ -1,11 +1,21 @@
 //! Defines common maps to a [`Utf8Array`]
 
+use std::collections::HashMap;
+
+use regex::Regex;
+
 use crate::{
-    array::{Array, Offset, Utf8Array},
+    array::{
+        Array, ListArray, MutableArray, MutableListArray, MutableUtf8Array, Offset, TryPush,
+        Utf8Array,
+    },
+    bitmap::Bitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
 };
 
+use super::utils::combine_validities;
+
 /// utf8_apply will apply `Fn(&str) -> String` to every value in Utf8Array.
 pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>) -> Utf8Array<O> {
     let iter = array.values_iter().map(f);
@@ -97,3 +107,507 @@ pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
 pub fn can_lower(data_type: &DataType) -> bool {
     matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
 }
+
+macro_rules! utf8_dyn {
+    ($array:expr, $name:expr, $op:ident $(, $arg:expr)*) => {{
+        let array = $array;
+        match array.data_type() {
+            DataType::LargeUtf8 => {
+                let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                Ok(Box::new($op(array $(, $arg)*)) as Box<dyn Array>)
+            }
+            DataType::Utf8 => {
+                let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                Ok(Box::new($op(array $(, $arg)*)) as Box<dyn Array>)
+            }
+            _ => Err(ArrowError::InvalidArgumentError(format!(
+                "{} does not support type {:?}",
+                $name,
+                array.data_type()
+            ))),
+        }
+    }};
+}
+
+/// Returns whether `c` is trimmed, i.e. whether it is in `characters` or, when `None`, whitespace.
+#[inline]
+fn is_trimmed(c: char, characters: Option<&str>) -> bool {
+    characters.map_or_else(|| c.is_whitespace(), |characters| characters.contains(c))
+}
+
+/// Returns a new [`Utf8Array`] where the leading and trailing characters of each element
+/// that are in `characters` are removed. When `characters` is `None`, whitespace is removed.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::trim_utf8;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("  a b "), None, Some("xxaxx")]);
+/// assert_eq!(
+///     trim_utf8(&array, None),
+///     Utf8Array::<i32>::from(&[Some("a b"), None, Some("xxaxx")])
+/// );
+/// assert_eq!(
+///     trim_utf8(&array, Some("x ")),
+///     Utf8Array::<i32>::from(&[Some("a b"), None, Some("a")])
+/// );
+/// ```
+pub fn trim_utf8<O: Offset>(array: &Utf8Array<O>, characters: Option<&str>) -> Utf8Array<O> {
+    let iter = array
+        .values_iter()
+        .map(|x| x.trim_matches(|c| is_trimmed(c, characters)));
+
+    let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+/// Returns a new [`Utf8Array`] where the leading characters of each element that are in
+/// `characters` are removed. When `characters` is `None`, whitespace is removed.
+pub fn ltrim_utf8<O: Offset>(array: &Utf8Array<O>, characters: Option<&str>) -> Utf8Array<O> {
+    let iter = array
+        .values_iter()
+        .map(|x| x.trim_start_matches(|c| is_trimmed(c, characters)));
+
+    let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+/// Returns a new [`Utf8Array`] where the trailing characters of each element that are in
+/// `characters` are removed. When `characters` is `None`, whitespace is removed.
+pub fn rtrim_utf8<O: Offset>(array: &Utf8Array<O>, characters: Option<&str>) -> Utf8Array<O> {
+    let iter = array
+        .values_iter()
+        .map(|x| x.trim_end_matches(|c| is_trimmed(c, characters)));
+
+    let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+/// Returns a new `Array` where the leading and trailing characters of each element that are
+/// in `characters` (or whitespace when `None`) are removed, see [`trim_utf8`].
+/// this function errors when the passed array is not a \[Large\]String array.
+pub fn trim(array: &dyn Array, characters: Option<&str>) -> Result<Box<dyn Array>> {
+    utf8_dyn!(array, "trim", trim_utf8, characters)
+}
+
+/// Returns a new `Array` where the leading characters of each element that are
+/// in `characters` (or whitespace when `None`) are removed, see [`ltrim_utf8`].
+/// this function errors when the passed array is not a \[Large\]String array.
+pub fn ltrim(array: &dyn Array, characters: Option<&str>) -> Result<Box<dyn Array>> {
+    utf8_dyn!(array, "ltrim", ltrim_utf8, characters)
+}
+
+/// Returns a new `Array` where the trailing characters of each element that are
+/// in `characters` (or whitespace when `None`) are removed, see [`rtrim_utf8`].
+/// this function errors when the passed array is not a \[Large\]String array.
+pub fn rtrim(array: &dyn Array, characters: Option<&str>) -> Result<Box<dyn Array>> {
+    utf8_dyn!(array, "rtrim", rtrim_utf8, characters)
+}
+
+/// Checks if an array of type `datatype` can perform the trim operations
+pub fn can_trim(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
+}
+
+/// Pads `value` to `length` characters by prepending (`left`) or appending `pad` cyclically.
+/// `value` is truncated to `length` characters when it is longer.
+fn pad_value(value: &str, length: usize, pad: &str, left: bool, result: &mut String) {
+    result.clear();
+    let chars = value.chars().count();
+    if chars >= length || pad.is_empty() {
+        let end = value
+            .char_indices()
+            .nth(length)
+            .map_or(value.len(), |(i, _)| i);
+        result.push_str(&value[..end]);
+        return;
+    }
+
+    let padding = pad.chars().cycle().take(length - chars);
+    if left {
+        result.extend(padding);
+        result.push_str(value);
+    } else {
+        result.push_str(value);
+        result.extend(padding);
+    }
+}
+
+fn pad_utf8<O: Offset>(array: &Utf8Array<O>, length: usize, pad: &str, left: bool) -> Utf8Array<O> {
+    let mut buffer = String::new();
+    let mut new = MutableUtf8Array::<O>::with_capacity(array.len());
+    array.values_iter().for_each(|x| {
+        pad_value(x, length, pad, left, &mut buffer);
+        new.push(Some(buffer.as_str()))
+    });
+
+    let new: Utf8Array<O> = new.into();
+    new.with_validity(array.validity().cloned())
+}
+
+/// Returns a new [`Utf8Array`] where each element is left-padded with `pad` to `length` characters.
+///
+/// Elements longer than `length` characters are truncated to `length` characters and elements
+/// are left unchanged when `pad` is empty.
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::lpad_utf8;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("hi"), None, Some("hello")]);
+/// assert_eq!(
+///     lpad_utf8(&array, 5, "xy"),
+///     Utf8Array::<i32>::from(&[Some("xyxhi"), None, Some("hello")])
+/// );
+/// assert_eq!(
+///     lpad_utf8(&array, 3, "xy"),
+///     Utf8Array::<i32>::from(&[Some("xhi"), None, Some("hel")])
+/// );
+/// ```
+pub fn lpad_utf8<O: Offset>(array: &Utf8Array<O>, length: usize, pad: &str) -> Utf8Array<O> {
+    pad_utf8(array, length, pad, true)
+}
+
+/// Returns a new [`Utf8Array`] where each element is right-padded with `pad` to `length` characters.
+///
+/// Elements longer than `length` characters are truncated to `length` characters and elements
+/// are left unchanged when `pad` is empty.
+pub fn rpad_utf8<O: Offset>(array: &Utf8Array<O>, length: usize, pad: &str) -> Utf8Array<O> {
+    pad_utf8(array, length, pad, false)
+}
+
+/// Returns a new `Array` where each element is left-padded with `pad` to `length` characters,
+/// see [`lpad_utf8`].
+/// this function errors when the passed array is not a \[Large\]String array.
+pub fn lpad(array: &dyn Array, length: usize, pad: &str) -> Result<Box<dyn Array>> {
+    utf8_dyn!(array, "lpad", lpad_utf8, length, pad)
+}
+
+/// Returns a new `Array` where each element is right-padded with `pad` to `length` characters,
+/// see [`rpad_utf8`].
+/// this function errors when the passed array is not a \[Large\]String array.
+pub fn rpad(array: &dyn Array, length: usize, pad: &str) -> Result<Box<dyn Array>> {
+    utf8_dyn!(array, "rpad", rpad_utf8, length, pad)
+}
+
+/// Checks if an array of type `datatype` can perform the pad operations
+pub fn can_pad(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
+}
+
+/// Concatenates the slots of `arrays` (followed by `suffix`) in each row, skipping
+/// null slots of `arrays` and writing empty rows where `validity` is null.
+fn concat_rows<O: Offset>(
+    arrays: &[&Utf8Array<O>],
+    suffix: &str,
+    validity: Option<Bitmap>,
+) -> Result<Utf8Array<O>> {
+    let len = arrays.first().map(|x| x.len()).ok_or_else(|| {
+        ArrowError::InvalidArgumentError("concat requires at least one array".to_string())
+    })?;
+    if arrays.iter().any(|x| x.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+
+    // the values of every slot (including the null slots, which are usually empty)
+    let capacity = arrays
+        .iter()
+        .map(|x| x.offsets()[len].to_usize() - x.offsets()[0].to_usize())
+        .sum::<usize>()
+        + suffix.len() * len;
+
+    let mut offsets = Vec::<O>::with_capacity(len + 1);
+    let mut values = Vec::<u8>::with_capacity(capacity);
+    offsets.push(O::default());
+    for i in 0..len {
+        if validity
+            .as_ref()
+            .map_or(true, |validity| validity.get_bit(i))
+        {
+            arrays
+                .iter()
+                .filter(|x| x.is_valid(i))
+                .for_each(|x| values.extend_from_slice(x.value(i).as_bytes()));
+            values.extend_from_slice(suffix.as_bytes());
+        }
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+    }
+
+    // Safety: the concatenation of utf8 values is utf8 and the offsets are monotonically increasing
+    Ok(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into(),
+            validity,
+        )
+    })
+}
+
+/// Returns a new [`Utf8Array`] where each element is the concatenation of the elements of
+/// `lhs` and `rhs` in the same slot.
+///
+/// An element is null when either of the elements is null; use [`concat_elementwise_coalesce`]
+/// to consider nulls as empty strings instead.
+/// # Errors
+/// This function errors when the arrays have different lengths or when the concatenated values
+/// do not fit in `O` offsets (use `i64` offsets for large arrays).
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::{concat_elementwise, concat_elementwise_scalar};
+///
+/// let first = Utf8Array::<i32>::from(&[Some("Jane"), Some("John"), None]);
+/// let last = Utf8Array::<i32>::from(&[Some("Doe"), None, Some("Roe")]);
+/// let first = concat_elementwise_scalar(&first, " ").unwrap();
+/// let result = concat_elementwise(&first, &last).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("Jane Doe"), None, None]));
+/// ```
+pub fn concat_elementwise<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+) -> Result<Utf8Array<O>> {
+    concat_elementwise_many(&[lhs, rhs])
+}
+
+/// Returns a new [`Utf8Array`] where each element is the concatenation of the elements of
+/// `arrays` in the same slot.
+///
+/// An element is null when any of the elements is null; use [`concat_elementwise_coalesce`]
+/// to consider nulls as empty strings instead.
+/// # Errors
+/// This function errors when `arrays` is empty, when the arrays have different lengths or when
+/// the concatenated values do not fit in `O` offsets (use `i64` offsets for large arrays).
+pub fn concat_elementwise_many<O: Offset>(arrays: &[&Utf8Array<O>]) -> Result<Utf8Array<O>> {
+    let validity = arrays.iter().fold(None, |acc, x| {
+        combine_validities(acc.as_ref(), x.validity())
+    });
+    concat_rows(arrays, "", validity)
+}
+
+/// Returns a new [`Utf8Array`] where each element is the concatenation of the elements of
+/// `arrays` in the same slot, considering nulls as empty strings (i.e. `COALESCE(x, '')`).
+/// The result has no nulls.
+/// # Errors
+/// This function errors when `arrays` is empty, when the arrays have different lengths or when
+/// the concatenated values do not fit in `O` offsets (use `i64` offsets for large arrays).
+pub fn concat_elementwise_coalesce<O: Offset>(arrays: &[&Utf8Array<O>]) -> Result<Utf8Array<O>> {
+    concat_rows(arrays, "", None)
+}
+
+/// Returns a new [`Utf8Array`] where `value` is appended to each element of `array`.
+/// Null elements remain null.
+/// # Errors
+/// This function errors when the concatenated values do not fit in `O` offsets
+/// (use `i64` offsets for large arrays).
+pub fn concat_elementwise_scalar<O: Offset>(
+    array: &Utf8Array<O>,
+    value: &str,
+) -> Result<Utf8Array<O>> {
+    concat_rows(&[array], value, array.validity().cloned())
+}
+
+/// Splits every value of `array` with `split` into a [`ListArray`] whose values share a
+/// single contiguous buffer.
+fn split_with<'a, O, F, I>(array: &'a Utf8Array<O>, split: F) -> Result<ListArray<i32>>
+where
+    O: Offset,
+    F: Fn(&'a str) -> I,
+    I: Iterator<Item = &'a str>,
+{
+    // the items are made of the bytes of the values, minus the delimiters
+    let offsets = array.offsets();
+    let bytes = (offsets[offsets.len() - 1] - offsets[0]).to_usize();
+    let values = MutableUtf8Array::<O>::with_capacities(array.len(), bytes);
+    let mut list =
+        MutableListArray::<i32, MutableUtf8Array<O>>::new_with_capacity(values, array.len());
+
+    for value in array.iter() {
+        match value {
+            Some(value) => {
+                let values = list.mut_values();
+                for item in split(value) {
+                    values.try_push(Some(item))?;
+                }
+                list.try_push_valid()?;
+            }
+            None => list.push_null(),
+        }
+    }
+    Ok(list.into())
+}
+
+/// Splits every value of `array` on `delimiter` into a [`ListArray`] of strings.
+///
+/// When `limit` is `Some(n)`, each value is split into at most `n` items, the last item
+/// containing the remainder of the value (as in [`str::splitn`]).
+///
+/// Nulls remain null. Consecutive delimiters result in empty items and the empty string
+/// results in a list with a single empty item, so that joining the items of a list with
+/// `delimiter` always returns its original value.
+/// # Errors
+/// This function errors when `delimiter` is empty.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array};
+/// use arrow2::compute::utf8::split;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a,b"), None, Some(""), Some("c,,d")]);
+/// let result = split(&array, ",", None).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected.try_extend(vec![
+///     Some(vec![Some("a"), Some("b")]),
+///     None,
+///     Some(vec![Some("")]),
+///     Some(vec![Some("c"), Some(""), Some("d")]),
+/// ]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result, expected);
+/// ```
+pub fn split<O: Offset>(
+    array: &Utf8Array<O>,
+    delimiter: &str,
+    limit: Option<usize>,
+) -> Result<ListArray<i32>> {
+    if delimiter.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "split requires a non-empty delimiter".to_string(),
+        ));
+    }
+    let limit = limit.unwrap_or(usize::MAX);
+    split_with(array, |value| value.splitn(limit, delimiter))
+}
+
+/// Splits every value of `array` on the matches of `regex` into a [`ListArray`] of strings.
+///
+/// `limit` and the handling of nulls and of empty strings are the same as in [`split`].
+/// # Errors
+/// This function errors when `regex` is not a valid regular expression.
+pub fn split_regex<O: Offset>(
+    array: &Utf8Array<O>,
+    regex: &str,
+    limit: Option<usize>,
+) -> Result<ListArray<i32>> {
+    let regex = Regex::new(regex)
+        .map_err(|e| ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
+    let limit = limit.unwrap_or(usize::MAX);
+    split_with(array, |value| regex.splitn(value, limit))
+}
+
+/// Returns a new [`Utf8Array`] by writing `op(value, &mut values)` for every value of `array`
+/// to a single values buffer, keeping the validity of `array`.
+/// # Errors
+/// This function errors when the written values do not fit in `O` offsets.
+fn map_values<O: Offset, F: FnMut(&str, &mut String)>(
+    array: &Utf8Array<O>,
+    mut op: F,
+) -> Result<Utf8Array<O>> {
+    let offsets = array.offsets();
+    let capacity = (offsets[offsets.len() - 1] - offsets[0]).to_usize();
+
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    let mut values = String::with_capacity(capacity);
+    offsets.push(O::default());
+    for value in array.values_iter() {
+        op(value, &mut values);
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+    }
+
+    // Safety: `values` is a `String` and the offsets are monotonically increasing
+    Ok(unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            array.data_type().clone(),
+            offsets.into(),
+            values.into_bytes().into(),
+            array.validity().cloned(),
+        )
+    })
+}
+
+/// Returns a new [`Utf8Array`] where the first `n` non-overlapping occurrences of `from`
+/// in each element are replaced by `to`. No occurrence is replaced when `n` is `0`.
+///
+/// The array is returned unchanged when `from` is empty.
+/// # Errors
+/// This function errors when the replaced values do not fit in `O` offsets
+/// (use `i64` offsets for large arrays).
+pub fn replace_n<O: Offset>(
+    array: &Utf8Array<O>,
+    from: &str,
+    to: &str,
+    n: usize,
+) -> Result<Utf8Array<O>> {
+    if from.is_empty() {
+        return Ok(array.clone());
+    }
+    map_values(array, |value, values| {
+        let mut last = 0;
+        for (start, _) in value.match_indices(from).take(n) {
+            values.push_str(&value[last..start]);
+            values.push_str(to);
+            last = start + from.len();
+        }
+        values.push_str(&value[last..]);
+    })
+}
+
+/// Returns a new [`Utf8Array`] where every non-overlapping occurrence of `from`
+/// in each element is replaced by `to`.
+///
+/// The array is returned unchanged when `from` is empty.
+/// # Errors
+/// This function errors when the replaced values do not fit in `O` offsets
+/// (use `i64` offsets for large arrays).
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::replace;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("banana"), None, Some("an")]);
+/// let result = replace(&array, "an", "AN").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("bANANa"), None, Some("AN")]));
+/// ```
+pub fn replace<O: Offset>(array: &Utf8Array<O>, from: &str, to: &str) -> Result<Utf8Array<O>> {
+    replace_n(array, from, to, usize::MAX)
+}
+
+/// Returns a new [`Utf8Array`] where every character of each element that is in `from_chars`
+/// is replaced by the character at the same position in `to_chars`.
+///
+/// Characters of `from_chars` without a corresponding character in `to_chars` are removed,
+/// and only the first occurrence of a character in `from_chars` is considered.
+/// # Errors
+/// This function errors when the translated values do not fit in `O` offsets
+/// (use `i64` offsets for large arrays).
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::translate;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("12345"), None]);
+/// let result = translate(&array, "143", "ax").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from(&[Some("a2x5"), None]));
+/// ```
+pub fn translate<O: Offset>(
+    array: &Utf8Array<O>,
+    from_chars: &str,
+    to_chars: &str,
+) -> Result<Utf8Array<O>> {
+    let mut to_chars = to_chars.chars();
+    let mut map = HashMap::new();
+    for c in from_chars.chars() {
+        let to = to_chars.next();
+        map.entry(c).or_insert(to);
+    }
+
+    map_values(array, |value, values| {
+        values.extend(
+            value
+                .chars()
+                .filter_map(|c| *map.get(&c).unwrap_or(&Some(c))),
+        )
+    })
+}
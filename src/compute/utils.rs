@@ -1,15 +1,19 @@
 use crate::{
-    array::Array,
-    bitmap::Bitmap,
+    array::{Array, BooleanArray},
+    bitmap::{and, Bitmap},
     error::{ArrowError, Result},
 };
 
+/// The maximum precision of a [`DataType::Decimal`](crate::datatypes::DataType::Decimal)
+/// backed by an `i128`.
+pub const MAX_PRECISION: usize = 38;
+
 pub fn combine_validities(lhs: Option<&Bitmap>, rhs: Option<&Bitmap>) -> Option<Bitmap> {
     match (lhs, rhs) {
         (Some(lhs), None) => Some(lhs.clone()),
         (None, Some(rhs)) => Some(rhs.clone()),
         (None, None) => None,
-        (Some(lhs), Some(rhs)) => Some(lhs & rhs),
+        (Some(lhs), Some(rhs)) => Some(and(lhs, rhs)),
     }
 }
 
@@ -23,3 +27,35 @@ pub fn check_same_len(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
     }
     Ok(())
 }
+
+/// Returns an iterator over the 64-slot words of the values and of the validity of `array`.
+/// The bits past the length of `array` are unset in the validity.
+pub fn boolean_words(array: &BooleanArray) -> impl Iterator<Item = (u64, u64)> + '_ {
+    let values = array.values().chunks::<u64>();
+    let remainder_mask = (1u64 << values.remainder_len()) - 1;
+    let values_remainder = values.remainder();
+
+    let (validity, validity_remainder) = match array.validity() {
+        Some(validity) => {
+            let validity = validity.chunks::<u64>();
+            let remainder = validity.remainder();
+            (Some(validity), remainder)
+        }
+        None => (None, u64::MAX),
+    };
+    let validity = validity
+        .into_iter()
+        .flatten()
+        .chain(std::iter::repeat(u64::MAX));
+
+    values.zip(validity).chain(std::iter::once((
+        values_remainder,
+        validity_remainder & remainder_mask,
+    )))
+}
+
+/// Returns whether `op(values, validity)` has a set bit for any 64-slot word of `array`,
+/// stopping at the first word that has one.
+pub fn any_word<F: Fn(u64, u64) -> u64>(array: &BooleanArray, op: F) -> bool {
+    boolean_words(array).any(|(values, validity)| op(values, validity) != 0)
+}
@@ -17,17 +17,25 @@
 
 //! Defines windowing functions, like `shift`ing
 
-use crate::compute::concatenate::concatenate;
-use num_traits::{abs, clamp};
-
 use crate::{
-    array::{new_null_array, Array},
+    array::{
+        growable::{make_growable, Growable},
+        new_null_array, Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, NullArray, Offset,
+        PrimitiveArray, Utf8Array,
+    },
+    datatypes::PhysicalType,
     error::{ArrowError, Result},
+    scalar::{
+        BinaryScalar, BooleanScalar, FixedSizeBinaryScalar, PrimitiveScalar, Scalar, Utf8Scalar,
+    },
 };
 
-/// Shifts array by defined number of items (to left or right)
-/// A positive value for `offset` shifts the array to the right
-/// a negative value shifts the array to the left.
+/// Shifts array by defined number of items (to left or right), filling the vacated slots
+/// with nulls. A positive value for `periods` shifts the array to the right (i.e. a lag),
+/// a negative value shifts the array to the left (i.e. a lead).
+///
+/// Shifting by more than the length of the array results in an array of nulls with
+/// the same length and [`DataType`](crate::datatypes::DataType) as `array`.
 /// # Examples
 /// ```
 /// use arrow2::array::Int32Array;
@@ -38,28 +46,129 @@ use crate::{
 /// let expected = Int32Array::from(&[None, Some(3), None]);
 /// assert_eq!(expected, result.as_ref());
 /// ```
-pub fn shift(array: &dyn Array, offset: i64) -> Result<Box<dyn Array>> {
-    if abs(offset) as usize > array.len() {
+/// # Panics
+/// This function panics iff `array` is a union or a map.
+pub fn shift(array: &dyn Array, periods: i64) -> Result<Box<dyn Array>> {
+    Ok(shift_impl(array, periods, None))
+}
+
+/// Shifts array by defined number of items (to left or right), filling the vacated slots
+/// with `fill`. See [`shift`] for details.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::shift_with;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let result = shift_with(&array, 2, &PrimitiveScalar::from(Some(0i32))).unwrap();
+/// let expected = Int32Array::from(&[Some(0), Some(0), Some(1)]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff the [`DataType`](crate::datatypes::DataType) of `fill` differs
+/// from the one of `array`, or a non-null `fill` is not a boolean, primitive, utf8, binary
+/// or fixed size binary scalar.
+/// # Panics
+/// This function panics iff `array` is a union or a map.
+pub fn shift_with(array: &dyn Array, periods: i64, fill: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if fill.data_type() != array.data_type() {
         return Err(ArrowError::InvalidArgumentError(format!(
-            "Shift's absolute offset must be smaller or equal to the arrays length. Offset is {}, length is {}",
-            abs(offset), array.len()
+            "The fill value of shift must be of the array's type {:?} (got {:?})",
+            array.data_type(),
+            fill.data_type()
         )));
     }
+    if !fill.is_valid() {
+        return shift(array, periods);
+    }
+    let fill = scalar_to_array(fill)?;
+    Ok(shift_impl(array, periods, Some(fill.as_ref())))
+}
 
-    // Compute slice
-    let slice_offset = clamp(-offset, 0, array.len() as i64) as usize;
-    let length = array.len() - abs(offset) as usize;
-    let slice = array.slice(slice_offset, length);
-
-    // Generate array with remaining `null` items
-    let nulls = abs(offset as i64) as usize;
+fn shift_impl(array: &dyn Array, periods: i64, fill: Option<&dyn Array>) -> Box<dyn Array> {
+    let len = array.len();
+    if periods == 0 {
+        return array.slice(0, len);
+    }
+    let vacated = (periods.unsigned_abs() as usize).min(len);
+    if vacated == len && fill.is_none() {
+        return new_null_array(array.data_type().clone(), len);
+    }
 
-    let null_array = new_null_array(array.data_type().clone(), nulls);
+    let arrays = match fill {
+        Some(fill) => vec![array, fill],
+        None => vec![array],
+    };
+    let mut growable = make_growable(&arrays, true, len);
+    let extend_vacated = |growable: &mut dyn Growable| {
+        if fill.is_some() {
+            (0..vacated).for_each(|_| growable.extend(1, 0, 1));
+        } else {
+            growable.extend_validity(vacated);
+        }
+    };
 
-    // Concatenate both arrays, add nulls after if shift > 0 else before
-    if offset > 0 {
-        concatenate(&[null_array.as_ref(), slice.as_ref()])
+    if periods > 0 {
+        extend_vacated(growable.as_mut());
+        growable.extend(0, 0, len - vacated);
     } else {
-        concatenate(&[slice.as_ref(), null_array.as_ref()])
+        growable.extend(0, vacated, len - vacated);
+        extend_vacated(growable.as_mut());
+    }
+    growable.as_box()
+}
+
+fn utf8_scalar_to_array<O: Offset>(scalar: &dyn Scalar) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<O>>().unwrap();
+    Box::new(Utf8Array::<O>::from([scalar.value()]))
+}
+
+fn binary_scalar_to_array<O: Offset>(scalar: &dyn Scalar) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<BinaryScalar<O>>().unwrap();
+    Box::new(BinaryArray::<O>::from([scalar.value()]))
+}
+
+/// Returns an [`Array`] of length 1 whose only slot is `scalar`.
+fn scalar_to_array(scalar: &dyn Scalar) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    if !scalar.is_valid() {
+        return Ok(new_null_array(scalar.data_type().clone(), 1));
     }
+    Ok(match scalar.data_type().to_physical_type() {
+        Null => Box::new(NullArray::from_data(scalar.data_type().clone(), 1)),
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            Box::new(BooleanArray::from(&[scalar.value()]))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<PrimitiveScalar<$T>>()
+                .unwrap();
+            Box::new(PrimitiveArray::<$T>::from(&[scalar.value()]).to(scalar.data_type().clone()))
+        }),
+        Utf8 => utf8_scalar_to_array::<i32>(scalar),
+        LargeUtf8 => utf8_scalar_to_array::<i64>(scalar),
+        Binary => binary_scalar_to_array::<i32>(scalar),
+        LargeBinary => binary_scalar_to_array::<i64>(scalar),
+        FixedSizeBinary => {
+            let scalar = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap();
+            let values = scalar.value().unwrap().to_vec();
+            Box::new(FixedSizeBinaryArray::from_data(
+                scalar.data_type().clone(),
+                values.into(),
+                None,
+            ))
+        }
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "shift with a fill value of type {:?}",
+                scalar.data_type()
+            )))
+        }
+    })
 }
@@ -315,25 +315,30 @@ unsafe fn to_data_type(schema: &ArrowSchema) -> Result<DataType> {
                         "Decimal must contain 2 or 3 comma-separated values".to_string(),
                     ));
                 };
-                if parts.len() == 3 {
-                    let bit_width = parts[0].parse::<usize>().map_err(|_| {
+                let bit_width = if parts.len() == 3 {
+                    parts[2].parse::<usize>().map_err(|_| {
                         ArrowError::OutOfSpec(
                             "Decimal bit width is not a valid integer".to_string(),
                         )
-                    })?;
-                    if bit_width != 128 {
-                        return Err(ArrowError::OutOfSpec(
-                            "Decimal256 is not supported".to_string(),
-                        ));
-                    }
-                }
+                    })?
+                } else {
+                    128
+                };
                 let precision = parts[0].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("Decimal precision is not a valid integer".to_string())
                 })?;
                 let scale = parts[1].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("Decimal scale is not a valid integer".to_string())
                 })?;
-                DataType::Decimal(precision, scale)
+                match bit_width {
+                    128 => DataType::Decimal(precision, scale),
+                    256 => DataType::Decimal256(precision, scale),
+                    _ => {
+                        return Err(ArrowError::OutOfSpec(
+                            "Decimal bit width must be 128 or 256".to_string(),
+                        ))
+                    }
+                }
             } else if !parts.is_empty() && ((parts[0] == "+us") || (parts[0] == "+ud")) {
                 // union
                 let mode = UnionMode::sparse(parts[0] == "+us");
@@ -416,6 +421,7 @@ fn to_format(data_type: &DataType) -> String {
             )
         }
         DataType::Decimal(precision, scale) => format!("d:{},{}", precision, scale),
+        DataType::Decimal256(precision, scale) => format!("d:{},{},256", precision, scale),
         DataType::List(_) => "+l".to_string(),
         DataType::LargeList(_) => "+L".to_string(),
         DataType::Struct(_) => "+s".to_string(),
@@ -117,6 +117,13 @@ impl<O: Offset> MutableArray for DynMutableListArray<O> {
     fn shrink_to_fit(&mut self) {
         todo!();
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -192,6 +199,10 @@ impl MutableArray for FixedItemsUtf8Dictionary {
     fn shrink_to_fit(&mut self) {
         todo!();
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.keys.reserve(additional)
+    }
 }
 
 /// Auxiliary struct
@@ -283,4 +294,11 @@ impl MutableArray for DynMutableStructArray {
     fn shrink_to_fit(&mut self) {
         todo!();
     }
+
+    fn reserve(&mut self, additional: usize) {
+        self.values.iter_mut().for_each(|x| x.reserve(additional));
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
 }
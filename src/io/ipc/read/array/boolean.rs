@@ -1,5 +1,4 @@
 use std::collections::VecDeque;
-use std::io::{Read, Seek};
 
 use crate::array::BooleanArray;
 use crate::datatypes::DataType;
@@ -8,7 +7,7 @@ use crate::error::{ArrowError, Result};
 use super::super::read_basic::*;
 use super::super::{Compression, IpcBuffer, Node};
 
-pub fn read_boolean<R: Read + Seek>(
+pub fn read_boolean<R: BufferReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -42,7 +41,7 @@ pub fn read_boolean<R: Read + Seek>(
         is_little_endian,
         compression,
     )?;
-    Ok(BooleanArray::from_data(data_type, values, validity))
+    BooleanArray::try_new(data_type, values, validity)
 }
 
 pub fn skip_boolean(
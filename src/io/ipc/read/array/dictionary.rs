@@ -1,16 +1,16 @@
 use std::collections::{HashSet, VecDeque};
 use std::convert::TryInto;
-use std::io::{Read, Seek};
 
 use crate::array::{DictionaryArray, DictionaryKey};
 use crate::error::{ArrowError, Result};
 
+use super::super::read_basic::BufferReader;
 use super::super::Dictionaries;
 use super::super::{Compression, IpcBuffer, Node};
 use super::{read_primitive, skip_primitive};
 
 #[allow(clippy::too_many_arguments)]
-pub fn read_dictionary<T: DictionaryKey, R: Read + Seek>(
+pub fn read_dictionary<T: DictionaryKey, R: BufferReader>(
     field_nodes: &mut VecDeque<Node>,
     id: Option<i64>,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -49,7 +49,7 @@ where
         compression,
     )?;
 
-    Ok(DictionaryArray::<T>::from_data(keys, values))
+    DictionaryArray::<T>::try_new(keys, values)
 }
 
 pub fn skip_dictionary(
@@ -1,5 +1,4 @@
 use std::collections::VecDeque;
-use std::io::{Read, Seek};
 
 use crate::array::FixedSizeBinaryArray;
 use crate::datatypes::DataType;
@@ -8,7 +7,7 @@ use crate::error::{ArrowError, Result};
 use super::super::read_basic::*;
 use super::super::{Compression, IpcBuffer, Node};
 
-pub fn read_fixed_size_binary<R: Read + Seek>(
+pub fn read_fixed_size_binary<R: BufferReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -43,7 +42,7 @@ pub fn read_fixed_size_binary<R: Read + Seek>(
         compression,
     )?;
 
-    Ok(FixedSizeBinaryArray::from_data(data_type, values, validity))
+    FixedSizeBinaryArray::try_new(data_type, values, validity)
 }
 
 pub fn skip_fixed_size_binary(
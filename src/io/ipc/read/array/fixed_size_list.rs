@@ -1,5 +1,4 @@
 use std::collections::VecDeque;
-use std::io::{Read, Seek};
 
 use crate::array::FixedSizeListArray;
 use crate::datatypes::DataType;
@@ -12,7 +11,7 @@ use super::super::Dictionaries;
 use super::super::{Compression, IpcBuffer, Node, Version};
 
 #[allow(clippy::too_many_arguments)]
-pub fn read_fixed_size_list<R: Read + Seek>(
+pub fn read_fixed_size_list<R: BufferReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     ipc_field: &IpcField,
@@ -54,7 +53,7 @@ pub fn read_fixed_size_list<R: Read + Seek>(
         compression,
         version,
     )?;
-    Ok(FixedSizeListArray::from_data(data_type, values, validity))
+    FixedSizeListArray::try_new(data_type, values, validity)
 }
 
 pub fn skip_fixed_size_list(
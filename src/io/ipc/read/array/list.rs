@@ -1,6 +1,5 @@
 use std::collections::VecDeque;
 use std::convert::TryInto;
-use std::io::{Read, Seek};
 
 use crate::array::{ListArray, Offset};
 use crate::buffer::Buffer;
@@ -14,7 +13,7 @@ use super::super::Dictionaries;
 use super::super::{Compression, IpcBuffer, Node, Version};
 
 #[allow(clippy::too_many_arguments)]
-pub fn read_list<O: Offset, R: Read + Seek>(
+pub fn read_list<O: Offset, R: BufferReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     ipc_field: &IpcField,
@@ -70,7 +69,7 @@ where
         compression,
         version,
     )?;
-    Ok(ListArray::from_data(data_type, offsets, values, validity))
+    ListArray::try_new(data_type, offsets, values, validity)
 }
 
 pub fn skip_list<O: Offset>(
@@ -1,5 +1,4 @@
 use std::collections::VecDeque;
-use std::io::{Read, Seek};
 
 use crate::array::MapArray;
 use crate::buffer::Buffer;
@@ -13,7 +12,7 @@ use super::super::Dictionaries;
 use super::super::{Compression, IpcBuffer, Node, Version};
 
 #[allow(clippy::too_many_arguments)]
-pub fn read_map<R: Read + Seek>(
+pub fn read_map<R: BufferReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     ipc_field: &IpcField,
@@ -66,7 +65,7 @@ pub fn read_map<R: Read + Seek>(
         compression,
         version,
     )?;
-    Ok(MapArray::from_data(data_type, offsets, field, validity))
+    MapArray::try_new(data_type, offsets, field, validity)
 }
 
 pub fn skip_map(
@@ -1,5 +1,4 @@
 use std::collections::VecDeque;
-use std::io::{Read, Seek};
 
 use crate::array::StructArray;
 use crate::datatypes::DataType;
@@ -12,7 +11,7 @@ use super::super::Dictionaries;
 use super::super::{Compression, IpcBuffer, Node, Version};
 
 #[allow(clippy::too_many_arguments)]
-pub fn read_struct<R: Read + Seek>(
+pub fn read_struct<R: BufferReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     ipc_field: &IpcField,
@@ -61,7 +60,7 @@ pub fn read_struct<R: Read + Seek>(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    Ok(StructArray::from_data(data_type, values, validity))
+    StructArray::try_new(data_type, values, validity)
 }
 
 pub fn skip_struct(
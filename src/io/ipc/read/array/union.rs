@@ -1,5 +1,4 @@
 use std::collections::VecDeque;
-use std::io::{Read, Seek};
 
 use crate::array::UnionArray;
 use crate::datatypes::DataType;
@@ -13,7 +12,7 @@ use super::super::Dictionaries;
 use super::super::{Compression, IpcBuffer, Node, Version};
 
 #[allow(clippy::too_many_arguments)]
-pub fn read_union<R: Read + Seek>(
+pub fn read_union<R: BufferReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     ipc_field: &IpcField,
@@ -85,7 +84,7 @@ pub fn read_union<R: Read + Seek>(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    Ok(UnionArray::from_data(data_type, types, fields, offsets))
+    UnionArray::try_new(data_type, types, fields, offsets)
 }
 
 pub fn skip_union(
@@ -95,19 +94,17 @@ pub fn skip_union(
 ) -> Result<()> {
     let _ = field_nodes.pop_front().ok_or_else(|| {
         ArrowError::oos(
-            "IPC: unable to fetch the field for struct. The file or stream is corrupted.",
+            "IPC: unable to fetch the field for union. The file or stream is corrupted.",
         )
     })?;
 
     let _ = buffers
         .pop_front()
-        .ok_or_else(|| ArrowError::oos("IPC: missing validity buffer."))?;
+        .ok_or_else(|| ArrowError::oos("IPC: missing types buffer."))?;
     if let DataType::Union(_, _, Dense) = data_type {
         let _ = buffers
             .pop_front()
             .ok_or_else(|| ArrowError::oos("IPC: missing offsets buffer."))?;
-    } else {
-        unreachable!()
     };
 
     let fields = UnionArray::get_fields(data_type);
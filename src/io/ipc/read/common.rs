@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use arrow_format;
 
+use crate::array::growable::make_growable;
 use crate::array::*;
 use crate::chunk::Chunk;
 use crate::datatypes::{DataType, Field};
@@ -11,6 +12,7 @@ use crate::error::{ArrowError, Result};
 use crate::io::ipc::{IpcField, IpcSchema};
 
 use super::deserialize::{read, skip};
+use super::read_basic::BufferReader;
 use super::Dictionaries;
 
 type ArrayRef = Arc<dyn Array>;
@@ -73,9 +75,10 @@ impl<'a, A, I: Iterator<Item = A>> Iterator for ProjectionIter<'a, A, I> {
     }
 }
 
-/// Creates a record batch from binary data using the `ipc::RecordBatch` indexes and the `Schema`
+/// Creates a record batch from binary data using the `ipc::RecordBatch` indexes and the `Schema`.
+/// The columns are returned in the order of `projection`; the buffers of the remaining columns are skipped.
 /// # Panic
-/// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
+/// Panics iff the projection contains repeated columns (e.g. `[0, 1, 1]` is not valid)
 #[allow(clippy::too_many_arguments)]
 pub fn read_record_batch<R: Read + Seek>(
     batch: arrow_format::ipc::RecordBatchRef,
@@ -86,6 +89,29 @@ pub fn read_record_batch<R: Read + Seek>(
     version: arrow_format::ipc::MetadataVersion,
     reader: &mut R,
     block_offset: u64,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    _read_record_batch(
+        batch,
+        fields,
+        ipc_schema,
+        projection,
+        dictionaries,
+        version,
+        reader,
+        block_offset,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn _read_record_batch<R: BufferReader>(
+    batch: arrow_format::ipc::RecordBatchRef,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    projection: Option<&[usize]>,
+    dictionaries: &Dictionaries,
+    version: arrow_format::ipc::MetadataVersion,
+    reader: &mut R,
+    block_offset: u64,
 ) -> Result<Chunk<Arc<dyn Array>>> {
     assert_eq!(fields.len(), ipc_schema.fields.len());
     let buffers = batch
@@ -99,10 +125,12 @@ pub fn read_record_batch<R: Read + Seek>(
     let mut field_nodes = field_nodes.iter().collect::<VecDeque<_>>();
 
     let columns = if let Some(projection) = projection {
-        let projection =
-            ProjectionIter::new(projection, fields.iter().zip(ipc_schema.fields.iter()));
+        // columns are stored in the order of the schema
+        let mut sorted = projection.to_vec();
+        sorted.sort_unstable();
+        let iter = ProjectionIter::new(&sorted, fields.iter().zip(ipc_schema.fields.iter()));
 
-        projection
+        let columns = iter
             .map(|maybe_field| match maybe_field {
                 ProjectionResult::Selected((field, ipc_field)) => Ok(Some(read(
                     &mut field_nodes,
@@ -122,7 +150,16 @@ pub fn read_record_batch<R: Read + Seek>(
                 }
             })
             .filter_map(|x| x.transpose())
-            .collect::<Result<Vec<_>>>()?
+            .collect::<Result<Vec<_>>>()?;
+
+        if sorted.as_slice() == projection {
+            columns
+        } else {
+            projection
+                .iter()
+                .map(|x| columns[sorted.binary_search(x).unwrap()].clone())
+                .collect()
+        }
     } else {
         fields
             .iter()
@@ -200,7 +237,9 @@ fn first_dict_field<'a>(
 }
 
 /// Read the dictionary from the buffer and provided metadata,
-/// updating the `dictionaries` with the resulting dictionary
+/// updating the `dictionaries` with the resulting dictionary.
+/// A replacement dictionary overwrites the existing one with the same id, while the values of
+/// a delta dictionary are appended to it.
 pub fn read_dictionary<R: Read + Seek>(
     batch: arrow_format::ipc::DictionaryBatchRef,
     fields: &[Field],
@@ -209,12 +248,24 @@ pub fn read_dictionary<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
 ) -> Result<()> {
-    if batch.is_delta()? {
-        return Err(ArrowError::NotYetImplemented(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
+    _read_dictionary(
+        batch,
+        fields,
+        ipc_schema,
+        dictionaries,
+        reader,
+        block_offset,
+    )
+}
 
+pub(crate) fn _read_dictionary<R: BufferReader>(
+    batch: arrow_format::ipc::DictionaryBatchRef,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut Dictionaries,
+    reader: &mut R,
+    block_offset: u64,
+) -> Result<()> {
     let id = batch.id()?;
     let (first_field, first_ipc_field) = first_dict_field(id, fields, &ipc_schema.fields)?;
 
@@ -229,7 +280,7 @@ pub fn read_dictionary<R: Read + Seek>(
                 fields: vec![first_ipc_field.clone()],
                 is_little_endian: ipc_schema.is_little_endian,
             };
-            let columns = read_record_batch(
+            let columns = _read_record_batch(
                 batch
                     .data()?
                     .ok_or_else(|| ArrowError::oos("The dictionary batch must have data."))?,
@@ -250,6 +301,22 @@ pub fn read_dictionary<R: Read + Seek>(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    let dictionary_values = if batch.is_delta()? {
+        let existing = dictionaries.get(&id).ok_or_else(|| {
+            ArrowError::oos(format!(
+                "A delta dictionary batch requires a previous dictionary with id {}",
+                id
+            ))
+        })?;
+        let arrays = [existing.as_ref(), dictionary_values.as_ref()];
+        let mut growable = make_growable(&arrays, false, existing.len() + dictionary_values.len());
+        growable.extend(0, 0, existing.len());
+        growable.extend(1, 0, dictionary_values.len());
+        growable.as_arc()
+    } else {
+        dictionary_values
+    };
+
     dictionaries.insert(id, dictionary_values);
 
     Ok(())
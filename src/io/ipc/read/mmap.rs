@@ -0,0 +1,203 @@
+use std::convert::TryInto;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow_format::ipc::planus::ReadAsRoot;
+
+use crate::array::Array;
+use crate::bitmap::Bitmap;
+use crate::buffer::Buffer;
+use crate::chunk::Chunk;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::super::endianess::is_native_little_endian;
+use super::super::CONTINUATION_MARKER;
+use super::common::{_read_dictionary, _read_record_batch};
+use super::read_basic::BufferReader;
+use super::reader::get_serialized_batch;
+use super::{Compression, Dictionaries, FileMetadata};
+
+/// A [`BufferReader`] whose buffers share the bytes of `owner`.
+struct Mapped<'a, O> {
+    data: &'a [u8],
+    owner: &'a Arc<O>,
+}
+
+impl<'a, O: AsRef<[u8]> + Send + Sync + 'static> BufferReader for Mapped<'a, O> {
+    fn read_buffer_at<T: NativeType>(
+        &mut self,
+        offset: u64,
+        buffer_length: usize,
+        length: usize,
+        is_little_endian: bool,
+        compression: Option<Compression>,
+    ) -> Result<Buffer<T>> {
+        let fits = matches!(
+            length.checked_mul(std::mem::size_of::<T>()),
+            Some(bytes) if bytes <= buffer_length
+        );
+        if compression.is_none() && is_little_endian == is_native_little_endian() && fits {
+            let buffer = offset.try_into().ok().and_then(|offset| {
+                Buffer::from_owner_region(self.owner.clone(), offset, length).ok()
+            });
+            if let Some(buffer) = buffer {
+                return Ok(buffer);
+            }
+        }
+        // compressed, byte-swapped and misaligned buffers are copied. So are invalid ones,
+        // so that they error as in any other reader.
+        Cursor::new(self.data).read_buffer_at(
+            offset,
+            buffer_length,
+            length,
+            is_little_endian,
+            compression,
+        )
+    }
+
+    fn read_bitmap_at(
+        &mut self,
+        offset: u64,
+        bytes: usize,
+        length: usize,
+        compression: Option<Compression>,
+    ) -> Result<Bitmap> {
+        if compression.is_none() {
+            let bitmap = offset.try_into().ok().and_then(|offset| {
+                Bitmap::from_owner_region(self.owner.clone(), offset, bytes, length)
+            });
+            if let Some(bitmap) = bitmap {
+                return Ok(bitmap);
+            }
+        }
+        Cursor::new(self.data).read_bitmap_at(offset, bytes, length, compression)
+    }
+}
+
+/// Returns the message at `offset` of `data`, preceded by its length and an optional
+/// continuation marker.
+fn read_message(data: &[u8], offset: u64) -> Result<arrow_format::ipc::MessageRef<'_>> {
+    let oos = || ArrowError::oos("IPC: a block is out of bounds of the file");
+    let get = |start: usize, length: usize| {
+        start
+            .checked_add(length)
+            .and_then(|end| data.get(start..end))
+            .ok_or_else(oos)
+    };
+
+    let mut start: usize = offset.try_into().map_err(|_| oos())?;
+    let mut meta_buf = get(start, 4)?;
+    start += 4;
+    if meta_buf == CONTINUATION_MARKER {
+        meta_buf = get(start, 4)?;
+        start += 4;
+    }
+    let meta_len = i32::from_le_bytes(meta_buf.try_into().unwrap());
+    let meta_len: usize = meta_len
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: the length of a message must be positive"))?;
+
+    arrow_format::ipc::MessageRef::read_as_root(get(start, meta_len)?)
+        .map_err(|err| ArrowError::oos(format!("Unable parse message: {:?}", err)))
+}
+
+fn read_dictionaries<O: AsRef<[u8]> + Send + Sync + 'static>(
+    mapped: &mut Mapped<O>,
+    metadata: &FileMetadata,
+) -> Result<Dictionaries> {
+    let mut dictionaries = Default::default();
+    let blocks = if let Some(blocks) = &metadata.dictionary_blocks {
+        blocks
+    } else {
+        return Ok(dictionaries);
+    };
+
+    for block in blocks {
+        let message = read_message(mapped.data, block.offset as u64)?;
+        let header = message
+            .header()?
+            .ok_or_else(|| ArrowError::oos("Message must have an header"))?;
+        match header {
+            arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
+                _read_dictionary(
+                    batch,
+                    &metadata.schema.fields,
+                    &metadata.ipc_schema,
+                    &mut dictionaries,
+                    mapped,
+                    block.offset as u64 + block.meta_data_length as u64,
+                )?;
+            }
+            t => {
+                return Err(ArrowError::OutOfSpec(format!(
+                    "Expecting DictionaryBatch in dictionary blocks, found {:?}.",
+                    t
+                )));
+            }
+        }
+    }
+    Ok(dictionaries)
+}
+
+fn read_batch<O: AsRef<[u8]> + Send + Sync + 'static>(
+    mapped: &mut Mapped<O>,
+    metadata: &FileMetadata,
+    dictionaries: &Dictionaries,
+    block: usize,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let block = metadata.blocks[block];
+    let message = read_message(mapped.data, block.offset as u64)?;
+    let batch = get_serialized_batch(&message)?;
+
+    _read_record_batch(
+        batch,
+        &metadata.schema.fields,
+        &metadata.ipc_schema,
+        None,
+        dictionaries,
+        message.version()?,
+        mapped,
+        block.offset as u64 + block.meta_data_length as u64,
+    )
+}
+
+/// Returns an iterator over the record batches of the Arrow IPC file whose bytes are `data`,
+/// e.g. a memory-mapped file, with its `metadata` (see [`read_file_metadata`](super::read_file_metadata)).
+///
+/// The buffers of the arrays point to the memory of `data` instead of being copied and keep
+/// it alive. The dictionaries of the file are read when this function is called.
+///
+/// # Copies
+/// A buffer is copied to a new allocation instead, without an error or a warning, when it:
+/// * is compressed (it is decompressed to the new allocation)
+/// * has an endianness different from the machine's (it is byte-swapped)
+/// * is not aligned to its type within `data`, e.g. an `i32` buffer at an odd address of a
+///   mapped file. Bitmaps and byte buffers (e.g. the values of a [`Utf8Array`](crate::array::Utf8Array))
+///   are never misaligned.
+///
+/// Whether a buffer was copied can be checked by whether its pointer
+/// (e.g. `array.values().as_ptr()`) lies within `data`.
+/// # Errors
+/// This function errors iff the dictionaries of the file are invalid. The iterator errors
+/// iff a record batch is invalid.
+pub fn read_file_mmap<T: AsRef<[u8]> + Send + Sync + 'static>(
+    data: Arc<T>,
+    metadata: FileMetadata,
+) -> Result<impl Iterator<Item = Result<Chunk<Arc<dyn Array>>>>> {
+    let dictionaries = read_dictionaries(
+        &mut Mapped {
+            data: (*data).as_ref(),
+            owner: &data,
+        },
+        &metadata,
+    )?;
+
+    Ok((0..metadata.blocks.len()).map(move |block| {
+        let mut mapped = Mapped {
+            data: (*data).as_ref(),
+            owner: &data,
+        };
+        read_batch(&mut mapped, &metadata, &dictionaries, block)
+    }))
+}
@@ -12,6 +12,7 @@ use crate::array::Array;
 mod array;
 mod common;
 mod deserialize;
+mod mmap;
 mod read_basic;
 mod reader;
 mod schema;
@@ -21,7 +22,10 @@ mod stream;
 pub mod stream_async;
 
 pub use common::{read_dictionary, read_record_batch};
-pub use reader::{read_file_metadata, FileMetadata, FileReader};
+pub use mmap::read_file_mmap;
+pub use reader::{
+    read_batch, read_file_dictionaries, read_file_metadata, FileMetadata, FileReader,
+};
 pub use schema::deserialize_schema;
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
 
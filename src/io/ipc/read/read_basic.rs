@@ -78,6 +78,37 @@ fn read_uncompressed_buffer<T: NativeType, R: Read + Seek>(
     Ok(buffer)
 }
 
+/// Decompresses an IPC buffer into `out`. Per the IPC spec, compressed buffers are prefixed by
+/// their uncompressed length as a little endian `i64`, where `-1` declares that the remaining
+/// bytes are not compressed.
+fn decompress(slice: &[u8], out: &mut [u8], compression: Compression) -> Result<()> {
+    if slice.is_empty() && out.is_empty() {
+        return Ok(());
+    }
+    if slice.len() < 8 {
+        return Err(ArrowError::oos(
+            "IPC: a compressed buffer must start with its uncompressed length",
+        ));
+    }
+    let (length, body) = slice.split_at(8);
+    let length = i64::from_le_bytes(length.try_into().unwrap());
+
+    if length == -1 {
+        if body.len() < out.len() {
+            return Err(ArrowError::oos(
+                "IPC: an uncompressed buffer is smaller than the number of bytes of its array",
+            ));
+        }
+        out.copy_from_slice(&body[..out.len()]);
+        return Ok(());
+    }
+
+    match compression.codec()? {
+        arrow_format::ipc::CompressionType::Lz4Frame => compression::decompress_lz4(body, out),
+        arrow_format::ipc::CompressionType::Zstd => compression::decompress_zstd(body, out),
+    }
+}
+
 fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     buffer_length: usize,
@@ -102,19 +133,76 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
 
     let out_slice = bytemuck::cast_slice_mut(&mut buffer);
 
-    match compression.codec()? {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&slice[8..], out_slice)?;
-            Ok(buffer)
-        }
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&slice[8..], out_slice)?;
-            Ok(buffer)
+    decompress(&slice, out_slice, compression)?;
+    Ok(buffer)
+}
+
+/// A source of the buffers of the IPC message bodies.
+///
+/// Any [`Read`] + [`Seek`] copies the buffers into new allocations; memory-mapped
+/// sources may instead share their memory with the arrays they back.
+pub(crate) trait BufferReader {
+    /// Reads a buffer of `length` slots of `T` from the `buffer_length` bytes at `offset`.
+    fn read_buffer_at<T: NativeType>(
+        &mut self,
+        offset: u64,
+        buffer_length: usize,
+        length: usize,
+        is_little_endian: bool,
+        compression: Option<Compression>,
+    ) -> Result<Buffer<T>>;
+
+    /// Reads a bitmap of `length` bits from the `bytes` bytes at `offset`.
+    fn read_bitmap_at(
+        &mut self,
+        offset: u64,
+        bytes: usize,
+        length: usize,
+        compression: Option<Compression>,
+    ) -> Result<Bitmap>;
+}
+
+impl<R: Read + Seek> BufferReader for R {
+    fn read_buffer_at<T: NativeType>(
+        &mut self,
+        offset: u64,
+        buffer_length: usize,
+        length: usize,
+        is_little_endian: bool,
+        compression: Option<Compression>,
+    ) -> Result<Buffer<T>> {
+        self.seek(SeekFrom::Start(offset))?;
+
+        if let Some(compression) = compression {
+            Ok(
+                read_compressed_buffer(self, buffer_length, length, is_little_endian, compression)?
+                    .into(),
+            )
+        } else {
+            Ok(read_uncompressed_buffer(self, buffer_length, length, is_little_endian)?.into())
         }
     }
+
+    fn read_bitmap_at(
+        &mut self,
+        offset: u64,
+        bytes: usize,
+        length: usize,
+        compression: Option<Compression>,
+    ) -> Result<Bitmap> {
+        self.seek(SeekFrom::Start(offset))?;
+
+        let buffer = if let Some(compression) = compression {
+            read_compressed_bitmap(length, bytes, compression, self)
+        } else {
+            read_uncompressed_bitmap(length, bytes, self)
+        }?;
+
+        Ok(Bitmap::from_bytes(buffer.into(), length))
+    }
 }
 
-pub fn read_buffer<T: NativeType, R: Read + Seek>(
+pub(crate) fn read_buffer<T: NativeType, R: BufferReader>(
     buf: &mut VecDeque<IpcBuffer>,
     length: usize, // in slots
     reader: &mut R,
@@ -126,18 +214,13 @@ pub fn read_buffer<T: NativeType, R: Read + Seek>(
         .pop_front()
         .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
 
-    reader.seek(SeekFrom::Start(block_offset + buf.offset() as u64))?;
-
-    let buffer_length = buf.length() as usize;
-
-    if let Some(compression) = compression {
-        Ok(
-            read_compressed_buffer(reader, buffer_length, length, is_little_endian, compression)?
-                .into(),
-        )
-    } else {
-        Ok(read_uncompressed_buffer(reader, buffer_length, length, is_little_endian)?.into())
-    }
+    reader.read_buffer_at(
+        block_offset + buf.offset() as u64,
+        buf.length() as usize,
+        length,
+        is_little_endian,
+        compression,
+    )
 }
 
 fn read_uncompressed_bitmap<R: Read + Seek>(
@@ -175,19 +258,11 @@ fn read_compressed_bitmap<R: Read + Seek>(
     let mut slice = vec![0u8; bytes];
     reader.read_exact(&mut slice)?;
 
-    match compression.codec()? {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&slice[8..], &mut buffer)?;
-            Ok(buffer)
-        }
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&slice[8..], &mut buffer)?;
-            Ok(buffer)
-        }
-    }
+    decompress(&slice, &mut buffer, compression)?;
+    Ok(buffer)
 }
 
-pub fn read_bitmap<R: Read + Seek>(
+pub(crate) fn read_bitmap<R: BufferReader>(
     buf: &mut VecDeque<IpcBuffer>,
     length: usize,
     reader: &mut R,
@@ -199,20 +274,15 @@ pub fn read_bitmap<R: Read + Seek>(
         .pop_front()
         .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
 
-    reader.seek(SeekFrom::Start(block_offset + buf.offset() as u64))?;
-
-    let bytes = buf.length() as usize;
-
-    let buffer = if let Some(compression) = compression {
-        read_compressed_bitmap(length, bytes, compression, reader)
-    } else {
-        read_uncompressed_bitmap(length, bytes, reader)
-    }?;
-
-    Ok(Bitmap::from_bytes(buffer.into(), length))
+    reader.read_bitmap_at(
+        block_offset + buf.offset() as u64,
+        buf.length() as usize,
+        length,
+        compression,
+    )
 }
 
-pub fn read_validity<R: Read + Seek>(
+pub(crate) fn read_validity<R: BufferReader>(
     buffers: &mut VecDeque<IpcBuffer>,
     field_node: Node,
     reader: &mut R,
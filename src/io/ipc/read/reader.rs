@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::convert::TryInto;
 use std::io::{Read, Seek, SeekFrom};
 use std::sync::Arc;
@@ -6,13 +7,13 @@ use crate::array::Array;
 use crate::chunk::Chunk;
 use crate::datatypes::{Field, Schema};
 use crate::error::{ArrowError, Result};
-use crate::io::ipc::IpcSchema;
+use crate::io::ipc::{IpcField, IpcSchema};
 
 use super::super::{ARROW_MAGIC, CONTINUATION_MARKER};
 use super::common::*;
 use super::schema::fb_to_schema;
 use super::Dictionaries;
-use arrow_format::ipc::planus::{ReadAsRoot, Vector};
+use arrow_format::ipc::planus::ReadAsRoot;
 
 /// Metadata of an Arrow IPC file, written in the footer of the file.
 #[derive(Debug, Clone)]
@@ -25,11 +26,16 @@ pub struct FileMetadata {
 
     /// The blocks in the file
     ///
-    /// A block indicates the regions in the file to read to get data
-    blocks: Vec<arrow_format::ipc::Block>,
+    /// A block indicates the regions in the file to read to get data.
+    /// There is one block per record batch, in the order they were written.
+    pub blocks: Vec<arrow_format::ipc::Block>,
 
-    /// Dictionaries associated to each dict_id
+    /// Dictionaries associated to each dict_id. The [`FileReader`] reads those required by its
+    /// projection on demand.
     dictionaries: Dictionaries,
+
+    /// The blocks of the dictionaries in the file
+    pub(super) dictionary_blocks: Option<Vec<arrow_format::ipc::Block>>,
 }
 
 /// Arrow File reader
@@ -38,6 +44,7 @@ pub struct FileReader<R: Read + Seek> {
     metadata: FileMetadata,
     current_block: usize,
     projection: Option<(Vec<usize>, Schema)>,
+    has_dictionaries: bool,
     buffer: Vec<u8>,
 }
 
@@ -62,18 +69,67 @@ fn read_dictionary_message<R: Read + Seek>(
     Ok(())
 }
 
+fn collect_dictionary_ids(ipc_field: &IpcField, ids: &mut HashSet<i64>) {
+    if let Some(id) = ipc_field.dictionary_id {
+        ids.insert(id);
+    }
+    ipc_field
+        .fields
+        .iter()
+        .for_each(|field| collect_dictionary_ids(field, ids));
+}
+
+/// Reads the dictionaries of the file required by the fields in `projection` (all if `None`).
+/// The bodies of the remaining dictionaries are not read.
+pub fn read_file_dictionaries<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+) -> Result<Dictionaries> {
+    let blocks = if let Some(blocks) = &metadata.dictionary_blocks {
+        blocks
+    } else {
+        return Ok(Default::default());
+    };
+
+    read_dictionaries(
+        reader,
+        &metadata.schema.fields,
+        &metadata.ipc_schema,
+        blocks,
+        &required_dictionary_ids(metadata, projection),
+    )
+}
+
+/// Returns the ids of the dictionaries required by the fields in `projection` (all if `None`).
+fn required_dictionary_ids(metadata: &FileMetadata, projection: Option<&[usize]>) -> HashSet<i64> {
+    let mut ids = HashSet::new();
+    match projection {
+        Some(projection) => projection
+            .iter()
+            .for_each(|x| collect_dictionary_ids(&metadata.ipc_schema.fields[*x], &mut ids)),
+        None => metadata
+            .ipc_schema
+            .fields
+            .iter()
+            .for_each(|field| collect_dictionary_ids(field, &mut ids)),
+    }
+    ids
+}
+
 fn read_dictionaries<R: Read + Seek>(
     reader: &mut R,
     fields: &[Field],
     ipc_schema: &IpcSchema,
-    blocks: Vector<arrow_format::ipc::BlockRef>,
+    blocks: &[arrow_format::ipc::Block],
+    ids: &HashSet<i64>,
 ) -> Result<Dictionaries> {
     let mut dictionaries = Default::default();
     let mut data = vec![];
 
     for block in blocks {
-        let offset = block.offset() as u64;
-        let length = block.meta_data_length() as u64;
+        let offset = block.offset as u64;
+        let length = block.meta_data_length as u64;
         read_dictionary_message(reader, offset, &mut data)?;
 
         let message = arrow_format::ipc::MessageRef::read_as_root(&data).map_err(|err| {
@@ -86,6 +142,9 @@ fn read_dictionaries<R: Read + Seek>(
 
         match header {
             arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
+                if !ids.contains(&batch.id()?) {
+                    continue;
+                }
                 let block_offset = offset + length;
                 read_dictionary(
                     batch,
@@ -147,13 +206,15 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
         .ok_or_else(|| ArrowError::OutOfSpec("Unable to get the schema from footer".to_string()))?;
     let (schema, ipc_schema) = fb_to_schema(ipc_schema)?;
 
-    let dictionary_blocks = footer.dictionaries()?;
-
-    let dictionaries = if let Some(blocks) = dictionary_blocks {
-        read_dictionaries(reader, &schema.fields, &ipc_schema, blocks)?
-    } else {
-        Default::default()
-    };
+    let dictionary_blocks = footer
+        .dictionaries()?
+        .map(|blocks| {
+            blocks
+                .iter()
+                .map(|block| Ok(block.try_into()?))
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?;
 
     Ok(FileMetadata {
         schema,
@@ -162,11 +223,12 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
             .iter()
             .map(|block| Ok(block.try_into()?))
             .collect::<Result<Vec<_>>>()?,
-        dictionaries,
+        dictionaries: Default::default(),
+        dictionary_blocks,
     })
 }
 
-fn get_serialized_batch<'a>(
+pub(super) fn get_serialized_batch<'a>(
     message: &'a arrow_format::ipc::MessageRef,
 ) -> Result<arrow_format::ipc::RecordBatchRef<'a>> {
     let header = message.header()?.ok_or_else(|| {
@@ -185,6 +247,10 @@ fn get_serialized_batch<'a>(
 }
 
 /// Read a batch from the reader.
+///
+/// The dictionaries required by `projection` that are not yet in `metadata` (e.g. when it was
+/// returned by [`read_file_metadata`]) are read from the file on every call. Use [`FileReader`]
+/// to read them once for all batches.
 pub fn read_batch<R: Read + Seek>(
     reader: &mut R,
     metadata: &FileMetadata,
@@ -192,6 +258,17 @@ pub fn read_batch<R: Read + Seek>(
     block: usize,
     block_data: &mut Vec<u8>,
 ) -> Result<Chunk<Arc<dyn Array>>> {
+    let read_dictionaries;
+    let dictionaries = if required_dictionary_ids(metadata, projection)
+        .iter()
+        .all(|id| metadata.dictionaries.contains_key(id))
+    {
+        &metadata.dictionaries
+    } else {
+        read_dictionaries = read_file_dictionaries(reader, metadata, projection)?;
+        &read_dictionaries
+    };
+
     let block = metadata.blocks[block];
 
     // read length
@@ -218,7 +295,7 @@ pub fn read_batch<R: Read + Seek>(
         &metadata.schema.fields,
         &metadata.ipc_schema,
         projection,
-        &metadata.dictionaries,
+        dictionaries,
         message.version()?,
         reader,
         block.offset as u64 + block.meta_data_length as u64,
@@ -226,16 +303,16 @@ pub fn read_batch<R: Read + Seek>(
 }
 
 impl<R: Read + Seek> FileReader<R> {
-    /// Creates a new [`FileReader`]. Use `projection` to only take certain columns.
+    /// Creates a new [`FileReader`]. Use `projection` to only take certain columns, in the order
+    /// they are declared. The buffers and dictionaries of the remaining columns are not read.
     /// # Panic
-    /// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
+    /// Panics iff the projection contains repeated columns (e.g. `[0, 1, 1]` is not valid)
     pub fn new(reader: R, metadata: FileMetadata, projection: Option<Vec<usize>>) -> Self {
         if let Some(projection) = projection.as_ref() {
-            projection.windows(2).for_each(|x| {
-                assert!(
-                    x[0] < x[1],
-                    "The projection on IPC must be ordered and non-overlapping"
-                );
+            let mut sorted = projection.clone();
+            sorted.sort_unstable();
+            sorted.windows(2).for_each(|x| {
+                assert!(x[0] < x[1], "The projection on IPC must be non-overlapping");
             });
         }
         let projection = projection.map(|projection| {
@@ -253,6 +330,7 @@ impl<R: Read + Seek> FileReader<R> {
             reader,
             metadata,
             projection,
+            has_dictionaries: false,
             current_block: 0,
             buffer: vec![],
         }
@@ -271,6 +349,41 @@ impl<R: Read + Seek> FileReader<R> {
         &self.metadata
     }
 
+    /// Reads the record batch at position `index` of the file, seeking to its block directly.
+    /// This does not modify the position of the iterator.
+    /// # Errors
+    /// This function errors iff `index` is out of bounds or the batch is invalid.
+    pub fn read_batch_at(&mut self, index: usize) -> Result<Chunk<Arc<dyn Array>>> {
+        if index >= self.metadata.blocks.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The file has {} record batches, but batch {} was requested",
+                self.metadata.blocks.len(),
+                index
+            )));
+        }
+        self.load_dictionaries()?;
+        read_batch(
+            &mut self.reader,
+            &self.metadata,
+            self.projection.as_ref().map(|x| x.0.as_ref()),
+            index,
+            &mut self.buffer,
+        )
+    }
+
+    /// Reads the dictionaries required by the projection, if not yet read
+    fn load_dictionaries(&mut self) -> Result<()> {
+        if !self.has_dictionaries {
+            self.metadata.dictionaries = read_file_dictionaries(
+                &mut self.reader,
+                &self.metadata,
+                self.projection.as_ref().map(|x| x.0.as_ref()),
+            )?;
+            self.has_dictionaries = true;
+        }
+        Ok(())
+    }
+
     /// Consumes this FileReader, returning the underlying reader
     pub fn into_inner(self) -> R {
         self.reader
@@ -281,6 +394,10 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     type Item = Result<Chunk<Arc<dyn Array>>>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Err(e) = self.load_dictionaries() {
+            return Some(Err(e));
+        }
+
         // get current block
         if self.current_block < self.metadata.blocks.len() {
             let block = self.current_block;
@@ -26,9 +26,14 @@ fn try_unzip_vec<A, B, I: Iterator<Item = Result<(A, B)>>>(iter: I) -> Result<(V
 }
 
 fn deserialize_field(ipc_field: arrow_format::ipc::FieldRef) -> Result<(Field, IpcField)> {
-    let metadata = read_metadata(&ipc_field)?;
+    let mut metadata = read_metadata(&ipc_field)?;
 
     let extension = get_extension(&metadata);
+    if extension.is_some() {
+        // the extension is declared by the `DataType`
+        metadata.remove("ARROW:extension:name");
+        metadata.remove("ARROW:extension:metadata");
+    }
 
     let (data_type, ipc_field_) = get_data_type(ipc_field, extension, true)?;
 
@@ -197,8 +202,18 @@ fn get_data_type(
             (DataType::Duration(time_unit), IpcField::default())
         }
         Decimal(decimal) => {
-            let data_type =
-                DataType::Decimal(decimal.precision()? as usize, decimal.scale()? as usize);
+            let precision = decimal.precision()? as usize;
+            let scale = decimal.scale()? as usize;
+            let data_type = match decimal.bit_width()? {
+                128 => DataType::Decimal(precision, scale),
+                256 => DataType::Decimal256(precision, scale),
+                bit_width => {
+                    return Err(ArrowError::oos(format!(
+                        "IPC: decimal bit width must be 128 or 256, but it is {}",
+                        bit_width
+                    )))
+                }
+            };
             (data_type, IpcField::default())
         }
         List(_) => {
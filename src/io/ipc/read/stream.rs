@@ -1,3 +1,4 @@
+use std::convert::TryInto;
 use std::io::Read;
 use std::sync::Arc;
 
@@ -79,6 +80,98 @@ impl StreamState {
     }
 }
 
+/// Parses a message of an Arrow stream, returning it and the length of its body.
+/// This is shared by the sync and async readers.
+pub(super) fn parse_message(
+    message_buffer: &[u8],
+) -> Result<(arrow_format::ipc::MessageRef<'_>, usize)> {
+    let message = arrow_format::ipc::MessageRef::read_as_root(message_buffer).map_err(|err| {
+        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+    })?;
+    let body_length = message.body_length()?;
+    let body_length: usize = body_length
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: the body length of a message must be positive"))?;
+    Ok((message, body_length))
+}
+
+/// Decodes a message of an Arrow stream and its body, yielding `None` if the message is a
+/// dictionary batch (which is added to `dictionaries`) and the [`Chunk`] otherwise.
+/// This is shared by the sync and async readers.
+pub(super) fn decode_message(
+    message: arrow_format::ipc::MessageRef,
+    body: &[u8],
+    metadata: &StreamMetadata,
+    dictionaries: &mut Dictionaries,
+) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+    let header = message.header()?.ok_or_else(|| {
+        ArrowError::oos("IPC: unable to fetch the message header. The file or stream is corrupted.")
+    })?;
+
+    match header {
+        arrow_format::ipc::MessageHeaderRef::Schema(_) => Err(ArrowError::oos(
+            "A stream must contain a single schema message, at its start",
+        )),
+        arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => read_record_batch(
+            batch,
+            &metadata.schema.fields,
+            &metadata.ipc_schema,
+            None,
+            dictionaries,
+            metadata.version,
+            &mut std::io::Cursor::new(body),
+            0,
+        )
+        .map(Some),
+        arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
+            read_dictionary(
+                batch,
+                &metadata.schema.fields,
+                &metadata.ipc_schema,
+                dictionaries,
+                &mut std::io::Cursor::new(body),
+                0,
+            )?;
+            Ok(None)
+        }
+        t => Err(ArrowError::OutOfSpec(format!(
+            "Reading types other than record batches not yet supported, unable to read {:?} ",
+            t
+        ))),
+    }
+}
+
+/// Reads the 4 bytes that start a message into `prefix`, returning `false` if the stream
+/// ended exactly at a message boundary.
+/// # Errors
+/// An [`ArrowError::Io`] of kind [`std::io::ErrorKind::UnexpectedEof`] if the stream ended
+/// after only part of the prefix was read.
+fn read_prefix<R: Read>(reader: &mut R, prefix: &mut [u8; 4]) -> Result<bool> {
+    let mut read = 0;
+    while read < prefix.len() {
+        match reader.read(&mut prefix[read..]) {
+            Ok(0) => break,
+            Ok(n) => read += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(ArrowError::from(e)),
+        }
+    }
+    prefix_read(read)
+}
+
+/// Maps the number of bytes read of a message prefix to whether a message follows.
+/// This is shared by the sync and async readers.
+pub(super) fn prefix_read(read: usize) -> Result<bool> {
+    match read {
+        0 => Ok(false),
+        4 => Ok(true),
+        _ => Err(ArrowError::Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "IPC: the stream ended in the middle of a message prefix",
+        ))),
+    }
+}
+
 /// Reads the next item, yielding `None` if the stream is done,
 /// and a [`StreamState`] otherwise.
 fn read_next<R: Read>(
@@ -91,18 +184,11 @@ fn read_next<R: Read>(
     // determine metadata length
     let mut meta_length: [u8; 4] = [0; 4];
 
-    match reader.read_exact(&mut meta_length) {
-        Ok(()) => (),
-        Err(e) => {
-            return if e.kind() == std::io::ErrorKind::UnexpectedEof {
-                // Handle EOF without the "0xFFFFFFFF 0x00000000"
-                // valid according to:
-                // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
-                Ok(Some(StreamState::Waiting))
-            } else {
-                Err(ArrowError::from(e))
-            };
-        }
+    if !read_prefix(reader, &mut meta_length)? {
+        // Handle EOF without the "0xFFFFFFFF 0x00000000"
+        // valid according to:
+        // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+        return Ok(Some(StreamState::Waiting));
     }
 
     let meta_length = {
@@ -123,58 +209,18 @@ fn read_next<R: Read>(
     message_buffer.resize(meta_length, 0);
     reader.read_exact(message_buffer)?;
 
-    let message = arrow_format::ipc::MessageRef::read_as_root(message_buffer).map_err(|err| {
-        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
-    })?;
-    let header = message.header()?.ok_or_else(|| {
-        ArrowError::oos("IPC: unable to fetch the message header. The file or stream is corrupted.")
-    })?;
+    let (message, body_length) = parse_message(message_buffer)?;
 
-    match header {
-        arrow_format::ipc::MessageHeaderRef::Schema(_) => Err(ArrowError::oos("A stream ")),
-        arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => {
-            // read the block that makes up the record batch into a buffer
-            data_buffer.clear();
-            data_buffer.resize(message.body_length()? as usize, 0);
-            reader.read_exact(data_buffer)?;
-
-            let mut reader = std::io::Cursor::new(data_buffer);
+    // read the block that makes up the record or dictionary batch into a buffer
+    data_buffer.clear();
+    data_buffer.resize(body_length, 0);
+    reader.read_exact(data_buffer)?;
 
-            read_record_batch(
-                batch,
-                &metadata.schema.fields,
-                &metadata.ipc_schema,
-                None,
-                dictionaries,
-                metadata.version,
-                &mut reader,
-                0,
-            )
-            .map(|x| Some(StreamState::Some(x)))
-        }
-        arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
-            // read the block that makes up the dictionary batch into a buffer
-            let mut buf = vec![0; message.body_length()? as usize];
-            reader.read_exact(&mut buf)?;
-
-            let mut dict_reader = std::io::Cursor::new(buf);
-
-            read_dictionary(
-                batch,
-                &metadata.schema.fields,
-                &metadata.ipc_schema,
-                dictionaries,
-                &mut dict_reader,
-                0,
-            )?;
-
-            // read the next message until we encounter a RecordBatch message
-            read_next(reader, metadata, dictionaries, message_buffer, data_buffer)
-        }
-        t => Err(ArrowError::OutOfSpec(format!(
-            "Reading types other than record batches not yet supported, unable to read {:?} ",
-            t
-        ))),
+    if let Some(chunk) = decode_message(message, data_buffer, metadata, dictionaries)? {
+        Ok(Some(StreamState::Some(chunk)))
+    } else {
+        // read the next message until we encounter a RecordBatch message
+        read_next(reader, metadata, dictionaries, message_buffer, data_buffer)
     }
 }
 
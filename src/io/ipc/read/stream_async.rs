@@ -1,7 +1,6 @@
 //! APIs to read Arrow streams asynchronously
 use std::sync::Arc;
 
-use arrow_format::ipc::planus::ReadAsRoot;
 use futures::future::BoxFuture;
 use futures::AsyncRead;
 use futures::AsyncReadExt;
@@ -12,8 +11,8 @@ use crate::chunk::Chunk;
 use crate::error::{ArrowError, Result};
 
 use super::super::CONTINUATION_MARKER;
-use super::common::{read_dictionary, read_record_batch};
 use super::schema::deserialize_stream_metadata;
+use super::stream::{decode_message, parse_message, prefix_read};
 use super::Dictionaries;
 use super::StreamMetadata;
 
@@ -28,13 +27,8 @@ struct ReadState<R> {
     pub message_buffer: Vec<u8>,
 }
 
-/// The state of an Arrow stream
-enum StreamState<R> {
-    /// The stream does not contain new chunks (and it has not been closed)
-    Waiting(ReadState<R>),
-    /// The stream contain a new chunk
-    Some((ReadState<R>, Chunk<Arc<dyn Array>>)),
-}
+/// The state of an Arrow stream after a chunk was read
+type StreamState<R> = (ReadState<R>, Chunk<Arc<dyn Array>>);
 
 /// Reads the [`StreamMetadata`] of the Arrow stream asynchronously
 pub async fn read_stream_metadata_async<R: AsyncRead + Unpin + Send>(
@@ -58,97 +52,74 @@ pub async fn read_stream_metadata_async<R: AsyncRead + Unpin + Send>(
     deserialize_stream_metadata(&meta_buffer)
 }
 
-/// Reads the next item, yielding `None` if the stream has been closed,
-/// or a [`StreamState`] otherwise.
-async fn maybe_next<R: AsyncRead + Unpin + Send>(
-    mut state: ReadState<R>,
-) -> Result<Option<StreamState<R>>> {
-    // determine metadata length
-    let mut meta_length: [u8; 4] = [0; 4];
-
-    match state.reader.read_exact(&mut meta_length).await {
-        Ok(()) => (),
-        Err(e) => {
-            return if e.kind() == std::io::ErrorKind::UnexpectedEof {
-                // Handle EOF without the "0xFFFFFFFF 0x00000000"
-                // valid according to:
-                // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
-                Ok(Some(StreamState::Waiting(state)))
-            } else {
-                Err(ArrowError::from(e))
-            };
+/// Reads the 4 bytes that start a message into `prefix`, returning `false` if the stream
+/// ended exactly at a message boundary and erroring if it ended in the middle of the prefix.
+async fn read_prefix<R: AsyncRead + Unpin + Send>(
+    reader: &mut R,
+    prefix: &mut [u8; 4],
+) -> Result<bool> {
+    let mut read = 0;
+    while read < prefix.len() {
+        match reader.read(&mut prefix[read..]).await {
+            Ok(0) => break,
+            Ok(n) => read += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(ArrowError::from(e)),
         }
     }
+    prefix_read(read)
+}
 
-    let meta_length = {
-        // If a continuation marker is encountered, skip over it and read
-        // the size from the next four bytes.
-        if meta_length == CONTINUATION_MARKER {
-            state.reader.read_exact(&mut meta_length).await?;
+/// Reads the next chunk, yielding `None` if the stream has been closed,
+/// or a [`StreamState`] otherwise. Dictionary batches are read until a record batch is found.
+async fn maybe_next<R: AsyncRead + Unpin + Send>(
+    mut state: ReadState<R>,
+) -> Result<Option<StreamState<R>>> {
+    loop {
+        // determine metadata length
+        let mut meta_length: [u8; 4] = [0; 4];
+
+        if !read_prefix(&mut state.reader, &mut meta_length).await? {
+            // Handle EOF without the "0xFFFFFFFF 0x00000000"
+            // valid according to:
+            // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+            return Ok(None);
         }
-        i32::from_le_bytes(meta_length) as usize
-    };
 
-    if meta_length == 0 {
-        // the stream has ended, mark the reader as finished
-        return Ok(None);
-    }
+        let meta_length = {
+            // If a continuation marker is encountered, skip over it and read
+            // the size from the next four bytes.
+            if meta_length == CONTINUATION_MARKER {
+                state.reader.read_exact(&mut meta_length).await?;
+            }
+            i32::from_le_bytes(meta_length) as usize
+        };
 
-    state.message_buffer.clear();
-    state.message_buffer.resize(meta_length, 0);
-    state.reader.read_exact(&mut state.message_buffer).await?;
-
-    let message =
-        arrow_format::ipc::MessageRef::read_as_root(&state.message_buffer).map_err(|err| {
-            ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
-        })?;
-    let header = message.header()?.ok_or_else(|| {
-        ArrowError::oos("IPC: unable to fetch the message header. The file or stream is corrupted.")
-    })?;
-
-    match header {
-        arrow_format::ipc::MessageHeaderRef::Schema(_) => Err(ArrowError::oos("A stream ")),
-        arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => {
-            // read the block that makes up the record batch into a buffer
-            state.data_buffer.clear();
-            state.data_buffer.resize(message.body_length()? as usize, 0);
-            state.reader.read_exact(&mut state.data_buffer).await?;
-
-            read_record_batch(
-                batch,
-                &state.metadata.schema.fields,
-                &state.metadata.ipc_schema,
-                None,
-                &state.dictionaries,
-                state.metadata.version,
-                &mut std::io::Cursor::new(&state.data_buffer),
-                0,
-            )
-            .map(|chunk| Some(StreamState::Some((state, chunk))))
+        if meta_length == 0 {
+            // the stream has ended, mark the reader as finished
+            return Ok(None);
         }
-        arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
-            // read the block that makes up the dictionary batch into a buffer
-            let mut buf = vec![0; message.body_length()? as usize];
-            state.reader.read_exact(&mut buf).await?;
-
-            let mut dict_reader = std::io::Cursor::new(buf);
-
-            read_dictionary(
-                batch,
-                &state.metadata.schema.fields,
-                &state.metadata.ipc_schema,
-                &mut state.dictionaries,
-                &mut dict_reader,
-                0,
-            )?;
-
-            // read the next message until we encounter a Chunk<Arc<dyn Array>> message
-            Ok(Some(StreamState::Waiting(state)))
+
+        state.message_buffer.clear();
+        state.message_buffer.resize(meta_length, 0);
+        state.reader.read_exact(&mut state.message_buffer).await?;
+
+        let (message, body_length) = parse_message(&state.message_buffer)?;
+
+        // read the block that makes up the record or dictionary batch into a buffer
+        state.data_buffer.clear();
+        state.data_buffer.resize(body_length, 0);
+        state.reader.read_exact(&mut state.data_buffer).await?;
+
+        let chunk = decode_message(
+            message,
+            &state.data_buffer,
+            &state.metadata,
+            &mut state.dictionaries,
+        )?;
+        if let Some(chunk) = chunk {
+            return Ok(Some((state, chunk)));
         }
-        t => Err(ArrowError::OutOfSpec(format!(
-            "Reading types other than record batches not yet supported, unable to read {:?} ",
-            t
-        ))),
     }
 }
 
@@ -195,11 +166,10 @@ impl<'a, R: AsyncRead + Unpin + Send> Stream for AsyncStreamReader<'a, R> {
                     me.future = None;
                     Poll::Ready(None)
                 }
-                Poll::Ready(Ok(Some(StreamState::Some((state, batch))))) => {
+                Poll::Ready(Ok(Some((state, batch)))) => {
                     me.future = Some(Box::pin(maybe_next(state)));
                     Poll::Ready(Some(Ok(batch)))
                 }
-                Poll::Ready(Ok(Some(StreamState::Waiting(_)))) => Poll::Pending,
                 Poll::Ready(Err(err)) => {
                     me.future = None;
                     Poll::Ready(Some(Err(err)))
@@ -27,6 +27,10 @@ pub struct WriteOptions {
     /// Whether the buffers should be compressed and which codec to use.
     /// Note: to use compression the crate must be compiled with feature `io_ipc_compression`.
     pub compression: Option<Compression>,
+    /// Whether a dictionary whose values grew since it was last written is written as a delta
+    /// (only its new values) instead of as a replacement.
+    /// Note: readers must support delta dictionaries to read them.
+    pub delta_dictionaries: bool,
 }
 
 fn encode_dictionary(
@@ -44,7 +48,8 @@ fn encode_dictionary(
             let dict_id = field.dictionary_id
                 .ok_or_else(|| ArrowError::InvalidArgumentError("Dictionaries must have an associated id".to_string()))?;
 
-            let values = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap().values();
+            let dictionary = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let values = dictionary.values();
             encode_dictionary(field,
                 values,
                 options,
@@ -52,15 +57,29 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            let emit = dictionary_tracker.insert(dict_id, array)?;
-
-            if emit {
-                encoded_dictionaries.push(dictionary_batch_to_bytes(
-                    dict_id,
-                    array.as_ref(),
-                    options,
-                    is_native_little_endian(),
-                ));
+            match dictionary_tracker.insert(dict_id, array, options.delta_dictionaries)? {
+                DictionaryEmit::Skip => {}
+                DictionaryEmit::Full => {
+                    encoded_dictionaries.push(dictionary_batch_to_bytes(
+                        dict_id,
+                        array.as_ref(),
+                        false,
+                        options,
+                        is_native_little_endian(),
+                    ));
+                }
+                DictionaryEmit::Delta(offset) => {
+                    let values = values.slice(offset, values.len() - offset).into();
+                    let keys = dictionary.keys().slice(0, 0);
+                    let delta = DictionaryArray::<$T>::from_data(keys, values);
+                    encoded_dictionaries.push(dictionary_batch_to_bytes(
+                        dict_id,
+                        &delta,
+                        true,
+                        options,
+                        is_native_little_endian(),
+                    ));
+                }
             };
             Ok(())
         }),
@@ -259,6 +278,7 @@ fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) ->
 fn dictionary_batch_to_bytes(
     dict_id: i64,
     array: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -290,7 +310,7 @@ fn dictionary_batch_to_bytes(
                     buffers: Some(buffers),
                     compression,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -306,8 +326,19 @@ fn dictionary_batch_to_bytes(
     }
 }
 
+/// What has to be written of a dictionary, as declared by [`DictionaryTracker::insert`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryEmit {
+    /// The dictionary was already written
+    Skip,
+    /// The whole dictionary must be written, replacing any previous one with the same id
+    Full,
+    /// Only the values from this offset onwards must be written, as a delta of the previous one
+    Delta(usize),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
-/// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
+/// multiple times. Can optionally error if a replacement of an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
 pub struct DictionaryTracker {
     written: Dictionaries,
@@ -324,14 +355,21 @@ impl DictionaryTracker {
 
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
-    /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
-    ///   that the dictionary was not actually inserted (because it's already been seen).
+    /// * If this ID has been written already and has the same data, return [`DictionaryEmit::Skip`]
+    ///   to indicate that the dictionary was not actually inserted (because it's already been seen).
+    /// * If `delta` and the previously written values of this ID are a prefix of the new values,
+    ///   return [`DictionaryEmit::Delta`] with the number of previously written values.
     /// * If this ID has been written already but with different data, and this tracker is
     ///   configured to return an error, return an error.
     /// * If the tracker has not been configured to error on replacement or this dictionary
-    ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
-    ///   inserted.
-    pub fn insert(&mut self, dict_id: i64, array: &Arc<dyn Array>) -> Result<bool> {
+    ///   has never been seen before, return [`DictionaryEmit::Full`] to indicate that the
+    ///   dictionary was just inserted.
+    pub fn insert(
+        &mut self,
+        dict_id: i64,
+        array: &Arc<dyn Array>,
+        delta: bool,
+    ) -> Result<DictionaryEmit> {
         let values = match array.data_type() {
             DataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -345,11 +383,18 @@ impl DictionaryTracker {
             _ => unreachable!(),
         };
 
+        let mut emit = DictionaryEmit::Full;
         // If a dictionary with this id was already emitted, check if it was the same.
         if let Some(last) = self.written.get(&dict_id) {
             if last.as_ref() == values.as_ref() {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(DictionaryEmit::Skip);
+            } else if delta
+                && last.len() < values.len()
+                && last.as_ref() == values.slice(0, last.len()).as_ref()
+            {
+                // New values were appended => only emit these
+                emit = DictionaryEmit::Delta(last.len());
             } else if self.error_on_replacement {
                 return Err(ArrowError::InvalidArgumentError(
                     "Dictionary replacement detected when writing IPC file format. \
@@ -361,7 +406,7 @@ impl DictionaryTracker {
         };
 
         self.written.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(emit)
     }
 }
 
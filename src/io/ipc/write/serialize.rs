@@ -311,27 +311,43 @@ pub fn write_union(
         compression,
     );
 
+    // the types of a sliced union are sliced, but its offsets and fields are not
+    let start = array.slice_offset();
+    let length = array.len();
     if let Some(offsets) = array.offsets() {
         write_buffer(
-            offsets,
+            &offsets[start..start + length],
             buffers,
             arrow_data,
             offset,
             is_little_endian,
             compression,
         );
+        // the offsets of a dense union point to all slots of its fields
+        array.fields().iter().for_each(|array| {
+            write(
+                array.as_ref(),
+                buffers,
+                arrow_data,
+                nodes,
+                offset,
+                is_little_endian,
+                compression,
+            )
+        });
+    } else {
+        array.fields().iter().for_each(|array| {
+            write(
+                array.slice(start, length).as_ref(),
+                buffers,
+                arrow_data,
+                nodes,
+                offset,
+                is_little_endian,
+                compression,
+            )
+        });
     }
-    array.fields().iter().for_each(|array| {
-        write(
-            array.as_ref(),
-            buffers,
-            arrow_data,
-            nodes,
-            offset,
-            is_little_endian,
-            compression,
-        )
-    });
 }
 
 fn write_map(
@@ -646,6 +662,23 @@ fn write_bytes(
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
+        compress(bytes, arrow_data, compression);
+    } else {
+        arrow_data.extend_from_slice(bytes);
+    };
+
+    buffers.push(finish_buffer(arrow_data, start, offset));
+}
+
+/// Buffers smaller than this (in bytes) are written uncompressed even when compression is requested,
+/// as the codec's framing would outweigh any savings.
+const MIN_COMPRESSION_SIZE: usize = 64;
+
+/// Writes `bytes` to `arrow_data` prefixed by their uncompressed length, as declared by the IPC spec.
+/// Buffers below [`MIN_COMPRESSION_SIZE`] or that do not shrink are written as is, with a length of `-1`.
+fn compress(bytes: &[u8], arrow_data: &mut Vec<u8>, compression: Compression) {
+    let start = arrow_data.len();
+    if bytes.len() >= MIN_COMPRESSION_SIZE {
         arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
         match compression {
             Compression::LZ4 => {
@@ -655,11 +688,13 @@ fn write_bytes(
                 compression::compress_zstd(bytes, arrow_data).unwrap();
             }
         }
-    } else {
-        arrow_data.extend_from_slice(bytes);
-    };
-
-    buffers.push(finish_buffer(arrow_data, start, offset));
+        if arrow_data.len() - start - 8 < bytes.len() {
+            return;
+        }
+        arrow_data.truncate(start);
+    }
+    arrow_data.extend_from_slice(&(-1i64).to_le_bytes());
+    arrow_data.extend_from_slice(bytes);
 }
 
 fn write_bitmap(
@@ -748,15 +783,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .map(|x| T::to_be_bytes(&x))
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
-    arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
-        Compression::LZ4 => {
-            compression::compress_lz4(&swapped, arrow_data).unwrap();
-        }
-        Compression::ZSTD => {
-            compression::compress_zstd(&swapped, arrow_data).unwrap();
-        }
-    }
+    compress(&swapped, arrow_data, compression);
 }
 
 fn _write_buffer<T: NativeType>(buffer: &[T], arrow_data: &mut Vec<u8>, is_little_endian: bool) {
@@ -777,17 +804,14 @@ fn _write_compressed_buffer<T: NativeType>(
 ) {
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            }
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            }
-        }
+        compress(bytes, arrow_data, compression);
     } else {
-        todo!()
+        _write_compressed_buffer_from_iter(
+            buffer.iter().copied(),
+            arrow_data,
+            is_little_endian,
+            compression,
+        )
     }
 }
 
@@ -11,7 +11,7 @@ use crate::{
     datatypes::{DataType, PhysicalType, PrimitiveType, Schema},
     error::{ArrowError, Result},
     io::ipc::IpcField,
-    types::{days_ms, months_days_ns, NativeType},
+    types::{days_ms, f16, i256, months_days_ns, NativeType},
 };
 
 use super::super::{ArrowJsonBatch, ArrowJsonColumn, ArrowJsonDictionaryBatch};
@@ -126,6 +126,51 @@ fn to_decimal(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray
     PrimitiveArray::<i128>::from_data(data_type, values, validity)
 }
 
+fn to_decimal256(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray<i256> {
+    let validity = to_validity(&json_col.validity);
+    let values = json_col
+        .data
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|value| match value {
+            Value::String(x) => {
+                let (is_negative, digits) = match x.strip_prefix('-') {
+                    Some(digits) => (true, digits),
+                    None => (false, x.as_str()),
+                };
+                let value = digits.bytes().fold(i256::default(), |acc, digit| {
+                    acc.checked_mul(i256::from(10))
+                        .and_then(|acc| acc.checked_add(i256::from((digit - b'0') as i128)))
+                        .unwrap()
+                });
+                if is_negative {
+                    value.wrapping_neg()
+                } else {
+                    value
+                }
+            }
+            _ => {
+                panic!()
+            }
+        })
+        .collect();
+
+    PrimitiveArray::<i256>::from_data(data_type, values, validity)
+}
+
+fn to_float16(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray<f16> {
+    let validity = to_validity(&json_col.validity);
+    let values = json_col
+        .data
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|value| f16::from_f64(value.as_f64().unwrap()))
+        .collect();
+    PrimitiveArray::<f16>::from_data(data_type, values, validity)
+}
+
 fn to_primitive<T: NativeType + NumCast>(
     json_col: &ArrowJsonColumn,
     data_type: DataType,
@@ -205,9 +250,9 @@ fn to_list<O: Offset>(
         dictionaries,
     )?;
     let offsets = to_offsets::<O>(json_col.offset.as_ref());
-    Ok(Arc::new(ListArray::<O>::from_data(
+    Ok(Arc::new(ListArray::<O>::try_new(
         data_type, offsets, values, validity,
-    )))
+    )?))
 }
 
 fn to_map(
@@ -227,9 +272,9 @@ fn to_map(
         dictionaries,
     )?;
     let offsets = to_offsets::<i32>(json_col.offset.as_ref());
-    Ok(Arc::new(MapArray::from_data(
+    Ok(Arc::new(MapArray::try_new(
         data_type, offsets, field, validity,
-    )))
+    )?))
 }
 
 fn to_dictionary<K: DictionaryKey>(
@@ -254,7 +299,7 @@ fn to_dictionary<K: DictionaryKey>(
         dictionaries,
     )?;
 
-    Ok(Arc::new(DictionaryArray::<K>::from_data(keys, values)))
+    Ok(Arc::new(DictionaryArray::<K>::try_new(keys, values)?))
 }
 
 /// Construct an [`Array`] from the JSON integration format
@@ -266,7 +311,7 @@ pub fn to_array(
 ) -> Result<Arc<dyn Array>> {
     use PhysicalType::*;
     match data_type.to_physical_type() {
-        Null => Ok(Arc::new(NullArray::from_data(data_type, json_col.count))),
+        Null => Ok(Arc::new(NullArray::try_new(data_type, json_col.count)?)),
         Boolean => {
             let validity = to_validity(&json_col.validity);
             let values = json_col
@@ -276,15 +321,16 @@ pub fn to_array(
                 .iter()
                 .map(|value| value.as_bool().unwrap())
                 .collect::<Bitmap>();
-            Ok(Arc::new(BooleanArray::from_data(
+            Ok(Arc::new(BooleanArray::try_new(
                 data_type, values, validity,
-            )))
+            )?))
         }
         Primitive(PrimitiveType::Int8) => Ok(Arc::new(to_primitive::<i8>(json_col, data_type))),
         Primitive(PrimitiveType::Int16) => Ok(Arc::new(to_primitive::<i16>(json_col, data_type))),
         Primitive(PrimitiveType::Int32) => Ok(Arc::new(to_primitive::<i32>(json_col, data_type))),
         Primitive(PrimitiveType::Int64) => Ok(Arc::new(to_primitive::<i64>(json_col, data_type))),
         Primitive(PrimitiveType::Int128) => Ok(Arc::new(to_decimal(json_col, data_type))),
+        Primitive(PrimitiveType::Int256) => Ok(Arc::new(to_decimal256(json_col, data_type))),
         Primitive(PrimitiveType::DaysMs) => Ok(Arc::new(to_primitive_days_ms(json_col, data_type))),
         Primitive(PrimitiveType::MonthDayNano) => {
             Ok(Arc::new(to_primitive_months_days_ns(json_col, data_type)))
@@ -293,6 +339,7 @@ pub fn to_array(
         Primitive(PrimitiveType::UInt16) => Ok(Arc::new(to_primitive::<u16>(json_col, data_type))),
         Primitive(PrimitiveType::UInt32) => Ok(Arc::new(to_primitive::<u32>(json_col, data_type))),
         Primitive(PrimitiveType::UInt64) => Ok(Arc::new(to_primitive::<u64>(json_col, data_type))),
+        Primitive(PrimitiveType::Float16) => Ok(Arc::new(to_float16(json_col, data_type))),
         Primitive(PrimitiveType::Float32) => Ok(Arc::new(to_primitive::<f32>(json_col, data_type))),
         Primitive(PrimitiveType::Float64) => Ok(Arc::new(to_primitive::<f64>(json_col, data_type))),
         Binary => Ok(to_binary::<i32>(json_col, data_type)),
@@ -309,9 +356,9 @@ pub fn to_array(
                 .iter()
                 .flat_map(|value| value.as_str().map(|x| hex::decode(x).unwrap()).unwrap())
                 .collect();
-            Ok(Arc::new(FixedSizeBinaryArray::from_data(
+            Ok(Arc::new(FixedSizeBinaryArray::try_new(
                 data_type, values, validity,
-            )))
+            )?))
         }
         List => to_list::<i32>(json_col, data_type, field, dictionaries),
         LargeList => to_list::<i64>(json_col, data_type, field, dictionaries),
@@ -328,9 +375,9 @@ pub fn to_array(
                 dictionaries,
             )?;
 
-            Ok(Arc::new(FixedSizeListArray::from_data(
+            Ok(Arc::new(FixedSizeListArray::try_new(
                 data_type, values, validity,
-            )))
+            )?))
         }
         Struct => {
             let validity = to_validity(&json_col.validity);
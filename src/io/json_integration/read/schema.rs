@@ -187,7 +187,15 @@ fn to_data_type(item: &Value, mut children: Vec<Field>) -> Result<DataType> {
                 )),
             };
 
-            DataType::Decimal(precision?, scale?)
+            match item.get("bitWidth").and_then(|x| x.as_u64()) {
+                None | Some(128) => DataType::Decimal(precision?, scale?),
+                Some(256) => DataType::Decimal256(precision?, scale?),
+                Some(_) => {
+                    return Err(ArrowError::OutOfSpec(
+                        "Expecting a bitWidth of 128 or 256 for decimal".to_string(),
+                    ))
+                }
+            }
         }
         "floatingpoint" => match item.get("precision") {
             Some(p) if p == "HALF" => DataType::Float16,
@@ -360,13 +368,18 @@ fn deserialize_field(value: &Value) -> Result<Field> {
         }
     };
 
-    let metadata = if let Some(metadata) = map.get("metadata") {
+    let mut metadata = if let Some(metadata) = map.get("metadata") {
         read_metadata(metadata)?
     } else {
         Metadata::default()
     };
 
     let extension = get_extension(&metadata);
+    if extension.is_some() {
+        // the extension is declared by the `DataType`
+        metadata.remove("ARROW:extension:name");
+        metadata.remove("ARROW:extension:metadata");
+    }
 
     let type_ = map
         .get("type")
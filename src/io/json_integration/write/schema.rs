@@ -89,6 +89,9 @@ fn serialize_data_type(data_type: &DataType) -> Value {
         DataType::Decimal(precision, scale) => {
             json!({"name": "decimal", "precision": precision, "scale": scale})
         }
+        DataType::Decimal256(precision, scale) => {
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 256})
+        }
         DataType::Extension(_, inner_data_type, _) => serialize_data_type(inner_data_type),
     }
 }
@@ -108,7 +111,7 @@ fn serialize_field(field: &Field, ipc_field: &IpcField) -> ArrowJsonField {
         }
         _ => vec![],
     };
-    let metadata = serialize_metadata(&field.metadata);
+    let metadata = serialize_metadata(&field.metadata, field.data_type());
 
     let dictionary = if let DataType::Dictionary(key_type, _, is_ordered) = field.data_type() {
         use crate::datatypes::IntegerType::*;
@@ -156,7 +159,23 @@ pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> ArrowJsonSc
     }
 }
 
-fn serialize_metadata(metadata: &Metadata) -> Option<Value> {
+fn serialize_metadata(metadata: &Metadata, data_type: &DataType) -> Option<Value> {
+    let data_type = match data_type {
+        DataType::Dictionary(_, inner, _) => inner.as_ref(),
+        data_type => data_type,
+    };
+    // the extension is declared in the metadata
+    let mut metadata = metadata.clone();
+    if let DataType::Extension(name, _, extension_metadata) = data_type {
+        metadata.insert("ARROW:extension:name".to_string(), name.clone());
+        if let Some(extension_metadata) = extension_metadata {
+            metadata.insert(
+                "ARROW:extension:metadata".to_string(),
+                extension_metadata.clone(),
+            );
+        }
+    }
+
     let array = metadata
         .iter()
         .map(|(k, v)| {
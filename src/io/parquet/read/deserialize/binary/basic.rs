@@ -65,8 +65,9 @@ pub(super) struct Required<'a> {
 
 impl<'a> Required<'a> {
     pub fn new(page: &'a DataPage) -> Self {
+        let (_, _, values) = utils::split_buffer(page);
         Self {
-            values: BinaryIter::new(page.buffer()),
+            values: BinaryIter::new(values),
             remaining: page.num_values(),
         }
     }
@@ -279,6 +280,22 @@ pub(super) fn finish<O: Offset, A: TraitBinaryArray<O>>(
     )
 }
 
+/// Deserializes all values of `page` into a single array
+pub(super) fn deserialize_page<O: Offset, A: TraitBinaryArray<O>>(
+    data_type: &DataType,
+    page: &DataPage,
+) -> Result<A> {
+    use utils::{Decoder, PageState};
+
+    let decoder = BinaryDecoder::<O>::default();
+    let mut state = decoder.build_state(page)?;
+    let length = state.len();
+    let mut values = decoder.with_capacity(length);
+    let mut validity = MutableBitmap::with_capacity(length);
+    decoder.extend_from_state(&mut state, &mut values, &mut validity, length);
+    finish(data_type, values, validity)
+}
+
 pub struct Iter<O: Offset, A: TraitBinaryArray<O>, I: DataPages> {
     iter: I,
     data_type: DataType,
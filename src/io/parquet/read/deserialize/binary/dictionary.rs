@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, sync::Arc};
 
-use parquet2::page::{BinaryPageDict, DictPage};
+use parquet2::page::{BinaryPageDict, DataPage, DictPage};
 
 use crate::{
     array::{Array, BinaryArray, DictionaryArray, DictionaryKey, Offset, Utf8Array},
@@ -12,6 +12,7 @@ use crate::{
 use super::super::dictionary::*;
 use super::super::utils::MaybeNext;
 use super::super::DataPages;
+use super::basic::deserialize_page;
 
 /// An iterator adapter over [`DataPages`] assumed to be encoded as parquet's dictionary-encoded binary representation
 #[derive(Debug)]
@@ -77,6 +78,18 @@ fn read_dict<O: Offset>(data_type: DataType, dict: &dyn DictPage) -> Arc<dyn Arr
     }
 }
 
+fn read_page<O: Offset>(data_type: &DataType, page: &DataPage) -> Result<Arc<dyn Array>> {
+    Ok(match data_type.to_physical_type() {
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8 => {
+            Arc::new(deserialize_page::<O, Utf8Array<O>>(data_type, page)?) as _
+        }
+        PhysicalType::Binary | PhysicalType::LargeBinary => {
+            Arc::new(deserialize_page::<O, BinaryArray<O>>(data_type, page)?) as _
+        }
+        _ => unreachable!(),
+    })
+}
+
 impl<K, O, I> Iterator for DictIter<K, O, I>
 where
     I: DataPages,
@@ -92,6 +105,7 @@ where
             &mut self.values,
             self.chunk_size,
             |dict| read_dict::<O>(self.data_type.clone(), dict),
+            |page| read_page::<O>(&self.data_type, page),
         );
         match maybe_state {
             MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use parquet2::{encoding::Encoding, page::DataPage, schema::Repetition};
+use parquet2::{encoding::Encoding, page::DataPage};
 
 use crate::{
     array::Offset, bitmap::MutableBitmap, datatypes::DataType, error::Result,
@@ -40,8 +40,7 @@ impl<'a, O: Offset> utils::Decoder<'a, &'a [u8], Binary<O>> for BinaryDecoder<O>
     type State = State<'a>;
 
     fn build_state(&self, page: &'a DataPage) -> Result<Self::State> {
-        let is_optional =
-            page.descriptor().type_().get_basic_info().repetition() == &Repetition::Optional;
+        let is_optional = is_optional_leaf(page.descriptor());
 
         match (page.encoding(), page.dictionary_page(), is_optional) {
             (Encoding::Plain, None, true) => {
@@ -76,9 +75,11 @@ impl<'a, O: Offset> utils::Decoder<'a, &'a [u8], Binary<O>> for BinaryDecoder<O>
         match state {
             State::Optional(page_validity, page_values) => {
                 let max_def = page_validity.max_def();
+                let min_def = page_validity.min_def();
                 read_optional_values(
                     page_validity.definition_levels.by_ref(),
                     max_def,
+                    min_def,
                     page_values.by_ref(),
                     values,
                     validity,
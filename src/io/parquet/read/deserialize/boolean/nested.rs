@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use parquet2::{encoding::Encoding, page::DataPage, schema::Repetition};
+use parquet2::{encoding::Encoding, page::DataPage};
 
 use crate::{
     array::BooleanArray,
@@ -25,8 +25,9 @@ struct Required<'a> {
 
 impl<'a> Required<'a> {
     pub fn new(page: &'a DataPage) -> Self {
+        let (_, _, values) = utils::split_buffer(page);
         Self {
-            values: page.buffer(),
+            values,
             offset: 0,
             length: page.num_values(),
         }
@@ -63,8 +64,7 @@ impl<'a> Decoder<'a, bool, MutableBitmap> for BooleanDecoder {
     type State = State<'a>;
 
     fn build_state(&self, page: &'a DataPage) -> Result<Self::State> {
-        let is_optional =
-            page.descriptor().type_().get_basic_info().repetition() == &Repetition::Optional;
+        let is_optional = is_optional_leaf(page.descriptor());
 
         match (page.encoding(), is_optional) {
             (Encoding::Plain, true) => {
@@ -98,9 +98,11 @@ impl<'a> Decoder<'a, bool, MutableBitmap> for BooleanDecoder {
         match state {
             State::Optional(page_validity, page_values) => {
                 let max_def = page_validity.max_def();
+                let min_def = page_validity.min_def();
                 read_optional_values(
                     page_validity.definition_levels.by_ref(),
                     max_def,
+                    min_def,
                     page_values.by_ref(),
                     values,
                     validity,
@@ -7,8 +7,11 @@ use parquet2::{
 };
 
 use crate::{
-    array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
-    bitmap::MutableBitmap,
+    array::{
+        growable::make_growable, new_empty_array, Array, DictionaryArray, DictionaryKey,
+        PrimitiveArray,
+    },
+    bitmap::{utils::SlicesIterator, MutableBitmap},
     error::{ArrowError, Result},
 };
 
@@ -25,6 +28,9 @@ where
 {
     Optional(Optional<'a, K>),
     Required(Required<'a, K>),
+    /// the keys of a page that is not dictionary-encoded, whose values were appended to the
+    /// dictionary, and whether the page is optional
+    Materialized(std::vec::IntoIter<Option<K>>, bool),
 }
 
 #[inline]
@@ -98,6 +104,7 @@ where
         match self {
             State::Optional(optional) => optional.validity.len(),
             State::Required(required) => required.values.size_hint().0,
+            State::Materialized(keys, _) => keys.len(),
         }
     }
 }
@@ -171,6 +178,14 @@ where
             State::Required(page) => {
                 values.extend(page.values.by_ref().take(remaining));
             }
+            State::Materialized(keys, is_optional) => {
+                for key in keys.by_ref().take(remaining) {
+                    if *is_optional {
+                        validity.push(key.is_some());
+                    }
+                    values.push(key.unwrap_or_default());
+                }
+            }
         }
     }
 }
@@ -179,59 +194,147 @@ where
 pub enum Dict {
     Empty,
     Complete(Arc<dyn Array>),
+    /// the dictionary, the arrays whose valid slots are yet to be appended to it, and the
+    /// length of the dictionary once they are
+    Extended(Arc<dyn Array>, Vec<Arc<dyn Array>>, usize),
 }
 
 impl Dict {
-    pub fn unwrap(&self) -> Arc<dyn Array> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::Complete(array) => array.len(),
+            Self::Extended(_, _, length) => *length,
+        }
+    }
+
+    /// Returns the dictionary, appending the values of [`Dict::Extended`] to it first.
+    pub fn unwrap(&mut self) -> Arc<dyn Array> {
+        if let Self::Extended(array, values, length) = self {
+            *self = Self::Complete(concatenate_valid(array, values, *length));
+        }
         match self {
-            Self::Empty => panic!(),
             Self::Complete(array) => array.clone(),
+            _ => panic!(),
         }
     }
 }
 
+/// Returns `array` followed by the valid slots of each of `values`.
+fn concatenate_valid(
+    array: &Arc<dyn Array>,
+    values: &[Arc<dyn Array>],
+    length: usize,
+) -> Arc<dyn Array> {
+    let mut arrays = vec![array.as_ref()];
+    arrays.extend(values.iter().map(|x| x.as_ref()));
+
+    let mut growable = make_growable(&arrays, false, length);
+    growable.extend(0, 0, array.len());
+    for (index, values) in values.iter().enumerate() {
+        match values.validity() {
+            Some(validity) => SlicesIterator::new(validity)
+                .for_each(|(start, len)| growable.extend(index + 1, start, len)),
+            None => growable.extend(index + 1, 0, values.len()),
+        }
+    }
+    growable.as_arc()
+}
+
+/// Appends the valid slots of `values` to the values of `dict` and returns the keys of `values`
+/// in it, `None` for null slots.
+/// The dictionary is only concatenated once it is requested via [`Dict::unwrap`], i.e. once per
+/// returned array instead of once per page.
+fn extend_dict<K: DictionaryKey>(
+    dict: &mut Dict,
+    values: Arc<dyn Array>,
+) -> Result<Vec<Option<K>>> {
+    let offset = dict.len();
+    let valid = values.len() - values.null_count();
+    if valid > 0 && K::from_usize(offset + valid - 1).is_none() {
+        return Err(ArrowError::Overflow);
+    }
+
+    let mut key = offset;
+    let keys = (0..values.len())
+        .map(|i| {
+            values.is_valid(i).then(|| {
+                key += 1;
+                K::from_usize(key - 1).unwrap()
+            })
+        })
+        .collect();
+
+    if valid > 0 {
+        *dict = match std::mem::replace(dict, Dict::Empty) {
+            Dict::Empty => Dict::Extended(
+                new_empty_array(values.data_type().clone()).into(),
+                vec![values],
+                valid,
+            ),
+            Dict::Complete(array) => Dict::Extended(array, vec![values], offset + valid),
+            Dict::Extended(array, mut pending, _) => {
+                pending.push(values);
+                Dict::Extended(array, pending, offset + valid)
+            }
+        };
+    }
+    Ok(keys)
+}
+
 fn finish_key<K: DictionaryKey>(values: Vec<K>, validity: MutableBitmap) -> PrimitiveArray<K> {
     PrimitiveArray::from_data(K::PRIMITIVE.into(), values.into(), validity.into())
 }
 
+/// Returns the next [`DictionaryArray`] whose keys are read from the pages of `iter` and whose
+/// values are the dictionary `dict`, read from the first dictionary page via `read_dict`.
+/// Pages that are not dictionary-encoded are deserialized via `read_page` and their values
+/// appended to the dictionary.
 #[inline]
 pub(super) fn next_dict<
     'a,
     K: DictionaryKey,
     I: DataPages,
     F: Fn(&dyn DictPage) -> Arc<dyn Array>,
+    G: Fn(&DataPage) -> Result<Arc<dyn Array>>,
 >(
     iter: &'a mut I,
     items: &mut VecDeque<(Vec<K>, MutableBitmap)>,
     dict: &mut Dict,
     chunk_size: usize,
     read_dict: F,
+    read_page: G,
 ) -> MaybeNext<Result<DictionaryArray<K>>> {
-    if items.len() > 1 {
-        let (values, validity) = items.pop_back().unwrap();
+    if items.len() > 1 || items.front().map(|x| x.0.len()) == Some(chunk_size) {
+        let (values, validity) = items.pop_front().unwrap();
         let keys = finish_key(values, validity);
-        return MaybeNext::Some(Ok(DictionaryArray::from_data(keys, dict.unwrap())));
+        return MaybeNext::Some(DictionaryArray::try_new(keys, dict.unwrap()));
     }
     match (items.pop_back(), iter.next()) {
         (_, Err(e)) => MaybeNext::Some(Err(e.into())),
         (None, Ok(None)) => MaybeNext::None,
         (state, Ok(Some(page))) => {
             // consume the dictionary page
-            match (&dict, page.dictionary_page()) {
-                (Dict::Empty, None) => {
-                    return MaybeNext::Some(Err(ArrowError::nyi(
-                        "dictionary arrays from non-dict-encoded pages",
-                    )));
-                }
-                (Dict::Empty, Some(dict_page)) => {
-                    *dict = Dict::Complete(read_dict(dict_page.as_ref()))
-                }
-                (Dict::Complete(_), _) => {}
-            };
+            if let (Dict::Empty, Some(dict_page)) = (&dict, page.dictionary_page()) {
+                *dict = Dict::Complete(read_dict(dict_page.as_ref()))
+            }
 
             let maybe_array = {
                 // there is a new page => consume the page from the start
-                let maybe_page = PrimitiveDecoder::default().build_state(page);
+                let maybe_page = match page.encoding() {
+                    Encoding::PlainDictionary | Encoding::RleDictionary => {
+                        PrimitiveDecoder::default().build_state(page)
+                    }
+                    // e.g. the writer fell back to plain once the dictionary grew too large
+                    _ => read_page(page)
+                        .and_then(|values| extend_dict(dict, values))
+                        .map(|keys| {
+                            let is_optional =
+                                page.descriptor().type_().get_basic_info().repetition()
+                                    == &Repetition::Optional;
+                            State::Materialized(keys.into_iter(), is_optional)
+                        }),
+                };
                 let page = match maybe_page {
                     Ok(page) => page,
                     Err(e) => return MaybeNext::Some(Err(e)),
@@ -253,7 +356,7 @@ pub(super) fn next_dict<
                         validity.into(),
                     );
 
-                    MaybeNext::Some(Ok(DictionaryArray::from_data(keys, dict.unwrap())))
+                    MaybeNext::Some(DictionaryArray::try_new(keys, dict.unwrap()))
                 }
                 Ok(None) => MaybeNext::More,
                 Err(e) => MaybeNext::Some(Err(e)),
@@ -266,7 +369,7 @@ pub(super) fn next_dict<
 
             let keys = finish_key(values, validity);
 
-            MaybeNext::Some(Ok(DictionaryArray::from_data(keys, dict.unwrap())))
+            MaybeNext::Some(DictionaryArray::try_new(keys, dict.unwrap()))
         }
     }
 }
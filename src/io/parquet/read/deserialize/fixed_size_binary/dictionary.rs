@@ -6,7 +6,7 @@ use crate::{
     array::{Array, DictionaryArray, DictionaryKey, FixedSizeBinaryArray},
     bitmap::MutableBitmap,
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
 };
 
 use super::super::dictionary::*;
@@ -75,6 +75,11 @@ where
             &mut self.values,
             self.chunk_size,
             |dict| read_dict(self.data_type.clone(), dict),
+            |_| {
+                Err(ArrowError::nyi(
+                    "dictionary arrays from non-dict-encoded pages",
+                ))
+            },
         );
         match maybe_state {
             MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
@@ -10,7 +10,10 @@ mod simple;
 mod utils;
 
 use crate::{
-    array::{Array, BinaryArray, ListArray, StructArray, Utf8Array},
+    array::{
+        growable::make_growable, Array, BinaryArray, FixedSizeListArray, ListArray, StructArray,
+        Utf8Array,
+    },
     datatypes::{DataType, Field},
     error::{ArrowError, Result},
 };
@@ -35,15 +38,27 @@ pub fn get_page_iterator<R: Read + Seek>(
     )?)
 }
 
+/// Creates a new [`Stream`](futures::Stream) of compressed pages of the column chunk
+/// `column_metadata`, read from `reader`.
+pub async fn get_page_stream<'a, R: AsyncRead + AsyncSeek + Send + Unpin>(
+    column_metadata: &'a ColumnChunkMetaData,
+    reader: &'a mut R,
+    pages_filter: Option<PageFilter>,
+    buffer: Vec<u8>,
+) -> Result<impl futures::Stream<Item = std::result::Result<CompressedDataPage, ParquetError>> + 'a>
+{
+    let pages_filter = pages_filter.unwrap_or_else(|| Arc::new(|_, _| true));
+    Ok(_get_page_stream(column_metadata, reader, buffer, pages_filter).await?)
+}
+
 fn create_list(
     data_type: DataType,
     nested: &mut NestedState,
     values: Arc<dyn Array>,
 ) -> Result<Arc<dyn Array>> {
-    Ok(match data_type {
+    let (offsets, validity) = nested.nested.pop().unwrap().inner();
+    Ok(match data_type.to_logical_type() {
         DataType::List(_) => {
-            let (offsets, validity) = nested.nested.pop().unwrap().inner();
-
             let offsets = offsets.iter().map(|x| *x as i32).collect::<Vec<_>>();
             Arc::new(ListArray::<i32>::from_data(
                 data_type,
@@ -52,11 +67,30 @@ fn create_list(
                 validity,
             ))
         }
-        DataType::LargeList(_) => {
-            let (offsets, validity) = nested.nested.pop().unwrap().inner();
-
-            Arc::new(ListArray::<i64>::from_data(
-                data_type, offsets, values, validity,
+        DataType::LargeList(_) => Arc::new(ListArray::<i64>::from_data(
+            data_type, offsets, values, validity,
+        )),
+        DataType::FixedSizeList(_, size) => {
+            let size = *size;
+            // null items are empty in parquet and are filled with nulls
+            let mut growable = make_growable(&[values.as_ref()], true, (offsets.len() - 1) * size);
+            for (i, window) in offsets.windows(2).enumerate() {
+                let (start, length) = (window[0] as usize, (window[1] - window[0]) as usize);
+                if length == size {
+                    growable.extend(0, start, length)
+                } else if length == 0 && validity.as_ref().map(|x| !x.get_bit(i)).unwrap_or(false) {
+                    growable.extend_validity(size)
+                } else {
+                    return Err(ArrowError::OutOfSpec(format!(
+                        "The list of item {} has {} values but its data type {:?} requires {}",
+                        i, length, data_type, size
+                    )));
+                }
+            }
+            Arc::new(FixedSizeListArray::from_data(
+                data_type,
+                growable.as_arc(),
+                validity,
             ))
         }
         _ => {
@@ -88,30 +122,53 @@ impl<'a> Iterator for StructIterator<'a> {
             .iters
             .iter_mut()
             .map(|iter| iter.next())
-            .collect::<Vec<_>>();
+            .collect::<Option<Vec<_>>>()?;
+        let mut values = match values.into_iter().collect::<Result<Vec<_>>>() {
+            Ok(values) => values.into_iter(),
+            Err(e) => return Some(Err(e)),
+        };
 
-        if values.iter().any(|x| x.is_none()) {
-            return None;
-        }
-        let values = values
-            .into_iter()
-            .map(|x| x.unwrap().map(|x| x.1))
-            .collect::<Result<Vec<_>>>();
+        // all fields share the levels up to (and including) this struct
+        let (mut nested, first) = values.next()?;
+        let (_, validity) = nested.nested.pop().unwrap().inner();
+        let values = std::iter::once(first).chain(values.map(|x| x.1)).collect();
 
-        match values {
-            Ok(values) => Some(Ok((
-                NestedState::new(vec![]), // todo
-                Arc::new(StructArray::from_data(
-                    DataType::Struct(self.fields.clone()),
-                    values,
-                    None,
-                )),
-            ))),
-            Err(e) => Some(Err(e)),
-        }
+        Some(Ok((
+            nested,
+            Arc::new(StructArray::from_data(
+                DataType::Struct(self.fields.clone()),
+                values,
+                validity,
+            )),
+        )))
     }
 }
 
+macro_rules! nested_primitive {
+    ($physical:ty, $native:ty, $columns:expr, $types:expr, $init:expr, $field:expr, $chunk_size:expr) => {{
+        $types.pop();
+        primitive::iter_to_arrays_nested(
+            $columns.pop().unwrap(),
+            $init.pop().unwrap(),
+            $field.data_type().clone(),
+            $chunk_size,
+            |x: $physical| x as $native,
+        )
+    }};
+}
+
+macro_rules! nested_binary {
+    ($offset:ty, $array:ty, $columns:expr, $types:expr, $init:expr, $field:expr, $chunk_size:expr) => {{
+        $types.pop();
+        binary::iter_to_arrays_nested::<$offset, $array, _>(
+            $columns.pop().unwrap(),
+            $init.pop().unwrap(),
+            $field.data_type().clone(),
+            $chunk_size,
+        )
+    }};
+}
+
 fn columns_to_iter_recursive<'a, I: 'a>(
     mut columns: Vec<I>,
     mut types: Vec<&ParquetType>,
@@ -140,47 +197,45 @@ where
             types.pop();
             boolean::iter_to_arrays_nested(columns.pop().unwrap(), init.pop().unwrap(), chunk_size)
         }
-        Int16 => {
-            types.pop();
-            primitive::iter_to_arrays_nested(
-                columns.pop().unwrap(),
-                init.pop().unwrap(),
-                field.data_type().clone(),
-                chunk_size,
-                |x: i32| x as i16,
-            )
+        Int8 => nested_primitive!(i32, i8, columns, types, init, field, chunk_size),
+        Int16 => nested_primitive!(i32, i16, columns, types, init, field, chunk_size),
+        Int32 | Date32 | Time32(_) => {
+            nested_primitive!(i32, i32, columns, types, init, field, chunk_size)
         }
-        Int64 => {
-            types.pop();
-            primitive::iter_to_arrays_nested(
-                columns.pop().unwrap(),
-                init.pop().unwrap(),
-                field.data_type().clone(),
-                chunk_size,
-                |x: i64| x,
-            )
-        }
-        Utf8 => {
-            types.pop();
-            binary::iter_to_arrays_nested::<i32, Utf8Array<i32>, _>(
-                columns.pop().unwrap(),
-                init.pop().unwrap(),
-                field.data_type().clone(),
-                chunk_size,
-            )
+        Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
+            nested_primitive!(i64, i64, columns, types, init, field, chunk_size)
         }
+        UInt8 => nested_primitive!(i32, u8, columns, types, init, field, chunk_size),
+        UInt16 => nested_primitive!(i32, u16, columns, types, init, field, chunk_size),
+        UInt32 => nested_primitive!(i32, u32, columns, types, init, field, chunk_size),
+        UInt64 => nested_primitive!(i64, u64, columns, types, init, field, chunk_size),
+        Float32 => nested_primitive!(f32, f32, columns, types, init, field, chunk_size),
+        Float64 => nested_primitive!(f64, f64, columns, types, init, field, chunk_size),
+        Utf8 => nested_binary!(i32, Utf8Array<i32>, columns, types, init, field, chunk_size),
+        LargeUtf8 => nested_binary!(i64, Utf8Array<i64>, columns, types, init, field, chunk_size),
+        Binary => nested_binary!(
+            i32,
+            BinaryArray<i32>,
+            columns,
+            types,
+            init,
+            field,
+            chunk_size
+        ),
         LargeBinary => {
-            types.pop();
-            binary::iter_to_arrays_nested::<i64, BinaryArray<i64>, _>(
-                columns.pop().unwrap(),
-                init.pop().unwrap(),
-                field.data_type().clone(),
-                chunk_size,
+            nested_binary!(
+                i64,
+                BinaryArray<i64>,
+                columns,
+                types,
+                init,
+                field,
+                chunk_size
             )
         }
-        List(inner) => {
+        List(inner) | LargeList(inner) | FixedSizeList(inner, _) => {
             let iter = columns_to_iter_recursive(
-                vec![columns.pop().unwrap()],
+                columns,
                 types,
                 inner.as_ref().clone(),
                 init,
@@ -198,11 +253,13 @@ where
                 .iter()
                 .rev()
                 .map(|f| {
+                    let num_leaves = num_leaves(f.data_type());
+                    let at = columns.len() - num_leaves;
                     columns_to_iter_recursive(
-                        vec![columns.pop().unwrap()],
-                        vec![types.pop().unwrap()],
+                        columns.split_off(at),
+                        types.split_off(at),
                         f.clone(),
-                        vec![init.pop().unwrap()],
+                        init.split_off(at),
                         chunk_size,
                     )
                 })
@@ -210,10 +267,26 @@ where
             let columns = columns.into_iter().rev().collect();
             Box::new(StructIterator::new(columns, fields.clone()))
         }
-        _ => todo!(),
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Reading nested parquet columns of data type {:?}",
+                other
+            )))
+        }
     })
 }
 
+/// Returns the number of parquet (leaf) columns of `data_type`
+fn num_leaves(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::List(inner) | DataType::LargeList(inner) | DataType::FixedSizeList(inner, _) => {
+            num_leaves(inner.data_type())
+        }
+        DataType::Struct(fields) => fields.iter().map(|x| num_leaves(x.data_type())).sum(),
+        _ => 1,
+    }
+}
+
 fn field_to_init(field: &Field) -> Vec<InitNested> {
     use crate::datatypes::PhysicalType::*;
     match field.data_type.to_physical_type() {
@@ -237,7 +310,7 @@ fn field_to_init(field: &Field) -> Vec<InitNested> {
         }
         Struct => {
             let inner = if let DataType::Struct(fields) = field.data_type.to_logical_type() {
-                fields.iter().rev().map(field_to_init).collect::<Vec<_>>()
+                fields.iter().map(field_to_init).collect::<Vec<_>>()
             } else {
                 unreachable!()
             };
@@ -1,7 +1,11 @@
 use std::{collections::VecDeque, sync::Arc};
 
 use parquet2::{
-    encoding::hybrid_rle::HybridRleDecoder, page::DataPage, read::levels::get_bit_width,
+    encoding::hybrid_rle::HybridRleDecoder,
+    metadata::ColumnDescriptor,
+    page::DataPage,
+    read::levels::get_bit_width,
+    schema::{types::ParquetType, Repetition},
 };
 
 use crate::{
@@ -16,25 +20,22 @@ use super::utils::{split_buffer, Decoder, MaybeNext, Pushable};
 
 /// trait describing deserialized repetition and definition levels
 pub trait Nested: std::fmt::Debug + Send + Sync {
+    /// The offsets (of a list) and the validity of this level
     fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>);
 
-    fn last_offset(&self) -> i64;
-
+    /// Pushes an item whose children (of a list) start at `length`
     fn push(&mut self, length: i64, is_valid: bool);
 
-    fn offsets(&mut self) -> &[i64];
-
+    /// Sets the end of the children (of a list) of the last item to `length`
     fn close(&mut self, length: i64);
 
     fn is_nullable(&self) -> bool;
 
-    /// number of rows
-    fn len(&self) -> usize;
-
-    fn len1(&self) -> usize;
+    /// Whether this level is a list, i.e. a repeated parquet field
+    fn is_repeated(&self) -> bool;
 
-    /// number of values associated to the primitive type this nested tracks
-    fn num_values(&self) -> usize;
+    /// number of items
+    fn len(&self) -> usize;
 }
 
 #[derive(Debug, Default)]
@@ -57,21 +58,16 @@ impl Nested for NestedPrimitive {
         (Default::default(), Default::default())
     }
 
-    #[inline]
-    fn last_offset(&self) -> i64 {
-        0
-    }
-
     fn is_nullable(&self) -> bool {
         self.is_nullable
     }
 
-    fn push(&mut self, _value: i64, _is_valid: bool) {
-        self.length += 1
+    fn is_repeated(&self) -> bool {
+        false
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &[]
+    fn push(&mut self, _value: i64, _is_valid: bool) {
+        self.length += 1
     }
 
     fn close(&mut self, _length: i64) {}
@@ -79,35 +75,29 @@ impl Nested for NestedPrimitive {
     fn len(&self) -> usize {
         self.length
     }
-
-    fn len1(&self) -> usize {
-        self.length
-    }
-
-    fn num_values(&self) -> usize {
-        self.length
-    }
 }
 
+/// A nullable list
 #[derive(Debug, Default)]
 pub struct NestedOptional {
     pub validity: MutableBitmap,
     pub offsets: Vec<i64>,
+    last: i64,
 }
 
 impl Nested for NestedOptional {
     fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>) {
-        let offsets = std::mem::take(&mut self.offsets);
+        let mut offsets = std::mem::take(&mut self.offsets);
+        offsets.push(self.last);
         let validity = std::mem::take(&mut self.validity);
         (offsets.into(), validity.into())
     }
 
-    #[inline]
-    fn last_offset(&self) -> i64 {
-        *self.offsets.last().unwrap()
+    fn is_nullable(&self) -> bool {
+        true
     }
 
-    fn is_nullable(&self) -> bool {
+    fn is_repeated(&self) -> bool {
         true
     }
 
@@ -116,43 +106,38 @@ impl Nested for NestedOptional {
         self.validity.push(is_valid);
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &self.offsets
-    }
-
     fn close(&mut self, length: i64) {
-        self.offsets.push(length)
+        self.last = length
     }
 
     fn len(&self) -> usize {
-        self.offsets.len().saturating_sub(1)
-    }
-
-    fn len1(&self) -> usize {
         self.offsets.len()
     }
-
-    fn num_values(&self) -> usize {
-        self.offsets.last().copied().unwrap_or(0) as usize
-    }
 }
 
 impl NestedOptional {
     pub fn with_capacity(capacity: usize) -> Self {
         let offsets = Vec::<i64>::with_capacity(capacity + 1);
         let validity = MutableBitmap::with_capacity(capacity);
-        Self { validity, offsets }
+        Self {
+            validity,
+            offsets,
+            last: 0,
+        }
     }
 }
 
+/// A non-nullable list
 #[derive(Debug, Default)]
 pub struct NestedValid {
     pub offsets: Vec<i64>,
+    last: i64,
 }
 
 impl Nested for NestedValid {
     fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>) {
-        let offsets = std::mem::take(&mut self.offsets);
+        let mut offsets = std::mem::take(&mut self.offsets);
+        offsets.push(self.last);
         (offsets.into(), None)
     }
 
@@ -160,46 +145,82 @@ impl Nested for NestedValid {
         false
     }
 
-    #[inline]
-    fn last_offset(&self) -> i64 {
-        *self.offsets.last().unwrap()
+    fn is_repeated(&self) -> bool {
+        true
     }
 
     fn push(&mut self, value: i64, _is_valid: bool) {
         self.offsets.push(value);
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &self.offsets
-    }
-
     fn close(&mut self, length: i64) {
-        self.offsets.push(length)
+        self.last = length
     }
 
     fn len(&self) -> usize {
-        self.offsets.len().saturating_sub(1)
-    }
-
-    fn len1(&self) -> usize {
         self.offsets.len()
     }
-
-    fn num_values(&self) -> usize {
-        self.offsets.last().copied().unwrap_or(0) as usize
-    }
 }
 
 impl NestedValid {
     pub fn with_capacity(capacity: usize) -> Self {
         let offsets = Vec::<i64>::with_capacity(capacity + 1);
-        Self { offsets }
+        Self { offsets, last: 0 }
     }
 }
 
+/// A struct, whose items have one child each
+#[derive(Debug, Default)]
+pub struct NestedStruct {
+    is_nullable: bool,
+    validity: MutableBitmap,
+}
+
+impl NestedStruct {
+    pub fn with_capacity(is_nullable: bool, capacity: usize) -> Self {
+        Self {
+            is_nullable,
+            validity: MutableBitmap::with_capacity(capacity),
+        }
+    }
+}
+
+impl Nested for NestedStruct {
+    fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>) {
+        let validity = std::mem::take(&mut self.validity);
+        let validity = if self.is_nullable {
+            validity.into()
+        } else {
+            None
+        };
+        (Default::default(), validity)
+    }
+
+    fn is_nullable(&self) -> bool {
+        self.is_nullable
+    }
+
+    fn is_repeated(&self) -> bool {
+        false
+    }
+
+    fn push(&mut self, _value: i64, is_valid: bool) {
+        self.validity.push(is_valid)
+    }
+
+    fn close(&mut self, _length: i64) {}
+
+    fn len(&self) -> usize {
+        self.validity.len()
+    }
+}
+
+/// Pushes `remaining` (possibly null) values, i.e. the values whose definition level is at
+/// least `min_def`, of which only the ones of definition level `max_def` are in `new_values`.
 pub(super) fn read_optional_values<D, C, G, P>(
-    def_levels: D,
+    mut def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     values: &mut P,
     validity: &mut MutableBitmap,
@@ -210,22 +231,60 @@ pub(super) fn read_optional_values<D, C, G, P>(
     C: Default,
     P: Pushable<C>,
 {
-    for def in def_levels {
+    while remaining > 0 {
+        let def = if let Some(def) = def_levels.next() {
+            def
+        } else {
+            break;
+        };
         if def == max_def {
             values.push(new_values.next().unwrap());
             validity.push(true);
             remaining -= 1;
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
             values.push(C::default());
             validity.push(false);
             remaining -= 1;
         }
-        if remaining == 0 {
-            break;
+    }
+}
+
+/// Returns the definition level from which the leaf column of `descriptor` has a (possibly
+/// null) value, i.e. the definition level of its innermost repeated field.
+fn min_def_level(descriptor: &ColumnDescriptor) -> u32 {
+    let mut type_ = descriptor.base_type();
+    let mut path = descriptor.path_in_schema().iter().skip(1);
+    let mut def = 0;
+    let mut min_def = 0;
+    loop {
+        match type_.get_basic_info().repetition() {
+            Repetition::Optional => def += 1,
+            Repetition::Repeated => {
+                def += 1;
+                min_def = def;
+            }
+            Repetition::Required => {}
+        }
+        let child = match (type_, path.next()) {
+            (ParquetType::GroupType { fields, .. }, Some(name)) => {
+                fields.iter().find(|x| x.name() == name)
+            }
+            _ => None,
+        };
+        if let Some(child) = child {
+            type_ = child
+        } else {
+            return min_def;
         }
     }
 }
 
+/// Whether the leaf column of `descriptor` has null values, i.e. values whose definition
+/// level is lower than its maximum.
+pub fn is_optional_leaf(descriptor: &ColumnDescriptor) -> bool {
+    descriptor.max_def_level() as u32 > min_def_level(descriptor)
+}
+
 #[derive(Debug, Clone)]
 pub enum InitNested {
     Primitive(bool),
@@ -253,11 +312,10 @@ fn init_nested_recursive(init: &InitNested, capacity: usize, container: &mut Vec
             init_nested_recursive(inner, capacity, container)
         }
         InitNested::Struct(inner, is_nullable) => {
-            if *is_nullable {
-                container.push(Box::new(NestedOptional::with_capacity(capacity)) as Box<dyn Nested>)
-            } else {
-                container.push(Box::new(NestedValid::with_capacity(capacity)) as Box<dyn Nested>)
-            }
+            container.push(Box::new(NestedStruct::with_capacity(
+                *is_nullable,
+                capacity,
+            )));
             init_nested_recursive(inner, capacity, container)
         }
     }
@@ -270,10 +328,7 @@ fn init_nested(init: &InitNested, capacity: usize) -> NestedState {
 }
 
 pub struct NestedPage<'a> {
-    repetitions: HybridRleDecoder<'a>,
-    _max_rep_level: u32,
-    definitions: HybridRleDecoder<'a>,
-    max_def_level: u32,
+    iter: std::iter::Peekable<std::iter::Zip<HybridRleDecoder<'a>, HybridRleDecoder<'a>>>,
 }
 
 impl<'a> NestedPage<'a> {
@@ -283,25 +338,19 @@ impl<'a> NestedPage<'a> {
         let max_rep_level = page.descriptor().max_rep_level();
         let max_def_level = page.descriptor().max_def_level();
 
+        let reps =
+            HybridRleDecoder::new(rep_levels, get_bit_width(max_rep_level), page.num_values());
+        let defs =
+            HybridRleDecoder::new(def_levels, get_bit_width(max_def_level), page.num_values());
+
         Self {
-            repetitions: HybridRleDecoder::new(
-                rep_levels,
-                get_bit_width(max_rep_level),
-                page.num_values(),
-            ),
-            _max_rep_level: max_rep_level as u32,
-            definitions: HybridRleDecoder::new(
-                def_levels,
-                get_bit_width(max_def_level),
-                page.num_values(),
-            ),
-            max_def_level: max_def_level as u32,
+            iter: reps.zip(defs).peekable(),
         }
     }
 
     // number of values (!= number of rows)
     pub fn len(&self) -> usize {
-        self.repetitions.size_hint().0
+        self.iter.size_hint().0
     }
 }
 
@@ -323,12 +372,7 @@ impl NestedState {
 
     /// The number of values associated with the primitive type
     pub fn num_values(&self) -> usize {
-        self.nested[0].num_values()
-    }
-
-    pub fn depth(&self) -> usize {
-        // outermost is the number of rows
-        self.nested.len()
+        self.nested.last().unwrap().len()
     }
 }
 
@@ -430,65 +474,52 @@ pub fn extend_offsets1<'a>(
 }
 
 fn extend_offsets2<'a>(page: &mut NestedPage<'a>, nested: &mut NestedState, additional: usize) {
-    let max_depth = nested.depth() - 1;
-    let mut values_count = vec![0; max_depth + 1];
-
-    let is_optional = nested.nested.last().unwrap().is_nullable();
-    let max_def = page.max_def_level;
-
-    let rate = if max_def == 1 { 1 } else { 2 };
-
-    let mut iter = page.repetitions.by_ref().zip(page.definitions.by_ref());
+    let nested = &mut nested.nested;
+
+    // the repetition level from which a value starts a new item of each level, and the
+    // definition levels from which that item exists and from which it is valid
+    let mut levels = Vec::with_capacity(nested.len());
+    let (mut rep, mut def, mut min_def) = (0, 0, 0);
+    for nest in nested.iter() {
+        let valid_def = def + nest.is_nullable() as u32;
+        levels.push((rep, min_def, valid_def));
+        def = valid_def;
+        if nest.is_repeated() {
+            rep += 1;
+            def += 1;
+            min_def = def;
+        }
+    }
 
     let mut rows = 0;
-    while rows < additional {
-        // unwrap is ok because by definition there has to be a closing statement
-        let (rep, def) = iter.next().unwrap();
+    while let Some(&(rep, def)) = page.iter.peek() {
         if rep == 0 {
-            rows += 1
+            if rows == additional {
+                break;
+            }
+            rows += 1;
         }
+        page.iter.next();
 
-        let closures = rep + 1 + (def / rate);
-
-        nested
-            .nested
-            .iter_mut()
-            .enumerate()
-            .zip(values_count.iter())
-            .skip(rep as usize)
-            .take(max_depth as usize - rep as usize)
-            .take(closures as usize)
-            .for_each(|((depth, nested), length)| {
-                let is_null = def - rep == depth as u32;
-                nested.push(*length, !is_null);
-            });
-
-        // add to the primitive
-        if (is_optional && def >= max_def - 1) || (!is_optional && def == max_def) {
-            let is_valid = def == max_def;
-            let length = values_count.last_mut().unwrap();
-            nested.nested.last_mut().unwrap().push(*length, is_valid);
-            *length += 1;
+        for (depth, (rep_level, min_def, valid_def)) in levels.iter().copied().enumerate() {
+            if rep > rep_level {
+                // the value belongs to the current item of this level
+                continue;
+            }
+            if def < min_def {
+                // an ancestor is null or empty
+                break;
+            }
+            let length = nested.get(depth + 1).map(|x| x.len() as i64).unwrap_or(0);
+            nested[depth].push(length, def >= valid_def);
         }
-
-        values_count
-            .iter_mut()
-            .rev()
-            .skip(1)
-            .zip(nested.nested.iter().rev())
-            .for_each(|(length, nested)| {
-                *length = nested.len1() as i64;
-            });
     }
 
-    // close validities
-    nested
-        .nested
-        .iter_mut()
-        .zip(values_count.iter())
-        .for_each(|(nested, length)| {
-            nested.close(*length);
-        });
+    // close the last items
+    for depth in 0..nested.len() {
+        let length = nested.get(depth + 1).map(|x| x.len() as i64).unwrap_or(0);
+        nested[depth].close(length);
+    }
 }
 
 // The state of an optional DataPage with a boolean physical type
@@ -496,6 +527,7 @@ fn extend_offsets2<'a>(page: &mut NestedPage<'a>, nested: &mut NestedState, addi
 pub struct Optional<'a> {
     pub definition_levels: HybridRleDecoder<'a>,
     max_def: u32,
+    min_def: u32,
 }
 
 impl<'a> Optional<'a> {
@@ -511,6 +543,7 @@ impl<'a> Optional<'a> {
                 page.num_values(),
             ),
             max_def: max_def as u32,
+            min_def: min_def_level(page.descriptor()),
         }
     }
 
@@ -523,6 +556,11 @@ impl<'a> Optional<'a> {
     pub fn max_def(&self) -> u32 {
         self.max_def
     }
+
+    #[inline]
+    pub fn min_def(&self) -> u32 {
+        self.min_def
+    }
 }
 
 #[inline]
@@ -540,10 +578,10 @@ where
     P: Pushable<C>,
     D: Decoder<'a, C, P>,
 {
-    // back[a1, a2, a3, ...]front
+    // front[a1, a2, a3, ...]back
     if items.len() > 1 {
-        let nested = nested_items.pop_back().unwrap();
-        let (values, validity) = items.pop_back().unwrap();
+        let nested = nested_items.pop_front().unwrap();
+        let (values, validity) = items.pop_front().unwrap();
         return MaybeNext::Some(Ok((nested, values, validity)));
     }
     match (nested_items.pop_back(), items.pop_back(), iter.next()) {
@@ -9,7 +9,7 @@ use crate::{
     array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
     bitmap::MutableBitmap,
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
     types::NativeType,
 };
 
@@ -95,6 +95,11 @@ where
             &mut self.values,
             self.chunk_size,
             |dict| read_dict::<P, T, _>(self.data_type.clone(), self.op, dict),
+            |_| {
+                Err(ArrowError::nyi(
+                    "dictionary arrays from non-dict-encoded pages",
+                ))
+            },
         );
         match maybe_state {
             MaybeNext::Some(Ok(dict)) => Some(Ok(dict)),
@@ -1,8 +1,7 @@
 use std::collections::VecDeque;
 
 use parquet2::{
-    encoding::Encoding, page::DataPage, schema::Repetition, types::decode,
-    types::NativeType as ParquetNativeType,
+    encoding::Encoding, page::DataPage, types::decode, types::NativeType as ParquetNativeType,
 };
 
 use crate::{
@@ -79,8 +78,7 @@ where
     type State = State<'a, P>;
 
     fn build_state(&self, page: &'a DataPage) -> Result<Self::State> {
-        let is_optional =
-            page.descriptor().type_().get_basic_info().repetition() == &Repetition::Optional;
+        let is_optional = is_optional_leaf(page.descriptor());
 
         match (page.encoding(), page.dictionary_page(), is_optional) {
             /*(Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), false) => {
@@ -120,9 +118,11 @@ where
         match state {
             State::Optional(page_validity, page_values) => {
                 let max_def = page_validity.max_def();
+                let min_def = page_validity.min_def();
                 read_optional_values(
                     page_validity.definition_levels.by_ref(),
                     max_def,
+                    min_def,
                     page_values.values.by_ref().map(decode).map(self.op),
                     values,
                     validity,
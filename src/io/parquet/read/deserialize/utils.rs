@@ -306,9 +306,10 @@ pub(super) fn next<'a, I: DataPages, C: Default, P: Pushable<C>, D: Decoder<'a,
     chunk_size: usize,
     decoder: &D,
 ) -> MaybeNext<Result<(P, MutableBitmap), ArrowError>> {
-    // back[a1, a2, a3, ...]front
-    if items.len() > 1 {
-        let item = items.pop_back().unwrap();
+    // front[a1, a2, a3, ...]back
+    // an item is complete if it is followed by others or if it has `chunk_size` items
+    if items.len() > 1 || items.front().map(|x| x.0.len()) == Some(chunk_size) {
+        let item = items.pop_front().unwrap();
         return MaybeNext::Some(Ok(item));
     }
     match (items.pop_back(), iter.next()) {
@@ -29,11 +29,12 @@ pub use parquet2::{
     FallibleStreamingIterator,
 };
 
-pub use deserialize::{column_iter_to_arrays, get_page_iterator};
+pub use deserialize::{column_iter_to_arrays, get_page_iterator, get_page_stream};
 pub use file::{FileReader, RowGroupReader};
 pub use row_group::*;
 pub(crate) use schema::is_type_nullable;
-pub use schema::{infer_schema, FileMetaData};
+pub use schema::{infer_schema, to_dictionary_field, FileMetaData};
+pub use statistics::filter_row_groups;
 
 use std::{
     io::{Read, Seek},
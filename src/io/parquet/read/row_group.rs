@@ -13,7 +13,11 @@ use parquet2::{
 };
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
+    array::{new_empty_array, Array},
+    buffer::Buffer,
+    chunk::Chunk,
+    datatypes::Field,
+    error::Result,
     io::parquet::read::column_iter_to_arrays,
 };
 
@@ -128,20 +132,81 @@ where
     Ok((meta, chunk))
 }
 
-async fn _read_single_column_async<'b, R, F>(
-    factory: F,
-    meta: &ColumnChunkMetaData,
-) -> Result<(&ColumnChunkMetaData, Vec<u8>)>
+/// Groups `columns`, a subset of the columns `all` of a row group, into byte ranges of columns
+/// that are adjacent in the file, i.e. that have no other column of `all` between them.
+/// Returns the start and end of each range together with the indices of the columns in it.
+///
+/// Adjacent columns may be separated by the metadata of the column chunk, which writers
+/// may write after it, and which is then read as part of the range.
+fn coalesce_byte_ranges(
+    columns: &[&ColumnChunkMetaData],
+    all: &[ColumnChunkMetaData],
+) -> Vec<(u64, u64, Vec<usize>)> {
+    let mut indices = (0..columns.len()).collect::<Vec<_>>();
+    indices.sort_by_key(|&i| columns[i].byte_range().0);
+
+    let mut ranges: Vec<(u64, u64, Vec<usize>)> = vec![];
+    for i in indices {
+        let (start, length) = columns[i].byte_range();
+        let end = start + length;
+        let is_adjacent = |range_end: u64| {
+            !all.iter()
+                .map(|column| column.byte_range().0)
+                .any(|column_start| column_start >= range_end && column_start < start)
+        };
+        match ranges.last_mut() {
+            Some((_, range_end, range)) if is_adjacent(*range_end) => {
+                *range_end = end.max(*range_end);
+                range.push(i);
+            }
+            _ => ranges.push((start, end, vec![i])),
+        }
+    }
+    ranges
+}
+
+async fn _read_byte_range_async<'b, R, F>(factory: F, start: u64, end: u64) -> Result<Buffer<u8>>
 where
     R: AsyncRead + AsyncSeek + Send + Unpin,
     F: Fn() -> BoxFuture<'b, std::io::Result<R>>,
 {
     let mut reader = factory().await?;
-    let (start, len) = meta.byte_range();
     reader.seek(std::io::SeekFrom::Start(start)).await?;
-    let mut chunk = vec![0; len as usize];
-    reader.read_exact(&mut chunk).await?;
-    Result::Ok((meta, chunk))
+    let mut data = vec![0; (end - start) as usize];
+    reader.read_exact(&mut data).await?;
+    Result::Ok(data.into())
+}
+
+/// Reads `columns`, a subset of the columns `all` of a row group, in the same order, with a
+/// single seek and read per byte range of adjacent columns.
+/// The ranges are read concurrently, each from a reader of `factory`. The columns of a range
+/// are slices of it and share its memory.
+async fn _read_columns_coalesced_async<
+    'a,
+    'b,
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+    F: Fn() -> BoxFuture<'b, std::io::Result<R>> + Clone,
+>(
+    factory: F,
+    columns: Vec<&'a ColumnChunkMetaData>,
+    all: &[ColumnChunkMetaData],
+) -> Result<Vec<(&'a ColumnChunkMetaData, Buffer<u8>)>> {
+    let ranges = coalesce_byte_ranges(&columns, all);
+
+    let futures = ranges
+        .iter()
+        .map(|(start, end, _)| _read_byte_range_async(factory.clone(), *start, *end));
+    let data = try_join_all(futures).await?;
+
+    let mut chunks = vec![Buffer::new(); columns.len()];
+    for ((range_start, _, range), data) in ranges.into_iter().zip(data) {
+        for i in range {
+            let (start, length) = columns[i].byte_range();
+            let start = (start - range_start) as usize;
+            chunks[i] = data.clone().slice(start, length as usize);
+        }
+    }
+    Ok(columns.into_iter().zip(chunks).collect())
 }
 
 /// Reads all columns that are part of the parquet field `field_name`
@@ -149,8 +214,9 @@ where
 /// This operation is IO-bounded `O(C)` where C is the number of columns associated to
 /// the field (one for non-nested types)
 ///
-/// It does so asynchronously via a single `join_all` over all the necessary columns for
-/// `field_name`.
+/// It does so asynchronously via a single `join_all` over the byte ranges of adjacent columns
+/// necessary for `field_name`, reading each range with a single seek and read. The columns of
+/// a range share its memory.
 pub async fn read_columns_async<
     'a,
     'b,
@@ -160,18 +226,15 @@ pub async fn read_columns_async<
     factory: F,
     columns: &'a [ColumnChunkMetaData],
     field_name: &str,
-) -> Result<Vec<(&'a ColumnChunkMetaData, Vec<u8>)>> {
-    let futures = get_field_columns(columns, field_name)
-        .into_iter()
-        .map(|meta| async { _read_single_column_async(factory.clone(), meta).await });
-
-    try_join_all(futures).await
+) -> Result<Vec<(&'a ColumnChunkMetaData, Buffer<u8>)>> {
+    _read_columns_coalesced_async(factory, get_field_columns(columns, field_name), columns).await
 }
 
 /// Converts a vector of columns associated with the parquet field whose name is [`Field`]
 /// to an iterator of [`Array`], [`ArrayIter`] of chunk size `chunk_size`.
-pub fn to_deserializer<'a>(
-    columns: Vec<(&ColumnChunkMetaData, Vec<u8>)>,
+/// The data of each column is any owned bytes, e.g. a [`Vec<u8>`] or a [`Buffer<u8>`].
+pub fn to_deserializer<'a, C: AsRef<[u8]> + Send + Sync + 'a>(
+    columns: Vec<(&ColumnChunkMetaData, C)>,
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
@@ -240,7 +303,8 @@ pub fn read_columns_many<'a, R: Read + Seek>(
 /// # Implementation
 /// This operation is IO-bounded `O(C)` where C is the number of columns in the row group -
 /// it reads all the columns to memory from the row group associated to the requested fields.
-/// It does so asynchronously via `join_all`
+/// It does so asynchronously via `join_all` over the byte ranges of adjacent columns of all
+/// `fields`, reading each range with a single seek and read.
 pub async fn read_columns_many_async<
     'a,
     'b,
@@ -252,17 +316,60 @@ pub async fn read_columns_many_async<
     fields: Vec<Field>,
     chunk_size: Option<usize>,
 ) -> Result<Vec<ArrayIter<'a>>> {
-    let futures = fields
+    let field_columns = fields
         .iter()
-        .map(|field| read_columns_async(factory.clone(), row_group.columns(), &field.name));
+        .map(|field| get_field_columns(row_group.columns(), &field.name))
+        .collect::<Vec<_>>();
+    let lengths = field_columns.iter().map(|x| x.len()).collect::<Vec<_>>();
 
-    let field_columns = try_join_all(futures).await?;
+    let columns = _read_columns_coalesced_async(
+        factory,
+        field_columns.into_iter().flatten().collect(),
+        row_group.columns(),
+    )
+    .await?;
 
-    field_columns
+    let mut columns = columns.into_iter();
+    lengths
         .into_iter()
         .zip(fields.into_iter())
-        .map(|(columns, field)| {
+        .map(|(length, field)| {
+            let columns = columns.by_ref().take(length).collect();
             to_deserializer(columns, field, row_group.num_rows() as usize, chunk_size)
         })
         .collect()
 }
+
+/// Reads the top level parquet fields whose name matches `fields`'s names from `row_group`
+/// into a single [`Chunk`].
+///
+/// # Implementation
+/// This reads the columns as [`read_columns_many_async`] does, with a single seek and read
+/// per byte range of adjacent columns, and then deserializes them, which is CPU-bounded.
+pub async fn read_row_group_async<
+    'b,
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+    F: Fn() -> BoxFuture<'b, std::io::Result<R>> + Clone,
+>(
+    factory: F,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let data_types = fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    let columns = read_columns_many_async(factory, row_group, fields, None).await?;
+
+    let num_rows = row_group.num_rows() as usize;
+    RowGroupDeserializer::new(columns, num_rows, None)
+        .next()
+        .unwrap_or_else(|| {
+            // the row group is empty
+            let arrays = data_types
+                .into_iter()
+                .map(|data_type| new_empty_array(data_type).into())
+                .collect();
+            Ok(Chunk::new(arrays))
+        })
+}
@@ -1,6 +1,6 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use crate::datatypes::Schema;
-use crate::error::Result;
+use crate::datatypes::{DataType, Field, IntegerType, PhysicalType, Schema};
+use crate::error::{ArrowError, Result};
 
 mod convert;
 mod metadata;
@@ -29,6 +29,33 @@ pub fn infer_schema(file_metadata: &FileMetaData) -> Result<Schema> {
     }))
 }
 
+/// Returns `field` with its data type `T` replaced by `Dictionary(Int32, T)`.
+///
+/// Reading a column with this field (e.g. via [`read_columns_many`](super::read_columns_many))
+/// deserializes its dictionary-encoded pages into a [`DictionaryArray<i32>`](crate::array::DictionaryArray)
+/// whose values are read once per row group, from its dictionary page, and shared by all its
+/// arrays. For binary and utf8 columns, the values of pages that are not dictionary-encoded
+/// (e.g. because the writer fell back to plain encoding) are appended to the values.
+/// # Error
+/// This function errors iff `T` is neither a primitive, binary, utf8 nor fixed-size binary type.
+pub fn to_dictionary_field(field: Field) -> Result<Field> {
+    match field.data_type.to_physical_type() {
+        PhysicalType::Primitive(_)
+        | PhysicalType::Binary
+        | PhysicalType::LargeBinary
+        | PhysicalType::Utf8
+        | PhysicalType::LargeUtf8
+        | PhysicalType::FixedSizeBinary => Ok(Field {
+            data_type: DataType::Dictionary(IntegerType::Int32, Box::new(field.data_type), false),
+            ..field
+        }),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Parquet columns of type {:?} cannot be read as dictionaries",
+            field.data_type
+        ))),
+    }
+}
+
 pub(crate) fn is_type_nullable(type_: &ParquetType) -> bool {
     is_nullable(type_.get_basic_info())
 }
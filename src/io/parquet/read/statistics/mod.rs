@@ -1,7 +1,7 @@
 //! APIs exposing `parquet2`'s statistics as arrow's statistics.
 use std::any::Any;
 
-use parquet2::metadata::ColumnChunkMetaData;
+use parquet2::metadata::{ColumnChunkMetaData, FileMetaData};
 use parquet2::schema::types::PhysicalType;
 use parquet2::statistics::PrimitiveStatistics as ParquetPrimitiveStatistics;
 use parquet2::statistics::Statistics as ParquetStatistics;
@@ -10,6 +10,9 @@ use crate::datatypes::DataType;
 use crate::datatypes::Field;
 use crate::error::ArrowError;
 use crate::error::Result;
+use crate::scalar::{
+    BinaryScalar, BooleanScalar, FixedSizeBinaryScalar, PrimitiveScalar, Scalar, Utf8Scalar,
+};
 
 mod primitive;
 pub use primitive::*;
@@ -129,3 +132,114 @@ pub fn deserialize_statistics(
         })
         .collect()
 }
+
+/// Returns the minimum and maximum values of `statistics` of a column of type `data_type` as
+/// [`Scalar`]s, or `None` if either of them is unknown.
+pub fn min_max(
+    statistics: &dyn Statistics,
+    data_type: &DataType,
+) -> Option<(Box<dyn Scalar>, Box<dyn Scalar>)> {
+    // the statistics of utf8 and binary columns do not distinguish the offset size
+    let values_type = match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => values.to_logical_type(),
+        data_type => data_type,
+    };
+    let is_large = matches!(values_type, DataType::LargeUtf8 | DataType::LargeBinary);
+
+    let any = statistics.as_any();
+    if let Some(stats) = any.downcast_ref::<BooleanStatistics>() {
+        let (min, max) = (stats.min_value?, stats.max_value?);
+        return Some((
+            Box::new(BooleanScalar::new(Some(min))),
+            Box::new(BooleanScalar::new(Some(max))),
+        ));
+    }
+    if let Some(stats) = any.downcast_ref::<Utf8Statistics>() {
+        let (min, max) = (stats.min_value.as_ref()?, stats.max_value.as_ref()?);
+        return Some(if is_large {
+            (
+                Box::new(Utf8Scalar::<i64>::new(Some(min.as_str()))),
+                Box::new(Utf8Scalar::<i64>::new(Some(max.as_str()))),
+            )
+        } else {
+            (
+                Box::new(Utf8Scalar::<i32>::new(Some(min.as_str()))),
+                Box::new(Utf8Scalar::<i32>::new(Some(max.as_str()))),
+            )
+        });
+    }
+    if let Some(stats) = any.downcast_ref::<BinaryStatistics>() {
+        let (min, max) = (stats.min_value.as_ref()?, stats.max_value.as_ref()?);
+        return Some(if is_large {
+            (
+                Box::new(BinaryScalar::<i64>::new(Some(min.as_slice()))),
+                Box::new(BinaryScalar::<i64>::new(Some(max.as_slice()))),
+            )
+        } else {
+            (
+                Box::new(BinaryScalar::<i32>::new(Some(min.as_slice()))),
+                Box::new(BinaryScalar::<i32>::new(Some(max.as_slice()))),
+            )
+        });
+    }
+    if let Some(stats) = any.downcast_ref::<FixedLenStatistics>() {
+        let (min, max) = (stats.min_value.as_ref()?, stats.max_value.as_ref()?);
+        return Some((
+            Box::new(FixedSizeBinaryScalar::new(
+                stats.data_type.clone(),
+                Some(min.as_slice()),
+            )),
+            Box::new(FixedSizeBinaryScalar::new(
+                stats.data_type.clone(),
+                Some(max.as_slice()),
+            )),
+        ));
+    }
+    if let crate::datatypes::PhysicalType::Primitive(primitive) =
+        statistics.data_type().to_physical_type()
+    {
+        return with_match_primitive_type!(primitive, |$T| {
+            let stats = any.downcast_ref::<PrimitiveStatistics<$T>>()?;
+            let (min, max) = (stats.min_value?, stats.max_value?);
+            Some((
+                Box::new(PrimitiveScalar::<$T>::new(stats.data_type.clone(), Some(min)))
+                    as Box<dyn Scalar>,
+                Box::new(PrimitiveScalar::<$T>::new(stats.data_type.clone(), Some(max))),
+            ))
+        });
+    }
+    None
+}
+
+/// Returns the indices of the row groups of `metadata` that `predicate` keeps, based on the
+/// statistics of the column of `field` in each row group.
+///
+/// `predicate` receives the minimum and maximum values of the column in a row group, as
+/// [`Scalar`]s of the column's type, and returns whether the row group may contain values of
+/// interest. Row groups whose column has no minimum or maximum are always kept, as are all row
+/// groups when `field` has more than one parquet column (e.g. a struct).
+///
+/// The indices can be used to only read the selected row groups, e.g. via
+/// [`FileReader::set_groups_filter`](super::FileReader::set_groups_filter).
+/// # Error
+/// This function errors iff the statistics of the column cannot be deserialized.
+pub fn filter_row_groups<F: Fn(&dyn Scalar, &dyn Scalar) -> bool>(
+    metadata: &FileMetaData,
+    field: &Field,
+    predicate: F,
+) -> Result<Vec<usize>> {
+    let mut selected = vec![];
+    for (i, row_group) in metadata.row_groups.iter().enumerate() {
+        let statistics = deserialize_statistics(field, row_group.columns())?;
+        let keep = match statistics.as_slice() {
+            [Some(statistics)] => min_max(statistics.as_ref(), field.data_type())
+                .map(|(min, max)| predicate(min.as_ref(), max.as_ref()))
+                .unwrap_or(true),
+            _ => true,
+        };
+        if keep {
+            selected.push(i);
+        }
+    }
+    Ok(selected)
+}
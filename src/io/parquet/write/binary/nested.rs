@@ -10,25 +10,22 @@ use crate::{
     io::parquet::read::is_type_nullable,
 };
 
-pub fn array_to_page<O, OO>(
+pub fn array_to_page<O>(
     array: &BinaryArray<O>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<OO>,
+    nested: levels::NestedInfo,
 ) -> Result<DataPage>
 where
-    OO: Offset,
     O: Offset,
 {
     let is_optional = is_type_nullable(descriptor.type_());
 
-    let validity = array.validity();
-
     let mut buffer = vec![];
     levels::write_rep_levels(&mut buffer, &nested, options.version)?;
     let repetition_levels_byte_length = buffer.len();
 
-    levels::write_def_levels(&mut buffer, &nested, validity, options.version)?;
+    levels::write_def_levels(&mut buffer, &nested, options.version)?;
     let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
 
     encode_plain(array, is_optional, &mut buffer);
@@ -41,8 +38,9 @@ where
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
-        array.null_count(),
+        nested.num_values(),
+        nested.num_rows(),
+        nested.num_values() - array.len() + array.null_count(),
         repetition_levels_byte_length,
         definition_levels_byte_length,
         statistics,
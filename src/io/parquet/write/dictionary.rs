@@ -2,14 +2,24 @@ use parquet2::{
     encoding::{hybrid_rle::encode_u32, Encoding},
     metadata::ColumnDescriptor,
     page::{EncodedDictPage, EncodedPage},
+    statistics::ParquetStatistics,
     write::{DynIter, WriteOptions},
 };
 
-use super::binary::encode_plain as binary_encode_plain;
-use super::fixed_len_bytes::encode_plain as fixed_binary_encode_plain;
-use super::primitive::encode_plain as primitive_encode_plain;
-use super::utf8::encode_plain as utf8_encode_plain;
-use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
+use super::array_to_page;
+use super::binary::{
+    build_statistics as binary_build_statistics, encode_plain as binary_encode_plain,
+};
+use super::fixed_len_bytes::{
+    build_statistics as fixed_binary_build_statistics, encode_plain as fixed_binary_encode_plain,
+};
+use super::primitive::{
+    build_statistics as primitive_build_statistics, encode_plain as primitive_encode_plain,
+};
+use super::utf8::{build_statistics as utf8_build_statistics, encode_plain as utf8_encode_plain};
+use crate::array::{
+    growable::make_growable, Array, DictionaryArray, DictionaryKey, PrimitiveArray,
+};
 use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
@@ -20,6 +30,7 @@ fn encode_keys<K: DictionaryKey>(
     array: &PrimitiveArray<K>,
     // todo: merge this to not discard values' validity
     validity: Option<&Bitmap>,
+    statistics: Option<ParquetStatistics>,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
 ) -> Result<EncodedPage> {
@@ -94,10 +105,11 @@ fn encode_keys<K: DictionaryKey>(
     utils::build_plain_page(
         buffer,
         array.len(),
+        array.len(),
         array.null_count(),
         0,
         definition_levels_byte_length,
-        None,
+        statistics,
         descriptor,
         options,
         Encoding::RleDictionary,
@@ -111,20 +123,85 @@ macro_rules! dyn_prim {
 
         let mut buffer = vec![];
         primitive_encode_plain::<$from, $to>(values, false, &mut buffer);
-        EncodedDictPage::new(buffer, values.len())
+        (buffer, values.len())
+    }};
+}
+
+/// Returns the values of `array`, i.e. the values of its dictionary taken by its keys.
+fn to_values<K: DictionaryKey>(array: &DictionaryArray<K>) -> Box<dyn Array> {
+    let mut growable = make_growable(&[array.values().as_ref()], true, array.len());
+    array.keys().iter().for_each(|key| match key {
+        Some(key) => growable.extend(0, key.to_usize().unwrap(), 1),
+        None => growable.extend_validity(1),
+    });
+    growable.as_box()
+}
+
+macro_rules! dyn_prim_stats {
+    ($from:ty, $to:ty, $values:expr, $descriptor:expr) => {{
+        let values = $values.as_any().downcast_ref().unwrap();
+        Some(primitive_build_statistics::<$from, $to>(
+            values,
+            $descriptor,
+        ))
     }};
 }
 
+/// Returns the statistics of `values`, the values of a dictionary array (see [`to_values`]).
+fn build_statistics(values: &dyn Array, descriptor: ColumnDescriptor) -> Option<ParquetStatistics> {
+    match values.data_type().to_logical_type() {
+        DataType::Int8 => dyn_prim_stats!(i8, i32, values, descriptor),
+        DataType::Int16 => dyn_prim_stats!(i16, i32, values, descriptor),
+        DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
+            dyn_prim_stats!(i32, i32, values, descriptor)
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => dyn_prim_stats!(i64, i64, values, descriptor),
+        DataType::UInt8 => dyn_prim_stats!(u8, i32, values, descriptor),
+        DataType::UInt16 => dyn_prim_stats!(u16, i32, values, descriptor),
+        DataType::UInt32 => dyn_prim_stats!(u32, i32, values, descriptor),
+        DataType::UInt64 => dyn_prim_stats!(u64, i64, values, descriptor),
+        DataType::Float32 => dyn_prim_stats!(f32, f32, values, descriptor),
+        DataType::Float64 => dyn_prim_stats!(f64, f64, values, descriptor),
+        DataType::Utf8 => Some(utf8_build_statistics::<i32>(
+            values.as_any().downcast_ref().unwrap(),
+            descriptor,
+        )),
+        DataType::LargeUtf8 => Some(utf8_build_statistics::<i64>(
+            values.as_any().downcast_ref().unwrap(),
+            descriptor,
+        )),
+        DataType::Binary => Some(binary_build_statistics::<i32>(
+            values.as_any().downcast_ref().unwrap(),
+            descriptor,
+        )),
+        DataType::LargeBinary => Some(binary_build_statistics::<i64>(
+            values.as_any().downcast_ref().unwrap(),
+            descriptor,
+        )),
+        DataType::FixedSizeBinary(_) => {
+            fixed_binary_build_statistics(values.as_any().downcast_ref().unwrap(), descriptor)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the dictionary page followed by the data page of `array`, or a plain data page of
+/// its values if the dictionary page would be larger than `dictionary_page_size_limit` bytes.
 pub fn array_to_pages<K: DictionaryKey>(
     array: &DictionaryArray<K>,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
     encoding: Encoding,
+    dictionary_page_size_limit: Option<usize>,
 ) -> Result<DynIter<'static, Result<EncodedPage>>> {
     match encoding {
         Encoding::PlainDictionary | Encoding::RleDictionary => {
             // write DictPage
-            let dict_page = match array.values().data_type().to_logical_type() {
+            let (buffer, num_values) = match array.values().data_type().to_logical_type() {
                 DataType::Int8 => dyn_prim!(i8, i32, array, options),
                 DataType::Int16 => dyn_prim!(i16, i32, array, options),
                 DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
@@ -146,34 +223,34 @@ pub fn array_to_pages<K: DictionaryKey>(
 
                     let mut buffer = vec![];
                     utf8_encode_plain::<i32>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
+                    (buffer, values.len())
                 }
                 DataType::LargeUtf8 => {
                     let values = array.values().as_any().downcast_ref().unwrap();
 
                     let mut buffer = vec![];
                     utf8_encode_plain::<i64>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
+                    (buffer, values.len())
                 }
                 DataType::Binary => {
                     let values = array.values().as_any().downcast_ref().unwrap();
 
                     let mut buffer = vec![];
                     binary_encode_plain::<i32>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
+                    (buffer, values.len())
                 }
                 DataType::LargeBinary => {
                     let values = array.values().as_any().downcast_ref().unwrap();
 
                     let mut buffer = vec![];
                     binary_encode_plain::<i64>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
+                    (buffer, values.len())
                 }
                 DataType::FixedSizeBinary(_) => {
                     let mut buffer = vec![];
                     let array = array.values().as_any().downcast_ref().unwrap();
                     fixed_binary_encode_plain(array, false, &mut buffer);
-                    EncodedDictPage::new(buffer, array.len())
+                    (buffer, array.len())
                 }
                 other => {
                     return Err(ArrowError::NotYetImplemented(format!(
@@ -182,11 +259,29 @@ pub fn array_to_pages<K: DictionaryKey>(
                     )))
                 }
             };
-            let dict_page = EncodedPage::Dict(dict_page);
+            if matches!(dictionary_page_size_limit, Some(limit) if buffer.len() > limit) {
+                // the dictionary is too large => write the values instead
+                let values = to_values(array);
+                let page = array_to_page(values.as_ref(), descriptor, options, Encoding::Plain)?;
+                return Ok(DynIter::new(std::iter::once(Ok(page))));
+            }
+            let dict_page = EncodedPage::Dict(EncodedDictPage::new(buffer, num_values));
+
+            // the statistics are of the values the keys point to, not of the whole dictionary
+            let statistics = if options.write_statistics {
+                build_statistics(to_values(array).as_ref(), descriptor.clone())
+            } else {
+                None
+            };
 
             // write DataPage pointing to DictPage
-            let data_page =
-                encode_keys(array.keys(), array.values().validity(), descriptor, options)?;
+            let data_page = encode_keys(
+                array.keys(),
+                array.values().validity(),
+                statistics,
+                descriptor,
+                options,
+            )?;
 
             let iter = std::iter::once(Ok(dict_page)).chain(std::iter::once(Ok(data_page)));
             Ok(DynIter::new(Box::new(iter)))
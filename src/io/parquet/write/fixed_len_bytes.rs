@@ -56,6 +56,7 @@ pub fn array_to_page(
     utils::build_plain_page(
         buffer,
         array.len(),
+        array.len(),
         array.null_count(),
         0,
         definition_levels_byte_length,
@@ -1,169 +1,160 @@
+use std::ops::Range;
+
 use parquet2::encoding::hybrid_rle::encode_u32;
 use parquet2::write::Version;
 
-use crate::{
-    array::Offset,
-    bitmap::{utils::BitmapIter, Bitmap},
-    error::Result,
-};
-
-pub fn num_values<O: Offset>(offsets: &[O]) -> usize {
-    offsets
-        .windows(2)
-        .map(|w| {
-            let length = w[1].to_usize() - w[0].to_usize();
-            if length == 0 {
-                1
-            } else {
-                length
-            }
-        })
-        .sum()
+use crate::{bitmap::Bitmap, error::Result};
+
+use super::utils::get_bit_width;
+
+/// A level of the nesting of a leaf column, from the outermost (the field) to the leaf.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Nested<'a> {
+    /// a leaf: its validity, whether it is optional and its length
+    Primitive(Option<&'a Bitmap>, bool, usize),
+    /// a list: its offsets, validity and whether it is optional
+    List(&'a [i32], Option<&'a Bitmap>, bool),
+    /// a large list: its offsets, validity and whether it is optional
+    LargeList(&'a [i64], Option<&'a Bitmap>, bool),
+    /// a fixed-size list: its size, validity, whether it is optional and its length
+    FixedSizeList(usize, Option<&'a Bitmap>, bool, usize),
+    /// a struct: its validity, whether it is optional and its length
+    Struct(Option<&'a Bitmap>, bool, usize),
 }
 
-/// Iterator adapter of parquet / dremel repetition levels
-#[derive(Debug)]
-pub struct RepLevelsIter<'a, O: Offset> {
-    iter: std::slice::Windows<'a, O>,
-    remaining: usize,
-    length: usize,
-    total_size: usize,
-}
-
-impl<'a, O: Offset> RepLevelsIter<'a, O> {
-    pub fn new(offsets: &'a [O]) -> Self {
-        let total_size = num_values(offsets);
-
-        Self {
-            iter: offsets.windows(2),
-            remaining: 0,
-            length: 0,
-            total_size,
+impl Nested<'_> {
+    /// The number of items of this level
+    pub fn len(&self) -> usize {
+        match self {
+            Nested::Primitive(_, _, length) => *length,
+            Nested::List(offsets, _, _) => offsets.len() - 1,
+            Nested::LargeList(offsets, _, _) => offsets.len() - 1,
+            Nested::FixedSizeList(_, _, _, length) => *length,
+            Nested::Struct(_, _, length) => *length,
         }
     }
-}
 
-impl<O: Offset> Iterator for RepLevelsIter<'_, O> {
-    type Item = u32;
+    /// Whether the item `index` of this level is null.
+    fn is_null(&self, index: usize) -> bool {
+        let (validity, is_optional) = match self {
+            Nested::Primitive(validity, is_optional, _) => (validity, is_optional),
+            Nested::List(_, validity, is_optional) => (validity, is_optional),
+            Nested::LargeList(_, validity, is_optional) => (validity, is_optional),
+            Nested::FixedSizeList(_, validity, is_optional, _) => (validity, is_optional),
+            Nested::Struct(validity, is_optional, _) => (validity, is_optional),
+        };
+        *is_optional && validity.map(|x| !x.get_bit(index)).unwrap_or(false)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == self.length {
-            if let Some(w) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
-                self.remaining = 0;
-                if self.length == 0 {
-                    self.total_size -= 1;
-                    return Some(0);
-                }
-            } else {
-                return None;
+    /// The range of the children of the item `index` if this level is a list
+    fn children(&self, index: usize) -> Option<Range<usize>> {
+        match self {
+            Nested::List(offsets, _, _) => {
+                Some(offsets[index] as usize..offsets[index + 1] as usize)
+            }
+            Nested::LargeList(offsets, _, _) => {
+                Some(offsets[index] as usize..offsets[index + 1] as usize)
             }
+            Nested::FixedSizeList(size, _, _, _) => Some(index * size..(index + 1) * size),
+            _ => None,
         }
-        let old = self.remaining;
-        self.remaining += 1;
-        self.total_size -= 1;
-        Some((old >= 1) as u32)
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.total_size, Some(self.total_size))
+    fn is_optional(&self) -> bool {
+        match self {
+            Nested::Primitive(_, is_optional, _) => *is_optional,
+            Nested::List(_, _, is_optional) => *is_optional,
+            Nested::LargeList(_, _, is_optional) => *is_optional,
+            Nested::FixedSizeList(_, _, is_optional, _) => *is_optional,
+            Nested::Struct(_, is_optional, _) => *is_optional,
+        }
     }
 }
 
-/// Iterator adapter of parquet / dremel definition levels
-pub struct DefLevelsIter<'a, O: Offset> {
-    iter: std::iter::Zip<std::slice::Windows<'a, O>, Box<dyn Iterator<Item = bool> + 'a>>,
-    primitive_validity: Option<BitmapIter<'a>>,
-    remaining: usize,
-    is_valid: bool,
-    length: usize,
-    total_size: usize,
+/// The dremel repetition and definition levels of a leaf column.
+#[derive(Debug, Default)]
+pub struct NestedInfo {
+    rep_levels: Vec<u32>,
+    def_levels: Vec<u32>,
+    max_rep_level: u32,
+    max_def_level: u32,
+    num_rows: usize,
+    leaves: Vec<Range<usize>>,
 }
 
-impl<'a, O: Offset> DefLevelsIter<'a, O> {
-    pub fn new(
-        offsets: &'a [O],
-        validity: Option<&'a Bitmap>,
-        primitive_validity: Option<&'a Bitmap>,
-    ) -> Self {
-        let total_size = num_values(offsets);
-
-        let primitive_validity = primitive_validity.map(|x| x.iter());
-
-        let validity = validity
-            .map(|x| Box::new(x.iter()) as Box<dyn Iterator<Item = bool>>)
-            .unwrap_or_else(|| {
-                Box::new(std::iter::repeat(true).take(offsets.len() - 1))
-                    as Box<dyn Iterator<Item = bool>>
-            });
-
-        Self {
-            iter: offsets.windows(2).zip(validity),
-            primitive_validity,
-            remaining: 0,
-            length: 0,
-            is_valid: false,
-            total_size,
-        }
+impl NestedInfo {
+    /// Computes the levels of the leaf of `nested`.
+    pub fn new(nested: &[Nested]) -> Self {
+        let max_rep_level = nested.iter().filter(|x| x.children(0).is_some()).count() as u32;
+        let max_def_level = nested
+            .iter()
+            .map(|x| x.is_optional() as u32 + x.children(0).is_some() as u32)
+            .sum();
+        let mut info = Self {
+            max_rep_level,
+            max_def_level,
+            num_rows: nested[0].len(),
+            ..Default::default()
+        };
+        (0..info.num_rows).for_each(|index| info.visit(nested, index, 0, 0, 0));
+        info
     }
-}
-
-impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
-    type Item = u32;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == self.length {
-            if let Some((w, is_valid)) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
-                self.remaining = 0;
-                self.is_valid = is_valid;
-                if self.length == 0 {
-                    self.total_size -= 1;
-                    return Some(is_valid as u32);
-                }
-            } else {
-                return None;
+    /// Pushes the levels of the item `index` of `nested[0]`, whose first value has
+    /// repetition level `rep` and whose parents are defined up to `def`.
+    /// `depth` is the number of lists containing this item.
+    fn visit(&mut self, nested: &[Nested], index: usize, rep: u32, def: u32, depth: u32) {
+        let level = &nested[0];
+        if let Nested::Primitive(..) = level {
+            // null values are written by the leaf itself
+            self.push(
+                rep,
+                def + (!level.is_null(index) && level.is_optional()) as u32,
+            );
+            match self.leaves.last_mut() {
+                Some(range) if range.end == index => range.end += 1,
+                _ => self.leaves.push(index..index + 1),
             }
+            return;
         }
-        self.remaining += 1;
-        self.total_size -= 1;
+        if level.is_null(index) {
+            self.push(rep, def);
+            return;
+        }
+        let def = def + level.is_optional() as u32;
 
-        let (base_def, p_is_valid) = self
-            .primitive_validity
-            .as_mut()
-            .map(|x| (1, x.next().unwrap() as u32))
-            .unwrap_or((0, 0));
-        let def_ = (base_def + 1) * self.is_valid as u32 + p_is_valid;
-        Some(def_)
+        if let Some(children) = level.children(index) {
+            if children.is_empty() {
+                self.push(rep, def);
+            }
+            for (i, child) in children.enumerate() {
+                let rep = if i == 0 { rep } else { depth + 1 };
+                self.visit(&nested[1..], child, rep, def + 1, depth + 1);
+            }
+        } else {
+            self.visit(&nested[1..], index, rep, def, depth)
+        }
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.total_size, Some(self.total_size))
+    fn push(&mut self, rep: u32, def: u32) {
+        self.rep_levels.push(rep);
+        self.def_levels.push(def);
     }
-}
 
-#[derive(Debug)]
-pub struct NestedInfo<'a, O: Offset> {
-    _is_optional: bool,
-    offsets: &'a [O],
-    validity: Option<&'a Bitmap>,
-}
+    /// The number of (repetition, definition) levels
+    pub fn num_values(&self) -> usize {
+        self.def_levels.len()
+    }
 
-impl<'a, O: Offset> NestedInfo<'a, O> {
-    pub fn new(offsets: &'a [O], validity: Option<&'a Bitmap>, is_optional: bool) -> Self {
-        Self {
-            _is_optional: is_optional,
-            offsets,
-            validity,
-        }
+    /// The number of rows
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
     }
 
-    pub fn offsets(&self) -> &'a [O] {
-        self.offsets
+    /// The ranges of the leaf values that the levels refer to, i.e. the values of the leaf
+    /// whose parents are neither null nor empty.
+    pub fn leaves(&self) -> &[Range<usize>] {
+        &self.leaves
     }
 }
 
@@ -185,26 +176,22 @@ fn write_levels_v1<F: Fn(&mut Vec<u8>) -> Result<()>>(
     Ok(())
 }
 
-/// writes the rep levels to a `Vec<u8>`.
-pub fn write_rep_levels<O: Offset>(
-    buffer: &mut Vec<u8>,
-    nested: &NestedInfo<O>,
-    version: Version,
-) -> Result<()> {
-    let num_bits = 1; // todo: compute this
+fn write_levels(buffer: &mut Vec<u8>, levels: &[u32], max: u32, version: Version) -> Result<()> {
+    if max == 0 {
+        // levels are only written when they can be non-zero
+        return Ok(());
+    }
+    let num_bits = get_bit_width(max as u64) as u8;
 
     match version {
         Version::V1 => {
             write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = RepLevelsIter::new(nested.offsets);
-                encode_u32(buffer, levels, num_bits)?;
+                encode_u32(buffer, levels.iter().copied(), num_bits)?;
                 Ok(())
             })?;
         }
         Version::V2 => {
-            let levels = RepLevelsIter::new(nested.offsets);
-
-            encode_u32(buffer, levels, num_bits)?;
+            encode_u32(buffer, levels.iter().copied(), num_bits)?;
         }
     }
 
@@ -212,29 +199,13 @@ pub fn write_rep_levels<O: Offset>(
 }
 
 /// writes the rep levels to a `Vec<u8>`.
-pub fn write_def_levels<O: Offset>(
-    buffer: &mut Vec<u8>,
-    nested: &NestedInfo<O>,
-    validity: Option<&Bitmap>,
-    version: Version,
-) -> Result<()> {
-    let num_bits = 2;
-
-    match version {
-        Version::V1 => {
-            write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
-                encode_u32(buffer, levels, num_bits)?;
-                Ok(())
-            })?;
-        }
-        Version::V2 => {
-            let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
-            encode_u32(buffer, levels, num_bits)?;
-        }
-    }
+pub fn write_rep_levels(buffer: &mut Vec<u8>, nested: &NestedInfo, version: Version) -> Result<()> {
+    write_levels(buffer, &nested.rep_levels, nested.max_rep_level, version)
+}
 
-    Ok(())
+/// writes the def levels to a `Vec<u8>`.
+pub fn write_def_levels(buffer: &mut Vec<u8>, nested: &NestedInfo, version: Version) -> Result<()> {
+    write_levels(buffer, &nested.def_levels, nested.max_def_level, version)
 }
 
 #[cfg(test)]
@@ -242,16 +213,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_rep_levels() {
-        let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
-        let expected = vec![0u32, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0];
-
-        let result = RepLevelsIter::new(offsets).collect::<Vec<_>>();
-        assert_eq!(result, expected)
-    }
-
-    #[test]
-    fn test_def_levels() {
+    fn test_list_levels() {
         let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
         let validity = Some(Bitmap::from([
             true, false, true, true, true, true, false, true,
@@ -263,10 +225,35 @@ mod tests {
             true, true, true, //[7, 8, 9]
             true, //[10]
         ]));
-        let expected = vec![3u32, 3, 0, 3, 2, 3, 3, 3, 3, 1, 3, 3, 3, 0, 3];
+        let nested = [
+            Nested::List(offsets, validity.as_ref(), true),
+            Nested::Primitive(primitive_validity.as_ref(), true, 12),
+        ];
+        let expected_rep = vec![0u32, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0];
+        let expected_def = vec![3u32, 3, 0, 3, 2, 3, 3, 3, 3, 1, 3, 3, 3, 0, 3];
+
+        let result = NestedInfo::new(&nested);
+        assert_eq!(result.rep_levels, expected_rep);
+        assert_eq!(result.def_levels, expected_def);
+        assert_eq!(result.num_rows(), 8);
+        assert_eq!(result.leaves(), vec![0..12]);
+    }
 
-        let result = DefLevelsIter::new(offsets, validity.as_ref(), primitive_validity.as_ref())
-            .collect::<Vec<_>>();
-        assert_eq!(result, expected)
+    #[test]
+    fn test_struct_of_list_levels() {
+        // [{"a": [1]}, None, {"a": None}, {"a": []}, {"a": [2, 3]}]
+        let validity = Some(Bitmap::from([true, false, true, true, true]));
+        // the null struct has a non-empty list
+        let offsets = [0, 1, 2, 2, 2, 4].as_ref();
+        let list_validity = Some(Bitmap::from([true, true, false, true, true]));
+        let nested = [
+            Nested::Struct(validity.as_ref(), true, 5),
+            Nested::List(offsets, list_validity.as_ref(), true),
+            Nested::Primitive(None, false, 4),
+        ];
+        let result = NestedInfo::new(&nested);
+        assert_eq!(result.rep_levels, vec![0, 0, 0, 0, 0, 1]);
+        assert_eq!(result.def_levels, vec![3, 0, 1, 2, 3, 3]);
+        assert_eq!(result.leaves(), &[0..1, 2..4]);
     }
 }
@@ -5,6 +5,7 @@ mod dictionary;
 mod file;
 mod fixed_len_bytes;
 mod levels;
+mod options;
 mod primitive;
 mod row_group;
 mod schema;
@@ -12,13 +13,13 @@ mod sink;
 mod utf8;
 mod utils;
 
+use crate::array::growable::make_growable;
 use crate::array::*;
-use crate::bitmap::Bitmap;
 use crate::buffer::Buffer;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::io::parquet::read::is_type_nullable;
-use crate::io::parquet::write::levels::NestedInfo;
+use crate::io::parquet::write::levels::{Nested, NestedInfo};
 use crate::types::days_ms;
 use crate::types::NativeType;
 
@@ -37,6 +38,7 @@ pub use parquet2::{
 };
 
 pub use file::FileWriter;
+pub use options::ColumnOptions;
 pub use row_group::{row_group_iter, RowGroupIterator};
 pub use schema::to_parquet_type;
 pub use sink::FileSink;
@@ -73,8 +75,14 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
                 Encoding::DeltaLengthByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
-            | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
-            | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
+            | (
+                Encoding::RleDictionary | Encoding::PlainDictionary,
+                DataType::Dictionary(_, _, _)
+                    | DataType::Binary
+                    | DataType::LargeBinary
+                    | DataType::Utf8
+                    | DataType::LargeUtf8,
+            )
     )
 }
 
@@ -85,7 +93,41 @@ pub fn array_to_pages(
     options: WriteOptions,
     encoding: Encoding,
 ) -> Result<DynIter<'static, Result<EncodedPage>>> {
-    match array.data_type() {
+    encode_pages(array, descriptor, options, encoding, None)
+}
+
+macro_rules! dyn_dict {
+    ($mutable:ty, $array_type:ty, $array:expr) => {{
+        let mut dictionary = MutableDictionaryArray::<i32, $mutable>::new();
+        let array = $array.as_any().downcast_ref::<$array_type>().unwrap();
+        dictionary.try_extend(array.iter())?;
+        dictionary.into()
+    }};
+}
+
+/// Dictionary-encodes the binary or utf8 `array`.
+fn to_dictionary(array: &dyn Array) -> Result<DictionaryArray<i32>> {
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => dyn_dict!(MutableUtf8Array<i32>, Utf8Array<i32>, array),
+        PhysicalType::LargeUtf8 => dyn_dict!(MutableUtf8Array<i64>, Utf8Array<i64>, array),
+        PhysicalType::Binary => dyn_dict!(MutableBinaryArray<i32>, BinaryArray<i32>, array),
+        PhysicalType::LargeBinary => dyn_dict!(MutableBinaryArray<i64>, BinaryArray<i64>, array),
+        _ => unreachable!(),
+    })
+}
+
+fn encode_pages(
+    array: &dyn Array,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+    dictionary_page_size_limit: Option<usize>,
+) -> Result<DynIter<'static, Result<EncodedPage>>> {
+    let is_dictionary = matches!(
+        encoding,
+        Encoding::RleDictionary | Encoding::PlainDictionary
+    );
+    match array.data_type().to_logical_type() {
         DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
                 dictionary::array_to_pages::<$T>(
@@ -93,9 +135,21 @@ pub fn array_to_pages(
                     descriptor,
                     options,
                     encoding,
+                    dictionary_page_size_limit,
                 )
             })
         }
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+            if is_dictionary =>
+        {
+            dictionary::array_to_pages(
+                &to_dictionary(array)?,
+                descriptor,
+                options,
+                encoding,
+                dictionary_page_size_limit,
+            )
+        }
         _ => array_to_page(array, descriptor, options, encoding)
             .map(|page| DynIter::new(std::iter::once(Ok(page)))),
     }
@@ -285,8 +339,20 @@ pub fn array_to_page(
                 fixed_len_bytes::array_to_page(&array, options, descriptor)
             }
         }
-        DataType::FixedSizeList(_, _) | DataType::List(_) | DataType::LargeList(_) => {
-            nested_array_to_page(array, descriptor, options)
+        DataType::FixedSizeList(_, _)
+        | DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::Struct(_) => {
+            let mut leaves = nested_leaves(array, &descriptor);
+            if leaves.len() != 1 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The data type {:?} has {} parquet columns; use `array_to_columns` to write it",
+                    data_type,
+                    leaves.len()
+                )));
+            }
+            let (nested, values) = leaves.pop().unwrap();
+            nested_array_to_page(&nested, values, descriptor, options)
         }
         other => Err(ArrowError::NotYetImplemented(format!(
             "Writing parquet V1 pages for data type {:?}",
@@ -297,138 +363,220 @@ pub fn array_to_page(
 }
 
 macro_rules! dyn_nested_prim {
-    ($from:ty, $to:ty, $offset:ty, $values:expr, $nested:expr,$descriptor:expr, $options:expr) => {{
+    ($from:ty, $to:ty, $values:expr, $nested:expr,$descriptor:expr, $options:expr) => {{
         let values = $values.as_any().downcast_ref().unwrap();
 
-        primitive::nested_array_to_page::<$from, $to, $offset>(
-            values,
-            $options,
-            $descriptor,
-            $nested,
-        )
+        primitive::nested_array_to_page::<$from, $to>(values, $options, $descriptor, $nested)
     }};
 }
 
-fn list_array_to_page<O: Offset>(
-    offsets: &[O],
-    validity: Option<&Bitmap>,
+/// Returns the number of parquet (leaf) columns of `data_type`.
+fn num_leaves(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            num_leaves(field.data_type())
+        }
+        DataType::Struct(fields) => fields.iter().map(|x| num_leaves(x.data_type())).sum(),
+        _ => 1,
+    }
+}
+
+/// Pushes the leaves of `array` to `leaves`, each with the levels of nesting that contain it.
+fn to_leaves<'a>(
+    array: &'a dyn Array,
+    is_optional: bool,
+    mut nested: Vec<Nested<'a>>,
+    leaves: &mut Vec<(Vec<Nested<'a>>, &'a dyn Array)>,
+) {
+    match array.data_type().to_logical_type() {
+        DataType::List(field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            nested.push(Nested::List(
+                array.offsets().as_slice(),
+                array.validity(),
+                is_optional,
+            ));
+            to_leaves(array.values().as_ref(), field.is_nullable, nested, leaves)
+        }
+        DataType::LargeList(field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            nested.push(Nested::LargeList(
+                array.offsets().as_slice(),
+                array.validity(),
+                is_optional,
+            ));
+            to_leaves(array.values().as_ref(), field.is_nullable, nested, leaves)
+        }
+        DataType::FixedSizeList(field, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            nested.push(Nested::FixedSizeList(
+                *size,
+                array.validity(),
+                is_optional,
+                array.len(),
+            ));
+            to_leaves(array.values().as_ref(), field.is_nullable, nested, leaves)
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            nested.push(Nested::Struct(array.validity(), is_optional, array.len()));
+            array
+                .values()
+                .iter()
+                .zip(fields.iter())
+                .for_each(|(values, field)| {
+                    to_leaves(values.as_ref(), field.is_nullable, nested.clone(), leaves)
+                });
+        }
+        _ => {
+            nested.push(Nested::Primitive(
+                array.validity(),
+                is_optional,
+                array.len(),
+            ));
+            leaves.push((nested, array))
+        }
+    }
+}
+
+/// Returns the leaves of the nested `array` whose first leaf column is `descriptor`.
+fn nested_leaves<'a>(
+    array: &'a dyn Array,
+    descriptor: &ColumnDescriptor,
+) -> Vec<(Vec<Nested<'a>>, &'a dyn Array)> {
+    let is_optional = is_type_nullable(descriptor.base_type());
+    let mut leaves = vec![];
+    to_leaves(array, is_optional, vec![], &mut leaves);
+    leaves
+}
+
+/// Returns an iterator of [`EncodedPage`] for each of the parquet (leaf) columns of `array`,
+/// described by `columns`, together with the [`ColumnOptions`] that override `options` and
+/// `encoding` for that column.
+/// Nested arrays (lists and structs) have a column per primitive field that they contain;
+/// every other array has a single column.
+/// # Errors
+/// Errors iff the number of `columns` differs from the number of parquet columns of `array`,
+/// or the encoding of a column is not supported for its data type (the leaves of nested arrays
+/// only support [`Encoding::Plain`]).
+pub fn array_to_columns(
+    array: &dyn Array,
+    mut columns: Vec<(ColumnDescriptor, ColumnOptions)>,
+    options: WriteOptions,
+    encoding: Encoding,
+) -> Result<Vec<DynIter<'static, Result<EncodedPage>>>> {
+    let num_leaves = num_leaves(array.data_type());
+    if columns.len() != num_leaves {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The data type {:?} has {} parquet columns but {} descriptors were provided",
+            array.data_type(),
+            num_leaves,
+            columns.len()
+        )));
+    }
+    match array.data_type().to_logical_type() {
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Struct(_) => nested_leaves(array, &columns[0].0)
+            .into_iter()
+            .zip(columns)
+            .map(|((nested, values), (descriptor, column))| {
+                let encoding = column.encoding(encoding);
+                if !can_encode(values.data_type(), encoding) {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "The datatype {:?} cannot be encoded by {:?}",
+                        values.data_type(),
+                        encoding
+                    )));
+                }
+                if encoding != Encoding::Plain {
+                    return Err(ArrowError::NotYetImplemented(format!(
+                        "Writing the leaves of nested data types with {:?}; only Encoding::Plain is supported",
+                        encoding
+                    )));
+                }
+                let options = column.write_options(options);
+                let page = nested_array_to_page(&nested, values, descriptor, options)?;
+                Ok(DynIter::new(std::iter::once(Ok(EncodedPage::Data(page)))))
+            })
+            .collect(),
+        _ => {
+            let (descriptor, column) = columns.pop().unwrap();
+            Ok(vec![encode_pages(
+                array,
+                descriptor,
+                column.write_options(options),
+                column.encoding(encoding),
+                column.dictionary_page_size_limit,
+            )?])
+        }
+    }
+}
+
+fn nested_array_to_page(
+    nested: &[Nested],
     values: &dyn Array,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
 ) -> Result<DataPage> {
     use DataType::*;
-    let is_optional = is_type_nullable(descriptor.type_());
-    let nested = NestedInfo::new(offsets, validity, is_optional);
+    let nested = NestedInfo::new(nested);
 
-    match values.data_type() {
+    // only the values whose parents are neither null nor empty are written
+    let values = match nested.leaves() {
+        [] => values.slice(0, 0),
+        [range] => values.slice(range.start, range.len()),
+        ranges => {
+            let mut growable = make_growable(&[values], false, values.len());
+            ranges
+                .iter()
+                .for_each(|range| growable.extend(0, range.start, range.len()));
+            growable.as_box()
+        }
+    };
+
+    match values.data_type().to_logical_type() {
         Boolean => {
             let values = values.as_any().downcast_ref().unwrap();
-            boolean::nested_array_to_page::<O>(values, options, descriptor, nested)
+            boolean::nested_array_to_page(values, options, descriptor, nested)
         }
-        UInt8 => dyn_nested_prim!(u8, i32, O, values, nested, descriptor, options),
-        UInt16 => dyn_nested_prim!(u16, i32, O, values, nested, descriptor, options),
-        UInt32 => dyn_nested_prim!(u32, i32, O, values, nested, descriptor, options),
-        UInt64 => dyn_nested_prim!(u64, i64, O, values, nested, descriptor, options),
+        UInt8 => dyn_nested_prim!(u8, i32, values, nested, descriptor, options),
+        UInt16 => dyn_nested_prim!(u16, i32, values, nested, descriptor, options),
+        UInt32 => dyn_nested_prim!(u32, i32, values, nested, descriptor, options),
+        UInt64 => dyn_nested_prim!(u64, i64, values, nested, descriptor, options),
 
-        Int8 => dyn_nested_prim!(i8, i32, O, values, nested, descriptor, options),
-        Int16 => dyn_nested_prim!(i16, i32, O, values, nested, descriptor, options),
+        Int8 => dyn_nested_prim!(i8, i32, values, nested, descriptor, options),
+        Int16 => dyn_nested_prim!(i16, i32, values, nested, descriptor, options),
         Int32 | Date32 | Time32(_) => {
-            dyn_nested_prim!(i32, i32, O, values, nested, descriptor, options)
+            dyn_nested_prim!(i32, i32, values, nested, descriptor, options)
         }
         Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
-            dyn_nested_prim!(i64, i64, O, values, nested, descriptor, options)
+            dyn_nested_prim!(i64, i64, values, nested, descriptor, options)
         }
 
-        Float32 => dyn_nested_prim!(f32, f32, O, values, nested, descriptor, options),
-        Float64 => dyn_nested_prim!(f64, f64, O, values, nested, descriptor, options),
+        Float32 => dyn_nested_prim!(f32, f32, values, nested, descriptor, options),
+        Float64 => dyn_nested_prim!(f64, f64, values, nested, descriptor, options),
 
         Utf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            utf8::nested_array_to_page::<i32, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            utf8::nested_array_to_page::<i32>(values, options, descriptor, nested)
         }
         LargeUtf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            utf8::nested_array_to_page::<i64, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            utf8::nested_array_to_page::<i64>(values, options, descriptor, nested)
         }
         Binary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            binary::nested_array_to_page::<i32, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            binary::nested_array_to_page::<i32>(values, options, descriptor, nested)
         }
         LargeBinary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            binary::nested_array_to_page::<i64, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            binary::nested_array_to_page::<i64>(values, options, descriptor, nested)
         }
-        _ => todo!(),
-    }
-}
-
-fn nested_array_to_page(
-    array: &dyn Array,
-    descriptor: ColumnDescriptor,
-    options: WriteOptions,
-) -> Result<DataPage> {
-    match array.data_type() {
-        DataType::List(_) => {
-            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            list_array_to_page(
-                array.offsets(),
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        DataType::LargeList(_) => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            list_array_to_page(
-                array.offsets(),
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        DataType::FixedSizeList(_, size) => {
-            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
-            let offsets = (0..array.len())
-                .map(|x| (*size * x) as i32)
-                .collect::<Vec<_>>();
-            list_array_to_page(
-                &offsets,
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        _ => todo!(),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Writing nested parquet pages of data type {:?}",
+            other
+        ))),
     }
 }
@@ -0,0 +1,34 @@
+use super::{Encoding, WriteOptions};
+
+/// Options of a parquet (leaf) column that override the [`WriteOptions`] and the [`Encoding`]
+/// of the field that contains it. Options set to `None` are taken from those.
+///
+/// The leaves of nested fields (lists and structs) only support [`Encoding::Plain`].
+///
+/// The compression can't be set per column: the parquet writer compresses every column chunk
+/// of a file with the compression of its [`WriteOptions`] and records it in their metadata.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColumnOptions {
+    /// The encoding of the column's data pages
+    pub encoding: Option<Encoding>,
+    /// Whether to write the column's statistics
+    pub write_statistics: Option<bool>,
+    /// The maximum size in bytes of the dictionary page of a dictionary-encoded column.
+    /// Columns whose dictionary page would be larger are plain-encoded instead.
+    pub dictionary_page_size_limit: Option<usize>,
+}
+
+impl ColumnOptions {
+    /// Returns `options` overridden by the options of this column.
+    pub fn write_options(&self, options: WriteOptions) -> WriteOptions {
+        WriteOptions {
+            write_statistics: self.write_statistics.unwrap_or(options.write_statistics),
+            ..options
+        }
+    }
+
+    /// Returns the encoding of this column, defaulting to `encoding`.
+    pub fn encoding(&self, encoding: Encoding) -> Encoding {
+        self.encoding.unwrap_or(encoding)
+    }
+}
@@ -7,33 +7,30 @@ use super::super::levels;
 use super::super::utils;
 use super::basic::{build_statistics, encode_plain};
 use crate::{
-    array::{Array, Offset, PrimitiveArray},
+    array::{Array, PrimitiveArray},
     error::Result,
     io::parquet::read::is_type_nullable,
     types::NativeType as ArrowNativeType,
 };
 
-pub fn array_to_page<T, R, O>(
+pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<O>,
+    nested: levels::NestedInfo,
 ) -> Result<DataPage>
 where
     T: ArrowNativeType,
     R: NativeType,
     T: num_traits::AsPrimitive<R>,
-    O: Offset,
 {
     let is_optional = is_type_nullable(descriptor.type_());
 
-    let validity = array.validity();
-
     let mut buffer = vec![];
     levels::write_rep_levels(&mut buffer, &nested, options.version)?;
     let repetition_levels_byte_length = buffer.len();
 
-    levels::write_def_levels(&mut buffer, &nested, validity, options.version)?;
+    levels::write_def_levels(&mut buffer, &nested, options.version)?;
     let definition_levels_byte_length = buffer.len() - repetition_levels_byte_length;
 
     encode_plain(array, is_optional, &mut buffer);
@@ -46,8 +43,9 @@ where
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
-        array.null_count(),
+        nested.num_values(),
+        nested.num_rows(),
+        nested.num_values() - array.len() + array.null_count(),
         repetition_levels_byte_length,
         definition_levels_byte_length,
         statistics,
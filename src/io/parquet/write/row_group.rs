@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use parquet2::FallibleStreamingIterator;
 use parquet2::{metadata::ColumnDescriptor, write::Compressor};
 
@@ -9,32 +11,59 @@ use crate::{
 };
 
 use super::{
-    array_to_pages, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    array_to_columns, num_leaves, to_parquet_schema, ColumnOptions, DynIter, DynStreamingIterator,
+    Encoding, RowGroupIter, SchemaDescriptor, WriteOptions,
 };
 
 /// Maps a [`Chunk`] and parquet-specific options to an [`RowGroupIter`] used to
-/// write to parquet
+/// write to parquet.
+/// `column_options` overrides `options` and `encodings` for the parquet (leaf) columns whose
+/// path in the parquet schema (see [`ColumnDescriptor::path_in_schema`]) is a key.
+/// # Panics
+/// Iff the number of `columns` differs from the number of parquet (leaf) columns of the chunk.
 pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
     chunk: Chunk<A>,
     encodings: Vec<Encoding>,
     columns: Vec<ColumnDescriptor>,
     options: WriteOptions,
+    column_options: &HashMap<Vec<String>, ColumnOptions>,
 ) -> RowGroupIter<'static, ArrowError> {
+    let mut columns = columns.into_iter().map(|descriptor| {
+        let column = column_options
+            .get(descriptor.path_in_schema())
+            .copied()
+            .unwrap_or_default();
+        (descriptor, column)
+    });
+    let arrays = chunk
+        .into_arrays()
+        .into_iter()
+        .map(|array| {
+            let num_leaves = num_leaves(array.as_ref().data_type());
+            let columns = columns.by_ref().take(num_leaves).collect::<Vec<_>>();
+            assert_eq!(columns.len(), num_leaves);
+            (array, columns)
+        })
+        .collect::<Vec<_>>();
+
     DynIter::new(
-        chunk
-            .into_arrays()
+        arrays
             .into_iter()
-            .zip(columns.into_iter())
-            .zip(encodings.into_iter())
-            .map(move |((array, descriptor), encoding)| {
-                array_to_pages(array.as_ref(), descriptor, options, encoding).map(move |pages| {
-                    let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
-                    let compressed_pages =
-                        Compressor::new(encoded_pages, options.compression, vec![])
-                            .map_err(ArrowError::from);
-                    DynStreamingIterator::new(compressed_pages)
-                })
+            .zip(encodings)
+            .flat_map(move |((array, columns), encoding)| {
+                match array_to_columns(array.as_ref(), columns, options, encoding) {
+                    Ok(columns) => columns
+                        .into_iter()
+                        .map(|pages| {
+                            let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
+                            let compressed_pages =
+                                Compressor::new(encoded_pages, options.compression, vec![])
+                                    .map_err(ArrowError::from);
+                            Ok(DynStreamingIterator::new(compressed_pages))
+                        })
+                        .collect::<Vec<_>>(),
+                    Err(error) => vec![Err(error)],
+                }
             }),
     )
 }
@@ -47,6 +76,7 @@ pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Re
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Encoding>,
+    column_options: HashMap<Vec<String>, ColumnOptions>,
 }
 
 impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
@@ -66,9 +96,17 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
             options,
             parquet_schema,
             encodings,
+            column_options: HashMap::new(),
         })
     }
 
+    /// Sets the [`ColumnOptions`] of the parquet (leaf) columns, by their path in the parquet
+    /// schema (see [`ColumnDescriptor::path_in_schema`]). They override the options and
+    /// encodings of this iterator for those columns.
+    pub fn set_column_options(&mut self, column_options: HashMap<Vec<String>, ColumnOptions>) {
+        self.column_options = column_options;
+    }
+
     /// Returns the [`SchemaDescriptor`] of the [`RowGroupIterator`].
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
@@ -93,6 +131,7 @@ impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chun
                     encodings,
                     self.parquet_schema.columns().to_vec(),
                     options,
+                    &self.column_options,
                 ),
                 len,
             ))
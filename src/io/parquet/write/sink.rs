@@ -3,7 +3,7 @@ use crate::{
     chunk::Chunk,
     datatypes::Schema,
     error::ArrowError,
-    io::parquet::write::{Encoding, SchemaDescriptor, WriteOptions},
+    io::parquet::write::{ColumnOptions, Encoding, SchemaDescriptor, WriteOptions},
 };
 use futures::{future::BoxFuture, AsyncWrite, FutureExt, Sink, TryFutureExt};
 use parquet2::metadata::KeyValue;
@@ -64,6 +64,7 @@ pub struct FileSink<'a, W: AsyncWrite + Send + Unpin> {
     encoding: Vec<Encoding>,
     schema: Schema,
     parquet_schema: SchemaDescriptor,
+    column_options: HashMap<Vec<String>, ColumnOptions>,
     /// Key-value metadata that will be written to the file on close.
     pub metadata: HashMap<String, Option<String>>,
 }
@@ -100,6 +101,7 @@ where
             schema,
             encoding,
             parquet_schema,
+            column_options: HashMap::default(),
             metadata: HashMap::default(),
         })
     }
@@ -119,6 +121,12 @@ where
         &self.options
     }
 
+    /// Sets the [`ColumnOptions`] of the parquet (leaf) columns, by their path in the parquet
+    /// schema. They override the options and encodings of the sink for those columns.
+    pub fn set_column_options(&mut self, column_options: HashMap<Vec<String>, ColumnOptions>) {
+        self.column_options = column_options;
+    }
+
     fn poll_complete(
         &mut self,
         cx: &mut std::task::Context<'_>,
@@ -156,6 +164,7 @@ where
                 this.encoding.clone(),
                 this.parquet_schema.columns().to_vec(),
                 this.options,
+                &this.column_options,
             );
             this.task = Some(Box::pin(async move {
                 writer.write(rows, count).await?;
@@ -61,6 +61,7 @@ pub fn write_def_levels(
 pub fn build_plain_page(
     buffer: Vec<u8>,
     len: usize,
+    num_rows: usize,
     null_count: usize,
     repetition_levels_byte_length: usize,
     definition_levels_byte_length: usize,
@@ -86,7 +87,7 @@ pub fn build_plain_page(
                 num_values: len as i32,
                 encoding: encoding.into(),
                 num_nulls: null_count as i32,
-                num_rows: len as i32,
+                num_rows: num_rows as i32,
                 definition_levels_byte_length: definition_levels_byte_length as i32,
                 repetition_levels_byte_length: repetition_levels_byte_length as i32,
                 is_compressed: Some(options.compression != Compression::Uncompressed),
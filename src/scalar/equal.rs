@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use super::*;
-use crate::types::days_ms;
+use crate::types::{days_ms, f16, i256};
 
 impl PartialEq for dyn Scalar + '_ {
     fn eq(&self, that: &dyn Scalar) -> bool {
@@ -40,7 +40,7 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
         return false;
     }
 
-    match lhs.data_type() {
+    match lhs.data_type().to_logical_type() {
         DataType::Null => {
             let lhs = lhs.as_any().downcast_ref::<NullScalar>().unwrap();
             let rhs = rhs.as_any().downcast_ref::<NullScalar>().unwrap();
@@ -85,10 +85,15 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
         DataType::Decimal(_, _) => {
             dyn_eq!(i128, lhs, rhs)
         }
+        DataType::Decimal256(_, _) => {
+            dyn_eq!(i256, lhs, rhs)
+        }
         DataType::Interval(IntervalUnit::DayTime) => {
             dyn_eq!(days_ms, lhs, rhs)
         }
-        DataType::Float16 => unreachable!(),
+        DataType::Float16 => {
+            dyn_eq!(f16, lhs, rhs)
+        }
         DataType::Float32 => {
             dyn_eq!(f32, lhs, rhs)
         }
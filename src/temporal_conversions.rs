@@ -45,9 +45,9 @@ pub fn date32_to_date(days: i32) -> NaiveDate {
 pub fn date64_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(
         // extract seconds from milliseconds
-        v / MILLISECONDS,
+        v.div_euclid(MILLISECONDS),
         // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
+        (v.rem_euclid(MILLISECONDS) * MICROSECONDS) as u32,
     )
 }
 
@@ -97,6 +97,16 @@ pub fn time64ns_to_time(v: i64) -> NaiveTime {
     )
 }
 
+/// Get the time unit as a multiple of a second
+pub const fn time_unit_multiple(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    }
+}
+
 /// converts a `i64` representing a `timestamp(s)` to [`NaiveDateTime`]
 #[inline]
 pub fn timestamp_s_to_datetime(seconds: i64) -> NaiveDateTime {
@@ -108,9 +118,9 @@ pub fn timestamp_s_to_datetime(seconds: i64) -> NaiveDateTime {
 pub fn timestamp_ms_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(
         // extract seconds from milliseconds
-        v / MILLISECONDS,
+        v.div_euclid(MILLISECONDS),
         // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
+        (v.rem_euclid(MILLISECONDS) * MICROSECONDS) as u32,
     )
 }
 
@@ -119,9 +129,9 @@ pub fn timestamp_ms_to_datetime(v: i64) -> NaiveDateTime {
 pub fn timestamp_us_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(
         // extract seconds from microseconds
-        v / MICROSECONDS,
+        v.div_euclid(MICROSECONDS),
         // discard extracted seconds and convert microseconds to nanoseconds
-        (v % MICROSECONDS * MILLISECONDS) as u32,
+        (v.rem_euclid(MICROSECONDS) * MILLISECONDS) as u32,
     )
 }
 
@@ -130,9 +140,9 @@ pub fn timestamp_us_to_datetime(v: i64) -> NaiveDateTime {
 pub fn timestamp_ns_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(
         // extract seconds from nanoseconds
-        v / NANOSECONDS,
+        v.div_euclid(NANOSECONDS),
         // discard extracted seconds
-        (v % NANOSECONDS) as u32,
+        v.rem_euclid(NANOSECONDS) as u32,
     )
 }
 
@@ -147,6 +157,22 @@ pub fn timestamp_to_naive_datetime(timestamp: i64, time_unit: TimeUnit) -> chron
     }
 }
 
+/// Converts a timestamp in `time_unit` into [`chrono::NaiveDateTime`], returning `None` iff
+/// it is out of the range of [`chrono::NaiveDateTime`].
+#[inline]
+pub fn timestamp_to_naive_datetime_opt(
+    timestamp: i64,
+    time_unit: TimeUnit,
+) -> Option<chrono::NaiveDateTime> {
+    let multiple = time_unit_multiple(time_unit);
+    chrono::DateTime::from_timestamp(
+        timestamp.div_euclid(multiple),
+        // the remainder is smaller than `multiple` and thus the nanoseconds fit in a `u32`
+        (timestamp.rem_euclid(multiple) * (NANOSECONDS / multiple)) as u32,
+    )
+    .map(|x| x.naive_utc())
+}
+
 /// Converts a timestamp in `time_unit` and `timezone` into [`chrono::DateTime`].
 #[inline]
 pub fn timestamp_to_datetime<T: chrono::TimeZone>(
@@ -181,32 +207,66 @@ pub fn timeunit_scale(a: TimeUnit, b: TimeUnit) -> f64 {
     }
 }
 
-/// Parses an offset of the form `"+WX:YZ"` or `"UTC"` into [`FixedOffset`].
+/// Parses an offset of the form `"+WX:YZ"`, `"-WX:YZ"`, `"+WXYZ"`, `"+WX"`, `"Z"` or `"UTC"`
+/// into [`FixedOffset`]. The sign is optional and defaults to `+`.
+///
+/// This is the timezone parsing shared by every kernel; named timezones such as
+/// `"America/New_York"` require the feature `chrono-tz` (see [`parse_offset_tz`]).
 /// # Errors
-/// If the offset is not in any of the allowed forms.
+/// If the offset is not in any of the allowed forms or is not within a day.
+/// # Examples
+/// ```
+/// use arrow2::temporal_conversions::parse_offset;
+///
+/// assert_eq!(parse_offset("-05:30").unwrap().local_minus_utc(), -(5 * 3600 + 30 * 60));
+/// assert_eq!(parse_offset("+0100").unwrap().local_minus_utc(), 3600);
+/// assert!(parse_offset("America/New_York").is_err());
+/// ```
 pub fn parse_offset(offset: &str) -> Result<FixedOffset> {
-    if offset == "UTC" {
+    if offset == "UTC" || offset == "Z" {
         return Ok(FixedOffset::east(0));
     }
-    let error = "timezone offset must be of the form [-]00:00";
-
-    let mut a = offset.split(':');
-    let first = a
-        .next()
-        .map(Ok)
-        .unwrap_or_else(|| Err(ArrowError::InvalidArgumentError(error.to_string())))?;
-    let last = a
-        .next()
-        .map(Ok)
-        .unwrap_or_else(|| Err(ArrowError::InvalidArgumentError(error.to_string())))?;
-    let hours: i32 = first
-        .parse()
-        .map_err(|_| ArrowError::InvalidArgumentError(error.to_string()))?;
-    let minutes: i32 = last
-        .parse()
-        .map_err(|_| ArrowError::InvalidArgumentError(error.to_string()))?;
-
-    Ok(FixedOffset::east(hours * 60 * 60 + minutes * 60))
+    let error = || {
+        ArrowError::InvalidArgumentError(format!(
+            "timezone offset \"{}\" must be of the form [-]00:00",
+            offset
+        ))
+    };
+
+    let (sign, rest) = match offset.as_bytes().first() {
+        Some(b'+') => (1, &offset[1..]),
+        Some(b'-') => (-1, &offset[1..]),
+        _ => (1, offset),
+    };
+    let (hours, minutes) = match rest.find(':') {
+        Some(index) => (&rest[..index], &rest[index + 1..]),
+        None if rest.len() == 4 => (&rest[..2], &rest[2..]),
+        None => (rest, "0"),
+    };
+
+    let parse = |x: &str| -> Result<i32> {
+        if x.is_empty() || x.len() > 2 || !x.bytes().all(|x| x.is_ascii_digit()) {
+            return Err(error());
+        }
+        x.parse().map_err(|_| error())
+    };
+    let hours = parse(hours)?;
+    let minutes = parse(minutes)?;
+    if minutes >= 60 {
+        return Err(error());
+    }
+
+    FixedOffset::east_opt(sign * (hours * 60 * 60 + minutes * 60)).ok_or_else(error)
+}
+
+/// The error of a timezone that is not an offset (see [`parse_offset`]) when the feature
+/// `chrono-tz` is not active.
+#[cfg(not(feature = "chrono-tz"))]
+pub(crate) fn chrono_tz_not_active(timezone: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" requires feature chrono-tz",
+        timezone
+    ))
 }
 
 /// Parses `value` to `Option<i64>` consistent with the Arrow's definition of timestamp with timezone.
@@ -284,10 +344,7 @@ fn chrono_tz_utf_to_timestamp_ns<O: Offset>(
     _: &str,
     timezone: String,
 ) -> Result<PrimitiveArray<i64>> {
-    Err(ArrowError::InvalidArgumentError(format!(
-        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
-        timezone
-    )))
+    Err(chrono_tz_not_active(&timezone))
 }
 
 /// Parses a [`Utf8Array`] to a timeozone-aware timestamp, i.e. [`PrimitiveArray<i64>`] with type `Timestamp(Nanosecond, Some(timezone))`.
@@ -333,9 +390,18 @@ fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
     chrono::NaiveDate::from_ymd(new_year, new_month as u32, 1)
 }
 
-fn get_days_between_months(year: i32, month: u32, months: i32) -> i64 {
-    add_month(year, month, months)
-        .signed_duration_since(chrono::NaiveDate::from_ymd(year, month, 1))
+/// Returns the number of days between `date` and `date` shifted by `months` calendar months.
+/// The day of the month is clamped to the last day of the resulting month,
+/// e.g. 2021-01-31 + 1 month = 2021-02-28.
+fn get_days_between_months(date: NaiveDate, months: i32) -> i64 {
+    let first = add_month(date.year(), date.month(), months);
+    let next = add_month(first.year(), first.month(), 1);
+    let days_in_month = next.signed_duration_since(first).num_days() as u32;
+
+    first
+        .with_day(date.day().min(days_in_month))
+        .unwrap()
+        .signed_duration_since(date)
         .num_days()
 }
 
@@ -351,8 +417,8 @@ pub fn add_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_
     };
 
     // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days = get_days_between_months(datetime.year(), datetime.month(), interval.months())
-        + interval.days() as i64;
+    let delta_days =
+        get_days_between_months(datetime.date(), interval.months()) + interval.days() as i64;
 
     // add; no leap hours are considered
     let new_datetime_tz = datetime
@@ -367,6 +433,19 @@ pub fn add_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_
     }
 }
 
+/// Adds an `interval` to a date represented as days since UNIX epoch ([`DataType::Date32`]).
+/// The nanoseconds of the interval are added as whole days, rounded towards negative infinity.
+#[inline]
+pub fn add_date32_interval(days: i32, interval: months_days_ns) -> i32 {
+    let date = date32_to_date(days);
+
+    let delta_days = get_days_between_months(date, interval.months())
+        + interval.days() as i64
+        + interval.ns().div_euclid(SECONDS_IN_DAY * NANOSECONDS);
+
+    (days as i64 + delta_days) as i32
+}
+
 /// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`.
 #[inline]
 pub fn add_interval<T: chrono::TimeZone>(
@@ -379,9 +458,8 @@ pub fn add_interval<T: chrono::TimeZone>(
     let datetime_tz = timestamp_to_datetime(timestamp, time_unit, timezone);
 
     // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days =
-        get_days_between_months(datetime_tz.year(), datetime_tz.month(), interval.months())
-            + interval.days() as i64;
+    let delta_days = get_days_between_months(datetime_tz.naive_local().date(), interval.months())
+        + interval.days() as i64;
 
     // add; tz will take care of leap hours
     let new_datetime_tz = datetime_tz
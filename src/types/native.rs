@@ -325,3 +325,758 @@ impl Neg for months_days_ns {
         Self::new(-self.months(), -self.days(), -self.ns())
     }
 }
+
+/// The in-memory representation of a signed 256-bit integer in two's complement,
+/// the physical type of the "Decimal256" logical type.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct i256 {
+    lo: u128,
+    hi: i128,
+}
+
+/// An unsigned 256-bit integer as its (upper, lower) 128 bits.
+type U256 = (u128, u128);
+
+#[inline]
+fn to_limbs(x: U256) -> [u64; 4] {
+    [
+        x.1 as u64,
+        (x.1 >> 64) as u64,
+        x.0 as u64,
+        (x.0 >> 64) as u64,
+    ]
+}
+
+#[inline]
+fn from_limbs(x: &[u64]) -> U256 {
+    (
+        x[2] as u128 | (x[3] as u128) << 64,
+        x[0] as u128 | (x[1] as u128) << 64,
+    )
+}
+
+/// Returns `lhs * rhs`, or `None` if it does not fit in 256 bits.
+fn mul_u256(lhs: U256, rhs: U256) -> Option<U256> {
+    let lhs = to_limbs(lhs);
+    let rhs = to_limbs(rhs);
+    let mut result = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let x = lhs[i] as u128 * rhs[j] as u128 + result[i + j] as u128 + carry;
+            result[i + j] = x as u64;
+            carry = x >> 64;
+        }
+        result[i + 4] = carry as u64;
+    }
+    if result[4..].iter().any(|x| *x != 0) {
+        None
+    } else {
+        Some(from_limbs(&result[..4]))
+    }
+}
+
+/// Returns the quotient and remainder of `lhs / rhs`, for `rhs <= 2^255`.
+/// # Panics
+/// Panics iff `rhs` is zero.
+fn div_rem_u256(lhs: U256, rhs: U256) -> (U256, U256) {
+    assert!(rhs != (0, 0), "attempt to divide by zero");
+    if lhs < rhs {
+        return ((0, 0), lhs);
+    }
+    let mut quotient = (0u128, 0u128);
+    let mut remainder = (0u128, 0u128);
+    for i in (0..256).rev() {
+        let bit = if i >= 128 {
+            (lhs.0 >> (i - 128)) & 1
+        } else {
+            (lhs.1 >> i) & 1
+        };
+        remainder = (
+            remainder.0 << 1 | remainder.1 >> 127,
+            remainder.1 << 1 | bit,
+        );
+        if remainder >= rhs {
+            let (lo, borrow) = remainder.1.overflowing_sub(rhs.1);
+            remainder = (remainder.0 - rhs.0 - borrow as u128, lo);
+            if i >= 128 {
+                quotient.0 |= 1 << (i - 128);
+            } else {
+                quotient.1 |= 1 << i;
+            }
+        }
+    }
+    (quotient, remainder)
+}
+
+impl i256 {
+    /// The largest value representable by an [`i256`], `2^255 - 1`.
+    pub const MAX: Self = Self {
+        lo: u128::MAX,
+        hi: i128::MAX,
+    };
+
+    /// The smallest value representable by an [`i256`], `-2^255`.
+    pub const MIN: Self = Self {
+        lo: 0,
+        hi: i128::MIN,
+    };
+
+    /// A new [`i256`] from its lower and upper 128 bits.
+    #[inline]
+    pub fn from_parts(lo: u128, hi: i128) -> Self {
+        Self { lo, hi }
+    }
+
+    /// The lower 128 bits
+    #[inline]
+    pub fn low(&self) -> u128 {
+        self.lo
+    }
+
+    /// The upper 128 bits
+    #[inline]
+    pub fn high(&self) -> i128 {
+        self.hi
+    }
+
+    /// Returns whether this value is negative
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.hi < 0
+    }
+
+    /// Returns this value as an `i128`, or `None` if it does not fit in one.
+    #[inline]
+    pub fn to_i128(&self) -> Option<i128> {
+        let lo = self.lo as i128;
+        if (self.hi == 0 && lo >= 0) || (self.hi == -1 && lo < 0) {
+            Some(lo)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `-self`, wrapping around at the boundary of the type.
+    #[inline]
+    pub fn wrapping_neg(self) -> Self {
+        let lo = (!self.lo).wrapping_add(1);
+        let hi = (!self.hi).wrapping_add((lo == 0) as i128);
+        Self { lo, hi }
+    }
+
+    /// Returns `-self`, or `None` on overflow.
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        if self == Self::MIN {
+            None
+        } else {
+            Some(self.wrapping_neg())
+        }
+    }
+
+    /// Returns `self + rhs`, or `None` on overflow.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let (hi, overflow1) = self.hi.overflowing_add(rhs.hi);
+        let (hi, overflow2) = hi.overflowing_add(carry as i128);
+        // the carry may undo an overflow of the upper bits
+        if overflow1 != overflow2 {
+            None
+        } else {
+            Some(Self { lo, hi })
+        }
+    }
+
+    /// Returns `self - rhs`, or `None` on overflow.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let (hi, overflow1) = self.hi.overflowing_sub(rhs.hi);
+        let (hi, overflow2) = hi.overflowing_sub(borrow as i128);
+        if overflow1 != overflow2 {
+            None
+        } else {
+            Some(Self { lo, hi })
+        }
+    }
+
+    #[inline]
+    fn unsigned_abs(self) -> U256 {
+        let abs = if self.is_negative() {
+            self.wrapping_neg()
+        } else {
+            self
+        };
+        (abs.hi as u128, abs.lo)
+    }
+
+    #[inline]
+    fn from_unsigned_abs(abs: U256, is_negative: bool) -> Option<Self> {
+        let value = Self {
+            lo: abs.1,
+            hi: abs.0 as i128,
+        };
+        match (is_negative, abs.0 > i128::MAX as u128) {
+            (false, false) => Some(value),
+            (false, true) => None,
+            (true, _) if abs <= (1 << 127, 0) => Some(value.wrapping_neg()),
+            (true, _) => None,
+        }
+    }
+
+    /// Returns `self * rhs`, or `None` on overflow.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let abs = mul_u256(self.unsigned_abs(), rhs.unsigned_abs())?;
+        Self::from_unsigned_abs(abs, self.is_negative() != rhs.is_negative())
+    }
+
+    /// Returns `self / rhs` rounded towards zero, or `None` if `rhs` is zero or on overflow.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::default() {
+            return None;
+        }
+        let (quotient, _) = div_rem_u256(self.unsigned_abs(), rhs.unsigned_abs());
+        Self::from_unsigned_abs(quotient, self.is_negative() != rhs.is_negative())
+    }
+
+    /// Returns `self % rhs` with the sign of `self`, or `None` if `rhs` is zero.
+    #[inline]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::default() {
+            return None;
+        }
+        let (_, remainder) = div_rem_u256(self.unsigned_abs(), rhs.unsigned_abs());
+        Self::from_unsigned_abs(remainder, self.is_negative())
+    }
+
+    /// Returns `self^exp`, or `None` on overflow.
+    #[inline]
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let mut base = self;
+        let mut result = Self::from(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+}
+
+impl From<i128> for i256 {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self {
+            lo: value as u128,
+            hi: if value < 0 { -1 } else { 0 },
+        }
+    }
+}
+
+impl PartialOrd for i256 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for i256 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.hi, self.lo).cmp(&(other.hi, other.lo))
+    }
+}
+
+impl NativeType for i256 {
+    const PRIMITIVE: PrimitiveType = PrimitiveType::Int256;
+    type Bytes = [u8; 32];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.lo.to_le_bytes());
+        result[16..].copy_from_slice(&self.hi.to_le_bytes());
+        result
+    }
+
+    #[inline]
+    fn to_ne_bytes(&self) -> Self::Bytes {
+        #[cfg(target_endian = "little")]
+        {
+            self.to_le_bytes()
+        }
+        #[cfg(target_endian = "big")]
+        {
+            self.to_be_bytes()
+        }
+    }
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.hi.to_be_bytes());
+        result[16..].copy_from_slice(&self.lo.to_be_bytes());
+        result
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        let mut hi = [0; 16];
+        hi.copy_from_slice(&bytes[..16]);
+        let mut lo = [0; 16];
+        lo.copy_from_slice(&bytes[16..]);
+        Self {
+            lo: u128::from_be_bytes(lo),
+            hi: i128::from_be_bytes(hi),
+        }
+    }
+}
+
+impl std::fmt::Display for i256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // the value in base 10^19, from the least significant digit
+        const BASE: U256 = (0, 10_000_000_000_000_000_000);
+        let mut digits = vec![];
+        let mut abs = self.unsigned_abs();
+        loop {
+            let (quotient, remainder) = div_rem_u256(abs, BASE);
+            digits.push(remainder.1 as u64);
+            abs = quotient;
+            if abs == (0, 0) {
+                break;
+            }
+        }
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        let mut digits = digits.iter().rev();
+        write!(f, "{}", digits.next().unwrap())?;
+        digits.try_for_each(|x| write!(f, "{:019}", x))
+    }
+}
+
+impl std::fmt::Debug for i256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// The in-memory representation of an IEEE 754 half-precision (16-bit) floating point number,
+/// the physical type of arrow's "Float16" logical type.
+///
+/// Values are stored as their bits; arithmetic is done by converting to [`f32`].
+#[derive(Copy, Clone, Default, Zeroable, Pod)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct f16(u16);
+
+impl f16 {
+    /// Positive zero, `0.0`.
+    pub const ZERO: Self = Self(0x0000);
+    /// One, `1.0`.
+    pub const ONE: Self = Self(0x3C00);
+    /// The largest finite value, `65504.0`.
+    pub const MAX: Self = Self(0x7BFF);
+    /// The smallest finite value, `-65504.0`.
+    pub const MIN: Self = Self(0xFBFF);
+    /// Positive infinity.
+    pub const INFINITY: Self = Self(0x7C00);
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Self(0xFC00);
+    /// Not a number.
+    pub const NAN: Self = Self(0x7E00);
+
+    /// A new [`f16`] from its bits.
+    #[inline]
+    pub fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// The bits of this value
+    #[inline]
+    pub fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns whether this value is NaN
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7C00 == 0x7C00 && self.0 & 0x03FF != 0
+    }
+
+    /// Converts an [`f32`] to the nearest [`f16`], rounding ties to even.
+    /// Values out of range become infinite.
+    pub fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+        let sign = (x & 0x8000_0000) >> 16;
+        let exp = x & 0x7F80_0000;
+        let man = x & 0x007F_FFFF;
+
+        // infinity or NaN; NaNs keep (part of) their payload and stay NaN
+        if exp == 0x7F80_0000 {
+            let nan_bit = if man == 0 { 0 } else { 0x0200 };
+            return Self((sign | 0x7C00 | nan_bit | (man >> 13)) as u16);
+        }
+
+        let half_exp = (exp >> 23) as i32 - 127 + 15;
+        if half_exp >= 0x1F {
+            // overflow
+            return Self((sign | 0x7C00) as u16);
+        }
+        if half_exp <= 0 {
+            // subnormal or underflow
+            if 14 - half_exp > 24 {
+                return Self(sign as u16);
+            }
+            let man = man | 0x0080_0000;
+            let mut half_man = man >> (14 - half_exp);
+            let round_bit = 1 << (13 - half_exp);
+            if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+                half_man += 1;
+            }
+            return Self((sign | half_man) as u16);
+        }
+
+        let bits = sign | (half_exp as u32) << 10 | man >> 13;
+        let round_bit = 0x0000_1000;
+        // a carry into the exponent correctly rounds up to the next power of two (or infinity)
+        if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+            Self((bits + 1) as u16)
+        } else {
+            Self(bits as u16)
+        }
+    }
+
+    /// Converts an [`f64`] to the nearest [`f16`], rounding ties to even.
+    /// Values out of range become infinite.
+    pub fn from_f64(value: f64) -> Self {
+        let x = value.to_bits();
+        let sign = ((x >> 48) & 0x8000) as u32;
+        let exp = (x >> 52) & 0x7FF;
+        let man = x & 0x000F_FFFF_FFFF_FFFF;
+
+        if exp == 0x7FF {
+            let nan_bit = if man == 0 { 0 } else { 0x0200 };
+            return Self((sign | 0x7C00 | nan_bit | (man >> 42) as u32) as u16);
+        }
+
+        let half_exp = exp as i64 - 1023 + 15;
+        if half_exp >= 0x1F {
+            return Self((sign | 0x7C00) as u16);
+        }
+        if half_exp <= 0 {
+            if 43 - half_exp > 53 {
+                return Self(sign as u16);
+            }
+            let man = man | 0x0010_0000_0000_0000;
+            let mut half_man = (man >> (43 - half_exp)) as u32;
+            let round_bit = 1u64 << (42 - half_exp);
+            if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+                half_man += 1;
+            }
+            return Self((sign | half_man) as u16);
+        }
+
+        let bits = sign | (half_exp as u32) << 10 | (man >> 42) as u32;
+        let round_bit = 1u64 << 41;
+        if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+            Self((bits + 1) as u16)
+        } else {
+            Self(bits as u16)
+        }
+    }
+
+    /// Converts this value to an [`f32`]. This conversion is lossless.
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exp = (self.0 & 0x7C00) as u32;
+        let man = (self.0 & 0x03FF) as u32;
+
+        if exp == 0x7C00 {
+            // infinity or NaN
+            return f32::from_bits(sign | 0x7F80_0000 | man << 13);
+        }
+        if exp == 0 {
+            if man == 0 {
+                return f32::from_bits(sign);
+            }
+            // subnormal: normalize the mantissa
+            let shift = man.leading_zeros() - 22;
+            let exp = (127 - 15 - shift) << 23;
+            let man = (man << (14 + shift)) & 0x007F_FFFF;
+            return f32::from_bits(sign | exp | man);
+        }
+        f32::from_bits(sign | ((exp >> 10) + 127 - 15) << 23 | man << 13)
+    }
+
+    /// Converts this value to an [`f64`]. This conversion is lossless.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+}
+
+impl From<f16> for f32 {
+    #[inline]
+    fn from(value: f16) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<f16> for f64 {
+    #[inline]
+    fn from(value: f16) -> Self {
+        value.to_f64()
+    }
+}
+
+// equality and order follow IEEE 754, like `f32`: `NaN != NaN` and `-0.0 == 0.0`
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl Neg for f16 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ 0x8000)
+    }
+}
+
+impl NativeType for f16 {
+    const PRIMITIVE: PrimitiveType = PrimitiveType::Float16;
+    type Bytes = [u8; 2];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_ne_bytes(&self) -> Self::Bytes {
+        self.0.to_ne_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self(u16::from_be_bytes(bytes))
+    }
+}
+
+impl std::fmt::Display for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+impl std::fmt::Debug for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pow10(exp: u32) -> i256 {
+        i256::from(10).checked_pow(exp).unwrap()
+    }
+
+    #[test]
+    fn i256_from_to_i128() {
+        for x in [0, 1, -1, i128::MAX, i128::MIN] {
+            assert_eq!(i256::from(x).to_i128(), Some(x));
+        }
+        let larger = i256::from(i128::MAX).checked_add(i256::from(1)).unwrap();
+        assert_eq!(larger.to_i128(), None);
+        let smaller = i256::from(i128::MIN).checked_sub(i256::from(1)).unwrap();
+        assert_eq!(smaller.to_i128(), None);
+    }
+
+    #[test]
+    fn i256_add_sub() {
+        let a = i256::from_parts(u128::MAX, 0);
+        let b = a.checked_add(i256::from(1)).unwrap();
+        assert_eq!(b, i256::from_parts(0, 1));
+        assert_eq!(b.checked_sub(i256::from(1)), Some(a));
+
+        assert_eq!(i256::MAX.checked_add(i256::from(1)), None);
+        assert_eq!(i256::MIN.checked_sub(i256::from(1)), None);
+        assert_eq!(i256::MIN.checked_add(i256::from(-1)), None);
+        assert_eq!(i256::MAX.checked_add(i256::MIN), Some(i256::from(-1)));
+    }
+
+    #[test]
+    fn i256_neg() {
+        assert_eq!(i256::from(5).wrapping_neg(), i256::from(-5));
+        assert_eq!(i256::from(0).wrapping_neg(), i256::from(0));
+        assert_eq!(i256::MIN.checked_neg(), None);
+        assert_eq!(
+            i256::MAX.checked_neg().unwrap().checked_sub(i256::from(1)),
+            Some(i256::MIN)
+        );
+    }
+
+    #[test]
+    fn i256_mul_div_rem() {
+        let a = pow10(40);
+        let b = i256::from(-3);
+        let c = a.checked_mul(b).unwrap();
+        assert!(c.is_negative());
+        assert_eq!(c.checked_div(b), Some(a));
+        assert_eq!(c.checked_div(a), Some(b));
+
+        let d = c.checked_add(i256::from(-2)).unwrap();
+        assert_eq!(d.checked_div(a), Some(b));
+        assert_eq!(d.checked_rem(a), Some(i256::from(-2)));
+        assert_eq!(
+            i256::from(7).checked_rem(i256::from(-2)),
+            Some(i256::from(1))
+        );
+
+        assert_eq!(pow10(76).checked_mul(i256::from(10)), None);
+        assert_eq!(i256::MIN.checked_mul(i256::from(-1)), None);
+        assert_eq!(i256::MIN.checked_div(i256::from(-1)), None);
+        assert_eq!(a.checked_div(i256::default()), None);
+        assert_eq!(
+            i256::MIN
+                .checked_div(i256::from(2))
+                .unwrap()
+                .checked_mul(i256::from(2)),
+            Some(i256::MIN)
+        );
+    }
+
+    #[test]
+    fn i256_pow() {
+        assert_eq!(i256::from(10).checked_pow(0), Some(i256::from(1)));
+        assert_eq!(pow10(38).to_i128(), Some(10i128.pow(38)));
+        assert_eq!(i256::from(10).checked_pow(77), None);
+        assert_eq!(i256::from(2).checked_pow(255), None);
+        assert_eq!(i256::from(-2).checked_pow(255), Some(i256::MIN));
+    }
+
+    #[test]
+    fn i256_ord() {
+        let mut values = vec![
+            i256::MAX,
+            i256::from(-1),
+            pow10(50),
+            i256::MIN,
+            i256::from(0),
+            pow10(50).wrapping_neg(),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![
+                i256::MIN,
+                pow10(50).wrapping_neg(),
+                i256::from(-1),
+                i256::from(0),
+                pow10(50),
+                i256::MAX,
+            ]
+        );
+    }
+
+    #[test]
+    fn i256_display() {
+        assert_eq!(i256::from(0).to_string(), "0");
+        assert_eq!(i256::from(-12).to_string(), "-12");
+        assert_eq!(pow10(40).to_string(), format!("1{}", "0".repeat(40)));
+        assert_eq!(
+            i256::MAX.to_string(),
+            "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+        );
+        assert_eq!(
+            i256::MIN.to_string(),
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+    }
+
+    #[test]
+    fn i256_bytes() {
+        let value = i256::from(-2);
+        let le = value.to_le_bytes();
+        assert_eq!(le[0], 0xfe);
+        assert!(le[1..].iter().all(|x| *x == 0xff));
+
+        let value = i256::from_parts(1, 2);
+        let be = value.to_be_bytes();
+        assert_eq!(be[15], 2);
+        assert_eq!(be[31], 1);
+        assert_eq!(i256::from_be_bytes(be), value);
+        assert_eq!(bytemuck::bytes_of(&value), &value.to_ne_bytes() as &[u8]);
+    }
+
+    #[test]
+    fn f16_roundtrip() {
+        for bits in 0..=u16::MAX {
+            let value = f16::from_bits(bits);
+            if value.is_nan() {
+                assert!(value.to_f32().is_nan());
+                assert!(f16::from_f32(value.to_f32()).is_nan());
+                assert!(f16::from_f64(value.to_f64()).is_nan());
+            } else {
+                assert_eq!(f16::from_f32(value.to_f32()).to_bits(), bits);
+                assert_eq!(f16::from_f64(value.to_f64()).to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn f16_from_float() {
+        assert_eq!(f16::from_f32(1.0).to_bits(), f16::ONE.to_bits());
+        assert_eq!(f16::from_f32(-2.5).to_f32(), -2.5);
+        assert_eq!(f16::from_f32(65504.0).to_bits(), f16::MAX.to_bits());
+        assert_eq!(f16::from_f32(1e6).to_bits(), f16::INFINITY.to_bits());
+        assert_eq!(f16::from_f64(-1e6).to_bits(), f16::NEG_INFINITY.to_bits());
+        assert!(f16::from_f32(f32::NAN).is_nan());
+        // the smallest subnormal, 2^-24, and half of it (a tie, rounded to even zero)
+        assert_eq!(f16::from_f32(2f32.powi(-24)).to_bits(), 1);
+        assert_eq!(f16::from_f64(2f64.powi(-25)).to_bits(), 0);
+        assert_eq!(f16::from_f64(-1e-10).to_bits(), 0x8000);
+
+        // 1 + 2^-11 is a tie between 1 and 1 + 2^-10: rounds to even (1)
+        let tie = 1.0 + 2f64.powi(-11);
+        assert_eq!(f16::from_f32(tie as f32).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f64(tie).to_bits(), 0x3C00);
+        // ... unless it is above the tie
+        assert_eq!(f16::from_f64(tie + 2f64.powi(-40)).to_bits(), 0x3C01);
+    }
+
+    #[test]
+    fn f16_cmp() {
+        assert_eq!(f16::from_bits(0x8000), f16::ZERO);
+        assert!(f16::NAN != f16::NAN);
+        assert!(f16::MIN < f16::ONE);
+        assert_eq!((-f16::ONE).to_f32(), -1.0);
+        assert_eq!(f16::from_f32(0.5).to_string(), "0.5");
+    }
+}
@@ -1,7 +1,7 @@
 //! Contains traits and implementations of multi-data used in SIMD.
 //! The actual representation is driven by the feature flag `"simd"`, which, if set,
 //! uses `packed_simd2` to get the intrinsics.
-use super::{days_ms, months_days_ns};
+use super::{days_ms, f16, i256, months_days_ns};
 use super::{BitChunk, BitChunkIter, NativeType};
 
 /// Describes the ability to convert itself from a [`BitChunk`].
@@ -132,6 +132,8 @@ pub(super) use native_simd;
 native_simd!(days_msx8, days_ms, 8, u8);
 native_simd!(months_days_nsx8, months_days_ns, 8, u8);
 native_simd!(i128x8, i128, 8, u8);
+native_simd!(i256x8, i256, 8, u8);
+native_simd!(f16x8, f16, 8, u8);
 
 // In the native implementation, a mask is 1 bit wide, as per AVX512.
 impl<T: BitChunk> FromMaskChunk<T> for T {
@@ -160,5 +162,7 @@ native!(i64, i64x8);
 native!(f32, f32x16);
 native!(f64, f64x8);
 native!(i128, i128x8);
+native!(i256, i256x8);
+native!(f16, f16x8);
 native!(days_ms, days_msx8);
 native!(months_days_ns, months_days_nsx8);
@@ -142,3 +142,46 @@ fn debug() {
 
     assert_eq!(format!("{:?}", array), "BinaryArray[[1, 2], [], None]");
 }
+
+#[test]
+fn negative_offsets() {
+    let offsets = Buffer::from_slice([-1, 2]);
+    let values = Buffer::from_slice(b"abc");
+    assert!(BinaryArray::<i32>::try_new(DataType::Binary, offsets, values, None).is_err());
+}
+
+proptest::proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well
+    fn try_new_corrupted_offsets(offsets in proptest::collection::vec(-2i32..6, 0..6)) {
+        let values = b"abcd";
+        let result = BinaryArray::<i32>::try_new(
+            DataType::Binary,
+            offsets.clone().into(),
+            Buffer::from_slice(values),
+            None,
+        );
+
+        // the array is accepted iff every slot is an in-bounds slice
+        let in_bounds = |o: &i32| (0..=values.len() as i32).contains(o);
+        let expected = (!offsets.is_empty() && offsets.iter().all(in_bounds))
+            .then(|| {
+                offsets
+                    .windows(2)
+                    .map(|w| {
+                        let start = usize::try_from(w[0]).ok()?;
+                        let end = usize::try_from(w[1]).ok()?;
+                        values.get(start..end)
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .flatten();
+        match (result, expected) {
+            (Ok(array), Some(expected)) => {
+                assert_eq!(array.values_iter().collect::<Vec<_>>(), expected)
+            }
+            (Err(_), None) => {}
+            (result, expected) => panic!("{:?} {:?}", result, expected),
+        }
+    }
+}
@@ -2,6 +2,7 @@ use std::ops::Deref;
 
 use arrow2::array::{BinaryArray, MutableArray, MutableBinaryArray};
 use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::DataType;
 use arrow2::error::ArrowError;
 
 #[test]
@@ -107,3 +108,22 @@ fn extend_trusted_len() {
         Some(&Bitmap::from_u8_slice(&[0b00001011], 4))
     );
 }
+
+#[test]
+fn try_extend_trusted_len_overflow() {
+    // the values are zeroed, so this does not touch (most of) its memory
+    let values = vec![0u8; i32::MAX as usize - 1];
+    let offsets = vec![0, i32::MAX - 1];
+    let mut array = MutableBinaryArray::<i32>::from_data(DataType::Binary, offsets, values, None);
+
+    let result = array.try_extend_trusted_len(vec![None, Some(b"ab")].into_iter());
+    assert!(matches!(result, Err(ArrowError::Overflow)));
+    let result = array.try_extend_trusted_len_values(vec![b"ab"].into_iter());
+    assert!(matches!(result, Err(ArrowError::Overflow)));
+
+    // the array is left unchanged
+    assert_eq!(array.len(), 1);
+    assert_eq!(array.offsets().as_slice(), &[0, i32::MAX - 1]);
+    assert_eq!(array.values().len(), i32::MAX as usize - 1);
+    assert_eq!(array.validity(), None);
+}
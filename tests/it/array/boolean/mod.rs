@@ -47,6 +47,35 @@ fn with_validity() {
     assert!(a.validity().is_some());
 }
 
+#[test]
+fn set_validity() {
+    let mut a = BooleanArray::from([Some(true), None, Some(false), Some(true)]).slice(1, 3);
+    a.set_validity(Some(Bitmap::from([true, true, false])));
+    assert_eq!(a, BooleanArray::from([Some(false), Some(false), None]));
+
+    a.set_validity(None);
+    assert_eq!(a.null_count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn set_validity_wrong_length() {
+    let mut a = BooleanArray::from_slice([true, false, true]);
+    a.set_validity(Some(Bitmap::from([true, false])));
+}
+
+#[test]
+fn apply_validity() {
+    let mut a = BooleanArray::from([Some(true), None, Some(false)]);
+    a.apply_validity(|bitmap| !bitmap);
+    assert_eq!(a, BooleanArray::from([None, Some(false), None]));
+
+    // without validity, all slots are valid
+    let mut a = BooleanArray::from_slice([true, false]);
+    a.apply_validity(|bitmap| bitmap & &Bitmap::from([true, false]));
+    assert_eq!(a, BooleanArray::from([Some(true), None]));
+}
+
 #[test]
 fn debug() {
     let array = BooleanArray::from([Some(true), None, Some(false)]);
@@ -1,4 +1,4 @@
-use arrow2::array::{MutableArray, MutableBooleanArray};
+use arrow2::array::{Array, BooleanArray, MutableArray, MutableBooleanArray};
 use arrow2::bitmap::MutableBitmap;
 use arrow2::datatypes::DataType;
 use arrow2::error::Result;
@@ -16,6 +16,39 @@ fn set() {
     assert_eq!(a.values(), &MutableBitmap::from([true, false, false]));
 }
 
+#[test]
+fn set_toggle() {
+    let mut a = MutableBooleanArray::from_slice(&[true, false, true]);
+    assert_eq!(a.validity(), None);
+
+    a.set(1, None);
+    assert_eq!(a.get(1), None);
+    a.set(1, Some(true));
+    assert_eq!(a.get(1), Some(true));
+    a.set(1, None);
+    a.set(2, Some(false));
+
+    assert_eq!(a.get(0), Some(true));
+    assert_eq!(a.get(2), Some(false));
+    let a: BooleanArray = a.into();
+    assert_eq!(a.null_count(), 1);
+    assert_eq!(a, BooleanArray::from([Some(true), None, Some(false)]));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn set_out_of_bounds() {
+    let mut a = MutableBooleanArray::from_slice(&[true]);
+    a.set(1, Some(true));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn get_out_of_bounds() {
+    let a = MutableBooleanArray::from_slice(&[true]);
+    a.get(1);
+}
+
 #[test]
 fn push() {
     let mut a = MutableBooleanArray::new();
@@ -97,3 +130,18 @@ fn extend_trusted_len_values() {
     );
     assert_eq!(a.values(), &MutableBitmap::from([false, true, false]));
 }
+
+#[test]
+fn try_extend_from_array() -> Result<()> {
+    let other = BooleanArray::from(&[Some(true), None, Some(false), Some(true)]).slice(1, 3);
+
+    let mut a = MutableBooleanArray::from_slice(&[false]);
+    a.try_extend_from_array(&other, 0, 3)?;
+
+    let a: BooleanArray = a.into();
+    assert_eq!(
+        a,
+        BooleanArray::from(&[Some(false), None, Some(false), Some(true)])
+    );
+    Ok(())
+}
@@ -1 +1,62 @@
 mod mutable;
+
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn with_validity() {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"]));
+    let keys = Int32Array::from_slice(&[0, 1, 0]);
+    let array = DictionaryArray::<i32>::from_data(keys, values.clone());
+
+    let array = array.with_validity(Some([true, false, true].into()));
+
+    assert_eq!(array.keys(), &Int32Array::from(&[Some(0), None, Some(0)]));
+    assert_eq!(array.values().as_ref(), values.as_ref() as &dyn Array);
+}
+
+#[test]
+fn try_new_out_of_bounds() {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"])) as Arc<dyn Array>;
+
+    let keys = Int32Array::from_slice(&[0, 2]);
+    assert!(DictionaryArray::<i32>::try_new(keys, values.clone()).is_err());
+
+    let keys = Int32Array::from_slice(&[-1, 0]);
+    assert!(DictionaryArray::<i32>::try_new(keys, values.clone()).is_err());
+
+    // null keys are not checked
+    let keys = Int32Array::from_data(
+        DataType::Int32,
+        vec![0, 2].into(),
+        Some([true, false].into()),
+    );
+    let array = DictionaryArray::<i32>::try_new(keys, values.clone()).unwrap();
+    assert_eq!(array.len(), 2);
+
+    // not even on empty dictionaries
+    let array = DictionaryArray::<i32>::new_null(array.data_type().clone(), 2);
+    assert_eq!(array.keys().null_count(), 2);
+}
+
+#[test]
+#[should_panic]
+fn from_data_out_of_bounds() {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"]));
+    let keys = Int32Array::from_slice(&[0, 2]);
+    DictionaryArray::<i32>::from_data(keys, values);
+}
+
+proptest::proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well
+    fn try_new_corrupted_keys(keys in proptest::collection::vec(proptest::option::of(-2i8..5), 0..6)) {
+        let values = Arc::new(Utf8Array::<i32>::from_slice(&["a", "b", "c"])) as Arc<dyn Array>;
+
+        let is_valid = keys.iter().flatten().all(|key| (0..3).contains(key));
+        let result = DictionaryArray::<i8>::try_new(Int8Array::from(keys), values);
+        assert_eq!(result.is_ok(), is_valid);
+    }
+}
@@ -38,3 +38,91 @@ fn binary_natural() -> Result<()> {
     assert_eq!(a.values().len(), 2);
     Ok(())
 }
+
+#[test]
+fn try_push_dedups() -> Result<()> {
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_push(Some("a"))?;
+    a.try_push(Some("b"))?;
+    a.push_null();
+    for i in 0..100 {
+        a.try_push(Some(format!("c{}", i)))?;
+    }
+    // equal bytes map to the same key irrespectively of their type and position
+    a.try_push(Some("a".to_string()))?;
+    a.try_push(Some("b"))?;
+    assert_eq!(a.len(), 105);
+    assert_eq!(a.values().len(), 102);
+
+    let array: DictionaryArray<i32> = a.into();
+    let keys = array.keys();
+    assert_eq!(keys.value(103), keys.value(0));
+    assert_eq!(keys.value(104), keys.value(1));
+    assert!(keys.is_null(2));
+    Ok(())
+}
+
+#[test]
+fn try_push_key_overflow() -> Result<()> {
+    let mut a = MutableDictionaryArray::<i8, MutablePrimitiveArray<i32>>::new();
+    for i in 0..128 {
+        a.try_push(Some(i))?;
+    }
+    // existing values and nulls can still be pushed
+    a.try_push(Some(0))?;
+    a.push_null();
+    assert!(a.try_push(Some(128)).is_err());
+    assert_eq!(a.len(), 130);
+    assert_eq!(a.values().len(), 128);
+    Ok(())
+}
+
+#[test]
+fn as_box() -> Result<()> {
+    let mut a = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    a.try_extend(vec![Some("a"), None, Some("b"), Some("a")])?;
+
+    let array = a.as_box();
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    assert_eq!(
+        array.keys(),
+        &UInt8Array::from(&[Some(0), None, Some(1), Some(0)])
+    );
+    let expected = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    assert_eq!(array.values().as_ref(), &expected as &dyn Array);
+
+    Ok(())
+}
+
+#[test]
+fn as_arc_resets_dictionary() -> Result<()> {
+    let mut a = MutableDictionaryArray::<u8, MutablePrimitiveArray<i32>>::new();
+    a.try_extend(vec![Some(1), Some(2)])?;
+    let _ = a.as_arc();
+    assert_eq!(a.len(), 0);
+
+    a.try_push(Some(2))?;
+    let array = a.as_arc();
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    assert_eq!(array.keys(), &UInt8Array::from_slice(&[0]));
+    let expected = Int32Array::from_slice(&[2]);
+    assert_eq!(array.values().as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn try_extend_from_array_unsupported() -> Result<()> {
+    let mut other = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    other.try_extend(vec![Some("a"), None])?;
+    let other: DictionaryArray<i32> = other.into();
+
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    assert!(a.try_extend_from_array(&other, 0, 2).is_err());
+    Ok(())
+}
@@ -0,0 +1,170 @@
+//! Asserts that a sliced array equals a new array built from the same range of values,
+//! across arbitrary offsets and null patterns.
+use std::sync::Arc;
+
+use proptest::prelude::*;
+
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field};
+
+/// Returns a strategy of arbitrary values, an offset and a length within them
+fn sliced_strategy<T: Arbitrary + Clone>(
+    element: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = (Vec<Option<T>>, usize, usize)> {
+    prop::collection::vec(prop::option::of(element), 1..100)
+        .prop_flat_map(|vec| {
+            let len = vec.len();
+            (Just(vec), 0..len)
+        })
+        .prop_flat_map(|(vec, offset)| {
+            let len = vec.len();
+            (Just(vec), Just(offset), 0..=len - offset)
+        })
+}
+
+fn list(values: &[Option<Vec<Option<i32>>>]) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(values.iter().cloned()).unwrap();
+    array.into()
+}
+
+fn dictionary(values: &[Option<String>]) -> DictionaryArray<i32> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().cloned()).unwrap();
+    array.into()
+}
+
+fn struct_(values: &[Option<(Option<i32>, Option<bool>)>]) -> StructArray {
+    let ints = values
+        .iter()
+        .map(|x| x.and_then(|x| x.0))
+        .collect::<Int32Array>();
+    let booleans = values
+        .iter()
+        .map(|x| x.and_then(|x| x.1))
+        .collect::<BooleanArray>();
+    let validity = values.iter().map(|x| x.is_some()).collect::<Bitmap>();
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Boolean, true),
+    ];
+    StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(ints), Arc::new(booleans)],
+        Some(validity),
+    )
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn primitive((values, offset, length) in sliced_strategy(any::<i32>())) {
+        let sliced = Int32Array::from(&values).slice(offset, length);
+        let expected = Int32Array::from(&values[offset..offset + length]);
+
+        prop_assert_eq!(&sliced, &expected);
+        prop_assert!(&sliced as &dyn Array == &expected as &dyn Array);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn boolean((values, offset, length) in sliced_strategy(any::<bool>())) {
+        let sliced = BooleanArray::from(&values).slice(offset, length);
+        let expected = BooleanArray::from(&values[offset..offset + length]);
+
+        prop_assert_eq!(sliced, expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn utf8((values, offset, length) in sliced_strategy(".{0,5}")) {
+        let sliced = Utf8Array::<i32>::from(&values).slice(offset, length);
+        let expected = Utf8Array::<i32>::from(&values[offset..offset + length]);
+        prop_assert_eq!(&sliced, &expected);
+
+        // same values behind offsets that start at a different base
+        let mut prefixed = vec![Some("prefix".to_string())];
+        prefixed.extend_from_slice(&values[offset..offset + length]);
+        let rebased = Utf8Array::<i32>::from(&prefixed).slice(1, length);
+        prop_assert_eq!(&sliced, &rebased);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn binary((values, offset, length) in sliced_strategy(prop::collection::vec(any::<u8>(), 0..5))) {
+        let sliced = BinaryArray::<i64>::from(&values).slice(offset, length);
+        let expected = BinaryArray::<i64>::from(&values[offset..offset + length]);
+
+        prop_assert_eq!(sliced, expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn fixed_size_binary((values, offset, length) in sliced_strategy(prop::array::uniform3(any::<u8>()))) {
+        let array = |values: &[Option<[u8; 3]>]| {
+            FixedSizeBinaryArray::from_iter(values.iter().copied(), 3)
+        };
+        let sliced = array(&values).slice(offset, length);
+        let expected = array(&values[offset..offset + length]);
+
+        prop_assert_eq!(sliced, expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn list_(
+        (values, offset, length) in sliced_strategy(prop::collection::vec(any::<Option<i32>>(), 0..4))
+    ) {
+        let sliced = list(&values).slice(offset, length);
+        let expected = list(&values[offset..offset + length]);
+
+        prop_assert_eq!(sliced, expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn dictionary_((values, offset, length) in sliced_strategy("[a-c]")) {
+        // the rebuilt dictionary has different values and keys than the sliced one
+        let sliced = dictionary(&values).slice(offset, length);
+        let expected = dictionary(&values[offset..offset + length]);
+
+        prop_assert_eq!(sliced, expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn struct__(
+        (values, offset, length) in sliced_strategy((any::<Option<i32>>(), any::<Option<bool>>()))
+    ) {
+        let sliced = struct_(&values).slice(offset, length);
+        let expected = struct_(&values[offset..offset + length]);
+        prop_assert_eq!(&sliced, &expected);
+
+        // values behind null slots are not compared
+        let expected = StructArray::from_data(
+            expected.data_type().clone(),
+            expected.values().iter().map(|x| new_null_array(x.data_type().clone(), x.len()).into()).collect(),
+            expected.validity().cloned(),
+        );
+        let all_null = values[offset..offset + length]
+            .iter()
+            .all(|x| matches!(x, None | Some((None, None))));
+        prop_assert_eq!(sliced == expected, all_null);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn different_values(
+        (lhs, rhs) in (1..20usize).prop_flat_map(|len| (
+            prop::collection::vec(prop::option::of(0..3i32), len),
+            prop::collection::vec(prop::option::of(0..3i32), len),
+        ))
+    ) {
+        let lhs_array = Int32Array::from(&lhs);
+        let rhs_array = Int32Array::from(&rhs);
+
+        prop_assert_eq!(lhs_array == rhs_array, lhs == rhs);
+    }
+}
@@ -56,3 +56,48 @@ fn from_iter() {
     let a = FixedSizeBinaryArray::from_iter(iter, 2);
     assert_eq!(a.len(), 2);
 }
+
+#[test]
+fn iter() {
+    let array = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(2),
+        Buffer::from_slice([1, 2, 3, 4, 5, 6, 7, 8]),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    let iter = array.iter();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        vec![Some(&[1, 2][..]), None, Some(&[5, 6]), Some(&[7, 8])]
+    );
+    assert_eq!(
+        array.values_iter().rev().collect::<Vec<_>>(),
+        vec![&[7, 8][..], &[5, 6], &[3, 4], &[1, 2]]
+    );
+
+    // the iterator of a slice is bound to it
+    let array = array.slice(1, 2);
+    assert_eq!(
+        (&array).into_iter().collect::<Vec<_>>(),
+        vec![None, Some(&[5, 6][..])]
+    );
+    assert_eq!(
+        array.iter().rev().collect::<Vec<_>>(),
+        vec![Some(&[5, 6][..]), None]
+    );
+    assert_eq!(
+        array.values_iter().collect::<Vec<_>>(),
+        vec![&[3, 4][..], &[5, 6]]
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn iter_values() {
+    let array = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(2),
+        Buffer::from_slice([1, 2, 3, 4]),
+        None,
+    );
+    assert!(array.iter_values().eq(array.values_iter()));
+}
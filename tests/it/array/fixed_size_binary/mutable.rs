@@ -66,3 +66,182 @@ fn push_null() {
     let array: FixedSizeBinaryArray = array.into();
     assert_eq!(array.validity(), Some(&Bitmap::from([false])));
 }
+
+#[test]
+fn push_null_first() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    array.push::<&[u8]>(None);
+    array.push(Some(b"ab"));
+
+    let array: FixedSizeBinaryArray = array.into();
+    assert_eq!(array.validity(), Some(&Bitmap::from([false, true])));
+    assert_eq!(array.values().as_slice(), &[0, 0, b'a', b'b']);
+}
+
+#[test]
+fn push_null_trait() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    let mutable: &mut dyn MutableArray = &mut array;
+    mutable.push_null();
+    assert_eq!(mutable.len(), 1);
+    assert_eq!(mutable.validity(), Some(&MutableBitmap::from([false])));
+
+    array.try_push(Some(b"ab")).unwrap();
+    let mutable: &mut dyn MutableArray = &mut array;
+    mutable.push_null();
+    array.try_push(Some(b"cd")).unwrap();
+
+    let array = array.as_box();
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.null_count(), 2);
+    assert!(array.is_null(0));
+    assert!(array.is_valid(1));
+    assert!(array.is_null(2));
+    assert!(array.is_valid(3));
+
+    let expected = FixedSizeBinaryArray::from_iter(vec![None, Some(b"ab"), None, Some(b"cd")], 2);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn pop() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    assert_eq!(array.pop(), None);
+
+    array.push(Some(b"ab"));
+    array.push::<&[u8]>(None);
+    array.push(Some(b"cd"));
+
+    assert_eq!(array.pop(), Some(Some(b"cd".to_vec())));
+    assert_eq!(array.pop(), Some(None));
+    assert_eq!(array.len(), 1);
+    array.push(Some(b"ef"));
+
+    let array: FixedSizeBinaryArray = array.into();
+    let expected = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), Some(b"ef")], 2);
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn pop_without_validity() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    array.push(Some(b"ab"));
+    assert_eq!(array.pop(), Some(Some(b"ab".to_vec())));
+    assert_eq!(array.pop(), None);
+}
+
+#[test]
+fn reserve() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    array.reserve(10);
+    assert!(array.capacity() >= 10);
+    let capacity = array.capacity();
+
+    (0..10).for_each(|_| array.push(Some(b"ab")));
+    assert_eq!(array.capacity(), capacity);
+
+    array.shrink_to_fit();
+    assert_eq!(array.capacity(), 10);
+}
+
+#[test]
+fn extend() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    array.extend(vec![Some(b"ab"), None, Some(b"cd")]);
+
+    let array: FixedSizeBinaryArray = array.into();
+    let expected = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), None, Some(b"cd")], 2);
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn try_extend() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    assert!(array
+        .try_extend(vec![Some(b"ab".as_ref()), Some(b"c")])
+        .is_err());
+}
+
+#[test]
+fn from_trusted_len_iter() {
+    let data = vec![Some(b"ab"), None, Some(b"cd")];
+    let array =
+        MutableFixedSizeBinaryArray::from_trusted_len_iter(data.clone().into_iter(), 2).unwrap();
+    let array: FixedSizeBinaryArray = array.into();
+
+    assert_eq!(array, FixedSizeBinaryArray::from_iter(data, 2));
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false, true])));
+}
+
+#[test]
+fn from_trusted_len_iter_wrong_size() {
+    let data = vec![Some(b"ab".as_ref()), Some(b"c")];
+    assert!(MutableFixedSizeBinaryArray::from_trusted_len_iter(data.into_iter(), 2).is_err());
+}
+
+#[test]
+fn from_trusted_len_values_iter() {
+    let data = vec![b"ab", b"cd"];
+    let array =
+        MutableFixedSizeBinaryArray::from_trusted_len_values_iter(data.into_iter(), 2).unwrap();
+    assert_eq!(array.validity(), None);
+    assert_eq!(array.values(), &b"abcd".to_vec());
+
+    let data = vec![b"ab".as_ref(), b"cde"];
+    assert!(
+        MutableFixedSizeBinaryArray::from_trusted_len_values_iter(data.into_iter(), 2).is_err()
+    );
+}
+
+#[test]
+fn extend_trusted_len() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    array.push(Some(b"ab"));
+
+    // no nulls: the validity remains unallocated
+    array
+        .extend_trusted_len(vec![Some(b"cd")].into_iter())
+        .unwrap();
+    assert_eq!(array.validity(), None);
+
+    array
+        .extend_trusted_len(vec![None, Some(b"ef")].into_iter())
+        .unwrap();
+    assert_eq!(array.len(), 4);
+    assert_eq!(
+        array.validity(),
+        Some(&MutableBitmap::from([true, true, false, true]))
+    );
+
+    unsafe { array.extend_trusted_len_unchecked(vec![Some(b"gh")].into_iter()) };
+    let array: FixedSizeBinaryArray = array.into();
+    let expected = FixedSizeBinaryArray::from_iter(
+        vec![Some(b"ab"), Some(b"cd"), None, Some(b"ef"), Some(b"gh")],
+        2,
+    );
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn extend_trusted_len_wrong_size() {
+    let mut array = MutableFixedSizeBinaryArray::new(2);
+    let result = array.extend_trusted_len(vec![Some(b"ab".as_ref()), Some(b"c"), None].into_iter());
+    assert!(result.is_err());
+    // the values before the wrong one are pushed
+    assert_eq!(array.len(), 1);
+}
+
+#[test]
+fn default() {
+    let mut a = MutableFixedSizeBinaryArray::default();
+    assert_eq!(a.size(), 1);
+    assert_eq!(a.len(), 0);
+    assert_eq!(a.data_type(), &DataType::FixedSizeBinary(1));
+
+    a.push(Some(b"a"));
+    a.push::<&[u8]>(None);
+    assert!(a.try_push(Some(b"ab")).is_err());
+    let a: FixedSizeBinaryArray = a.into();
+    assert_eq!(a.values().as_slice(), b"a\0");
+    assert_eq!(a.validity(), Some(&Bitmap::from([true, false])));
+}
@@ -1 +1,59 @@
+use std::sync::Arc;
+
+use arrow2::{array::*, bitmap::Bitmap, datatypes::DataType};
+
 mod mutable;
+
+fn data() -> FixedSizeListArray {
+    let values = Arc::new(Int32Array::from_slice([1, 2, 3, 4, 5, 6, 7, 8]));
+    FixedSizeListArray::from_data(
+        FixedSizeListArray::default_datatype(DataType::Int32, 2),
+        values,
+        Some(Bitmap::from([true, false, true, true])),
+    )
+}
+
+#[test]
+fn iter() {
+    let array = data();
+    let iter = array.iter();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+
+    let expected = [Some([1, 2]), None, Some([5, 6]), Some([7, 8])];
+    array
+        .iter()
+        .zip(expected.iter())
+        .for_each(|(a, b)| match (a, b) {
+            (Some(a), Some(b)) => assert_eq!(a.as_ref(), &Int32Array::from_slice(b) as &dyn Array),
+            (None, None) => {}
+            _ => panic!(),
+        });
+
+    let last = array.values_iter().next_back().unwrap();
+    assert_eq!(last.as_ref(), &Int32Array::from_slice([7, 8]) as &dyn Array);
+}
+
+#[test]
+fn iter_sliced() {
+    let array = data().slice(1, 2);
+
+    let mut iter = (&array).into_iter();
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert!(iter.next().unwrap().is_none());
+    assert_eq!(
+        iter.next().unwrap().unwrap().as_ref(),
+        &Int32Array::from_slice([5, 6]) as &dyn Array
+    );
+    assert!(iter.next().is_none());
+
+    let values = array.values_iter().rev().collect::<Vec<_>>();
+    assert_eq!(values.len(), 2);
+    assert_eq!(
+        values[0].as_ref(),
+        &Int32Array::from_slice([5, 6]) as &dyn Array
+    );
+    assert_eq!(
+        values[1].as_ref(),
+        &Int32Array::from_slice([3, 4]) as &dyn Array
+    );
+}
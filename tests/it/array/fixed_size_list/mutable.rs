@@ -1,4 +1,6 @@
 use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field};
 
 #[test]
 fn primitive() {
@@ -24,3 +26,84 @@ fn primitive() {
     let expected = Int32Array::from(vec![None, None, None]);
     assert_eq!(a, &expected)
 }
+
+#[test]
+fn push_null() {
+    let mut list = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), 2);
+    list.try_push(Some(vec![Some(1), Some(2)])).unwrap();
+    list.push_null();
+    list.try_push(Some(vec![None, Some(4)])).unwrap();
+    assert_eq!(list.len(), 3);
+    // the values are padded with nulls
+    assert_eq!(list.values().len(), 6);
+
+    let list = list.as_arc();
+    assert_eq!(
+        list.data_type(),
+        &FixedSizeListArray::default_datatype(DataType::Int32, 2)
+    );
+    let list = list.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    assert_eq!(list.validity(), Some(&Bitmap::from([true, false, true])));
+    assert_eq!(
+        list.values().as_ref(),
+        &Int32Array::from(&[Some(1), Some(2), None, None, None, Some(4)]) as &dyn Array
+    );
+}
+
+#[test]
+fn empty() {
+    let mut list =
+        MutableFixedSizeListArray::new_with_field(MutableUtf8Array::<i32>::new(), "a", false, 3);
+    let list = list.as_box();
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("a", DataType::Utf8, false)), 3);
+    assert_eq!(list.data_type(), &data_type);
+    assert_eq!(list.len(), 0);
+}
+
+#[test]
+fn try_push_valid() {
+    let mut list = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), 2);
+    list.mut_values().push(Some(1));
+    assert!(list.try_push_valid().is_err());
+    // the values of the failed push are removed
+    assert_eq!(list.values().len(), 0);
+    list.mut_values().push(Some(1));
+    list.mut_values().push(Some(2));
+    list.try_push_valid().unwrap();
+    assert_eq!(list.len(), 1);
+
+    // more than `size` values is also an error
+    list.mut_values().push(Some(3));
+    list.mut_values().push(Some(4));
+    list.mut_values().push(Some(5));
+    assert!(list.try_push_valid().is_err());
+    assert_eq!(list.len(), 1);
+    assert_eq!(list.values().len(), 2);
+}
+
+#[test]
+fn try_push_wrong_size() {
+    let mut list = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), 2);
+    assert!(list.try_push(Some(vec![Some(1)])).is_err());
+    assert_eq!(list.len(), 0);
+    assert_eq!(list.values().len(), 0);
+}
+
+#[test]
+fn valid_invalid_valid() {
+    let mut list = MutableFixedSizeListArray::new(MutableUtf8Array::<i32>::new(), 2);
+    list.try_push(Some(vec![Some("a"), Some("b")])).unwrap();
+    assert!(list
+        .try_push(Some(vec![Some("c"), None, Some("d")]))
+        .is_err());
+    list.try_push(Some(vec![None, Some("e")])).unwrap();
+    assert_eq!(list.len(), 2);
+
+    let list: FixedSizeListArray = list.into();
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.validity(), None);
+    assert_eq!(
+        list.values().as_ref(),
+        &Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("e")]) as &dyn Array
+    );
+}
@@ -0,0 +1,88 @@
+use arrow2::array::{
+    growable::{Growable, GrowableMap},
+    *,
+};
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+fn data_type() -> DataType {
+    DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    )
+}
+
+// builds a map array from rows of (key, value) entries
+fn map(rows: &[Option<&[(&str, Option<i32>)]>]) -> Result<MapArray> {
+    let mut array = MutableMapArray::new(
+        data_type(),
+        Box::new(MutableUtf8Array::<i32>::new()),
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+    );
+    for row in rows {
+        match row {
+            Some(entries) => {
+                for (key, value) in entries.iter() {
+                    array.keys::<MutableUtf8Array<i32>>().push(Some(key));
+                    array.values::<MutablePrimitiveArray<i32>>().push(*value);
+                    array.try_push_entry()?;
+                }
+                array.try_push_valid()?;
+            }
+            None => array.push_null(),
+        }
+    }
+    Ok(array.into())
+}
+
+#[test]
+fn basic() -> Result<()> {
+    let array = map(&[
+        Some(&[("a", Some(1)), ("b", None)]),
+        None,
+        Some(&[]),
+        Some(&[("c", Some(3))]),
+    ])?;
+
+    for length in 1..3 {
+        for index in 0..(array.len() - length + 1) {
+            let mut a = GrowableMap::new(vec![&array], false, 0);
+
+            a.extend(0, index, length);
+            let expected = array.slice(index, length);
+
+            let result: MapArray = a.into();
+
+            assert_eq!(expected, result);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn many() -> Result<()> {
+    let array1 = map(&[Some(&[("a", Some(1))]), None])?;
+    let array2 = map(&[Some(&[("b", Some(2)), ("c", None)])])?;
+
+    let mut a = GrowableMap::new(vec![&array1, &array2], false, 0);
+    a.extend(0, 0, 2);
+    a.extend(1, 0, 1);
+    a.extend_validity(1);
+    let result: MapArray = a.into();
+
+    let expected = map(&[
+        Some(&[("a", Some(1))]),
+        None,
+        Some(&[("b", Some(2)), ("c", None)]),
+        None,
+    ])?;
+    assert_eq!(result, expected);
+    Ok(())
+}
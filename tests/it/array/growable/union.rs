@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{
+        growable::{Growable, GrowableUnion},
+        *,
+    },
+    buffer::Buffer,
+    datatypes::*,
+    error::Result,
+};
+
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]
+}
+
+#[test]
+fn sparse() -> Result<()> {
+    let data_type = DataType::Union(fields(), None, UnionMode::Sparse);
+    let types = Buffer::from_slice([0, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
+    let array = UnionArray::from_data(data_type, types, fields, None);
+
+    for length in 1..3 {
+        for index in 0..(array.len() - length + 1) {
+            let mut a = GrowableUnion::new(vec![&array], false, 10);
+
+            a.extend(0, index, length);
+            let expected = array.slice(index, length);
+
+            let result: UnionArray = a.into();
+
+            assert_eq!(expected, result);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn dense() -> Result<()> {
+    let data_type = DataType::Union(fields(), None, UnionMode::Dense);
+    let types = Buffer::from_slice([0, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+    ];
+    let offsets = Some(Buffer::from_slice([0, 1, 0]));
+
+    let array = UnionArray::from_data(data_type, types, fields, offsets);
+
+    for length in 1..3 {
+        for index in 0..(array.len() - length + 1) {
+            let mut a = GrowableUnion::new(vec![&array], false, 10);
+
+            a.extend(0, index, length);
+            let expected = array.slice(index, length);
+
+            let result: UnionArray = a.into();
+
+            assert_eq!(expected, result);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn dense_only_copies_referenced_slots() {
+    let data_type = DataType::Union(fields(), None, UnionMode::Dense);
+    let types = Buffer::from_slice([1, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from_slice(&[1])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(&["a", "b"])) as Arc<dyn Array>,
+    ];
+    let offsets = Some(Buffer::from_slice([0, 0, 1]));
+    let array = UnionArray::from_data(data_type, types, fields, offsets);
+
+    let mut a = GrowableUnion::new(vec![&array, &array], false, 10);
+    a.extend(0, 2, 1);
+    a.extend(1, 0, 2);
+    let result: UnionArray = a.into();
+
+    assert_eq!(result.types().as_slice(), &[1, 1, 0]);
+    assert_eq!(result.offsets().as_ref().unwrap().as_slice(), &[0, 1, 0]);
+    assert_eq!(
+        result.fields()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(&["b", "a"]) as &dyn Array
+    );
+    assert_eq!(
+        result.fields()[0].as_ref(),
+        &Int32Array::from_slice(&[1]) as &dyn Array
+    );
+}
+
+#[test]
+fn extend_validity() {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields(), Some(vec![5, 7]), mode);
+        let types = Buffer::from_slice([7]);
+        let (fields, offsets) = if mode.is_sparse() {
+            let fields = vec![
+                Arc::new(Int32Array::from_slice(&[1])) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from_slice(&["a"])) as Arc<dyn Array>,
+            ];
+            (fields, None)
+        } else {
+            let fields = vec![
+                Arc::new(Int32Array::from_slice(&[])) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from_slice(&["a"])) as Arc<dyn Array>,
+            ];
+            (fields, Some(Buffer::from_slice([0])))
+        };
+        let array = UnionArray::from_data(data_type, types, fields, offsets);
+
+        let mut a = GrowableUnion::new(vec![&array], true, 10);
+        a.extend(0, 0, 1);
+        a.extend_validity(2);
+        let result: UnionArray = a.into();
+
+        assert_eq!(result.len(), 3);
+        // nulls are nulls of the first field
+        assert_eq!(result.types().as_slice(), &[7, 5, 5]);
+        assert!(!result.value(1).is_valid());
+        assert!(!result.value(2).is_valid());
+        assert_eq!(result.value(0).as_ref(), array.value(0).as_ref());
+    }
+}
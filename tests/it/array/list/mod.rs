@@ -70,3 +70,79 @@ fn test_nested_display() {
     let expected = "ListArray[[[1, 2], [3, 4]], [[5, 6, 7], [], [8]], [[9, 10]]]";
     assert_eq!(format!("{:?}", nested), expected);
 }
+
+proptest::proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well
+    fn try_new_corrupted_offsets(offsets in proptest::collection::vec(-2i32..6, 0..6)) {
+        let values = Int32Array::from_slice([1, 2, 3, 4]);
+        let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+        let result = ListArray::<i32>::try_new(
+            data_type,
+            offsets.clone().into(),
+            Arc::new(values.clone()),
+            None,
+        );
+
+        // the array is accepted iff every slot is an in-bounds slice of the values
+        let in_bounds = |o: &i32| (0..=values.len() as i32).contains(o);
+        let expected = (!offsets.is_empty() && offsets.iter().all(in_bounds))
+            .then(|| {
+                offsets
+                    .windows(2)
+                    .map(|w| {
+                        let start = usize::try_from(w[0]).ok()?;
+                        let end = usize::try_from(w[1]).ok()?;
+                        (start <= end && end <= values.len()).then(|| values.slice(start, end - start))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .flatten();
+        match (result, expected) {
+            (Ok(array), Some(expected)) => {
+                assert_eq!(array.len(), expected.len());
+                array
+                    .values_iter()
+                    .zip(expected.iter())
+                    .for_each(|(a, b)| assert_eq!(a.as_ref(), b as &dyn Array));
+            }
+            (Err(_), None) => {}
+            (result, expected) => panic!("{:?} {:?}", result, expected),
+        }
+    }
+}
+
+#[test]
+fn with_validity_keeps_values() {
+    let values = Buffer::from_slice([1, 2, 3, 4, 5]);
+    let values = PrimitiveArray::<i32>::from_data(DataType::Int32, values, None);
+
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 2, 3, 5]),
+        Arc::new(values),
+        None,
+    );
+    let array = array.with_validity(Some([true, false, true, true].into()));
+
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(array.values().null_count(), 0);
+    assert_eq!(array.values().len(), 5);
+}
+
+#[test]
+#[should_panic]
+fn with_validity_wrong_length() {
+    let values = Buffer::from_slice([1, 2, 3]);
+    let values = PrimitiveArray::<i32>::from_data(DataType::Int32, values, None);
+
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 3]),
+        Arc::new(values),
+        None,
+    );
+    let _ = array.with_validity(Some([true].into()));
+}
@@ -50,3 +50,28 @@ fn push() {
     assert_eq!(array.offsets().as_ref(), [0, 3]);
     assert_eq!(array.validity(), None);
 }
+
+#[test]
+fn try_extend_from_array() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(3), None]),
+        Some(vec![]),
+    ];
+    let mut other = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    other.try_extend(data.clone()).unwrap();
+    let other: ListArray<i32> = other.into();
+    let other = other.slice(1, 3);
+
+    let mut a = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    a.try_extend(data[..1].to_vec()).unwrap();
+    a.try_extend_from_array(&other, 0, 3).unwrap();
+    let a: ListArray<i32> = a.into();
+
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(data).unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(a, expected);
+    assert_eq!(a.offsets().as_slice(), &[0, 2, 2, 4, 4]);
+}
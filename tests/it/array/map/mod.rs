@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::{DataType, Field},
+};
+
+mod mutable;
+
+fn data_type(is_nullable: bool) -> DataType {
+    DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, is_nullable),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    )
+}
+
+fn entries(data_type: &DataType) -> Arc<dyn Array> {
+    let inner = match data_type {
+        DataType::Map(field, _) => field.data_type().clone(),
+        _ => unreachable!(),
+    };
+    Arc::new(StructArray::from_data(
+        inner,
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>,
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        ],
+        None,
+    ))
+}
+
+// [{a: 1, b: None}, None, {}, {c: 3}]
+fn array() -> MapArray {
+    let data_type = data_type(false);
+    let field = entries(&data_type);
+    MapArray::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 2, 2, 3]),
+        field,
+        Some(Bitmap::from([true, false, true, true])),
+    )
+}
+
+#[test]
+fn basics() {
+    let array = array();
+
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(
+        array.keys().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+    assert_eq!(
+        array.values().as_ref(),
+        &Int32Array::from([Some(1), None, Some(3)]) as &dyn Array
+    );
+
+    let value = array.value(0);
+    let value = value.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(value, &entries(array.data_type()).slice(0, 2).as_ref());
+
+    // empty maps and null slots have no entries
+    assert_eq!(array.value(1).len(), 0);
+    assert_eq!(array.value(2).len(), 0);
+    assert_eq!(
+        array.iter().map(|x| x.map(|x| x.len())).collect::<Vec<_>>(),
+        vec![Some(2), None, Some(0), Some(1)]
+    );
+}
+
+#[test]
+fn debug() {
+    let array = array();
+
+    assert_eq!(
+        format!("{:?}", array),
+        "MapArray[[{key: a, value: 1}, {key: b, value: None}], None, [], [{key: c, value: 3}]]"
+    );
+}
+
+#[test]
+fn slice() {
+    let array = array().slice(1, 3);
+
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(
+        format!("{:?}", array),
+        "MapArray[None, [], [{key: c, value: 3}]]"
+    );
+
+    let data_type = data_type(false);
+    let field = entries(&data_type);
+    let expected = MapArray::from_data(
+        data_type,
+        Buffer::from_slice([2, 2, 2, 3]),
+        field,
+        Some(Bitmap::from([false, true, true])),
+    );
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn nullable_keys() {
+    let data_type = data_type(true);
+    let field = entries(&data_type);
+    assert!(MapArray::try_new(data_type, Buffer::from_slice([0, 3]), field, None).is_err());
+}
+
+#[test]
+fn new_null_and_empty() {
+    let array = MapArray::new_null(data_type(false), 2);
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.null_count(), 2);
+    assert_eq!(format!("{:?}", array), "MapArray[None, None]");
+
+    let array = MapArray::new_empty(data_type(false));
+    assert_eq!(array.len(), 0);
+    assert_eq!(format!("{:?}", array), "MapArray[]");
+}
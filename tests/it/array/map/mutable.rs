@@ -0,0 +1,165 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field};
+
+fn data_type() -> DataType {
+    DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    )
+}
+
+fn new() -> MutableMapArray {
+    MutableMapArray::new(
+        data_type(),
+        Box::new(MutableUtf8Array::<i32>::new()),
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+    )
+}
+
+fn push_entry(array: &mut MutableMapArray, key: Option<&str>, value: Option<i32>) -> bool {
+    array.keys::<MutableUtf8Array<i32>>().push(key);
+    array.values::<MutablePrimitiveArray<i32>>().push(value);
+    array.try_push_entry().is_ok()
+}
+
+#[test]
+fn round_trip() {
+    let mut array = new();
+    // {"a": 1, "b": null}
+    assert!(push_entry(&mut array, Some("a"), Some(1)));
+    assert!(push_entry(&mut array, Some("b"), None));
+    array.try_push_valid().unwrap();
+    // {}
+    array.try_push_valid().unwrap();
+    // null
+    array.push_null();
+    // {"c": 3}
+    assert!(push_entry(&mut array, Some("c"), Some(3)));
+    array.try_push_valid().unwrap();
+    assert_eq!(array.len(), 4);
+
+    let array = array.as_arc();
+    assert_eq!(array.data_type(), &data_type());
+    let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 2, 3]);
+    assert_eq!(
+        array.validity(),
+        Some(&Bitmap::from([true, true, false, true]))
+    );
+
+    let field = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(
+        field.values()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(&["a", "b", "c"]) as &dyn Array
+    );
+    assert_eq!(
+        field.values()[1].as_ref(),
+        &Int32Array::from(&[Some(1), None, Some(3)]) as &dyn Array
+    );
+}
+
+#[test]
+fn empty() {
+    let mut array = new();
+    let array = array.as_box();
+    assert_eq!(array.data_type(), &data_type());
+    assert_eq!(array.len(), 0);
+    assert_eq!(
+        array.as_ref(),
+        &MapArray::new_empty(data_type()) as &dyn Array
+    );
+}
+
+#[test]
+fn null_key() {
+    let mut array = new();
+    assert!(push_entry(&mut array, Some("a"), Some(1)));
+    assert!(!push_entry(&mut array, None, Some(2)));
+    assert_eq!(array.field().len(), 1);
+}
+
+#[test]
+fn valid_invalid_valid() {
+    let mut array = new();
+    assert!(push_entry(&mut array, Some("a"), Some(1)));
+    assert!(!push_entry(&mut array, None, Some(2)));
+    assert!(push_entry(&mut array, Some("b"), None));
+    array.try_push_valid().unwrap();
+
+    let array: MapArray = array.into();
+    assert_eq!(array.offsets().as_slice(), &[0, 2]);
+    let field = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(field.validity(), None);
+    assert_eq!(
+        field.values()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn Array
+    );
+    assert_eq!(
+        field.values()[1].as_ref(),
+        &Int32Array::from(&[Some(1), None]) as &dyn Array
+    );
+}
+
+#[test]
+fn entry_length_mismatch() {
+    let mut array = new();
+    array.keys::<MutableUtf8Array<i32>>().push(Some("a"));
+    assert!(array.try_push_entry().is_err());
+    // the key of the failed entry is removed
+    assert_eq!(array.field().values()[0].len(), 0);
+    assert!(push_entry(&mut array, Some("a"), Some(1)));
+    array.try_push_valid().unwrap();
+
+    let array: MapArray = array.into();
+    assert_eq!(array.offsets().as_slice(), &[0, 1]);
+    assert_eq!(array.validity(), None);
+}
+
+#[test]
+fn try_new_errors() {
+    let keys = Box::new(MutableUtf8Array::<i32>::new());
+    let values = Box::new(MutableUtf8Array::<i32>::new());
+    assert!(MutableMapArray::try_new(data_type(), keys, values).is_err());
+
+    let keys = Box::new(MutableUtf8Array::<i32>::new());
+    let values = Box::new(MutablePrimitiveArray::<i32>::new());
+    assert!(MutableMapArray::try_new(DataType::Int32, keys, values).is_err());
+}
+
+#[test]
+fn try_extend_from_array() {
+    let mut other = new();
+    assert!(push_entry(&mut other, Some("a"), Some(1)));
+    other.try_push_valid().unwrap();
+    other.push_null();
+    assert!(push_entry(&mut other, Some("b"), Some(2)));
+    assert!(push_entry(&mut other, Some("c"), None));
+    other.try_push_valid().unwrap();
+    let other = other.as_arc();
+    let other = other.slice(1, 2);
+
+    let mut array = new();
+    array.try_extend_from_array(other.as_ref(), 0, 2).unwrap();
+    assert_eq!(array.len(), 2);
+
+    let array = array.as_arc();
+    let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+    assert_eq!(array.offsets().as_slice(), &[0, 0, 2]);
+    assert_eq!(array.validity(), Some(&Bitmap::from([false, true])));
+}
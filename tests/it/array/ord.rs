@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 
 use arrow2::array::ord::build_compare;
 use arrow2::array::*;
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
 
 #[test]
@@ -101,3 +102,70 @@ fn dict_i32() -> Result<()> {
     assert_eq!(Ordering::Greater, (cmp)(2, 3));
     Ok(())
 }
+
+#[test]
+fn dict_different_values() -> Result<()> {
+    let mut array1 = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array1.try_extend(vec![Some("a"), Some("b")])?;
+    let array1: DictionaryArray<i32> = array1.into();
+    let mut array2 = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array2.try_extend(vec![Some("b"), Some("c"), Some("a")])?;
+    let array2: DictionaryArray<i32> = array2.into();
+
+    let cmp = build_compare(&array1, &array2)?;
+
+    assert_eq!(Ordering::Equal, (cmp)(0, 2));
+    assert_eq!(Ordering::Equal, (cmp)(1, 0));
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+    assert_eq!(Ordering::Less, (cmp)(1, 1));
+    Ok(())
+}
+
+#[test]
+fn decimal_different_scales() -> Result<()> {
+    let array1 = Int128Array::from_slice(&[150, -150]).to(DataType::Decimal(5, 2));
+    let array2 = Int128Array::from_slice(&[15, 2]).to(DataType::Decimal(4, 1));
+
+    let cmp = build_compare(&array1, &array2)?;
+
+    assert_eq!(Ordering::Equal, (cmp)(0, 0));
+    assert_eq!(Ordering::Greater, (cmp)(0, 1));
+    assert_eq!(Ordering::Less, (cmp)(1, 1));
+    Ok(())
+}
+
+#[test]
+fn decimal_rescale_overflow() -> Result<()> {
+    let big = 10i128.pow(37);
+    let array1 = Int128Array::from_slice(&[big, -big, 0]).to(DataType::Decimal(38, 0));
+    let array2 = Int128Array::from_slice(&[big, 0]).to(DataType::Decimal(38, 38));
+
+    let cmp = build_compare(&array1, &array2)?;
+
+    assert_eq!(Ordering::Greater, (cmp)(0, 0));
+    assert_eq!(Ordering::Less, (cmp)(1, 0));
+    assert_eq!(Ordering::Equal, (cmp)(2, 1));
+    Ok(())
+}
+
+#[test]
+fn list_sliced() -> Result<()> {
+    let mut array1 = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array1.try_extend(vec![Some(vec![Some(0)]), Some(vec![Some(1), Some(2)])])?;
+    let array1: ListArray<i32> = array1.into();
+    let array1 = array1.slice(1, 1);
+    let mut array2 = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array2.try_extend(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), Some(3)]),
+        Some(vec![Some(1)]),
+    ])?;
+    let array2: ListArray<i32> = array2.into();
+
+    let cmp = build_compare(&array1, &array2)?;
+
+    assert_eq!(Ordering::Equal, (cmp)(0, 0));
+    assert_eq!(Ordering::Less, (cmp)(0, 1));
+    assert_eq!(Ordering::Greater, (cmp)(0, 2));
+    Ok(())
+}
@@ -5,7 +5,7 @@ use arrow2::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::*,
-    types::{days_ms, months_days_ns},
+    types::{days_ms, i256, months_days_ns},
 };
 
 mod mutable;
@@ -82,6 +82,20 @@ fn debug_int32() {
     assert_eq!(format!("{:?}", array), "Int32[1, None, 2]");
 }
 
+#[test]
+fn debug_decimal256() {
+    let big = i256::from(10).checked_pow(40).unwrap();
+    let array = Int256Array::from(&[Some(big), None, Some(i256::from(-5)), Some(i256::from(0))])
+        .to(DataType::Decimal256(42, 2));
+    assert_eq!(
+        format!("{:?}", array),
+        format!(
+            "Decimal256(42, 2)[1{}.00, None, -0.05, 0.00]",
+            "0".repeat(38)
+        )
+    );
+}
+
 #[test]
 fn debug_date32() {
     let array = Int32Array::from(&[Some(1), None, Some(2)]).to(DataType::Date32);
@@ -304,3 +318,18 @@ fn wrong_data_type() {
     let values = Buffer::from_slice(b"abbb");
     PrimitiveArray::from_data(DataType::Utf8, values, None);
 }
+
+#[test]
+fn apply_validity() {
+    let mut a = Int32Array::from([Some(1), None, Some(3)]);
+    a.apply_validity(|bitmap| bitmap & &Bitmap::from([false, true, true]));
+    assert_eq!(a, Int32Array::from([None, None, Some(3)]));
+
+    // without validity, all slots are valid
+    let mut a = Int32Array::from_slice([1, 2]);
+    a.apply_validity(|bitmap| {
+        assert_eq!(bitmap.null_count(), 0);
+        bitmap & &Bitmap::from([false, true])
+    });
+    assert_eq!(a, Int32Array::from([None, Some(2)]));
+}
@@ -83,6 +83,59 @@ fn set() {
     assert_eq!(a.values(), &Vec::from([2, 0]));
 }
 
+#[test]
+fn set_toggle() {
+    let mut a = MutablePrimitiveArray::<i32>::from_slice([1, 2, 3]);
+    assert_eq!(a.validity(), None);
+
+    a.set(1, None);
+    assert_eq!(a.get(1), None);
+    a.set(1, Some(5));
+    assert_eq!(a.get(1), Some(5));
+    a.set(2, None);
+    a.set(1, None);
+
+    assert_eq!(a.get(0), Some(1));
+    let a: PrimitiveArray<i32> = a.into();
+    assert_eq!(a.null_count(), 2);
+    assert_eq!(a, PrimitiveArray::<i32>::from([Some(1), None, None]));
+}
+
+#[test]
+fn set_valid_again() {
+    let mut a = MutablePrimitiveArray::<i32>::from_slice([1, 2]);
+    a.set(0, None);
+    a.set(0, Some(3));
+
+    let a: PrimitiveArray<i32> = a.into();
+    assert_eq!(a.null_count(), 0);
+    assert_eq!(a, PrimitiveArray::<i32>::from_slice([3, 2]));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn set_out_of_bounds() {
+    let mut a = MutablePrimitiveArray::<i32>::from_slice([1, 2]);
+    a.set(2, Some(1));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn get_out_of_bounds() {
+    let a = MutablePrimitiveArray::<i32>::from_slice([1, 2]);
+    a.get(2);
+}
+
+#[test]
+fn values_mut_slice_nulls() {
+    let mut a = MutablePrimitiveArray::<i32>::from([Some(1), None, Some(3)]);
+    a.values_mut_slice().iter_mut().for_each(|x| *x *= 2);
+
+    assert_eq!(a.get(0), Some(2));
+    assert_eq!(a.get(1), None);
+    assert_eq!(a.get(2), Some(6));
+}
+
 #[test]
 fn from_iter() {
     let a = MutablePrimitiveArray::<i32>::from_iter((0..2).map(Some));
@@ -231,3 +284,38 @@ fn wrong_data_type() {
     let values = vec![1u8];
     MutablePrimitiveArray::from_data(DataType::Utf8, values, None);
 }
+
+#[test]
+fn try_extend_from_array() -> Result<()> {
+    let other = Int32Array::from(&[Some(1), None, Some(3), Some(4)]).slice(1, 3);
+
+    let mut a = MutablePrimitiveArray::<i32>::from_slice(&[0]);
+    a.try_extend_from_array(&other, 0, 2)?;
+    a.try_extend_from_array(&other, 2, 1)?;
+
+    let a: Int32Array = a.into();
+    assert_eq!(a, Int32Array::from(&[Some(0), None, Some(3), Some(4)]));
+    Ok(())
+}
+
+#[test]
+fn try_extend_from_array_invalid() {
+    let mut a = MutablePrimitiveArray::<i32>::new();
+
+    let other = Int64Array::from_slice(&[1, 2]);
+    assert!(a.try_extend_from_array(&other, 0, 1).is_err());
+
+    let other = Int32Array::from_slice(&[1, 2]).to(DataType::Date32);
+    assert!(a.try_extend_from_array(&other, 0, 1).is_err());
+
+    let other = Int32Array::from_slice(&[1, 2]);
+    assert!(a.try_extend_from_array(&other, 1, 2).is_err());
+    assert_eq!(a.len(), 0);
+}
+
+#[test]
+fn reserve() {
+    let mut a: Box<dyn MutableArray> = Box::new(MutablePrimitiveArray::<i32>::new());
+    a.reserve(10);
+    assert_eq!(a.len(), 0);
+}
@@ -51,3 +51,11 @@ fn array_to_mutable_validity() {
     let arr = PrimitiveArray::from_data(DataType::Int32, data.into(), Some(bitmap.clone()));
     assert!(matches!(arr.into_mut(), Either::Left(_)));
 }
+
+#[test]
+fn array_to_mutable_sliced() {
+    let arr = PrimitiveArray::from_data(DataType::Int32, vec![1, 2, 3].into(), None);
+    assert!(arr.slice(1, 2).into_mut().is_left());
+    let arr = arr.slice(0, 2).into_mut().unwrap_left();
+    assert_eq!(arr.values().as_slice(), [1, 2]);
+}
@@ -1,4 +1,5 @@
 mod iterator;
+mod mutable;
 
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
@@ -25,3 +26,54 @@ fn debug() {
         "StructArray[{b: false, c: 42}, {b: false, c: 28}, None, {b: true, c: 31}]"
     );
 }
+
+#[test]
+fn equal_ignores_values_behind_nulls() {
+    use std::sync::Arc;
+    let fields = vec![Field::new("a", DataType::Int32, false)];
+    let validity = Some(Bitmap::from([true, false, true]));
+
+    let lhs = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![Arc::new(Int32Array::from_slice(&[1, 2, 3]))],
+        validity.clone(),
+    );
+    let rhs = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![Arc::new(Int32Array::from_slice(&[0, 1, 5, 3]).slice(1, 3))],
+        validity,
+    );
+    assert_eq!(lhs, rhs);
+
+    let rhs = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(Int32Array::from_slice(&[1, 2, 3]))],
+        None,
+    );
+    assert!(lhs != rhs);
+}
+
+#[test]
+fn equal_without_validity() {
+    use std::sync::Arc;
+    let fields = vec![Field::new("a", DataType::Int32, false)];
+
+    let lhs = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![Arc::new(Int32Array::from_slice([1, 2, 3]))],
+        None,
+    );
+    let rhs = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![Arc::new(Int32Array::from_slice([0, 1, 2, 3]).slice(1, 3))],
+        Some(Bitmap::from([true, true, true])),
+    );
+    assert_eq!(lhs, rhs);
+
+    let rhs = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(Int32Array::from_slice([1, 2, 4]))],
+        None,
+    );
+    assert!(lhs != rhs);
+}
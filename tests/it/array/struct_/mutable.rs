@@ -0,0 +1,154 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::datatypes::{DataType, Field};
+
+fn new() -> MutableStructArray {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let values: Vec<Box<dyn MutableArray>> = vec![
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+        Box::new(MutableUtf8Array::<i32>::new()),
+    ];
+    MutableStructArray::new(data_type, values)
+}
+
+fn push(array: &mut MutableStructArray, a: Option<i32>, b: Option<&str>) {
+    array.value::<MutablePrimitiveArray<i32>>(0).push(a);
+    array.value::<MutableUtf8Array<i32>>(1).push(b);
+}
+
+#[test]
+fn push_values() {
+    let mut array = new();
+    push(&mut array, Some(1), Some("a"));
+    array.push(true);
+    push(&mut array, None, Some("b"));
+    array.push(true);
+    assert_eq!(array.len(), 2);
+    // validity is only allocated on the first null
+    assert!(array.validity().is_none());
+
+    let array: StructArray = array.into();
+    assert_eq!(array.validity(), None);
+    assert_eq!(
+        array.values()[0].as_ref(),
+        &Int32Array::from(&[Some(1), None]) as &dyn Array
+    );
+    assert_eq!(
+        array.values()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(&["a", "b"]) as &dyn Array
+    );
+}
+
+#[test]
+fn push_null() {
+    let mut array = new();
+    push(&mut array, Some(1), Some("a"));
+    array.push(true);
+    array.push_null();
+    push(&mut array, Some(3), Some("c"));
+    array.push(true);
+    assert_eq!(array.len(), 3);
+
+    let array = array.as_box();
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false, true])));
+    assert_eq!(
+        array.values()[0].as_ref(),
+        &Int32Array::from(&[Some(1), None, Some(3)]) as &dyn Array
+    );
+    assert_eq!(
+        array.values()[1].as_ref(),
+        &Utf8Array::<i32>::from(&[Some("a"), None, Some("c")]) as &dyn Array
+    );
+}
+
+#[test]
+fn push_invalid() {
+    // a row can be null while its children have values
+    let mut array = new();
+    push(&mut array, Some(1), Some("a"));
+    array.push(true);
+    push(&mut array, Some(2), Some("b"));
+    array.push(false);
+
+    let array: StructArray = array.into();
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false])));
+    assert_eq!(
+        array.values()[0].as_ref(),
+        &Int32Array::from_slice(&[1, 2]) as &dyn Array
+    );
+}
+
+#[test]
+fn push_length_mismatch() {
+    let mut array = new();
+    array.value::<MutablePrimitiveArray<i32>>(0).push(Some(1));
+    assert!(array.try_push(true).is_err());
+    assert_eq!(array.len(), 0);
+
+    // the row can be completed and pushed afterwards
+    array.value::<MutableUtf8Array<i32>>(1).push(Some("a"));
+    array.try_push(true).unwrap();
+    assert_eq!(array.len(), 1);
+
+    // more than one value per child is also an error
+    push(&mut array, Some(2), Some("b"));
+    push(&mut array, Some(3), Some("c"));
+    assert!(array.try_push(false).is_err());
+    assert_eq!(array.len(), 1);
+    assert!(array.validity().is_none());
+}
+
+#[test]
+#[should_panic]
+fn value_wrong_type() {
+    let mut array = new();
+    array.value::<MutableBooleanArray>(0);
+}
+
+#[test]
+fn try_new_errors() {
+    let data_type = DataType::Struct(vec![Field::new("a", DataType::Int32, true)]);
+    let utf8: Vec<Box<dyn MutableArray>> = vec![Box::new(MutableUtf8Array::<i32>::new())];
+    assert!(MutableStructArray::try_new(data_type.clone(), utf8).is_err());
+
+    let non_empty: Vec<Box<dyn MutableArray>> =
+        vec![Box::new(MutablePrimitiveArray::<i32>::from(&[Some(1)]))];
+    assert!(MutableStructArray::try_new(data_type.clone(), non_empty).is_err());
+
+    assert!(MutableStructArray::try_new(data_type, vec![]).is_err());
+    assert!(MutableStructArray::try_new(DataType::Int32, vec![]).is_err());
+}
+
+#[test]
+fn try_extend_from_array() {
+    let mut other = new();
+    push(&mut other, Some(1), Some("a"));
+    other.push(true);
+    push(&mut other, None, None);
+    other.push(false);
+    push(&mut other, Some(3), Some("c"));
+    other.push(true);
+    let other: StructArray = other.into();
+    let other = other.slice(1, 2);
+
+    let mut array = new();
+    push(&mut array, Some(0), None);
+    array.push(true);
+    array.try_extend_from_array(&other, 0, 2).unwrap();
+    assert_eq!(array.len(), 3);
+
+    let array: StructArray = array.into();
+    assert_eq!(array.validity(), Some(&Bitmap::from([true, false, true])));
+    assert_eq!(
+        array.values()[0].as_ref(),
+        &Int32Array::from(&[Some(0), None, Some(3)]) as &dyn Array
+    );
+    assert_eq!(
+        array.values()[1].as_ref(),
+        &Utf8Array::<i32>::from(&[None, None, Some("c")]) as &dyn Array
+    );
+}
@@ -39,9 +39,130 @@ fn slice() -> Result<()> {
 
     let result = array.slice(1, 2);
 
+    // the children of a sparse union are sliced alongside its types
     let types = Buffer::from_slice([0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("b"), Some("c")])) as Arc<dyn Array>,
+    ];
     let expected = UnionArray::from_data(data_type, types, fields, None);
 
     assert_eq!(expected, result);
     Ok(())
 }
+
+#[test]
+fn dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let types = Buffer::from_slice([0, 1, 0]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+    ];
+    let offsets = Some(Buffer::from_slice([0, 0, 1]));
+
+    let array = UnionArray::try_new(data_type.clone(), types.clone(), fields.clone(), offsets)?;
+    assert_eq!(format!("{:?}", array), "UnionArray[1, c, None]");
+
+    // round-trip through its components
+    let result = UnionArray::from_data(
+        array.data_type().clone(),
+        array.types().clone(),
+        array.fields().clone(),
+        array.offsets().clone(),
+    );
+    assert_eq!(array, result);
+
+    assert_eq!(format!("{:?}", array.slice(1, 2)), "UnionArray[c, None]");
+    assert_eq!(array.slice(1, 2), array.slice(1, 2));
+    Ok(())
+}
+
+#[test]
+fn with_ids() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Sparse);
+    let types = Buffer::from_slice([7, 5]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("a"), Some("b")])) as Arc<dyn Array>,
+    ];
+
+    let array = UnionArray::try_new(data_type.clone(), types, fields.clone(), None)?;
+    assert_eq!(format!("{:?}", array), "UnionArray[a, 2]");
+
+    // 0 is not one of the ids
+    let types = Buffer::from_slice([0, 5]);
+    assert!(UnionArray::try_new(data_type, types, fields, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn try_new_invalid() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let sparse = DataType::Union(fields.clone(), None, UnionMode::Sparse);
+    let dense = DataType::Union(fields, None, UnionMode::Dense);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from(&[Some("c")])) as Arc<dyn Array>,
+    ];
+
+    // type id without a field
+    let types = Buffer::from_slice([0, 2]);
+    let offsets = Some(Buffer::from_slice([0, 0]));
+    assert!(UnionArray::try_new(dense.clone(), types, fields.clone(), offsets).is_err());
+
+    // negative type id
+    let types = Buffer::from_slice([-1]);
+    let offsets = Some(Buffer::from_slice([0]));
+    assert!(UnionArray::try_new(dense.clone(), types, fields.clone(), offsets).is_err());
+
+    // offset out of bounds of its field
+    let types = Buffer::from_slice([0, 1]);
+    let offsets = Some(Buffer::from_slice([0, 1]));
+    assert!(UnionArray::try_new(dense.clone(), types, fields.clone(), offsets).is_err());
+
+    // negative offset
+    let types = Buffer::from_slice([0]);
+    let offsets = Some(Buffer::from_slice([-1]));
+    assert!(UnionArray::try_new(dense.clone(), types, fields.clone(), offsets).is_err());
+
+    // fewer offsets than types
+    let types = Buffer::from_slice([0, 0]);
+    let offsets = Some(Buffer::from_slice([0]));
+    assert!(UnionArray::try_new(dense, types, fields.clone(), offsets).is_err());
+
+    // sparse field shorter than the union
+    let types = Buffer::from_slice([0, 0]);
+    assert!(UnionArray::try_new(sparse, types, fields, None).is_err());
+}
+
+#[test]
+fn new_null_and_empty() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let data_type = DataType::Union(fields.clone(), None, mode);
+
+        let array = UnionArray::new_null(data_type.clone(), 2);
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.null_count(), 0);
+        assert_eq!(format!("{:?}", array), "UnionArray[None, None]");
+
+        let array = UnionArray::new_empty(data_type);
+        assert_eq!(array.len(), 0);
+        assert!(array.iter().next().is_none());
+    }
+}
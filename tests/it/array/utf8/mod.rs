@@ -182,3 +182,124 @@ fn debug() {
 
     assert_eq!(format!("{:?}", array), "Utf8Array[aa, , None]");
 }
+
+#[test]
+fn try_from_binary() {
+    let binary = BinaryArray::<i32>::from(&[Some("aa".as_bytes()), None, Some("💖".as_bytes())]);
+    let array = Utf8Array::<i32>::try_from_binary(binary.clone()).unwrap();
+    assert_eq!(
+        array,
+        Utf8Array::<i32>::from(&[Some("aa"), None, Some("💖")])
+    );
+    // zero-copy
+    assert_eq!(array.values().as_ptr(), binary.values().as_ptr());
+    assert_eq!(array.offsets().as_ptr(), binary.offsets().as_ptr());
+
+    let back = array.into_binary();
+    assert_eq!(back, binary);
+    assert_eq!(back.values().as_ptr(), binary.values().as_ptr());
+
+    // sliced
+    let array = Utf8Array::<i32>::try_from_binary(binary.slice(1, 2)).unwrap();
+    assert_eq!(array, Utf8Array::<i32>::from(&[None, Some("💖")]));
+}
+
+#[test]
+fn try_from_binary_invalid() {
+    let binary = BinaryArray::<i64>::from_slice(&[b"a".as_ref(), b"b", b"\xff", b"c"]);
+    let error = Utf8Array::<i64>::try_from_binary(binary.clone()).unwrap_err();
+    assert!(error.to_string().contains("index 2"));
+
+    // the invalid slot is outside of the slice
+    assert!(Utf8Array::<i64>::try_from_binary(binary.slice(0, 2)).is_ok());
+    assert!(Utf8Array::<i64>::try_from_binary(binary.slice(3, 1)).is_ok());
+
+    // the values are valid utf8, but the slots split a character
+    let heart = "💖".as_bytes();
+    let binary = BinaryArray::<i32>::from_data(
+        DataType::Binary,
+        Buffer::from(vec![0, 1, 1, 4]),
+        Buffer::from(heart.to_vec()),
+        None,
+    );
+    let error = Utf8Array::<i32>::try_from_binary(binary).unwrap_err();
+    assert!(error.to_string().contains("index 0"));
+
+    // null slots are also checked
+    let binary = BinaryArray::<i32>::from(&[Some(b"a".as_ref()), Some(b"\xff")]);
+    let binary = binary.with_validity(Some(Bitmap::from([true, false])));
+    let error = Utf8Array::<i32>::try_from_binary(binary).unwrap_err();
+    assert!(error.to_string().contains("index 1"));
+}
+
+#[test]
+fn negative_offsets() {
+    let offsets = Buffer::from_slice([-1, 2]);
+    let values = Buffer::from_slice(b"abc");
+    assert!(Utf8Array::<i32>::try_new(DataType::Utf8, offsets, values, None).is_err());
+}
+
+#[test]
+fn try_new_errors() {
+    let values = Buffer::from_slice(b"abc");
+    assert!(Utf8Array::<i32>::try_new(
+        DataType::Utf8,
+        Buffer::from_slice([0, 4]),
+        values.clone(),
+        None
+    )
+    .is_err());
+    assert!(Utf8Array::<i32>::try_new(
+        DataType::Utf8,
+        Buffer::from_slice([0, 2, 1]),
+        values.clone(),
+        None
+    )
+    .is_err());
+    assert!(Utf8Array::<i32>::try_new(
+        DataType::Utf8,
+        Buffer::from_slice([0, 3]),
+        values,
+        Some(Bitmap::from([true, false]))
+    )
+    .is_err());
+}
+
+proptest::proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well
+    fn try_new_corrupted_offsets(offsets in proptest::collection::vec(-2i32..12, 0..6)) {
+        // multi-byte chars so that offsets can split them
+        let values = "aé€b".as_bytes();
+        let result = Utf8Array::<i32>::try_new(
+            DataType::Utf8,
+            offsets.clone().into(),
+            Buffer::from_slice(values),
+            None,
+        );
+
+        // the array is accepted iff every offset is an in-bounds char boundary
+        let is_boundary = |o: &i32| {
+            usize::try_from(*o).map_or(false, |o| std::str::from_utf8(values).unwrap().is_char_boundary(o))
+        };
+        let expected = (!offsets.is_empty() && offsets.iter().all(is_boundary))
+            .then(|| {
+                offsets
+                    .windows(2)
+                    .map(|w| {
+                        let start = usize::try_from(w[0]).ok()?;
+                        let end = usize::try_from(w[1]).ok()?;
+                        std::str::from_utf8(values.get(start..end)?).ok()
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .flatten();
+        match (result, expected) {
+            (Ok(array), Some(expected)) => {
+                assert_eq!(array.values_iter().collect::<Vec<_>>(), expected)
+            }
+            (Err(_), None) => {}
+            (result, expected) => panic!("{:?} {:?}", result, expected),
+        }
+    }
+}
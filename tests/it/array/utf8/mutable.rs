@@ -1,6 +1,7 @@
-use arrow2::array::{MutableUtf8Array, Utf8Array};
+use arrow2::array::{Array, MutableArray, MutableUtf8Array, TryPush, Utf8Array};
 use arrow2::bitmap::Bitmap;
 use arrow2::datatypes::DataType;
+use arrow2::error::ArrowError;
 
 #[test]
 fn capacities() {
@@ -8,6 +9,37 @@ fn capacities() {
 
     assert!(b.values().capacity() >= 10);
     assert!(b.offsets().capacity() >= 2);
+    assert!(b.values_capacity() >= 10);
+    assert!(b.capacity() >= 1);
+}
+
+#[test]
+fn reserve() {
+    let mut b = MutableUtf8Array::<i32>::new();
+    b.reserve(10, 100);
+    assert!(b.capacity() >= 10);
+    assert!(b.values_capacity() >= 100);
+    let (capacity, values_capacity) = (b.capacity(), b.values_capacity());
+
+    (0..10).for_each(|_| b.push(Some("0123456789")));
+    assert_eq!(b.capacity(), capacity);
+    assert_eq!(b.values_capacity(), values_capacity);
+}
+
+#[test]
+fn push_unchecked() {
+    let mut array = MutableUtf8Array::<i32>::new();
+    unsafe {
+        array.push_unchecked(Some("a"));
+        array.push_unchecked::<&str>(None);
+        array.push_unchecked(Some("bc"));
+    }
+
+    let array: Utf8Array<i32> = array.into();
+    assert_eq!(
+        array,
+        Utf8Array::<i32>::from(&[Some("a"), None, Some("bc")])
+    );
 }
 
 #[test]
@@ -95,3 +127,63 @@ fn test_extend_values() {
     assert_eq!(array.offsets().as_slice(), &[0, 2, 7, 12, 17]);
     assert_eq!(array.validity(), None,);
 }
+
+#[test]
+fn try_extend_from_array() {
+    let other = Utf8Array::<i32>::from(&[Some("a"), None, Some("bc"), Some("d")]).slice(1, 3);
+
+    // `a` has no validity until it is extended with nulls
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.push(Some("e"));
+    a.try_extend_from_array(&other, 0, 2).unwrap();
+    a.try_extend_from_array(&other, 2, 1).unwrap();
+
+    let a: Utf8Array<i32> = a.into();
+    assert_eq!(
+        a,
+        Utf8Array::<i32>::from(&[Some("e"), None, Some("bc"), Some("d")])
+    );
+}
+
+#[test]
+fn try_extend_trusted_len_overflow() {
+    // the values are zeroed, so this does not touch (most of) its memory
+    let values = vec![0u8; i32::MAX as usize - 1];
+    let offsets = vec![0, i32::MAX - 1];
+    let mut array = unsafe {
+        MutableUtf8Array::<i32>::from_data_unchecked(DataType::Utf8, offsets, values, None)
+    };
+
+    let result = array.try_extend_trusted_len(vec![None, Some("ab")].into_iter());
+    assert!(matches!(result, Err(ArrowError::Overflow)));
+    let result = array.try_extend_trusted_len_values(vec!["ab"].into_iter());
+    assert!(matches!(result, Err(ArrowError::Overflow)));
+    assert!(matches!(
+        array.try_push(Some("ab")),
+        Err(ArrowError::Overflow)
+    ));
+
+    // the array is left unchanged
+    assert_eq!(array.len(), 1);
+    assert_eq!(array.offsets().as_slice(), &[0, i32::MAX - 1]);
+    assert_eq!(array.values().len(), i32::MAX as usize - 1);
+    assert_eq!(array.validity(), None);
+
+    array.push::<&str>(None);
+    assert_eq!(array.len(), 2);
+}
+
+#[test]
+fn as_box_twice() {
+    let mut a = MutableUtf8Array::<i32>::new();
+    a.push(Some("a"));
+    let _ = a.as_box();
+    // the array is left empty and can be reused
+    assert_eq!(a.len(), 0);
+    a.push(Some("bc"));
+    let a = a.as_box();
+    assert_eq!(
+        a.as_ref(),
+        &Utf8Array::<i32>::from_slice(["bc"]) as &dyn Array
+    );
+}
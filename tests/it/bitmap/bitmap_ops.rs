@@ -1,9 +1,45 @@
 use proptest::prelude::*;
 
-use arrow2::bitmap::Bitmap;
+use arrow2::bitmap::{and, or, xor, Bitmap};
 
 use crate::bitmap::bitmap_strategy;
 
+/// Returns a strategy of two bitmaps of the same length sliced at independent offsets
+fn two_bitmaps_strategy() -> impl Strategy<Value = (Bitmap, Bitmap)> {
+    (1..300usize)
+        .prop_flat_map(|len| (0..70usize, 0..70usize, Just(len)))
+        .prop_flat_map(|(lhs_offset, rhs_offset, len)| {
+            (
+                prop::collection::vec(any::<bool>(), lhs_offset + len),
+                prop::collection::vec(any::<bool>(), rhs_offset + len),
+                Just((lhs_offset, rhs_offset, len)),
+            )
+        })
+        .prop_map(|(lhs, rhs, (lhs_offset, rhs_offset, len))| {
+            (
+                Bitmap::from(&lhs).slice(lhs_offset, len),
+                Bitmap::from(&rhs).slice(rhs_offset, len),
+            )
+        })
+}
+
+fn assert_op<F, G>(lhs: &Bitmap, rhs: &Bitmap, op: F, expected: G)
+where
+    F: Fn(&Bitmap, &Bitmap) -> Bitmap,
+    G: Fn(bool, bool) -> bool,
+{
+    let result = op(lhs, rhs);
+    let expected: Bitmap = lhs
+        .iter()
+        .zip(rhs.iter())
+        .map(|(x, y)| expected(x, y))
+        .collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result.len(), lhs.len());
+    assert_eq!(result.null_count(), result.iter().filter(|x| !x).count());
+}
+
 proptest! {
     /// Asserts that !bitmap equals all bits flipped
     #[test]
@@ -12,5 +48,44 @@ proptest! {
         let not_bitmap: Bitmap = bitmap.iter().map(|x| !x).collect();
 
         assert_eq!(!&bitmap, not_bitmap);
+        assert_eq!(arrow2::bitmap::not(&bitmap), not_bitmap);
+        assert_eq!((!&bitmap).null_count(), bitmap.len() - bitmap.null_count());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn binary_ops((lhs, rhs) in two_bitmaps_strategy()) {
+        assert_op(&lhs, &rhs, and, |x, y| x & y);
+        assert_op(&lhs, &rhs, or, |x, y| x | y);
+        assert_op(&lhs, &rhs, xor, |x, y| x ^ y);
+        assert_op(&lhs, &rhs, |x, y| x & y, |x, y| x & y);
     }
 }
+
+#[test]
+fn misaligned() {
+    let len = 200;
+    let lhs: Bitmap = (0..len + 3).map(|x| x % 3 == 0).collect();
+    let rhs: Bitmap = (0..len + 61).map(|x| x % 5 != 0).collect();
+    let lhs = lhs.slice(3, len);
+    let rhs = rhs.slice(61, len);
+
+    assert_op(&lhs, &rhs, and, |x, y| x & y);
+    assert_op(&lhs, &rhs, or, |x, y| x | y);
+    assert_op(&lhs, &rhs, xor, |x, y| x ^ y);
+}
+
+#[test]
+fn trailing_bits_are_unset() {
+    // the remainder of the result must not set bits past the length
+    let lhs: Bitmap = std::iter::repeat(true).take(70).collect();
+    let rhs = !&Bitmap::new_zeroed(70);
+
+    let result = or(&lhs, &rhs);
+    assert_eq!(result.null_count(), 0);
+    let (slice, _, _) = result.as_slice();
+    assert_eq!(slice[8], 0b0011_1111);
+
+    let (slice, _, _) = rhs.as_slice();
+    assert_eq!(slice[8], 0b0011_1111);
+}
@@ -1,4 +1,8 @@
-use arrow2::bitmap::Bitmap;
+use proptest::prelude::*;
+
+use arrow2::bitmap::{Bitmap, MutableBitmap};
+
+use super::bitmap_strategy;
 
 #[test]
 fn as_slice() {
@@ -39,3 +43,114 @@ fn debug() {
 
     assert_eq!(format!("{:?}", b), "[0b111110__, 0b_______1]");
 }
+
+#[test]
+fn into_mut_sliced() {
+    let b = Bitmap::from([
+        true, false, true, true, false, true, true, true, false, true,
+    ]);
+    assert!(b.clone().slice(1, 9).into_mut().is_left());
+    assert!(b.clone().slice(0, 7).into_mut().is_left());
+
+    // a slice within the last byte is reclaimed without its hidden bits
+    let mut b = b.slice(0, 9).into_mut().unwrap_right();
+    b.extend_constant(1, false);
+    let b: Bitmap = b.into();
+    assert_eq!(
+        b,
+        Bitmap::from([true, false, true, true, false, true, true, true, false, false])
+    );
+}
+
+#[test]
+fn count_ones_zeros() {
+    let b = Bitmap::from([true, false, true, true, false, false, true, true, true]).slice(1, 8);
+    assert_eq!(b.count_ones(0, 8), 5);
+    assert_eq!(b.count_zeros(0, 8), 3);
+    assert_eq!(b.count_ones(2, 3), 1);
+    assert_eq!(b.count_zeros(2, 0), 0);
+}
+
+#[test]
+#[should_panic]
+fn count_zeros_out_of_bounds() {
+    let b = Bitmap::from([true, false, true]).slice(1, 2);
+    b.count_zeros(1, 2);
+}
+
+proptest! {
+    /// Asserts that counting bits over a range equals counting them one by one
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn count_range(
+        (bitmap, offset, length) in bitmap_strategy().prop_flat_map(|bitmap| {
+            let len = bitmap.len();
+            (Just(bitmap), 0..=len)
+        }).prop_flat_map(|(bitmap, offset)| {
+            let len = bitmap.len();
+            (Just(bitmap), Just(offset), 0..=len - offset)
+        })
+    ) {
+        let ones = bitmap.iter().skip(offset).take(length).filter(|x| *x).count();
+
+        prop_assert_eq!(bitmap.count_ones(offset, length), ones);
+        prop_assert_eq!(bitmap.count_zeros(offset, length), length - ones);
+        prop_assert_eq!(bitmap.clone().slice(offset, length).null_count(), length - ones);
+
+        let mutable: MutableBitmap = bitmap.iter().collect();
+        prop_assert_eq!(mutable.count_ones(offset, length), ones);
+        prop_assert_eq!(mutable.count_zeros(offset, length), length - ones);
+    }
+}
+
+#[test]
+fn lazy_null_count() {
+    let bitmap = Bitmap::from([true, false, true, true, false, true, true, true]);
+
+    let sliced = bitmap.clone().slice(1, 4);
+    // clones share the count computed so far, but not the count computed later
+    let cloned = sliced.clone();
+    assert_eq!(sliced.null_count(), 2);
+    assert_eq!(cloned.null_count(), 2);
+    assert_eq!(sliced.clone().null_count(), 2);
+
+    assert_eq!(sliced.clone().slice(1, 2).null_count(), 0);
+    assert_eq!(sliced.slice(0, 0).null_count(), 0);
+    assert_eq!(bitmap.slice(0, 8).null_count(), 2);
+}
+
+#[test]
+fn constant_null_count() {
+    // slices of bitmaps with only set or only unset bits are not counted
+    let bitmap = Bitmap::from([true; 100]);
+    assert_eq!(bitmap.slice(3, 50).null_count(), 0);
+
+    let bitmap = Bitmap::new_zeroed(100);
+    assert_eq!(bitmap.slice(3, 50).null_count(), 50);
+}
+
+proptest! {
+    /// Asserts that the lazily computed null count of nested slices equals the eager one
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn nested_slices(
+        (bitmap, offset, length) in bitmap_strategy().prop_flat_map(|bitmap| {
+            let len = bitmap.len();
+            (Just(bitmap), 0..=len)
+        }).prop_flat_map(|(bitmap, offset)| {
+            let len = bitmap.len();
+            (Just(bitmap), Just(offset), 0..=len - offset)
+        })
+    ) {
+        let expected = |bitmap: &Bitmap| bitmap.iter().filter(|x| !x).count();
+
+        let sliced = bitmap.clone().slice(offset, length);
+        prop_assert_eq!(sliced.null_count(), expected(&sliced));
+
+        let half = length / 2;
+        let nested = sliced.clone().slice(half, length - half);
+        prop_assert_eq!(nested.null_count(), expected(&nested));
+        let nested = bitmap.slice(offset, length).slice(0, half);
+        prop_assert_eq!(nested.null_count(), expected(&nested));
+    }
+}
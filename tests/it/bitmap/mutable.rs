@@ -1,3 +1,5 @@
+use proptest::prelude::*;
+
 use arrow2::bitmap::{Bitmap, MutableBitmap};
 
 #[test]
@@ -42,6 +44,25 @@ fn push() {
     assert_eq!(bitmap.as_slice().0, &[0b11111001, 0b00000011]);
 }
 
+#[test]
+fn pop() {
+    let mut bitmap = MutableBitmap::new();
+    assert_eq!(bitmap.pop(), None);
+    for i in 0..10 {
+        bitmap.push(i % 3 == 0)
+    }
+    assert_eq!(bitmap.pop(), Some(true));
+    assert_eq!(bitmap.pop(), Some(false));
+    assert_eq!(bitmap.pop(), Some(false));
+    assert_eq!(bitmap.len(), 7);
+    bitmap.push(false);
+    bitmap.push(true);
+
+    let bitmap: Bitmap = bitmap.into();
+    assert_eq!(bitmap.len(), 9);
+    assert_eq!(bitmap.as_slice().0, &[0b01001001, 0b00000001]);
+}
+
 #[test]
 fn push_small() {
     let mut bitmap = MutableBitmap::new();
@@ -144,13 +165,13 @@ fn extend_from_bitmap() {
     let mut bitmap = MutableBitmap::new();
 
     // call is optimized to perform a memcopy
-    bitmap.extend_from_bitmap(&other);
+    bitmap.extend_from_bitmap(&other, 0, other.len());
 
     assert_eq!(bitmap.len(), 3);
     assert_eq!(bitmap.as_slice()[0], 0b00000101);
 
-    // this call iterates over all bits
-    bitmap.extend_from_bitmap(&other);
+    // this call shifts the bits of `other`
+    bitmap.extend_from_bitmap(&other, 0, other.len());
 
     assert_eq!(bitmap.len(), 6);
     assert_eq!(bitmap.as_slice()[0], 0b00101101);
@@ -162,7 +183,7 @@ fn extend_from_bitmap_offset() {
     let mut bitmap = MutableBitmap::from_vec(vec![1, 0, 0b00101010], 22);
 
     // call is optimized to perform a memcopy
-    bitmap.extend_from_bitmap(&other);
+    bitmap.extend_from_bitmap(&other, 0, other.len());
 
     assert_eq!(bitmap.len(), 22 + 8);
     assert_eq!(bitmap.as_slice(), &[1, 0, 0b11101010, 0b00001111]);
@@ -172,7 +193,7 @@ fn extend_from_bitmap_offset() {
     let mut bitmap = MutableBitmap::from_vec(vec![1, 0, 0b00101010], 22);
 
     // call is optimized to perform a memcopy
-    bitmap.extend_from_bitmap(&other);
+    bitmap.extend_from_bitmap(&other, 0, other.len());
 
     assert_eq!(bitmap.len(), 22 + 20);
     assert_eq!(
@@ -341,3 +362,98 @@ fn shrink_to_fit() {
     a.shrink_to_fit();
     assert!(a.capacity() < 1025);
 }
+
+#[test]
+fn extend_from_bitmap_range() {
+    let other = Bitmap::from([
+        true, false, true, true, false, true, true, true, false, true,
+    ]);
+    let other = other.slice(1, 9);
+
+    let mut bitmap = MutableBitmap::new();
+    bitmap.push(false);
+    bitmap.extend_from_bitmap(&other, 2, 6);
+
+    assert_eq!(bitmap.len(), 7);
+    assert_eq!(
+        bitmap.iter().collect::<Vec<_>>(),
+        vec![false, true, false, true, true, true, false]
+    );
+}
+
+#[test]
+#[should_panic]
+fn extend_from_bitmap_out_of_bounds() {
+    let other = Bitmap::from([true, false, true]);
+    let mut bitmap = MutableBitmap::new();
+    bitmap.extend_from_bitmap(&other, 1, 3);
+}
+
+#[test]
+fn extend_from_slice_of_bools() {
+    let mut bitmap = MutableBitmap::new();
+    bitmap.push(true);
+    bitmap.extend_from_slice_of_bools(&[false, true, true]);
+
+    assert_eq!(
+        bitmap.iter().collect::<Vec<_>>(),
+        vec![true, false, true, true]
+    );
+}
+
+proptest! {
+    /// Asserts that extending from a range of a bitmap equals pushing its bits one by one,
+    /// for any alignment of the source and of the destination
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn extend_from_bitmap_oracle(
+        (initial, values, offset, length) in (
+            prop::collection::vec(any::<bool>(), 0..80),
+            prop::collection::vec(any::<bool>(), 1..300),
+        )
+            .prop_flat_map(|(initial, values)| {
+                let len = values.len();
+                (Just(initial), Just(values), 0..=len)
+            })
+            .prop_flat_map(|(initial, values, offset)| {
+                let len = values.len();
+                (Just(initial), Just(values), Just(offset), 0..=len - offset)
+            })
+    ) {
+        let source = Bitmap::from(&values);
+        // slice the source so that its bits do not start at the beginning of a byte
+        let sliced = source.clone().slice(offset, values.len() - offset);
+
+        let mut expected = MutableBitmap::new();
+        initial.iter().chain(values[offset..offset + length].iter()).for_each(|x| expected.push(*x));
+
+        let mut result = MutableBitmap::new();
+        result.extend_from_slice_of_bools(&initial);
+        result.extend_from_bitmap(&source, offset, length);
+        prop_assert_eq!(result.len(), expected.len());
+        prop_assert_eq!(result.iter().collect::<Vec<_>>(), expected.iter().collect::<Vec<_>>());
+        prop_assert_eq!(result.null_count(), expected.null_count());
+
+        let mut result = MutableBitmap::new();
+        result.extend_from_slice_of_bools(&initial);
+        result.extend_from_bitmap(&sliced, 0, length);
+        prop_assert_eq!(result.iter().collect::<Vec<_>>(), expected.iter().collect::<Vec<_>>());
+
+        // pushing after extending must not observe bits past the length
+        result.push(false);
+        expected.push(false);
+        result.extend_constant(3, true);
+        expected.extend_constant(3, true);
+        prop_assert_eq!(Bitmap::from(result), Bitmap::from(expected));
+    }
+}
+
+#[test]
+fn extend_from_empty_trusted_len_iter() {
+    let mut bitmap = MutableBitmap::new();
+    bitmap.extend_from_slice_of_bools(&[]);
+    bitmap.extend_from_slice(&[0b00000101], 0, 3);
+
+    assert_eq!(bitmap.len(), 3);
+    assert_eq!(bitmap.as_slice(), &[0b00000101]);
+}
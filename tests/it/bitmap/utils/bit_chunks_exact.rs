@@ -11,7 +11,7 @@ fn basics() {
 fn basics_u16_small() {
     let mut iter = BitChunksExact::<u16>::new(&[0b11111111u8], 7);
     assert_eq!(iter.next(), None);
-    assert_eq!(iter.remainder(), 0b0000_0000_1111_1111u16);
+    assert_eq!(iter.remainder(), 0b0000_0000_0111_1111u16);
 }
 
 #[test]
@@ -29,5 +29,17 @@ fn remainder_u16() {
     );
     assert_eq!(iter.next(), Some(511));
     assert_eq!(iter.next(), None);
-    assert_eq!(iter.remainder(), 0b1101_1011_0000_0001u16);
+    assert_eq!(iter.remainder(), 0b0000_0000_0000_0001u16);
+}
+
+#[test]
+fn remainder_is_bounded() {
+    // the remainder only reads the bytes up to `len`
+    let iter = BitChunksExact::<u8>::new(&[0b11111111u8, 0b11111111u8, 0b11111111u8], 3);
+    assert_eq!(iter.remainder(), 0b00000111);
+    assert_eq!(iter.len(), 0);
+
+    let iter = BitChunksExact::<u64>::new(&[255; 20], 70);
+    assert_eq!(iter.remainder(), 0b00111111);
+    assert_eq!(iter.len(), 1);
 }
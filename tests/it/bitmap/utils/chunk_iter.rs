@@ -1,4 +1,5 @@
-use arrow2::bitmap::utils::BitChunks;
+use arrow2::bitmap::utils::{BitChunkIterExact, BitChunks};
+use arrow2::bitmap::Bitmap;
 use arrow2::types::BitChunkIter;
 
 #[test]
@@ -10,8 +11,8 @@ fn basics() {
 
 #[test]
 fn remainder() {
-    let a = BitChunks::<u16>::new(&[0b00000001u8, 0b00000010u8, 0b00000100u8], 0, 18);
-    assert_eq!(a.remainder(), 0b00000100u16);
+    let a = BitChunks::<u16>::new(&[0b00000001u8, 0b00000010u8, 0b00000110u8], 0, 18);
+    assert_eq!(a.remainder(), 0b00000010u16);
 }
 
 #[test]
@@ -32,7 +33,7 @@ fn basics_offset() {
 fn basics_offset_remainder() {
     let mut a = BitChunks::<u16>::new(&[0b00000001u8, 0b00000011u8, 0b10000001u8], 1, 15);
     assert_eq!(a.next(), None);
-    assert_eq!(a.remainder(), 0b1000_0001_1000_0000u16);
+    assert_eq!(a.remainder(), 0b0000_0001_1000_0000u16);
     assert_eq!(a.remainder_len(), 15);
 }
 
@@ -52,7 +53,7 @@ fn offset_remainder_saturating2() {
 fn offset_remainder_saturating3() {
     let input: &[u8] = &[0b01000000, 0b01000001];
     let a = BitChunks::<u64>::new(input, 8, 2);
-    assert_eq!(a.remainder(), 0b0100_0001u64);
+    assert_eq!(a.remainder(), 0b0000_0001u64);
 }
 
 #[test]
@@ -161,3 +162,45 @@ fn remainder_2() {
     assert_eq!(iter.next(), None);
     assert_eq!(iter.remainder(), 0b10010010010010010010010010u64);
 }
+
+/// Returns 200 bits sliced at `offset`, alongside the expected bits
+fn sliced(offset: usize) -> (Bitmap, Vec<bool>) {
+    let values = (0..offset + 200)
+        .map(|x| x % 3 == 0 || x % 7 == 0)
+        .collect::<Vec<_>>();
+    let bitmap = Bitmap::from(&values).slice(offset, 200);
+    (bitmap, values[offset..].to_vec())
+}
+
+#[test]
+fn chunks_offsets() {
+    for offset in [0, 1, 7, 8, 63, 64] {
+        let (bitmap, expected) = sliced(offset);
+
+        let chunks = bitmap.chunks::<u64>();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.remainder_len(), 8);
+
+        let remainder = chunks.remainder_iter().collect::<Vec<_>>();
+        let result = chunks
+            .flat_map(|chunk| BitChunkIter::new(chunk, 64))
+            .chain(remainder)
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "offset {}", offset);
+
+        // the bits past the remainder are unset
+        let chunks = bitmap.chunks::<u64>();
+        assert_eq!(chunks.remainder() >> 8, 0, "offset {}", offset);
+    }
+}
+
+#[test]
+fn len() {
+    let mut iter = BitChunks::<u8>::new(&[0b00000001u8, 0b00000011u8, 0b00000001u8], 1, 17);
+    assert_eq!(iter.len(), 2);
+    iter.next();
+    assert_eq!(iter.len(), 1);
+    iter.next();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
@@ -149,3 +149,39 @@ fn remainder_1() {
     let chunks = iter.collect::<Vec<_>>();
     assert_eq!(chunks, vec![(2, 1), (4, 1)]);
 }
+
+#[test]
+fn offsets() {
+    for offset in [0, 1, 7, 8, 63, 64] {
+        let values = (0..offset + 200).map(|x| x % 17 > 5).collect::<Vec<_>>();
+        let bitmap = Bitmap::from(&values).slice(offset, 200);
+
+        let mut expected = vec![];
+        let mut start = None;
+        for (i, value) in values[offset..]
+            .iter()
+            .chain(std::iter::once(&false))
+            .enumerate()
+        {
+            match (start, value) {
+                (None, true) => start = Some(i),
+                (Some(s), false) => {
+                    expected.push((s, i - s));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        assert_eq!(
+            bitmap.slices().collect::<Vec<_>>(),
+            expected,
+            "offset {}",
+            offset
+        );
+        assert_eq!(
+            SlicesIterator::new(&bitmap).slots(),
+            bitmap.len() - bitmap.null_count()
+        );
+    }
+}
@@ -1,4 +1,12 @@
+use std::any::Any;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use arrow2::array::PrimitiveArray;
 use arrow2::buffer::Buffer;
+use arrow2::compute::aggregate::sum_primitive;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result as ArrowResult;
 
 #[test]
 fn new() {
@@ -74,3 +82,238 @@ fn from_vec() {
     assert_eq!(buffer.len(), 3);
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
+
+#[test]
+fn into_mut() {
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]);
+    let ptr = buffer.as_slice().as_ptr();
+    let vec = buffer.into_mut().unwrap_right();
+    assert_eq!(vec, vec![0, 1, 2]);
+    assert_eq!(vec.as_ptr(), ptr);
+}
+
+#[test]
+fn into_mut_shared() {
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]);
+    let _shared = buffer.clone();
+    let buffer = buffer.into_mut().unwrap_left();
+    assert_eq!(buffer.as_slice(), &[0, 1, 2]);
+}
+
+#[test]
+fn into_mut_sliced() {
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]);
+    assert!(buffer.clone().slice(1, 2).into_mut().is_left());
+
+    // a slice starting at zero still hides the remaining values
+    let buffer = buffer.slice(0, 2).into_mut().unwrap_left();
+    assert_eq!(buffer.as_slice(), &[0, 1]);
+}
+
+#[test]
+fn from_vec_zero_copy() {
+    let vec = vec![1i32, 2, 3];
+    let ptr = vec.as_ptr();
+    let buffer: Buffer<i32> = vec.into();
+    assert_eq!(buffer.as_slice().as_ptr(), ptr);
+}
+
+/// An owner of foreign memory that records when it is dropped
+struct Owner {
+    data: Vec<u8>,
+    dropped: Arc<AtomicBool>,
+}
+
+impl AsRef<[u8]> for Owner {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Drop for Owner {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn from_owner() -> ArrowResult<()> {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let owner = Arc::new(Owner {
+        data: vec![1, 2, 3, 4],
+        dropped: dropped.clone(),
+    });
+    let ptr = owner.data.as_ptr();
+
+    let buffer = Buffer::<u8>::from_owner(owner)?;
+    assert_eq!(buffer.as_slice(), &[1, 2, 3, 4]);
+    assert_eq!(buffer.as_slice().as_ptr(), ptr);
+
+    // the owner is kept alive by any buffer referencing its memory
+    let sliced = buffer.clone().slice(1, 2);
+    drop(buffer);
+    assert!(!dropped.load(Ordering::SeqCst));
+    assert_eq!(sliced.as_slice(), &[2, 3]);
+
+    let array = PrimitiveArray::<u8>::from_data(DataType::UInt8, sliced, None);
+    assert_eq!(sum_primitive(&array), Some(5));
+    assert!(!dropped.load(Ordering::SeqCst));
+
+    drop(array);
+    assert!(dropped.load(Ordering::SeqCst));
+    Ok(())
+}
+
+#[test]
+fn from_owner_is_not_mutable() -> ArrowResult<()> {
+    let buffer = Buffer::<u8>::from_owner(Arc::new(vec![1u8, 2]))?;
+    assert!(buffer.into_mut().is_left());
+    Ok(())
+}
+
+#[test]
+fn from_owner_invalid_length() {
+    assert!(Buffer::<i32>::from_owner(Arc::new(vec![0u8; 6])).is_err());
+}
+
+#[test]
+fn from_owner_region() -> ArrowResult<()> {
+    let owner = Arc::new(vec![0u8, 1, 0, 0, 0, 2, 0, 0, 0]);
+    let bytes = owner.as_ptr();
+
+    let buffer = Buffer::<u8>::from_owner_region(owner.clone(), 1, 4)?;
+    assert_eq!(buffer.as_slice(), &[1, 0, 0, 0]);
+    assert_eq!(buffer.as_slice().as_ptr(), bytes.wrapping_add(1));
+
+    assert!(Buffer::<u8>::from_owner_region(owner.clone(), 6, 4).is_err());
+    assert!(Buffer::<u8>::from_owner_region(owner, usize::MAX, 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn from_owner_region_misaligned() -> ArrowResult<()> {
+    let data = [1i32, 2, 3];
+    let owner = Arc::new(
+        data.iter()
+            .flat_map(|x| x.to_ne_bytes())
+            .collect::<Vec<u8>>(),
+    );
+
+    let buffer = Buffer::<i32>::from_owner_region(owner.clone(), 4, 2);
+    // a `Vec<u8>` is not necessarily aligned to 4 bytes
+    if owner.as_ptr().align_offset(4) == 0 {
+        assert_eq!(buffer?.as_slice(), &[2, 3]);
+        assert!(Buffer::<i32>::from_owner_region(owner, 2, 1).is_err());
+    } else {
+        assert!(buffer.is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn from_foreign_misaligned() {
+    let data = vec![0i64; 3];
+    let ptr = data.as_ptr() as *const u8;
+    let owner: Arc<dyn Any + Send + Sync> = Arc::new(data);
+
+    // Safety: the region is valid for the 8 bytes after its first byte
+    let result = unsafe { Buffer::<i32>::from_foreign(ptr.add(1) as *const i32, 2, owner.clone()) };
+    assert!(result.is_err());
+
+    // Safety: the region is valid for 24 bytes, i.e. 6 `i32`
+    let buffer =
+        unsafe { Buffer::<i32>::from_foreign(ptr.add(4) as *const i32, 2, owner) }.unwrap();
+    assert_eq!(buffer.as_slice(), &[0, 0]);
+}
+
+#[test]
+fn from_foreign_null() {
+    let owner: Arc<dyn Any + Send + Sync> = Arc::new(());
+    assert!(unsafe { Buffer::<i32>::from_foreign(std::ptr::null(), 1, owner.clone()) }.is_err());
+
+    let buffer = unsafe { Buffer::<i32>::from_foreign(std::ptr::null(), 0, owner) }.unwrap();
+    assert!(buffer.is_empty());
+}
+
+#[cfg(unix)]
+mod mmap {
+    use std::io::Write;
+    use std::os::unix::io::AsRawFd;
+    use std::sync::Arc;
+
+    use arrow2::array::PrimitiveArray;
+    use arrow2::buffer::Buffer;
+    use arrow2::compute::aggregate::{max_primitive, sum_primitive};
+    use arrow2::compute::arithmetics::basic::add_scalar;
+    use arrow2::datatypes::DataType;
+    use arrow2::error::Result;
+
+    /// A read-only memory-mapped file
+    struct Mmap {
+        ptr: *mut libc::c_void,
+        len: usize,
+    }
+
+    // Safety: the mapping is read-only and its memory is not shared with anything else
+    unsafe impl Send for Mmap {}
+    unsafe impl Sync for Mmap {}
+
+    impl Mmap {
+        fn new(file: &std::fs::File, len: usize) -> Self {
+            let ptr = unsafe {
+                libc::mmap(
+                    std::ptr::null_mut(),
+                    len,
+                    libc::PROT_READ,
+                    libc::MAP_PRIVATE,
+                    file.as_raw_fd(),
+                    0,
+                )
+            };
+            assert_ne!(ptr, libc::MAP_FAILED);
+            Self { ptr, len }
+        }
+    }
+
+    impl AsRef<[u8]> for Mmap {
+        fn as_ref(&self) -> &[u8] {
+            unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+        }
+    }
+
+    impl Drop for Mmap {
+        fn drop(&mut self) {
+            unsafe { libc::munmap(self.ptr, self.len) };
+        }
+    }
+
+    #[test]
+    fn primitive_array() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("arrow2_mmap_{}.bin", std::process::id()));
+        let values = (0..1000i32).collect::<Vec<_>>();
+        let bytes = values
+            .iter()
+            .flat_map(|x| x.to_ne_bytes())
+            .collect::<Vec<_>>();
+        std::fs::File::create(&path)?.write_all(&bytes)?;
+
+        let file = std::fs::File::open(&path)?;
+        let mmap = Mmap::new(&file, bytes.len());
+        drop(file);
+        std::fs::remove_file(&path)?;
+
+        // mapped regions are page-aligned
+        let buffer = Buffer::<i32>::from_owner(Arc::new(mmap))?;
+        let array = PrimitiveArray::<i32>::from_data(DataType::Int32, buffer, None);
+
+        assert_eq!(array.len(), 1000);
+        assert_eq!(sum_primitive(&array), Some(499500));
+        assert_eq!(max_primitive(&array.clone().slice(10, 20)), Some(29));
+
+        let result = add_scalar(&array, &1);
+        assert_eq!(result.value(999), 1000);
+        drop(array);
+        assert_eq!(result.value(0), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{index_of_first_sorted, index_of_max, index_of_min};
+use arrow2::compute::sort::{sort, SortOptions};
+use arrow2::compute::take::take;
+
+#[test]
+fn primitive() {
+    let a = Int32Array::from(&[Some(3), None, Some(1), Some(5), Some(1), Some(5)]);
+    assert_eq!(index_of_min(&a).unwrap(), Some(2));
+    assert_eq!(index_of_max(&a).unwrap(), Some(3));
+}
+
+#[test]
+fn empty_and_all_null() {
+    let a = Int32Array::from_slice(&[]);
+    assert_eq!(index_of_min(&a).unwrap(), None);
+    assert_eq!(index_of_max(&a).unwrap(), None);
+
+    let a = Int32Array::from(&[None, None]);
+    assert_eq!(index_of_min(&a).unwrap(), None);
+    assert_eq!(index_of_max(&a).unwrap(), None);
+}
+
+#[test]
+fn sliced() {
+    let a = Int64Array::from(&[Some(-10), Some(2), None, Some(1), Some(10)]);
+    let a = a.slice(1, 3);
+    assert_eq!(index_of_min(&a).unwrap(), Some(2));
+    assert_eq!(index_of_max(&a).unwrap(), Some(0));
+}
+
+#[test]
+fn float_nan() {
+    let a = Float64Array::from(&[
+        Some(1.0),
+        Some(f64::NAN),
+        None,
+        Some(-1.0),
+        Some(f64::INFINITY),
+    ]);
+    assert_eq!(index_of_min(&a).unwrap(), Some(3));
+    assert_eq!(index_of_max(&a).unwrap(), Some(1));
+
+    // consistent with the order of the sort kernels
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let sorted = sort(&a, &options, Some(1)).unwrap();
+    let sorted = sorted.as_any().downcast_ref::<Float64Array>().unwrap();
+    let max = take(&a, &Int32Array::from_slice(&[1])).unwrap();
+    let max = max.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert!(max.value(0).is_nan());
+    assert!(sorted.value(0).is_nan());
+}
+
+#[test]
+fn utf8() {
+    let a = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("c"), Some("a")]);
+    assert_eq!(index_of_min(&a).unwrap(), Some(2));
+    assert_eq!(index_of_max(&a).unwrap(), Some(3));
+
+    let a = Utf8Array::<i64>::from(&[Some("b"), Some("c"), Some("c")]);
+    assert_eq!(index_of_min(&a).unwrap(), Some(0));
+    assert_eq!(index_of_max(&a).unwrap(), Some(1));
+}
+
+#[test]
+fn boolean() {
+    let a = BooleanArray::from(&[None, Some(true), Some(false), Some(true)]);
+    assert_eq!(index_of_min(&a).unwrap(), Some(2));
+    assert_eq!(index_of_max(&a).unwrap(), Some(1));
+}
+
+#[test]
+fn unsupported() {
+    let a = BinaryArray::<i32>::from_slice(&[b"a"]);
+    assert!(index_of_min(&a).is_err());
+}
+
+#[test]
+fn first_sorted() {
+    let a = Int32Array::from(&[Some(3), None, Some(1), Some(5)]);
+    let options = |descending, nulls_first| SortOptions {
+        descending,
+        nulls_first,
+    };
+    assert_eq!(
+        index_of_first_sorted(&a, &options(false, false)).unwrap(),
+        Some(2)
+    );
+    assert_eq!(
+        index_of_first_sorted(&a, &options(true, false)).unwrap(),
+        Some(3)
+    );
+    assert_eq!(
+        index_of_first_sorted(&a, &options(true, true)).unwrap(),
+        Some(1)
+    );
+
+    let a = Int32Array::from(&[None, None]);
+    assert_eq!(
+        index_of_first_sorted(&a, &options(false, false)).unwrap(),
+        Some(0)
+    );
+
+    let a = Int32Array::from_slice(&[]);
+    assert_eq!(
+        index_of_first_sorted(&a, &options(false, true)).unwrap(),
+        None
+    );
+}
@@ -0,0 +1,112 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{
+    can_sum, max, max_decimal, min, min_decimal, sum, sum_decimal, wrapping_sum_decimal,
+};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+
+fn decimal(values: &[Option<i128>], precision: usize, scale: usize) -> PrimitiveArray<i128> {
+    PrimitiveArray::<i128>::from(values).to(DataType::Decimal(precision, scale))
+}
+
+#[test]
+fn sum_with_nulls() {
+    let a = decimal(&[Some(1_00), None, Some(2_50), Some(-0_25)], 5, 2);
+    let expected = PrimitiveScalar::new(DataType::Decimal(38, 2), Some(3_25));
+    assert_eq!(sum_decimal(&a).unwrap(), expected);
+}
+
+#[test]
+fn sum_all_null() {
+    let a = decimal(&[None, None], 5, 2);
+    let expected = PrimitiveScalar::new(DataType::Decimal(38, 2), None);
+    assert_eq!(sum_decimal(&a).unwrap(), expected);
+
+    let a = decimal(&[], 5, 2);
+    assert_eq!(sum_decimal(&a).unwrap(), expected);
+}
+
+#[test]
+fn sum_exceeds_precision() {
+    // 1.5 * 10^38 fits in an i128 but not in 38 digits
+    let max = 10i128.pow(38) - 1;
+    let half = 5 * 10i128.pow(37);
+    let a = decimal(&[Some(max), Some(half)], 38, 0);
+    assert!(sum_decimal(&a).is_err());
+
+    // ... unless the values cancel out
+    let a = decimal(&[Some(max), Some(half), Some(-half)], 38, 0);
+    let expected = PrimitiveScalar::new(DataType::Decimal(38, 0), Some(max));
+    assert_eq!(sum_decimal(&a).unwrap(), expected);
+}
+
+#[test]
+fn sum_overflows_i128() {
+    let a = decimal(&[Some(i128::MAX), Some(1)], 38, 0);
+    assert!(sum_decimal(&a).is_err());
+
+    let expected = PrimitiveScalar::new(DataType::Decimal(38, 0), Some(i128::MIN));
+    assert_eq!(wrapping_sum_decimal(&a), expected);
+}
+
+#[test]
+fn min_max() {
+    let a = decimal(&[Some(1_00), None, Some(-2_50), Some(3_25)], 5, 2);
+    let data_type = DataType::Decimal(5, 2);
+    assert_eq!(
+        min_decimal(&a),
+        PrimitiveScalar::new(data_type.clone(), Some(-2_50))
+    );
+    assert_eq!(
+        max_decimal(&a),
+        PrimitiveScalar::new(data_type.clone(), Some(3_25))
+    );
+
+    let a = decimal(&[None], 5, 2);
+    assert_eq!(
+        min_decimal(&a),
+        PrimitiveScalar::new(data_type.clone(), None)
+    );
+    assert_eq!(max_decimal(&a), PrimitiveScalar::new(data_type, None));
+}
+
+#[test]
+fn sliced() {
+    let a = decimal(
+        &[Some(10_00), Some(1_00), None, Some(2_00), Some(-10_00)],
+        5,
+        2,
+    );
+    let a = a.slice(1, 3);
+    assert_eq!(
+        sum_decimal(&a).unwrap(),
+        PrimitiveScalar::new(DataType::Decimal(38, 2), Some(3_00))
+    );
+    assert_eq!(
+        min_decimal(&a),
+        PrimitiveScalar::new(DataType::Decimal(5, 2), Some(1_00))
+    );
+    assert_eq!(
+        max_decimal(&a),
+        PrimitiveScalar::new(DataType::Decimal(5, 2), Some(2_00))
+    );
+}
+
+#[test]
+fn dyn_aggregates() {
+    let a = decimal(&[Some(1_00), None, Some(-2_50)], 5, 2);
+    assert!(can_sum(a.data_type()));
+
+    assert_eq!(
+        sum(&a).unwrap().as_ref(),
+        &PrimitiveScalar::new(DataType::Decimal(38, 2), Some(-1_50i128)) as &dyn Scalar
+    );
+    assert_eq!(
+        min(&a).unwrap().as_ref(),
+        &PrimitiveScalar::new(DataType::Decimal(5, 2), Some(-2_50i128)) as &dyn Scalar
+    );
+    assert_eq!(
+        max(&a).unwrap().as_ref(),
+        &PrimitiveScalar::new(DataType::Decimal(5, 2), Some(1_00i128)) as &dyn Scalar
+    );
+}
@@ -0,0 +1,60 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{can_sum, max, max_f16, min, min_f16, sum, sum_f16};
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Scalar};
+use arrow2::types::f16;
+
+fn float16(values: &[Option<f32>]) -> Float16Array {
+    Float16Array::from_trusted_len_iter(values.iter().map(|x| x.map(f16::from_f32)))
+}
+
+#[test]
+fn sum_accumulates_in_f32() {
+    // summed in f16, 2048 + 1 would be rounded back to 2048 at every step
+    let mut values = vec![Some(2048.0)];
+    values.extend(std::iter::repeat(Some(1.0)).take(8));
+    values.push(None);
+    let a = float16(&values);
+
+    assert!(can_sum(&DataType::Float16));
+    assert_eq!(sum_f16(&a), Some(f16::from_f32(2056.0)));
+    assert_eq!(
+        &PrimitiveScalar::<f16>::from(Some(f16::from_f32(2056.0))) as &dyn Scalar,
+        sum(&a).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn sum_all_null() {
+    let a = float16(&[None, None]);
+    assert_eq!(sum_f16(&a), None);
+    assert_eq!(sum_f16(&float16(&[])), None);
+}
+
+#[test]
+fn min_max() {
+    let a = float16(&[Some(1.5), None, Some(-0.25), Some(f32::NAN), Some(3.0)]);
+    assert_eq!(min_f16(&a), Some(f16::from_f32(-0.25)));
+    assert_eq!(max_f16(&a), Some(f16::from_f32(3.0)));
+
+    assert_eq!(
+        &PrimitiveScalar::<f16>::from(Some(f16::from_f32(-0.25))) as &dyn Scalar,
+        min(&a).unwrap().as_ref()
+    );
+    assert_eq!(
+        &PrimitiveScalar::<f16>::from(Some(f16::from_f32(3.0))) as &dyn Scalar,
+        max(&a).unwrap().as_ref()
+    );
+
+    let a = float16(&[None]);
+    assert_eq!(min_f16(&a), None);
+    assert_eq!(max_f16(&a), None);
+}
+
+#[test]
+fn sliced() {
+    let a = float16(&[Some(100.0), Some(1.0), Some(2.0), Some(-100.0)]).slice(1, 2);
+    assert_eq!(sum_f16(&a), Some(f16::from_f32(3.0)));
+    assert_eq!(min_f16(&a), Some(f16::from_f32(1.0)));
+    assert_eq!(max_f16(&a), Some(f16::from_f32(2.0)));
+}
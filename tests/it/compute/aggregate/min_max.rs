@@ -1,6 +1,6 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_max_binary,
+    min_max_boolean, min_max_primitive, min_max_string, min_primitive, min_string,
 };
 use arrow2::{array::*, datatypes::DataType};
 
@@ -186,3 +186,130 @@ fn test_binary_min_max_1() {
     assert_eq!(Some("a".as_bytes()), min_binary(&a));
     assert_eq!(Some("b".as_bytes()), max_binary(&a));
 }
+
+#[test]
+fn test_string_min_max_empty() {
+    let a = Utf8Array::<i32>::from_slice::<&str, _>([]);
+    assert_eq!(None, min_string(&a));
+    assert_eq!(None, max_string(&a));
+}
+
+#[test]
+fn test_string_min_max_sliced() {
+    let a = Utf8Array::<i32>::from_slice(["z", "c", "b", "d", "a"]);
+    let a = a.slice(1, 3);
+    assert_eq!(Some("b"), min_string(&a));
+    assert_eq!(Some("d"), max_string(&a));
+
+    let a = Utf8Array::<i64>::from(&[Some("a"), None, Some("bb"), Some("c"), None, Some("z")]);
+    let a = a.slice(1, 4);
+    assert_eq!(Some("bb"), min_string(&a));
+    assert_eq!(Some("c"), max_string(&a));
+
+    // only nulls remain
+    let a = a.slice(3, 1);
+    assert_eq!(None, min_string(&a));
+    assert_eq!(None, max_string(&a));
+}
+
+#[test]
+fn test_binary_min_max_empty() {
+    let a = BinaryArray::<i64>::from_slice::<&[u8], _>([]);
+    assert_eq!(None, min_binary(&a));
+    assert_eq!(None, max_binary(&a));
+}
+
+#[test]
+fn test_binary_min_max_sliced() {
+    let a = BinaryArray::<i32>::from_slice([b"z", b"c", b"b", b"d", b"a"]);
+    let a = a.slice(1, 3);
+    assert_eq!(Some(b"b".as_ref()), min_binary(&a));
+    assert_eq!(Some(b"d".as_ref()), max_binary(&a));
+
+    let a = BinaryArray::<i64>::from(&[Some(b"a".as_ref()), None, Some(b"bb"), Some(b"c"), None]);
+    let a = a.slice(1, 4);
+    assert_eq!(Some(b"bb".as_ref()), min_binary(&a));
+    assert_eq!(Some(b"c".as_ref()), max_binary(&a));
+}
+
+#[test]
+fn test_dyn_string_binary_min_max() {
+    use arrow2::compute::aggregate::{max, min};
+    use arrow2::scalar::{BinaryScalar, Utf8Scalar};
+
+    let a = Utf8Array::<i32>::from(&[Some("b"), None, Some("a")]);
+    assert_eq!(
+        min(&a).unwrap().as_ref(),
+        &Utf8Scalar::<i32>::new(Some("a")) as &dyn arrow2::scalar::Scalar
+    );
+    assert_eq!(
+        max(&a).unwrap().as_ref(),
+        &Utf8Scalar::<i32>::new(Some("b")) as &dyn arrow2::scalar::Scalar
+    );
+
+    let a = BinaryArray::<i64>::from(&[None::<&[u8]>, None]);
+    assert_eq!(
+        max(&a).unwrap().as_ref(),
+        &BinaryScalar::<i64>::new(None::<&[u8]>) as &dyn arrow2::scalar::Scalar
+    );
+}
+
+#[test]
+fn test_min_max_primitive() {
+    let a = Int32Array::from_slice(&[5, 6, 7, 8, 9]);
+    assert_eq!(min_max_primitive(&a), Some((5, 9)));
+
+    // more than one chunk of lanes, with nulls and a non-aligned offset
+    let a = (0..200)
+        .map(|x| if x % 5 == 0 { None } else { Some(x - 100) })
+        .collect::<Int64Array>();
+    for (offset, length) in [(0, 200), (3, 150), (17, 1), (1, 64)] {
+        let a = a.slice(offset, length);
+        assert_eq!(
+            min_max_primitive(&a),
+            min_primitive(&a).zip(max_primitive(&a)),
+        );
+    }
+
+    let a = Int32Array::from(&[None, None]);
+    assert_eq!(min_max_primitive(&a), None);
+    let a = Int32Array::from_slice(&[]);
+    assert_eq!(min_max_primitive(&a), None);
+}
+
+#[test]
+fn test_min_max_primitive_float() {
+    let a = Float64Array::from(&[Some(1.0), None, Some(f64::NAN), Some(-3.0), Some(2.0)]);
+    let (min, max) = min_max_primitive(&a).unwrap();
+    assert_eq!(min, min_primitive(&a).unwrap());
+    let expected = max_primitive(&a).unwrap();
+    assert!(max == expected || (max.is_nan() && expected.is_nan()));
+}
+
+#[test]
+fn test_min_max_string_binary() {
+    let a = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("c")]);
+    assert_eq!(min_max_string(&a), Some(("a", "c")));
+    assert_eq!(min_max_string(&a.slice(2, 1)), Some(("a", "a")));
+    assert_eq!(min_max_string(&a.slice(1, 1)), None);
+
+    let a = BinaryArray::<i64>::from_slice(&[b"b", b"a", b"c"]);
+    assert_eq!(min_max_binary(&a), Some((b"a".as_ref(), b"c".as_ref())));
+}
+
+#[test]
+fn test_min_max_boolean() {
+    let a = BooleanArray::from(&[Some(true), None, Some(false)]);
+    assert_eq!(min_max_boolean(&a), Some((false, true)));
+
+    let a = BooleanArray::from(&[Some(true), None, Some(true)]);
+    assert_eq!(min_max_boolean(&a), Some((true, true)));
+
+    // the `false` is outside of the slice
+    let a = (0..100).map(|x| Some(x != 0)).collect::<BooleanArray>();
+    assert_eq!(min_max_boolean(&a.slice(1, 99)), Some((true, true)));
+    assert_eq!(min_max_boolean(&a), Some((false, true)));
+
+    let a = BooleanArray::from(&[None, None]);
+    assert_eq!(min_max_boolean(&a), None);
+}
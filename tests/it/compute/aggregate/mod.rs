@@ -1,3 +1,9 @@
+mod arg_min_max;
+mod decimal;
+mod float16;
 mod memory;
 mod min_max;
+mod quantile;
+mod sliced;
 mod sum;
+mod variance;
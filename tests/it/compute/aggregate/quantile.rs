@@ -0,0 +1,154 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{median, quantile, quantile_chunked, QuantileMethod};
+use proptest::prelude::*;
+
+/// The linear interpolation of the sorted values, as a reference
+fn oracle(values: &[Option<i64>], q: f64) -> Option<f64> {
+    let mut values = values
+        .iter()
+        .flatten()
+        .map(|x| *x as f64)
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let position = q * (values.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    Some(values[lower] + (position - lower as f64) * (values[upper] - values[lower]))
+}
+
+/// A skewed (log-normal-like) sample from a deterministic generator
+fn skewed(len: usize) -> Vec<f64> {
+    let mut state = 0x2545f4914f6cdd1du64;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let uniform = (state >> 11) as f64 / (1u64 << 53) as f64;
+            (uniform * 12.0).exp()
+        })
+        .collect()
+}
+
+#[test]
+fn exact() {
+    let array = Int32Array::from_slice(&[5, 1, 4, 2, 3]);
+    let result = |q| quantile(&array, q, QuantileMethod::Exact).unwrap();
+    assert_eq!(result(0.0), Some(1.0));
+    assert_eq!(result(0.1), Some(1.4));
+    assert_eq!(result(0.5), Some(3.0));
+    assert_eq!(result(0.75), Some(4.0));
+    assert_eq!(result(1.0), Some(5.0));
+    assert_eq!(median(&array), Some(3.0));
+}
+
+#[test]
+fn nulls_and_nans() {
+    let array = Float64Array::from(&[Some(1.0), None, Some(f64::NAN), Some(2.0)]);
+    for method in [QuantileMethod::Exact, QuantileMethod::Approximate] {
+        assert_eq!(quantile(&array, 0.5, method).unwrap(), Some(1.5));
+    }
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from(&[None, None]);
+    for method in [QuantileMethod::Exact, QuantileMethod::Approximate] {
+        assert_eq!(quantile(&array, 0.5, method).unwrap(), None);
+        assert_eq!(quantile_chunked::<i32>(&[], 0.5, method).unwrap(), None);
+    }
+    assert_eq!(median(&array), None);
+}
+
+#[test]
+fn invalid_quantile() {
+    let array = Int32Array::from_slice(&[1]);
+    for q in [-0.1, 1.1, f64::NAN] {
+        assert!(quantile(&array, q, QuantileMethod::Exact).is_err());
+        assert!(quantile(&array, q, QuantileMethod::Approximate).is_err());
+    }
+}
+
+#[test]
+fn chunked_exact() {
+    let a = Int64Array::from(&[Some(3), None, Some(1)]);
+    let b = Int64Array::from_slice(&[4, 2]);
+    let result = quantile_chunked(&[&a, &b], 0.5, QuantileMethod::Exact).unwrap();
+    assert_eq!(result, Some(2.5));
+}
+
+#[test]
+fn approximate_small_is_exact() {
+    // fewer values than the t-digest's compression are not merged
+    let array = Int32Array::from_slice(&[5, 1, 4, 2, 3, 8]);
+    for q in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+        let exact = quantile(&array, q, QuantileMethod::Exact).unwrap().unwrap();
+        let approximate = quantile(&array, q, QuantileMethod::Approximate)
+            .unwrap()
+            .unwrap();
+        assert!(
+            (exact - approximate).abs() < 1e-9,
+            "{} != {}",
+            exact,
+            approximate
+        );
+    }
+}
+
+#[test]
+fn approximate_skewed() {
+    let values = skewed(100_000);
+    let arrays = values
+        .chunks(10_000)
+        .map(Float64Array::from_slice)
+        .collect::<Vec<_>>();
+    let arrays = arrays.iter().collect::<Vec<_>>();
+
+    let mut sorted = values.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    // the fraction of values smaller than `x`
+    let rank = |x: f64| sorted.partition_point(|v| *v < x) as f64 / sorted.len() as f64;
+
+    for q in [
+        0.0, 0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999, 1.0,
+    ] {
+        let exact = quantile_chunked(&arrays, q, QuantileMethod::Exact)
+            .unwrap()
+            .unwrap();
+        let approximate = quantile_chunked(&arrays, q, QuantileMethod::Approximate)
+            .unwrap()
+            .unwrap();
+        // within 0.5% of the rank, and 0.1% beyond the 1st and 99th percentiles
+        let bound = if !(0.01..=0.99).contains(&q) {
+            0.001
+        } else {
+            0.005
+        };
+        assert!(
+            (rank(approximate) - rank(exact)).abs() <= bound,
+            "q={}: exact {} (rank {}), approximate {} (rank {})",
+            q,
+            exact,
+            rank(exact),
+            approximate,
+            rank(approximate)
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn exact_is_oracle(values in prop::collection::vec(prop::option::of(-100i64..100), 0..100), q in 0.0..=1.0f64) {
+        let array = Int64Array::from(&values);
+        let result = quantile(&array, q, QuantileMethod::Exact).unwrap();
+        let expected = oracle(&values, q);
+        match (result, expected) {
+            (Some(result), Some(expected)) => prop_assert!((result - expected).abs() < 1e-9),
+            (result, expected) => prop_assert_eq!(result, expected),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Asserts that every aggregate of a sliced array equals the aggregate of a new array
+//! with the same values, across arbitrary offsets and null patterns.
+use proptest::prelude::*;
+
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::compute::boolean;
+
+/// Returns a strategy of arbitrary values, an offset and a length within them
+fn sliced_strategy<T: Arbitrary + Clone>() -> impl Strategy<Value = (Vec<Option<T>>, usize, usize)>
+{
+    prop::collection::vec(any::<Option<T>>(), 1..300)
+        .prop_flat_map(|vec| {
+            let len = vec.len();
+            (Just(vec), 0..len)
+        })
+        .prop_flat_map(|(vec, offset)| {
+            let len = vec.len();
+            (Just(vec), Just(offset), 0..=len - offset)
+        })
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn primitive((values, offset, length) in sliced_strategy::<i32>()) {
+        let sliced = Int32Array::from(&values).slice(offset, length);
+        let expected = Int32Array::from(&values[offset..offset + length]);
+
+        prop_assert_eq!(sum_primitive(&sliced), sum_primitive(&expected));
+        prop_assert_eq!(min_primitive(&sliced), min_primitive(&expected));
+        prop_assert_eq!(max_primitive(&sliced), max_primitive(&expected));
+        prop_assert_eq!(min_max_primitive(&sliced), min_max_primitive(&expected));
+        prop_assert_eq!(sum(&sliced).unwrap(), sum(&expected).unwrap());
+        prop_assert_eq!(min(&sliced).unwrap(), min(&expected).unwrap());
+        prop_assert_eq!(max(&sliced).unwrap(), max(&expected).unwrap());
+        prop_assert_eq!(mean(&sliced), mean(&expected));
+        prop_assert_eq!(
+            variance(&sliced, VarianceKind::Sample),
+            variance(&expected, VarianceKind::Sample)
+        );
+        prop_assert_eq!(index_of_min(&sliced).unwrap(), index_of_min(&expected).unwrap());
+        prop_assert_eq!(index_of_max(&sliced).unwrap(), index_of_max(&expected).unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn float((values, offset, length) in sliced_strategy::<i16>()) {
+        // floats from integers, so that the sums are exact regardless of the summation order
+        let values = values.into_iter().map(|x| x.map(|x| x as f64)).collect::<Vec<_>>();
+        let sliced = Float64Array::from(&values).slice(offset, length);
+        let expected = Float64Array::from(&values[offset..offset + length]);
+
+        prop_assert_eq!(sum_primitive(&sliced), sum_primitive(&expected));
+        prop_assert_eq!(sum_compensated(&sliced), sum_compensated(&expected));
+        prop_assert_eq!(min_primitive(&sliced), min_primitive(&expected));
+        prop_assert_eq!(max_primitive(&sliced), max_primitive(&expected));
+        prop_assert_eq!(min_max_primitive(&sliced), min_max_primitive(&expected));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn boolean((values, offset, length) in sliced_strategy::<bool>()) {
+        let sliced = BooleanArray::from(&values).slice(offset, length);
+        let expected = BooleanArray::from(&values[offset..offset + length]);
+
+        prop_assert_eq!(min_boolean(&sliced), min_boolean(&expected));
+        prop_assert_eq!(max_boolean(&sliced), max_boolean(&expected));
+        prop_assert_eq!(min_max_boolean(&sliced), min_max_boolean(&expected));
+        prop_assert_eq!(min(&sliced).unwrap(), min(&expected).unwrap());
+        prop_assert_eq!(max(&sliced).unwrap(), max(&expected).unwrap());
+        prop_assert_eq!(boolean::any(&sliced), boolean::any(&expected));
+        prop_assert_eq!(boolean::all(&sliced), boolean::all(&expected));
+        prop_assert_eq!(index_of_min(&sliced).unwrap(), index_of_min(&expected).unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn utf8((values, offset, length) in sliced_strategy::<u8>()) {
+        let values = values
+            .into_iter()
+            .map(|x| x.map(|x| x.to_string()))
+            .collect::<Vec<_>>();
+        let sliced = Utf8Array::<i32>::from(&values).slice(offset, length);
+        let expected = Utf8Array::<i32>::from(&values[offset..offset + length]);
+
+        prop_assert_eq!(min_string(&sliced), min_string(&expected));
+        prop_assert_eq!(max_string(&sliced), max_string(&expected));
+        prop_assert_eq!(min_max_string(&sliced), min_max_string(&expected));
+    }
+}
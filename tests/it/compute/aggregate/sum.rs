@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::aggregate::{sum, sum_primitive};
+use arrow2::compute::aggregate::{sum, sum_compensated, sum_primitive};
 use arrow2::compute::arithmetics;
 use arrow2::datatypes::DataType;
 use arrow2::scalar::{PrimitiveScalar, Scalar};
@@ -52,3 +52,43 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+#[test]
+fn test_sum_compensated() {
+    let a = Float64Array::from(&[Some(1e16), None, Some(1.0), Some(1.0), Some(-1e16)]);
+    assert_eq!(sum_compensated(&a), Some(2.0));
+
+    let a = Float32Array::from_slice(&[0.1f32; 1000]);
+    let expected = 100.0f32;
+    assert!((sum_compensated(&a).unwrap() - expected).abs() <= expected * f32::EPSILON);
+
+    let a = Float64Array::from(&[None, None]);
+    assert_eq!(sum_compensated(&a), None);
+}
+
+#[test]
+fn test_sum_compensated_independent_of_slicing() {
+    // large values that cancel out make the naive sum lose every small value
+    let values = std::iter::once(Some(1e16))
+        .chain((0..1000).map(|i| if i % 7 == 0 { None } else { Some(1.0) }))
+        .chain(std::iter::once(Some(-1e16)))
+        .collect::<Vec<_>>();
+    let a = Float64Array::from(values);
+    let whole = sum_compensated(&a).unwrap();
+    assert_eq!(whole, 857.0);
+
+    for offset in [1, 63, 64, 65, 500, 1001] {
+        let lhs = sum_compensated(&a.slice(0, offset)).unwrap();
+        let rhs = sum_compensated(&a.slice(offset, a.len() - offset)).unwrap();
+        // the only error left is the rounding of each partial sum to a float
+        assert!((lhs + rhs - whole).abs() <= (lhs.abs() + rhs.abs()) * f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_sum_wraps_regardless_of_offset() {
+    let a = Int8Array::from_slice(&[100i8; 130]);
+    let expected = (0..129).fold(0i8, |acc, _| acc.wrapping_add(100));
+    assert_eq!(sum_primitive(&a.slice(1, 129)), Some(expected));
+    assert_eq!(sum_primitive(&a.slice(0, 129)), Some(expected));
+}
@@ -0,0 +1,72 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{mean, stddev, variance, VarianceKind};
+
+fn assert_close(result: Option<f64>, expected: f64) {
+    let result = result.unwrap();
+    assert!(
+        (result - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+        "{} != {}",
+        result,
+        expected
+    );
+}
+
+#[test]
+fn basics() {
+    let a = Int32Array::from_slice(&[2, 4, 4, 4, 5, 5, 7, 9]);
+    assert_close(mean(&a), 5.0);
+    assert_close(variance(&a, VarianceKind::Population), 4.0);
+    assert_close(variance(&a, VarianceKind::Sample), 32.0 / 7.0);
+    assert_close(stddev(&a, VarianceKind::Population), 2.0);
+    assert_close(stddev(&a, VarianceKind::Sample), (32.0f64 / 7.0).sqrt());
+}
+
+#[test]
+fn with_nulls() {
+    // nulls interleaved across more than one 64-slot chunk of the validity
+    let a = (0..200)
+        .map(|x| if x % 3 == 0 { None } else { Some(x as f64) })
+        .collect::<Float64Array>();
+    let valid = (0..200).filter(|x| x % 3 != 0).map(|x| x as f64);
+    let n = valid.clone().count() as f64;
+    let expected_mean = valid.clone().sum::<f64>() / n;
+    let m2 = valid.map(|x| (x - expected_mean).powi(2)).sum::<f64>();
+
+    assert_close(mean(&a), expected_mean);
+    assert_close(variance(&a, VarianceKind::Population), m2 / n);
+    assert_close(variance(&a, VarianceKind::Sample), m2 / (n - 1.0));
+}
+
+#[test]
+fn sliced() {
+    let a = Int64Array::from(&[Some(100), Some(1), None, Some(3), Some(-100)]);
+    let a = a.slice(1, 3);
+    assert_close(mean(&a), 2.0);
+    assert_close(variance(&a, VarianceKind::Population), 1.0);
+    assert_close(variance(&a, VarianceKind::Sample), 2.0);
+}
+
+#[test]
+fn too_few_values() {
+    let a = Float32Array::from(&[None, None]);
+    assert_eq!(mean(&a), None);
+    assert_eq!(variance(&a, VarianceKind::Population), None);
+    assert_eq!(stddev(&a, VarianceKind::Sample), None);
+
+    let a = Float32Array::from(&[None, Some(1.5)]);
+    assert_close(mean(&a), 1.5);
+    assert_close(variance(&a, VarianceKind::Population), 0.0);
+    assert_eq!(variance(&a, VarianceKind::Sample), None);
+
+    let a = UInt8Array::from_slice(&[]);
+    assert_eq!(mean(&a), None);
+}
+
+#[test]
+fn numerically_stable() {
+    // a naive `E[x^2] - E[x]^2` loses all significant digits for such values
+    let a = Float64Array::from_slice(&[1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+    assert_close(mean(&a), 1e9 + 10.0);
+    assert_close(variance(&a, VarianceKind::Population), 22.5);
+    assert_close(variance(&a, VarianceKind::Sample), 30.0);
+}
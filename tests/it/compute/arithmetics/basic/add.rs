@@ -106,6 +106,29 @@ fn test_add_scalar() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn test_add_scalar_owned() {
+    let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
+    let expected = Int32Array::from(&[None, Some(7), None, Some(7)]);
+
+    // a uniquely-owned array is mutated in place
+    let ptr = a.values().as_slice().as_ptr();
+    let result = add_scalar_owned(a, &1i32);
+    assert_eq!(result, expected);
+    assert_eq!(result.values().as_slice().as_ptr(), ptr);
+
+    // a shared array is not
+    let shared = result.clone();
+    let result = add_scalar_owned(result, &1i32);
+    assert_eq!(result, Int32Array::from(&[None, Some(8), None, Some(8)]));
+    assert_eq!(shared, expected);
+
+    // a sliced array is not
+    let result = add_scalar_owned(shared.slice(1, 2), &1i32);
+    assert_eq!(result, Int32Array::from(&[Some(8), None]));
+    assert_ne!(result.values().as_slice().as_ptr(), ptr);
+}
+
 #[test]
 fn test_add_scalar_checked() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
@@ -1,33 +1,36 @@
 use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
 use arrow2::compute::arithmetics::basic::*;
-use arrow2::compute::arithmetics::{ArrayCheckedDiv, ArrayDiv};
+use arrow2::compute::arithmetics::{
+    ArrayCheckedDiv, ArrayDiv, ArrayOverflowingDiv, ArraySaturatingDiv,
+};
 
 #[test]
 #[should_panic]
 fn test_div_mismatched_length() {
     let a = Int32Array::from_slice(&[5, 6]);
     let b = Int32Array::from_slice(&[5]);
-    div(&a, &b);
+    div(&a, &b).unwrap();
 }
 
 #[test]
 fn test_div() {
     let a = Int32Array::from(&[Some(5), Some(6)]);
     let b = Int32Array::from(&[Some(5), Some(6)]);
-    let result = div(&a, &b);
+    let result = div(&a, &b).unwrap();
     let expected = Int32Array::from(&[Some(1), Some(1)]);
     assert_eq!(result, expected);
 
     // Trait testing
-    let result = a.div(&b);
+    let result = a.div(&b).unwrap();
     assert_eq!(result, expected);
 }
 
 #[test]
 #[should_panic]
-fn test_div_panic() {
-    let a = Int8Array::from(&[Some(10i8)]);
-    let b = Int8Array::from(&[Some(0i8)]);
+fn test_div_overflow_panic() {
+    let a = Int8Array::from(&[Some(i8::MIN)]);
+    let b = Int8Array::from(&[Some(-1i8)]);
     let _ = div(&a, &b);
 }
 
@@ -53,32 +56,32 @@ fn test_div_checked() {
 #[test]
 fn test_div_scalar() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
-    let result = div_scalar(&a, &1i32);
+    let result = div_scalar(&a, &1i32).unwrap();
     let expected = Int32Array::from(&[None, Some(6), None, Some(6)]);
     assert_eq!(result, expected);
 
     // Trait testing
-    let result = a.div(&1i32);
+    let result = a.div(&1i32).unwrap();
     assert_eq!(result, expected);
 
     // check the strength reduced branches
     let a = UInt64Array::from(&[None, Some(6), None, Some(6)]);
-    let result = div_scalar(&a, &1u64);
+    let result = div_scalar(&a, &1u64).unwrap();
     let expected = UInt64Array::from(&[None, Some(6), None, Some(6)]);
     assert_eq!(result, expected);
 
     let a = UInt32Array::from(&[None, Some(6), None, Some(6)]);
-    let result = div_scalar(&a, &1u32);
+    let result = div_scalar(&a, &1u32).unwrap();
     let expected = UInt32Array::from(&[None, Some(6), None, Some(6)]);
     assert_eq!(result, expected);
 
     let a = UInt16Array::from(&[None, Some(6), None, Some(6)]);
-    let result = div_scalar(&a, &1u16);
+    let result = div_scalar(&a, &1u16).unwrap();
     let expected = UInt16Array::from(&[None, Some(6), None, Some(6)]);
     assert_eq!(result, expected);
 
     let a = UInt8Array::from(&[None, Some(6), None, Some(6)]);
-    let result = div_scalar(&a, &1u8);
+    let result = div_scalar(&a, &1u8).unwrap();
     let expected = UInt8Array::from(&[None, Some(6), None, Some(6)]);
     assert_eq!(result, expected);
 }
@@ -99,3 +102,191 @@ fn test_div_scalar_checked() {
     let result = a.checked_div(&0);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_div_saturating() {
+    let a = Int8Array::from(&[Some(i8::MIN), None, Some(3), Some(i8::MIN)]);
+    let b = Int8Array::from(&[Some(-1), Some(3), None, Some(1)]);
+    let result = saturating_div(&a, &b);
+    let expected = Int8Array::from(&[Some(i8::MAX), None, None, Some(i8::MIN)]);
+    assert_eq!(result, expected);
+
+    // Trait testing
+    let result = a.saturating_div(&b);
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[should_panic]
+fn test_div_saturating_zero() {
+    let a = Int8Array::from(&[Some(1)]);
+    let b = Int8Array::from(&[Some(0)]);
+    saturating_div(&a, &b);
+}
+
+#[test]
+fn test_div_overflowing() {
+    let a = Int8Array::from(&[Some(i8::MIN), None, Some(6), Some(i8::MIN)]);
+    let b = Int8Array::from(&[Some(-1), Some(3), Some(3), Some(1)]);
+    let (result, overflow) = overflowing_div(&a, &b);
+    let expected = Int8Array::from(&[Some(i8::MIN), None, Some(2), Some(i8::MIN)]);
+    assert_eq!(result, expected);
+    assert_eq!(overflow, Bitmap::from([true, false, false, false]));
+
+    // Trait testing
+    let (result, overflow) = a.overflowing_div(&b);
+    assert_eq!(result, expected);
+    assert_eq!(overflow, Bitmap::from([true, false, false, false]));
+}
+
+#[test]
+fn test_div_scalar_saturating_overflowing() {
+    let a = Int64Array::from(&[Some(i64::MIN), None, Some(6)]);
+    let result = saturating_div_scalar(&a, &-1);
+    let expected = Int64Array::from(&[Some(i64::MAX), None, Some(-6)]);
+    assert_eq!(result, expected);
+    assert_eq!(a.saturating_div(&-1), expected);
+
+    let (result, overflow) = overflowing_div_scalar(&a, &-1);
+    let expected = Int64Array::from(&[Some(i64::MIN), None, Some(-6)]);
+    assert_eq!(result, expected);
+    assert_eq!(overflow, Bitmap::from([true, false, false]));
+}
+
+macro_rules! test_overflow_variants {
+    ($name:ident, $type:ty) => {
+        #[test]
+        fn $name() {
+            let max = PrimitiveArray::<$type>::from([Some(<$type>::MAX), None, Some(2)]);
+            let min = PrimitiveArray::<$type>::from([Some(<$type>::MIN), None, Some(1)]);
+            let one = PrimitiveArray::<$type>::from([Some(1), Some(1), Some(1)]);
+
+            let expected = PrimitiveArray::<$type>::from([None, None, Some(3)]);
+            assert_eq!(checked_add(&max, &one), expected);
+            let expected = PrimitiveArray::<$type>::from([None, None, Some(0)]);
+            assert_eq!(checked_sub(&min, &one), expected);
+            let expected = PrimitiveArray::<$type>::from([None, None, Some(4)]);
+            assert_eq!(checked_mul(&max, &max), expected);
+            let zero = PrimitiveArray::<$type>::from([Some(0), Some(1), Some(1)]);
+            let expected = PrimitiveArray::<$type>::from([None, None, Some(2)]);
+            assert_eq!(checked_div(&max, &zero), expected);
+
+            let expected = PrimitiveArray::<$type>::from([Some(<$type>::MAX), None, Some(3)]);
+            assert_eq!(saturating_add(&max, &one), expected);
+            let expected = PrimitiveArray::<$type>::from([Some(<$type>::MIN), None, Some(0)]);
+            assert_eq!(saturating_sub(&min, &one), expected);
+            let expected = PrimitiveArray::<$type>::from([Some(<$type>::MAX), None, Some(4)]);
+            assert_eq!(saturating_mul(&max, &max), expected);
+
+            // the overflow of null slots is undefined and thus not checked
+            let (result, overflow) = overflowing_add(&max, &one);
+            let expected = PrimitiveArray::<$type>::from([Some(<$type>::MIN), None, Some(3)]);
+            assert_eq!(result, expected);
+            assert!(overflow.get_bit(0) && !overflow.get_bit(2));
+            let (result, overflow) = overflowing_sub(&min, &one);
+            let expected = PrimitiveArray::<$type>::from([Some(<$type>::MAX), None, Some(0)]);
+            assert_eq!(result, expected);
+            assert!(overflow.get_bit(0) && !overflow.get_bit(2));
+            let (_, overflow) = overflowing_mul(&max, &max);
+            assert!(overflow.get_bit(0) && !overflow.get_bit(2));
+        }
+    };
+}
+
+test_overflow_variants!(test_overflow_variants_i8, i8);
+test_overflow_variants!(test_overflow_variants_i16, i16);
+test_overflow_variants!(test_overflow_variants_i32, i32);
+test_overflow_variants!(test_overflow_variants_i64, i64);
+test_overflow_variants!(test_overflow_variants_u8, u8);
+test_overflow_variants!(test_overflow_variants_u16, u16);
+test_overflow_variants!(test_overflow_variants_u32, u32);
+test_overflow_variants!(test_overflow_variants_u64, u64);
+
+#[test]
+fn test_div_nulls() {
+    let a = Int32Array::from(&[Some(10), None, Some(6), Some(7)]);
+    let b = Int32Array::from(&[Some(5), Some(3), None, Some(7)]);
+    let result = div(&a, &b).unwrap();
+    let expected = Int32Array::from(&[Some(2), None, None, Some(1)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_div_zero_first() {
+    let a = Int32Array::from(&[Some(10), Some(1), Some(6)]);
+    let b = Int32Array::from(&[Some(0), Some(1), Some(6)]);
+    let error = div(&a, &b).unwrap_err().to_string();
+    assert!(error.contains("index 0"), "{}", error);
+
+    let expected = Int32Array::from(&[None, Some(1), Some(1)]);
+    assert_eq!(checked_div(&a, &b), expected);
+}
+
+#[test]
+fn test_div_zero_last() {
+    let a = UInt64Array::from(&[Some(10), Some(1), Some(6)]);
+    let b = UInt64Array::from(&[Some(5), Some(1), Some(0)]);
+    let error = div(&a, &b).unwrap_err().to_string();
+    assert!(error.contains("index 2"), "{}", error);
+
+    let expected = UInt64Array::from(&[Some(2), Some(1), None]);
+    assert_eq!(checked_div(&a, &b), expected);
+}
+
+#[test]
+fn test_div_zero_under_null() {
+    let a = Int16Array::from(&[Some(10), Some(1), Some(6)]);
+    // the value of the null slot is zero
+    let b = Int16Array::from_data(
+        arrow2::datatypes::DataType::Int16,
+        vec![5, 0, 3].into(),
+        Some(Bitmap::from([true, false, true])),
+    );
+    let expected = Int16Array::from(&[Some(2), None, Some(2)]);
+
+    assert_eq!(div(&a, &b).unwrap(), expected);
+    assert_eq!(checked_div(&a, &b), expected);
+}
+
+#[test]
+fn test_div_overflow_under_null() {
+    // the null slot contains `MIN / -1`, which overflows
+    let a = Int32Array::from_data(
+        arrow2::datatypes::DataType::Int32,
+        vec![i32::MIN, 6].into(),
+        Some(Bitmap::from([false, true])),
+    );
+    let b = Int32Array::from_slice([-1, -3]);
+    assert_eq!(div(&a, &b).unwrap(), Int32Array::from(&[None, Some(-2)]));
+}
+
+#[test]
+fn test_div_sliced() {
+    let a = Int32Array::from(&[Some(1), Some(10), Some(6)]).slice(1, 2);
+    let b = Int32Array::from(&[Some(0), Some(5), Some(3)]).slice(1, 2);
+    assert_eq!(div(&a, &b).unwrap(), Int32Array::from(&[Some(2), Some(2)]));
+}
+
+#[test]
+fn test_div_float() {
+    let a = Float64Array::from(&[Some(1.0), Some(-1.0)]);
+    let b = Float64Array::from(&[Some(0.0), Some(0.0)]);
+    assert_eq!(
+        div(&a, &b).unwrap(),
+        Float64Array::from(&[Some(f64::INFINITY), Some(f64::NEG_INFINITY)])
+    );
+}
+
+#[test]
+fn test_div_scalar_zero() {
+    let a = Int32Array::from(&[None, Some(6), Some(0)]);
+    assert_eq!(
+        div_scalar(&a, &3).unwrap(),
+        Int32Array::from(&[None, Some(2), Some(0)])
+    );
+    assert!(div_scalar(&a, &0).is_err());
+
+    // the zero is rejected even when all values are null
+    let a = UInt8Array::from(&[None, None]);
+    assert!(div_scalar(&a, &0).is_err());
+}
@@ -0,0 +1,131 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn test_unary() {
+    let a = Float64Array::from(&[Some(1.0), None, Some(4.0)]);
+
+    assert_eq!(sqrt(&a), Float64Array::from(&[Some(1.0), None, Some(2.0)]));
+    assert_eq!(
+        ln(&a),
+        Float64Array::from(&[Some(0.0), None, Some(4f64.ln())])
+    );
+    assert_eq!(log2(&a), Float64Array::from(&[Some(0.0), None, Some(2.0)]));
+    assert_eq!(
+        log10(&a),
+        Float64Array::from(&[Some(0.0), None, Some(4f64.log10())])
+    );
+    assert_eq!(
+        exp(&a),
+        Float64Array::from(&[Some(1f64.exp()), None, Some(4f64.exp())])
+    );
+}
+
+#[test]
+fn test_trigonometry() {
+    let a = Float32Array::from(&[Some(0.0), None, Some(std::f32::consts::FRAC_PI_2)]);
+
+    assert_eq!(sin(&a), Float32Array::from(&[Some(0.0), None, Some(1.0)]));
+    let result = cos(&a);
+    assert_eq!(result.value(0), 1.0);
+    assert!(result.is_null(1));
+    assert!(result.value(2).abs() < 1e-6);
+    assert_eq!(tan(&a).value(0), 0.0);
+}
+
+#[test]
+fn test_domain_errors_are_nan() {
+    let a = Float64Array::from(&[Some(-1.0), None]);
+
+    for result in [sqrt(&a), ln(&a), log2(&a), log10(&a)] {
+        assert!(result.value(0).is_nan());
+        assert!(result.is_null(1));
+        assert_eq!(result.null_count(), 1);
+    }
+
+    let a = Float64Array::from_slice([0.0]);
+    assert_eq!(ln(&a).value(0), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_rounding() {
+    let a = Float64Array::from(&[Some(1.5), Some(-1.5), None, Some(-0.4), Some(2.7)]);
+
+    assert_eq!(
+        round(&a),
+        Float64Array::from(&[Some(2.0), Some(-2.0), None, Some(-0.0), Some(3.0)])
+    );
+    assert_eq!(
+        floor(&a),
+        Float64Array::from(&[Some(1.0), Some(-2.0), None, Some(-1.0), Some(2.0)])
+    );
+    assert_eq!(
+        ceil(&a),
+        Float64Array::from(&[Some(2.0), Some(-1.0), None, Some(-0.0), Some(3.0)])
+    );
+    assert_eq!(
+        trunc(&a),
+        Float64Array::from(&[Some(1.0), Some(-1.0), None, Some(-0.0), Some(2.0)])
+    );
+}
+
+#[test]
+fn test_round_digits() {
+    let a = Float64Array::from(&[Some(1.23456), None, Some(-2.5), Some(1234.5)]);
+
+    assert_eq!(round_digits(&a, 0), round(&a));
+    assert_eq!(
+        round_digits(&a, 2),
+        Float64Array::from(&[Some(1.23), None, Some(-2.5), Some(1234.5)])
+    );
+    assert_eq!(
+        round_digits(&a, -2),
+        Float64Array::from(&[Some(0.0), None, Some(-0.0), Some(1200.0)])
+    );
+}
+
+#[test]
+fn test_unary_sliced() {
+    let a = Float32Array::from(&[Some(-1.0), Some(9.0), None, Some(16.0)]).slice(1, 3);
+    assert_eq!(sqrt(&a), Float32Array::from(&[Some(3.0), None, Some(4.0)]));
+}
+
+#[test]
+fn test_abs() {
+    let a = Int32Array::from(&[Some(-1), None, Some(2), Some(i32::MIN + 1)]);
+    assert_eq!(
+        abs(&a),
+        Int32Array::from(&[Some(1), None, Some(2), Some(i32::MAX)])
+    );
+
+    let a = Float64Array::from(&[Some(-1.5), None, Some(-0.0)]);
+    assert_eq!(abs(&a), Float64Array::from(&[Some(1.5), None, Some(0.0)]));
+}
+
+#[test]
+#[should_panic(expected = "overflew")]
+fn test_abs_overflow() {
+    let a = Int8Array::from(&[Some(i8::MIN)]);
+    let _ = abs(&a);
+}
+
+#[test]
+fn test_abs_null_min() {
+    // the value of a null slot is not considered
+    let a = Int8Array::from_data(
+        DataType::Int8,
+        vec![i8::MIN, -3].into(),
+        Some([false, true].into()),
+    );
+    assert_eq!(abs(&a), Int8Array::from(&[None, Some(3)]));
+}
+
+#[test]
+fn test_checked_abs() {
+    let a = Int64Array::from(&[Some(-1), None, Some(i64::MIN), Some(3)]);
+    assert_eq!(
+        checked_abs(&a),
+        Int64Array::from(&[Some(1), None, None, Some(3)])
+    );
+}
@@ -1,5 +1,6 @@
 mod add;
 mod div;
+mod float;
 mod mul;
 mod pow;
 mod rem;
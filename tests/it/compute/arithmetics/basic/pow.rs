@@ -16,3 +16,15 @@ fn test_raise_power_scalar_checked() {
     let expected = Int8Array::from(&[Some(1i8), None, None]);
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_raise_power_scalar_integer_exponent() {
+    let a = Float64Array::from(&[Some(1.0), Some(2f64), None, Some(-3.0)]).slice(1, 3);
+    let actual = powi_scalar(&a, 3);
+    let expected = Float64Array::from(&[Some(8.0), None, Some(-27.0)]);
+    assert_eq!(expected, actual);
+
+    let actual = powi_scalar(&a, -1);
+    let expected = Float64Array::from(&[Some(0.5), None, Some(-1.0 / 3.0)]);
+    assert_eq!(expected, actual);
+}
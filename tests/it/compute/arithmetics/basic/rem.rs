@@ -1,36 +1,30 @@
 use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
 use arrow2::compute::arithmetics::basic::*;
 use arrow2::compute::arithmetics::{ArrayCheckedRem, ArrayRem};
+use arrow2::datatypes::DataType;
+use arrow2::error::ArrowError;
 
 #[test]
-#[should_panic]
 fn test_rem_mismatched_length() {
     let a = Int32Array::from_slice(&[5, 6]);
     let b = Int32Array::from_slice(&[5]);
-    rem(&a, &b);
+    assert!(rem(&a, &b).is_err());
 }
 
 #[test]
 fn test_rem() {
     let a = Int32Array::from(&[Some(5), Some(6)]);
     let b = Int32Array::from(&[Some(4), Some(4)]);
-    let result = rem(&a, &b);
+    let result = rem(&a, &b).unwrap();
     let expected = Int32Array::from(&[Some(1), Some(2)]);
     assert_eq!(result, expected);
 
     // Trait testing
-    let result = a.rem(&b);
+    let result = a.rem(&b).unwrap();
     assert_eq!(result, expected);
 }
 
-#[test]
-#[should_panic]
-fn test_rem_panic() {
-    let a = Int8Array::from(&[Some(10i8)]);
-    let b = Int8Array::from(&[Some(0i8)]);
-    let _ = rem(&a, &b);
-}
-
 #[test]
 fn test_rem_checked() {
     let a = Int32Array::from(&[Some(5), None, Some(3), Some(6)]);
@@ -53,32 +47,32 @@ fn test_rem_checked() {
 #[test]
 fn test_rem_scalar() {
     let a = Int32Array::from(&[None, Some(6), None, Some(5)]);
-    let result = rem_scalar(&a, &2i32);
+    let result = rem_scalar(&a, &2i32).unwrap();
     let expected = Int32Array::from(&[None, Some(0), None, Some(1)]);
     assert_eq!(result, expected);
 
     // Trait testing
-    let result = a.rem(&2i32);
+    let result = a.rem(&2i32).unwrap();
     assert_eq!(result, expected);
 
     // check the strength reduced branches
     let a = UInt64Array::from(&[None, Some(6), None, Some(5)]);
-    let result = rem_scalar(&a, &2u64);
+    let result = rem_scalar(&a, &2u64).unwrap();
     let expected = UInt64Array::from(&[None, Some(0), None, Some(1)]);
     assert_eq!(result, expected);
 
     let a = UInt32Array::from(&[None, Some(6), None, Some(5)]);
-    let result = rem_scalar(&a, &2u32);
+    let result = rem_scalar(&a, &2u32).unwrap();
     let expected = UInt32Array::from(&[None, Some(0), None, Some(1)]);
     assert_eq!(result, expected);
 
     let a = UInt16Array::from(&[None, Some(6), None, Some(5)]);
-    let result = rem_scalar(&a, &2u16);
+    let result = rem_scalar(&a, &2u16).unwrap();
     let expected = UInt16Array::from(&[None, Some(0), None, Some(1)]);
     assert_eq!(result, expected);
 
     let a = UInt8Array::from(&[None, Some(6), None, Some(5)]);
-    let result = rem_scalar(&a, &2u8);
+    let result = rem_scalar(&a, &2u8).unwrap();
     let expected = UInt8Array::from(&[None, Some(0), None, Some(1)]);
     assert_eq!(result, expected);
 }
@@ -99,3 +93,77 @@ fn test_rem_scalar_checked() {
     let result = a.checked_rem(&0);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_rem_zero_divisor() {
+    let a = Int32Array::from(&[Some(5), None, Some(3), Some(6)]);
+    // the null divisor slot is zero and must not be treated as a zero divisor
+    let b = Int32Array::from_data(
+        arrow2::datatypes::DataType::Int32,
+        vec![4, 3, 0, 4].into(),
+        Some([true, true, false, true].into()),
+    );
+    let result = rem(&a, &b).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, None, Some(2)]);
+    assert_eq!(result, expected);
+
+    let b = Int32Array::from(&[Some(5), Some(3), Some(3), Some(0)]);
+    let error = rem(&a, &b).unwrap_err().to_string();
+    assert!(error.contains("index 3"), "{}", error);
+}
+
+#[test]
+fn test_rem_sliced() {
+    let a = Int64Array::from(&[Some(1), Some(5), None, Some(7)]).slice(1, 3);
+    let b = Int64Array::from(&[Some(0), Some(3), Some(3), Some(4)]).slice(1, 3);
+    let result = rem(&a, &b).unwrap();
+    let expected = Int64Array::from(&[Some(2), None, Some(3)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_rem_float() {
+    let a = Float64Array::from(&[Some(5.5), None]);
+    let b = Float64Array::from(&[Some(0.0), Some(2.0)]);
+    let result = rem(&a, &b).unwrap();
+    assert!(result.value(0).is_nan());
+    assert!(result.is_null(1));
+}
+
+#[test]
+fn test_rem_scalar_zero() {
+    let a = Int32Array::from(&[None, Some(6), None, Some(7)]).slice(1, 3);
+    let result = rem_scalar(&a, &4i32).unwrap();
+    let expected = Int32Array::from(&[Some(2), None, Some(3)]);
+    assert_eq!(result, expected);
+
+    assert!(rem_scalar(&a, &0i32).is_err());
+
+    let a = Float32Array::from(&[Some(1.0)]);
+    assert!(rem_scalar(&a, &0.0f32).unwrap().value(0).is_nan());
+}
+
+#[test]
+fn test_rem_overflow() {
+    let a = Int8Array::from(&[Some(i8::MIN), Some(7)]);
+    let b = Int8Array::from(&[Some(-1), Some(-1)]);
+    assert!(matches!(rem(&a, &b), Err(ArrowError::Overflow)));
+    assert!(matches!(rem_scalar(&a, &-1), Err(ArrowError::Overflow)));
+
+    // `MIN` under a null does not overflow
+    let a = Int8Array::from_data(
+        DataType::Int8,
+        vec![i8::MIN, 7].into(),
+        Some(Bitmap::from([false, true])),
+    );
+    let expected = Int8Array::from(&[None, Some(0)]);
+    assert_eq!(rem(&a, &b).unwrap(), expected);
+    assert_eq!(rem_scalar(&a, &-1).unwrap(), expected);
+
+    // unsigned integers are never negative
+    let a = UInt8Array::from(&[Some(u8::MAX)]);
+    assert_eq!(
+        rem_scalar(&a, &u8::MAX).unwrap(),
+        UInt8Array::from(&[Some(0)])
+    );
+}
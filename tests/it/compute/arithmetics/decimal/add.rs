@@ -1,9 +1,13 @@
 #![allow(clippy::zero_prefixed_literal, clippy::inconsistent_digit_grouping)]
 
 use arrow2::array::*;
-use arrow2::compute::arithmetics::decimal::{adaptive_add, add, checked_add, saturating_add};
+use arrow2::compute::arithmetics::decimal::{
+    adaptive_add, add, add_scalar, checked_add, checked_add_scalar, saturating_add,
+};
 use arrow2::compute::arithmetics::{ArrayAdd, ArrayCheckedAdd, ArraySaturatingAdd};
 use arrow2::datatypes::DataType;
+use arrow2::error::ArrowError;
+use arrow2::scalar::PrimitiveScalar;
 
 #[test]
 fn test_add_normal() {
@@ -179,3 +183,94 @@ fn test_add_adaptive() {
     assert_eq!(result, expected);
     assert_eq!(result.data_type(), &DataType::Decimal(7, 4));
 }
+
+#[test]
+fn test_add_adaptive_precision_overflow() {
+    let a = PrimitiveArray::from([Some(1i128)]).to(DataType::Decimal(38, 10));
+    let b = PrimitiveArray::from([Some(1i128)]).to(DataType::Decimal(38, 0));
+    assert!(adaptive_add(&a, &b).is_err());
+}
+
+#[test]
+fn test_add_scalar() {
+    let a =
+        PrimitiveArray::from([Some(11111i128), Some(-11100i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveScalar::from(Some(100i128)).to(DataType::Decimal(5, 2));
+
+    let result = add_scalar(&a, &b).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(11211i128), Some(-11000i128), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+
+    let b = PrimitiveScalar::<i128>::from(None).to(DataType::Decimal(5, 2));
+    let result = add_scalar(&a, &b).unwrap();
+    let expected = PrimitiveArray::from([None, None, None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_scalar_checked_overflow() {
+    let a = PrimitiveArray::from([Some(1i128), Some(99999i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveScalar::from(Some(1i128)).to(DataType::Decimal(5, 2));
+
+    let result = checked_add_scalar(&a, &b);
+    let expected = PrimitiveArray::from([Some(2i128), None, None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_scalar_checked_overflow_i128() {
+    // the sum of two values of precision 38 may not fit in an i128
+    let max = 10i128.pow(38) - 1;
+    let a = PrimitiveArray::from([Some(max), Some(-max)]).to(DataType::Decimal(38, 0));
+    let b = PrimitiveScalar::from(Some(max)).to(DataType::Decimal(38, 0));
+
+    let result = checked_add_scalar(&a, &b);
+    let expected = PrimitiveArray::from([None, Some(0i128)]).to(DataType::Decimal(38, 0));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_scalar_overflow_i128() {
+    let max = 10i128.pow(38) - 1;
+    let a = PrimitiveArray::from([Some(max)]).to(DataType::Decimal(38, 0));
+    let b = PrimitiveScalar::from(Some(max)).to(DataType::Decimal(38, 0));
+
+    let error = add_scalar(&a, &b).unwrap_err().to_string();
+    assert!(error.contains("Overflow in addition presented for precision 38"));
+}
+
+#[test]
+fn test_add_scalar_overflow_under_null() {
+    // the value of the null slot exceeds the precision once added
+    let a = PrimitiveArray::from_data(
+        DataType::Decimal(5, 2),
+        vec![99999i128, 1].into(),
+        Some([false, true].into()),
+    );
+    let b = PrimitiveScalar::from(Some(1i128)).to(DataType::Decimal(5, 2));
+
+    let result = add_scalar(&a, &b).unwrap();
+    let expected = PrimitiveArray::from([None, Some(2i128)]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_scalar_different_precision() {
+    let a = PrimitiveArray::from([Some(1i128)]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveScalar::from(Some(1i128)).to(DataType::Decimal(6, 2));
+    assert!(matches!(
+        add_scalar(&a, &b),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+}
+
+#[test]
+fn test_add_dyn() {
+    let a = PrimitiveArray::from([Some(11111i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveArray::from([Some(-1i128), Some(1i128)]).to(DataType::Decimal(5, 2));
+
+    let result = arrow2::compute::arithmetics::add(&a, &b);
+    let expected = PrimitiveArray::from([Some(11110i128), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
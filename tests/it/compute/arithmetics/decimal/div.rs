@@ -45,7 +45,7 @@ fn test_divide_normal() {
     assert_eq!(result, expected);
 
     // Testing trait
-    let result = a.div(&b);
+    let result = a.div(&b).unwrap();
     assert_eq!(result, expected);
 }
 
@@ -1,9 +1,12 @@
 #![allow(clippy::zero_prefixed_literal, clippy::inconsistent_digit_grouping)]
 
 use arrow2::array::*;
-use arrow2::compute::arithmetics::decimal::{adaptive_sub, checked_sub, saturating_sub, sub};
+use arrow2::compute::arithmetics::decimal::{
+    adaptive_sub, checked_sub, checked_sub_scalar, saturating_sub, sub, sub_scalar,
+};
 use arrow2::compute::arithmetics::{ArrayCheckedSub, ArraySaturatingSub, ArraySub};
 use arrow2::datatypes::DataType;
+use arrow2::scalar::PrimitiveScalar;
 
 #[test]
 fn test_subtract_normal() {
@@ -175,3 +178,59 @@ fn test_subtract_adaptive() {
     assert_eq!(result, expected);
     assert_eq!(result.data_type(), &DataType::Decimal(7, 4));
 }
+
+#[test]
+fn test_subtract_scalar() {
+    let a =
+        PrimitiveArray::from([Some(11111i128), Some(-11100i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveScalar::from(Some(100i128)).to(DataType::Decimal(5, 2));
+
+    let result = sub_scalar(&a, &b).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(11011i128), Some(-11200i128), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_subtract_scalar_checked_overflow_i128() {
+    // the difference of two values of precision 38 may not fit in an i128
+    let max = 10i128.pow(38) - 1;
+    let a = PrimitiveArray::from([Some(-max), Some(max)]).to(DataType::Decimal(38, 0));
+    let b = PrimitiveScalar::from(Some(max)).to(DataType::Decimal(38, 0));
+
+    let result = checked_sub_scalar(&a, &b);
+    let expected = PrimitiveArray::from([None, Some(0i128)]).to(DataType::Decimal(38, 0));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_subtract_scalar_overflow_i128() {
+    let max = 10i128.pow(38) - 1;
+    let a = PrimitiveArray::from([Some(-max)]).to(DataType::Decimal(38, 0));
+    let b = PrimitiveScalar::from(Some(max)).to(DataType::Decimal(38, 0));
+
+    let error = sub_scalar(&a, &b).unwrap_err().to_string();
+    assert!(error.contains("Overflow in subtraction presented for precision 38"));
+
+    // the null slot is not checked
+    let a = PrimitiveArray::from_data(
+        DataType::Decimal(38, 0),
+        vec![-max, 1].into(),
+        Some([false, true].into()),
+    );
+    let expected = PrimitiveArray::from([None, Some(1 - max)]).to(DataType::Decimal(38, 0));
+    assert_eq!(sub_scalar(&a, &b).unwrap(), expected);
+
+    let b = PrimitiveScalar::from(Some(max)).to(DataType::Decimal(38, 1));
+    assert!(sub_scalar(&a, &b).is_err());
+}
+
+#[test]
+fn test_subtract_scalar_checked_overflow() {
+    let a = PrimitiveArray::from([Some(1i128), Some(-99999i128), None]).to(DataType::Decimal(5, 2));
+    let b = PrimitiveScalar::from(Some(1i128)).to(DataType::Decimal(5, 2));
+
+    let result = checked_sub_scalar(&a, &b);
+    let expected = PrimitiveArray::from([Some(0i128), None, None]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
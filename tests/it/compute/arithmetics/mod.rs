@@ -7,6 +7,7 @@ use arrow2::compute::arithmetics::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::{IntervalUnit, TimeUnit};
 use arrow2::scalar::PrimitiveScalar;
+use arrow2::types::i256;
 
 #[test]
 fn test_add() {
@@ -26,6 +27,32 @@ fn test_add_scalar() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_div_zero() {
+    let a = Int32Array::from(&[Some(6), Some(6)]);
+    let b = Int32Array::from(&[Some(3), Some(0)]);
+    assert!(div(&a, &b).is_err());
+
+    let b: PrimitiveScalar<i32> = Some(0i32).into();
+    assert!(div_scalar(&a, &b).is_err());
+
+    let b: PrimitiveScalar<i32> = Some(3i32).into();
+    let expected = Int32Array::from(&[Some(2), Some(2)]);
+    assert_eq!(expected, div_scalar(&a, &b).unwrap().as_ref());
+}
+
+#[test]
+fn test_rem_zero() {
+    let a = Int32Array::from(&[Some(7), Some(7)]);
+    let b = Int32Array::from(&[Some(3), Some(0)]);
+    let error = rem(&a, &b).unwrap_err().to_string();
+    assert!(error.contains("index 1"), "{}", error);
+
+    let b = Int32Array::from(&[Some(3), None]);
+    let expected = Int32Array::from(&[Some(1), None]);
+    assert_eq!(expected, rem(&a, &b).unwrap().as_ref());
+}
+
 #[test]
 fn consistency() {
     let datatypes = vec![
@@ -77,10 +104,10 @@ fn consistency() {
             mul(lhs_a.as_ref(), rhs_a.as_ref());
         }
         if can_div(&lhs, &rhs) {
-            div(lhs_a.as_ref(), rhs_a.as_ref());
+            div(lhs_a.as_ref(), rhs_a.as_ref()).unwrap();
         }
         if can_rem(&lhs, &rhs) {
-            rem(lhs_a.as_ref(), rhs_a.as_ref());
+            rem(lhs_a.as_ref(), rhs_a.as_ref()).unwrap();
         }
     });
 }
@@ -93,6 +120,43 @@ fn test_neg() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_neg_decimal() {
+    let a = Int128Array::from(&[None, Some(1_00), Some(-2_50)]).to(Decimal(5, 2));
+    assert!(can_neg(a.data_type()));
+    let result = neg(&a);
+    let expected = Int128Array::from(&[None, Some(-1_00), Some(2_50)]).to(Decimal(5, 2));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+#[should_panic(expected = "use `can_neg` to check")]
+fn test_neg_unsupported() {
+    let a = UInt32Array::from_slice([1, 2]);
+    assert!(!can_neg(a.data_type()));
+    neg(&a);
+}
+
+#[test]
+#[should_panic(expected = "use `can_neg` to check")]
+fn test_neg_unsupported_decimal256() {
+    let a = PrimitiveArray::<i256>::from_slice([i256::from(1)]).to(Decimal256(40, 2));
+    assert!(!can_neg(a.data_type()));
+    neg(&a);
+}
+
+#[test]
+fn test_negate_sliced() {
+    let a = Int64Array::from(&[Some(1), None, Some(i64::MIN), Some(7)]).slice(1, 3);
+    let result = arrow2::compute::arithmetics::basic::checked_negate(&a);
+    let expected = Int64Array::from(&[None, None, Some(-7)]);
+    assert_eq!(expected, result);
+
+    let result = arrow2::compute::arithmetics::basic::wrapping_negate(&a);
+    let expected = Int64Array::from(&[None, Some(i64::MIN), Some(-7)]);
+    assert_eq!(expected, result);
+}
+
 #[test]
 fn test_neg_dict() {
     let a = DictionaryArray::<u8>::from_data(
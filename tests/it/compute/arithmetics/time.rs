@@ -1,4 +1,6 @@
 use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::buffer::Buffer;
 use arrow2::compute::arithmetics::time::*;
 use arrow2::datatypes::{DataType, TimeUnit};
 use arrow2::scalar::*;
@@ -317,3 +319,383 @@ fn test_date64() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_subtract_timestamps_timezone() {
+    let tz = Some("+01:00".to_string());
+    let timestamp_a = PrimitiveArray::from([Some(100_010i64), None, Some(300_030i64)])
+        .to(DataType::Timestamp(TimeUnit::Second, tz.clone()));
+    let timestamp_b = PrimitiveArray::from([Some(100_000i64), Some(200_000i64), Some(300_000i64)])
+        .to(DataType::Timestamp(TimeUnit::Second, tz));
+
+    let expected = PrimitiveArray::from([Some(10i64), None, Some(30i64)])
+        .to(DataType::Duration(TimeUnit::Second));
+
+    let result = subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    assert_eq!(result, expected);
+
+    let scalar = PrimitiveScalar::from(Some(100_000i64)).to(timestamp_b.data_type().clone());
+    let result = sub_timestamps_scalar(&timestamp_a, &scalar).unwrap();
+    let expected = PrimitiveArray::from([Some(10i64), None, Some(200_030i64)])
+        .to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+
+    // timestamps are absolute instants: different timezones can be subtracted
+    let timestamp_b = timestamp_b.to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+02:00".to_string()),
+    ));
+    let result = subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    let expected = PrimitiveArray::from([Some(10i64), None, Some(30i64)])
+        .to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+
+    let timestamp_b = timestamp_b.to(DataType::Timestamp(TimeUnit::Second, None));
+    let result = subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_subtract_timestamps_coarser_lhs() {
+    // seconds - milliseconds is in milliseconds, without losing precision
+    let timestamp_a = PrimitiveArray::from([Some(1i64), None, Some(-1i64), Some(3i64)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let timestamp_b = PrimitiveArray::from([Some(1i64), Some(2i64), Some(-1_500i64), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+    let expected = PrimitiveArray::from([Some(999i64), None, Some(500i64), None])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+
+    let result = subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    assert_eq!(result, expected);
+    let result = checked_subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    assert_eq!(result, expected);
+
+    let scalar = PrimitiveScalar::from(Some(500i64)).to(timestamp_b.data_type().clone());
+    let result = sub_timestamps_scalar(&timestamp_a, &scalar).unwrap();
+    let expected = PrimitiveArray::from([Some(500i64), None, Some(-1_500i64), Some(2_500i64)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_checked_subtract_timestamps() {
+    let timestamp_a = PrimitiveArray::from([Some(i64::MAX / 1_000), Some(i64::MIN), Some(1i64)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let timestamp_b = PrimitiveArray::from([Some(-1_000i64), Some(1i64), Some(0i64)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+    // the first conversion fits but the difference overflows; the second subtraction overflows
+    let result = checked_subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    let expected = PrimitiveArray::from([None, None, Some(1_000i64)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+
+    // units that do not fit the finest unit overflow
+    let timestamp_a =
+        PrimitiveArray::from([Some(i64::MAX)]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let timestamp_b =
+        PrimitiveArray::from([Some(0i64)]).to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let result = checked_subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    assert_eq!(result.null_count(), 1);
+}
+
+#[test]
+fn test_subtract_timestamps_null_overflow() {
+    // values behind nulls are not valid timestamps and must not overflow the conversion
+    let values = Buffer::from_slice([1i64, i64::MAX]);
+    let validity = Some(Bitmap::from([true, false]));
+    let timestamp_a = PrimitiveArray::from_data(
+        DataType::Timestamp(TimeUnit::Second, None),
+        values,
+        validity,
+    );
+    let timestamp_b = PrimitiveArray::from([Some(0i64), Some(0)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+    let expected =
+        PrimitiveArray::from([Some(1_000i64), None]).to(DataType::Duration(TimeUnit::Millisecond));
+    let result = subtract_timestamps(&timestamp_a, &timestamp_b).unwrap();
+    assert_eq!(result, expected);
+
+    let scalar = PrimitiveScalar::from(Some(0i64)).to(timestamp_b.data_type().clone());
+    let result = sub_timestamps_scalar(&timestamp_a, &scalar).unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_subtract_timestamps_invalid() {
+    let timestamp =
+        PrimitiveArray::from([Some(1i64)]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let duration = PrimitiveArray::from([Some(1i64)]).to(DataType::Duration(TimeUnit::Second));
+    assert!(subtract_timestamps(&timestamp, &duration).is_err());
+    assert!(checked_subtract_timestamps(&timestamp, &duration).is_err());
+
+    let other =
+        PrimitiveArray::from([Some(1i64), None]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert!(subtract_timestamps(&timestamp, &other).is_err());
+}
+
+#[test]
+fn test_subtract_dates() {
+    let date_a =
+        PrimitiveArray::from([Some(10i32), None, Some(-1i32), Some(i32::MAX)]).to(DataType::Date32);
+    let date_b = PrimitiveArray::from([Some(9i32), Some(1i32), Some(0i32), Some(i32::MIN)])
+        .to(DataType::Date32);
+    let result = subtract_date32(&date_a, &date_b).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(86_400i64),
+        None,
+        Some(-86_400i64),
+        Some((u32::MAX as i64) * 86_400),
+    ])
+    .to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+
+    let date_a = PrimitiveArray::from([Some(86_400_000i64), None, Some(0i64)]).to(DataType::Date64);
+    let date_b =
+        PrimitiveArray::from([Some(0i64), Some(0i64), Some(86_400_000i64)]).to(DataType::Date64);
+    let result = subtract_date64(&date_a, &date_b).unwrap();
+    let expected = PrimitiveArray::from([Some(86_400_000i64), None, Some(-86_400_000i64)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result, expected);
+
+    // the arrays must be dates
+    let integers = PrimitiveArray::from([Some(0i64), Some(0i64), Some(0i64)]);
+    assert!(subtract_date64(&date_a, &integers).is_err());
+}
+
+#[test]
+fn test_add_durations() {
+    let a = PrimitiveArray::from([Some(1i64), None, Some(3i64)])
+        .to(DataType::Duration(TimeUnit::Second));
+    let b = PrimitiveArray::from([Some(2i64), Some(2i64), None])
+        .to(DataType::Duration(TimeUnit::Second));
+
+    let result = add_durations(&a, &b).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(3i64), None, None]).to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+
+    let result = sub_durations(&a, &b).unwrap();
+    let expected =
+        PrimitiveArray::from([Some(-1i64), None, None]).to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_durations_different_unit() {
+    let a = PrimitiveArray::from([Some(1i64), None, Some(3i64)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+    let b = PrimitiveArray::from([Some(2i64), Some(2i64), Some(1i64)])
+        .to(DataType::Duration(TimeUnit::Nanosecond));
+
+    // the coarser unit is converted to the finer one
+    let expected = PrimitiveArray::from([Some(1_000_002i64), None, Some(3_000_001i64)])
+        .to(DataType::Duration(TimeUnit::Nanosecond));
+    assert_eq!(add_durations(&a, &b).unwrap(), expected);
+    assert_eq!(add_durations(&b, &a).unwrap(), expected);
+
+    let expected = PrimitiveArray::from([Some(-999_998i64), None, Some(-2_999_999i64)])
+        .to(DataType::Duration(TimeUnit::Nanosecond));
+    assert_eq!(sub_durations(&b, &a).unwrap(), expected);
+
+    let scalar = PrimitiveScalar::from(Some(1i64)).to(DataType::Duration(TimeUnit::Second));
+    let expected = PrimitiveArray::from([Some(-999i64), None, Some(-997i64)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(sub_durations_scalar(&a, &scalar).unwrap(), expected);
+
+    let scalar = PrimitiveScalar::<i64>::from(None).to(DataType::Duration(TimeUnit::Second));
+    let expected = PrimitiveArray::<i64>::new_null(DataType::Duration(TimeUnit::Millisecond), 3);
+    assert_eq!(add_durations_scalar(&a, &scalar).unwrap(), expected);
+}
+
+#[test]
+fn test_add_durations_overflow() {
+    let a = PrimitiveArray::from([Some(i64::MAX), Some(i64::MAX / 1_000), Some(1i64)])
+        .to(DataType::Duration(TimeUnit::Second));
+    let b = PrimitiveArray::from([Some(0i64), Some(1_000i64), Some(-1i64)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+
+    // the conversion of `a` to milliseconds and the sum wrap around
+    let result = add_durations(&a, &b).unwrap();
+    assert_eq!(result.value(0), i64::MAX.wrapping_mul(1_000));
+    assert_eq!(
+        result.value(1),
+        (i64::MAX / 1_000 * 1_000).wrapping_add(1_000)
+    );
+
+    let expected = PrimitiveArray::from([None, None, Some(999i64)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(checked_add_durations(&a, &b).unwrap(), expected);
+
+    let b = PrimitiveArray::from([Some(0i64), Some(-1i64), Some(1i64)])
+        .to(DataType::Duration(TimeUnit::Second));
+    let c = PrimitiveArray::from([Some(i64::MIN), Some(i64::MAX), Some(1i64)])
+        .to(DataType::Duration(TimeUnit::Second));
+    let result = sub_durations(&b, &c).unwrap();
+    assert_eq!(result.value(0), i64::MIN);
+    let expected = PrimitiveArray::from([None, Some(i64::MIN), Some(0i64)])
+        .to(DataType::Duration(TimeUnit::Second));
+    assert_eq!(checked_sub_durations(&b, &c).unwrap(), expected);
+
+    let scalar = PrimitiveScalar::from(Some(i64::MAX)).to(DataType::Duration(TimeUnit::Second));
+    let result = add_durations_scalar(&c, &scalar).unwrap();
+    assert_eq!(result.value(2), i64::MIN);
+}
+
+#[test]
+fn test_subtract_date64_overflow() {
+    let a = PrimitiveArray::from([Some(i64::MIN), Some(2i64)]).to(DataType::Date64);
+    let b = PrimitiveArray::from([Some(1i64), Some(1i64)]).to(DataType::Date64);
+
+    let result = subtract_date64(&a, &b).unwrap();
+    assert_eq!(result.value(0), i64::MAX);
+
+    let expected =
+        PrimitiveArray::from([None, Some(1i64)]).to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(checked_subtract_date64(&a, &b).unwrap(), expected);
+}
+
+#[test]
+fn test_add_durations_invalid() {
+    let a = PrimitiveArray::from([Some(1i64)]).to(DataType::Duration(TimeUnit::Second));
+    let b = PrimitiveArray::from([Some(1i64)]);
+    assert!(add_durations(&a, &b).is_err());
+    assert!(sub_durations(&b, &a).is_err());
+}
+
+#[test]
+fn test_add_durations_dyn() {
+    let a = PrimitiveArray::from([Some(1i64), None]).to(DataType::Duration(TimeUnit::Second));
+    let b = PrimitiveArray::from([Some(1i64), Some(1i64)])
+        .to(DataType::Duration(TimeUnit::Millisecond));
+
+    let result = arrow2::compute::arithmetics::add(&a, &b);
+    let expected =
+        PrimitiveArray::from([Some(1_001i64), None]).to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+fn date32(year: i32, month: u32, day: u32) -> i32 {
+    use chrono::Datelike;
+    chrono::NaiveDate::from_ymd(year, month, day).num_days_from_ce()
+        - arrow2::temporal_conversions::EPOCH_DAYS_FROM_CE
+}
+
+#[test]
+fn test_add_interval_date32() {
+    use arrow2::datatypes::IntervalUnit;
+    use arrow2::types::months_days_ns;
+
+    let date = PrimitiveArray::from([
+        Some(date32(2021, 1, 31)),
+        Some(date32(2020, 1, 31)),
+        Some(date32(2020, 3, 31)),
+        Some(date32(2021, 3, 1)),
+        None,
+        Some(date32(2021, 1, 1)),
+    ])
+    .to(DataType::Date32);
+    let interval = PrimitiveArray::from([
+        // end of month clamping
+        Some(months_days_ns::new(1, 0, 0)),
+        // leap year
+        Some(months_days_ns::new(1, 0, 0)),
+        // negative months, clamping to a leap day
+        Some(months_days_ns::new(-1, 0, 0)),
+        // negative days
+        Some(months_days_ns::new(0, -1, 0)),
+        Some(months_days_ns::new(1, 0, 0)),
+        // months, then days, then nanoseconds rounded down to days
+        Some(months_days_ns::new(12, 30, -1)),
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    let expected = PrimitiveArray::from([
+        Some(date32(2021, 2, 28)),
+        Some(date32(2020, 2, 29)),
+        Some(date32(2020, 2, 29)),
+        Some(date32(2021, 2, 28)),
+        None,
+        Some(date32(2022, 1, 30)),
+    ])
+    .to(DataType::Date32);
+
+    let result = add_interval_date32(&date, &interval).unwrap();
+    assert_eq!(result, expected);
+
+    let interval = PrimitiveScalar::from(Some(months_days_ns::new(13, 0, 0)))
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    let result = add_interval_date32_scalar(&date, &interval).unwrap();
+    let expected = PrimitiveArray::from([
+        Some(date32(2022, 2, 28)),
+        Some(date32(2021, 2, 28)),
+        Some(date32(2021, 4, 30)),
+        Some(date32(2022, 4, 1)),
+        None,
+        Some(date32(2022, 2, 1)),
+    ])
+    .to(DataType::Date32);
+    assert_eq!(result, expected);
+
+    let interval = PrimitiveScalar::<months_days_ns>::from(None)
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    let result = add_interval_date32_scalar(&date, &interval).unwrap();
+    assert_eq!(result.null_count(), 6);
+
+    // only dates are accepted
+    let date = PrimitiveArray::from([Some(1i32)]);
+    let interval = PrimitiveArray::from([Some(months_days_ns::new(1, 0, 0))])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert!(add_interval_date32(&date, &interval).is_err());
+}
+
+#[test]
+fn test_add_interval_date32_dyn() {
+    use arrow2::compute::arithmetics::{add, add_scalar, can_add};
+    use arrow2::datatypes::IntervalUnit;
+    use arrow2::types::months_days_ns;
+
+    let date = PrimitiveArray::from([Some(date32(2021, 1, 31)), None]).to(DataType::Date32);
+    let interval = PrimitiveArray::from([Some(months_days_ns::new(1, 1, 0)); 2])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert!(can_add(date.data_type(), interval.data_type()));
+
+    let expected = PrimitiveArray::from([Some(date32(2021, 3, 1)), None]).to(DataType::Date32);
+    let result = add(&date, &interval);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let interval = PrimitiveScalar::from(Some(months_days_ns::new(1, 1, 0)))
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    let result = add_scalar(&date, &interval);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn test_add_interval_timestamp_clamps() {
+    use arrow2::datatypes::IntervalUnit;
+    use arrow2::types::months_days_ns;
+
+    let seconds = |year, month, day| date32(year, month, day) as i64 * 86_400;
+
+    // 2021-01-31T01:00:00
+    let timestamp = PrimitiveArray::from([Some(seconds(2021, 1, 31) + 3600), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let interval = PrimitiveScalar::from(Some(months_days_ns::new(1, 1, 1_000_000_000)))
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    // 2021-02-28T01:00:00 + 1 day + 1 second
+    let expected = PrimitiveArray::from([Some(seconds(2021, 3, 1) + 3601), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let result = add_interval_scalar(&timestamp, &interval).unwrap();
+    assert_eq!(result, expected);
+
+    // Date64 behaves like a timestamp in milliseconds
+    let date = PrimitiveArray::from([Some(seconds(2020, 3, 31) * 1000)]).to(DataType::Date64);
+    let interval = PrimitiveArray::from([Some(months_days_ns::new(-1, 0, 0))])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    let expected = PrimitiveArray::from([Some(seconds(2020, 2, 29) * 1000)]).to(DataType::Date64);
+    let result = add_interval(&date, &interval).unwrap();
+    assert_eq!(result, expected);
+}
@@ -39,3 +39,72 @@ fn test_not() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_scalar() {
+    let a = UInt64Array::from(&[Some(0b1010), None, Some(0)]);
+    assert_eq!(
+        and_scalar(&a, &0b0110),
+        UInt64Array::from(&[Some(0b0010), None, Some(0)])
+    );
+    assert_eq!(
+        or_scalar(&a, &0b0110),
+        UInt64Array::from(&[Some(0b1110), None, Some(0b0110)])
+    );
+    assert_eq!(
+        xor_scalar(&a, &0b0110),
+        UInt64Array::from(&[Some(0b1100), None, Some(0b0110)])
+    );
+}
+
+#[test]
+fn test_shift_left() {
+    let a = Int32Array::from(&[Some(1), None, Some(-1), Some(i32::MIN)]);
+    let result = shift_left(&a, 1);
+    let expected = Int32Array::from(&[Some(2), None, Some(-2), Some(0)]);
+    assert_eq!(result, expected);
+
+    assert_eq!(shift_left(&a, 0), a);
+    assert_eq!(shift_left(&a, 31).value(0), i32::MIN);
+
+    // shifts greater than or equal to the bit width result in zero
+    let expected = Int32Array::from(&[Some(0), None, Some(0), Some(0)]);
+    assert_eq!(shift_left(&a, 32), expected);
+    assert_eq!(shift_left(&a, 100), expected);
+}
+
+#[test]
+fn test_shift_right() {
+    let a = Int16Array::from(&[Some(8), None, Some(-8), Some(i16::MIN)]);
+    let result = shift_right(&a, 2);
+    let expected = Int16Array::from(&[Some(2), None, Some(-2), Some(i16::MIN / 4)]);
+    assert_eq!(result, expected);
+
+    // shifts greater than or equal to the bit width fill with the sign bit
+    let expected = Int16Array::from(&[Some(0), None, Some(-1), Some(-1)]);
+    assert_eq!(shift_right(&a, 15), expected);
+    assert_eq!(shift_right(&a, 16), expected);
+    assert_eq!(shift_right(&a, 100), expected);
+
+    let a = UInt16Array::from(&[Some(8), None, Some(u16::MAX)]);
+    assert_eq!(
+        shift_right(&a, 3),
+        UInt16Array::from(&[Some(1), None, Some(u16::MAX >> 3)])
+    );
+    let expected = UInt16Array::from(&[Some(0), None, Some(0)]);
+    assert_eq!(shift_right(&a, 16), expected);
+    assert_eq!(shift_right(&a, 17), expected);
+}
+
+#[test]
+fn test_shift_sliced() {
+    let a = UInt8Array::from(&[Some(1), Some(2), None, Some(4)]).slice(1, 3);
+    assert_eq!(
+        shift_left(&a, 2),
+        UInt8Array::from(&[Some(8), None, Some(16)])
+    );
+    assert_eq!(
+        shift_right(&a, 1),
+        UInt8Array::from(&[Some(1), None, Some(2)])
+    );
+}
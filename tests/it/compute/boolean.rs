@@ -339,6 +339,40 @@ fn test_nullable_array_with_offset_is_not_null() {
     assert_eq!(expected, res);
 }
 
+#[test]
+fn test_null_array_is_null() {
+    let a = NullArray::from_data(arrow2::datatypes::DataType::Null, 5).slice(1, 3);
+
+    let res = is_null(&a);
+    assert_eq!(BooleanArray::from_slice(vec![true, true, true]), res);
+    assert_eq!(res.validity(), None);
+
+    let res = is_not_null(&a);
+    assert_eq!(BooleanArray::from_slice(vec![false, false, false]), res);
+    assert_eq!(res.validity(), None);
+}
+
+#[test]
+fn test_dictionary_array_is_null() {
+    // the null of the values (key 1) is not a null of the dictionary
+    let values = Utf8Array::<i32>::from(&[Some("a"), None]);
+    let keys = Int32Array::from(&[Some(0), Some(1), None, Some(0), None]);
+    let a = DictionaryArray::<i32>::from_data(keys, std::sync::Arc::new(values));
+    let a = a.slice(1, 4);
+
+    let res = is_null(&a);
+    assert_eq!(
+        BooleanArray::from_slice(vec![false, true, false, true]),
+        res
+    );
+
+    let res = is_not_null(&a);
+    assert_eq!(
+        BooleanArray::from_slice(vec![true, false, true, false]),
+        res
+    );
+}
+
 #[test]
 fn array_and_scalar() {
     let array = BooleanArray::from_slice(&[false, false, true, true]);
@@ -420,6 +454,100 @@ fn array_or_scalar_validity() {
     assert_eq!(real, expected);
 }
 
+#[test]
+fn array_xor() {
+    let a = BooleanArray::from_slice(vec![false, false, true, true]);
+    let b = BooleanArray::from_slice(vec![false, true, false, true]);
+    let c = xor(&a, &b).unwrap();
+
+    let expected = BooleanArray::from_slice(vec![false, true, true, false]);
+
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn array_xor_validity() {
+    let a = BooleanArray::from(vec![None, Some(false), Some(true), Some(true)]);
+    let b = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+    let c = xor(&a, &b).unwrap();
+
+    let expected = BooleanArray::from(vec![None, None, Some(true), Some(false)]);
+
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn array_binary_sliced() {
+    let a = (0..200)
+        .map(|i| if i % 11 == 0 { None } else { Some(i % 3 == 0) })
+        .collect::<BooleanArray>();
+    let b = (0..200)
+        .map(|i| if i % 7 == 0 { None } else { Some(i % 2 == 0) })
+        .collect::<BooleanArray>();
+    // operands with different, non-byte-aligned offsets
+    let a = a.slice(3, 180);
+    let b = b.slice(13, 180);
+
+    let expected = |op: fn(bool, bool) -> bool| {
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| a.zip(b).map(|(a, b)| op(a, b)))
+            .collect::<BooleanArray>()
+    };
+    assert_eq!(and(&a, &b).unwrap(), expected(|a, b| a & b));
+    assert_eq!(or(&a, &b).unwrap(), expected(|a, b| a | b));
+    assert_eq!(xor(&a, &b).unwrap(), expected(|a, b| a ^ b));
+}
+
+#[test]
+fn array_xor_scalar() {
+    let array = BooleanArray::from(&[None, Some(false), Some(true)]);
+
+    let scalar = BooleanScalar::new(Some(true));
+    let real = xor_scalar(&array, &scalar);
+
+    let expected = BooleanArray::from(&[None, Some(true), Some(false)]);
+    assert_eq!(real, expected);
+
+    let scalar = BooleanScalar::new(Some(false));
+    let real = xor_scalar(&array, &scalar);
+    assert_eq!(real, array);
+
+    let scalar = BooleanScalar::new(None);
+    let real = xor_scalar(&array, &scalar);
+
+    let expected = BooleanArray::from(&[None; 3]);
+    assert_eq!(real, expected);
+}
+
+#[test]
+fn array_scalar_sliced() {
+    let array = (0..100)
+        .map(|i| if i % 5 == 0 { None } else { Some(i % 3 == 0) })
+        .collect::<BooleanArray>();
+    let array = array.slice(7, 80);
+
+    for scalar in [true, false] {
+        let expected = |op: fn(bool, bool) -> bool| {
+            array
+                .iter()
+                .map(|x| x.map(|x| op(x, scalar)))
+                .collect::<BooleanArray>()
+        };
+        let scalar = BooleanScalar::new(Some(scalar));
+        assert_eq!(and_scalar(&array, &scalar), expected(|a, b| a & b));
+        assert_eq!(or_scalar(&array, &scalar), expected(|a, b| a | b));
+        assert_eq!(xor_scalar(&array, &scalar), expected(|a, b| a ^ b));
+    }
+
+    // `and` with `true` shares the values of the array
+    let result = and_scalar(&array, &BooleanScalar::new(Some(true)));
+    assert_eq!(
+        result.values().as_slice().0.as_ptr(),
+        array.values().as_slice().0.as_ptr()
+    );
+}
+
 #[test]
 fn test_any_all() {
     let array = BooleanArray::from(&[None, Some(false), Some(true)]);
@@ -446,4 +574,28 @@ fn test_any_all() {
     let array = BooleanArray::from(&[Some(false)]);
     assert!(!any(&array));
     assert!(!all(&array));
+    let array = BooleanArray::from_slice(&[]);
+    assert!(!any(&array));
+    assert!(!all(&array));
+}
+
+#[test]
+fn test_any_all_sliced() {
+    // the `true` and `false` outside of the slice must be ignored
+    let mut values = vec![true; 3];
+    values.extend(std::iter::repeat(false).take(130));
+    values.push(true);
+    let array = BooleanArray::from_slice(&values);
+    assert!(!any(&array.slice(3, 130)));
+    assert!(any(&array.slice(2, 130)));
+    assert!(any(&array.slice(4, 130)));
+
+    let array = BooleanArray::from_slice(&[false, true, true, true, false]);
+    assert!(all(&array.slice(1, 3)));
+    assert!(!all(&array.slice(1, 4)));
+
+    // the `true` under a null must be ignored
+    let array = BooleanArray::from(&[Some(true), None, Some(false), Some(false)]);
+    let array = array.with_validity(Some([true, false, true, true].into()));
+    assert!(!any(&array.slice(1, 3)));
 }
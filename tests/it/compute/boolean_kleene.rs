@@ -214,3 +214,132 @@ fn array_or_none() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn any_all() {
+    let array = BooleanArray::from(&[None, Some(false), Some(true)]);
+    assert_eq!(any(&array), Some(true));
+    assert_eq!(all(&array), Some(false));
+
+    let array = BooleanArray::from(&[None, Some(false), Some(false)]);
+    assert_eq!(any(&array), None);
+    assert_eq!(all(&array), Some(false));
+
+    let array = BooleanArray::from(&[None, Some(true), Some(true)]);
+    assert_eq!(any(&array), Some(true));
+    assert_eq!(all(&array), None);
+
+    let array = BooleanArray::from(&[None, None]);
+    assert_eq!(any(&array), None);
+    assert_eq!(all(&array), None);
+
+    let array = BooleanArray::from_slice(&[true, true]);
+    assert_eq!(any(&array), Some(true));
+    assert_eq!(all(&array), Some(true));
+
+    let array = BooleanArray::from_slice(&[]);
+    assert_eq!(any(&array), Some(false));
+    assert_eq!(all(&array), Some(true));
+}
+
+#[test]
+fn any_all_sliced() {
+    // a non-byte-aligned offset over more than one word
+    let values = (0..200).map(|i| match i {
+        3 => Some(true),
+        5 => None,
+        _ => Some(false),
+    });
+    let array = values.collect::<BooleanArray>();
+
+    let sliced = array.slice(4, 150);
+    assert_eq!(any(&sliced), None);
+    assert_eq!(all(&sliced), Some(false));
+
+    let sliced = array.slice(3, 1);
+    assert_eq!(any(&sliced), Some(true));
+    assert_eq!(all(&sliced), Some(true));
+
+    let sliced = array.slice(6, 194);
+    assert_eq!(any(&sliced), Some(false));
+    assert_eq!(all(&sliced), Some(false));
+}
+
+fn kleene_and(lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+    match (lhs, rhs) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+fn kleene_or(lhs: Option<bool>, rhs: Option<bool>) -> Option<bool> {
+    match (lhs, rhs) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+#[test]
+fn and_or_sliced() {
+    let with_nulls = (0..300)
+        .map(|i| match i % 3 {
+            0 => None,
+            1 => Some(i % 2 == 0),
+            _ => Some(i % 5 == 0),
+        })
+        .collect::<BooleanArray>();
+    let without_nulls = (0..300).map(|i| Some(i % 7 < 3)).collect::<BooleanArray>();
+
+    // operands with different, non-byte-aligned offsets, with and without validities
+    for (lhs, rhs) in [
+        (&with_nulls, &with_nulls),
+        (&with_nulls, &without_nulls),
+        (&without_nulls, &with_nulls),
+        (&without_nulls, &without_nulls),
+    ] {
+        let lhs = lhs.slice(3, 250);
+        let rhs = rhs.slice(17, 250);
+
+        let expected = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(lhs, rhs)| kleene_and(lhs, rhs))
+            .collect::<BooleanArray>();
+        assert_eq!(and(&lhs, &rhs).unwrap(), expected);
+
+        let expected = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(lhs, rhs)| kleene_or(lhs, rhs))
+            .collect::<BooleanArray>();
+        assert_eq!(or(&lhs, &rhs).unwrap(), expected);
+    }
+}
+
+#[test]
+fn and_or_scalar_sliced() {
+    let array = (0..100)
+        .map(|i| match i % 3 {
+            0 => None,
+            1 => Some(true),
+            _ => Some(false),
+        })
+        .collect::<BooleanArray>();
+    let array = array.slice(5, 90);
+
+    for scalar in [Some(true), Some(false), None] {
+        let expected = array
+            .iter()
+            .map(|x| kleene_and(x, scalar))
+            .collect::<BooleanArray>();
+        assert_eq!(and_scalar(&array, &BooleanScalar::new(scalar)), expected);
+
+        let expected = array
+            .iter()
+            .map(|x| kleene_or(x, scalar))
+            .collect::<BooleanArray>();
+        assert_eq!(or_scalar(&array, &BooleanScalar::new(scalar)), expected);
+    }
+}
@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{can_cast_types, cast, CastOptions, DecimalRounding};
 use arrow2::datatypes::*;
-use arrow2::types::NativeType;
+use arrow2::types::{f16, i256, NativeType};
 
 #[test]
 fn i32_to_f64() {
@@ -90,6 +92,90 @@ fn f32_as_u8_overflow() {
     assert_eq!(expected, b.as_ref());
 }
 
+#[test]
+fn i64_to_i32_overflow() {
+    let array = Int64Array::from_slice(&[
+        3_000_000_000,
+        i32::MAX as i64,
+        i32::MIN as i64,
+        -3_000_000_000,
+    ]);
+    let b = cast(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    let expected = Int32Array::from(&[None, Some(i32::MAX), Some(i32::MIN), None]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(
+        &array,
+        &DataType::Int32,
+        CastOptions {
+            wrapped: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let expected = Int32Array::from_slice(&[
+        3_000_000_000i64 as i32,
+        i32::MAX,
+        i32::MIN,
+        -3_000_000_000i64 as i32,
+    ]);
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn f64_to_i64_non_finite() {
+    let array = Float64Array::from(&[
+        Some(f64::NAN),
+        Some(f64::INFINITY),
+        Some(f64::NEG_INFINITY),
+        Some(1e19),
+        Some(-1.5),
+        None,
+    ]);
+    let b = cast(&array, &DataType::Int64, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[None, None, None, None, Some(-1), None]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(
+        &array,
+        &DataType::Int64,
+        CastOptions {
+            wrapped: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let expected = Int64Array::from(&[
+        Some(0),
+        Some(i64::MAX),
+        Some(i64::MIN),
+        Some(i64::MAX),
+        Some(-1),
+        None,
+    ]);
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn u64_to_i64_boundaries() {
+    let array = UInt64Array::from_slice(&[0, i64::MAX as u64, i64::MAX as u64 + 1, u64::MAX]);
+    let b = cast(&array, &DataType::Int64, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(0), Some(i64::MAX), None, None]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(
+        &array,
+        &DataType::Int64,
+        CastOptions {
+            wrapped: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let expected = Int64Array::from_slice(&[0, i64::MAX, i64::MIN, -1]);
+    assert_eq!(expected, b.as_ref());
+}
+
 #[test]
 fn i32_to_u8() {
     let array = Int32Array::from_slice(&[-5, 6, -7, 8, 100000000]);
@@ -251,6 +337,48 @@ fn int32_to_decimal() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn float16_to_float() {
+    let values = [
+        Some(f16::from_f32(1.5)),
+        None,
+        Some(f16::MAX),
+        Some(f16::NEG_INFINITY),
+    ];
+    let array = Float16Array::from(&values);
+    assert!(can_cast_types(array.data_type(), &DataType::Float32));
+    assert!(can_cast_types(array.data_type(), &DataType::Float64));
+
+    let b = cast(&array, &DataType::Float32, CastOptions::default()).unwrap();
+    let expected = Float32Array::from(&[Some(1.5), None, Some(65504.0), Some(f32::NEG_INFINITY)]);
+    assert_eq!(expected, b.as_ref());
+
+    let b = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    let expected = Float64Array::from(&[Some(1.5), None, Some(65504.0), Some(f64::NEG_INFINITY)]);
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn float_to_float16() {
+    // 1.0009765625 = 1 + 2^-10 is representable; 1 + 2^-12 rounds to 1; 1e5 overflows
+    let array = Float32Array::from(&[Some(1.0009765625), Some(1.000244140625), None, Some(1e5)]);
+    assert!(can_cast_types(array.data_type(), &DataType::Float16));
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let expected = Float16Array::from(&[
+        Some(f16::from_bits(0x3C01)),
+        Some(f16::ONE),
+        None,
+        Some(f16::INFINITY),
+    ]);
+    assert_eq!(expected, b.as_ref());
+
+    let array = Float64Array::from(&[Some(-2.5), None]);
+    assert!(can_cast_types(array.data_type(), &DataType::Float16));
+    let b = cast(&array, &DataType::Float16, CastOptions::default()).unwrap();
+    let expected = Float16Array::from(&[Some(f16::from_f32(-2.5)), None]);
+    assert_eq!(expected, b.as_ref());
+}
+
 #[test]
 fn float32_to_decimal() {
     let array = Float32Array::from(&[
@@ -296,13 +424,13 @@ fn int32_to_decimal_scaled() {
 fn decimal_to_decimal() {
     // increase scale and precision
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+        .to(DataType::Decimal(2, 0));
 
-    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(3, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected = Int128Array::from(&[Some(20), Some(100), Some(-20), Some(-100), None])
-        .to(DataType::Decimal(2, 1));
+        .to(DataType::Decimal(3, 1));
     assert_eq!(c, &expected)
 }
 
@@ -311,16 +439,144 @@ fn decimal_to_decimal_scaled() {
     // decrease precision
     // 10 and -10 can't be represented with precision 1 and scale 1
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+        .to(DataType::Decimal(2, 1));
 
     let b = cast(&array, &DataType::Decimal(1, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected =
-        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(1, 1));
+        Int128Array::from(&[Some(2), None, Some(-2), None, None]).to(DataType::Decimal(1, 1));
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn decimal_to_decimal_rescale_rounding() {
+    let array = Int128Array::from(&[Some(1_25), Some(-1_25), Some(1_24), Some(-1_26), None])
+        .to(DataType::Decimal(5, 2));
+    let to_type = DataType::Decimal(5, 1);
+
+    let b = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int128Array::from(&[Some(1_2), Some(-1_2), Some(1_2), Some(-1_2), None])
+        .to(to_type.clone());
+    assert_eq!(expected, b.as_ref());
+
+    let options = CastOptions {
+        rounding: DecimalRounding::HalfAwayFromZero,
+        ..Default::default()
+    };
+    let b = cast(&array, &to_type, options).unwrap();
+    let expected =
+        Int128Array::from(&[Some(1_3), Some(-1_3), Some(1_2), Some(-1_3), None]).to(to_type);
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn decimal_to_decimal_overflow() {
+    // 1000.0 does not fit in Decimal(4, 1)
+    let array =
+        Int128Array::from(&[Some(999_99), Some(-1000_00), None]).to(DataType::Decimal(6, 2));
+    let to_type = DataType::Decimal(4, 1);
+
+    let b = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int128Array::from(&[Some(999_9), None, None]).to(to_type.clone());
+    assert_eq!(expected, b.as_ref());
+
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let error = cast(&array, &to_type, options).unwrap_err().to_string();
+    assert!(error.contains("-1000.00 at index 1"));
+}
+
+#[test]
+fn utf8_to_decimal() {
+    let array = Utf8Array::<i32>::from(&[
+        Some("123.4500"),
+        Some("+1.5"),
+        Some(".5"),
+        Some("-0.05"),
+        Some("7"),
+        Some("1e2"),
+        Some("1.2.3"),
+        Some(""),
+        Some("."),
+        Some("100000"),
+        None,
+    ]);
+    let to_type = DataType::Decimal(7, 2);
+
+    let b = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int128Array::from(&[
+        Some(123_45),
+        Some(1_50),
+        Some(0_50),
+        Some(-0_05),
+        Some(7_00),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ])
+    .to(to_type.clone());
+    assert_eq!(expected, b.as_ref());
+
+    let options = CastOptions {
+        rounding: DecimalRounding::HalfAwayFromZero,
+        ..Default::default()
+    };
+    let array = Utf8Array::<i64>::from_slice(&["1.005", "-1.004", "9.995"]);
+    let b = cast(&array, &DataType::Decimal(3, 2), options).unwrap();
+    let expected = Int128Array::from(&[Some(1_01), Some(-1_00), None]).to(DataType::Decimal(3, 2));
+    assert_eq!(expected, b.as_ref());
+
+    // "9.995" rounds to 10.00, which does not fit
+    let options = CastOptions {
+        strict: true,
+        ..options
+    };
+    assert!(cast(&array, &DataType::Decimal(3, 2), options).is_err());
+}
+
+#[test]
+fn decimal_to_utf8() {
+    let array = Int128Array::from(&[Some(123_45), Some(-0_05), Some(0), Some(-1_00), None])
+        .to(DataType::Decimal(5, 2));
+    let b = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from(&[
+        Some("123.45"),
+        Some("-0.05"),
+        Some("0.00"),
+        Some("-1.00"),
+        None,
+    ]);
+    assert_eq!(expected, b.as_ref());
+
+    let array = Int128Array::from_slice(&[-12]).to(DataType::Decimal(2, 0));
+    let b = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    assert_eq!(Utf8Array::<i64>::from_slice(&["-12"]), b.as_ref());
+}
+
+#[test]
+fn decimal_utf8_roundtrip() {
+    let values = [
+        Some(i128::from(i64::MAX)),
+        Some(-1),
+        Some(0),
+        None,
+        Some(-10i128.pow(37)),
+    ];
+    for scale in [0, 1, 10, 38] {
+        let to_type = DataType::Decimal(38, scale);
+        let array = Int128Array::from(&values).to(to_type.clone());
+        let utf8 = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+        let result = cast(utf8.as_ref(), &to_type, CastOptions::default()).unwrap();
+        assert_eq!(array, result.as_ref());
+    }
+}
+
 #[test]
 fn decimal_to_decimal_fast() {
     // increase precision
@@ -336,6 +592,100 @@ fn decimal_to_decimal_fast() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn decimal_to_decimal256() {
+    let max = 10i128.pow(38) - 1;
+    let array =
+        Int128Array::from(&[Some(max), Some(-max), Some(-1_25), None]).to(DataType::Decimal(38, 2));
+    assert!(can_cast_types(
+        array.data_type(),
+        &DataType::Decimal256(76, 4)
+    ));
+
+    // widening never overflows
+    let b = cast(&array, &DataType::Decimal256(76, 4), CastOptions::default()).unwrap();
+    let hundred = i256::from(100);
+    let expected = Int256Array::from(&[
+        i256::from(max).checked_mul(hundred),
+        i256::from(-max).checked_mul(hundred),
+        Some(i256::from(-1_2500)),
+        None,
+    ])
+    .to(DataType::Decimal256(76, 4));
+    assert_eq!(expected, b.as_ref());
+
+    let c = cast(
+        b.as_ref(),
+        &DataType::Decimal(38, 2),
+        CastOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(array, c.as_ref());
+}
+
+#[test]
+fn decimal256_to_decimal() {
+    // 10^40 of scale 2 does not fit in 38 digits
+    let big = i256::from(10).checked_pow(40).unwrap();
+    let array = Int256Array::from(&[Some(big), Some(i256::from(-1_25)), None])
+        .to(DataType::Decimal256(50, 2));
+    let to_type = DataType::Decimal(38, 1);
+    assert!(can_cast_types(array.data_type(), &to_type));
+
+    let options = CastOptions {
+        rounding: DecimalRounding::HalfAwayFromZero,
+        ..Default::default()
+    };
+    let b = cast(&array, &to_type, options).unwrap();
+    let expected = Int128Array::from(&[None, Some(-1_3), None]).to(to_type.clone());
+    assert_eq!(expected, b.as_ref());
+
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let error = cast(&array, &to_type, options).unwrap_err().to_string();
+    assert!(error.contains(&format!("1{}.00 at index 0", "0".repeat(38))));
+}
+
+#[test]
+fn decimal256_to_decimal256() {
+    let array = Int256Array::from(&[Some(i256::from(1_25)), Some(i256::from(-1_25)), None])
+        .to(DataType::Decimal256(40, 2));
+
+    let b = cast(
+        &array,
+        &DataType::Decimal256(76, 40),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let factor = i256::from(10).checked_pow(38).unwrap();
+    let expected = Int256Array::from(&[
+        i256::from(1_25).checked_mul(factor),
+        i256::from(-1_25).checked_mul(factor),
+        None,
+    ])
+    .to(DataType::Decimal256(76, 40));
+    assert_eq!(expected, b.as_ref());
+
+    let c = cast(
+        b.as_ref(),
+        &DataType::Decimal256(40, 2),
+        CastOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(array, c.as_ref());
+
+    // 1.25 does not fit in 10^76 of scale 76
+    let d = cast(
+        &array,
+        &DataType::Decimal256(76, 76),
+        CastOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(d.null_count(), 3);
+}
+
 #[test]
 fn decimal_to_float() {
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
@@ -446,6 +796,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -471,6 +822,13 @@ fn consistency() {
         Duration(TimeUnit::Nanosecond),
         List(Box::new(Field::new("a", Utf8, true))),
         LargeList(Box::new(Field::new("a", Utf8, true))),
+        FixedSizeBinary(2),
+        Struct(vec![Field::new("a", Utf8, true)]),
+        Struct(vec![Field::new("a", Int32, true)]),
+        Struct(vec![
+            Field::new("a", Utf8, true),
+            Field::new("b", Int32, true),
+        ]),
     ];
     for d1 in &datatypes {
         for d2 in &datatypes {
@@ -602,6 +960,315 @@ fn timestamp_to_timestamp() {
     );
 }
 
+#[test]
+fn timestamp_to_timestamp_with_timezone() {
+    let array = Int64Array::from(&[Some(1_500_000_123_456i64), None]).to(DataType::Timestamp(
+        TimeUnit::Nanosecond,
+        Some("UTC".to_string()),
+    ));
+    let to_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string()));
+
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(1_500_000i64), None]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn timestamp_to_timestamp_overflow() {
+    let array = Int64Array::from_slice(&[1, i64::MAX / 1000])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(1_000_000_000i64), None]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn naive_timestamp_to_timestamp_wall_clock() {
+    // 2021-01-01 12:00:00
+    let array = Int64Array::from(&[Some(1_609_502_400i64), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let to_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string()));
+
+    // by default, the naive timestamp is interpreted as UTC
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = Int64Array::from(&[Some(1_609_502_400_000i64), None]).to(to_type.clone());
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        wall_clock: true,
+        ..Default::default()
+    };
+    let result = cast(&array, &to_type, options).unwrap();
+    let expected = Int64Array::from(&[Some(1_609_498_800_000i64), None]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+
+    // and back
+    let result = cast(result.as_ref(), array.data_type(), options).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn naive_timestamp_to_timestamp_wall_clock_out_of_range() {
+    // `i64::MAX` seconds is out of the range of dates, both in a valid and a null slot
+    let array = Int64Array::from_data(
+        DataType::Timestamp(TimeUnit::Second, None),
+        vec![i64::MAX, i64::MAX, 1_609_502_400].into(),
+        Some([true, false, true].into()),
+    );
+    let to_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+    let options = CastOptions {
+        wall_clock: true,
+        ..Default::default()
+    };
+
+    let result = cast(&array, &to_type, options).unwrap();
+    let expected = Int64Array::from(&[None, None, Some(1_609_498_800)]).to(to_type.clone());
+    assert_eq!(expected, result.as_ref());
+
+    let array = array.to(to_type);
+    let result = cast(
+        &array,
+        &DataType::Timestamp(TimeUnit::Second, None),
+        options,
+    )
+    .unwrap();
+    let expected = Int64Array::from(&[None, None, Some(1_609_506_000)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn timestamp_to_timestamp_invalid_timezone() {
+    let from_type = DataType::Timestamp(TimeUnit::Second, None);
+    let to_type = DataType::Timestamp(TimeUnit::Second, Some("not a timezone".to_string()));
+    // re-labeling does not need to parse the timezone
+    assert!(can_cast_types(&from_type, &to_type));
+    assert!(!can_cast_types(&to_type, &DataType::Utf8));
+    assert!(!can_cast_types(&DataType::Utf8, &to_type));
+
+    let array = Int64Array::from_slice(&[1]).to(from_type);
+    assert!(cast(&array, &to_type, CastOptions::default()).is_ok());
+    let options = CastOptions {
+        wall_clock: true,
+        ..Default::default()
+    };
+    assert!(cast(&array, &to_type, options).is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn naive_timestamp_to_timestamp_dst() {
+    let array = Int64Array::from_slice(&[
+        1_616_895_000, // 2021-03-28 01:30:00, does not exist in Lisbon
+        1_635_643_800, // 2021-10-31 01:30:00, happens twice in Lisbon
+        1_625_142_600, // 2021-07-01 12:30:00
+    ])
+    .to(DataType::Timestamp(TimeUnit::Second, None));
+    let to_type = DataType::Timestamp(TimeUnit::Second, Some("Europe/Lisbon".to_string()));
+
+    let options = CastOptions {
+        wall_clock: true,
+        ..Default::default()
+    };
+    let result = cast(&array, &to_type, options).unwrap();
+    let expected = Int64Array::from(&[None, Some(1_635_640_200), Some(1_625_139_000)]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn struct_to_struct() {
+    let from_type = DataType::Struct(vec![
+        Field::new("b", DataType::Utf8, true),
+        Field::new("a", DataType::Int32, true),
+    ]);
+    let to_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let array = StructArray::from_data(
+        from_type,
+        vec![
+            Arc::new(Utf8Array::<i32>::from(&[Some("x"), None, Some("z")])),
+            Arc::new(Int32Array::from(&[Some(1), Some(2), None])),
+        ],
+        Some([true, false, true].into()),
+    );
+
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    let expected = StructArray::from_data(
+        to_type,
+        vec![
+            Arc::new(Int64Array::from(&[Some(1), Some(2), None])),
+            Arc::new(Utf8Array::<i32>::from(&[Some("x"), None, Some("z")])),
+        ],
+        Some([true, false, true].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn struct_to_struct_nested() {
+    let inner_from = DataType::Struct(vec![Field::new("c", DataType::Int64, true)]);
+    let inner_to = DataType::Struct(vec![Field::new("c", DataType::Int8, true)]);
+    let from_type = DataType::Struct(vec![Field::new("inner", inner_from.clone(), true)]);
+    let to_type = DataType::Struct(vec![Field::new("inner", inner_to.clone(), true)]);
+
+    let inner = StructArray::from_data(
+        inner_from,
+        vec![Arc::new(Int64Array::from(&[Some(1), Some(1000), None]))],
+        None,
+    );
+    let array = StructArray::from_data(from_type, vec![Arc::new(inner)], None);
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    // the child cast overflows and is null in safe mode
+    let inner = StructArray::from_data(
+        inner_to,
+        vec![Arc::new(Int8Array::from(&[Some(1), None, None]))],
+        None,
+    );
+    let expected = StructArray::from_data(to_type, vec![Arc::new(inner)], None);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn struct_to_struct_missing_and_extra_fields() {
+    let from_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("extra", DataType::Int32, true),
+    ]);
+    let array = StructArray::from_data(
+        from_type,
+        vec![
+            Arc::new(Int32Array::from_slice(&[1, 2])),
+            Arc::new(Int32Array::from_slice(&[3, 4])),
+        ],
+        None,
+    );
+
+    let to_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("missing", DataType::Utf8, true),
+    ]);
+    // only castable with non-default options
+    assert!(!can_cast_types(array.data_type(), &to_type));
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+
+    let options = CastOptions {
+        null_missing_fields: true,
+        ..Default::default()
+    };
+    // the extra field is only dropped when allowed
+    assert!(cast(&array, &to_type, options).is_err());
+
+    let options = CastOptions {
+        null_missing_fields: true,
+        drop_extra_fields: true,
+        ..Default::default()
+    };
+    let result = cast(&array, &to_type, options).unwrap();
+    let expected = StructArray::from_data(
+        to_type,
+        vec![
+            Arc::new(Int32Array::from_slice(&[1, 2])),
+            Arc::new(Utf8Array::<i32>::new_null(DataType::Utf8, 2)),
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+
+    // a missing field that is not nullable cannot be filled
+    let to_type = DataType::Struct(vec![Field::new("missing", DataType::Utf8, false)]);
+    assert!(!can_cast_types(array.data_type(), &to_type));
+    assert!(cast(&array, &to_type, options).is_err());
+}
+
+#[test]
+fn utf8_to_i32_whitespace_and_sign() {
+    let array = Utf8Array::<i32>::from(&[
+        Some(" 12 "),
+        Some("+3"),
+        Some("-4\t"),
+        Some(""),
+        Some("   "),
+        Some("+-1"),
+        Some("1 2"),
+        None,
+    ]);
+    let result = cast(&array, &DataType::Int32, CastOptions::default()).unwrap();
+    let expected = Int32Array::from(&[Some(12), Some(3), Some(-4), None, None, None, None, None]);
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let error = cast(&array, &DataType::Int32, options).unwrap_err();
+    assert!(error.to_string().contains("at index 3"));
+    assert!(cast(&array.slice(0, 3), &DataType::Int32, options).is_ok());
+}
+
+#[test]
+fn utf8_to_f64_whitespace_and_sign() {
+    let array = Utf8Array::<i64>::from(&[Some(" +1.5"), Some("-2e3 "), Some(" "), Some("")]);
+    let result = cast(&array, &DataType::Float64, CastOptions::default()).unwrap();
+    let expected = Float64Array::from(&[Some(1.5), Some(-2000.0), None, None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_to_boolean() {
+    let array = Utf8Array::<i32>::from(&[
+        Some("true"),
+        Some("FALSE"),
+        Some(" Yes "),
+        Some("n"),
+        Some("1"),
+        Some("0"),
+        Some("on"),
+        Some("OFF"),
+        Some(""),
+        Some("  "),
+        Some("maybe"),
+        None,
+    ]);
+    assert!(can_cast_types(array.data_type(), &DataType::Boolean));
+    let result = cast(&array, &DataType::Boolean, CastOptions::default()).unwrap();
+    let expected = BooleanArray::from(&[
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        Some(true),
+        Some(false),
+        None,
+        None,
+        None,
+        None,
+    ]);
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+    let error = cast(&array, &DataType::Boolean, options).unwrap_err();
+    assert!(error.to_string().contains("at index 8"));
+
+    let array = Utf8Array::<i64>::from(&[Some("t"), None, Some("F")]);
+    let result = cast(&array, &DataType::Boolean, options).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, Some(false)]);
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn utf8_to_dict() {
     let array = Utf8Array::<i32>::from(&[Some("one"), None, Some("three"), Some("one")]);
@@ -649,6 +1316,105 @@ fn i32_to_dict() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn utf8_to_dict_cast_values() {
+    let array = Utf8Array::<i32>::from(&[Some("1"), None, Some("3"), Some("1"), Some("a")]);
+
+    let cast_type = DataType::Dictionary(i16::KEY_TYPE, Box::new(DataType::Int64), false);
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+
+    let mut expected = MutableDictionaryArray::<i16, MutablePrimitiveArray<i64>>::new();
+    expected
+        .try_extend([Some(1), None, Some(3), Some(1), None])
+        .unwrap();
+    let expected: DictionaryArray<i16> = expected.into();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn date32_to_dict() {
+    let array = Int32Array::from(&[Some(1), None, Some(1)]).to(DataType::Date32);
+
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Date32), false);
+    assert!(can_cast_types(array.data_type(), &cast_type));
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    assert_eq!(result.keys(), &UInt8Array::from(&[Some(0), None, Some(0)]));
+    assert_eq!(result.values().data_type(), &DataType::Date32);
+}
+
+#[test]
+fn float_to_dict_unsupported() {
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Float64), false);
+    assert!(!can_cast_types(&DataType::Float64, &cast_type));
+
+    let array = Float64Array::from_slice(&[1.0]);
+    assert!(cast(&array, &cast_type, CastOptions::default()).is_err());
+}
+
+#[test]
+fn dict_with_null_values_to_utf8() {
+    let values = Utf8Array::<i32>::from(&[Some("one"), None]);
+    let keys = Int32Array::from(&[Some(0), Some(1), None, Some(0)]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).expect("cast failed");
+
+    let expected = Utf8Array::<i32>::from(&[Some("one"), None, None, Some("one")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn dict_to_dict_keys_and_values() {
+    let values = Int32Array::from(&[Some(10), None, Some(30)]);
+    let keys = Int32Array::from(&[Some(2), Some(1), None, Some(0)]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    let cast_type = DataType::Dictionary(i8::KEY_TYPE, Box::new(DataType::Int64), false);
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert_eq!(
+        result.keys(),
+        &Int8Array::from(&[Some(2), Some(1), None, Some(0)])
+    );
+    assert_eq!(
+        result.values().as_ref(),
+        &Int64Array::from(&[Some(10), None, Some(30)]) as &dyn Array
+    );
+
+    let result = cast(result, &DataType::Int64, CastOptions::default()).expect("cast failed");
+    let expected = Int64Array::from(&[Some(30), None, None, Some(10)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn dict_to_dict_keys_overflow() {
+    let values = Int32Array::from_values(0..200);
+    let keys = Int32Array::from_values(0..200);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    let cast_type = DataType::Dictionary(i8::KEY_TYPE, Box::new(DataType::Int32), false);
+    assert!(cast(&array, &cast_type, CastOptions::default()).is_err());
+    let options = CastOptions {
+        wrapped: true,
+        ..Default::default()
+    };
+    assert!(cast(&array, &cast_type, options).is_err());
+
+    let cast_type = DataType::Dictionary(u8::KEY_TYPE, Box::new(DataType::Int32), false);
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+    let result = cast(result.as_ref(), &DataType::Int32, CastOptions::default()).unwrap();
+    assert_eq!(Int32Array::from_values(0..200), result.as_ref());
+}
+
 #[test]
 fn list_to_list() {
     let data = vec![
@@ -673,6 +1439,183 @@ fn list_to_list() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn list_to_large_list() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+        Some(vec![]),
+    ];
+
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data.clone()).unwrap();
+    let array: ListArray<i32> = array.into();
+    let array = array.slice(1, 3);
+
+    let expected_data = data[1..]
+        .iter()
+        .map(|x| x.as_ref().map(|x| x.iter().map(|x| x.map(|x| x as i64))));
+    let mut expected = MutableListArray::<i64, MutablePrimitiveArray<i64>>::new();
+    expected.try_extend(expected_data).unwrap();
+    let expected: ListArray<i64> = expected.into();
+
+    assert!(can_cast_types(array.data_type(), expected.data_type()));
+    let result = cast(&array, expected.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    // and back
+    let result = cast(result.as_ref(), array.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn large_list_to_list_sliced() {
+    let data = vec![
+        Some(vec![Some(1i64), Some(2)]),
+        Some(vec![Some(3)]),
+        None,
+        Some(vec![Some(4), None]),
+    ];
+
+    let mut array = MutableListArray::<i64, MutablePrimitiveArray<i64>>::new();
+    array.try_extend(data.clone()).unwrap();
+    let array: ListArray<i64> = array.into();
+    let array = array.slice(1, 3);
+
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    expected.try_extend(data[1..].iter().cloned()).unwrap();
+    let expected: ListArray<i32> = expected.into();
+
+    let result = cast(&array, expected.data_type(), CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(&expected, result);
+    // the offsets are rebased to the values of the slice
+    assert_eq!(result.offsets().as_slice(), &[0, 1, 1, 3]);
+    assert_eq!(result.values().len(), 3);
+}
+
+#[test]
+fn utf8_to_large_utf8_sliced() {
+    let array = Utf8Array::<i32>::from(&[Some("aa"), None, Some("bbb"), Some("")]);
+    let array = array.slice(1, 3);
+
+    let result = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i64>::from(&[None, Some("bbb"), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let large = Utf8Array::<i64>::from(&[Some("aa"), None, Some("bbb"), Some("")]);
+    let large = large.slice(2, 2);
+    let result = cast(&large, &DataType::Utf8, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(result, &Utf8Array::<i32>::from(&[Some("bbb"), Some("")]));
+    assert_eq!(result.offsets().as_slice(), &[0, 3, 3]);
+}
+
+#[test]
+fn binary_to_large_binary_sliced() {
+    let array = BinaryArray::<i32>::from(&[Some(b"aa".as_ref()), None, Some(b"bbb")]);
+    let array = array.slice(1, 2);
+
+    let result = cast(&array, &DataType::LargeBinary, CastOptions::default()).unwrap();
+    let expected = BinaryArray::<i64>::from(&[None, Some(b"bbb".as_ref())]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(result.as_ref(), &DataType::Binary, CastOptions::default()).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    let large = BinaryArray::<i64>::from(&[Some(b"aa".as_ref()), None, Some(b"bbb")]);
+    let large = large.slice(1, 2);
+    let result = cast(&large, &DataType::Binary, CastOptions::default()).unwrap();
+    let result = result.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 0, 3]);
+    assert_eq!(result.values().as_slice(), b"bbb");
+}
+
+#[test]
+fn binary_to_fixed_size_binary() {
+    let array = BinaryArray::<i32>::from(&[Some(b"ab".as_ref()), None, Some(b"abc"), Some(b"cd")]);
+    let to_type = DataType::FixedSizeBinary(2);
+    assert!(can_cast_types(array.data_type(), &to_type));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected =
+        FixedSizeBinaryArray::from_iter(vec![Some(b"ab".as_ref()), None, None, Some(b"cd")], 2);
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+    assert!(cast(&array, &to_type, options).is_err());
+    let result = cast(&array.slice(0, 2), &to_type, options).unwrap();
+    assert_eq!(expected.slice(0, 2), result.as_ref());
+}
+
+#[test]
+fn binary_to_utf8() {
+    let array = BinaryArray::<i32>::from(&[Some(b"aa".as_ref()), None, Some(b"\xff"), Some(b"b")]);
+
+    let result = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("aa"), None, None, Some("b")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i64>::from(&[Some("aa"), None, None, Some("b")]);
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+    assert!(cast(&array, &DataType::Utf8, options).is_err());
+
+    // valid values are not copied
+    let array = array.slice(3, 1);
+    let result = cast(&array, &DataType::Utf8, options).unwrap();
+    let result = result.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(result, &Utf8Array::<i32>::from_slice(&["b"]));
+    assert_eq!(result.values().as_ptr(), array.values().as_ptr());
+
+    let large = BinaryArray::<i64>::from(&[Some(b"aa".as_ref()), Some(b"\xff")]);
+    assert!(can_cast_types(large.data_type(), &DataType::LargeUtf8));
+    let result = cast(&large, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i64>::from(&[Some("aa"), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_to_binary() {
+    let array = Utf8Array::<i32>::from(&[Some("aa"), None, Some("💖")]).slice(1, 2);
+
+    assert!(can_cast_types(array.data_type(), &DataType::Binary));
+    let result = cast(&array, &DataType::Binary, CastOptions::default()).unwrap();
+    let expected = BinaryArray::<i32>::from(&[None, Some("💖".as_bytes())]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = Utf8Array::<i64>::from(&[Some("aa"), None]);
+    let result = cast(&array, &DataType::LargeBinary, CastOptions::default()).unwrap();
+    let expected = BinaryArray::<i64>::from(&[Some(b"aa".as_ref()), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn fixed_size_binary_to_binary() {
+    let array = FixedSizeBinaryArray::from_iter(
+        vec![Some(b"ab".as_ref()), None, Some(b"cd"), Some(b"ef")],
+        2,
+    );
+    let array = array.slice(1, 3);
+
+    let result = cast(&array, &DataType::Binary, CastOptions::default()).unwrap();
+    let expected = BinaryArray::<i32>::from(&[None, Some(b"cd".as_ref()), Some(b"ef")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(&array, &DataType::LargeBinary, CastOptions::default()).unwrap();
+    let expected = BinaryArray::<i64>::from(&[None, Some(b"cd".as_ref()), Some(b"ef")]);
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn timestamp_with_tz_to_utf8() {
     let tz = "-02:00".to_string();
@@ -710,6 +1653,92 @@ fn utf8_to_naive_timestamp() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn utf8_to_timestamp_with_format() {
+    let array = Utf8Array::<i32>::from(&[Some("19/12/1996 16:39"), None, Some("1996-12-19")]);
+    let options = CastOptions {
+        format: Some("%d/%m/%Y %H:%M"),
+        ..Default::default()
+    };
+
+    let expected = Int64Array::from(&[Some(851013540000000000), None, None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let result = cast(&array, expected.data_type(), options).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    // strict errors with the index and value that cannot be parsed
+    let options = CastOptions {
+        strict: true,
+        ..options
+    };
+    let error = cast(&array, expected.data_type(), options)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("\"1996-12-19\" at index 2"));
+
+    // `None` is not a parsing error
+    let result = cast(&array.slice(0, 2), expected.data_type(), options).unwrap();
+    assert_eq!(expected.slice(0, 2), result.as_ref());
+}
+
+#[test]
+fn utf8_to_timestamp_with_tz_and_format() {
+    let tz = "-02:00".to_string();
+    let array =
+        Utf8Array::<i64>::from_slice(&["19/12/1996 16:39:57 -0200", "19/12/1996 19:39:57 +0000"]);
+    let options = CastOptions {
+        format: Some("%d/%m/%Y %H:%M:%S %z"),
+        ..Default::default()
+    };
+    // the offset of each value is converted to the timezone of the type
+    let expected = Int64Array::from_slice(&[851020797000000000, 851024397000000000])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, Some(tz)));
+
+    let result = cast(&array, expected.data_type(), options).unwrap();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_to_dates_with_format() {
+    let array = Utf8Array::<i32>::from(&[Some("02/01/1970"), Some("1970-01-02"), None]);
+    let options = CastOptions {
+        format: Some("%d/%m/%Y"),
+        ..Default::default()
+    };
+
+    let expected = Int32Array::from(&[Some(1), None, None]).to(DataType::Date32);
+    let result = cast(&array, &DataType::Date32, options).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let expected = Int64Array::from(&[Some(86_400_000), None, None]).to(DataType::Date64);
+    let result = cast(&array, &DataType::Date64, options).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        strict: true,
+        ..options
+    };
+    assert!(cast(&array, &DataType::Date32, options).is_err());
+    assert!(cast(&array, &DataType::Date64, options).is_err());
+}
+
+#[test]
+fn utf8_to_dates_with_owned_format() {
+    // the format does not need to be `'static`, e.g. when read from a configuration
+    let format = ["%d", "%m", "%Y"].join("/");
+    let options = CastOptions {
+        format: Some(&format),
+        ..Default::default()
+    };
+
+    let array = Utf8Array::<i32>::from_slice(["02/01/1970"]);
+    let result = cast(&array, &DataType::Date32, options).unwrap();
+    assert_eq!(
+        Int32Array::from_slice([1]).to(DataType::Date32),
+        result.as_ref()
+    );
+}
+
 #[test]
 fn naive_timestamp_to_utf8() {
     let array = Int64Array::from_slice(&[851013597000000000, 851017197000000000])
@@ -0,0 +1,129 @@
+use arrow2::array::*;
+use arrow2::compute::coalesce::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+
+#[test]
+fn basics() -> Result<()> {
+    let a = Utf8Array::<i32>::from(&[Some("a"), None, None, None, Some("e")]);
+    let b = Utf8Array::<i32>::from(&[Some("A"), Some("B"), None, None, None]);
+    let c = Utf8Array::<i32>::from(&[None, Some("1"), Some("2"), None, Some("4")]);
+
+    let result = coalesce(&[&a, &b, &c])?;
+    let expected = Utf8Array::<i32>::from(&[Some("a"), Some("B"), Some("2"), None, Some("e")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn first_without_nulls() -> Result<()> {
+    let a = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    let b = Utf8Array::<i32>::from(&[None, Some("B")]);
+
+    let result = coalesce(&[&a, &b])?;
+    assert_eq!(a, result.as_ref());
+
+    // a validity without nulls
+    let a = Int32Array::from(&[Some(1), Some(2)]);
+    let b = Int32Array::from(&[None, Some(3)]);
+    let result = coalesce(&[&a, &b])?;
+    assert_eq!(a, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn later_without_nulls() -> Result<()> {
+    let a = Utf8Array::<i32>::from(&[None, Some("b"), None]);
+    let b = Utf8Array::<i32>::from_slice(&["A", "B", "C"]);
+    let c = Utf8Array::<i32>::from(&[Some("1"), None, Some("3")]);
+
+    let result = coalesce(&[&a, &b, &c])?;
+    let expected = Utf8Array::<i32>::from_slice(&["A", "b", "C"]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(result.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn all_null() -> Result<()> {
+    let a = Int32Array::from(&[None, None, Some(3)]);
+    let b = Int32Array::from(&[None, None, None]);
+    let c = Int32Array::from(&[None, Some(8), None]);
+
+    let result = coalesce(&[&a, &b, &c])?;
+    let expected = Int32Array::from(&[None, Some(8), Some(3)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = coalesce(&[&b, &b])?;
+    assert_eq!(b, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn primitive() -> Result<()> {
+    let a = Int64Array::from(&[Some(1), None, None, Some(4)]).to(DataType::Date64);
+    let b = Int64Array::from(&[Some(5), Some(6), None, None]).to(DataType::Date64);
+
+    let expected = Int64Array::from(&[Some(1), Some(6), None, Some(4)]).to(DataType::Date64);
+    assert_eq!(expected, coalesce(&[&a, &b])?.as_ref());
+    assert_eq!(expected, coalesce_primitive(&a, &b)?);
+
+    let b = Int64Array::from_slice(&[5, 6, 7, 8]).to(DataType::Date64);
+    let expected = Int64Array::from_slice(&[1, 6, 7, 4]).to(DataType::Date64);
+    let result = coalesce_primitive(&a, &b)?;
+    assert_eq!(expected, result);
+    assert_eq!(result.validity(), None);
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let a = Int32Array::from(&[Some(0), None, Some(2), None, None, Some(5)]).slice(1, 4);
+    let b = Int32Array::from(&[None, Some(10), None, None, Some(40), None]).slice(1, 4);
+    let c = Int32Array::from(&[Some(0), None, None, None, Some(300), None]).slice(2, 4);
+
+    // primitive fast path
+    let result = coalesce(&[&a, &b])?;
+    let expected = Int32Array::from(&[Some(10), Some(2), None, Some(40)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = coalesce(&[&a, &b, &c])?;
+    let expected = Int32Array::from(&[Some(10), Some(2), Some(300), Some(40)]);
+    assert_eq!(expected, result.as_ref());
+
+    let a = Utf8Array::<i32>::from(&[Some("x"), None, Some("c"), None]).slice(1, 3);
+    let b = Utf8Array::<i32>::from(&[Some("y"), Some("B"), None, None]).slice(1, 3);
+    let result = coalesce(&[&a, &b])?;
+    let expected = Utf8Array::<i32>::from(&[Some("B"), Some("c"), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn list() -> Result<()> {
+    let to_list = |data: Vec<Option<Vec<Option<i32>>>>| -> Result<ListArray<i32>> {
+        let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(data)?;
+        Ok(array.into())
+    };
+    let a = to_list(vec![None, Some(vec![Some(1)]), None])?;
+    let b = to_list(vec![Some(vec![]), Some(vec![Some(2)]), None])?;
+
+    let result = coalesce(&[&a, &b])?;
+    let expected = to_list(vec![Some(vec![]), Some(vec![Some(1)]), None])?;
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn invalid_arguments() {
+    assert!(coalesce(&[]).is_err());
+
+    let a = Int32Array::from_slice(&[1, 2]);
+    let b = Int64Array::from_slice(&[1, 2]);
+    assert!(coalesce(&[&a, &b]).is_err());
+
+    let b = Int32Array::from_slice(&[1, 2, 3]);
+    assert!(coalesce(&[&a, &b]).is_err());
+    assert!(coalesce_primitive(&a, &b).is_err());
+}
@@ -3,6 +3,7 @@ use arrow2::compute::comparison::{self, boolean::*};
 use arrow2::datatypes::{DataType::*, IntervalUnit};
 use arrow2::datatypes::{IntegerType, TimeUnit};
 use arrow2::scalar::new_scalar;
+use std::sync::Arc;
 
 #[test]
 fn consistency() {
@@ -18,11 +19,13 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Interval(IntervalUnit::YearMonth),
         Interval(IntervalUnit::MonthDayNano),
         Interval(IntervalUnit::DayTime),
+        Decimal256(40, 2),
         Timestamp(TimeUnit::Second, None),
         Timestamp(TimeUnit::Millisecond, None),
         Timestamp(TimeUnit::Microsecond, None),
@@ -42,6 +45,8 @@ fn consistency() {
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
         Dictionary(IntegerType::Int32, Box::new(LargeBinary), false),
+        List(Box::new(arrow2::datatypes::Field::new("item", Int32, true))),
+        Struct(vec![arrow2::datatypes::Field::new("a", Int32, true)]),
     ];
 
     // array <> array
@@ -271,7 +276,7 @@ mod tests {
 
         // now mask with a null
         let mask = Bitmap::from_iter([false, true, true]);
-        let a_masked = a.with_validity(Some(mask));
+        let a_masked = a.clone().with_validity(Some(mask));
         let out = comparison::utf8::eq_and_validity(&a, &a_masked);
         check_mask(&out, &[false, true, true]);
 
@@ -312,7 +317,7 @@ mod tests {
 
         // now mask with a null
         let mask = Bitmap::from_iter([false, true, true]);
-        let a_masked = a.with_validity(Some(mask));
+        let a_masked = a.clone().with_validity(Some(mask));
         let out = comparison::utf8::neq_and_validity(&a, &a_masked);
         check_mask(&out, &[true, false, false]);
 
@@ -328,3 +333,283 @@ mod tests {
         check_mask(&out, &[false, true, true]);
     }
 }
+
+fn list_array(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn list_eq_neq() {
+    let a = list_array(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), None]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(1)]),
+    ]);
+    let b = list_array(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), None]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), Some(1)]),
+    ]);
+
+    let expected = BooleanArray::from([Some(true), Some(true), Some(false), None, Some(false)]);
+    assert_eq!(comparison::eq(&a, &b), expected);
+    assert_eq!(comparison::nested::list_eq(&a, &b), expected);
+
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), None, Some(true)]);
+    assert_eq!(comparison::neq(&a, &b), expected);
+}
+
+#[test]
+fn list_eq_sliced() {
+    // the children of both arrays have different offsets
+    let a = list_array(vec![
+        Some(vec![Some(0), Some(0), Some(0)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3)]),
+    ])
+    .slice(1, 2);
+    let b = list_array(vec![Some(vec![Some(1), Some(2)]), Some(vec![Some(4)])]);
+
+    let expected = BooleanArray::from([Some(true), Some(false)]);
+    assert_eq!(comparison::eq(&a, &b), expected);
+    assert_eq!(comparison::eq(&b, &a), expected);
+}
+
+#[test]
+fn fixed_size_list_eq_neq() {
+    let data_type = FixedSizeList(
+        Box::new(arrow2::datatypes::Field::new("item", Int32, true)),
+        2,
+    );
+    let values = Arc::new(Int32Array::from([
+        Some(1),
+        Some(2),
+        Some(3),
+        None,
+        Some(5),
+        Some(6),
+        Some(1),
+        Some(2),
+    ])) as Arc<dyn Array>;
+    let a = FixedSizeListArray::from_data(data_type.clone(), values, None);
+    // [[1, 2], [3, None], [5, 6], [1, 2]] sliced to [[3, None], [5, 6], [1, 2]]
+    let a = a.slice(1, 3);
+
+    let values = Arc::new(Int32Array::from([
+        Some(3),
+        None,
+        Some(5),
+        Some(7),
+        Some(1),
+        Some(2),
+    ])) as Arc<dyn Array>;
+    let b = FixedSizeListArray::from_data(
+        data_type,
+        values,
+        Some(arrow2::bitmap::Bitmap::from([true, true, false])),
+    );
+
+    let result = comparison::eq(&a, &b);
+    assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+    let result = comparison::neq(&a, &b);
+    assert_eq!(result, BooleanArray::from([Some(false), Some(true), None]));
+}
+
+#[test]
+fn struct_eq_neq() {
+    let fields = vec![
+        arrow2::datatypes::Field::new("a", Int32, true),
+        arrow2::datatypes::Field::new("b", Utf8, true),
+    ];
+    let a = StructArray::from_data(
+        Struct(fields.clone()),
+        vec![
+            Arc::new(Int32Array::from([Some(0), Some(1), Some(2), None, Some(4)])),
+            Arc::new(Utf8Array::<i32>::from([
+                Some("x"),
+                Some("a"),
+                Some("b"),
+                None,
+                Some("d"),
+            ])),
+        ],
+        Some(arrow2::bitmap::Bitmap::from([
+            true, true, true, true, false,
+        ])),
+    )
+    .slice(1, 4);
+    let b = StructArray::from_data(
+        Struct(fields),
+        vec![
+            Arc::new(Int32Array::from([Some(1), Some(2), None, Some(4)])),
+            Arc::new(Utf8Array::<i32>::from([
+                Some("a"),
+                Some("c"),
+                None,
+                Some("d"),
+            ])),
+        ],
+        None,
+    );
+
+    let result = comparison::eq(&a, &b);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), Some(true), None])
+    );
+    let result = comparison::neq(&a, &b);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(true), Some(false), None])
+    );
+}
+
+#[test]
+fn struct_nested_eq_neq() {
+    let list = list_array(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), None]),
+        None,
+    ]);
+    let fields = vec![
+        arrow2::datatypes::Field::new("a", list.data_type().clone(), true),
+        arrow2::datatypes::Field::new("b", Int32, true),
+    ];
+    let a = StructArray::from_data(
+        Struct(fields.clone()),
+        vec![
+            Arc::new(list),
+            Arc::new(Int32Array::from([Some(1), Some(2), None])),
+        ],
+        None,
+    );
+    let list = list_array(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), Some(3)]),
+        None,
+    ]);
+    let b = StructArray::from_data(
+        Struct(fields),
+        vec![
+            Arc::new(list),
+            Arc::new(Int32Array::from([Some(1), Some(2), None])),
+        ],
+        None,
+    );
+
+    let result = comparison::eq(&a, &b);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), Some(true)])
+    );
+    let result = comparison::neq(&a, &b);
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(true), Some(false)])
+    );
+}
+
+#[test]
+fn list_eq_different_item_names() {
+    let a = list_array(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        None,
+    ]);
+    let b = list_array(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(2)]),
+        None,
+    ]);
+    let data_type = List(Box::new(arrow2::datatypes::Field::new(
+        "element", Int32, true,
+    )));
+    let b = ListArray::<i32>::from_data(
+        data_type,
+        b.offsets().clone(),
+        b.values().clone(),
+        b.validity().cloned(),
+    );
+    // wrap both in another list so that the difference is in the items of the values
+    let a = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(a.data_type().clone()),
+        vec![0, 1, 2, 3].into(),
+        Arc::new(a),
+        None,
+    );
+    let b = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(b.data_type().clone()),
+        vec![0, 1, 2, 3].into(),
+        Arc::new(b),
+        None,
+    );
+
+    let expected = BooleanArray::from([Some(true), Some(false), Some(true)]);
+    assert_eq!(comparison::eq(&a, &b), expected);
+    assert_eq!(comparison::eq_and_validity(&b, &a), expected);
+    let expected = BooleanArray::from([Some(false), Some(true), Some(false)]);
+    assert_eq!(comparison::neq(&a, &b), expected);
+}
+
+#[test]
+#[should_panic]
+fn struct_eq_different_field_names() {
+    let a = StructArray::from_data(
+        Struct(vec![arrow2::datatypes::Field::new("a", Int32, true)]),
+        vec![Arc::new(Int32Array::from([Some(1)]))],
+        None,
+    );
+    let b = StructArray::from_data(
+        Struct(vec![arrow2::datatypes::Field::new("b", Int32, true)]),
+        vec![Arc::new(Int32Array::from([Some(1)]))],
+        None,
+    );
+    comparison::eq(&a, &b);
+}
+
+#[test]
+fn decimal256() {
+    use arrow2::types::i256;
+
+    let big = i256::from(10).checked_pow(60).unwrap();
+    let data_type = Decimal256(76, 0);
+    let lhs =
+        Int256Array::from_slice(&[big, i256::from(1), big.wrapping_neg()]).to(data_type.clone());
+    let rhs = Int256Array::from_slice(&[big, big, i256::from(-1)]).to(data_type);
+
+    assert_eq!(
+        comparison::eq(&lhs, &rhs),
+        BooleanArray::from_slice(&[true, false, false])
+    );
+    assert_eq!(
+        comparison::lt(&lhs, &rhs),
+        BooleanArray::from_slice(&[false, true, true])
+    );
+}
+
+#[test]
+fn float16() {
+    use arrow2::types::f16;
+
+    let lhs = Float16Array::from_slice(&[f16::ONE, f16::from_f32(-0.0), f16::NAN, f16::MIN]);
+    let rhs = Float16Array::from_slice(&[f16::ONE, f16::ZERO, f16::NAN, f16::from_f32(-1.0)]);
+
+    assert_eq!(
+        comparison::eq(&lhs, &rhs),
+        BooleanArray::from_slice(&[true, true, false, false])
+    );
+    assert_eq!(
+        comparison::lt(&lhs, &rhs),
+        BooleanArray::from_slice(&[false, false, false, true])
+    );
+    assert_eq!(
+        comparison::primitive::gt_eq_scalar(&lhs, f16::ZERO),
+        BooleanArray::from_slice(&[true, true, false, false])
+    );
+}
@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
+use arrow2::buffer::Buffer;
 use arrow2::compute::concatenate::concatenate;
+use arrow2::datatypes::{DataType, Field, UnionMode};
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +119,278 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+fn dictionary<K: DictionaryKey>(values: &[Option<&str>]) -> Result<DictionaryArray<K>> {
+    let mut array = MutableDictionaryArray::<K, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().copied())?;
+    Ok(array.into())
+}
+
+fn dictionary_values<K: DictionaryKey>(array: &dyn Array) -> Vec<Option<String>> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    array
+        .keys()
+        .iter()
+        .map(|key| {
+            let key = key?.to_usize().unwrap();
+            values.is_valid(key).then(|| values.value(key).to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn dictionary_overlapping() -> Result<()> {
+    let a = dictionary::<i32>(&[Some("a"), Some("b"), None, Some("a")])?;
+    let b = dictionary::<i32>(&[Some("c"), Some("b"), None])?;
+
+    let result = concatenate(&[&a, &b])?;
+
+    let dictionary = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(
+        dictionary.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(&["a", "b", "c"]) as &dyn Array
+    );
+    assert_eq!(
+        dictionary.keys(),
+        &Int32Array::from(&[Some(0), Some(1), None, Some(0), Some(2), Some(1), None])
+    );
+    Ok(())
+}
+
+#[test]
+fn dictionary_disjoint() -> Result<()> {
+    let a = dictionary::<u8>(&[Some("a"), None])?;
+    let b = dictionary::<u8>(&[None, Some("b"), Some("c")])?;
+
+    let result = concatenate(&[&a, &b])?;
+
+    assert_eq!(
+        dictionary_values::<u8>(result.as_ref()),
+        vec![
+            Some("a".to_string()),
+            None,
+            None,
+            Some("b".to_string()),
+            Some("c".to_string())
+        ]
+    );
+    let dictionary = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    assert_eq!(dictionary.values().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn dictionary_identical() -> Result<()> {
+    let a = dictionary::<i32>(&[Some("a"), Some("b"), None])?;
+    let b = a.slice(1, 2);
+
+    let result = concatenate(&[&a, &b])?;
+
+    let dictionary = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    // the values are re-used
+    assert!(Arc::ptr_eq(dictionary.values(), a.values()));
+    assert_eq!(
+        dictionary.keys(),
+        &Int32Array::from(&[Some(0), Some(1), None, Some(1), None])
+    );
+    Ok(())
+}
+
+#[test]
+fn dictionary_key_overflow() -> Result<()> {
+    let values = (0..200).map(|x| x.to_string()).collect::<Vec<_>>();
+    let values = values.iter().map(|x| Some(x.as_str())).collect::<Vec<_>>();
+    let a = dictionary::<i8>(&values[..100])?;
+    let b = dictionary::<i8>(&values[100..])?;
+
+    let error = concatenate(&[&a, &b]).unwrap_err();
+    assert!(error.to_string().contains("wider key type"));
+    Ok(())
+}
+
+fn union_fields() -> Vec<Field> {
+    vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]
+}
+
+#[test]
+fn sparse_union() -> Result<()> {
+    let data_type = DataType::Union(union_fields(), None, UnionMode::Sparse);
+    let a = UnionArray::from_data(
+        data_type.clone(),
+        Buffer::from_slice([0, 1]),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, Some("a")])) as Arc<dyn Array>,
+        ],
+        None,
+    );
+    let b = UnionArray::from_data(
+        data_type.clone(),
+        Buffer::from_slice([1]),
+        vec![
+            Arc::new(Int32Array::from(&[None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[Some("b")])) as Arc<dyn Array>,
+        ],
+        None,
+    );
+
+    let result = concatenate(&[&a, &b.slice(0, 1), &a.slice(1, 1)])?;
+
+    let expected = UnionArray::from_data(
+        data_type,
+        Buffer::from_slice([0, 1, 1, 1]),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None, None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[
+                None,
+                Some("a"),
+                Some("b"),
+                Some("a"),
+            ])) as Arc<dyn Array>,
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn dense_union() -> Result<()> {
+    let data_type = DataType::Union(union_fields(), None, UnionMode::Dense);
+    let a = UnionArray::from_data(
+        data_type.clone(),
+        Buffer::from_slice([0, 1, 0]),
+        vec![
+            Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&["a"])) as Arc<dyn Array>,
+        ],
+        Some(Buffer::from_slice([0, 0, 1])),
+    );
+    let b = UnionArray::from_data(
+        data_type.clone(),
+        Buffer::from_slice([1, 1]),
+        vec![
+            Arc::new(Int32Array::from_slice(&[])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&["b", "c"])) as Arc<dyn Array>,
+        ],
+        Some(Buffer::from_slice([0, 1])),
+    );
+
+    let result = concatenate(&[&a, &b])?;
+    let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+
+    assert_eq!(result.types().as_slice(), &[0, 1, 0, 1, 1]);
+    assert_eq!(
+        result.offsets().as_ref().unwrap().as_slice(),
+        &[0, 0, 1, 1, 2]
+    );
+    assert_eq!(
+        result.fields()[0].as_ref(),
+        &Int32Array::from(&[Some(1), None]) as &dyn Array
+    );
+    assert_eq!(
+        result.fields()[1].as_ref(),
+        &Utf8Array::<i32>::from_slice(&["a", "b", "c"]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn map() -> Result<()> {
+    let data_type = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    );
+    let new = || {
+        MutableMapArray::new(
+            data_type.clone(),
+            Box::new(MutableUtf8Array::<i32>::new()),
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+        )
+    };
+    let push = |array: &mut MutableMapArray, key: &str, value: Option<i32>| {
+        array.keys::<MutableUtf8Array<i32>>().push(Some(key));
+        array.values::<MutablePrimitiveArray<i32>>().push(value);
+        array.try_push_entry()
+    };
+
+    let mut a = new();
+    push(&mut a, "a", Some(1))?;
+    a.try_push_valid()?;
+    a.push_null();
+    let a: MapArray = a.into();
+
+    let mut b = new();
+    push(&mut b, "b", None)?;
+    push(&mut b, "c", Some(3))?;
+    b.try_push_valid()?;
+    let b: MapArray = b.into();
+
+    let result = concatenate(&[&a, &b])?;
+
+    let mut expected = new();
+    push(&mut expected, "a", Some(1))?;
+    expected.try_push_valid()?;
+    expected.push_null();
+    push(&mut expected, "b", None)?;
+    push(&mut expected, "c", Some(3))?;
+    expected.try_push_valid()?;
+    let expected: MapArray = expected.into();
+
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn decimal256() -> Result<()> {
+    use arrow2::types::i256;
+
+    let data_type = DataType::Decimal256(76, 2);
+    let big = i256::from(10).checked_pow(60).unwrap();
+    let lhs = Int256Array::from(&[Some(big), None]).to(data_type.clone());
+    let rhs = Int256Array::from(&[Some(i256::from(-1))]).to(data_type.clone());
+
+    let result = concatenate(&[&lhs, &rhs.slice(0, 1)])?;
+
+    let expected = Int256Array::from(&[Some(big), None, Some(i256::from(-1))]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn float16() -> Result<()> {
+    use arrow2::types::f16;
+
+    let lhs = Float16Array::from(&[Some(f16::ONE), None]);
+    let rhs = Float16Array::from(&[Some(f16::MIN), Some(f16::MAX)]);
+
+    let result = concatenate(&[&lhs, &rhs.slice(1, 1)])?;
+
+    let expected = Float16Array::from(&[Some(f16::ONE), None, Some(f16::MAX)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
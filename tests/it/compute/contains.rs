@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::contains::contains;
+use arrow2::compute::contains::{contains, is_in, is_in_with, IsInOptions};
+use arrow2::datatypes::DataType;
 
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
@@ -59,3 +60,107 @@ fn test_contains_binary() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn is_in_primitive() {
+    let values = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+    let set = Int32Array::from_slice(&[4, 1, 3]);
+
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn is_in_set_with_nulls() {
+    let values = Int32Array::from(&[Some(1), Some(2), None]);
+    let set = Int32Array::from(&[Some(1), None]);
+
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, None]);
+    assert_eq!(result, expected);
+
+    let options = IsInOptions {
+        ignore_set_nulls: true,
+    };
+    let result = is_in_with(&values, &set, options).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn is_in_float() {
+    let values = Float64Array::from_slice(&[f64::NAN, -0.0, 1.0, 2.0]);
+    let set = Float64Array::from_slice(&[f64::NAN, 0.0, 2.0]);
+
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from_slice(&[false, true, false, true]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn is_in_utf8() {
+    let values = Utf8Array::<i64>::from(&[Some("a"), Some("bb"), None, Some("")]);
+    let set = Utf8Array::<i64>::from_slice(&["", "a", "c"]);
+
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn is_in_binary() {
+    let values = BinaryArray::<i32>::from(&[Some(b"a".as_ref()), Some(b"b"), None]);
+    let set = BinaryArray::<i32>::from(&[Some(b"b".as_ref()), None]);
+
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from(&[None, Some(true), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn is_in_sliced() {
+    let values = Int32Array::from_slice(&[1, 2, 3, 4]).slice(1, 3);
+    let set = Int32Array::from_slice(&[1, 2, 3]).slice(1, 1);
+
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from_slice(&[true, false, false]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn is_in_dictionary() {
+    let mut values = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    values
+        .try_extend(vec![Some("a"), None, Some("b"), Some("a"), Some("c")])
+        .unwrap();
+    let values: DictionaryArray<i32> = values.into();
+    let set = Utf8Array::<i32>::from_slice(&["a", "c"]);
+
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, Some(false), Some(true), Some(true)]);
+    assert_eq!(result, expected);
+
+    let set = Utf8Array::<i32>::from(&[Some("a"), None]);
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from(&[Some(true), None, None, Some(true), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn is_in_logical_types() {
+    let values = Int32Array::from_slice(&[1, 2]).to(DataType::Date32);
+    let set = Int32Array::from_slice(&[2]).to(DataType::Date32);
+    let result = is_in(&values, &set).unwrap();
+    assert_eq!(result, BooleanArray::from_slice(&[false, true]));
+}
+
+#[test]
+fn is_in_different_types() {
+    let values = Int32Array::from_slice(&[1, 2]);
+    let set = Int64Array::from_slice(&[1]);
+    assert!(is_in(&values, &set).is_err());
+
+    let set = Int32Array::from_slice(&[1]).to(DataType::Date32);
+    assert!(is_in(&values, &set).is_err());
+}
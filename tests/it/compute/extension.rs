@@ -0,0 +1,192 @@
+//! Asserts that the extension data type of an array is kept through compute kernels.
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::{aggregate, cast, concatenate::concatenate, filter::filter, take::take};
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+use arrow2::scalar::PrimitiveScalar;
+
+fn uuid_type() -> DataType {
+    DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        None,
+    )
+}
+
+fn uuids() -> FixedSizeBinaryArray {
+    let values = [Some([1u8; 16]), None, Some([2u8; 16]), Some([3u8; 16])];
+    FixedSizeBinaryArray::from_iter(values, 16).to(uuid_type())
+}
+
+fn id_type() -> DataType {
+    DataType::Extension("id".to_string(), Box::new(DataType::Int32), None)
+}
+
+fn ids() -> Int32Array {
+    Int32Array::from(&[Some(5), None, Some(1), Some(3)]).to(id_type())
+}
+
+fn label_type() -> DataType {
+    DataType::Extension("label".to_string(), Box::new(DataType::Utf8), None)
+}
+
+fn labels() -> Utf8Array<i32> {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("c"), Some("d")]);
+    Utf8Array::<i32>::from_data(
+        label_type(),
+        array.offsets().clone(),
+        array.values().clone(),
+        array.validity().cloned(),
+    )
+}
+
+fn categories() -> DictionaryArray<i32> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["x", "y"])) as Arc<dyn Array>;
+    let keys = Int32Array::from(&[Some(1), None, Some(0), Some(1)]);
+    let array = DictionaryArray::<i32>::from_data(keys, values);
+    let data_type = DataType::Extension(
+        "category".to_string(),
+        Box::new(array.data_type().clone()),
+        None,
+    );
+    array.to(data_type)
+}
+
+fn points_type() -> DataType {
+    let fields = vec![
+        Field::new("x", DataType::Int32, true),
+        Field::new("y", id_type(), true),
+    ];
+    DataType::Extension(
+        "point".to_string(),
+        Box::new(DataType::Struct(fields)),
+        None,
+    )
+}
+
+fn points() -> StructArray {
+    StructArray::from_data(
+        points_type(),
+        vec![
+            Arc::new(Int32Array::from_slice(&[1, 2, 3, 4])),
+            Arc::new(ids()),
+        ],
+        None,
+    )
+}
+
+fn columns() -> Vec<Box<dyn Array>> {
+    vec![
+        Box::new(uuids()),
+        Box::new(ids()),
+        Box::new(labels()),
+        Box::new(categories()),
+        Box::new(points()),
+    ]
+}
+
+#[test]
+fn take_filter_concatenate() -> Result<()> {
+    let indices = UInt32Array::from(&[Some(3), None, Some(0), Some(2)]);
+    let mask = BooleanArray::from_slice(&[true, false, true, true]);
+
+    for array in columns() {
+        let data_type = array.data_type().clone();
+
+        let taken = take(array.as_ref(), &indices)?;
+        assert_eq!(taken.data_type(), &data_type);
+
+        let filtered = filter(taken.as_ref(), &mask)?;
+        assert_eq!(filtered.data_type(), &data_type);
+        assert_eq!(filtered.len(), 3);
+
+        let result = concatenate(&[filtered.as_ref(), array.as_ref()])?;
+        assert_eq!(result.data_type(), &data_type);
+        assert_eq!(result.len(), 7);
+        assert_eq!(result.slice(3, 4).as_ref(), array.as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn cast_storage() -> Result<()> {
+    for array in columns() {
+        let data_type = array.data_type().clone();
+        let storage = data_type.to_logical_type().clone();
+
+        let result = cast::cast(array.as_ref(), &storage, Default::default())?;
+        assert_eq!(result.data_type(), &storage);
+
+        let result = cast::cast(result.as_ref(), &data_type, Default::default())?;
+        assert_eq!(result.as_ref(), array.as_ref());
+    }
+
+    // casts through the storage type
+    let result = cast::cast(&ids(), &DataType::Int64, Default::default())?;
+    assert_eq!(
+        result.as_ref(),
+        &Int64Array::from(&[Some(5), None, Some(1), Some(3)]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn min_max() -> Result<()> {
+    let array = ids();
+    let expected = PrimitiveScalar::<i32>::new(id_type(), Some(1));
+    assert_eq!(
+        aggregate::min(&array)?.as_ref(),
+        &expected as &dyn arrow2::scalar::Scalar
+    );
+    let expected = PrimitiveScalar::<i32>::new(id_type(), Some(5));
+    assert_eq!(
+        aggregate::max(&array)?.as_ref(),
+        &expected as &dyn arrow2::scalar::Scalar
+    );
+
+    let array = labels();
+    assert!(aggregate::min(&array).is_ok());
+    Ok(())
+}
+
+#[test]
+fn equality() {
+    let array = uuids();
+    let storage = array.clone().to(DataType::FixedSizeBinary(16));
+    assert!(&array as &dyn Array != &storage as &dyn Array);
+    assert!(array != storage);
+
+    // the metadata of an extension type is not part of its equality
+    let other_metadata = DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        Some("v2".to_string()),
+    );
+    let other = array.clone().to(other_metadata);
+    assert!(array == other);
+
+    let other_name = DataType::Extension(
+        "guid".to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        None,
+    );
+    let other = array.clone().to(other_name);
+    assert!(array != other);
+}
+
+#[test]
+fn new_empty_null() {
+    for array in columns() {
+        let data_type = array.data_type().clone();
+
+        let empty = new_empty_array(data_type.clone());
+        assert_eq!(empty.data_type(), &data_type);
+        assert_eq!(empty.len(), 0);
+
+        let null = new_null_array(data_type.clone(), 2);
+        assert_eq!(null.data_type(), &data_type);
+        assert_eq!(null.len(), 2);
+    }
+}
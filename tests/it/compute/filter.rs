@@ -1,6 +1,11 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
+use arrow2::buffer::Buffer;
 use arrow2::compute::filter::*;
+use arrow2::datatypes::{DataType, Field, UnionMode};
+use arrow2::error::Result;
 
 #[test]
 fn array_slice() {
@@ -128,6 +133,92 @@ fn masked_true_values() {
     assert_eq!(expected, c.as_ref());
 }
 
+fn union(mode: UnionMode) -> UnionArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, mode);
+    let types = Buffer::from_slice([0, 1, 0, 1]);
+    let (fields, offsets) = if mode.is_sparse() {
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None, None, None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(&[None, Some("b"), None, Some("d")])) as Arc<dyn Array>,
+        ];
+        (fields, None)
+    } else {
+        let fields = vec![
+            Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(&["b", "d"])) as Arc<dyn Array>,
+        ];
+        (fields, Some(Buffer::from_slice([0, 0, 1, 1])))
+    };
+    UnionArray::from_data(data_type, types, fields, offsets)
+}
+
+#[test]
+fn union_array() {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let a = union(mode);
+        let b = BooleanArray::from_slice(&[false, true, true, false]);
+        let c = filter(&a, &b).unwrap();
+        let c = c.as_any().downcast_ref::<UnionArray>().unwrap();
+
+        assert_eq!(c.types().as_slice(), &[1, 0]);
+        assert_eq!(c.value(0).as_ref(), a.value(1).as_ref());
+        assert_eq!(c.value(1).as_ref(), a.value(2).as_ref());
+        assert!(!c.value(1).is_valid());
+
+        // filtering a slice
+        let c = filter(&a.slice(1, 3), &b.slice(1, 3)).unwrap();
+        let c = c.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(c.value(0).as_ref(), a.value(1).as_ref());
+        assert_eq!(c.value(1).as_ref(), a.value(2).as_ref());
+    }
+}
+
+#[test]
+fn map_array() -> Result<()> {
+    let data_type = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    );
+    let mut a = MutableMapArray::new(
+        data_type.clone(),
+        Box::new(MutableUtf8Array::<i32>::new()),
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+    );
+    for (key, value) in [("a", Some(1)), ("b", None)] {
+        a.keys::<MutableUtf8Array<i32>>().push(Some(key));
+        a.values::<MutablePrimitiveArray<i32>>().push(value);
+        a.try_push_entry()?;
+    }
+    a.try_push_valid()?;
+    a.push_null();
+    a.keys::<MutableUtf8Array<i32>>().push(Some("c"));
+    a.values::<MutablePrimitiveArray<i32>>().push(Some(3));
+    a.try_push_entry()?;
+    a.try_push_valid()?;
+    let a: MapArray = a.into();
+
+    let b = BooleanArray::from_slice(&[false, true, true]);
+    let c = filter(&a, &b)?;
+    let c = c.as_any().downcast_ref::<MapArray>().unwrap();
+
+    assert_eq!(c.data_type(), &data_type);
+    assert_eq!(c.offsets().as_slice(), &[0, 0, 1]);
+    assert_eq!(c.validity(), Some(&Bitmap::from([false, true])));
+    assert_eq!(c.value(1).as_ref(), a.value(2).as_ref());
+    Ok(())
+}
+
 /*
 #[test]
 fn dictionary_array() {
@@ -193,3 +284,33 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn decimal256() -> Result<()> {
+    use arrow2::types::i256;
+
+    let data_type = DataType::Decimal256(76, 2);
+    let big = i256::from(10).checked_pow(60).unwrap();
+    let array = Int256Array::from(&[Some(big), None, Some(i256::from(-1))]).to(data_type.clone());
+    let mask = BooleanArray::from_slice(&[true, true, false]);
+
+    let result = filter(&array, &mask)?;
+
+    let expected = Int256Array::from(&[Some(big), None]).to(data_type);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn float16() -> Result<()> {
+    use arrow2::types::f16;
+
+    let array = Float16Array::from(&[Some(f16::ONE), None, Some(f16::MAX)]);
+    let mask = BooleanArray::from_slice(&[false, true, true]);
+
+    let result = filter(&array, &mask)?;
+
+    let expected = Float16Array::from(&[None, Some(f16::MAX)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
@@ -1,7 +1,11 @@
-use arrow2::array::new_null_array;
+use std::sync::Arc;
+
+use proptest::prelude::*;
+
+use arrow2::array::*;
 use arrow2::compute::hash::*;
 use arrow2::datatypes::DataType::*;
-use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{Field, TimeUnit};
 
 #[test]
 fn consistency() {
@@ -16,6 +20,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -47,3 +52,200 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn null_hash() {
+    let array = Int32Array::from(&[Some(1), None]);
+    let result = hash(&array).unwrap();
+    assert_eq!(result.validity(), array.validity());
+    assert_eq!(result.values()[1], NULL_HASH);
+
+    let array = new_null_array(Utf8, 2);
+    let result = hash(array.as_ref()).unwrap();
+    assert_eq!(result.values().as_slice(), &[NULL_HASH, NULL_HASH]);
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i32>::from_slice(&["a", "b", "c"]);
+    let expected = hash(&Utf8Array::<i32>::from_slice(&["b", "c"])).unwrap();
+
+    assert_eq!(hash(&array.slice(1, 2)).unwrap(), expected);
+}
+
+#[test]
+fn float_zeros() {
+    let array = Float64Array::from_slice(&[0.0, -0.0]);
+    let result = hash(&array).unwrap();
+
+    assert_eq!(result.value(0), result.value(1));
+}
+
+#[test]
+fn dictionary_like_values() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![Some("b"), None, Some("a"), Some("b")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+    let decoded = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("b")]);
+
+    assert_eq!(hash(&array).unwrap(), hash(&decoded).unwrap());
+}
+
+#[test]
+fn list() {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(2), Some(1)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![None]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let result = hash(&array).unwrap();
+    assert_eq!(result.value(0), result.value(4));
+    assert_ne!(result.value(0), result.value(1));
+    assert_ne!(result.value(3), result.value(5));
+    assert!(result.is_null(2));
+
+    // slicing does not change the hash of a slot
+    let sliced = hash(&array.slice(4, 2)).unwrap();
+    assert_eq!(sliced.value(0), result.value(4));
+}
+
+#[test]
+fn fixed_size() {
+    let array = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), None, Some(b"ab")], 2);
+    let result = hash(&array).unwrap();
+    assert_eq!(result.value(0), result.value(2));
+    assert_eq!(result.values()[1], NULL_HASH);
+
+    let values = Arc::new(Int32Array::from_slice(&[1, 2, 3, 4, 1, 2]));
+    let data_type = FixedSizeListArray::default_datatype(Int32, 2);
+    let array = FixedSizeListArray::from_data(data_type, values, None);
+    let result = hash(&array).unwrap();
+    assert_eq!(result.value(0), result.value(2));
+    assert_ne!(result.value(0), result.value(1));
+}
+
+#[test]
+fn combine() {
+    let a = Int32Array::from_slice(&[1, 1, 2]);
+    let b = Utf8Array::<i32>::from_slice(&["a", "b", "a"]);
+
+    let mut hashes = vec![0; 3];
+    hash_combine(&a, &mut hashes).unwrap();
+    hash_combine(&b, &mut hashes).unwrap();
+
+    // equals hashing the equivalent struct
+    let fields = vec![Field::new("a", Int32, false), Field::new("b", Utf8, false)];
+    let array = StructArray::from_data(Struct(fields), vec![Arc::new(a), Arc::new(b)], None);
+    assert_eq!(hash(&array).unwrap().values().as_slice(), hashes.as_slice());
+
+    assert_ne!(hashes[0], hashes[1]);
+    assert_ne!(hashes[0], hashes[2]);
+
+    assert!(hash_combine(&array, &mut [0; 2]).is_err());
+}
+
+#[test]
+fn can() {
+    assert!(can_hash(&Float32));
+    assert!(can_hash(&List(Box::new(Field::new("a", Utf8, true)))));
+    assert!(!can_hash(&List(Box::new(Field::new(
+        "a",
+        Union(vec![], None, arrow2::datatypes::UnionMode::Dense),
+        true
+    )))));
+}
+
+/// Asserts that the slots that are equal via `eq` have equal hashes
+#[cfg(feature = "compute_comparison")]
+fn assert_eq_consistent(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+) -> std::result::Result<(), TestCaseError> {
+    let equal = arrow2::compute::comparison::eq(lhs, rhs);
+    let lhs = hash(lhs).unwrap();
+    let rhs = hash(rhs).unwrap();
+    for (i, equal) in equal.iter().enumerate() {
+        if equal == Some(true) {
+            prop_assert_eq!(lhs.value(i), rhs.value(i));
+        }
+    }
+    Ok(())
+}
+
+/// Returns a strategy of pairs of values from a small domain, so that many are equal
+#[cfg(feature = "compute_comparison")]
+fn pairs() -> impl Strategy<Value = Vec<(Option<u8>, Option<u8>)>> {
+    prop::collection::vec((prop::option::of(0..4u8), prop::option::of(0..4u8)), 0..100)
+}
+
+#[cfg(feature = "compute_comparison")]
+fn to_list(values: &[Option<u8>]) -> ListArray<i32> {
+    // each slot is either null or a list with `x` items of value `x`
+    let data = values
+        .iter()
+        .map(|x| x.map(|x| vec![Some(x as i32); x as usize]))
+        .collect::<Vec<_>>();
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[cfg(feature = "compute_comparison")]
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn eq_consistency(pairs in pairs()) {
+        let (lhs, rhs): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+
+        let to_i32 = |x: &[Option<u8>]| Int32Array::from_iter(x.iter().map(|x| x.map(|x| x as i32)));
+        assert_eq_consistent(&to_i32(&lhs), &to_i32(&rhs))?;
+
+        let to_f64 = |x: &[Option<u8>]| {
+            Float64Array::from_iter(x.iter().map(|x| x.map(|x| if x == 0 { -0.0 } else { x as f64 })))
+        };
+        assert_eq_consistent(&to_f64(&lhs), &to_i32(&rhs).iter().map(|x| x.map(|x| *x as f64)).collect::<Float64Array>())?;
+
+        let to_bool = |x: &[Option<u8>]| BooleanArray::from_iter(x.iter().map(|x| x.map(|x| x % 2 == 0)));
+        assert_eq_consistent(&to_bool(&lhs), &to_bool(&rhs))?;
+
+        let to_utf8 = |x: &[Option<u8>]| Utf8Array::<i64>::from_iter(x.iter().map(|x| x.map(|x| "a".repeat(x as usize))));
+        assert_eq_consistent(&to_utf8(&lhs), &to_utf8(&rhs))?;
+
+        let to_binary = |x: &[Option<u8>]| BinaryArray::<i32>::from_iter(x.iter().map(|x| x.map(|x| vec![x; 2])));
+        assert_eq_consistent(&to_binary(&lhs), &to_binary(&rhs))?;
+
+        assert_eq_consistent(&to_list(&lhs), &to_list(&rhs))?;
+
+        let to_struct = |x: &[Option<u8>]| {
+            let fields = vec![Field::new("a", Int32, true), Field::new("b", Boolean, true)];
+            let values: Vec<Arc<dyn Array>> = vec![Arc::new(to_i32(x)), Arc::new(to_bool(x))];
+            StructArray::from_data(Struct(fields), values, None)
+        };
+        assert_eq_consistent(&to_struct(&lhs), &to_struct(&rhs))?;
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn dictionary_consistency(pairs in pairs()) {
+        let (values, _): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+        let values = values.into_iter().map(|x| x.map(|x| x.to_string())).collect::<Vec<_>>();
+
+        let mut array = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+        array.try_extend(values.clone()).unwrap();
+        let array: DictionaryArray<u8> = array.into();
+
+        prop_assert_eq!(
+            hash(&array).unwrap(),
+            hash(&Utf8Array::<i32>::from(&values)).unwrap()
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use arrow2::array::*;
-use arrow2::compute::if_then_else::if_then_else;
+use arrow2::compute::if_then_else::{if_then_else, if_then_else_primitive};
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
+use arrow2::scalar::Utf8Scalar;
 
 #[test]
 fn basics() -> Result<()> {
@@ -40,3 +42,138 @@ fn basics_nulls_pred() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn primitive_nulls() -> Result<()> {
+    let lhs = Int32Array::from(&[Some(1), None, Some(3), None, Some(5)]).to(DataType::Date32);
+    let rhs = Int32Array::from(&[None, Some(7), Some(8), None, Some(10)]).to(DataType::Date32);
+    let predicate = BooleanArray::from(&[Some(false), Some(true), None, Some(false), Some(true)]);
+
+    let expected = Int32Array::from(&[None, None, None, None, Some(5)]).to(DataType::Date32);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(expected, if_then_else_primitive(&predicate, &lhs, &rhs)?);
+
+    // only one side with validity
+    let lhs = Int32Array::from_slice(&[1, 2, 3, 4, 5]).to(DataType::Date32);
+    let expected = Int32Array::from(&[None, Some(2), None, None, Some(5)]).to(DataType::Date32);
+    assert_eq!(expected, if_then_else_primitive(&predicate, &lhs, &rhs)?);
+
+    let expected =
+        Int32Array::from(&[Some(1), Some(7), None, Some(4), Some(10)]).to(DataType::Date32);
+    assert_eq!(expected, if_then_else_primitive(&predicate, &rhs, &lhs)?);
+    Ok(())
+}
+
+#[test]
+fn primitive_sliced() -> Result<()> {
+    let lhs = Int64Array::from_slice(&[0, 1, 2, 3, 4]).slice(1, 3);
+    let rhs = Int64Array::from(&[Some(5), None, Some(7), Some(8)]).slice(1, 3);
+    let predicate = BooleanArray::from_slice(&[true, false, true, false]).slice(1, 3);
+
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let expected = Int64Array::from(&[None, Some(2), Some(8)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn utf8_nulls_pred() -> Result<()> {
+    let lhs = Utf8Array::<i32>::from_slice(&["a", "b", "c", "d", "e", "f"]);
+    let rhs =
+        Utf8Array::<i32>::from(&[Some("A"), None, Some("C"), Some("D"), Some("E"), Some("F")]);
+    // runs at both boundaries: null, null, true, false, false, null
+    let predicate = BooleanArray::from(&[None, None, Some(true), Some(false), Some(false), None]);
+
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let expected = Utf8Array::<i32>::from(&[None, None, Some("c"), Some("D"), Some("E"), None]);
+    assert_eq!(expected, result.as_ref());
+
+    // a false slot of `rhs` that is null, and a null predicate slot whose value is `true`
+    let predicate = BooleanArray::from_data(
+        DataType::Boolean,
+        [false, false, true, true, false, true].into(),
+        Some([true, true, true, false, true, true].into()),
+    );
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let expected =
+        Utf8Array::<i32>::from(&[Some("A"), None, Some("c"), None, Some("E"), Some("f")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let mut lhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    lhs.try_extend(vec![Some("a"), Some("b"), None])?;
+    let lhs: DictionaryArray<i32> = lhs.into();
+    let mut rhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    rhs.try_extend(vec![Some("c"), Some("d"), Some("c")])?;
+    let rhs: DictionaryArray<i32> = rhs.into();
+    let predicate = BooleanArray::from(&[Some(true), Some(false), None]);
+
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = result
+        .iter()
+        .map(|x| {
+            x.map(|x| {
+                x.as_any()
+                    .downcast_ref::<Utf8Scalar<i32>>()
+                    .unwrap()
+                    .value()
+                    .unwrap()
+                    .to_string()
+            })
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![Some("a".to_string()), Some("d".to_string()), None]
+    );
+    Ok(())
+}
+
+#[test]
+fn list() -> Result<()> {
+    let to_list = |data: Vec<Option<Vec<Option<i32>>>>| -> Result<ListArray<i32>> {
+        let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(data)?;
+        Ok(array.into())
+    };
+    let lhs = to_list(vec![Some(vec![Some(1)]), None, Some(vec![Some(3), None])])?;
+    let rhs = to_list(vec![Some(vec![]), Some(vec![Some(5)]), Some(vec![Some(6)])])?;
+    let predicate = BooleanArray::from(&[Some(false), Some(false), Some(true)]);
+
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let expected = to_list(vec![
+        Some(vec![]),
+        Some(vec![Some(5)]),
+        Some(vec![Some(3), None]),
+    ])?;
+    assert_eq!(expected, result.as_ref());
+
+    let predicate = BooleanArray::from(&[None, Some(true), Some(false)]);
+    let result = if_then_else(&predicate, &lhs, &rhs)?;
+    let expected = to_list(vec![None, None, Some(vec![Some(6)])])?;
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn invalid_arguments() {
+    let lhs = Int32Array::from_slice(&[1, 2]);
+    let rhs = Int64Array::from_slice(&[1, 2]);
+    let predicate = BooleanArray::from_slice(&[true, false]);
+    assert!(if_then_else(&predicate, &lhs, &rhs).is_err());
+
+    let rhs = Int32Array::from_slice(&[1, 2, 3]);
+    assert!(if_then_else(&predicate, &lhs, &rhs).is_err());
+    assert!(if_then_else_primitive(&predicate, &lhs, &rhs).is_err());
+
+    let predicate = BooleanArray::from_slice(&[true]);
+    assert!(if_then_else(&predicate, &lhs, &lhs).is_err());
+}
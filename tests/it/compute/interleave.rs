@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::interleave::interleave;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+#[test]
+fn primitive() -> Result<()> {
+    let a = Int32Array::from_slice(&[1, 2, 3]);
+    let b = Int32Array::from(&[None, Some(5)]);
+
+    let result = interleave(&[&a, &b], &[(0, 1), (0, 2), (1, 0), (0, 0), (1, 1)])?;
+
+    let expected = Int32Array::from(&[Some(2), Some(3), None, Some(1), Some(5)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    let a = Utf8Array::<i32>::from_slice(&["a", "bb"]);
+    let b = Utf8Array::<i32>::from(&[Some("ccc"), None]);
+
+    let result = interleave(&[&a, &b], &[(1, 1), (0, 1), (1, 0), (0, 1)])?;
+
+    let expected = Utf8Array::<i32>::from(&[None, Some("bb"), Some("ccc"), Some("bb")]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn struct_() -> Result<()> {
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let values = Arc::new(Int32Array::from_slice(&[1, 2, 3])) as Arc<dyn Array>;
+    let a = StructArray::from_data(DataType::Struct(fields.clone()), vec![values], None);
+
+    let result = interleave(&[&a], &[(0, 2), (0, 0)])?;
+
+    let values = Arc::new(Int32Array::from_slice(&[3, 1])) as Arc<dyn Array>;
+    let expected = StructArray::from_data(DataType::Struct(fields), vec![values], None);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let a = Int32Array::from_slice(&[1, 2, 3, 4]).slice(2, 2);
+
+    let result = interleave(&[&a], &[(0, 1), (0, 0)])?;
+
+    let expected = Int32Array::from_slice(&[4, 3]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn empty_indices() -> Result<()> {
+    let a = Int32Array::from_slice(&[1]);
+
+    let result = interleave(&[&a], &[])?;
+
+    assert_eq!(result.len(), 0);
+    assert_eq!(result.data_type(), &DataType::Int32);
+    Ok(())
+}
+
+#[test]
+fn dictionaries_with_different_values() -> Result<()> {
+    let mut a = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    a.try_extend(vec![Some("a"), Some("b"), None])?;
+    let a: DictionaryArray<i32> = a.into();
+    let mut b = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    b.try_extend(vec![Some("c"), Some("a")])?;
+    let b: DictionaryArray<i32> = b.into();
+
+    let result = interleave(&[&a, &b], &[(1, 0), (0, 1), (0, 2), (1, 1), (0, 0)])?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    // the values are not deduplicated
+    let expected_values = Utf8Array::<i32>::from_slice(&["a", "b", "c", "a"]);
+    assert_eq!(expected_values, result.values().as_ref());
+    let expected_keys = Int32Array::from(&[Some(2), Some(1), None, Some(3), Some(0)]);
+    assert_eq!(&expected_keys, result.keys());
+    Ok(())
+}
+
+#[test]
+fn empty_arrays() {
+    assert!(interleave(&[], &[]).is_err());
+}
+
+#[test]
+fn different_types() {
+    let a = Int32Array::from_slice(&[1]);
+    let b = Int64Array::from_slice(&[1]);
+    assert!(interleave(&[&a, &b], &[(0, 0)]).is_err());
+}
+
+#[test]
+fn out_of_bounds() {
+    let a = Int32Array::from_slice(&[1, 2]);
+
+    let error = interleave(&[&a], &[(0, 0), (1, 0)]).unwrap_err();
+    assert!(error.to_string().contains("(1, 0)"));
+
+    let error = interleave(&[&a], &[(0, 2)]).unwrap_err();
+    assert!(error.to_string().contains("(0, 2)"));
+}
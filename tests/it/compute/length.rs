@@ -1,6 +1,7 @@
 use arrow2::array::*;
 use arrow2::compute::length::*;
 use arrow2::datatypes::*;
+use arrow2::error::ArrowError;
 
 fn length_test_string<O: Offset>() {
     vec![
@@ -40,6 +41,101 @@ fn utf8() {
     length_test_string::<i32>()
 }
 
+fn length_type<O: Offset>() -> DataType {
+    if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    }
+}
+
+fn length_test_binary<O: Offset>() {
+    let array =
+        BinaryArray::<O>::from(&[Some(b"ab".as_ref()), None, Some(b""), Some(b"abc")]).slice(1, 3);
+
+    let result = length(&array).unwrap();
+    let expected = PrimitiveArray::<O>::from(&[None, Some(O::zero()), O::from_usize(3)])
+        .to(length_type::<O>());
+    assert_eq!(expected, result.as_ref());
+
+    let result = bit_length(&array).unwrap();
+    let expected = PrimitiveArray::<O>::from(&[None, Some(O::zero()), O::from_usize(24)])
+        .to(length_type::<O>());
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn binary() {
+    length_test_binary::<i32>()
+}
+
+#[test]
+fn large_binary() {
+    length_test_binary::<i64>()
+}
+
+#[test]
+fn fixed_size_binary() {
+    let array = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(3),
+        vec![0u8; 9].into(),
+        Some([true, false, true].into()),
+    );
+
+    let result = length(&array).unwrap();
+    assert_eq!(Int32Array::from(&[Some(3), None, Some(3)]), result.as_ref());
+
+    let result = bit_length(&array.slice(1, 2)).unwrap();
+    assert_eq!(Int32Array::from(&[None, Some(24)]), result.as_ref());
+}
+
+#[test]
+fn bit_length_overflow() {
+    // a size of 2^28 bytes is 2^31 bits, which does not fit in an `i32`
+    let array = FixedSizeBinaryArray::new_empty(DataType::FixedSizeBinary(1 << 28));
+    assert_eq!(length(&array).unwrap().len(), 0);
+    assert!(matches!(bit_length(&array), Err(ArrowError::Overflow)));
+}
+
+fn char_length_test<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[
+        Some("x"),
+        Some("straße"),
+        None,
+        Some(""),
+        Some("💖a"),
+        Some("日本"),
+    ])
+    .slice(1, 5);
+
+    let result = char_length(&array);
+    let expected = [Some(6), None, Some(0), Some(2), Some(2)]
+        .iter()
+        .map(|x| x.map(|x| O::from_usize(x).unwrap()))
+        .collect::<PrimitiveArray<O>>()
+        .to(length_type::<O>());
+    assert_eq!(result, expected);
+
+    // `length` remains the number of bytes
+    let result = length(&array).unwrap();
+    let expected = [Some(7), None, Some(0), Some(5), Some(6)]
+        .iter()
+        .map(|x| x.map(|x| O::from_usize(x).unwrap()))
+        .collect::<PrimitiveArray<O>>()
+        .to(length_type::<O>());
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_char_length() {
+    char_length_test::<i32>()
+}
+
+#[test]
+fn large_utf8_char_length() {
+    char_length_test::<i64>()
+}
+
 #[test]
 fn consistency() {
     use arrow2::datatypes::DataType::*;
@@ -71,6 +167,7 @@ fn consistency() {
         LargeUtf8,
         Binary,
         LargeBinary,
+        FixedSizeBinary(2),
         Duration(TimeUnit::Second),
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
@@ -81,8 +178,10 @@ fn consistency() {
         let array = new_null_array(d1.clone(), 10);
         if can_length(&d1) {
             assert!(length(array.as_ref()).is_ok());
+            assert!(bit_length(array.as_ref()).is_ok());
         } else {
             assert!(length(array.as_ref()).is_err());
+            assert!(bit_length(array.as_ref()).is_err());
         }
     });
 }
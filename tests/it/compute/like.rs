@@ -44,3 +44,207 @@ fn test_nlike_binary_scalar() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_like_utf8_scalar_fast_paths() -> Result<()> {
+    let array =
+        Utf8Array::<i32>::from(&[Some("Arrow"), None, Some("Parquet"), Some("row"), Some("")]);
+
+    let cases = [
+        (
+            "Arrow",
+            [Some(true), None, Some(false), Some(false), Some(false)],
+        ),
+        (
+            "Ar%",
+            [Some(true), None, Some(false), Some(false), Some(false)],
+        ),
+        (
+            "%row",
+            [Some(true), None, Some(false), Some(true), Some(false)],
+        ),
+        (
+            "%rr%",
+            [Some(true), None, Some(false), Some(false), Some(false)],
+        ),
+        ("%%", [Some(true), None, Some(true), Some(true), Some(true)]),
+        ("%", [Some(true), None, Some(true), Some(true), Some(true)]),
+    ];
+    for (pattern, expected) in cases {
+        let result = like_utf8_scalar(&array, pattern)?;
+        assert_eq!(result, BooleanArray::from(expected), "{}", pattern);
+
+        let result = nlike_utf8_scalar(&array, pattern)?;
+        let expected = expected.iter().map(|x| x.map(|x| !x)).collect::<Vec<_>>();
+        assert_eq!(result, BooleanArray::from(expected), "{}", pattern);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_like_binary_scalar_fast_paths() -> Result<()> {
+    let array =
+        BinaryArray::<i64>::from(&[Some(b"Arrow".as_ref()), None, Some(b"Parquet")]).slice(1, 2);
+
+    let result = like_binary_scalar(&array, b"%rqu%")?;
+    assert_eq!(result, BooleanArray::from(&[None, Some(true)]));
+
+    let result = nlike_binary_scalar(&array, b"Parquet")?;
+    assert_eq!(result, BooleanArray::from(&[None, Some(false)]));
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_scalar_escape() -> Result<()> {
+    let array = Utf8Array::<i32>::from(&[
+        Some("100%"),
+        Some("1000"),
+        Some("a_b"),
+        Some("axb"),
+        Some(r"a\b"),
+        None,
+        Some("a.b"),
+    ]);
+
+    let result = like_utf8_scalar(&array, r"100\%")?;
+    let expected = [true, false, false, false, false, false, false];
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(expected).with_validity(array.validity().cloned())
+    );
+
+    let result = like_utf8_scalar(&array, r"a\_b")?;
+    let expected = [false, false, true, false, false, false, false];
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(expected).with_validity(array.validity().cloned())
+    );
+
+    let result = like_utf8_scalar(&array, r"a\\b")?;
+    let expected = [false, false, false, false, true, false, false];
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(expected).with_validity(array.validity().cloned())
+    );
+
+    // an escaped wildcard in the middle of a prefix pattern
+    let result = like_utf8_scalar(&array, r"10\%%")?;
+    let expected = [false, false, false, false, false, false, false];
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(expected).with_validity(array.validity().cloned())
+    );
+
+    // regex characters are literals
+    let result = like_utf8_scalar(&array, "a._")?;
+    let expected = [false, false, false, false, false, false, true];
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(expected).with_validity(array.validity().cloned())
+    );
+
+    let result = like_utf8_scalar(&array, "a_b")?;
+    let expected = [false, false, true, true, true, false, true];
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(expected).with_validity(array.validity().cloned())
+    );
+
+    let result = like_utf8_scalar_with_escape(&array, "a#_b", Some('#'))?;
+    let expected = [false, false, true, false, false, false, false];
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(expected).with_validity(array.validity().cloned())
+    );
+
+    let result = like_utf8_scalar_with_escape(&array, r"a\%", None)?;
+    let expected = [false, false, false, false, true, false, false];
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(expected).with_validity(array.validity().cloned())
+    );
+
+    assert!(like_utf8_scalar(&array, r"100\").is_err());
+    assert!(like_utf8_scalar_with_escape(&array, "100#", Some('#')).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_escape() -> Result<()> {
+    let strings = Utf8Array::<i32>::from_slice(&["100%", "1000", "a_b", "axb"]);
+    let patterns = Utf8Array::<i32>::from_slice(&[r"100\%", r"100\%", r"a\_b", r"a\_b"]);
+    let result = like_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from_slice(&[true, false, true, false])
+    );
+
+    let patterns = Utf8Array::<i32>::from_slice(&["1%", "1%", "a", r"a\"]);
+    assert!(like_utf8(&strings, &patterns).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8_scalar() -> Result<()> {
+    let array = Utf8Array::<i64>::from(&[
+        Some("Arrow"),
+        Some("ÁRVORE"),
+        None,
+        Some("árvore"),
+        Some("Σίσυφος"),
+    ]);
+
+    let result = ilike_utf8_scalar(&array, "ARROW")?;
+    let expected = BooleanArray::from(&[Some(true), Some(false), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = ilike_utf8_scalar(&array, "árv%")?;
+    let expected = BooleanArray::from(&[Some(false), Some(true), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = ilike_utf8_scalar(&array, "%ΣΥΦ%")?;
+    let expected = BooleanArray::from(&[Some(false), Some(false), None, Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = nilike_utf8_scalar(&array, "%r_o%")?;
+    let expected = BooleanArray::from(&[Some(false), Some(false), None, Some(false), Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = ilike_utf8_scalar_with_escape(&array, "a!%", Some('!'))?;
+    let expected = BooleanArray::from(&[Some(false), Some(false), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    assert!(ilike_utf8_scalar(&array, r"\").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8() -> Result<()> {
+    let strings = Utf8Array::<i32>::from(&[Some("Arrow"), Some("ÁRVORE"), None, Some("a_b")]);
+    let patterns = Utf8Array::<i32>::from(&[Some("a%W"), Some("árvore"), Some("%"), Some(r"A\_B")]);
+    let result = ilike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(true), Some(true), None, Some(true)])
+    );
+
+    let result = nilike_utf8(&strings, &patterns)?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(false), Some(false), None, Some(false)])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_like_binary_escape() -> Result<()> {
+    let array = BinaryArray::<i32>::from_slice(&["100%", "1000"]);
+    let result = like_binary_scalar(&array, br"100\%")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false]));
+
+    let result = like_binary_scalar(&array, br"1%\%")?;
+    assert_eq!(result, BooleanArray::from_slice(&[true, false]));
+
+    assert!(like_binary_scalar(&array, br"100\").is_err());
+    Ok(())
+}
@@ -228,3 +228,72 @@ fn test_sort() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn merge_duplicated_keys() -> Result<()> {
+    let a0: &dyn Array = &Int32Array::from(&[None, Some(1), Some(1), Some(2), Some(2), Some(3)]);
+    let a1: &dyn Array = &Int32Array::from(&[None, Some(1), Some(2), Some(2), Some(3), Some(3)]);
+    let a2: &dyn Array = &Int32Array::from_slice(&[1, 1, 1]);
+
+    let options = SortOptions::default();
+    let arrays = vec![a0, a1, a2];
+    let pairs = vec![(arrays.as_ref(), &options)];
+    let slices = slices(&pairs)?;
+
+    // equal keys are taken from the current array for as long as possible
+    assert_eq!(
+        slices,
+        vec![
+            (0, 0, 1),
+            (1, 0, 2),
+            (2, 0, 3),
+            (0, 1, 4),
+            (1, 2, 4),
+            (0, 5, 1)
+        ]
+    );
+
+    let result = take_arrays(&arrays, slices, None);
+    let expected = Int32Array::from(&[
+        None,
+        None,
+        Some(1),
+        Some(1),
+        Some(1),
+        Some(1),
+        Some(1),
+        Some(1),
+        Some(2),
+        Some(2),
+        Some(2),
+        Some(2),
+        Some(3),
+        Some(3),
+        Some(3),
+    ]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn merge_slices_are_maximal() -> Result<()> {
+    let a0: &dyn Array = &Int32Array::from_slice(&[0, 1, 2, 3]);
+    let a1: &dyn Array = &Int32Array::from_slice(&[4, 5, 6]);
+
+    let options = SortOptions::default();
+    let arrays = vec![a0, a1];
+    let pairs = vec![(arrays.as_ref(), &options)];
+    let comparator = build_comparator(&pairs)?;
+
+    // contiguous slices of the same array are coalesced, and empty slices are dropped
+    let lhs = [(0, 0, 2), (0, 2, 0), (0, 2, 2)];
+    let rhs = [(1, 0, 1), (1, 1, 2)];
+    let result = merge_sort_slices(lhs.iter(), rhs.iter(), &comparator).collect::<Vec<_>>();
+    assert_eq!(result, vec![(0, 0, 4), (1, 0, 3)]);
+
+    // one side without slices
+    let rhs = [(1, 0, 1), (1, 2, 1)];
+    let result = merge_sort_slices([].iter(), rhs.iter(), &comparator).collect::<Vec<_>>();
+    assert_eq!(result, vec![(1, 0, 1), (1, 2, 1)]);
+    Ok(())
+}
@@ -10,18 +10,30 @@ mod boolean;
 mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 mod cast;
+#[cfg(feature = "compute_coalesce")]
+mod coalesce;
 #[cfg(feature = "compute_comparison")]
 mod comparison;
 #[cfg(feature = "compute_concatenate")]
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(all(
+    feature = "compute_aggregate",
+    feature = "compute_cast",
+    feature = "compute_concatenate",
+    feature = "compute_filter",
+    feature = "compute_take"
+))]
+mod extension;
 #[cfg(feature = "compute_filter")]
 mod filter;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
+#[cfg(feature = "compute_interleave")]
+mod interleave;
 #[cfg(feature = "compute_length")]
 mod length;
 #[cfg(feature = "compute_like")]
@@ -30,10 +42,14 @@ mod like;
 mod limit;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_nullif")]
+mod nullif;
 #[cfg(feature = "compute_partition")]
 mod partition;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_search_sorted")]
+mod search_sorted;
 #[cfg(feature = "compute_sort")]
 mod sort;
 #[cfg(feature = "compute_substring")]
@@ -42,6 +58,8 @@ mod substring;
 mod take;
 #[cfg(feature = "compute_temporal")]
 mod temporal;
+#[cfg(feature = "compute_unique")]
+mod unique;
 #[cfg(feature = "compute_utf8")]
 mod utf8;
 #[cfg(feature = "compute_window")]
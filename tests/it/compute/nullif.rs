@@ -0,0 +1,152 @@
+use arrow2::array::*;
+use arrow2::compute::nullif::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Utf8Scalar};
+
+#[test]
+fn primitive_null_rhs() {
+    // the value behind a null slot of `rhs` must not be compared
+    let lhs = Int32Array::from_slice(&[0, 1, 2]);
+    let rhs = Int32Array::from_slice(&[0, 1, 2]).with_validity(Some([false, true, false].into()));
+    let result = primitive_nullif(&lhs, &rhs);
+
+    let expected = Int32Array::from(&[Some(0), None, Some(2)]);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn primitive_reuses_values() {
+    let lhs = Int32Array::from(&[Some(1), None, Some(3)]);
+    let rhs = Int32Array::from_slice(&[1, 2, 2]);
+    let result = primitive_nullif(&lhs, &rhs);
+
+    assert_eq!(result.values().as_ptr(), lhs.values().as_ptr());
+    assert_eq!(result, Int32Array::from(&[None, None, Some(3)]));
+}
+
+#[test]
+fn utf8() {
+    let lhs = Utf8Array::<i32>::from(&[None, None, Some("a"), Some("b"), Some("c")]);
+    let rhs = Utf8Array::<i32>::from(&[None, Some("a"), None, Some("b"), Some("d")]);
+    let result = nullif_utf8(&lhs, &rhs);
+
+    let expected = Utf8Array::<i32>::from(&[None, None, Some("a"), None, Some("c")]);
+
+    assert_eq!(expected, result);
+    assert_eq!(result.values().as_ptr(), lhs.values().as_ptr());
+    assert_eq!(result.offsets().as_ptr(), lhs.offsets().as_ptr());
+}
+
+#[test]
+fn utf8_scalar() {
+    let lhs = Utf8Array::<i64>::from(&[None, Some("a"), Some("b"), Some("a")]);
+    let result = nullif_utf8_scalar(&lhs, "a");
+
+    let expected = Utf8Array::<i64>::from(&[None, None, Some("b"), None]);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn boolean() {
+    let lhs = BooleanArray::from(&[None, None, Some(true), Some(true), Some(false)]);
+    let rhs = BooleanArray::from(&[None, Some(true), None, Some(true), Some(true)]);
+    let result = nullif_boolean(&lhs, &rhs);
+
+    let expected = BooleanArray::from(&[None, None, Some(true), None, Some(false)]);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn boolean_scalar() {
+    let lhs = BooleanArray::from(&[None, Some(true), Some(false)]);
+    let result = nullif_boolean_scalar(&lhs, true);
+
+    let expected = BooleanArray::from(&[None, None, Some(false)]);
+
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn dyn_utf8() {
+    let lhs = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]);
+    let rhs = Utf8Array::<i32>::from(&[Some("a"), Some("c"), Some("c")]);
+    let result = nullif(&lhs, &rhs);
+
+    let expected = Utf8Array::<i32>::from(&[None, Some("b"), None]);
+
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+}
+
+#[test]
+fn dyn_binary() {
+    let lhs = BinaryArray::<i32>::from(&[Some(b"a".as_ref()), Some(b"b"), None]);
+    let rhs = BinaryArray::<i32>::from(&[Some(b"a".as_ref()), None, Some(b"c")]);
+    let result = nullif(&lhs, &rhs);
+
+    let expected = BinaryArray::<i32>::from(&[None, Some(b"b".as_ref()), None]);
+
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+}
+
+#[test]
+fn dyn_scalar() {
+    let lhs = BooleanArray::from(&[None, Some(true), Some(false)]);
+    let result = nullif_scalar(&lhs, &BooleanScalar::from(Some(false)));
+    let expected = BooleanArray::from(&[None, Some(true), None]);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+
+    let lhs = Utf8Array::<i32>::from(&[Some("a"), Some("b")]);
+    let result = nullif_scalar(&lhs, &Utf8Scalar::<i32>::from(Some("b")));
+    let expected = Utf8Array::<i32>::from(&[Some("a"), None]);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+
+    let lhs = BinaryArray::<i32>::from(&[Some(b"a".as_ref()), Some(b"b")]);
+    let result = nullif_scalar(&lhs, &BinaryScalar::<i32>::from(Some(b"a")));
+    let expected = BinaryArray::<i32>::from(&[None, Some(b"b".as_ref())]);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+}
+
+#[test]
+fn dyn_null_scalar() {
+    let lhs = Int32Array::from(&[None, Some(0), Some(1)]);
+    let result = nullif_scalar(&lhs, &PrimitiveScalar::<i32>::from(None));
+
+    assert_eq!(&lhs as &dyn Array, result.as_ref());
+}
+
+#[test]
+fn dyn_dictionary_scalar() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![Some("a"), None, Some("b"), Some("a")])
+        .unwrap();
+    let lhs: DictionaryArray<i32> = array.into();
+
+    let result = nullif_scalar(&lhs, &Utf8Scalar::<i32>::from(Some("a")));
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    assert_eq!(
+        result.keys(),
+        &Int32Array::from(&[None, None, Some(1), None])
+    );
+    assert_eq!(result.values(), lhs.values());
+}
+
+#[test]
+fn can() {
+    assert!(can_nullif(&DataType::Utf8, &DataType::Utf8));
+    assert!(can_nullif(&DataType::Boolean, &DataType::Boolean));
+    assert!(!can_nullif(&DataType::Utf8, &DataType::LargeUtf8));
+    let dictionary = DataType::Dictionary(
+        arrow2::datatypes::IntegerType::Int32,
+        Box::new(DataType::Utf8),
+        false,
+    );
+    assert!(can_nullif_scalar(&dictionary));
+}
@@ -1,7 +1,7 @@
 use arrow2::array::*;
 use arrow2::compute::partition::*;
 use arrow2::compute::sort::{SortColumn, SortOptions};
-use arrow2::datatypes::DataType;
+use arrow2::datatypes::{DataType, IntegerType};
 use arrow2::error::Result;
 
 #[test]
@@ -189,3 +189,73 @@ fn lexicographical_partition_non_unique_column_1() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn lexicographical_partition_no_rows() -> Result<()> {
+    let values = Int32Array::from_slice(&[]);
+    let input = vec![SortColumn {
+        values: &values,
+        options: None,
+    }];
+    let results = lexicographical_partition_ranges(&input)?;
+    assert_eq!(results.count(), 0);
+    Ok(())
+}
+
+#[test]
+fn lexicographical_partition_consecutive_nulls() -> Result<()> {
+    let values1 =
+        Utf8Array::<i32>::from(&[None, None, None, Some("a"), Some("a"), Some("b"), Some("b")]);
+    let values2 = Int32Array::from(&[None, None, Some(1), None, None, Some(1), Some(1)]);
+    let input = vec![
+        SortColumn {
+            values: &values1,
+            options: Some(SortOptions {
+                descending: false,
+                nulls_first: true,
+            }),
+        },
+        SortColumn {
+            values: &values2,
+            options: Some(SortOptions {
+                descending: false,
+                nulls_first: true,
+            }),
+        },
+    ];
+    let results = lexicographical_partition_ranges(&input[..1])?;
+    assert_eq!(vec![0..3, 3..5, 5..7], results.collect::<Vec<_>>());
+
+    let results = lexicographical_partition_ranges(&input)?;
+    assert_eq!(vec![0..2, 2..3, 3..5, 5..7], results.collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn partition_ranges_single_column() -> Result<()> {
+    let values = Int32Array::from(&[Some(3), Some(3), Some(1), None, None]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let results = partition_ranges(&values, Some(options))?;
+    assert_eq!(vec![0..2, 2..3, 3..5], results.collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn partition_ranges_dictionary() -> Result<()> {
+    // unordered dictionaries are compared by their values
+    let keys = Int32Array::from(&[None, Some(1), Some(2), Some(0), Some(0)]);
+    let values = std::sync::Arc::new(Utf8Array::<i32>::from_slice(&["b", "a", "b"]));
+    let array = DictionaryArray::<i32>::from_data(keys, values);
+
+    let results = partition_ranges(&array, None)?;
+    assert_eq!(vec![0..1, 1..2, 2..5], results.collect::<Vec<_>>());
+
+    let data_type = DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), true);
+    let array = new_null_array(data_type, 3);
+    let results = partition_ranges(array.as_ref(), None)?;
+    assert_eq!(vec![0..3], results.collect::<Vec<_>>());
+    Ok(())
+}
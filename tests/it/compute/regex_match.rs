@@ -55,3 +55,92 @@ fn test_like_scalar() {
         vec![true, false, false, false],
     )
 }
+
+fn test_extract<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[
+        Some("2021-12-01"),
+        None,
+        Some("no date"),
+        Some("1999-01-31"),
+    ]);
+    let pattern = r"(?P<year>\d{4})-(?P<month>\d{2})(-(?P<day>x))?";
+
+    let result = regex_extract(&array, pattern, 0).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("2021-12"), None, None, Some("1999-01")]);
+    assert_eq!(result, expected);
+
+    let result = regex_extract(&array, pattern, 2).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("12"), None, None, Some("01")]);
+    assert_eq!(result, expected);
+
+    // the group does not participate in the match
+    let result = regex_extract(&array, pattern, 4).unwrap();
+    let expected = Utf8Array::<O>::from(&[None::<&str>, None, None, None]);
+    assert_eq!(result, expected);
+
+    // sliced
+    let result = regex_extract(&array.slice(1, 3), pattern, 1).unwrap();
+    let expected = Utf8Array::<O>::from(&[None, None, Some("1999")]);
+    assert_eq!(result, expected);
+
+    // empty matches are valid values
+    let result = regex_extract(&array, "x*", 0).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some(""), None, Some(""), Some("")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn extract() {
+    test_extract::<i32>();
+    test_extract::<i64>();
+}
+
+#[test]
+fn extract_errors() {
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(regex_extract(&array, "(", 0).is_err());
+    assert!(regex_extract(&array, "(a)", 2).is_err());
+}
+
+fn test_replace<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[Some("a-b-c"), None, Some("abc"), Some("")]);
+
+    let result = regex_replace(&array, "-", "+", usize::MAX).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("a+b+c"), None, Some("abc"), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = regex_replace(&array, "-", "+", 1).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("a+b-c"), None, Some("abc"), Some("")]);
+    assert_eq!(result, expected);
+
+    // as in `replace_n`, nothing is replaced when `max_replacements` is 0
+    let result = regex_replace(&array, "-", "+", 0).unwrap();
+    assert_eq!(result, array);
+
+    // named groups in the replacement
+    let result = regex_replace(&array, r"(?P<l>\w)-(?P<r>\w)", "${r}${l}", usize::MAX).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("ba-c"), None, Some("abc"), Some("")]);
+    assert_eq!(result, expected);
+
+    // empty matches are replaced
+    let result = regex_replace(&array, "x*", "_", usize::MAX).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("_a_-_b_-_c_"), None, Some("_a_b_c_"), Some("_")]);
+    assert_eq!(result, expected);
+
+    // sliced
+    let result = regex_replace(&array.slice(1, 2), "b", "", usize::MAX).unwrap();
+    let expected = Utf8Array::<O>::from(&[None, Some("ac")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn replace() {
+    test_replace::<i32>();
+    test_replace::<i64>();
+}
+
+#[test]
+fn replace_invalid_regex() {
+    let array = Utf8Array::<i64>::from_slice(&["a"]);
+    assert!(regex_replace(&array, "[", "", 0).is_err());
+}
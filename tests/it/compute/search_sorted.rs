@@ -0,0 +1,122 @@
+use arrow2::array::*;
+use arrow2::compute::search_sorted::{search_sorted, Side};
+use arrow2::compute::sort::{sort, SortOptions};
+use arrow2::datatypes::DataType;
+
+#[test]
+fn duplicates() {
+    let sorted = Int32Array::from_slice(&[1, 2, 2, 2, 3]);
+    let probes = Int32Array::from_slice(&[0, 1, 2, 3, 4]);
+    let options = SortOptions::default();
+
+    let result = search_sorted(&sorted, &probes, Side::Left, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[0, 0, 1, 4, 5]));
+
+    let result = search_sorted(&sorted, &probes, Side::Right, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[0, 1, 4, 5, 5]));
+}
+
+#[test]
+fn descending() {
+    let sorted = Int32Array::from_slice(&[3, 2, 2, 1]);
+    let probes = Int32Array::from_slice(&[4, 2, 0]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+
+    let result = search_sorted(&sorted, &probes, Side::Left, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[0, 1, 4]));
+
+    let result = search_sorted(&sorted, &probes, Side::Right, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[0, 3, 4]));
+}
+
+#[test]
+fn nulls_first() {
+    let sorted = Int32Array::from(&[None, None, Some(1), Some(2)]);
+    let probes = Int32Array::from(&[None, Some(0), Some(2)]);
+    let options = SortOptions::default();
+
+    let result = search_sorted(&sorted, &probes, Side::Left, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[0, 2, 3]));
+
+    let result = search_sorted(&sorted, &probes, Side::Right, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[2, 2, 4]));
+}
+
+#[test]
+fn nulls_last() {
+    let sorted = Int32Array::from(&[Some(1), Some(2), None, None]);
+    let probes = Int32Array::from(&[None, Some(0), Some(3)]);
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+
+    let result = search_sorted(&sorted, &probes, Side::Left, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[2, 0, 2]));
+
+    let result = search_sorted(&sorted, &probes, Side::Right, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[4, 0, 2]));
+}
+
+#[test]
+fn empty() {
+    let sorted = Int32Array::from_slice(&[]);
+    let probes = Int32Array::from(&[Some(1), None]);
+    let options = SortOptions::default();
+
+    let result = search_sorted(&sorted, &probes, Side::Right, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[0, 0]));
+}
+
+#[test]
+fn float() {
+    let sorted = Float64Array::from_slice(&[-1.0, 0.0, 1.5, f64::NAN]);
+    let probes = Float64Array::from_slice(&[0.0, 2.0, f64::NAN]);
+    let options = SortOptions::default();
+
+    let result = search_sorted(&sorted, &probes, Side::Left, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[1, 3, 3]));
+}
+
+#[test]
+fn utf8() {
+    let sorted = Utf8Array::<i32>::from(&[None, Some("a"), Some("b"), Some("b"), Some("d")]);
+    let probes = Utf8Array::<i32>::from(&[Some("b"), Some("c"), None]);
+    let options = SortOptions::default();
+
+    let result = search_sorted(&sorted, &probes, Side::Left, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[2, 4, 0]));
+
+    let result = search_sorted(&sorted, &probes, Side::Right, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[4, 4, 1]));
+}
+
+#[test]
+fn from_sort() {
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let array = Int64Array::from(&[Some(5), None, Some(1), Some(3), Some(3)]).to(DataType::Date64);
+    let sorted = sort(&array, &options, None).unwrap();
+
+    let probes = Int64Array::from(&[Some(3), Some(2), None]).to(DataType::Date64);
+
+    let result = search_sorted(sorted.as_ref(), &probes, Side::Left, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[1, 3, 4]));
+
+    let result = search_sorted(sorted.as_ref(), &probes, Side::Right, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice(&[3, 3, 5]));
+}
+
+#[test]
+fn different_types() {
+    let sorted = Int32Array::from_slice(&[1]);
+    let probes = Int64Array::from_slice(&[1]);
+    let options = SortOptions::default();
+
+    assert!(search_sorted(&sorted, &probes, Side::Left, &options).is_err());
+}
@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
-use arrow2::compute::sort::{lexsort, SortColumn, SortOptions};
+use arrow2::compute::sort::{lexsort, lexsort_to_indices, SortColumn, SortOptions};
+use arrow2::datatypes::{DataType, Field, IntervalUnit};
+use arrow2::types::months_days_ns;
 
 fn test_lex_sort_arrays(input: Vec<SortColumn>, expected: Vec<Box<dyn Array>>) {
     let sorted = lexsort::<i32>(&input, None).unwrap();
@@ -73,6 +77,100 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 
+/// sorts `values` with a second column that breaks ties in reverse order of the rows
+fn test_lex_sort_to_indices(values: &dyn Array, expected: &[u32]) {
+    let ties = (0..values.len() as i32).rev().collect::<Vec<_>>();
+    let ties = Int32Array::from_vec(ties);
+    let input = vec![
+        SortColumn {
+            values,
+            options: None,
+        },
+        SortColumn {
+            values: &ties,
+            options: None,
+        },
+    ];
+    let result = lexsort_to_indices::<u32>(&input, None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice(expected));
+}
+
+#[test]
+fn dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![Some("c"), Some("a"), Some("b"), Some("a")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    test_lex_sort_to_indices(&array, &[3, 1, 2, 0]);
+}
+
+#[test]
+fn dictionary_null_values() {
+    let keys = Int32Array::from_slice(&[0, 1, 2, 0]);
+    let values = Utf8Array::<i32>::from(&[Some("b"), None, Some("a")]);
+    let array = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    test_lex_sort_to_indices(&array, &[1, 2, 3, 0]);
+}
+
+#[test]
+fn decimal() {
+    let array = Int128Array::from_slice(&[150, -25, 100, 150]).to(DataType::Decimal(5, 2));
+
+    test_lex_sort_to_indices(&array, &[1, 2, 3, 0]);
+}
+
+#[test]
+fn interval_month_day_nano() {
+    let array = PrimitiveArray::<months_days_ns>::from_slice(&[
+        months_days_ns::new(1, 0, 0),
+        months_days_ns::new(0, 40, 0),
+        months_days_ns::new(0, 40, -1),
+        months_days_ns::new(1, 0, 0),
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    test_lex_sort_to_indices(&array, &[2, 1, 3, 0]);
+}
+
+#[test]
+fn list() {
+    let data = vec![
+        Some(vec![Some(9)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), None]),
+        Some(vec![]),
+        Some(vec![Some(1), Some(2)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+    let array = array.slice(1, 5);
+
+    test_lex_sort_to_indices(&array, &[3, 1, 2, 4, 0]);
+}
+
+#[test]
+fn struct_() {
+    let a = Int32Array::from_slice(&[5, 1, 0, 1, 0]);
+    let b = Utf8Array::<i32>::from_slice(&["a", "b", "z", "a", "z"]);
+    let fields = vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(a), Arc::new(b)],
+        None,
+    );
+    let array = array.slice(1, 4);
+
+    test_lex_sort_to_indices(&array, &[3, 1, 2, 0]);
+}
+
 /*
     // test sort with nulls first
     let input = vec![
@@ -1,9 +1,10 @@
 mod lex_sort;
+mod rank;
 
 use arrow2::array::*;
 use arrow2::compute::sort::*;
 use arrow2::datatypes::*;
-use arrow2::types::NativeType;
+use arrow2::types::{i256, NativeType};
 
 fn to_indices_boolean_arrays(data: &[Option<bool>], options: SortOptions, expected_data: &[i32]) {
     let output = BooleanArray::from(data);
@@ -603,3 +604,30 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn decimal256() {
+    let big = i256::from(10).checked_pow(60).unwrap();
+    let data_type = DataType::Decimal256(76, 0);
+    assert!(can_sort(&data_type));
+
+    primitive_arrays::<i256>(
+        &[
+            Some(big),
+            None,
+            Some(big.wrapping_neg()),
+            Some(i256::from(1)),
+        ],
+        data_type,
+        SortOptions {
+            descending: false,
+            nulls_first: true,
+        },
+        &[
+            None,
+            Some(big.wrapping_neg()),
+            Some(i256::from(1)),
+            Some(big),
+        ],
+    );
+}
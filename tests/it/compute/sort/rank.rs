@@ -0,0 +1,132 @@
+use arrow2::array::*;
+use arrow2::compute::sort::{rank, RankMethod, SortOptions};
+
+fn ranks(array: &dyn Array, options: SortOptions, method: RankMethod) -> Vec<u64> {
+    rank(array, &options, method).unwrap().values().to_vec()
+}
+
+const ASCENDING: SortOptions = SortOptions {
+    descending: false,
+    nulls_first: true,
+};
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from_slice(&[3, 1, 3, 2, 1, 3]);
+
+    assert_eq!(
+        ranks(&array, ASCENDING, RankMethod::Min),
+        vec![4, 1, 4, 3, 1, 4]
+    );
+    assert_eq!(
+        ranks(&array, ASCENDING, RankMethod::Dense),
+        vec![3, 1, 3, 2, 1, 3]
+    );
+    assert_eq!(
+        ranks(&array, ASCENDING, RankMethod::Ordinal),
+        vec![4, 1, 5, 3, 2, 6]
+    );
+}
+
+#[test]
+fn descending() {
+    let array = Int32Array::from_slice(&[3, 1, 3, 2, 1, 3]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+
+    assert_eq!(
+        ranks(&array, options, RankMethod::Min),
+        vec![1, 5, 1, 4, 5, 1]
+    );
+    assert_eq!(
+        ranks(&array, options, RankMethod::Dense),
+        vec![1, 3, 1, 2, 3, 1]
+    );
+    assert_eq!(
+        ranks(&array, options, RankMethod::Ordinal),
+        vec![1, 5, 2, 4, 6, 3]
+    );
+}
+
+#[test]
+fn nulls() {
+    let array = Int32Array::from(&[Some(2), None, Some(1), None]);
+
+    assert_eq!(ranks(&array, ASCENDING, RankMethod::Min), vec![4, 1, 3, 1]);
+    assert_eq!(
+        ranks(&array, ASCENDING, RankMethod::Dense),
+        vec![3, 1, 2, 1]
+    );
+    assert_eq!(
+        ranks(&array, ASCENDING, RankMethod::Ordinal),
+        vec![4, 1, 3, 2]
+    );
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    assert_eq!(ranks(&array, options, RankMethod::Min), vec![2, 3, 1, 3]);
+    assert_eq!(ranks(&array, options, RankMethod::Dense), vec![2, 3, 1, 3]);
+    assert_eq!(
+        ranks(&array, options, RankMethod::Ordinal),
+        vec![2, 3, 1, 4]
+    );
+}
+
+#[test]
+fn all_nulls() {
+    let array = Utf8Array::<i32>::from(&[None::<&str>, None, None]);
+
+    assert_eq!(ranks(&array, ASCENDING, RankMethod::Min), vec![1, 1, 1]);
+    assert_eq!(ranks(&array, ASCENDING, RankMethod::Dense), vec![1, 1, 1]);
+    assert_eq!(ranks(&array, ASCENDING, RankMethod::Ordinal), vec![1, 2, 3]);
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from_slice(&[]);
+    assert!(ranks(&array, ASCENDING, RankMethod::Min).is_empty());
+}
+
+#[test]
+fn utf8_heavy_duplication() {
+    let values = (0..1000)
+        .map(|i| match i % 10 {
+            0 => None,
+            x => Some(["a", "b", "c"][x % 3]),
+        })
+        .collect::<Vec<_>>();
+    let array = Utf8Array::<i32>::from(&values);
+
+    let count = |value: Option<&str>| values.iter().filter(|x| **x == value).count() as u64;
+    let (nulls, a, b) = (count(None), count(Some("a")), count(Some("b")));
+
+    let expected = |value: &Option<&str>| match value {
+        None => (1, 1),
+        Some("a") => (nulls + 1, 2),
+        Some("b") => (nulls + a + 1, 3),
+        _ => (nulls + a + b + 1, 4),
+    };
+    let min = ranks(&array, ASCENDING, RankMethod::Min);
+    let dense = ranks(&array, ASCENDING, RankMethod::Dense);
+    values
+        .iter()
+        .zip(min.iter().zip(dense.iter()))
+        .for_each(|(value, (min, dense))| assert_eq!(expected(value), (*min, *dense)));
+
+    // ordinal ranks are a permutation that orders equal values by position
+    let ordinal = ranks(&array, ASCENDING, RankMethod::Ordinal);
+    let mut sorted = ordinal.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (1..=1000).collect::<Vec<_>>());
+    for i in 0..values.len() {
+        for j in i + 1..values.len() {
+            if values[i] == values[j] {
+                assert!(ordinal[i] < ordinal[j]);
+            }
+        }
+    }
+}
@@ -152,6 +152,95 @@ fn without_nulls_large_string() -> Result<()> {
     without_nulls_utf8::<i64>()
 }
 
+fn multi_byte_utf8<O: Offset>() -> Result<()> {
+    // "e\u{301}" is "é" written with a combining character, i.e. two characters
+    let array = Utf8Array::<O>::from(&[
+        Some("x"),
+        Some("a💖b"),
+        Some("e\u{301}f"),
+        None,
+        Some("日本語"),
+    ])
+    .slice(1, 4);
+
+    let cases = vec![
+        (
+            0,
+            Some(2),
+            vec![Some("a💖"), Some("e\u{301}"), None, Some("日本")],
+        ),
+        (
+            1,
+            Some(1),
+            vec![Some("💖"), Some("\u{301}"), None, Some("本")],
+        ),
+        (2, None, vec![Some("b"), Some("f"), None, Some("語")]),
+        (3, None, vec![Some(""), Some(""), None, Some("")]),
+        (-1, None, vec![Some("b"), Some("f"), None, Some("語")]),
+        (
+            -2,
+            Some(1),
+            vec![Some("💖"), Some("\u{301}"), None, Some("本")],
+        ),
+        (-3, Some(0), vec![Some(""), Some(""), None, Some("")]),
+        (
+            -4,
+            Some(2),
+            vec![Some("a💖"), Some("e\u{301}"), None, Some("日本")],
+        ),
+        (
+            1,
+            Some(u64::MAX),
+            vec![Some("💖b"), Some("\u{301}f"), None, Some("本語")],
+        ),
+        (i64::MAX, None, vec![Some(""), Some(""), None, Some("")]),
+        (
+            i64::MIN,
+            None,
+            vec![Some("a💖b"), Some("e\u{301}f"), None, Some("日本語")],
+        ),
+    ];
+
+    cases
+        .into_iter()
+        .try_for_each::<_, Result<()>>(|(start, length, expected)| {
+            let result = substring(&array, start, &length)?;
+            let result = result.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+            let expected = Utf8Array::<O>::from(&expected);
+
+            assert_eq!(&expected, result, "{} {:?}", start, length);
+            Ok(())
+        })
+}
+
+#[test]
+fn multi_byte_string() -> Result<()> {
+    multi_byte_utf8::<i32>()
+}
+
+#[test]
+fn multi_byte_large_string() -> Result<()> {
+    multi_byte_utf8::<i64>()
+}
+
+#[test]
+fn binary_bytes() -> Result<()> {
+    // binary arrays are indexed by bytes, even when the values are utf8
+    let array = BinaryArray::<i64>::from_slice(&["a💖".as_bytes()]);
+
+    let result = substring(&array, -2, &None)?;
+    let result = result.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+    assert_eq!(
+        result,
+        &BinaryArray::<i64>::from_slice(&[&"💖".as_bytes()[2..]])
+    );
+
+    let result = substring(&array, 1, &Some(u64::MAX))?;
+    let result = result.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+    assert_eq!(result, &BinaryArray::<i64>::from_slice(&["💖".as_bytes()]));
+    Ok(())
+}
+
 fn with_null_binarys<O: Offset>() -> Result<()> {
     let cases = vec![
         // identity
@@ -342,3 +431,96 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn starts_ends_contains_utf8_scalar() {
+    let array = Utf8Array::<i32>::from(&[Some("Arrow"), None, Some("arrow"), Some("")]);
+
+    let result = starts_with_utf8_scalar(&array, "Ar");
+    let expected = BooleanArray::from(&[Some(true), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = ends_with_utf8_scalar(&array, "row");
+    let expected = BooleanArray::from(&[Some(true), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = contains_utf8_scalar(&array, "rr");
+    let expected = BooleanArray::from(&[Some(true), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    // the empty string is a substring of every value
+    let result = contains_utf8_scalar(&array, "");
+    let expected = BooleanArray::from(&[Some(true), None, Some(true), Some(true)]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn starts_ends_contains_utf8_scalar_sliced() {
+    let array =
+        Utf8Array::<i64>::from(&[Some("Arrow"), None, Some("Parquet"), Some("Avro")]).slice(1, 3);
+
+    let result = starts_with_utf8_scalar(&array, "A");
+    assert_eq!(result, BooleanArray::from(&[None, Some(false), Some(true)]));
+
+    let result = ends_with_utf8_scalar(&array, "et");
+    assert_eq!(result, BooleanArray::from(&[None, Some(true), Some(false)]));
+
+    let result = contains_utf8_scalar(&array, "vr");
+    assert_eq!(result, BooleanArray::from(&[None, Some(false), Some(true)]));
+}
+
+#[test]
+fn starts_ends_contains_utf8() -> Result<()> {
+    let lhs = Utf8Array::<i32>::from(&[Some("Arrow"), Some("Arrow"), None, Some("Parquet")]);
+    let rhs = Utf8Array::<i32>::from(&[Some("Ar"), None, Some("a"), Some("rq")]);
+
+    let result = starts_with_utf8(&lhs, &rhs)?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(true), None, None, Some(false)])
+    );
+
+    let result = ends_with_utf8(&lhs, &rhs)?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(false), None, None, Some(false)])
+    );
+
+    let result = contains_utf8(&lhs, &rhs)?;
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(true), None, None, Some(true)])
+    );
+
+    // sliced
+    let result = contains_utf8(&lhs.slice(1, 3), &rhs.slice(1, 3))?;
+    assert_eq!(result, BooleanArray::from(&[None, None, Some(true)]));
+
+    assert!(contains_utf8(&lhs, &rhs.slice(0, 2)).is_err());
+    Ok(())
+}
+
+#[test]
+fn starts_ends_contains_binary() -> Result<()> {
+    let lhs = BinaryArray::<i32>::from(&[Some(b"\x00\x01\x02".as_ref()), None, Some(b"\x03")]);
+    let rhs = BinaryArray::<i32>::from(&[Some(b"\x01".as_ref()), Some(b"\x01"), Some(b"\x03")]);
+
+    let result = starts_with_binary(&lhs, &rhs)?;
+    assert_eq!(result, BooleanArray::from(&[Some(false), None, Some(true)]));
+
+    let result = ends_with_binary(&lhs, &rhs)?;
+    assert_eq!(result, BooleanArray::from(&[Some(false), None, Some(true)]));
+
+    let result = contains_binary(&lhs, &rhs)?;
+    assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(true)]));
+
+    let result = starts_with_binary_scalar(&lhs, b"\x00");
+    assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+
+    let result = ends_with_binary_scalar(&lhs.slice(1, 2), b"\x03");
+    assert_eq!(result, BooleanArray::from(&[None, Some(true)]));
+
+    let result = contains_binary_scalar(&lhs, b"\x01\x02");
+    assert_eq!(result, BooleanArray::from(&[Some(true), None, Some(false)]));
+    Ok(())
+}
@@ -62,6 +62,29 @@ fn test_take_primitive_null_values() {
     .unwrap();
 }
 
+#[test]
+fn test_take_primitive_null_values_null_indices() {
+    let indices = Int32Array::from(&[Some(5), None, Some(0), Some(1), None, Some(5)]);
+    test_take_primitive::<i64>(
+        &[None, Some(2), Some(4), Some(6), Some(8), Some(10)],
+        &indices,
+        &[Some(10), None, None, Some(2), None, Some(10)],
+        DataType::Int64,
+    )
+    .unwrap();
+
+    let indices = Int32Array::from(&[None; 0]);
+    test_take_primitive::<i64>(&[None, Some(2)], &indices, &[], DataType::Int64).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_take_primitive_null_values_null_indices_out_of_bounds() {
+    let indices = Int32Array::from(&[Some(0), None, Some(6)]);
+    let values = Int64Array::from(&[None, Some(2)]);
+    let _ = take(&values, &indices);
+}
+
 fn create_test_struct() -> StructArray {
     let boolean = BooleanArray::from_slice(&[true, false, false, true]);
     let int = Int32Array::from_slice(&[42, 28, 19, 31]);
@@ -125,6 +148,7 @@ fn consistency() {
         Int16,
         Int32,
         Int64,
+        Float16,
         Float32,
         Float64,
         Timestamp(TimeUnit::Second, None),
@@ -134,6 +158,7 @@ fn consistency() {
         Time64(TimeUnit::Microsecond),
         Time64(TimeUnit::Nanosecond),
         Interval(IntervalUnit::DayTime),
+        Decimal256(40, 2),
         Interval(IntervalUnit::YearMonth),
         Date32,
         Time32(TimeUnit::Second),
@@ -147,6 +172,7 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        FixedSizeBinary(3),
     ];
 
     datatypes.into_iter().for_each(|d1| {
@@ -160,6 +186,17 @@ fn consistency() {
     });
 }
 
+#[test]
+fn fixed_size_binary() {
+    let values = FixedSizeBinaryArray::from_iter([Some([1u8, 2]), None, Some([3, 4])], 2);
+    let indices = Int32Array::from(&[Some(2), None, Some(1), Some(0)]);
+
+    let result = take(&values, &indices).unwrap();
+
+    let expected = FixedSizeBinaryArray::from_iter([Some([3u8, 4]), None, None, Some([1, 2])], 2);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn empty() {
     let indices = Int32Array::from_slice(&[]);
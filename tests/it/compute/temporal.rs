@@ -372,3 +372,633 @@ fn consistency_check<O: arrow2::types::NativeType>(
         }
     });
 }
+
+// 2020-02-28T23:59:59, 2020-02-29T12:00:00, 2020-12-31T23:59:59, None,
+// 2021-01-01T00:00:00, 2021-01-03T00:00:00, 2024-12-30T00:00:00
+const SECONDS: [Option<i64>; 7] = [
+    Some(1582934399),
+    Some(1582977600),
+    Some(1609459199),
+    None,
+    Some(1609459200),
+    Some(1609632000),
+    Some(1735516800),
+];
+
+fn date_like_arrays() -> Vec<Box<dyn Array>> {
+    let seconds = Int64Array::from(&SECONDS);
+    let mut arrays: Vec<Box<dyn Array>> = [
+        (TimeUnit::Second, 1),
+        (TimeUnit::Millisecond, 1_000),
+        (TimeUnit::Microsecond, 1_000_000),
+        (TimeUnit::Nanosecond, 1_000_000_000),
+    ]
+    .iter()
+    .map(|(unit, factor)| {
+        let array = arrow2::compute::arity::unary(&seconds, |x| x * factor, DataType::Int64);
+        Box::new(array.to(DataType::Timestamp(*unit, None))) as Box<dyn Array>
+    })
+    .collect();
+
+    let date64 = arrow2::compute::arity::unary(&seconds, |x| x * 1_000, DataType::Date64);
+    arrays.push(Box::new(date64));
+    let date32 = arrow2::compute::arity::unary(&seconds, |x| (x / 86_400) as i32, DataType::Date32);
+    arrays.push(Box::new(date32));
+    arrays
+}
+
+#[test]
+fn date_parts_year_boundaries_and_leap_days() {
+    let day_ = UInt32Array::from(&[
+        Some(28),
+        Some(29),
+        Some(31),
+        None,
+        Some(1),
+        Some(3),
+        Some(30),
+    ]);
+    let ordinal_ = UInt32Array::from(&[
+        Some(59),
+        Some(60),
+        Some(366),
+        None,
+        Some(1),
+        Some(3),
+        Some(365),
+    ]);
+    let quarter_ = UInt32Array::from(&[Some(1), Some(1), Some(4), None, Some(1), Some(1), Some(4)]);
+    // Monday is 1, Sunday is 7
+    let weekday_ = UInt32Array::from(&[Some(5), Some(6), Some(4), None, Some(5), Some(7), Some(1)]);
+    let iso_week_ = UInt32Array::from(&[
+        Some(9),
+        Some(9),
+        Some(53),
+        None,
+        Some(53),
+        Some(53),
+        Some(1),
+    ]);
+    let year_ = Int32Array::from(&[
+        Some(2020),
+        Some(2020),
+        Some(2020),
+        None,
+        Some(2021),
+        Some(2021),
+        Some(2024),
+    ]);
+
+    for array in date_like_arrays() {
+        let array = array.as_ref();
+        let data_type = array.data_type();
+        assert_eq!(day(array).unwrap(), day_, "{:?}", data_type);
+        assert_eq!(ordinal(array).unwrap(), ordinal_, "{:?}", data_type);
+        assert_eq!(quarter(array).unwrap(), quarter_, "{:?}", data_type);
+        assert_eq!(weekday(array).unwrap(), weekday_, "{:?}", data_type);
+        assert_eq!(iso_week(array).unwrap(), iso_week_, "{:?}", data_type);
+        assert_eq!(year(array).unwrap(), year_, "{:?}", data_type);
+    }
+}
+
+#[test]
+fn date_parts_fixed_offset() {
+    // 2020-12-31T23:59:59Z and 2021-01-01T00:00:00Z
+    let array = Int64Array::from(&[Some(1609459199), None, Some(1609459200)]);
+
+    // 2021-01-01T04:59:59 and 2021-01-01T05:00:00
+    let east = array.clone().to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+05:00".to_string()),
+    ));
+    assert_eq!(
+        year(&east).unwrap(),
+        Int32Array::from(&[Some(2021), None, Some(2021)])
+    );
+    assert_eq!(
+        ordinal(&east).unwrap(),
+        UInt32Array::from(&[Some(1), None, Some(1)])
+    );
+    assert_eq!(
+        quarter(&east).unwrap(),
+        UInt32Array::from(&[Some(1), None, Some(1)])
+    );
+    assert_eq!(
+        weekday(&east).unwrap(),
+        UInt32Array::from(&[Some(5), None, Some(5)])
+    );
+    assert_eq!(
+        minute(&east).unwrap(),
+        UInt32Array::from(&[Some(59), None, Some(0)])
+    );
+
+    // 2020-12-31T18:59:59 and 2020-12-31T19:00:00
+    let west = array.to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("-05:00".to_string()),
+    ));
+    assert_eq!(
+        year(&west).unwrap(),
+        Int32Array::from(&[Some(2020), None, Some(2020)])
+    );
+    assert_eq!(
+        ordinal(&west).unwrap(),
+        UInt32Array::from(&[Some(366), None, Some(366)])
+    );
+    assert_eq!(
+        quarter(&west).unwrap(),
+        UInt32Array::from(&[Some(4), None, Some(4)])
+    );
+    assert_eq!(
+        iso_week(&west).unwrap(),
+        UInt32Array::from(&[Some(53), None, Some(53)])
+    );
+    assert_eq!(
+        hour(&west).unwrap(),
+        UInt32Array::from(&[Some(18), None, Some(19)])
+    );
+}
+
+#[test]
+fn fixed_offset_with_minutes() {
+    // 2021-01-01T00:00:00Z is 2020-12-31T18:30:00-05:30 and 2021-01-01T05:45:00+05:45
+    let array = Int64Array::from(&[Some(1609459200), None]);
+
+    let west = array.clone().to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("-05:30".to_string()),
+    ));
+    assert_eq!(hour(&west).unwrap(), UInt32Array::from(&[Some(18), None]));
+    assert_eq!(minute(&west).unwrap(), UInt32Array::from(&[Some(30), None]));
+
+    let east = array.to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+0545".to_string()),
+    ));
+    assert_eq!(hour(&east).unwrap(), UInt32Array::from(&[Some(5), None]));
+    assert_eq!(minute(&east).unwrap(), UInt32Array::from(&[Some(45), None]));
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn timezone_dst_transitions() {
+    let array = Int64Array::from(&[
+        // 2021-03-14T06:59:59Z, 01:59:59 EST
+        Some(1615705199),
+        // 2021-03-14T07:00:00Z, 03:00:00 EDT (02:00 to 02:59 does not exist)
+        Some(1615705200),
+        // 2021-11-07T05:30:00Z, 01:30:00 EDT
+        Some(1636263000),
+        // 2021-11-07T06:30:00Z, 01:30:00 EST (01:00 to 01:59 happens twice)
+        Some(1636266600),
+        None,
+    ])
+    .to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("America/New_York".to_string()),
+    ));
+
+    assert_eq!(
+        hour(&array).unwrap(),
+        UInt32Array::from(&[Some(1), Some(3), Some(1), Some(1), None])
+    );
+    assert_eq!(
+        minute(&array).unwrap(),
+        UInt32Array::from(&[Some(59), Some(0), Some(30), Some(30), None])
+    );
+    assert_eq!(
+        day(&array).unwrap(),
+        UInt32Array::from(&[Some(14), Some(14), Some(7), Some(7), None])
+    );
+}
+
+#[test]
+fn invalid_timezone() {
+    let array = Int64Array::from(&[Some(1)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("Mars/Olympus_Mons".to_string()),
+    ));
+    assert!(hour(&array).is_err());
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+#[test]
+fn timezone_requires_chrono_tz() {
+    let array = Int64Array::from(&[Some(1)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("America/New_York".to_string()),
+    ));
+    let error = hour(&array).unwrap_err().to_string();
+    assert!(error.contains("requires feature chrono-tz"), "{}", error);
+}
+
+#[test]
+fn sub_second_parts() {
+    // 2020-02-29T00:00:00.123456789
+    let nanoseconds = 1582934400123456789i64;
+    let cases = [
+        (TimeUnit::Second, 1_000_000_000, [0, 0, 0]),
+        (
+            TimeUnit::Millisecond,
+            1_000_000,
+            [123, 123_000, 123_000_000],
+        ),
+        (TimeUnit::Microsecond, 1_000, [123, 123_456, 123_456_000]),
+        (TimeUnit::Nanosecond, 1, [123, 123_456, 123_456_789]),
+    ];
+    for (unit, divisor, [milli, micro, nano]) in cases {
+        let array = Int64Array::from(&[Some(nanoseconds / divisor), None])
+            .to(DataType::Timestamp(unit, Some("+01:00".to_string())));
+        assert_eq!(
+            millisecond(&array).unwrap(),
+            UInt32Array::from(&[Some(milli), None])
+        );
+        assert_eq!(
+            microsecond(&array).unwrap(),
+            UInt32Array::from(&[Some(micro), None])
+        );
+        assert_eq!(
+            nanosecond(&array).unwrap(),
+            UInt32Array::from(&[Some(nano), None])
+        );
+        assert_eq!(day(&array).unwrap(), UInt32Array::from(&[Some(29), None]));
+    }
+
+    // 01:02:03.456789
+    let array =
+        Int64Array::from(&[Some(3_723_456_789), None]).to(DataType::Time64(TimeUnit::Microsecond));
+    assert_eq!(
+        millisecond(&array).unwrap(),
+        UInt32Array::from(&[Some(456), None])
+    );
+    assert_eq!(
+        microsecond(&array).unwrap(),
+        UInt32Array::from(&[Some(456_789), None])
+    );
+
+    // dates have no sub-second parts
+    let array = Int32Array::from(&[Some(18321), None]).to(DataType::Date32);
+    assert_eq!(
+        millisecond(&array).unwrap(),
+        UInt32Array::from(&[Some(0), None])
+    );
+}
+
+#[test]
+fn consistency_ordinal() {
+    consistency_check(can_ordinal, ordinal);
+}
+
+#[test]
+fn consistency_quarter() {
+    consistency_check(can_quarter, quarter);
+}
+
+#[test]
+fn consistency_millisecond() {
+    consistency_check(can_millisecond, millisecond);
+}
+
+#[test]
+fn consistency_microsecond() {
+    consistency_check(can_microsecond, microsecond);
+}
+
+fn trunc(array: &dyn Array, granularity: Granularity) -> Box<dyn Array> {
+    let result = date_trunc(array, granularity).unwrap();
+    assert_eq!(result.data_type(), array.data_type());
+    result
+}
+
+#[test]
+fn date_trunc_timestamp() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    // 2021-05-26T12:00:00
+    let array = Int64Array::from(&[Some(1622030400), None]).to(data_type.clone());
+
+    let expected = |x: i64| Int64Array::from(&[Some(x), None]).to(data_type.clone());
+    // 2021-01-01
+    assert_eq!(
+        expected(1609459200),
+        trunc(&array, Granularity::Year).as_ref()
+    );
+    // 2021-04-01
+    assert_eq!(
+        expected(1617235200),
+        trunc(&array, Granularity::Quarter).as_ref()
+    );
+    // 2021-05-01
+    assert_eq!(
+        expected(1619827200),
+        trunc(&array, Granularity::Month).as_ref()
+    );
+    // Monday 2021-05-24
+    assert_eq!(
+        expected(1621814400),
+        trunc(&array, Granularity::Week).as_ref()
+    );
+    // 2021-05-26
+    assert_eq!(
+        expected(1621987200),
+        trunc(&array, Granularity::Day).as_ref()
+    );
+    assert_eq!(
+        expected(1622030400),
+        trunc(&array, Granularity::Hour).as_ref()
+    );
+}
+
+#[test]
+fn date_trunc_before_epoch() {
+    // 1969-12-31T23:59:59 and 1970-01-01T00:00:00
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let array = Int64Array::from(&[Some(-1), Some(0)]).to(data_type.clone());
+
+    let expected = |x: [i64; 2]| Int64Array::from_slice(x).to(data_type.clone());
+    assert_eq!(
+        expected([-31536000, 0]),
+        trunc(&array, Granularity::Year).as_ref()
+    );
+    // 1969-10-01
+    assert_eq!(
+        expected([-7948800, 0]),
+        trunc(&array, Granularity::Quarter).as_ref()
+    );
+    // 1969-12-01
+    assert_eq!(
+        expected([-2678400, 0]),
+        trunc(&array, Granularity::Month).as_ref()
+    );
+    // Monday 1969-12-29 for both
+    assert_eq!(
+        expected([-259200, -259200]),
+        trunc(&array, Granularity::Week).as_ref()
+    );
+    assert_eq!(
+        expected([-86400, 0]),
+        trunc(&array, Granularity::Day).as_ref()
+    );
+    assert_eq!(
+        expected([-3600, 0]),
+        trunc(&array, Granularity::Hour).as_ref()
+    );
+    assert_eq!(
+        expected([-60, 0]),
+        trunc(&array, Granularity::Minute).as_ref()
+    );
+}
+
+#[test]
+fn date_trunc_sub_second_before_epoch() {
+    // 1969-12-31T23:59:59.999999999
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let array = Int64Array::from_slice([-1]).to(data_type.clone());
+    assert_eq!(
+        Int64Array::from_slice([-1_000_000_000]).to(data_type.clone()),
+        trunc(&array, Granularity::Second).as_ref()
+    );
+    assert_eq!(
+        Int64Array::from_slice([-2678400 * 1_000_000_000]).to(data_type),
+        trunc(&array, Granularity::Month).as_ref()
+    );
+
+    // 1969-12-31T23:59:59.999
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+    let array = Int64Array::from_slice([-1]).to(data_type.clone());
+    assert_eq!(
+        Int64Array::from_slice([-31536000 * 1000]).to(data_type),
+        trunc(&array, Granularity::Year).as_ref()
+    );
+}
+
+#[test]
+fn date_trunc_fixed_offset() {
+    // 1970-01-01T05:30:00+05:30
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+05:30".to_string()));
+    let array = Int64Array::from_slice([0]).to(data_type.clone());
+
+    // 1970-01-01T00:00:00+05:30
+    assert_eq!(
+        Int64Array::from_slice([-19800]).to(data_type.clone()),
+        trunc(&array, Granularity::Day).as_ref()
+    );
+    // 1970-01-01T05:00:00+05:30
+    assert_eq!(
+        Int64Array::from_slice([-1800]).to(data_type.clone()),
+        trunc(&array, Granularity::Hour).as_ref()
+    );
+    // 1970-01-01T00:00:00+05:30
+    assert_eq!(
+        Int64Array::from_slice([-19800]).to(data_type),
+        trunc(&array, Granularity::Year).as_ref()
+    );
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn date_trunc_timezone() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("America/New_York".to_string()));
+    // 2021-03-14T08:00:00-04:00 (after the DST switch) and 2021-11-15T07:00:00-05:00
+    let array = Int64Array::from_slice([1615723200, 1636977600]).to(data_type.clone());
+
+    // 2021-03-14T00:00:00-05:00 (before the DST switch)
+    assert_eq!(
+        Int64Array::from_slice([1615698000, 1636952400]).to(data_type.clone()),
+        trunc(&array, Granularity::Day).as_ref()
+    );
+    // 2021-03-01T00:00:00-05:00 and 2021-11-01T00:00:00-04:00
+    assert_eq!(
+        Int64Array::from_slice([1614574800, 1635739200]).to(data_type),
+        trunc(&array, Granularity::Month).as_ref()
+    );
+}
+
+#[test]
+fn date_trunc_dates() {
+    // 1969-12-28 (Sunday), 1969-12-31, 1970-01-01 and 2021-05-26
+    let array = Int32Array::from_slice([-4, -1, 0, 18773]).to(DataType::Date32);
+
+    let expected = |x: [i32; 4]| Int32Array::from_slice(x).to(DataType::Date32);
+    assert_eq!(
+        expected([-365, -365, 0, 18628]),
+        trunc(&array, Granularity::Year).as_ref()
+    );
+    assert_eq!(
+        expected([-31, -31, 0, 18748]),
+        trunc(&array, Granularity::Month).as_ref()
+    );
+    assert_eq!(
+        expected([-10, -3, -3, 18771]),
+        trunc(&array, Granularity::Week).as_ref()
+    );
+    assert_eq!(
+        expected([-4, -1, 0, 18773]),
+        trunc(&array, Granularity::Hour).as_ref()
+    );
+
+    // 1969-12-31 and 2021-05-26
+    let array = Int64Array::from_slice([-86_400_000, 1_621_987_200_000]).to(DataType::Date64);
+    assert_eq!(
+        Int64Array::from_slice([-2_678_400_000, 1_619_827_200_000]).to(DataType::Date64),
+        trunc(&array, Granularity::Month).as_ref()
+    );
+}
+
+#[test]
+fn date_trunc_out_of_range() {
+    // the third slot is null
+    let validity = Some([true, true, false, true].into());
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let values = vec![i64::MAX, i64::MIN, i64::MAX, 0];
+    let array = Int64Array::from_data(data_type.clone(), values.into(), validity.clone());
+
+    let day = i64::MAX.div_euclid(86400) * 86400;
+    assert_eq!(
+        Int64Array::from(&[Some(day), None, None, Some(0)]).to(data_type.clone()),
+        trunc(&array, Granularity::Day).as_ref()
+    );
+    assert_eq!(
+        Int64Array::from(&[None, None, None, Some(0)]).to(data_type),
+        trunc(&array, Granularity::Month).as_ref()
+    );
+
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+05:30".to_string()));
+    let array = array.to(data_type.clone());
+    assert_eq!(
+        Int64Array::from(&[None, None, None, Some(-1800)]).to(data_type.clone()),
+        trunc(&array, Granularity::Hour).as_ref()
+    );
+    assert_eq!(
+        Int64Array::from(&[None, None, None, Some(-19800)]).to(data_type),
+        trunc(&array, Granularity::Year).as_ref()
+    );
+
+    let values = vec![i32::MAX, i32::MIN, i32::MIN, 0];
+    let array = Int32Array::from_data(DataType::Date32, values.into(), validity);
+    let week = (i32::MAX - 4).div_euclid(7) * 7 + 4;
+    assert_eq!(
+        Int32Array::from(&[Some(week), None, None, Some(-3)]).to(DataType::Date32),
+        trunc(&array, Granularity::Week).as_ref()
+    );
+    assert_eq!(
+        Int32Array::from(&[None, None, None, Some(0)]).to(DataType::Date32),
+        trunc(&array, Granularity::Month).as_ref()
+    );
+}
+
+#[test]
+fn date_trunc_invalid() {
+    let array = Int64Array::from_slice([0]);
+    assert!(date_trunc(&array, Granularity::Day).is_err());
+    assert!(!can_date_trunc(&DataType::Int64));
+    assert!(can_date_trunc(&DataType::Date32));
+}
+
+#[test]
+fn strftime_dates() {
+    // 1969-12-31 and 2021-05-24
+    let array = Int32Array::from(&[Some(-1), None, Some(18771)]).to(DataType::Date32);
+    let expected = Utf8Array::<i32>::from(&[Some("31/12/1969 00h"), None, Some("24/05/2021 00h")]);
+    assert_eq!(strftime(&array, "%d/%m/%Y %Hh").unwrap(), expected);
+
+    let array =
+        Int64Array::from(&[Some(-86_400_000), None, Some(1_621_814_400_000)]).to(DataType::Date64);
+    assert_eq!(strftime(&array, "%d/%m/%Y %Hh").unwrap(), expected);
+}
+
+#[test]
+fn strftime_timestamps() {
+    // 1969-12-31T23:59:59.5 and 2021-05-24T17:25:30
+    let values = [Some(-500), None, Some(1621877130000)];
+    let expected = Utf8Array::<i32>::from(&[
+        Some("1969-12-31 23:59:59.500"),
+        None,
+        Some("2021-05-24 17:25:30.000"),
+    ]);
+    let format = "%Y-%m-%d %H:%M:%S%.3f";
+    for (time_unit, multiple) in [
+        (TimeUnit::Millisecond, 1),
+        (TimeUnit::Microsecond, 1_000),
+        (TimeUnit::Nanosecond, 1_000_000),
+    ] {
+        let array = Int64Array::from_iter(values.iter().map(|x| x.map(|x| x * multiple)))
+            .to(DataType::Timestamp(time_unit, None));
+        assert_eq!(strftime(&array, format).unwrap(), expected);
+    }
+
+    let array = Int64Array::from(&[Some(1621877130)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("-05:30".to_string()),
+    ));
+    assert_eq!(
+        strftime(&array, "%Y-%m-%dT%H:%M:%S%:z").unwrap(),
+        Utf8Array::<i32>::from_slice(["2021-05-24T11:55:30-05:30"])
+    );
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn strftime_timezone() {
+    // 2021-03-14T06:59:59Z and 2021-03-14T07:00:00Z
+    let array = Int64Array::from_slice([1615705199, 1615705200]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("America/New_York".to_string()),
+    ));
+    assert_eq!(
+        strftime(&array, "%H:%M:%S %Z").unwrap(),
+        Utf8Array::<i32>::from_slice(["01:59:59 EST", "03:00:00 EDT"])
+    );
+}
+
+#[test]
+fn strftime_invalid() {
+    let array = Int64Array::from_slice([0]).to(DataType::Timestamp(TimeUnit::Second, None));
+    // invalid format
+    assert!(strftime(&array, "%Y-%Q").is_err());
+    // naive timestamps have no offset
+    assert!(strftime(&array, "%Y %z").is_err());
+    // not a temporal type
+    assert!(strftime(&Int64Array::from_slice([0]), "%Y").is_err());
+    assert!(can_strftime(&DataType::Date64));
+    assert!(!can_strftime(&DataType::Utf8));
+}
+
+#[cfg(feature = "compute_cast")]
+#[test]
+fn strftime_cast_roundtrip() {
+    use arrow2::compute::cast::{cast, CastOptions};
+
+    let options = |format| CastOptions {
+        format: Some(format),
+        ..Default::default()
+    };
+
+    let format = "%d/%m/%Y";
+    let array = Int32Array::from(&[Some(-1), None, Some(18771)]).to(DataType::Date32);
+    let result = cast(
+        &strftime(&array, format).unwrap(),
+        &DataType::Date32,
+        options(format),
+    );
+    assert_eq!(array, result.unwrap().as_ref());
+
+    let format = "%Y-%m-%d %H:%M:%S%.9f";
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let array = Int64Array::from(&[Some(-1), None, Some(1621877130123456789)]).to(data_type);
+    let result = cast(
+        &strftime(&array, format).unwrap(),
+        array.data_type(),
+        options(format),
+    );
+    assert_eq!(array, result.unwrap().as_ref());
+
+    let format = "%Y-%m-%dT%H:%M:%S%.9f%:z";
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string()));
+    let array = Int64Array::from(&[Some(-1), None, Some(1621877130123456789)]).to(data_type);
+    let result = cast(
+        &strftime(&array, format).unwrap(),
+        array.data_type(),
+        options(format),
+    );
+    assert_eq!(array, result.unwrap().as_ref());
+}
@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::unique::*;
+use arrow2::datatypes::{DataType, Field, UnionMode};
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(2), Some(1), None, Some(2), Some(3), None, Some(1)]);
+
+    let result = unique(&array).unwrap();
+    let expected = Int32Array::from(&[Some(2), Some(1), None, Some(3)]);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+
+    let (values, counts) = value_counts(&array).unwrap();
+    assert_eq!(&expected as &dyn Array, values.as_ref());
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 2, 2, 1]));
+}
+
+#[test]
+fn floats() {
+    let array = Float64Array::from_slice(&[f64::NAN, 0.0, -0.0, f64::NAN, 1.0]);
+    let (values, counts) = value_counts(&array).unwrap();
+
+    let values = values.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert!(values.value(0).is_nan());
+    assert_eq!(&values.values()[1..], &[0.0, 1.0]);
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 2, 1]));
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i64>::from_slice(&["a", "bb", "a", "", "bb", ""]);
+    let (values, counts) = value_counts(&array).unwrap();
+
+    let expected = Utf8Array::<i64>::from_slice(&["a", "bb", ""]);
+    assert_eq!(&expected as &dyn Array, values.as_ref());
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 2, 2]));
+}
+
+#[test]
+fn all_null() {
+    let array = Int32Array::from(&[None, None, None]);
+    let (values, counts) = value_counts(&array).unwrap();
+    assert_eq!(&Int32Array::from(&[None]) as &dyn Array, values.as_ref());
+    assert_eq!(counts, UInt64Array::from_slice(&[3]));
+
+    let array = new_null_array(DataType::Null, 3);
+    let (values, counts) = value_counts(array.as_ref()).unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values.data_type(), &DataType::Null);
+    assert_eq!(counts, UInt64Array::from_slice(&[3]));
+}
+
+#[test]
+fn only_distinct_value_is_null() {
+    let array = Utf8Array::<i32>::from(&[None, Some("a"), None, Some("a")]);
+    let (values, counts) = value_counts(&array.slice(0, 1)).unwrap();
+    assert_eq!(
+        &Utf8Array::<i32>::from(&[None::<&str>]) as &dyn Array,
+        values.as_ref()
+    );
+    assert_eq!(counts, UInt64Array::from_slice(&[1]));
+
+    let (values, counts) = value_counts(&array).unwrap();
+    let expected = Utf8Array::<i32>::from(&[None, Some("a")]);
+    assert_eq!(&expected as &dyn Array, values.as_ref());
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 2]));
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from_slice(&[]);
+    let (values, counts) = value_counts(&array).unwrap();
+    assert_eq!(values.len(), 0);
+    assert_eq!(counts.len(), 0);
+}
+
+#[test]
+fn dictionary_unused_values() {
+    // "c" and the null value are not referenced by any key
+    let values = Arc::new(Utf8Array::<i32>::from(&[
+        Some("a"),
+        Some("b"),
+        Some("c"),
+        None,
+        Some("b"),
+    ]));
+    let keys = Int32Array::from(&[Some(1), Some(0), None, Some(4), Some(1), Some(0)]);
+    let array = DictionaryArray::<i32>::from_data(keys, values);
+
+    let (values, counts) = value_counts(&array).unwrap();
+    let values = values
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    // keys `1` and `4` point to equal values, and are thus the same value
+    let keys = Int32Array::from(&[Some(1), Some(0), None]);
+    assert_eq!(values.keys(), &keys);
+    assert_eq!(counts, UInt64Array::from_slice(&[3, 2, 1]));
+}
+
+#[test]
+fn dictionary_null_values() {
+    let values = Arc::new(Int32Array::from(&[Some(1), None]));
+    let keys = Int32Array::from(&[Some(1), Some(0), None, Some(1)]);
+    let array = DictionaryArray::<i32>::from_data(keys, values);
+
+    let (values, counts) = value_counts(&array).unwrap();
+    let values = values
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    // a null key and a key to a null value are the same (null) value
+    assert_eq!(values.keys(), &Int32Array::from_slice(&[1, 0]));
+    assert_eq!(counts, UInt64Array::from_slice(&[3, 1]));
+}
+
+#[test]
+fn nested() {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        Some(vec![Some(1)]),
+        None,
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let (values, counts) = value_counts(&array).unwrap();
+
+    let expected = vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(1)]),
+    ];
+    let mut expected_array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected_array.try_extend(expected).unwrap();
+    let expected: ListArray<i32> = expected_array.into();
+
+    assert_eq!(&expected as &dyn Array, values.as_ref());
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 2, 1, 1]));
+}
+
+#[test]
+fn fixed_size_binary() {
+    let array = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), Some(b"cd"), Some(b"ab")], 2);
+    let (values, counts) = value_counts(&array).unwrap();
+
+    let expected = FixedSizeBinaryArray::from_iter(vec![Some(b"ab"), Some(b"cd")], 2);
+    assert_eq!(&expected as &dyn Array, values.as_ref());
+    assert_eq!(counts, UInt64Array::from_slice(&[2, 1]));
+}
+
+#[test]
+fn not_implemented() {
+    let data_type = DataType::Union(
+        vec![Field::new("a", DataType::Int32, true)],
+        None,
+        UnionMode::Sparse,
+    );
+    let array = new_null_array(data_type, 1);
+    assert!(unique(array.as_ref()).is_err());
+}
@@ -369,3 +369,308 @@ fn consistency_upper() {
         }
     });
 }
+
+#[test]
+fn lower_changes_byte_length() {
+    let array = Utf8Array::<i32>::from(&[Some("xx"), Some("İa"), None, Some("ÀB")]);
+    let array = array.slice(1, 3);
+
+    let result = lower(&array).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("i\u{307}a"), None, Some("àb")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = upper(&array).unwrap();
+    let expected = Utf8Array::<i32>::from(&[Some("İA"), None, Some("ÀB")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+fn trim_sliced<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[
+        Some("skip"),
+        Some("  héllo \t"),
+        None,
+        Some("¡¡olá!!"),
+        Some(""),
+        Some("   "),
+    ]);
+    let array = array.slice(1, 5);
+
+    let result = trim(&array, None).unwrap();
+    let expected =
+        Utf8Array::<O>::from(&[Some("héllo"), None, Some("¡¡olá!!"), Some(""), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = ltrim(&array, None).unwrap();
+    let expected =
+        Utf8Array::<O>::from(&[Some("héllo \t"), None, Some("¡¡olá!!"), Some(""), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rtrim(&array, None).unwrap();
+    let expected =
+        Utf8Array::<O>::from(&[Some("  héllo"), None, Some("¡¡olá!!"), Some(""), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = trim(&array, Some("¡! ")).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("héllo \t"), None, Some("olá"), Some(""), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = ltrim(&array, Some("¡")).unwrap();
+    let expected = Utf8Array::<O>::from(&[
+        Some("  héllo \t"),
+        None,
+        Some("olá!!"),
+        Some(""),
+        Some("   "),
+    ]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rtrim(&array, Some("!")).unwrap();
+    let expected = Utf8Array::<O>::from(&[
+        Some("  héllo \t"),
+        None,
+        Some("¡¡olá"),
+        Some(""),
+        Some("   "),
+    ]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_trim() {
+    trim_sliced::<i32>()
+}
+
+#[test]
+fn test_large_trim() {
+    trim_sliced::<i64>()
+}
+
+fn pad_sliced<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[Some("skip"), Some("añ"), None, Some("ñandú"), Some("")]);
+    let array = array.slice(1, 4);
+
+    let result = lpad(&array, 4, "ü-").unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("ü-añ"), None, Some("ñand"), Some("ü-ü-")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rpad(&array, 5, "é").unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("añééé"), None, Some("ñandú"), Some("ééééé")]);
+    assert_eq!(expected, result.as_ref());
+
+    // an empty pad only truncates
+    let result = rpad(&array, 3, "").unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("añ"), None, Some("ñan"), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = lpad(&array, 0, "x").unwrap();
+    let expected = Utf8Array::<O>::from(&[Some(""), None, Some(""), Some("")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_pad() {
+    pad_sliced::<i32>()
+}
+
+#[test]
+fn test_large_pad() {
+    pad_sliced::<i64>()
+}
+
+#[test]
+fn trim_pad_unsupported() {
+    let array = Int32Array::from_slice(&[1]);
+    assert!(!can_trim(array.data_type()));
+    assert!(!can_pad(array.data_type()));
+    assert!(trim(&array, None).is_err());
+    assert!(lpad(&array, 1, " ").is_err());
+}
+
+fn concat_sliced<O: Offset>() {
+    let lhs = Utf8Array::<O>::from(&[Some("skip"), Some("añ"), None, Some("a"), Some("")]);
+    let rhs = Utf8Array::<O>::from(&[Some("b"), Some("c"), Some("d"), None, Some("ü")]);
+    let lhs = lhs.slice(1, 4);
+    let rhs = rhs.slice(0, 4);
+
+    let result = concat_elementwise(&lhs, &rhs).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("añb"), None, Some("ad"), None]);
+    assert_eq!(expected, result);
+
+    let result = concat_elementwise_coalesce(&[&lhs, &rhs]).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("añb"), Some("c"), Some("ad"), Some("")]);
+    assert_eq!(expected, result);
+    assert_eq!(result.validity(), None);
+
+    let result = concat_elementwise_many(&[&lhs, &rhs, &lhs]).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("añbañ"), None, Some("ada"), None]);
+    assert_eq!(expected, result);
+
+    let result = concat_elementwise_scalar(&lhs, "-é").unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("añ-é"), None, Some("a-é"), Some("-é")]);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_concat_elementwise() {
+    concat_sliced::<i32>()
+}
+
+#[test]
+fn test_large_concat_elementwise() {
+    concat_sliced::<i64>()
+}
+
+#[test]
+fn concat_elementwise_errors() {
+    let lhs = Utf8Array::<i32>::from_slice(&["a", "b"]);
+    let rhs = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(concat_elementwise(&lhs, &rhs).is_err());
+    assert!(concat_elementwise_many::<i32>(&[]).is_err());
+}
+
+fn list<O: Offset>(values: Vec<Option<Vec<Option<&str>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutableUtf8Array<O>>::new();
+    array.try_extend(values).unwrap();
+    array.into()
+}
+
+fn split_sliced<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[
+        Some("x"),
+        Some("a,b,c"),
+        None,
+        Some(""),
+        Some(",a,,b,"),
+        Some("x"),
+    ])
+    .slice(1, 4);
+
+    let result = split(&array, ",", None).unwrap();
+    let expected = list::<O>(vec![
+        Some(vec![Some("a"), Some("b"), Some("c")]),
+        None,
+        Some(vec![Some("")]),
+        Some(vec![Some(""), Some("a"), Some(""), Some("b"), Some("")]),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = split(&array, ",", Some(2)).unwrap();
+    let expected = list::<O>(vec![
+        Some(vec![Some("a"), Some("b,c")]),
+        None,
+        Some(vec![Some("")]),
+        Some(vec![Some(""), Some("a,,b,")]),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = split_regex(&array, ",+", None).unwrap();
+    let expected = list::<O>(vec![
+        Some(vec![Some("a"), Some("b"), Some("c")]),
+        None,
+        Some(vec![Some("")]),
+        Some(vec![Some(""), Some("a"), Some("b"), Some("")]),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = split_regex(&array, ",", Some(3)).unwrap();
+    let expected = list::<O>(vec![
+        Some(vec![Some("a"), Some("b"), Some("c")]),
+        None,
+        Some(vec![Some("")]),
+        Some(vec![Some(""), Some("a"), Some(",b,")]),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_split() {
+    split_sliced::<i32>();
+}
+
+#[test]
+fn test_large_split() {
+    split_sliced::<i64>();
+}
+
+#[test]
+fn split_errors() {
+    let array = Utf8Array::<i32>::from_slice(&["a"]);
+    assert!(split(&array, "", None).is_err());
+    assert!(split_regex(&array, "(", None).is_err());
+}
+
+fn replace_sliced<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[
+        Some("aaa"),
+        Some("abab"),
+        None,
+        Some("ab"),
+        Some(""),
+        Some("b"),
+    ])
+    .slice(1, 5);
+
+    // `to` longer than `from`
+    let result = replace(&array, "a", "xyz").unwrap();
+    let expected =
+        Utf8Array::<O>::from(&[Some("xyzbxyzb"), None, Some("xyzb"), Some(""), Some("b")]);
+    assert_eq!(result, expected);
+
+    // rows that become empty
+    let result = replace(&array, "ab", "").unwrap();
+    let expected = Utf8Array::<O>::from(&[Some(""), None, Some(""), Some(""), Some("b")]);
+    assert_eq!(result, expected);
+
+    let result = replace_n(&array, "ab", "c", 1).unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("cab"), None, Some("c"), Some(""), Some("b")]);
+    assert_eq!(result, expected);
+
+    // as in `regex_replace`, nothing is replaced when `n` is 0
+    let result = replace_n(&array, "ab", "c", 0).unwrap();
+    assert_eq!(result, array);
+
+    let result = replace_n(&array, "ab", "c", usize::MAX).unwrap();
+    assert_eq!(result, replace(&array, "ab", "c").unwrap());
+
+    // empty `from` is a no-op
+    let result = replace(&array, "", "c").unwrap();
+    assert_eq!(result, array);
+}
+
+#[test]
+fn test_replace() {
+    replace_sliced::<i32>();
+}
+
+#[test]
+fn test_large_replace() {
+    replace_sliced::<i64>();
+}
+
+fn translate_sliced<O: Offset>() {
+    let array = Utf8Array::<O>::from(&[Some("x"), Some("straße"), None, Some("ßß"), Some("año")])
+        .slice(1, 4);
+
+    // non-ASCII characters, removal of characters without a counterpart
+    let result = translate(&array, "ßñaa", "sn").unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("strse"), None, Some("ss"), Some("no")]);
+    assert_eq!(result, expected);
+
+    // characters growing and shrinking in bytes, rows that become empty
+    let result = translate(&array, "aß", "ā").unwrap();
+    let expected = Utf8Array::<O>::from(&[Some("strāe"), None, Some(""), Some("āño")]);
+    assert_eq!(result, expected);
+
+    let result = translate(&array, "", "abc").unwrap();
+    assert_eq!(result, array);
+}
+
+#[test]
+fn test_translate() {
+    translate_sliced::<i32>();
+}
+
+#[test]
+fn test_large_translate() {
+    translate_sliced::<i64>();
+}
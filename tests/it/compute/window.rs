@@ -1,6 +1,9 @@
-use arrow2::array::{new_null_array, Int32Array};
+use std::sync::Arc;
+
+use arrow2::array::*;
 use arrow2::compute::window::*;
-use arrow2::datatypes::DataType;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::{BooleanScalar, PrimitiveScalar, Utf8Scalar};
 
 #[test]
 fn shift_pos() {
@@ -15,7 +18,14 @@ fn shift_pos() {
 #[test]
 fn shift_many() {
     let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
-    assert!(shift(&array, 10).is_err());
+    let expected = new_null_array(DataType::Date32, 3);
+
+    let result = shift(&array, 10).unwrap();
+    assert_eq!(expected.as_ref(), result.as_ref());
+    let result = shift(&array, -10).unwrap();
+    assert_eq!(expected.as_ref(), result.as_ref());
+    let result = shift(&array, i64::MIN).unwrap();
+    assert_eq!(expected.as_ref(), result.as_ref());
 }
 
 #[test]
@@ -27,3 +37,153 @@ fn shift_max() {
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn shift_neg() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+    let result = shift(&array, -2).unwrap();
+
+    let expected = Int32Array::from(&[Some(3), Some(4), None, None]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(
+        result.validity().unwrap().iter().collect::<Vec<_>>(),
+        vec![true, true, false, false]
+    );
+}
+
+#[test]
+fn shift_validity_boundaries() {
+    // an array without validity
+    let array = Int32Array::from_slice([1, 2, 3]);
+
+    let result = shift(&array, 1).unwrap();
+    assert_eq!(
+        result.validity().unwrap().iter().collect::<Vec<_>>(),
+        vec![false, true, true]
+    );
+    let result = shift(&array, -1).unwrap();
+    assert_eq!(
+        result.validity().unwrap().iter().collect::<Vec<_>>(),
+        vec![true, true, false]
+    );
+
+    // a sliced array whose nulls are at the boundaries
+    let array = Int32Array::from(&[Some(0), None, Some(2), None, Some(4)]).slice(1, 3);
+    let result = shift(&array, 1).unwrap();
+    assert_eq!(Int32Array::from(&[None, None, Some(2)]), result.as_ref());
+    let result = shift(&array, -1).unwrap();
+    assert_eq!(Int32Array::from(&[Some(2), None, None]), result.as_ref());
+}
+
+#[test]
+fn shift_zero() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]);
+    let result = shift(&array, 0).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    let array = Int32Array::from_slice([]);
+    let result = shift(&array, 1).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn shift_utf8_and_boolean() {
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("c")]);
+    let result = shift(&array, 1).unwrap();
+    assert_eq!(
+        Utf8Array::<i32>::from(&[None, Some("a"), None]),
+        result.as_ref()
+    );
+
+    let array = BooleanArray::from(&[Some(true), Some(false), None]);
+    let result = shift(&array, -1).unwrap();
+    assert_eq!(
+        BooleanArray::from(&[Some(false), None, None]),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn shift_nested() {
+    let data = vec![Some(vec![Some(1i32), Some(2)]), None, Some(vec![Some(3)])];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let result = shift(&array, 1).unwrap();
+    let expected = vec![None, Some(vec![Some(1i32), Some(2)]), None];
+    let mut expected_array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected_array.try_extend(expected).unwrap();
+    let expected: ListArray<i32> = expected_array.into();
+    assert_eq!(expected, result.as_ref());
+
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let values = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let array = StructArray::from_data(DataType::Struct(fields.clone()), vec![values], None);
+    let result = shift(&array, -1).unwrap();
+    let values = Arc::new(Int32Array::from(&[Some(2), Some(3), None])) as Arc<dyn Array>;
+    let validity = Some([true, true, false].into());
+    let expected = StructArray::from_data(DataType::Struct(fields), vec![values], validity);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn shift_dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![Some("a"), Some("b"), Some("a")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    let result = shift(&array, 2).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(result.keys(), &Int32Array::from(&[None, None, Some(0)]));
+    assert_eq!(result.values().as_ref(), array.values().as_ref());
+}
+
+#[test]
+fn shift_with_fill() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
+    let fill = PrimitiveScalar::from(Some(0i32)).to(DataType::Date32);
+
+    let result = shift_with(&array, 1, &fill).unwrap();
+    let expected = Int32Array::from(&[Some(0), Some(1), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let result = shift_with(&array, -2, &fill).unwrap();
+    let expected = Int32Array::from(&[Some(3), Some(0), Some(0)]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(result.null_count(), 0);
+
+    // beyond the length, every slot is the fill value
+    let result = shift_with(&array, 5, &fill).unwrap();
+    let expected = Int32Array::from_slice([0, 0, 0]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    // a null fill value is the same as `shift`
+    let fill = PrimitiveScalar::<i32>::from(None).to(DataType::Date32);
+    let result = shift_with(&array, 1, &fill).unwrap();
+    let expected = Int32Array::from(&[None, Some(1), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("c")]);
+    let result = shift_with(&array, -1, &Utf8Scalar::<i32>::from(Some("z"))).unwrap();
+    assert_eq!(
+        Utf8Array::<i32>::from(&[None, Some("c"), Some("z")]),
+        result.as_ref()
+    );
+
+    let array = BooleanArray::from_slice([true, false]);
+    let result = shift_with(&array, 1, &BooleanScalar::from(Some(false))).unwrap();
+    assert_eq!(BooleanArray::from_slice([false, true]), result.as_ref());
+}
+
+#[test]
+fn shift_with_invalid_fill() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
+    let fill = PrimitiveScalar::from(Some(0i32));
+    assert!(shift_with(&array, 1, &fill).is_err());
+}
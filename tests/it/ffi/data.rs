@@ -75,6 +75,20 @@ fn i64() -> Result<()> {
     test_round_trip(data)
 }
 
+#[test]
+fn decimal() -> Result<()> {
+    let data = Int128Array::from(&[Some(2), None, Some(-1)]).to(DataType::Decimal(5, 2));
+    test_round_trip(data)
+}
+
+#[test]
+fn decimal256() -> Result<()> {
+    let big = arrow2::types::i256::from(10).checked_pow(60).unwrap();
+    let data = Int256Array::from(&[Some(big), None, Some(big.wrapping_neg())])
+        .to(DataType::Decimal256(76, 2));
+    test_round_trip(data)
+}
+
 #[test]
 fn utf8() -> Result<()> {
     let data = Utf8Array::<i32>::from(&vec![Some("a"), None, Some("bb"), None]);
@@ -1,9 +1,15 @@
 use std::{collections::HashMap, fs::File, io::Read, sync::Arc};
 
 use arrow2::{
-    array::Array, chunk::Chunk, datatypes::Schema, error::Result,
-    io::ipc::read::read_stream_metadata, io::ipc::read::StreamReader, io::ipc::IpcField,
-    io::json_integration::read, io::json_integration::ArrowJson,
+    array::{Array, Float16Array, Int256Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    error::Result,
+    io::ipc::read::read_stream_metadata,
+    io::ipc::read::StreamReader,
+    io::ipc::IpcField,
+    io::json_integration::read,
+    io::json_integration::ArrowJson,
 };
 
 use flate2::read::GzDecoder;
@@ -69,3 +75,53 @@ pub fn read_arrow_stream(version: &str, file_name: &str) -> IpcRead {
             .unwrap(),
     )
 }
+
+/// The expected schema and columns of the files written by `ipc_integration/write_ipc.py`
+pub fn pyarrow_ipc(name: &str) -> (Schema, Vec<Arc<dyn Array>>) {
+    use arrow2::types::{f16, i256};
+
+    match name {
+        "float16" => {
+            let array = Float16Array::from(&[
+                Some(f16::ONE),
+                None,
+                Some(f16::from_f32(-0.5)),
+                Some(f16::MAX),
+                Some(f16::INFINITY),
+            ]);
+            let schema = Schema::from(vec![Field::new("float16", DataType::Float16, true)]);
+            (schema, vec![Arc::new(array) as Arc<dyn Array>])
+        }
+        "decimal256" => {
+            let two = i256::from(2);
+            let decimal_76 = Int256Array::from(&[
+                Some(two.checked_pow(200).unwrap()),
+                None,
+                Some(
+                    two.checked_pow(130)
+                        .and_then(|x| x.checked_add(i256::from(7)))
+                        .unwrap()
+                        .wrapping_neg(),
+                ),
+                Some(i256::from(0)),
+            ])
+            .to(DataType::Decimal256(76, 0));
+            let decimal_40 = Int256Array::from(&[
+                Some(i256::from(125)),
+                Some(i256::from(-1)),
+                None,
+                Some(i256::from(10i128.pow(37))),
+            ])
+            .to(DataType::Decimal256(40, 2));
+            let schema = Schema::from(vec![
+                Field::new("decimal_76", decimal_76.data_type().clone(), true),
+                Field::new("decimal_40", decimal_40.data_type().clone(), true),
+            ]);
+            (
+                schema,
+                vec![Arc::new(decimal_76) as Arc<dyn Array>, Arc::new(decimal_40)],
+            )
+        }
+        _ => unreachable!(),
+    }
+}
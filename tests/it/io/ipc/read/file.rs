@@ -1,9 +1,15 @@
 use std::fs::File;
+use std::io::Cursor;
+use std::sync::Arc;
 
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write;
 
-use super::super::common::read_gzip_json;
+use super::super::common::{pyarrow_ipc, read_gzip_json};
 
 fn test_file(version: &str, file_name: &str) -> Result<()> {
     let testdata = crate::test_util::arrow_test_data();
@@ -182,3 +188,254 @@ fn read_projected() -> Result<()> {
     test_projection("1.0.0-littleendian", "generated_dictionary", 2)?;
     test_projection("1.0.0-littleendian", "generated_nested", 0)
 }
+
+fn write_and_corrupt(array: Arc<dyn Array>, from: &[u8], to: &[u8]) -> Result<Vec<u8>> {
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let options = write::WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
+    let mut writer = write::FileWriter::try_new(vec![], &schema, None, options)?;
+    writer.write(&Chunk::try_new(vec![array])?, None)?;
+    writer.finish()?;
+    let mut data = writer.into_inner();
+
+    let position = data
+        .windows(from.len())
+        .position(|window| window == from)
+        .unwrap();
+    data[position..position + to.len()].copy_from_slice(to);
+    Ok(data)
+}
+
+fn read_all(data: Vec<u8>) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    FileReader::new(reader, metadata, None).collect()
+}
+
+fn to_bytes(values: &[i32]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+#[test]
+fn read_corrupted_offsets() -> Result<()> {
+    let array = Arc::new(Utf8Array::<i32>::from_slice(["a", "bb", "c"])) as Arc<dyn Array>;
+    let offsets = to_bytes(&[0, 1, 3, 4]);
+
+    // out of bounds, decreasing and negative offsets are all errors
+    for corrupted in [[0, 1, 3, 100], [0, 3, 1, 4], [-2, 1, 3, 4]] {
+        let data = write_and_corrupt(array.clone(), &offsets, &to_bytes(&corrupted))?;
+        assert!(read_all(data).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn read_corrupted_dictionary_keys() -> Result<()> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b"]));
+    let keys = Int32Array::from_slice([0, 1, 1, 0]);
+    let array = Arc::new(DictionaryArray::<i32>::from_data(keys, values)) as Arc<dyn Array>;
+
+    let data = write_and_corrupt(array, &to_bytes(&[0, 1, 1, 0]), &to_bytes(&[0, 1, 7, 0]))?;
+    assert!(read_all(data).is_err());
+    Ok(())
+}
+
+fn write_projectable() -> Result<(Vec<u8>, Vec<Arc<dyn Array>>)> {
+    let keys = Int32Array::from_slice([0, 1, 1, 0]);
+    let arrays = vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+        Arc::new(DictionaryArray::<i32>::from_data(
+            keys.clone(),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+        )),
+        Arc::new(BooleanArray::from_slice([true, false, false, true])),
+        Arc::new(DictionaryArray::<i32>::from_data(
+            keys,
+            Arc::new(Int64Array::from_slice([10, 20])),
+        )),
+    ];
+    let fields = arrays
+        .iter()
+        .enumerate()
+        .map(|(i, array)| Field::new(&i.to_string(), array.data_type().clone(), true))
+        .collect::<Vec<_>>();
+    let schema = Schema::from(fields);
+
+    let options = write::WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
+    let mut writer = write::FileWriter::try_new(vec![], &schema, None, options)?;
+    let chunk = Chunk::try_new(arrays.clone())?;
+    writer.write(&chunk, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    Ok((writer.into_inner(), arrays))
+}
+
+fn read_projection(
+    data: &[u8],
+    projection: Vec<usize>,
+) -> Result<(Schema, Vec<Chunk<Arc<dyn Array>>>)> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, Some(projection));
+    let schema = reader.schema().clone();
+    Ok((schema, reader.collect::<Result<Vec<_>>>()?))
+}
+
+#[test]
+fn read_projected_in_order() -> Result<()> {
+    let (data, arrays) = write_projectable()?;
+
+    for projection in [
+        vec![1],
+        vec![0, 3],
+        vec![3, 0],
+        vec![2, 1, 3],
+        vec![3, 2, 1, 0],
+    ] {
+        let (schema, chunks) = read_projection(&data, projection.clone())?;
+
+        let names = projection.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        let fields = schema
+            .fields
+            .iter()
+            .map(|f| f.name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, names);
+
+        let expected = projection
+            .iter()
+            .map(|x| arrays[*x].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(chunks.len(), 2);
+        for chunk in chunks {
+            assert_eq!(chunk.into_arrays(), expected);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn read_projected_repeated() {
+    let (data, _) = write_projectable().unwrap();
+    let _ = read_projection(&data, vec![1, 0, 1]);
+}
+
+#[test]
+fn read_projected_dictionaries() -> Result<()> {
+    let (data, _) = write_projectable()?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+
+    let all = read_file_dictionaries(&mut reader, &metadata, None)?;
+    assert_eq!(all.len(), 2);
+
+    // columns without dictionaries do not read any
+    let none = read_file_dictionaries(&mut reader, &metadata, Some(&[0, 2]))?;
+    assert!(none.is_empty());
+
+    let one = read_file_dictionaries(&mut reader, &metadata, Some(&[2, 3]))?;
+    assert_eq!(one.len(), 1);
+    assert_eq!(
+        one.values().next().unwrap().as_ref(),
+        &Int64Array::from_slice([10, 20]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn read_batch_at() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let options = write::WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
+    let mut writer = write::FileWriter::try_new(vec![], &schema, None, options)?;
+    for i in 0..5 {
+        let array = Arc::new(array.slice(0, i % 3 + 1)) as Arc<dyn Array>;
+        writer.write(&Chunk::try_new(vec![array])?, None)?;
+    }
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    assert_eq!(metadata.blocks.len(), 5);
+
+    let mut reader = FileReader::new(reader, metadata, None);
+    let reversed = (0..5)
+        .rev()
+        .map(|i| reader.read_batch_at(i))
+        .collect::<Result<Vec<_>>>()?;
+    assert!(reader.read_batch_at(5).is_err());
+
+    // random access does not move the iterator
+    let mut sequential = reader.collect::<Result<Vec<_>>>()?;
+    sequential.reverse();
+    assert_eq!(reversed, sequential);
+    Ok(())
+}
+
+#[test]
+fn read_batch_at_projected() -> Result<()> {
+    let (data, arrays) = write_projectable()?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let mut reader = FileReader::new(reader, metadata, Some(vec![3, 1]));
+
+    let chunk = reader.read_batch_at(1)?;
+    assert_eq!(
+        chunk.into_arrays(),
+        vec![arrays[3].clone(), arrays[1].clone()]
+    );
+    Ok(())
+}
+
+fn test_pyarrow(file_name: &str) -> Result<()> {
+    let mut file = File::open(format!("fixtures/pyarrow3/ipc/{}.arrow_file", file_name))?;
+
+    let (schema, expected) = pyarrow_ipc(file_name);
+
+    let metadata = read_file_metadata(&mut file)?;
+    let reader = FileReader::new(file, metadata, None);
+
+    assert_eq!(&schema, reader.schema());
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![Chunk::try_new(expected)?]);
+    Ok(())
+}
+
+#[test]
+fn read_pyarrow_float16() -> Result<()> {
+    test_pyarrow("float16")
+}
+
+#[test]
+fn read_pyarrow_decimal256() -> Result<()> {
+    test_pyarrow("decimal256")
+}
+
+#[test]
+fn read_batch_dictionaries() -> Result<()> {
+    let (data, arrays) = write_projectable()?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+
+    let mut buffer = vec![];
+    let chunk = read_batch(&mut reader, &metadata, None, 1, &mut buffer)?;
+    assert_eq!(chunk.into_arrays(), arrays);
+
+    let chunk = read_batch(&mut reader, &metadata, Some(&[3, 0]), 0, &mut buffer)?;
+    assert_eq!(
+        chunk.into_arrays(),
+        vec![arrays[3].clone(), arrays[0].clone()]
+    );
+    Ok(())
+}
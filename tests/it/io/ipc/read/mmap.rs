@@ -0,0 +1,228 @@
+use std::io::Cursor;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write;
+
+/// The bytes of a file, starting at byte `offset` of an 8-byte aligned region, e.g. a mapped file
+struct Owner {
+    data: Vec<u64>,
+    offset: usize,
+    len: usize,
+    dropped: Arc<AtomicBool>,
+}
+
+impl Owner {
+    fn new(bytes: &[u8], offset: usize, dropped: Arc<AtomicBool>) -> Self {
+        let mut data = vec![0u64; (offset + bytes.len()) / 8 + 1];
+        // Safety: `data` is valid for at least `offset + bytes.len()` bytes
+        let region =
+            unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut u8, data.len() * 8) };
+        region[offset..offset + bytes.len()].copy_from_slice(bytes);
+        Self {
+            data,
+            offset,
+            len: bytes.len(),
+            dropped,
+        }
+    }
+
+    fn contains<T>(&self, ptr: *const T) -> bool {
+        let bytes = self.as_ref();
+        bytes.as_ptr_range().contains(&(ptr as *const u8))
+    }
+}
+
+impl AsRef<[u8]> for Owner {
+    fn as_ref(&self) -> &[u8] {
+        // Safety: `data` is valid for at least `offset + len` bytes
+        unsafe {
+            std::slice::from_raw_parts((self.data.as_ptr() as *const u8).add(self.offset), self.len)
+        }
+    }
+}
+
+impl Drop for Owner {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::SeqCst);
+    }
+}
+
+fn write_file(compression: Option<write::Compression>) -> Result<Vec<u8>> {
+    let keys = Int32Array::from_slice([0, 1, 1, 0]);
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(4)]),
+    ])?;
+    let list: ListArray<i32> = list.into();
+    let arrays = vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([
+            Some("a"),
+            Some("bb"),
+            None,
+            Some("ccc"),
+        ])),
+        Arc::new(BooleanArray::from([
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+        ])),
+        Arc::new(list),
+        Arc::new(DictionaryArray::<i32>::from_data(
+            keys,
+            Arc::new(Int64Array::from_slice([10, 20])),
+        )),
+    ];
+    let fields = arrays
+        .iter()
+        .enumerate()
+        .map(|(i, array)| Field::new(i.to_string(), array.data_type().clone(), true))
+        .collect::<Vec<_>>();
+    let schema = Schema::from(fields);
+
+    let options = write::WriteOptions {
+        compression,
+        delta_dictionaries: false,
+    };
+    let mut writer = write::FileWriter::try_new(vec![], &schema, None, options)?;
+    let chunk = Chunk::try_new(arrays)?;
+    writer.write(&chunk, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+fn read_file(data: &[u8]) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    FileReader::new(reader, metadata, None).collect()
+}
+
+fn read_mmap(owner: &Arc<Owner>) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let metadata = read_file_metadata(&mut Cursor::new(owner.as_ref().as_ref()))?;
+    read_file_mmap(owner.clone(), metadata)?.collect()
+}
+
+fn int32_values(chunk: &Chunk<Arc<dyn Array>>) -> *const i32 {
+    let array = chunk.arrays()[0]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    array.values().as_ptr()
+}
+
+#[test]
+fn read_zero_copy() -> Result<()> {
+    let data = write_file(None)?;
+    let expected = read_file(&data)?;
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let owner = Arc::new(Owner::new(&data, 0, dropped.clone()));
+    let chunks = read_mmap(&owner)?;
+    assert_eq!(chunks, expected);
+
+    for chunk in &chunks {
+        let arrays = chunk.arrays();
+        assert!(owner.contains(int32_values(chunk)));
+        let validity = arrays[0].validity().unwrap().as_slice().0;
+        assert!(owner.contains(validity.as_ptr()));
+
+        let utf8 = arrays[1].as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+        assert!(owner.contains(utf8.offsets().as_ptr()));
+        assert!(owner.contains(utf8.values().as_ptr()));
+
+        let list = arrays[3].as_any().downcast_ref::<ListArray<i32>>().unwrap();
+        assert!(owner.contains(list.offsets().as_ptr()));
+
+        let dictionary = arrays[4]
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap();
+        assert!(owner.contains(dictionary.keys().values().as_ptr()));
+        let values = dictionary
+            .values()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert!(owner.contains(values.values().as_ptr()));
+    }
+
+    // the batches keep the bytes alive
+    drop(owner);
+    assert!(!dropped.load(Ordering::SeqCst));
+    assert_eq!(chunks, expected);
+
+    drop(chunks);
+    assert!(dropped.load(Ordering::SeqCst));
+    Ok(())
+}
+
+#[test]
+fn read_iterator_outlives_owner() -> Result<()> {
+    let data = write_file(None)?;
+    let expected = read_file(&data)?;
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let owner = Arc::new(Owner::new(&data, 0, dropped.clone()));
+    let metadata = read_file_metadata(&mut Cursor::new(&data))?;
+    let mut iter = read_file_mmap(owner, metadata)?;
+
+    let first = iter.next().unwrap()?;
+    assert!(!dropped.load(Ordering::SeqCst));
+    assert_eq!(iter.collect::<Result<Vec<_>>>()?, expected[1..]);
+    assert!(!dropped.load(Ordering::SeqCst));
+
+    assert_eq!(first, expected[0]);
+    drop(first);
+    assert!(dropped.load(Ordering::SeqCst));
+    Ok(())
+}
+
+#[test]
+fn read_misaligned() -> Result<()> {
+    let data = write_file(None)?;
+    let expected = read_file(&data)?;
+
+    let owner = Arc::new(Owner::new(&data, 1, Default::default()));
+    let chunks = read_mmap(&owner)?;
+    assert_eq!(chunks, expected);
+
+    for chunk in &chunks {
+        // buffers not aligned to their type are copied...
+        assert!(!owner.contains(int32_values(chunk)));
+
+        // ... while bitmaps and byte buffers, which are always aligned, are not
+        let arrays = chunk.arrays();
+        let validity = arrays[0].validity().unwrap().as_slice().0;
+        assert!(owner.contains(validity.as_ptr()));
+        let utf8 = arrays[1].as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+        assert!(!owner.contains(utf8.offsets().as_ptr()));
+        assert!(owner.contains(utf8.values().as_ptr()));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn read_compressed() -> Result<()> {
+    let data = write_file(Some(write::Compression::LZ4))?;
+    let expected = read_file(&data)?;
+
+    let owner = Arc::new(Owner::new(&data, 0, Default::default()));
+    let chunks = read_mmap(&owner)?;
+    assert_eq!(chunks, expected);
+
+    // compressed buffers are copied
+    assert!(!owner.contains(int32_values(&chunks[0])));
+    Ok(())
+}
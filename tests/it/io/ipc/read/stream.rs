@@ -1,9 +1,10 @@
 use std::fs::File;
 
+use arrow2::chunk::Chunk;
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
 
-use crate::io::ipc::common::read_gzip_json;
+use crate::io::ipc::common::{pyarrow_ipc, read_gzip_json};
 
 fn test_file(version: &str, file_name: &str) -> Result<()> {
     let testdata = crate::test_util::arrow_test_data();
@@ -100,3 +101,30 @@ fn read_generated_200_compression_lz4() -> Result<()> {
 fn read_generated_200_compression_zstd() -> Result<()> {
     test_file("2.0.0-compression", "generated_zstd")
 }
+
+fn test_pyarrow(file_name: &str) -> Result<()> {
+    let mut file = File::open(format!("fixtures/pyarrow3/ipc/{}.stream", file_name))?;
+
+    let (schema, expected) = pyarrow_ipc(file_name);
+
+    let metadata = read_stream_metadata(&mut file)?;
+    let reader = StreamReader::new(file, metadata);
+
+    assert_eq!(&schema, &reader.metadata().schema);
+
+    let batches = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![Chunk::try_new(expected)?]);
+    Ok(())
+}
+
+#[test]
+fn read_pyarrow_float16() -> Result<()> {
+    test_pyarrow("float16")
+}
+
+#[test]
+fn read_pyarrow_decimal256() -> Result<()> {
+    test_pyarrow("decimal256")
+}
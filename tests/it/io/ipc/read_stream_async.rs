@@ -1,9 +1,16 @@
+use std::sync::Arc;
+
+use futures::channel::oneshot;
 use futures::StreamExt;
 use tokio::fs::File;
 use tokio_util::compat::*;
 
-use arrow2::error::Result;
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
+use arrow2::error::{ArrowError, Result};
 use arrow2::io::ipc::read::stream_async::*;
+use arrow2::io::ipc::write::stream_async::{StreamWriter, WriteOptions};
 
 use crate::io::ipc::common::read_gzip_json;
 
@@ -43,3 +50,127 @@ async fn test_file(version: &str, file_name: &str) -> Result<()> {
 async fn write_async() -> Result<()> {
     test_file("1.0.0-littleendian", "generated_primitive").await
 }
+
+fn dictionary_chunks() -> (Schema, Vec<Chunk<Arc<dyn Array>>>) {
+    let chunks = [["a", "b"], ["b", "c"], ["b", "c"]]
+        .iter()
+        .enumerate()
+        .map(|(i, values)| {
+            let keys = Int32Array::from(&[Some(0), None, Some(1), Some(i as i32 % 2)]);
+            let values = Arc::new(Utf8Array::<i32>::from_slice(values));
+            let dictionary = DictionaryArray::<i32>::from_data(keys, values);
+            let ints = Int64Array::from_values((0..4).map(|x| x * i as i64));
+            Chunk::new(vec![
+                Arc::new(dictionary) as Arc<dyn Array>,
+                Arc::new(ints) as Arc<dyn Array>,
+            ])
+        })
+        .collect::<Vec<_>>();
+    let schema = Schema::from(vec![
+        Field::new("a", chunks[0].arrays()[0].data_type().clone(), true),
+        Field::new("b", chunks[0].arrays()[1].data_type().clone(), true),
+    ]);
+    (schema, chunks)
+}
+
+#[tokio::test]
+async fn read_duplex_with_dictionaries() -> Result<()> {
+    let (schema, chunks) = dictionary_chunks();
+
+    // a small capacity forces messages to be split across many partial reads
+    let (writer, reader) = tokio::io::duplex(16);
+
+    let write = async {
+        let mut writer = StreamWriter::new(writer.compat_write(), WriteOptions::default());
+        writer.start(&schema, None).await?;
+        for chunk in &chunks {
+            writer.write(chunk, &schema, None).await?;
+        }
+        writer.finish().await
+    };
+
+    let read = async {
+        let mut reader = reader.compat();
+        let metadata = read_stream_metadata_async(&mut reader).await?;
+        assert_eq!(metadata.schema, schema);
+        let reader = AsyncStreamReader::new(reader, metadata);
+        reader
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+    };
+
+    let (written, read) = futures::join!(write, read);
+    written?;
+    assert_eq!(read?, chunks);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_duplex_paused() -> Result<()> {
+    let (schema, chunks) = dictionary_chunks();
+    let (writer, reader) = tokio::io::duplex(1024);
+    let (sender, receiver) = oneshot::channel::<()>();
+
+    let write = async {
+        let mut writer = StreamWriter::new(writer.compat_write(), WriteOptions::default());
+        writer.start(&schema, None).await?;
+        writer.write(&chunks[0], &schema, None).await?;
+        // the peer pauses until the first chunk was read
+        receiver.await.unwrap();
+        writer.write(&chunks[1], &schema, None).await?;
+        writer.finish().await
+    };
+
+    let read = async {
+        let mut reader = reader.compat();
+        let metadata = read_stream_metadata_async(&mut reader).await?;
+        let mut reader = AsyncStreamReader::new(reader, metadata);
+
+        let first = reader.next().await.unwrap()?;
+        sender.send(()).unwrap();
+        let mut read = vec![first];
+        while let Some(chunk) = reader.next().await {
+            read.push(chunk?);
+        }
+        Result::Ok(read)
+    };
+
+    let (written, read) = futures::join!(write, read);
+    written?;
+    assert_eq!(read?, chunks[..2]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_truncated() -> Result<()> {
+    let (schema, chunks) = dictionary_chunks();
+    let mut result = vec![];
+    let mut writer = StreamWriter::new(&mut result, WriteOptions::default());
+    writer.start(&schema, None).await?;
+    writer.write(&chunks[0], &schema, None).await?;
+    writer.finish().await?;
+
+    async fn read(data: &[u8]) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+        let mut reader = futures::io::Cursor::new(data);
+        let metadata = read_stream_metadata_async(&mut reader).await?;
+        let reader = AsyncStreamReader::new(reader, metadata);
+        reader.collect::<Vec<_>>().await.into_iter().collect()
+    }
+
+    // without the end-of-stream marker, the stream ends at a message boundary
+    assert_eq!(read(&result[..result.len() - 8]).await?, chunks[..1]);
+
+    // ending in the middle of the continuation marker or of the length is an error
+    for length in [result.len() - 6, result.len() - 2] {
+        match read(&result[..length]).await {
+            Err(ArrowError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof),
+            other => panic!(
+                "expected an unexpected EOF, got {:?}",
+                other.map(|x| x.len())
+            ),
+        }
+    }
+    Ok(())
+}
@@ -2,8 +2,9 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow2::array::*;
+use arrow2::buffer::Buffer;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
+use arrow2::datatypes::{DataType, Field, Schema, UnionMode};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::{read_file_metadata, FileReader};
 use arrow2::io::ipc::{write::*, IpcField};
@@ -17,7 +18,10 @@ fn write_(
     compression: Option<Compression>,
 ) -> Result<Vec<u8>> {
     let result = vec![];
-    let options = WriteOptions { compression };
+    let options = WriteOptions {
+        compression,
+        delta_dictionaries: false,
+    };
     let mut writer = FileWriter::try_new(result, schema, ipc_fields.clone(), options)?;
     for batch in batches {
         writer.write(batch, ipc_fields.as_ref().map(|x| x.as_ref()))?;
@@ -365,3 +369,266 @@ fn write_sliced_list() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+fn union(mode: UnionMode) -> UnionArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), mode);
+    let types = Buffer::from(vec![5i8, 7, 5, 7, 7]);
+    let (fields, offsets) = if mode.is_sparse() {
+        let fields = vec![
+            Arc::new(Int32Array::from([Some(1), None, None, Some(4), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from([
+                None,
+                Some("b"),
+                Some("c"),
+                None,
+                Some("e"),
+            ])),
+        ];
+        (fields, None)
+    } else {
+        let fields = vec![
+            Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from([Some("b"), None, Some("e")])),
+        ];
+        (fields, Some(Buffer::from(vec![0i32, 0, 1, 1, 2])))
+    };
+    UnionArray::from_data(data_type, types, fields, offsets)
+}
+
+fn map(keys_sorted: bool) -> MapArray {
+    let entries = DataType::Struct(vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Int32, true),
+    ]);
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries.clone(), false)),
+        keys_sorted,
+    );
+    let field = StructArray::from_data(
+        entries,
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "a", "c", "d"])),
+            Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4), None])),
+        ],
+        None,
+    );
+    MapArray::from_data(
+        data_type,
+        Buffer::from(vec![0i32, 2, 2, 3, 5]),
+        Arc::new(field),
+        Some([true, false, true, true].into()),
+    )
+}
+
+#[test]
+fn write_union() -> Result<()> {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let array = Arc::new(union(mode)) as Arc<dyn Array>;
+        let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+        let columns = Chunk::try_new(vec![array])?;
+        round_trip(columns, schema, None, None)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn write_sliced_union() -> Result<()> {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let array = Arc::new(union(mode).slice(1, 3)) as Arc<dyn Array>;
+        let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+        let columns = Chunk::try_new(vec![array])?;
+        round_trip(columns, schema, None, None)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn write_map() -> Result<()> {
+    for keys_sorted in [false, true] {
+        let array = Arc::new(map(keys_sorted)) as Arc<dyn Array>;
+        for array in [array.clone(), array.slice(1, 3).into()] {
+            let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+            let columns = Chunk::try_new(vec![array])?;
+            round_trip(columns, schema, None, None)?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn write_list_of_maps() -> Result<()> {
+    let map = Arc::new(map(false)) as Arc<dyn Array>;
+    let data_type = ListArray::<i32>::default_datatype(map.data_type().clone());
+    let array = Arc::new(ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from(vec![0i32, 1, 1, 4]),
+        map,
+        Some([true, false, true].into()),
+    )) as Arc<dyn Array>;
+    for array in [array.clone(), array.slice(2, 1).into()] {
+        let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+        let columns = Chunk::try_new(vec![array])?;
+        round_trip(columns, schema, None, None)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn write_union_and_map_projected() -> Result<()> {
+    let arrays = vec![
+        Arc::new(union(UnionMode::Sparse)) as Arc<dyn Array>,
+        Arc::new(union(UnionMode::Dense)),
+        Arc::new(map(false).slice(0, 4)),
+        Arc::new(Int32Array::from_slice([1, 2, 3, 4, 5])),
+    ];
+    let arrays = arrays
+        .into_iter()
+        .map(|array| array.slice(0, 4).into())
+        .collect::<Vec<Arc<dyn Array>>>();
+    let fields = arrays
+        .iter()
+        .enumerate()
+        .map(|(i, array)| Field::new(i.to_string(), array.data_type().clone(), true))
+        .collect::<Vec<_>>();
+    let schema = Schema::from(fields);
+    let data = write_(&[Chunk::try_new(arrays.clone())?], &schema, None, None)?;
+
+    // the buffers of the skipped unions and maps are consumed in order
+    for projection in [vec![3], vec![1, 3], vec![0, 2], vec![2, 3]] {
+        let mut reader = Cursor::new(&data);
+        let metadata = read_file_metadata(&mut reader)?;
+        let reader = FileReader::new(reader, metadata, Some(projection.clone()));
+        let batches = reader.collect::<Result<Vec<_>>>()?;
+        let expected = projection
+            .iter()
+            .map(|x| arrays[*x].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(batches, vec![Chunk::try_new(expected)?]);
+    }
+    Ok(())
+}
+
+#[test]
+fn write_decimal256() -> Result<()> {
+    let big = arrow2::types::i256::from(10).checked_pow(60).unwrap();
+    let array = Int256Array::from(&[Some(big), None, Some(big.wrapping_neg())])
+        .to(arrow2::datatypes::DataType::Decimal256(76, 2));
+    let array = Arc::new(array.slice(1, 2)) as Arc<dyn Array>;
+
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn write_float16() -> Result<()> {
+    use arrow2::types::f16;
+
+    let array = Float16Array::from(&[
+        Some(f16::ONE),
+        None,
+        Some(f16::from_f32(-0.5)),
+        Some(f16::MAX),
+        Some(f16::INFINITY),
+    ]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+
+    let columns = Chunk::try_new(vec![Arc::new(array.clone()) as Arc<dyn Array>])?;
+    round_trip(columns, schema.clone(), None, None)?;
+
+    let array = Arc::new(array.slice(1, 3)) as Arc<dyn Array>;
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(columns, schema, None, Some(Compression::ZSTD))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_compressed_small_and_large_buffers() -> Result<()> {
+    // buffers below the threshold are stored uncompressed; large ones are compressed
+    let small = Int32Array::from(&[Some(1), None, Some(3)]);
+    let large = Int32Array::from_values((0..10_000).map(|x| x % 7));
+    let utf8 = Utf8Array::<i32>::from_iter((0..1_000).map(|x| (x % 3 == 0).then(|| "aaaa")));
+
+    for compression in [Compression::LZ4, Compression::ZSTD] {
+        for array in [
+            Arc::new(small.clone()) as Arc<dyn Array>,
+            Arc::new(large.clone()),
+            Arc::new(large.slice(3, 5_000)),
+            Arc::new(utf8.clone()),
+            Arc::new(utf8.slice(7, 500)),
+        ] {
+            let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+            let columns = Chunk::try_new(vec![array])?;
+            round_trip(columns, schema, None, Some(compression))?;
+        }
+    }
+
+    let schema = Schema::from(vec![Field::new("a", large.data_type().clone(), true)]);
+    let batches = [Chunk::try_new(vec![Arc::new(large) as Arc<dyn Array>])?];
+    let uncompressed = write_(&batches, &schema, None, None)?;
+    let compressed = write_(&batches, &schema, None, Some(Compression::ZSTD))?;
+    assert!(compressed.len() < uncompressed.len());
+    Ok(())
+}
+
+#[test]
+fn write_extension_and_metadata() -> Result<()> {
+    use arrow2::datatypes::{DataType, Metadata};
+
+    let uuid = DataType::Extension(
+        "arrow.uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        Some("{\"version\":1}".to_string()),
+    );
+    let values = FixedSizeBinaryArray::from_iter(
+        [Some([1u8; 16]), None, Some([255u8; 16])].iter().copied(),
+        16,
+    );
+    let ids = FixedSizeBinaryArray::from_data(
+        uuid.clone(),
+        values.values().clone(),
+        values.validity().cloned(),
+    );
+
+    let mut child_metadata = Metadata::new();
+    child_metadata.insert("unit".to_string(), "meters".to_string());
+    let child = Field::new("distance", DataType::Int32, true).with_metadata(child_metadata);
+    let item = Field::new("item", uuid.clone(), true);
+    let struct_type = DataType::Struct(vec![
+        child,
+        Field::new("ids", DataType::List(Box::new(item.clone())), true),
+    ]);
+
+    let distances = Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let offsets = Buffer::from(vec![0i32, 1, 1, 3]);
+    let lists = Arc::new(ListArray::<i32>::from_data(
+        DataType::List(Box::new(item)),
+        offsets,
+        Arc::new(ids.slice(0, 3)),
+        None,
+    )) as Arc<dyn Array>;
+    let nested = StructArray::from_data(struct_type.clone(), vec![distances, lists], None);
+
+    let mut field_metadata = Metadata::new();
+    field_metadata.insert("origin".to_string(), "sensor".to_string());
+    let mut schema = Schema::from(vec![
+        Field::new("id", uuid, true).with_metadata(field_metadata),
+        Field::new("nested", struct_type, true),
+    ]);
+    schema
+        .metadata
+        .insert("created_by".to_string(), "arrow2".to_string());
+
+    let columns = Chunk::try_new(vec![Arc::new(ids) as Arc<dyn Array>, Arc::new(nested)])?;
+    round_trip(columns.clone(), schema.clone(), None, None)?;
+
+    let expected = vec![columns];
+    let result = write_(&expected, &schema, None, None)?;
+    let metadata = read_file_metadata(&mut Cursor::new(result))?;
+    assert_eq!(metadata.schema, schema);
+    Ok(())
+}
@@ -1,12 +1,12 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use arrow2::array::Array;
+use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
-use arrow2::error::Result;
+use arrow2::datatypes::{Field, Schema};
+use arrow2::error::{ArrowError, Result};
 use arrow2::io::ipc::read::read_stream_metadata;
-use arrow2::io::ipc::read::StreamReader;
+use arrow2::io::ipc::read::{StreamReader, StreamState};
 use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
 use arrow2::io::ipc::IpcField;
 
@@ -17,10 +17,22 @@ fn write_(
     schema: &Schema,
     ipc_fields: Option<Vec<IpcField>>,
     batches: &[Chunk<Arc<dyn Array>>],
+) -> Vec<u8> {
+    let options = WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
+    write_with_options(schema, ipc_fields, batches, options)
+}
+
+fn write_with_options(
+    schema: &Schema,
+    ipc_fields: Option<Vec<IpcField>>,
+    batches: &[Chunk<Arc<dyn Array>>],
+    options: WriteOptions,
 ) -> Vec<u8> {
     let mut result = vec![];
 
-    let options = WriteOptions { compression: None };
     let mut writer = StreamWriter::new(&mut result, options);
     writer.start(schema, ipc_fields).unwrap();
     for batch in batches {
@@ -147,3 +159,135 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+#[test]
+fn write_replaced_dictionaries() -> Result<()> {
+    let chunks = [["a", "b"], ["a", "b"], ["b", "c"]]
+        .iter()
+        .map(|values| {
+            let keys = Int32Array::from(&[Some(0), None, Some(1)]);
+            let values = Arc::new(Utf8Array::<i32>::from_slice(values));
+            let array = DictionaryArray::<i32>::from_data(keys, values);
+            Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+        })
+        .collect::<Vec<_>>();
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunks[0].arrays()[0].data_type().clone(),
+        true,
+    )]);
+
+    let result = write_(&schema, None, &chunks);
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata);
+    let read = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(read, chunks);
+    Ok(())
+}
+
+fn read_(data: Vec<u8>) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata);
+    reader.map(|x| x.map(|x| x.unwrap())).collect()
+}
+
+fn dictionary_chunk(values: &[&str], keys: &[Option<i32>]) -> Chunk<Arc<dyn Array>> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(values));
+    let array = DictionaryArray::<i32>::from_data(Int32Array::from(keys), values);
+    Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+}
+
+/// the logical values of a dictionary-encoded utf8 chunk
+fn logical_values(chunk: &Chunk<Arc<dyn Array>>) -> Vec<Option<String>> {
+    let array = chunk.arrays()[0]
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    array
+        .keys()
+        .iter()
+        .map(|key| key.map(|key| values.value(*key as usize).to_string()))
+        .collect()
+}
+
+#[test]
+fn write_delta_dictionaries() -> Result<()> {
+    let values = ["alpha", "bravo", "charlie", "delta", "echo"]
+        .iter()
+        .map(|x| x.repeat(5))
+        .collect::<Vec<_>>();
+    let values = values.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+    let chunks = vec![
+        dictionary_chunk(&values[..2], &[Some(0), None, Some(1)]),
+        dictionary_chunk(&values[..3], &[Some(2), Some(0)]),
+        dictionary_chunk(&values, &[Some(4), Some(3), None, Some(1)]),
+        // not a prefix of the previous values => replacement
+        dictionary_chunk(&[values[4], values[3]], &[Some(0), Some(1)]),
+    ];
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        chunks[0].arrays()[0].data_type().clone(),
+        true,
+    )]);
+
+    let options = WriteOptions {
+        compression: None,
+        delta_dictionaries: true,
+    };
+    let delta = write_with_options(&schema, None, &chunks, options);
+    let replacement = write_(&schema, None, &chunks);
+    // deltas only contain the new values
+    assert!(delta.len() < replacement.len());
+
+    for data in [delta, replacement] {
+        let read = read_(data)?;
+
+        let expected = chunks.iter().map(logical_values).collect::<Vec<_>>();
+        let result = read.iter().map(logical_values).collect::<Vec<_>>();
+        assert_eq!(result, expected);
+        assert_eq!(read, chunks);
+    }
+    Ok(())
+}
+
+#[test]
+fn read_truncated_stream() -> Result<()> {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+    let result = write_(&schema, None, std::slice::from_ref(&chunk));
+
+    let read = |length: usize| -> Result<Vec<StreamState>> {
+        let mut reader = Cursor::new(&result[..length]);
+        let metadata = read_stream_metadata(&mut reader)?;
+        // a live stream waits forever; stop at the item after the chunk
+        StreamReader::new(reader, metadata).take(2).collect()
+    };
+
+    // without the end-of-stream marker, the stream ends at a message boundary
+    let mut states = read(result.len() - 8)?;
+    assert!(matches!(states.pop(), Some(StreamState::Waiting)));
+    assert_eq!(states.pop().unwrap().unwrap(), chunk);
+
+    // ending in the middle of the continuation marker or of the length is an error
+    for length in [result.len() - 6, result.len() - 2] {
+        match read(length) {
+            Err(ArrowError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof),
+            other => panic!(
+                "expected an unexpected EOF, got {:?}",
+                other.map(|x| x.len())
+            ),
+        }
+    }
+    Ok(())
+}
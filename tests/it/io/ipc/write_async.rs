@@ -20,7 +20,10 @@ async fn write_(
 ) -> Result<Vec<u8>> {
     let mut result = AsyncCursor::new(vec![]);
 
-    let options = stream_async::WriteOptions { compression: None };
+    let options = stream_async::WriteOptions {
+        compression: None,
+        delta_dictionaries: false,
+    };
     let mut writer = stream_async::StreamWriter::new(&mut result, options);
     writer.start(schema, Some(ipc_fields)).await?;
     for batch in batches {
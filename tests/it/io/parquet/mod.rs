@@ -9,6 +9,7 @@ use arrow2::{
 use crate::io::ipc::read_gzip_json;
 
 mod read;
+mod read_async;
 mod write;
 mod write_async;
 
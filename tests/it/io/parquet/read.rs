@@ -493,3 +493,302 @@ fn invalid_utf8() {
         error
     );
 }
+
+/// A reader that records the position of every read from `inner`
+struct RecordingReader<R> {
+    inner: R,
+    reads: Arc<std::sync::Mutex<Vec<u64>>>,
+}
+
+impl<R: Read + Seek> Read for RecordingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let position = self.inner.stream_position()?;
+        self.reads.lock().unwrap().push(position);
+        self.inner.read(buf)
+    }
+}
+
+impl<R: Seek> Seek for RecordingReader<R> {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+type DisjointFile = (Schema, Vec<Chunk<Arc<dyn Array>>>, Vec<u8>);
+
+/// Writes a row group per chunk, each with values of "a" in a disjoint range
+fn write_disjoint_row_groups(write_statistics: bool) -> Result<DisjointFile> {
+    let chunks = (0..3)
+        .map(|group| {
+            let start = group * 100;
+            let a = Int32Array::from_values(start..start + 10);
+            let b =
+                Utf8Array::<i32>::from_iter_values((start..start + 10).map(|x| format!("v{}", x)));
+            let c =
+                Utf8Array::<i64>::from_iter_values((start..start + 10).map(|x| format!("v{}", x)));
+            Chunk::try_new(vec![
+                Arc::new(a) as Arc<dyn Array>,
+                Arc::new(b),
+                Arc::new(c),
+            ])
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+        Field::new("c", DataType::LargeUtf8, false),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        chunks.clone().into_iter().map(Ok),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain, Encoding::Plain],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    Ok((schema, chunks, writer.into_inner()))
+}
+
+fn int32(scalar: &dyn arrow2::scalar::Scalar) -> i32 {
+    scalar
+        .as_any()
+        .downcast_ref::<arrow2::scalar::PrimitiveScalar<i32>>()
+        .unwrap()
+        .value()
+        .unwrap()
+}
+
+#[test]
+fn filter_row_groups_by_statistics() -> Result<()> {
+    let (schema, chunks, data) = write_disjoint_row_groups(true)?;
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.row_groups.len(), 3);
+
+    // x > 100
+    let groups = filter_row_groups(&metadata, &schema.fields[0], |_, max| int32(max) > 100)?;
+    assert_eq!(groups, vec![1, 2]);
+
+    let groups = filter_row_groups(&metadata, &schema.fields[0], |min, max| {
+        int32(min) <= 5 && 5 <= int32(max)
+    })?;
+    assert_eq!(groups, vec![0]);
+
+    let groups = filter_row_groups(&metadata, &schema.fields[1], |min, _| {
+        let min = min
+            .as_any()
+            .downcast_ref::<arrow2::scalar::Utf8Scalar<i32>>()
+            .unwrap();
+        min.value() >= Some("v2")
+    })?;
+    assert_eq!(groups, vec![2]);
+
+    let groups = filter_row_groups(&metadata, &schema.fields[2], |min, _| {
+        let min = min
+            .as_any()
+            .downcast_ref::<arrow2::scalar::Utf8Scalar<i64>>()
+            .unwrap();
+        min.value() >= Some("v2")
+    })?;
+    assert_eq!(groups, vec![2]);
+
+    // only the pages of the selected row groups are read
+    let groups = filter_row_groups(&metadata, &schema.fields[0], |_, max| int32(max) > 100)?;
+    let reads = Arc::new(std::sync::Mutex::new(vec![]));
+    let reader = RecordingReader {
+        inner: Cursor::new(&data),
+        reads: reads.clone(),
+    };
+    let mut reader = FileReader::try_new(reader, None, None, None, None)?;
+    reader.set_groups_filter(Arc::new(move |i, _| groups.contains(&i)));
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, chunks[1..]);
+
+    let reads = reads.lock().unwrap();
+    let read_in = |group: usize| {
+        metadata.row_groups[group].columns().iter().any(|column| {
+            let (start, length) = column.byte_range();
+            reads.iter().any(|x| (start..start + length).contains(x))
+        })
+    };
+    assert!(!read_in(0));
+    assert!(read_in(1) && read_in(2));
+    Ok(())
+}
+
+#[test]
+fn filter_row_groups_without_statistics() -> Result<()> {
+    let (schema, _, data) = write_disjoint_row_groups(false)?;
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+
+    let groups = filter_row_groups(&metadata, &schema.fields[0], |_, _| false)?;
+    assert_eq!(groups, vec![0, 1, 2]);
+    Ok(())
+}
+
+/// Writes a file with a single utf8 column chunk with one page per array of `pages`.
+/// The dictionary arrays share the dictionary page of the first of them.
+fn write_utf8_pages(pages: &[Arc<dyn Array>]) -> Result<Vec<u8>> {
+    let schema = Schema::from(vec![Field::new("a1", DataType::Utf8, true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    let mut encoded = vec![];
+    let mut has_dictionary = false;
+    for array in pages {
+        let is_dictionary = matches!(array.data_type(), DataType::Dictionary(..));
+        let encoding = if is_dictionary {
+            Encoding::RleDictionary
+        } else {
+            Encoding::Plain
+        };
+        let iter = array_to_pages(array.as_ref(), descriptor.clone(), options, encoding)?;
+        let skip = (is_dictionary && has_dictionary) as usize;
+        has_dictionary |= is_dictionary;
+        for page in iter.skip(skip) {
+            encoded.push(page?);
+        }
+    }
+    let num_rows = pages.iter().map(|x| x.len()).sum();
+
+    let encoded = DynIter::new(encoded.into_iter().map(Ok));
+    let compressed =
+        Compressor::new(encoded, options.compression, vec![]).map_err(ArrowError::from);
+    let columns = std::iter::once(Ok(DynStreamingIterator::new(compressed)));
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    writer.write(DynIter::new(columns), num_rows)?;
+    let (_size, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+fn read_utf8_column(
+    data: &[u8],
+    as_dictionary: bool,
+    chunk_size: usize,
+) -> Result<Vec<Arc<dyn Array>>> {
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let mut field = infer_schema(&metadata)?.fields.remove(0);
+    if as_dictionary {
+        field = to_dictionary_field(field)?;
+    }
+    let mut columns = read_columns_many(
+        &mut reader,
+        &metadata.row_groups[0],
+        vec![field],
+        Some(chunk_size),
+    )?;
+    columns.remove(0).collect()
+}
+
+fn utf8_dictionary(keys: &[Option<i32>]) -> Arc<dyn Array> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["aa", "b", "ccc"]));
+    Arc::new(DictionaryArray::<i32>::from_data(
+        Int32Array::from(keys),
+        values,
+    ))
+}
+
+#[test]
+fn read_dictionary_pages() -> Result<()> {
+    let pages = [
+        utf8_dictionary(&[Some(0), None, Some(2), Some(0)]),
+        utf8_dictionary(&[Some(1), Some(1), None, Some(2), Some(0)]),
+    ];
+    let data = write_utf8_pages(&pages)?;
+
+    let expected = read_utf8_column(&data, false, 3)?;
+    let arrays = read_utf8_column(&data, true, 3)?;
+    assert_eq!(arrays.len(), 3);
+
+    let values = |array: &Arc<dyn Array>| {
+        let array = array
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false)
+        );
+        array.values().clone()
+    };
+    for (array, expected) in arrays.iter().zip(expected.iter()) {
+        let flat =
+            arrow2::compute::cast::cast(array.as_ref(), &DataType::Utf8, Default::default())?;
+        assert_eq!(flat.as_ref(), expected.as_ref());
+    }
+
+    // the values of all arrays are the dictionary page
+    let dictionary = values(&arrays[0]);
+    assert_eq!(
+        dictionary.as_ref(),
+        &Utf8Array::<i32>::from_slice(["aa", "b", "ccc"]) as &dyn Array
+    );
+    for array in &arrays[1..] {
+        assert!(Arc::ptr_eq(&values(array), &dictionary));
+    }
+    Ok(())
+}
+
+#[test]
+fn read_dictionary_with_plain_pages() -> Result<()> {
+    let pages = [
+        utf8_dictionary(&[Some(0), None, Some(2)]),
+        Arc::new(Utf8Array::<i32>::from([Some("dd"), None, Some("aa")])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([None, Some("ee")])),
+        utf8_dictionary(&[Some(1)]),
+    ];
+    let data = write_utf8_pages(&pages)?;
+
+    let expected = read_utf8_column(&data, false, 2)?;
+    let arrays = read_utf8_column(&data, true, 2)?;
+    assert_eq!(arrays.len(), expected.len());
+    for (array, expected) in arrays.iter().zip(expected.iter()) {
+        let flat =
+            arrow2::compute::cast::cast(array.as_ref(), &DataType::Utf8, Default::default())?;
+        assert_eq!(flat.as_ref(), expected.as_ref());
+    }
+
+    // the valid values of the plain pages were appended to the dictionary page
+    let last = arrays[arrays.len() - 1]
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = last
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    assert_eq!(
+        values,
+        &Utf8Array::<i32>::from_slice(["aa", "b", "ccc", "dd", "aa", "ee"])
+    );
+    assert_eq!(values.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn to_dictionary_field_nested() {
+    let field = Field::new(
+        "a",
+        DataType::List(Box::new(Field::new("item", DataType::Utf8, true))),
+        true,
+    );
+    assert!(to_dictionary_field(field).is_err());
+}
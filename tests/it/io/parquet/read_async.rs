@@ -0,0 +1,192 @@
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use futures::io::{AsyncRead, AsyncSeek, Cursor as AsyncCursor};
+
+use super::*;
+
+/// A reader of in-memory bytes that counts its seeks, i.e. the range requests that a remote
+/// reader (e.g. of an object store) would issue.
+#[derive(Clone)]
+struct Remote {
+    inner: AsyncCursor<Arc<[u8]>>,
+    requests: Arc<AtomicUsize>,
+}
+
+impl AsyncRead for Remote {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncSeek for Remote {
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        Pin::new(&mut self.inner).poll_seek(cx, pos)
+    }
+}
+
+fn write_file(chunks: &[Chunk<Arc<dyn Array>>]) -> Result<(Schema, Vec<u8>)> {
+    let schema = Schema::from(
+        chunks[0]
+            .arrays()
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let encodings = vec![Encoding::Plain; schema.fields.len()];
+    let row_groups =
+        RowGroupIterator::try_new(chunks.iter().cloned().map(Ok), &schema, options, encodings)?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+    Ok((schema, writer.into_inner()))
+}
+
+fn chunk(offset: i32) -> Chunk<Arc<dyn Array>> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Int32Array::from([Some(offset), None, Some(offset + 2)])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "bb", "ccc"])),
+        ],
+        None,
+    );
+    Chunk::new(vec![
+        Arc::new(Int64Array::from([Some(offset as i64), Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("x"), None, Some("yy")])),
+        Arc::new(array),
+    ])
+}
+
+fn data_slice(remote: &Remote, start: u64, length: u64) -> Vec<u8> {
+    let data = remote.inner.get_ref();
+    data[start as usize..(start + length) as usize].to_vec()
+}
+
+#[tokio::test]
+async fn read_row_group_coalesced() -> Result<()> {
+    let chunks = vec![chunk(0), chunk(10)];
+    let (schema, data) = write_file(&chunks)?;
+    let data: Arc<[u8]> = data.into();
+
+    let requests = Arc::new(AtomicUsize::new(0));
+    let remote = Remote {
+        inner: AsyncCursor::new(data),
+        requests: requests.clone(),
+    };
+    let metadata = read_metadata_async(&mut remote.clone()).await?;
+    assert_eq!(infer_schema(&metadata)?, schema);
+
+    let factory = || Box::pin(futures::future::ready(Ok(remote.clone()))) as BoxFuture<_>;
+    for (group, expected) in metadata.row_groups.iter().zip(chunks.iter()) {
+        // the columns of a row group are contiguous => a single request
+        requests.store(0, Ordering::SeqCst);
+        let chunk = read_row_group_async(factory, group, schema.fields.clone()).await?;
+        assert_eq!(&chunk, expected);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    // the first and last fields are not contiguous
+    requests.store(0, Ordering::SeqCst);
+    let fields = vec![schema.fields[0].clone(), schema.fields[2].clone()];
+    let chunk = read_row_group_async(factory, &metadata.row_groups[1], fields).await?;
+    assert_eq!(
+        chunk.arrays(),
+        &[chunks[1].arrays()[0].clone(), chunks[1].arrays()[2].clone()]
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    // the two columns of the struct are read in a single request
+    requests.store(0, Ordering::SeqCst);
+    let columns = read_columns_async(factory, metadata.row_groups[0].columns(), "c2").await?;
+    assert_eq!(columns.len(), 2);
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    // ... and share the memory of the request
+    let (first, second) = (&columns[0], &columns[1]);
+    let distance = second.0.byte_range().0 - first.0.byte_range().0;
+    assert_eq!(
+        second.1.as_ptr() as usize - first.1.as_ptr() as usize,
+        distance as usize
+    );
+    for (column, data) in &columns {
+        let (start, length) = column.byte_range();
+        assert_eq!(data.as_slice(), &data_slice(&remote, start, length));
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_columns_many_async_chunked() -> Result<()> {
+    let chunks = vec![chunk(0)];
+    let (schema, data) = write_file(&chunks)?;
+
+    let mut reader = AsyncCursor::new(data);
+    let metadata = read_metadata_async(&mut reader).await?;
+    let factory = || Box::pin(futures::future::ready(Ok(reader.clone()))) as BoxFuture<_>;
+
+    let group = &metadata.row_groups[0];
+    let columns = read_columns_many_async(factory, group, schema.fields.clone(), Some(2)).await?;
+    let result = RowGroupDeserializer::new(columns, group.num_rows() as usize, None)
+        .collect::<Result<Vec<_>>>()?;
+
+    let expected = chunks[0]
+        .arrays()
+        .iter()
+        .map(|x| x.slice(0, 2).into())
+        .collect::<Vec<Arc<dyn Array>>>();
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0], Chunk::new(expected));
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_page_stream() -> Result<()> {
+    use futures::TryStreamExt;
+
+    let chunks = vec![chunk(0)];
+    let (schema, data) = write_file(&chunks)?;
+
+    let mut reader = AsyncCursor::new(data);
+    let metadata = read_metadata_async(&mut reader).await?;
+    let column = &metadata.row_groups[0].columns()[1];
+
+    let pages = get_page_stream(column, &mut reader, None, vec![])
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let pages = BasicDecompressor::new(pages.into_iter().map(Ok), vec![]);
+
+    let field = schema.fields[1].clone();
+    let arrays = column_iter_to_arrays(vec![pages], vec![&column.descriptor().type_()], field, 3)?
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(arrays, vec![chunks[0].arrays()[1].clone()]);
+    Ok(())
+}
@@ -1,6 +1,7 @@
+use std::collections::HashMap;
 use std::io::Cursor;
 
-use arrow2::error::Result;
+use arrow2::error::{ArrowError, Result};
 use arrow2::io::parquet::write::*;
 
 use super::*;
@@ -18,6 +19,16 @@ fn round_trip(
             pyarrow_nested_nullable(column),
             pyarrow_nested_nullable_statistics(column),
         )
+    } else if nullable && column == 6 {
+        // the statistics of a dictionary array are of the values its keys point to
+        let statistics: Box<dyn Statistics> = Box::new(PrimitiveStatistics::<i32> {
+            data_type: DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Int32), false),
+            null_count: Some(1),
+            distinct_count: None,
+            min_value: Some(10),
+            max_value: Some(200),
+        });
+        (pyarrow_nullable(column), Some(statistics))
     } else if nullable {
         (
             pyarrow_nullable(column),
@@ -519,3 +530,390 @@ fn decimal_26_required_v2() -> Result<()> {
         Encoding::Plain,
     )
 }
+
+fn write_nested(array: Arc<dyn Array>, version: Version) -> Result<Vec<u8>> {
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array])];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![Encoding::Plain])?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+fn round_trip_chunked(array: Arc<dyn Array>, version: Version) -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let data = write_nested(array.clone(), version)?;
+
+    // chunks smaller than the page split rows of the same page across arrays
+    let chunk_size = 2;
+    let reader = FileReader::try_new(Cursor::new(data), None, Some(chunk_size), None, None)?;
+    assert_eq!(reader.schema(), &schema);
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+
+    assert_eq!(chunks.len(), (array.len() + chunk_size - 1) / chunk_size);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let offset = i * chunk_size;
+        let expected = array.slice(offset, chunk_size.min(array.len() - offset));
+        assert_eq!(chunk.arrays()[0].as_ref(), expected.as_ref());
+    }
+    Ok(())
+}
+
+fn list_i32(values: &[Option<Vec<Option<i32>>>]) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(values.iter().cloned()).unwrap();
+    array.into()
+}
+
+#[test]
+fn primitive_chunked() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(3), Some(4), Some(5)]);
+    round_trip_chunked(Arc::new(array), Version::V1)
+}
+
+#[test]
+fn list_null_and_empty() -> Result<()> {
+    let array = list_i32(&[
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2), Some(3), Some(4)]),
+        None,
+        Some(vec![]),
+        Some(vec![None]),
+    ]);
+    round_trip_chunked(Arc::new(array.clone()), Version::V1)?;
+    round_trip_chunked(Arc::new(array.clone()), Version::V2)?;
+
+    // the null and the empty lists are distinguished
+    let data = write_nested(Arc::new(array), Version::V2)?;
+    let (result, _) = read_column(&mut Cursor::new(data), 0, 0)?;
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 2, 2, 2, 5, 5, 5, 6]);
+    let validity = result.validity().unwrap();
+    assert_eq!(
+        validity.iter().collect::<Vec<_>>(),
+        vec![true, false, true, true, false, true, true]
+    );
+    Ok(())
+}
+
+#[test]
+fn list_sliced() -> Result<()> {
+    let array = list_i32(&[
+        Some(vec![Some(1), None]),
+        Some(vec![Some(2)]),
+        None,
+        Some(vec![Some(3), Some(4)]),
+        Some(vec![Some(5)]),
+    ]);
+    round_trip_chunked(Arc::new(array.slice(1, 3)), Version::V1)
+}
+
+#[test]
+fn list_required_values() -> Result<()> {
+    let values = Int32Array::from_slice([1, 2, 3, 4]);
+    let data_type = ListArray::<i32>::default_datatype(DataType::Int32);
+    let data_type = if let DataType::List(field) = data_type {
+        DataType::List(Box::new(Field::new(field.name, DataType::Int32, false)))
+    } else {
+        unreachable!()
+    };
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 1, 1, 3, 3, 4]),
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true, true, true])),
+    );
+    round_trip_chunked(Arc::new(array.clone()), Version::V1)?;
+    round_trip_chunked(Arc::new(array), Version::V2)
+}
+
+#[test]
+fn large_list_utf8() -> Result<()> {
+    let mut array = MutableListArray::<i64, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![
+        Some(vec![Some("a"), None, Some("bb")]),
+        Some(vec![]),
+        None,
+        Some(vec![Some("")]),
+    ])?;
+    let array: ListArray<i64> = array.into();
+    round_trip_chunked(Arc::new(array.clone()), Version::V1)?;
+    round_trip_chunked(Arc::new(array), Version::V2)
+}
+
+#[test]
+fn list_of_lists() -> Result<()> {
+    let inner = list_i32(&[
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(2), None]),
+        Some(vec![Some(3)]),
+    ]);
+    let data_type = ListArray::<i32>::default_datatype(inner.data_type().clone());
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 2, 2, 5]),
+        Arc::new(inner),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    round_trip_chunked(Arc::new(array), Version::V1)
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let values = Int16Array::from([Some(1), None, Some(3), Some(4), Some(5), None]);
+    let data_type = FixedSizeListArray::default_datatype(DataType::Int16, 2);
+    let array = FixedSizeListArray::from_data(
+        data_type,
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true])),
+    );
+    round_trip_chunked(Arc::new(array.clone()), Version::V1)?;
+    round_trip_chunked(Arc::new(array), Version::V2)
+}
+
+fn struct_(validity: Option<Bitmap>) -> StructArray {
+    let a = Int32Array::from([Some(1), None, Some(3), Some(4), None]);
+    let b = Utf8Array::<i32>::from_slice(["a", "bb", "", "ccc", "d"]);
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ];
+    StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(a), Arc::new(b)],
+        validity,
+    )
+}
+
+#[test]
+fn struct_required() -> Result<()> {
+    round_trip_chunked(Arc::new(struct_(None)), Version::V1)?;
+    round_trip_chunked(Arc::new(struct_(None)), Version::V2)
+}
+
+#[test]
+fn struct_optional() -> Result<()> {
+    // the values under null structs are not written
+    let array = struct_(Some(Bitmap::from([true, false, true, true, false])));
+    round_trip_chunked(Arc::new(array.clone()), Version::V1)?;
+    round_trip_chunked(Arc::new(array), Version::V2)
+}
+
+#[test]
+fn struct_of_list() -> Result<()> {
+    let list = list_i32(&[
+        Some(vec![Some(1)]),
+        Some(vec![Some(2), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(3)]),
+    ]);
+    let fields = vec![
+        Field::new("list", list.data_type().clone(), true),
+        Field::new("b", DataType::Boolean, true),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(list),
+            Arc::new(BooleanArray::from([
+                Some(true),
+                None,
+                Some(false),
+                Some(true),
+                None,
+            ])),
+        ],
+        Some(Bitmap::from([true, false, true, true, true])),
+    );
+    round_trip_chunked(Arc::new(array.clone()), Version::V1)?;
+    round_trip_chunked(Arc::new(array), Version::V2)
+}
+
+#[test]
+fn list_of_struct() -> Result<()> {
+    let values = struct_(Some(Bitmap::from([true, false, true, true, true])));
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    let array = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 2, 2, 5]),
+        Arc::new(values),
+        Some(Bitmap::from([true, false, true, true])),
+    );
+    round_trip_chunked(Arc::new(array.clone()), Version::V1)?;
+    round_trip_chunked(Arc::new(array), Version::V2)
+}
+
+fn write_with_column_options(
+    chunk: Chunk<Arc<dyn Array>>,
+    column_options: HashMap<Vec<String>, ColumnOptions>,
+) -> Result<Vec<u8>> {
+    let schema = Schema::from(
+        chunk
+            .arrays()
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let encodings = vec![Encoding::Plain; chunk.arrays().len()];
+
+    let mut row_groups =
+        RowGroupIterator::try_new(std::iter::once(Ok(chunk)), &schema, options, encodings)?;
+    row_groups.set_column_options(column_options);
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn column_options() -> Result<()> {
+    let names = Utf8Array::<i32>::from([Some("a"), None, Some("bb"), Some("a"), Some("bb")]);
+    let chunk = Chunk::try_new(vec![
+        Arc::new(Int64Array::from_slice([1, 2, 3, 4, 5])) as Arc<dyn Array>,
+        Arc::new(names.clone()),
+        Arc::new(BinaryArray::<i32>::from_slice([
+            b"a", b"b", b"c", b"d", b"e",
+        ])),
+        Arc::new(names),
+        Arc::new(struct_(None)),
+    ])?;
+
+    let path = |path: &[&str]| path.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    let dictionary = ColumnOptions {
+        encoding: Some(Encoding::RleDictionary),
+        ..Default::default()
+    };
+    let column_options = vec![
+        (path(&["c1"]), dictionary),
+        (
+            path(&["c2"]),
+            ColumnOptions {
+                write_statistics: Some(false),
+                ..Default::default()
+            },
+        ),
+        (
+            path(&["c3"]),
+            ColumnOptions {
+                dictionary_page_size_limit: Some(4),
+                ..dictionary
+            },
+        ),
+        (
+            path(&["c4", "b"]),
+            ColumnOptions {
+                encoding: Some(Encoding::Plain),
+                write_statistics: Some(false),
+                ..Default::default()
+            },
+        ),
+    ]
+    .into_iter()
+    .collect();
+    let data = write_with_column_options(chunk.clone(), column_options)?;
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    let columns = metadata.row_groups[0].columns();
+    assert_eq!(columns.len(), 6);
+    let is_dictionary_encoded = |i: usize| {
+        columns[i]
+            .column_encoding()
+            .contains(&Encoding::RleDictionary.into())
+    };
+    assert_eq!(
+        (0..6).map(is_dictionary_encoded).collect::<Vec<_>>(),
+        vec![false, true, false, false, false, false]
+    );
+    // the dictionary of `c3` is larger than its limit
+    assert!(columns[3]
+        .column_encoding()
+        .contains(&Encoding::Plain.into()));
+    assert_eq!(
+        columns
+            .iter()
+            .map(|x| x.statistics().is_some())
+            .collect::<Vec<_>>(),
+        vec![true, true, false, true, true, false]
+    );
+    // the statistics of the dictionary-encoded `c1` are of its values
+    let (_, statistics) = read_column(&mut Cursor::new(&data), 0, 1)?;
+    let expected: Box<dyn Statistics> = Box::new(Utf8Statistics {
+        null_count: Some(1),
+        distinct_count: None,
+        min_value: Some("a".to_string()),
+        max_value: Some("bb".to_string()),
+    });
+    assert_eq!(statistics, Some(expected));
+    assert!(columns
+        .iter()
+        .all(|x| x.compression() == Compression::Uncompressed));
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn column_options_unsupported_encoding() -> Result<()> {
+    let chunk = Chunk::try_new(vec![Arc::new(struct_(None)) as Arc<dyn Array>])?;
+    let options = |path: &str, encoding| {
+        std::iter::once((
+            vec!["c0".to_string(), path.to_string()],
+            ColumnOptions {
+                encoding: Some(encoding),
+                ..Default::default()
+            },
+        ))
+        .collect()
+    };
+
+    // the encoding is checked against the type of the leaf
+    let result =
+        write_with_column_options(chunk.clone(), options("a", Encoding::DeltaLengthByteArray));
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("cannot be encoded"));
+
+    // the leaves of nested types are only plain-encoded
+    for encoding in [Encoding::DeltaLengthByteArray, Encoding::RleDictionary] {
+        let result = write_with_column_options(chunk.clone(), options("b", encoding));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("only Encoding::Plain is supported"));
+    }
+    Ok(())
+}
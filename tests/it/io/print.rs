@@ -381,3 +381,50 @@ fn write_union() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn write_map() -> Result<()> {
+    let data_type = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    );
+    let mut array = MutableMapArray::new(
+        data_type,
+        Box::new(MutableUtf8Array::<i32>::new()),
+        Box::new(MutablePrimitiveArray::<i32>::new()),
+    );
+    array.keys::<MutableUtf8Array<i32>>().push(Some("a"));
+    array.values::<MutablePrimitiveArray<i32>>().push(Some(1));
+    array.try_push_entry()?;
+    array.try_push_valid()?;
+    array.push_null();
+    array.try_push_valid()?;
+    let array: MapArray = array.into();
+
+    let batch = Chunk::new(vec![&array as &dyn Array]);
+
+    let table = write(&[batch], &["a"]);
+
+    let expected = vec![
+        "+----------------------+",
+        "| a                    |",
+        "+----------------------+",
+        "| [{key: a, value: 1}] |",
+        "|                      |",
+        "| []                   |",
+        "+----------------------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    Ok(())
+}
@@ -67,6 +67,42 @@ fn tz_aware_no_timezone() {
     assert_eq!(format!("{:?}", r), expected);
 }
 
+#[test]
+fn parse_offset() {
+    let offset = |x| {
+        temporal_conversions::parse_offset(x)
+            .unwrap()
+            .local_minus_utc()
+    };
+    assert_eq!(offset("UTC"), 0);
+    assert_eq!(offset("Z"), 0);
+    assert_eq!(offset("+01:00"), 3600);
+    assert_eq!(offset("01:00"), 3600);
+    assert_eq!(offset("-05:30"), -(5 * 3600 + 30 * 60));
+    assert_eq!(offset("+0530"), 5 * 3600 + 30 * 60);
+    assert_eq!(offset("-08"), -8 * 3600);
+}
+
+#[test]
+fn parse_offset_invalid() {
+    for offset in [
+        "",
+        "+",
+        "abc",
+        "+25:00",
+        "+05:60",
+        "+5:3a",
+        "+-1:00",
+        "Europe/Lisbon",
+    ] {
+        assert!(
+            temporal_conversions::parse_offset(offset).is_err(),
+            "{}",
+            offset
+        );
+    }
+}
+
 #[test]
 fn add_interval_fixed_offset() {
     // 1972 has a leap year on the 29th.